@@ -218,10 +218,13 @@ fn default_config() -> cbindgen::Config {
             ("KeyEventArg".into(), "KeyEvent".into()),
             ("PointerEventArg".into(), "PointerEvent".into()),
             ("PointerScrollEventArg".into(), "PointerScrollEvent".into()),
+            ("DropEventArg".into(), "DropEvent".into()),
+            ("CompositionEventArg".into(), "CompositionEvent".into()),
             ("PointArg".into(), "slint::LogicalPosition".into()),
             ("FloatArg".into(), "float".into()),
             ("IntArg".into(), "int".into()),
             ("MenuEntryArg".into(), "MenuEntry".into()),
+            ("StringArg".into(), "SharedString".into()),
             // Note: these types are not the same, but they are only used in callback return types that are only used in C++ (set and called)
             // therefore it is ok to reinterpret_cast
             ("MenuEntryModel".into(), "std::shared_ptr<slint::Model<MenuEntry>>".into()),
@@ -286,6 +289,9 @@ fn gen_corelib(
         "TouchArea",
         "FocusScope",
         "SwipeGestureHandler",
+        "DropArea",
+        "PinchGestureHandler",
+        "WindowFrameArea",
         "Flickable",
         "SimpleText",
         "ComplexText",
@@ -322,6 +328,8 @@ fn gen_corelib(
         "PointerEventButton",
         "PointerEvent",
         "PointerScrollEvent",
+        "DropEvent",
+        "CompositionEvent",
         "Rect",
         "SortOrder",
         "BitmapFont",
@@ -373,10 +381,13 @@ fn gen_corelib(
         "KeyEventArg",
         "PointerEventArg",
         "PointerScrollEventArg",
+        "DropEventArg",
+        "CompositionEventArg",
         "PointArg",
         "Point",
         "MenuEntryModel",
         "MenuEntryArg",
+        "StringArg",
         "slint_color_brighter",
         "slint_color_darker",
         "slint_color_transparentize",
@@ -515,7 +526,7 @@ fn gen_corelib(
             ],
             vec!["Color"],
             "slint_image_internal.h",
-            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; } }",
+            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; struct AnimatedImage{}; } }",
         ),
         (
             vec!["Color", "slint_color_brighter", "slint_color_darker",
@@ -535,7 +546,15 @@ fn gen_corelib(
             "",
         ),
         (
-            vec!["Brush", "LinearGradient", "GradientStop", "RadialGradient"],
+            vec![
+                "Brush",
+                "LinearGradient",
+                "GradientStop",
+                "RadialGradient",
+                "ConicGradient",
+                "ImagePatternBrush",
+                "ImagePatternData",
+            ],
             vec!["Color"],
             "slint_brush_internal.h",
             "",
@@ -570,6 +589,8 @@ fn gen_corelib(
             "slint_windowrc_set_logical_size",
             "slint_windowrc_set_physical_size",
             "slint_windowrc_color_scheme",
+            "slint_windowrc_accent_color",
+            "slint_windowrc_font_metrics",
             "slint_windowrc_supports_native_menu_bar",
             "slint_windowrc_setup_native_menu_bar",
             "slint_windowrc_default_font_size",
@@ -583,6 +604,8 @@ fn gen_corelib(
             "slint_windowrc_is_minimized",
             "slint_windowrc_is_maximized",
             "slint_windowrc_take_snapshot",
+            "slint_windowrc_start_system_move",
+            "slint_windowrc_start_system_resize",
             "slint_new_path_elements",
             "slint_new_path_events",
             "slint_color_brighter",
@@ -620,6 +643,17 @@ fn gen_corelib(
         };
         special_config.structure.derive_eq = true;
         special_config.structure.derive_neq = true;
+        // The images are compared with slint_image_compare_equal()
+        special_config.export.body.insert(
+            "ImagePatternData".to_owned(),
+            "    friend bool operator==(const ImagePatternData &a, const ImagePatternData &b) {
+        return slint_image_compare_equal(&a.image, &b.image) && a.offset_x == b.offset_x
+            && a.offset_y == b.offset_y && a.scale_x == b.scale_x && a.scale_y == b.scale_y
+            && a.rotation == b.rotation && a.opacity == b.opacity && a.repeat_x == b.repeat_x
+            && a.repeat_y == b.repeat_y;
+    }"
+            .into(),
+        );
         // Put the rust type in a deeper "types" namespace, so the use of same type in for example generated
         // Property<> fields uses the public `slint::Blah` type
         special_config.namespaces =
@@ -713,7 +747,8 @@ fn gen_corelib(
     );
     config.export.body.insert(
         "EasingCurve".to_owned(),
-        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}".into()
+        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}
+    static EasingCurve spring(float stiffness, float damping, float mass) { EasingCurve c(Tag::Spring); c.spring = { { stiffness, damping, mass } }; return c; }".into()
     );
     config.export.body.insert(
         "LayoutInfo".to_owned(),
@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 #pragma once
+#include <algorithm>
 #include <string_view>
 #include "slint_color.h"
+#include "slint_image.h"
 #include "slint_brush_internal.h"
 #include "slint_string.h"
 
@@ -98,10 +100,127 @@ private:
     }
 };
 
+/// \private
+/// ConicGradientBrush represents a gradient whose colors are swept clockwise around the center,
+/// starting at a specific angle.
+class ConicGradientBrush
+{
+public:
+    /// Constructs an empty conic gradient with no color stops.
+    ConicGradientBrush() = default;
+    /// Constructs a new conic gradient starting at the specified \a fromAngle. The color stops
+    /// will be constructed from the stops array pointed to be \a firstStop, with the length
+    /// \a stopCount.
+    ConicGradientBrush(float fromAngle, const GradientStop *firstStop, int stopCount)
+        : inner(make_conic_gradient(fromAngle, firstStop, stopCount))
+    {
+    }
+
+    /// Returns the angle at which the conic gradient starts, in degrees.
+    float fromAngle() const
+    {
+        // The gradient's first stop is a fake stop to store the angle
+        return inner[0].position;
+    }
+
+    /// Returns the number of gradient stops.
+    int stopCount() const { return int(inner.size()) - 1; }
+
+    /// Returns a pointer to the first gradient stop; undefined if the gradient has not stops.
+    const GradientStop *stopsBegin() const { return inner.begin() + 1; }
+    /// Returns a pointer past the last gradient stop. The returned pointer cannot be dereferenced,
+    /// it can only be used for comparison.
+    const GradientStop *stopsEnd() const { return inner.end(); }
+
+private:
+    cbindgen_private::types::ConicGradientBrush inner;
+
+    friend class slint::Brush;
+
+    static SharedVector<private_api::GradientStop>
+    make_conic_gradient(float fromAngle, const GradientStop *firstStop, int stopCount)
+    {
+        SharedVector<private_api::GradientStop> gradient;
+        gradient.push_back({ Color::from_argb_encoded(0).inner, fromAngle });
+        for (int i = 0; i < stopCount; ++i, ++firstStop)
+            gradient.push_back(*firstStop);
+        return gradient;
+    }
+};
+
 }
 
+/// ImagePatternBrush describes how to fill a shape with an image. By default, the image starts at
+/// the top-left corner of the shape and is repeated in both directions, with one pixel of the image
+/// covering one logical pixel.
+class ImagePatternBrush
+{
+public:
+    /// Constructs a new image pattern that repeats \a image in both directions.
+    explicit ImagePatternBrush(const Image &image)
+        : inner({ cbindgen_private::types::ImagePatternData {
+                image.data, 0., 0., 1., 1., 0., 1., true, true } })
+    {
+    }
+
+    /// Returns a copy of this pattern with the top-left corner of the image moved by \a x and
+    /// \a y logical pixels, relative to the top-left corner of the shape.
+    [[nodiscard]] ImagePatternBrush with_offset(float x, float y) const
+    {
+        ImagePatternBrush result = *this;
+        result.inner[0].offset_x = x;
+        result.inner[0].offset_y = y;
+        return result;
+    }
+
+    /// Returns a copy of this pattern with the image scaled by the factors \a x and \a y.
+    [[nodiscard]] ImagePatternBrush with_scale(float x, float y) const
+    {
+        ImagePatternBrush result = *this;
+        result.inner[0].scale_x = x;
+        result.inner[0].scale_y = y;
+        return result;
+    }
+
+    /// Returns a copy of this pattern with the image rotated clockwise by \a angle degrees,
+    /// around its top-left corner.
+    [[nodiscard]] ImagePatternBrush with_rotation(float angle) const
+    {
+        ImagePatternBrush result = *this;
+        result.inner[0].rotation = angle;
+        return result;
+    }
+
+    /// Returns a copy of this pattern that repeats the image horizontally if \a horizontal is
+    /// true, and vertically if \a vertical is true. Otherwise, the image is drawn only once in
+    /// that direction.
+    [[nodiscard]] ImagePatternBrush with_repeat(bool horizontal, bool vertical) const
+    {
+        ImagePatternBrush result = *this;
+        result.inner[0].repeat_x = horizontal;
+        result.inner[0].repeat_y = vertical;
+        return result;
+    }
+
+    /// Returns a copy of this pattern with the image drawn with the \a opacity, between 0 and 1.
+    [[nodiscard]] ImagePatternBrush with_opacity(float opacity) const
+    {
+        ImagePatternBrush result = *this;
+        result.inner[0].opacity = std::clamp(opacity, 0.f, 1.f);
+        return result;
+    }
+
+    /// Returns the image of the pattern.
+    Image image() const { return Image(inner[0].image); }
+
+private:
+    cbindgen_private::types::ImagePatternBrush inner;
+
+    friend class slint::Brush;
+};
+
 /// Brush is used to declare how to fill or outline shapes, such as rectangles, paths or text. A
-/// brush is either a solid color or a linear gradient.
+/// brush is either a solid color, a gradient, or an image pattern.
 class Brush
 {
 public:
@@ -123,8 +242,18 @@ public:
     {
     }
 
+    /// \private
+    /// Constructs a new brush that is the gradient \a gradient.
+    Brush(const private_api::ConicGradientBrush &gradient)
+        : data(Inner::ConicGradient(gradient.inner))
+    {
+    }
+
+    /// Constructs a new brush that fills shapes with the image \a pattern.
+    Brush(const ImagePatternBrush &pattern) : data(Inner::ImagePattern(pattern.inner)) { }
+
     /// Returns the color of the brush. If the brush is a gradient, this function returns the color
-    /// of the first stop.
+    /// of the first stop. If the brush is an image pattern, a transparent color is returned.
     inline Color color() const;
 
     /// Returns a new version of this brush that has the brightness increased
@@ -177,6 +306,13 @@ Color Brush::color() const
             result.inner = data.radial_gradient._0[0].color;
         }
         break;
+    case Tag::ConicGradient:
+        if (data.conic_gradient._0.size() > 1) {
+            result.inner = data.conic_gradient._0[1].color;
+        }
+        break;
+    case Tag::ImagePattern:
+        break;
     }
     return result;
 }
@@ -201,6 +337,14 @@ inline Brush Brush::brighter(float factor) const
                                                           &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_brighter(&data.conic_gradient._0[i].color, factor,
+                                                          &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::ImagePattern:
+        break;
     }
     return result;
 }
@@ -225,6 +369,14 @@ inline Brush Brush::darker(float factor) const
                                                         &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_darker(&data.conic_gradient._0[i].color, factor,
+                                                        &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::ImagePattern:
+        break;
     }
     return result;
 }
@@ -251,6 +403,17 @@ inline Brush Brush::transparentize(float factor) const
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_transparentize(
+                    &data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::ImagePattern:
+        result.data.image_pattern._0[0].opacity =
+                std::clamp(data.image_pattern._0[0].opacity * (1.f - factor), 0.f, 1.f);
+        break;
     }
     return result;
 }
@@ -277,6 +440,16 @@ inline Brush Brush::with_alpha(float alpha) const
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_with_alpha(
+                    &data.conic_gradient._0[i].color, alpha,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
+    case Tag::ImagePattern:
+        result.data.image_pattern._0[0].opacity = std::clamp(alpha, 0.f, 1.f);
+        break;
     }
     return result;
 }
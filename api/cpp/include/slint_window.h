@@ -79,6 +79,22 @@ public:
     {
         return slint_windowrc_color_scheme(&inner);
     }
+    Color accent_color() const
+    {
+        Color color;
+        slint_windowrc_accent_color(&inner, &color);
+        return color;
+    }
+    cbindgen_private::FontMetrics font_metrics(const SharedString &font_family,
+                                               float font_size) const
+    {
+        return slint_windowrc_font_metrics(&inner, &font_family, font_size);
+    }
+    void start_system_move() const { slint_windowrc_start_system_move(&inner); }
+    void start_system_resize(cbindgen_private::WindowResizeEdge edge) const
+    {
+        slint_windowrc_start_system_resize(&inner, edge);
+    }
     bool supports_native_menu_bar() const
     {
         return slint_windowrc_supports_native_menu_bar(&inner);
@@ -87,9 +103,6 @@ public:
     template<typename Component, typename SubMenu, typename Activated>
     void setup_native_menu_bar(Component component, SubMenu submenu, Activated activated) const
     {
-        if (!supports_native_menu_bar()) {
-            return;
-        }
         struct MenuWrapper
         {
             Component component;
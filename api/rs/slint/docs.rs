@@ -62,6 +62,25 @@ pub fn get_user_name(&self) -> crate::SharedString {
         }
         /// Assigns a new value to the `user_name` property.
         pub fn set_user_name(&self, value: crate::SharedString) {}
+        /// For each property declared at the root of the component, a function to set a handler
+        /// that is called with the new value when the property changes is generated. Like the
+        /// `changed` callbacks in the `.slint` design markup, the handler runs after the change,
+        /// before the next frame is rendered, and only if the value differs. Setting a new handler
+        /// replaces the previous one.
+        ///
+        /// No function is generated if a callback is declared with the same name, such as
+        /// `counter-changed`.
+        pub fn on_counter_changed(&self, f: impl FnMut(i32) + 'static) {}
+        /// For each property declared at the root of the component whose type can be animated, such as
+        /// numbers, lengths, and colors, a function to animate the property to a new value is generated.
+        /// The returned [`AnimationHandle`](crate::AnimationHandle) pauses, resumes, or seeks the animation.
+        pub fn animate_counter(
+            &self,
+            value: i32,
+            animation: crate::Animation,
+        ) -> crate::AnimationHandle {
+            unimplemented!()
+        }
 
         /// For each callback declared at the root of the component, a function to synchronously call that
         /// callback is generated. This is the function that calls the `hello` callback declared
@@ -83,6 +102,30 @@ pub fn invoke_hello(&self) {}
         /// ```
         pub fn on_hello(&self, f: impl Fn() + 'static) {}
 
+        /// For each callback without return value declared at the root of the component, a function to
+        /// connect an async handler to that callback is generated. Every time the `hello` callback is
+        /// emitted, the future returned by `f` is spawned on the Slint event loop with [`crate::spawn_local()`].
+        /// The future runs in the thread of the event loop, so it can upgrade a weak reference to
+        /// the component and set properties to report its progress or its result:
+        /// ```ignore
+        ///     let sample = SampleComponent::new().unwrap();
+        ///     let sample_weak = sample.as_weak();
+        ///     sample.on_hello_async(move || {
+        ///         let sample_weak = sample_weak.clone();
+        ///         async move {
+        ///             let user_name = fetch_user_name().await;
+        ///             if let Some(sample) = sample_weak.upgrade() {
+        ///                 sample.set_user_name(user_name);
+        ///             }
+        ///         }
+        ///     });
+        /// ```
+        pub fn on_hello_async<F: core::future::Future<Output = ()> + 'static>(
+            &self,
+            f: impl FnMut() -> F + 'static,
+        ) {
+        }
+
         /// For each public function declared at the root of the component, a function to synchronously call
         /// that function is generated. This is the function that calls the `do-something` function
         /// declared in the `.slint` design markup.
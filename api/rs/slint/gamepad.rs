@@ -0,0 +1,554 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Input from gamepads and joysticks.
+//!
+//! **Note:** This module is only available with the "gamepad" feature
+//!
+//! [`Gamepads`] reads the connected gamepads in a background thread, with XInput and
+//! Windows.Gaming.Input on Windows, IOKit on macOS, and evdev on Linux. The thread wakes the Slint
+//! event loop only when a gamepad reports something, and the events are delivered from the event
+//! loop.
+//!
+//! By default, the gamepads navigate the user interface of a window like a TV remote control:
+//!  - The D-pad sends the arrow keys to the focused item. If the focused item doesn't handle them,
+//!    the keyboard focus moves to the closest item in that direction, whatever the
+//!    `key-navigation` of the window, and the `Flickable`s containing it scroll to show it.
+//!  - The south button (A on Xbox controllers, cross on PlayStation controllers) sends `Key::Return`
+//!    to the focused item.
+//!  - The east button (B or circle) sends `Key::Escape` to the focused item.
+//!
+//! Games and other applications that need the raw input disable this with
+//! [`Gamepads::set_navigation_enabled()`], and use [`Gamepads::on_event()`],
+//! [`Gamepads::is_pressed()`], and [`Gamepads::axis()`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use slint::gamepad::{GamepadButton, GamepadEvent, Gamepads};
+//! slint::slint!{
+//!     export component App inherits Window {
+//!         in-out property <string> last-button;
+//!     }
+//! }
+//! let app = App::new().unwrap();
+//! let gamepads = Gamepads::new(&app).unwrap();
+//! let app_weak = app.as_weak();
+//! gamepads.on_event(move |event| {
+//!     if let GamepadEvent::ButtonPressed(_, button) = event {
+//!         app_weak.unwrap().set_last_button(format!("{button:?}").into());
+//!     }
+//! });
+//! app.run().unwrap();
+//! ```
+
+use crate::platform::{Key, WindowEvent};
+use crate::{ComponentHandle, PlatformError};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::rc::{Rc, Weak};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+/// How long the background thread waits for an event before checking whether it must stop.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Identifies one of the connected gamepads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GamepadId(usize);
+
+/// A button of a gamepad, with the names of the standard layout of the W3C gamepad specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GamepadButton {
+    /// The bottom button of the right cluster: A on Xbox controllers, cross on PlayStation controllers.
+    South,
+    /// The right button of the right cluster: B on Xbox controllers, circle on PlayStation controllers.
+    East,
+    /// The top button of the right cluster: Y on Xbox controllers, triangle on PlayStation controllers.
+    North,
+    /// The left button of the right cluster: X on Xbox controllers, square on PlayStation controllers.
+    West,
+    /// The left shoulder button.
+    LeftShoulder,
+    /// The left trigger. Its position is available as [`GamepadAxis::LeftTrigger`].
+    LeftTrigger,
+    /// The right shoulder button.
+    RightShoulder,
+    /// The right trigger. Its position is available as [`GamepadAxis::RightTrigger`].
+    RightTrigger,
+    /// The select, back, or view button.
+    Select,
+    /// The start, or menu button.
+    Start,
+    /// The button with the logo of the vendor.
+    Mode,
+    /// Pressing the left stick.
+    LeftStick,
+    /// Pressing the right stick.
+    RightStick,
+    /// Up on the directional pad.
+    DPadUp,
+    /// Down on the directional pad.
+    DPadDown,
+    /// Left on the directional pad.
+    DPadLeft,
+    /// Right on the directional pad.
+    DPadRight,
+}
+
+impl GamepadButton {
+    fn from_gilrs(button: gilrs::Button) -> Option<Self> {
+        use gilrs::Button as B;
+        Some(match button {
+            B::South => Self::South,
+            B::East => Self::East,
+            B::North => Self::North,
+            B::West => Self::West,
+            B::LeftTrigger => Self::LeftShoulder,
+            B::LeftTrigger2 => Self::LeftTrigger,
+            B::RightTrigger => Self::RightShoulder,
+            B::RightTrigger2 => Self::RightTrigger,
+            B::Select => Self::Select,
+            B::Start => Self::Start,
+            B::Mode => Self::Mode,
+            B::LeftThumb => Self::LeftStick,
+            B::RightThumb => Self::RightStick,
+            B::DPadUp => Self::DPadUp,
+            B::DPadDown => Self::DPadDown,
+            B::DPadLeft => Self::DPadLeft,
+            B::DPadRight => Self::DPadRight,
+            _ => return None,
+        })
+    }
+}
+
+/// An analog input of a gamepad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GamepadAxis {
+    /// The horizontal position of the left stick, between -1 (left) and 1 (right).
+    LeftStickX,
+    /// The vertical position of the left stick, between -1 (down) and 1 (up).
+    LeftStickY,
+    /// The horizontal position of the right stick, between -1 (left) and 1 (right).
+    RightStickX,
+    /// The vertical position of the right stick, between -1 (down) and 1 (up).
+    RightStickY,
+    /// How far the left trigger is pressed, between 0 and 1.
+    LeftTrigger,
+    /// How far the right trigger is pressed, between 0 and 1.
+    RightTrigger,
+}
+
+impl GamepadAxis {
+    fn from_gilrs(axis: gilrs::Axis) -> Option<Self> {
+        use gilrs::Axis as A;
+        Some(match axis {
+            A::LeftStickX => Self::LeftStickX,
+            A::LeftStickY => Self::LeftStickY,
+            A::RightStickX => Self::RightStickX,
+            A::RightStickY => Self::RightStickY,
+            _ => return None,
+        })
+    }
+}
+
+/// An event of a gamepad, passed to the callback set with [`Gamepads::on_event()`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum GamepadEvent {
+    /// A gamepad was connected.
+    Connected(GamepadId),
+    /// A gamepad was disconnected.
+    Disconnected(GamepadId),
+    /// A button was pressed.
+    ButtonPressed(GamepadId, GamepadButton),
+    /// A button was released.
+    ButtonReleased(GamepadId, GamepadButton),
+    /// A stick or a trigger moved to the given value.
+    AxisChanged(GamepadId, GamepadAxis, f32),
+}
+
+/// What the background thread sends to the event loop.
+enum Input {
+    /// A gamepad is connected. `notify` is false for the gamepads that were already connected
+    /// when the thread started, which aren't reported to the callback.
+    Connected {
+        id: GamepadId,
+        name: String,
+        notify: bool,
+    },
+    Event(GamepadEvent),
+}
+
+/// The state of a connected gamepad, as reported by the background thread so far.
+#[derive(Default)]
+struct GamepadState {
+    name: String,
+    pressed: HashSet<GamepadButton>,
+    axes: HashMap<GamepadAxis, f32>,
+}
+
+struct GamepadsInner {
+    receiver: mpsc::Receiver<Input>,
+    /// Set by the background thread when it wakes the event loop, and cleared when the
+    /// pending inputs are processed.
+    wake_pending: Arc<AtomicBool>,
+    stop: Arc<AtomicBool>,
+    states: RefCell<HashMap<GamepadId, GamepadState>>,
+    navigation_enabled: Cell<bool>,
+    handler: RefCell<Option<Rc<dyn Fn(GamepadEvent)>>>,
+    /// Sends the press or the release of a key to the window, to navigate it.
+    navigate_window: Box<dyn Fn(Key, bool)>,
+}
+
+impl GamepadsInner {
+    fn process_pending_inputs(&self) {
+        self.wake_pending.store(false, Ordering::Relaxed);
+        while let Ok(input) = self.receiver.try_recv() {
+            self.process_input(input);
+        }
+    }
+
+    fn process_input(&self, input: Input) {
+        let event = match input {
+            Input::Connected { id, name, notify } => {
+                self.states.borrow_mut().insert(id, GamepadState { name, ..Default::default() });
+                if !notify {
+                    return;
+                }
+                GamepadEvent::Connected(id)
+            }
+            Input::Event(event) => event,
+        };
+        match &event {
+            GamepadEvent::Connected(_) => {}
+            GamepadEvent::Disconnected(id) => {
+                self.states.borrow_mut().remove(id);
+            }
+            GamepadEvent::ButtonPressed(id, button) => {
+                if let Some(state) = self.states.borrow_mut().get_mut(id) {
+                    state.pressed.insert(*button);
+                }
+                self.navigate(*button, true);
+            }
+            GamepadEvent::ButtonReleased(id, button) => {
+                if let Some(state) = self.states.borrow_mut().get_mut(id) {
+                    state.pressed.remove(button);
+                }
+                self.navigate(*button, false);
+            }
+            GamepadEvent::AxisChanged(id, axis, value) => {
+                if let Some(state) = self.states.borrow_mut().get_mut(id) {
+                    state.axes.insert(*axis, *value);
+                }
+            }
+        }
+        let handler = self.handler.borrow().clone();
+        if let Some(handler) = handler {
+            handler(event);
+        }
+    }
+
+    /// Sends the keys that navigate the user interface for a button.
+    fn navigate(&self, button: GamepadButton, pressed: bool) {
+        if !self.navigation_enabled.get() {
+            return;
+        }
+        let key = match button {
+            GamepadButton::DPadUp => Key::UpArrow,
+            GamepadButton::DPadDown => Key::DownArrow,
+            GamepadButton::DPadLeft => Key::LeftArrow,
+            GamepadButton::DPadRight => Key::RightArrow,
+            GamepadButton::South => Key::Return,
+            GamepadButton::East => Key::Escape,
+            _ => return,
+        };
+        (self.navigate_window)(key, pressed);
+    }
+}
+
+thread_local! {
+    /// The live `Gamepads` of this thread, by the number that their background thread wakes them with.
+    static INSTANCES: RefCell<HashMap<usize, Weak<GamepadsInner>>> = Default::default();
+}
+
+/// Reads the gamepads until `stop` is set, sending their inputs to `sender`, and calling `wake`
+/// when there are inputs to process. Reports whether gilrs could be initialized to `init`.
+fn read_gamepads(
+    init: mpsc::Sender<Result<(), String>>,
+    sender: mpsc::Sender<Input>,
+    stop: Arc<AtomicBool>,
+    wake: impl Fn() -> bool,
+) {
+    let mut gilrs = match gilrs::Gilrs::new() {
+        Ok(gilrs) => {
+            init.send(Ok(())).ok();
+            gilrs
+        }
+        Err(error) => {
+            init.send(Err(error.to_string())).ok();
+            return;
+        }
+    };
+
+    let mut connected = false;
+    for (id, gamepad) in gilrs.gamepads() {
+        let id = GamepadId(id.into());
+        sender.send(Input::Connected { id, name: gamepad.name().into(), notify: false }).ok();
+        connected = true;
+    }
+    if connected && !wake() {
+        return;
+    }
+
+    while !stop.load(Ordering::Relaxed) {
+        let Some(gilrs::Event { id: gilrs_id, event, .. }) =
+            gilrs.next_event_blocking(Some(STOP_CHECK_INTERVAL))
+        else {
+            continue;
+        };
+        let id = GamepadId(gilrs_id.into());
+        let event = match event {
+            gilrs::EventType::Connected => {
+                let name = gilrs.gamepad(gilrs_id).name().into();
+                Input::Connected { id, name, notify: true }
+            }
+            gilrs::EventType::Disconnected => Input::Event(GamepadEvent::Disconnected(id)),
+            gilrs::EventType::ButtonPressed(button, _) => {
+                let Some(button) = GamepadButton::from_gilrs(button) else { continue };
+                Input::Event(GamepadEvent::ButtonPressed(id, button))
+            }
+            gilrs::EventType::ButtonReleased(button, _) => {
+                let Some(button) = GamepadButton::from_gilrs(button) else { continue };
+                Input::Event(GamepadEvent::ButtonReleased(id, button))
+            }
+            // The triggers are analog buttons for gilrs
+            gilrs::EventType::ButtonChanged(gilrs::Button::LeftTrigger2, value, _) => {
+                Input::Event(GamepadEvent::AxisChanged(id, GamepadAxis::LeftTrigger, value))
+            }
+            gilrs::EventType::ButtonChanged(gilrs::Button::RightTrigger2, value, _) => {
+                Input::Event(GamepadEvent::AxisChanged(id, GamepadAxis::RightTrigger, value))
+            }
+            gilrs::EventType::AxisChanged(axis, value, _) => {
+                let Some(axis) = GamepadAxis::from_gilrs(axis) else { continue };
+                Input::Event(GamepadEvent::AxisChanged(id, axis, value))
+            }
+            _ => continue,
+        };
+        if sender.send(event).is_err() || !wake() {
+            return;
+        }
+    }
+}
+
+/// The gamepads connected to the computer.
+///
+/// They are read by a background thread as long as this object is alive.
+pub struct Gamepads {
+    inner: Rc<GamepadsInner>,
+    instance: usize,
+}
+
+impl Gamepads {
+    /// Starts reading the gamepads, navigating the window of `component` with them.
+    pub fn new<C: ComponentHandle + 'static>(component: &C) -> Result<Self, PlatformError> {
+        static NEXT_INSTANCE: std::sync::atomic::AtomicUsize =
+            std::sync::atomic::AtomicUsize::new(0);
+        let instance = NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed);
+
+        let (init_sender, init_receiver) = mpsc::channel();
+        let (sender, receiver) = mpsc::channel();
+        let wake_pending = Arc::new(AtomicBool::new(false));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let thread_wake_pending = wake_pending.clone();
+        let wake = move || {
+            if thread_wake_pending.swap(true, Ordering::Relaxed) {
+                return true;
+            }
+            crate::invoke_from_event_loop(move || {
+                let inner = INSTANCES.with(|instances| instances.borrow().get(&instance).cloned());
+                if let Some(inner) = inner.and_then(|inner| inner.upgrade()) {
+                    inner.process_pending_inputs();
+                }
+            })
+            .is_ok()
+        };
+        let thread_stop = stop.clone();
+        std::thread::Builder::new()
+            .name("Slint gamepads".into())
+            .spawn(move || read_gamepads(init_sender, sender, thread_stop, wake))
+            .map_err(|error| PlatformError::Other(format!("Gamepad error: {error}")))?;
+        init_receiver
+            .recv()
+            .map_err(|_| PlatformError::Other("Gamepad error: the gamepad thread stopped".into()))?
+            .map_err(|error| PlatformError::Other(format!("Gamepad error: {error}")))?;
+
+        let component = component.as_weak();
+        let inner = Rc::new(GamepadsInner {
+            receiver,
+            wake_pending,
+            stop,
+            states: Default::default(),
+            navigation_enabled: Cell::new(true),
+            handler: Default::default(),
+            navigate_window: Box::new(move |key, pressed| {
+                let Some(component) = component.upgrade() else { return };
+                let window = component.window();
+                if matches!(key, Key::UpArrow | Key::DownArrow | Key::LeftArrow | Key::RightArrow) {
+                    i_slint_core::window::WindowInner::from_pub(window)
+                        .process_directional_key(key.into(), pressed);
+                } else if pressed {
+                    window.dispatch_event(WindowEvent::KeyPressed { text: key.into() });
+                } else {
+                    window.dispatch_event(WindowEvent::KeyReleased { text: key.into() });
+                }
+            }),
+        });
+        INSTANCES.with(|instances| instances.borrow_mut().insert(instance, Rc::downgrade(&inner)));
+        Ok(Self { inner, instance })
+    }
+
+    /// Enables or disables the navigation of the window with the D-pad and the south and east
+    /// buttons. It's enabled by default.
+    pub fn set_navigation_enabled(&self, enabled: bool) {
+        self.inner.navigation_enabled.set(enabled);
+    }
+
+    /// Sets the callback that is invoked when a gamepad is connected or disconnected, and when
+    /// its buttons or axes change.
+    ///
+    /// The callback is invoked from the Slint event loop. Calling this function again replaces
+    /// the previous callback.
+    pub fn on_event(&self, handler: impl Fn(GamepadEvent) + 'static) {
+        *self.inner.handler.borrow_mut() = Some(Rc::new(handler));
+    }
+
+    /// Returns the connected gamepads, with their names.
+    pub fn connected(&self) -> Vec<(GamepadId, String)> {
+        let mut connected: Vec<_> = self
+            .inner
+            .states
+            .borrow()
+            .iter()
+            .map(|(id, state)| (*id, state.name.clone()))
+            .collect();
+        connected.sort_by_key(|(id, _)| *id);
+        connected
+    }
+
+    /// Returns true if `button` of the gamepad is pressed.
+    pub fn is_pressed(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.inner.states.borrow().get(&id).is_some_and(|state| state.pressed.contains(&button))
+    }
+
+    /// Returns the current value of `axis` of the gamepad, or 0 if the gamepad isn't connected.
+    pub fn axis(&self, id: GamepadId, axis: GamepadAxis) -> f32 {
+        self.inner
+            .states
+            .borrow()
+            .get(&id)
+            .and_then(|state| state.axes.get(&axis).copied())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for Gamepads {
+    fn drop(&mut self) {
+        self.inner.stop.store(true, Ordering::Relaxed);
+        INSTANCES.with(|instances| instances.borrow_mut().remove(&self.instance));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gamepads(keys: Rc<RefCell<Vec<(Key, bool)>>>) -> (Gamepads, mpsc::Sender<Input>) {
+        let (sender, receiver) = mpsc::channel();
+        let inner = Rc::new(GamepadsInner {
+            receiver,
+            wake_pending: Default::default(),
+            stop: Default::default(),
+            states: Default::default(),
+            navigation_enabled: Cell::new(true),
+            handler: Default::default(),
+            navigate_window: Box::new(move |key, pressed| keys.borrow_mut().push((key, pressed))),
+        });
+        (Gamepads { inner, instance: usize::MAX }, sender)
+    }
+
+    #[test]
+    fn gamepad_state() {
+        let (gamepads, sender) = gamepads(Default::default());
+        let events = Rc::new(RefCell::new(Vec::new()));
+        gamepads.on_event({
+            let events = events.clone();
+            move |event| events.borrow_mut().push(event)
+        });
+        let (first, second) = (GamepadId(3), GamepadId(1));
+
+        // The gamepads connected at startup aren't reported
+        sender.send(Input::Connected { id: first, name: "First".into(), notify: false }).unwrap();
+        sender.send(Input::Connected { id: second, name: "Second".into(), notify: true }).unwrap();
+        sender
+            .send(Input::Event(GamepadEvent::ButtonPressed(first, GamepadButton::North)))
+            .unwrap();
+        sender
+            .send(Input::Event(GamepadEvent::AxisChanged(first, GamepadAxis::LeftStickX, 0.5)))
+            .unwrap();
+        gamepads.inner.process_pending_inputs();
+        assert_eq!(
+            *events.borrow(),
+            [
+                GamepadEvent::Connected(second),
+                GamepadEvent::ButtonPressed(first, GamepadButton::North),
+                GamepadEvent::AxisChanged(first, GamepadAxis::LeftStickX, 0.5),
+            ]
+        );
+        assert_eq!(gamepads.connected(), [(second, "Second".into()), (first, "First".into())]);
+        assert!(gamepads.is_pressed(first, GamepadButton::North));
+        assert!(!gamepads.is_pressed(second, GamepadButton::North));
+        assert_eq!(gamepads.axis(first, GamepadAxis::LeftStickX), 0.5);
+        assert_eq!(gamepads.axis(first, GamepadAxis::LeftStickY), 0.);
+
+        sender
+            .send(Input::Event(GamepadEvent::ButtonReleased(first, GamepadButton::North)))
+            .unwrap();
+        sender.send(Input::Event(GamepadEvent::Disconnected(second))).unwrap();
+        gamepads.inner.process_pending_inputs();
+        assert!(!gamepads.is_pressed(first, GamepadButton::North));
+        assert_eq!(gamepads.connected(), [(first, "First".into())]);
+    }
+
+    #[test]
+    fn gamepad_navigation() {
+        let keys = Rc::new(RefCell::new(Vec::new()));
+        let (gamepads, sender) = gamepads(keys.clone());
+        let id = GamepadId(0);
+        sender.send(Input::Connected { id, name: "Gamepad".into(), notify: true }).unwrap();
+        for button in [GamepadButton::DPadLeft, GamepadButton::South, GamepadButton::North] {
+            sender.send(Input::Event(GamepadEvent::ButtonPressed(id, button))).unwrap();
+            sender.send(Input::Event(GamepadEvent::ButtonReleased(id, button))).unwrap();
+        }
+        gamepads.inner.process_pending_inputs();
+        assert_eq!(
+            *keys.borrow(),
+            [
+                (Key::LeftArrow, true),
+                (Key::LeftArrow, false),
+                (Key::Return, true),
+                (Key::Return, false)
+            ]
+        );
+
+        keys.borrow_mut().clear();
+        gamepads.set_navigation_enabled(false);
+        sender.send(Input::Event(GamepadEvent::ButtonPressed(id, GamepadButton::DPadUp))).unwrap();
+        gamepads.inner.process_pending_inputs();
+        assert!(keys.borrow().is_empty());
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Zero-copy rendering of [GStreamer](https://gstreamer.freedesktop.org) video frames.
+//!
+//! **Note:** This module is only available with the "unstable-gstreamer-021" feature
+//!
+//! **Warning**: The functions of this module take and return types of
+//! [gstreamer-rs](https://gitlab.freedesktop.org/gstreamer/gstreamer-rs) 0.21. They are not covered
+//! by the semver guarantees of Slint: the module changes, together with the name of the feature,
+//! when Slint updates to a newer gstreamer-rs. Use the same version of gstreamer-rs in your
+//! application, and pin the Slint version.
+//!
+//! GStreamer uploads the decoded frames (from system memory, or imported from dmabufs by
+//! `glupload`) into OpenGL textures that live in a context shared with the OpenGL context of
+//! Slint's renderer. The textures are then shown as borrowed OpenGL textures, so the frames never
+//! make a round-trip through the CPU.
+//!
+//! This requires a renderer using OpenGL through EGL, such as the FemtoVG renderer or the Skia
+//! renderer with OpenGL. With other renderers, [`VideoTextureImporter::new()`] returns an error.
+//! Importing the frames into Vulkan images (through external memory) is not supported: with the
+//! Skia Vulkan renderer, copy the frames through the CPU into an [`Image`] instead.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use gst::prelude::*;
+//! use slint::gstreamer::{create_video_sink, VideoTextureImporter};
+//! slint::slint!{
+//!     export component Player inherits Window {
+//!         in property <image> video-frame;
+//!         Image { source: video-frame; }
+//!     }
+//! }
+//! let player = Player::new().unwrap();
+//! gst::init().unwrap();
+//! let pipeline = gst::Pipeline::new();
+//! let source = gst::ElementFactory::make("videotestsrc").build().unwrap();
+//! let (video_sink, appsink) = create_video_sink().unwrap();
+//! pipeline.add_many([&source, &video_sink]).unwrap();
+//! source.link(&video_sink).unwrap();
+//!
+//! let player_weak = player.as_weak();
+//! let mut importer = None;
+//! player.window().set_rendering_notifier(move |state, graphics_api| match state {
+//!     slint::RenderingState::RenderingSetup => {
+//!         match VideoTextureImporter::new(graphics_api, &pipeline) {
+//!             Ok(i) => importer = Some(i),
+//!             Err(err) => eprintln!("Cannot show the video: {err}"),
+//!         }
+//!         pipeline.set_state(gst::State::Playing).unwrap();
+//!     }
+//!     slint::RenderingState::BeforeRendering => {
+//!         let (Some(importer), Some(player)) = (importer.as_mut(), player_weak.upgrade()) else {
+//!             return;
+//!         };
+//!         if let Some(sample) = appsink.try_pull_sample(gst::ClockTime::ZERO) {
+//!             match importer.import_sample(&sample) {
+//!                 Ok(frame) => player.set_video_frame(frame),
+//!                 Err(err) => eprintln!("Cannot show the video frame: {err}"),
+//!             }
+//!         }
+//!     }
+//!     slint::RenderingState::RenderingTeardown => importer = None,
+//!     _ => {}
+//! }).unwrap();
+//! player.run().unwrap();
+//! ```
+
+use crate::{GraphicsAPI, Image, PlatformError};
+use gst::prelude::*;
+use gst_gl::prelude::*;
+
+/// Creates the sink for a video pipeline: an `appsink` negotiating `memory:GLMemory` wrapped in
+/// a `glsinkbin`, which takes care of uploading and converting the frames to RGBA textures.
+///
+/// Returns the element to add to the pipeline, and the `appsink` from which the samples for
+/// [`VideoTextureImporter::import_sample()`] are pulled.
+pub fn create_video_sink() -> Result<(gst::Element, gst_app::AppSink), PlatformError> {
+    let appsink = gst_app::AppSink::builder()
+        .enable_last_sample(false)
+        .max_buffers(1u32)
+        .caps(
+            &gst_video::VideoCapsBuilder::new()
+                .features([gst_gl::CAPS_FEATURE_MEMORY_GL_MEMORY])
+                .format(gst_video::VideoFormat::Rgba)
+                .field("texture-target", "2D")
+                .build(),
+        )
+        .build();
+
+    let glsinkbin = gst::ElementFactory::make("glsinkbin")
+        .property("sink", &appsink)
+        .build()
+        .map_err(|_| {
+            PlatformError::Other(
+                "Could not create the glsinkbin element. Is the GStreamer GL plugin installed?"
+                    .into(),
+            )
+        })?;
+
+    Ok((glsinkbin, appsink))
+}
+
+/// Imports the OpenGL textures of the frames decoded by a GStreamer pipeline as [`Image`]s.
+///
+/// Create it in the [`RenderingState::RenderingSetup`](crate::RenderingState::RenderingSetup)
+/// phase of a rendering notifier, before the pipeline starts, so that GStreamer creates its
+/// OpenGL context sharing textures with the one of the renderer. Drop it in the
+/// [`RenderingState::RenderingTeardown`](crate::RenderingState::RenderingTeardown) phase: this
+/// stops the pipeline, whose elements can't use the OpenGL context of the renderer anymore.
+pub struct VideoTextureImporter {
+    pipeline: gst::Pipeline,
+    context: gst_gl::GLContext,
+    // The frame currently displayed, kept mapped so that its texture stays valid while Slint renders it.
+    current_frame: Option<gst_video::VideoFrame<gst_video::video_frame::Readable>>,
+}
+
+impl VideoTextureImporter {
+    /// Shares the OpenGL context of the renderer with the elements of the pipeline.
+    ///
+    /// Returns an error if the renderer doesn't use OpenGL through EGL.
+    pub fn new(
+        graphics_api: &GraphicsAPI<'_>,
+        pipeline: &gst::Pipeline,
+    ) -> Result<Self, PlatformError> {
+        let GraphicsAPI::NativeOpenGL { get_proc_address } = graphics_api else {
+            return Err(PlatformError::Other(
+                "Importing video frames as textures requires a renderer using OpenGL".into(),
+            ));
+        };
+        let (context, display) = wrap_current_egl_context(get_proc_address)?;
+
+        let bus = pipeline
+            .bus()
+            .ok_or_else(|| PlatformError::Other("The GStreamer pipeline has no bus".into()))?;
+        let sync_context = context.clone();
+        bus.set_sync_handler(move |_, msg| {
+            if let gst::MessageView::NeedContext(need_context) = msg.view() {
+                let context_type = need_context.context_type();
+                let element = msg.src().and_then(|s| s.downcast_ref::<gst::Element>());
+                if let Some(element) = element {
+                    if context_type == *gst_gl::GL_DISPLAY_CONTEXT_TYPE {
+                        let gst_context = gst::Context::new(context_type, true);
+                        gst_context.set_gl_display(Some(&display));
+                        element.set_context(&gst_context);
+                    } else if context_type == "gst.gl.app_context" {
+                        let mut gst_context = gst::Context::new(context_type, true);
+                        gst_context
+                            .get_mut()
+                            .unwrap()
+                            .structure_mut()
+                            .set("context", &sync_context);
+                        element.set_context(&gst_context);
+                    }
+                }
+            }
+            gst::BusSyncReply::Pass
+        });
+
+        Ok(Self { pipeline: pipeline.clone(), context, current_frame: None })
+    }
+
+    /// Returns an image showing the texture of the frame of `sample`, which must come from the
+    /// `appsink` returned by [`create_video_sink()`].
+    ///
+    /// The texture of the returned image is only valid until the next call to this function, or
+    /// until the importer is dropped, so call it in the
+    /// [`RenderingState::BeforeRendering`](crate::RenderingState::BeforeRendering) phase and
+    /// replace the previous frame with the new image right away.
+    pub fn import_sample(&mut self, sample: &gst::Sample) -> Result<Image, PlatformError> {
+        let invalid = |what: &str| PlatformError::Other(format!("Invalid video sample: {what}"));
+        let buffer = sample.buffer_owned().ok_or_else(|| invalid("no buffer"))?;
+        let info = sample
+            .caps()
+            .and_then(|caps| gst_video::VideoInfo::from_caps(caps).ok())
+            .ok_or_else(|| invalid("no video caps"))?;
+
+        if let Some(sync_meta) = buffer.meta::<gst_gl::GLSyncMeta>() {
+            sync_meta.wait(&self.context);
+        }
+
+        let frame = gst_video::VideoFrame::from_buffer_readable_gl(buffer, &info)
+            .map_err(|_| invalid("the buffer is not in GL memory"))?;
+        let texture_id = frame
+            .texture_id(0)
+            .and_then(core::num::NonZeroU32::new)
+            .ok_or_else(|| invalid("no texture"))?;
+        // Safety: the frame is kept mapped, and its texture alive, until the next call
+        let image = unsafe {
+            crate::BorrowedOpenGLTextureBuilder::new_gl_2d_rgba_texture(
+                texture_id,
+                [frame.width(), frame.height()].into(),
+            )
+            .build()
+        };
+        self.current_frame = Some(frame);
+        Ok(image)
+    }
+}
+
+impl Drop for VideoTextureImporter {
+    fn drop(&mut self) {
+        self.current_frame = None;
+        self.pipeline.set_state(gst::State::Null).ok();
+        if let Some(bus) = self.pipeline.bus() {
+            bus.unset_sync_handler();
+        }
+    }
+}
+
+/// Wraps the EGL context that Slint made current for rendering into a GStreamer GL context, so that
+/// GStreamer creates its own context sharing textures with it.
+fn wrap_current_egl_context(
+    get_proc_address: &dyn Fn(&core::ffi::CStr) -> *const core::ffi::c_void,
+) -> Result<(gst_gl::GLContext, gst_gl_egl::GLDisplayEGL), PlatformError> {
+    type EglGetCurrentDisplay = unsafe extern "C" fn() -> *mut core::ffi::c_void;
+
+    let platform = gst_gl::GLPlatform::EGL;
+    let not_egl = || PlatformError::Other("The OpenGL context of the renderer is not EGL".into());
+
+    let egl_get_current_display = get_proc_address(c"eglGetCurrentDisplay");
+    if egl_get_current_display.is_null() {
+        return Err(not_egl());
+    }
+    let egl_display = unsafe {
+        core::mem::transmute::<*const core::ffi::c_void, EglGetCurrentDisplay>(
+            egl_get_current_display,
+        )()
+    };
+    if egl_display.is_null() {
+        return Err(not_egl());
+    }
+
+    let display = unsafe { gst_gl_egl::GLDisplayEGL::with_egl_display(egl_display as usize) }
+        .map_err(|err| PlatformError::Other(format!("Unable to wrap the EGL display: {err}")))?;
+    let native_context = gst_gl::GLContext::current_gl_context(platform);
+    let (gl_api, _, _) = gst_gl::GLContext::current_gl_api(platform);
+    let context =
+        unsafe { gst_gl::GLContext::new_wrapped(&display, native_context, platform, gl_api) }
+            .ok_or_else(|| PlatformError::Other("Unable to wrap the current EGL context".into()))?;
+
+    context
+        .activate(true)
+        .map_err(|err| PlatformError::Other(format!("Unable to use the EGL context: {err}")))?;
+    context
+        .fill_info()
+        .map_err(|err| PlatformError::Other(format!("Unable to use the EGL context: {err}")))?;
+
+    Ok((context, display))
+}
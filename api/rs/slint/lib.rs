@@ -143,10 +143,16 @@ struct MyComponent { /*...*/ }
 For each top-level property
   - A setter [`fn set_<property_name>(&self, value: <PropertyType>)`](docs::generated_code::SampleComponent::set_counter)
   - A getter [`fn get_<property_name>(&self) -> <PropertyType>`](docs::generated_code::SampleComponent::get_counter)
+  - [`fn on_<property_name>_changed(&self, callback: impl FnMut(<PropertyType>) + 'static)`](docs::generated_code::SampleComponent::on_counter_changed):
+    to set a handler called when the value of the property changes.
+  - [`fn animate_<property_name>(&self, value: <PropertyType>, animation: Animation) -> AnimationHandle`](docs::generated_code::SampleComponent::animate_counter):
+    to animate the property to a new value, for properties whose type can be animated.
 
 For each top-level callback
   - [`fn invoke_<callback_name>(&self)`](docs::generated_code::SampleComponent::invoke_hello): to invoke the callback
   - [`fn on_<callback_name>(&self, callback: impl Fn(<CallbackArgs>) + 'static)`](docs::generated_code::SampleComponent::on_hello): to set the callback handler.
+  - [`fn on_<callback_name>_async(&self, callback: impl FnMut(<CallbackArgs>) -> impl Future<Output = ()> + 'static)`](docs::generated_code::SampleComponent::on_hello_async):
+    to set a handler whose future is spawned on the event loop, for callbacks without return value.
 
 Note: All dashes (`-`) are replaced by underscores (`_`) in names of types or functions.
 
@@ -181,10 +187,16 @@ struct MyComponent { /*...*/ }
 For each property
   - A setter: `fn set_<property_name>(&self, value: <PropertyType>)`
   - A getter: `fn get_<property_name>(&self) -> <PropertyType>`
+  - `fn on_<property_name>_changed(&self, callback: impl FnMut(<PropertyType>) + 'static)` to set a handler
+    called when the value of the property changes.
+  - `fn animate_<property_name>(&self, value: <PropertyType>, animation: Animation) -> AnimationHandle` to animate
+    the property to a new value, for properties whose type can be animated.
 
 For each callback
   - `fn invoke_<callback_name>(&self, <CallbackArgs>) -> <ReturnValue>` to invoke the callback
   - `fn on_<callback_name>(&self, callback: impl Fn(<CallbackArgs>) + 'static)` to set the callback handler.
+  - `fn on_<callback_name>_async(&self, callback: impl FnMut(<CallbackArgs>) -> impl Future<Output = ()> + 'static)`
+    to set a handler whose future is spawned on the event loop, for callbacks without return value.
 
 The global can be accessed with the [`ComponentHandle::global()`] function, or with [`Global::get()`]
 
@@ -210,6 +222,7 @@ struct MyComponent { /*...*/ }
 
 pub use slint_macros::slint;
 
+pub use i_slint_core::animations::{Animation, AnimationHandle, EasingCurve};
 pub use i_slint_core::api::*;
 #[doc(hidden)]
 #[deprecated(note = "Experimental type was made public by mistake")]
@@ -218,15 +231,32 @@ struct MyComponent { /*...*/ }
 pub use i_slint_core::graphics::{BorrowedOpenGLTextureBuilder, BorrowedOpenGLTextureOrigin};
 // keep in sync with internal/interpreter/api.rs
 pub use i_slint_core::graphics::{
-    Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
+    Brush, Color, ConicGradientBrush, GradientStop, Image, ImagePatternBrush, LoadImageError,
+    Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer, VisionSimulation,
 };
+pub use i_slint_core::items::{ImageLoadingStatus, MenuEntry, SortOrder};
+#[cfg(target_has_atomic = "ptr")]
+pub use i_slint_core::model::PagedModel;
 pub use i_slint_core::model::{
     FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
-    ReverseModel, SortModel, StandardListViewItem, TableColumn, VecModel,
+    ReverseModel, SortModel, StandardListViewItem, TableColumn, TextLinesModel, VecModel,
 };
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub use i_slint_core::settings::FileSettingsStorage;
+#[cfg(all(feature = "settings-registry", target_family = "windows"))]
+pub use i_slint_core::settings::RegistrySettingsStorage;
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
+pub use i_slint_core::settings::WebSettingsStorage;
+pub use i_slint_core::settings::{Settings, SettingsMap, SettingsStorage};
 pub use i_slint_core::sharedvector::SharedVector;
-pub use i_slint_core::timers::{Timer, TimerMode};
-pub use i_slint_core::translations::{select_bundled_translation, SelectBundledTranslationError};
+pub use i_slint_core::timers::{
+    invoke_when_idle, IdleCallbackHandle, IdleDeadline, Timer, TimerMode,
+};
+pub use i_slint_core::translations::{
+    select_bundled_translation, set_pseudo_localization, PseudoLocalization,
+    SelectBundledTranslationError,
+};
+pub use i_slint_core::undo::UndoStack;
 pub use i_slint_core::{
     format,
     string::{SharedString, ToSharedString},
@@ -243,7 +273,9 @@ struct MyComponent { /*...*/ }
 /// See also [`run_event_loop_until_quit()`] to keep the event loop running until
 /// [`quit_event_loop()`] is called, even if all windows are closed.
 pub fn run_event_loop() -> Result<(), PlatformError> {
-    i_slint_backend_selector::with_platform(|b| b.run_event_loop())
+    let result = i_slint_backend_selector::with_platform(|b| b.run_event_loop());
+    i_slint_core::settings::store_window_geometries();
+    result
 }
 
 /// Similar to [`run_event_loop()`], but this function enters the main event loop
@@ -253,11 +285,13 @@ pub fn run_event_loop() -> Result<(), PlatformError> {
 /// This is useful for system tray applications where the application needs to stay alive
 /// even if no windows are visible.
 pub fn run_event_loop_until_quit() -> Result<(), PlatformError> {
-    i_slint_backend_selector::with_platform(|b| {
+    let result = i_slint_backend_selector::with_platform(|b| {
         #[allow(deprecated)]
         b.set_event_loop_quit_on_last_window_closed(false);
         b.run_event_loop()
-    })
+    });
+    i_slint_core::settings::store_window_geometries();
+    result
 }
 
 /// Spawns a [`Future`](core::future::Future) to execute in the Slint event loop.
@@ -339,6 +373,10 @@ pub fn run_event_loop_until_quit() -> Result<(), PlatformError> {
 /// // Wrap the call to run_event_loop to ensure presence of a Tokio run-time.
 /// tokio::task::block_in_place(slint::run_event_loop).unwrap();
 /// ```
+///
+/// With the `tokio` feature, the functions of the `slint::tokio` module take care of this: `slint::tokio::run_event_loop()`
+/// runs the event loop in the context of a multi-threaded Tokio runtime, so that Tokio futures work without `Compat`,
+/// and `slint::tokio::run_in_event_loop()` lets Tokio tasks await code that runs in the Slint event loop.
 #[cfg(target_has_atomic = "ptr")]
 pub fn spawn_local<F: core::future::Future + 'static>(
     fut: F,
@@ -417,6 +455,51 @@ pub mod femtovg_renderer {
         pub use i_slint_renderer_femtovg::FemtoVGRenderer;
         pub use i_slint_renderer_femtovg::OpenGLInterface;
     }
+
+    /// This module contains the traits and types needed to implement a custom renderer.
+    ///
+    /// It is only enabled when the `unstable-renderer-api` Slint feature is enabled.
+    ///
+    /// **Warning**: This API is not covered by the semver guarantees of Slint. It follows
+    /// the internals of the built-in renderers and may change in minor releases.
+    ///
+    /// A renderer implements [`RendererSealed`] and is returned from
+    /// [`WindowAdapter::renderer()`](super::WindowAdapter::renderer). To draw a frame, it
+    /// implements [`ItemRenderer`] for its drawing surface and passes it to
+    /// [`render_component_items()`] with the item tree of the window, which calls the `draw_*`
+    /// functions for every visible item:
+    ///
+    /// ```rust,ignore
+    /// let window_inner = WindowInner::from_pub(window);
+    /// if let Some(component) = window_inner.try_component() {
+    ///     let mut item_renderer = MyItemRenderer::new(&mut surface, window_inner);
+    ///     render_component_items(&component, &mut item_renderer, LogicalPoint::default());
+    /// }
+    /// ```
+    ///
+    /// [`ItemCache`] and [`CachedRenderingData`] let the renderer keep per-item data, such as
+    /// decoded images or shaped text, that is invalidated when the properties of the item change.
+    /// Renderers that only redraw the changed parts of the window can use
+    /// [`PartialRenderingState`] to compute the [`DirtyRegion`] of each frame.
+    #[cfg(feature = "unstable-renderer-api")]
+    pub mod renderer {
+        pub use i_slint_core::graphics::FontRequest;
+        pub use i_slint_core::item_rendering::{
+            render_component_items, render_item_children, CachedRenderingData, DirtyRegion,
+            ItemCache, ItemRenderer, PartialRenderer, PartialRenderingState, RenderBorderRectangle,
+            RenderImage, RenderText, RepaintBufferType,
+        };
+        pub use i_slint_core::item_tree::{ItemRc, ItemTreeRc};
+        pub use i_slint_core::items::{
+            BoxShadow, Clip, Layer, Opacity, Path, Rectangle, RenderingResult, TextInput,
+        };
+        pub use i_slint_core::lengths::{
+            LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize,
+            LogicalVector, ScaleFactor,
+        };
+        pub use i_slint_core::renderer::RendererSealed;
+        pub use i_slint_core::window::WindowInner;
+    }
 }
 
 #[cfg(any(
@@ -428,6 +511,18 @@ pub mod femtovg_renderer {
 ))]
 pub mod android;
 
+#[cfg(feature = "media-controls")]
+pub mod media_controls;
+
+#[cfg(feature = "gamepad")]
+pub mod gamepad;
+
+#[cfg(feature = "tokio")]
+pub mod tokio;
+
+#[cfg(feature = "unstable-gstreamer-021")]
+pub mod gstreamer;
+
 pub use i_slint_backend_selector::api::*;
 
 /// Helper type that helps checking that the generated code is generated for the right version
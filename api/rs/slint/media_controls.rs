@@ -0,0 +1,281 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Integration with the media controls of the operating system.
+//!
+//! **Note:** This module is only available with the "media-controls" feature
+//!
+//! Media applications can use [`MediaControls`] to show what is currently playing in the
+//! media overlay of the operating system, and to react to the play, pause, next, or previous
+//! commands issued from there, from the lock screen, or from the media keys of a headset.
+//!
+//! The platform integration is:
+//!  - MPRIS over D-Bus on Linux and other Unix systems,
+//!  - the System Media Transport Controls (SMTC) on Windows,
+//!  - `MPNowPlayingInfoCenter` and `MPRemoteCommandCenter` on macOS.
+//!
+//! Events are delivered in the Slint event loop, so there is no need to run a separate
+//! event loop or to synchronize with another thread.
+//!
+//! Media keys pressed while a Slint window has the focus are also delivered as ordinary key
+//! events, with the key codes from [`Key`](crate::platform::Key) such as `Key::MediaPlayPause`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use slint::media_controls::{MediaControlEvent, MediaControls, MediaMetadata, MediaPlayback};
+//! slint::slint!{
+//!     export component Player inherits Window {
+//!         in-out property <bool> playing;
+//!     }
+//! }
+//! let player = Player::new().unwrap();
+//! // On Windows, the controls need the native window, so it must be shown first
+//! player.show().unwrap();
+//! let player_weak = player.as_weak();
+//! let mut controls = MediaControls::new(player.window(), "my_player", "My Player").unwrap();
+//! controls
+//!     .on_event(move |event| {
+//!         let player = player_weak.unwrap();
+//!         match event {
+//!             MediaControlEvent::Play => player.set_playing(true),
+//!             MediaControlEvent::Pause | MediaControlEvent::Stop => player.set_playing(false),
+//!             MediaControlEvent::Toggle => player.set_playing(!player.get_playing()),
+//!             _ => {}
+//!         }
+//!     })
+//!     .unwrap();
+//! controls
+//!     .set_metadata(MediaMetadata { title: Some("Some Song"), artist: Some("Someone"), ..Default::default() })
+//!     .unwrap();
+//! controls.set_playback(MediaPlayback::Playing { progress: None }).unwrap();
+//! player.run().unwrap();
+//! ```
+
+use crate::{PlatformError, Window};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// A command received from the media controls of the operating system.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum MediaControlEvent {
+    /// Start or resume playback.
+    Play,
+    /// Pause the playback.
+    Pause,
+    /// Toggle between playing and paused.
+    Toggle,
+    /// Skip to the next track.
+    Next,
+    /// Go back to the previous track.
+    Previous,
+    /// Stop the playback.
+    Stop,
+    /// Seek forward (when `true`) or backward (when `false`) by an application defined amount.
+    Seek {
+        /// Whether to seek forward or backward.
+        forward: bool,
+    },
+    /// Seek forward (when `forward` is `true`) or backward by the given amount.
+    SeekBy {
+        /// Whether to seek forward or backward.
+        forward: bool,
+        /// The amount of time to seek by.
+        amount: Duration,
+    },
+    /// Move the playback to the given position from the start of the track.
+    SetPosition(Duration),
+    /// Set the volume, between 0 and 1.
+    SetVolume(f64),
+    /// Open the given URI.
+    OpenUri(String),
+    /// Bring the application window to the front.
+    Raise,
+    /// Quit the application.
+    Quit,
+}
+
+impl MediaControlEvent {
+    fn from_souvlaki(event: souvlaki::MediaControlEvent) -> Self {
+        use souvlaki::{MediaControlEvent as E, SeekDirection};
+        match event {
+            E::Play => Self::Play,
+            E::Pause => Self::Pause,
+            E::Toggle => Self::Toggle,
+            E::Next => Self::Next,
+            E::Previous => Self::Previous,
+            E::Stop => Self::Stop,
+            E::Seek(direction) => {
+                Self::Seek { forward: matches!(direction, SeekDirection::Forward) }
+            }
+            E::SeekBy(direction, amount) => {
+                Self::SeekBy { forward: matches!(direction, SeekDirection::Forward), amount }
+            }
+            E::SetPosition(position) => Self::SetPosition(position.0),
+            E::SetVolume(volume) => Self::SetVolume(volume),
+            E::OpenUri(uri) => Self::OpenUri(uri),
+            E::Raise => Self::Raise,
+            E::Quit => Self::Quit,
+        }
+    }
+}
+
+/// The information about the media that is currently playing, as shown by the operating system.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MediaMetadata<'a> {
+    /// The title of the track.
+    pub title: Option<&'a str>,
+    /// The album the track is part of.
+    pub album: Option<&'a str>,
+    /// The artist of the track.
+    pub artist: Option<&'a str>,
+    /// The URL of the cover art. Use a `file://` URL for local images.
+    pub cover_url: Option<&'a str>,
+    /// The total duration of the track.
+    pub duration: Option<Duration>,
+}
+
+/// The playback state reported to the operating system.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MediaPlayback {
+    /// Nothing is playing.
+    Stopped,
+    /// The playback is paused at the given position.
+    Paused {
+        /// The position from the start of the track, if known.
+        progress: Option<Duration>,
+    },
+    /// The media is playing, and is at the given position.
+    Playing {
+        /// The position from the start of the track, if known.
+        progress: Option<Duration>,
+    },
+}
+
+thread_local! {
+    static EVENT_HANDLERS: RefCell<HashMap<usize, Rc<dyn Fn(MediaControlEvent)>>> = Default::default();
+}
+
+static NEXT_HANDLER_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+fn to_platform_error(error: souvlaki::Error) -> PlatformError {
+    PlatformError::Other(format!("Media controls error: {error:?}"))
+}
+
+/// A connection to the media controls of the operating system.
+///
+/// The controls are removed from the operating system when this object is dropped.
+pub struct MediaControls {
+    controls: souvlaki::MediaControls,
+    handler_id: usize,
+}
+
+impl MediaControls {
+    /// Registers the application with the media controls of the operating system.
+    ///
+    /// `id` identifies the application. On Linux, it's used as the last part of the MPRIS D-Bus
+    /// name `org.mpris.MediaPlayer2.<id>`, and must therefore only contain ASCII letters, digits,
+    /// and underscores. `display_name` is the name shown to the user.
+    ///
+    /// On Windows, the controls are associated with the native handle of `window`, so the window
+    /// must be shown before calling this function.
+    pub fn new(window: &Window, id: &str, display_name: &str) -> Result<Self, PlatformError> {
+        #[cfg(target_os = "windows")]
+        let hwnd = {
+            use raw_window_handle_06::{HasWindowHandle, RawWindowHandle};
+            match window.window_handle().window_handle().map(|handle| handle.as_raw()) {
+                Ok(RawWindowHandle::Win32(handle)) => {
+                    Some(handle.hwnd.get() as *mut core::ffi::c_void)
+                }
+                _ => {
+                    return Err(PlatformError::Other(
+                        "Media controls require a native window handle".into(),
+                    ))
+                }
+            }
+        };
+        #[cfg(not(target_os = "windows"))]
+        let hwnd = {
+            let _ = window;
+            None
+        };
+
+        let controls = souvlaki::MediaControls::new(souvlaki::PlatformConfig {
+            dbus_name: id,
+            display_name,
+            hwnd,
+        })
+        .map_err(to_platform_error)?;
+        let handler_id = NEXT_HANDLER_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(Self { controls, handler_id })
+    }
+
+    /// Sets the callback that is invoked when the operating system sends a command, for example
+    /// when the user presses the play button in the media overlay.
+    ///
+    /// The callback is invoked from the Slint event loop. Calling this function again replaces
+    /// the previous callback.
+    pub fn on_event(
+        &mut self,
+        handler: impl Fn(MediaControlEvent) + 'static,
+    ) -> Result<(), PlatformError> {
+        let handler_id = self.handler_id;
+        EVENT_HANDLERS.with(|handlers| handlers.borrow_mut().insert(handler_id, Rc::new(handler)));
+        self.controls.detach().map_err(to_platform_error)?;
+        self.controls
+            .attach(move |event| {
+                let event = MediaControlEvent::from_souvlaki(event);
+                // The event may come from another thread, so it is forwarded to the event loop
+                // where the handler lives. Errors mean that the event loop has quit.
+                crate::invoke_from_event_loop(move || {
+                    // Clone the handler so that it can replace itself or drop the controls
+                    let handler =
+                        EVENT_HANDLERS.with(|handlers| handlers.borrow().get(&handler_id).cloned());
+                    if let Some(handler) = handler {
+                        handler(event);
+                    }
+                })
+                .ok();
+            })
+            .map_err(to_platform_error)
+    }
+
+    /// Sets the information about the media that is currently playing.
+    pub fn set_metadata(&mut self, metadata: MediaMetadata) -> Result<(), PlatformError> {
+        self.controls
+            .set_metadata(souvlaki::MediaMetadata {
+                title: metadata.title,
+                album: metadata.album,
+                artist: metadata.artist,
+                cover_url: metadata.cover_url,
+                duration: metadata.duration,
+            })
+            .map_err(to_platform_error)
+    }
+
+    /// Sets the playback state, which the operating system uses to show a play or pause button
+    /// and the progress of the track.
+    pub fn set_playback(&mut self, playback: MediaPlayback) -> Result<(), PlatformError> {
+        self.controls
+            .set_playback(match playback {
+                MediaPlayback::Stopped => souvlaki::MediaPlayback::Stopped,
+                MediaPlayback::Paused { progress } => souvlaki::MediaPlayback::Paused {
+                    progress: progress.map(souvlaki::MediaPosition),
+                },
+                MediaPlayback::Playing { progress } => souvlaki::MediaPlayback::Playing {
+                    progress: progress.map(souvlaki::MediaPosition),
+                },
+            })
+            .map_err(to_platform_error)
+    }
+}
+
+impl Drop for MediaControls {
+    fn drop(&mut self) {
+        self.controls.detach().ok();
+        EVENT_HANDLERS.try_with(|handlers| handlers.borrow_mut().remove(&self.handler_id)).ok();
+    }
+}
@@ -127,6 +127,58 @@ pub fn set_callback_handler<
     })
 }
 
+/// Runs the future returned by a handler set with `on_<callback_name>_async` on the event loop.
+#[cfg(target_has_atomic = "ptr")]
+pub fn spawn_callback_future(fut: impl core::future::Future<Output = ()> + 'static) {
+    if let Err(err) = crate::spawn_local(fut) {
+        i_slint_core::debug_log!("Slint: could not run the async callback handler: {err}");
+    }
+}
+
+/// The handlers set with `on_<property_name>_changed` on a component or on one of its globals.
+#[derive(Default)]
+pub struct ChangeHandlers(
+    core::cell::RefCell<Vec<(&'static str, ChangeTracker, Box<dyn core::any::Any>)>>,
+);
+
+impl ChangeHandlers {
+    /// Sets the handler called when the value returned by `eval_fn` changes, replacing the previous
+    /// handler with the same `name`. `eval_fn` returns `None` once the component is gone.
+    pub fn set_handler<Data: 'static, T: PartialEq + 'static>(
+        &self,
+        name: &'static str,
+        data: Data,
+        eval_fn: impl Fn(&Data) -> Option<T> + 'static,
+        handler: impl FnMut(&T) + 'static,
+    ) {
+        type Slot<T> = Rc<core::cell::Cell<Option<Box<dyn FnMut(&T)>>>>;
+
+        // Replace the handler rather than the tracker, which may be running the old handler right now.
+        if let Some(slot) = self.0.borrow().iter().find(|(n, ..)| *n == name) {
+            if let Some(slot) = slot.2.downcast_ref::<Slot<T>>() {
+                slot.set(Some(Box::new(handler)));
+                return;
+            }
+        }
+
+        let slot: Slot<T> = Rc::new(core::cell::Cell::new(Some(Box::new(handler))));
+        let tracker = ChangeTracker::default();
+        tracker.init(
+            (data, slot.clone()),
+            move |(data, _)| eval_fn(data),
+            |(_, slot), value| {
+                if let (Some(value), Some(mut handler)) = (value, slot.take()) {
+                    handler(value);
+                    // Keep the handler that was set while this one was running, if any.
+                    let replaced = slot.take();
+                    slot.set(replaced.or(Some(handler)));
+                }
+            },
+        );
+        self.0.borrow_mut().push((name, tracker, Box::new(slot)));
+    }
+}
+
 pub fn debug(s: SharedString) {
     #[cfg(feature = "log")]
     log::debug!("{s}");
@@ -192,8 +244,8 @@ pub mod re_exports {
     pub use i_slint_core::date_time::*;
     pub use i_slint_core::graphics::*;
     pub use i_slint_core::input::{
-        key_codes::Key, FocusEvent, InputEventResult, KeyEvent, KeyEventResult, KeyboardModifiers,
-        MouseEvent,
+        key_codes::Key, FocusEvent, InputEventResult, KeyEvent, KeyEventResult, KeyEventType,
+        KeyboardModifiers, MouseEvent,
     };
     pub use i_slint_core::item_tree::{
         register_item_tree, unregister_item_tree, IndexRange, ItemTree, ItemTreeRefPin,
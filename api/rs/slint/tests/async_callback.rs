@@ -0,0 +1,52 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use ::slint::slint;
+
+#[test]
+fn async_callback_handler() {
+    i_slint_backend_testing::init_integration_test_with_mock_time();
+    slint! {
+        export global Logic {
+            callback load(int);
+            in-out property <string> status;
+        }
+        export component App inherits Window {
+            callback fetch(string, int);
+            in-out property <string> result;
+        }
+    }
+
+    let app = App::new().unwrap();
+    let app_weak = app.as_weak();
+    app.on_fetch_async(move |text, count| {
+        let app_weak = app_weak.clone();
+        async move {
+            let handle = slint::spawn_local(async move { text.repeat(count as usize) }).unwrap();
+            let result = handle.await;
+            app_weak.upgrade().unwrap().set_result(result.into());
+            slint::quit_event_loop().unwrap();
+        }
+    });
+    let app_weak = app.as_weak();
+    app.global::<Logic>().on_load_async(move |value| {
+        let app_weak = app_weak.clone();
+        async move {
+            app_weak
+                .upgrade()
+                .unwrap()
+                .global::<Logic>()
+                .set_status(format!("loaded {value}").into());
+        }
+    });
+
+    app.global::<Logic>().invoke_load(42);
+    app.invoke_fetch("ab".into(), 3);
+    // The handlers return before the futures complete.
+    assert_eq!(app.global::<Logic>().get_status(), "");
+    assert_eq!(app.get_result(), "");
+    slint::run_event_loop().unwrap();
+
+    assert_eq!(app.get_result(), "ababab");
+    assert_eq!(app.global::<Logic>().get_status(), "loaded 42");
+}
@@ -0,0 +1,132 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use slint::{Brush, Color, ConicGradientBrush, GradientStop, Rgb8Pixel};
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+const WIDTH: usize = 20;
+const HEIGHT: usize = 20;
+
+const RED: Rgb8Pixel = Rgb8Pixel { r: 255, g: 0, b: 0 };
+const GREEN: Rgb8Pixel = Rgb8Pixel { r: 0, g: 255, b: 0 };
+const BLUE: Rgb8Pixel = Rgb8Pixel { r: 0, g: 0, b: 255 };
+const WHITE: Rgb8Pixel = Rgb8Pixel { r: 255, g: 255, b: 255 };
+const BLACK: Rgb8Pixel = Rgb8Pixel { r: 0, g: 0, b: 0 };
+
+slint::slint! {
+    export component TestCase inherits Window {
+        in property <brush> fill;
+        background: black;
+        Rectangle {
+            x: 2phx;
+            y: 2phx;
+            width: 12phx;
+            height: 12phx;
+            background: fill;
+        }
+    }
+}
+
+fn render(ui: &TestCase) -> Vec<Rgb8Pixel> {
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(WIDTH as _, HEIGHT as _));
+    ui.show().unwrap();
+    let mut buffer = vec![Rgb8Pixel::default(); WIDTH * HEIGHT];
+    window.request_redraw();
+    assert!(window.draw_if_needed(|renderer| {
+        renderer.render(buffer.as_mut_slice(), WIDTH);
+    }));
+    buffer
+}
+
+/// A conic gradient with a red, a green, a blue, and a white quarter, clockwise from `from_angle`.
+fn quarters(from_angle: f32) -> Brush {
+    let stops = [RED, GREEN, BLUE, WHITE].into_iter().enumerate().flat_map(|(i, c)| {
+        let color = Color::from_rgb_u8(c.r, c.g, c.b);
+        [
+            GradientStop { color, position: i as f32 / 4. },
+            GradientStop { color, position: (i + 1) as f32 / 4. },
+        ]
+    });
+    Brush::ConicGradient(ConicGradientBrush::new(from_angle, stops))
+}
+
+#[test]
+fn conic_gradient_start_and_direction() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    // The rectangle goes from 2 to 14, so the center of the gradient is at 8,8
+    let top_right = 11 + 4 * WIDTH;
+    let bottom_right = 11 + 11 * WIDTH;
+    let bottom_left = 4 + 11 * WIDTH;
+    let top_left = 4 + 4 * WIDTH;
+
+    // Starts at the top, clockwise
+    ui.set_fill(quarters(0.));
+    let buffer = render(&ui);
+    assert_eq!(buffer[top_right], RED);
+    assert_eq!(buffer[bottom_right], GREEN);
+    assert_eq!(buffer[bottom_left], BLUE);
+    assert_eq!(buffer[top_left], WHITE);
+    // Nothing is drawn outside of the rectangle
+    assert_eq!(buffer[0], BLACK);
+    assert_eq!(buffer[15 + 8 * WIDTH], BLACK);
+    assert_eq!(buffer[8 + 15 * WIDTH], BLACK);
+
+    // Starts on the right
+    ui.set_fill(quarters(90.));
+    let buffer = render(&ui);
+    assert_eq!(buffer[bottom_right], RED);
+    assert_eq!(buffer[bottom_left], GREEN);
+    assert_eq!(buffer[top_left], BLUE);
+    assert_eq!(buffer[top_right], WHITE);
+
+    // A negative angle goes counter-clockwise, so this starts on the left
+    ui.set_fill(quarters(-90.));
+    let buffer = render(&ui);
+    assert_eq!(buffer[top_left], RED);
+    assert_eq!(buffer[top_right], GREEN);
+    assert_eq!(buffer[bottom_right], BLUE);
+    assert_eq!(buffer[bottom_left], WHITE);
+}
+
+#[test]
+fn conic_gradient_interpolation() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    // From red at the top to blue at the bottom, on both sides
+    ui.set_fill(Brush::ConicGradient(ConicGradientBrush::new(
+        0.,
+        [
+            GradientStop { color: Color::from_rgb_u8(255, 0, 0), position: 0. },
+            GradientStop { color: Color::from_rgb_u8(0, 0, 255), position: 0.5 },
+            GradientStop { color: Color::from_rgb_u8(255, 0, 0), position: 1. },
+        ],
+    )));
+    let buffer = render(&ui);
+    // Just right of the center of the top edge, and of the bottom edge
+    let top = buffer[8 + 2 * WIDTH];
+    let bottom = buffer[8 + 13 * WIDTH];
+    assert!(top.r > 240 && top.b < 15, "{top:?}");
+    assert!(bottom.b > 240 && bottom.r < 15, "{bottom:?}");
+    // The middle of the right and left edges are half-way
+    for pixel in [buffer[13 + 7 * WIDTH], buffer[2 + 7 * WIDTH]] {
+        assert!(pixel.r.abs_diff(pixel.b) < 40 && pixel.g == 0, "{pixel:?}");
+    }
+    // The gradient is symmetric
+    assert_eq!(buffer[11 + 4 * WIDTH], buffer[4 + 4 * WIDTH]);
+}
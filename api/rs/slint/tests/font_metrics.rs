@@ -0,0 +1,44 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+slint::slint! {
+    import "../../../demos/home-automation/ui/fonts/Poppins-Regular.ttf";
+    import "../../../demos/printerdemo/ui/fonts/NotoSans-Regular.ttf";
+    export component TestCase inherits Window {
+        Text { text: "Hello"; font-family: "Poppins"; }
+        Text { text: "Hello"; font-family: "Noto Sans"; }
+    }
+}
+
+#[test]
+fn metrics_of_known_fonts() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+
+    // Poppins has 1000 units per em, an ascender of 1050, a descender of -350 and a line gap of 100
+    let metrics = ui.window().font_metrics(Some("Poppins"), 20.);
+    assert_eq!(metrics.ascent, 21.);
+    assert_eq!(metrics.descent, -7.);
+    assert_eq!(metrics.line_spacing, 30.);
+    assert!(metrics.line_spacing > metrics.ascent - metrics.descent);
+
+    // Noto Sans has no line gap
+    let metrics = ui.window().font_metrics(Some("Noto Sans"), 20.);
+    assert!((metrics.line_spacing - (metrics.ascent - metrics.descent)).abs() < 1., "{metrics:?}");
+}
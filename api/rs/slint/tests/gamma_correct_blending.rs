@@ -0,0 +1,104 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{
+    MinimalSoftwareWindow, PremultipliedRgbaColor, RepaintBufferType, TargetPixel,
+};
+use slint::platform::{PlatformError, WindowAdapter};
+use slint::Rgb8Pixel;
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+const WIDTH: usize = 101;
+const HEIGHT: usize = 2;
+
+slint::slint! {
+    export component TestCase inherits Window {
+        background: black;
+        // Half transparent white over black in the first line
+        Rectangle {
+            y: 0phx;
+            height: 1phx;
+            background: #ffffff80;
+        }
+        // A gradient from black to white in the second line
+        Rectangle {
+            y: 1phx;
+            height: 1phx;
+            background: @linear-gradient(90deg, black 0%, white 100%);
+        }
+    }
+}
+
+/// A pixel type that doesn't implement linear blending
+#[derive(Clone, Copy, Default)]
+struct SrgbPixel(Rgb8Pixel);
+
+impl TargetPixel for SrgbPixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        self.0.blend(color)
+    }
+
+    fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self(Rgb8Pixel::new(red, green, blue))
+    }
+}
+
+fn render<T: TargetPixel + Default>(ui: &TestCase) -> Vec<T> {
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(WIDTH as _, HEIGHT as _));
+    ui.show().unwrap();
+    let mut buffer = vec![T::default(); WIDTH * HEIGHT];
+    window.request_redraw();
+    assert!(window.draw_if_needed(|renderer| {
+        renderer.render(buffer.as_mut_slice(), WIDTH);
+    }));
+    buffer
+}
+
+#[track_caller]
+fn assert_gray(pixel: Rgb8Pixel, expected: u8) {
+    assert!(
+        pixel.r.abs_diff(expected) <= 3 && pixel.r == pixel.g && pixel.g == pixel.b,
+        "{pixel:?} isn't close to {expected}"
+    );
+}
+
+#[test]
+fn gamma_correct_blending() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+
+    // In the sRGB color space, half of the encoded value
+    let buffer = render::<Rgb8Pixel>(&ui);
+    assert_gray(buffer[WIDTH / 2], 128);
+    assert_gray(buffer[WIDTH + WIDTH / 2], 128);
+
+    // In linear light, half of the intensity, which is brighter
+    ui.window().set_gamma_correct_blending(true).unwrap();
+    let buffer = render::<Rgb8Pixel>(&ui);
+    assert_gray(buffer[WIDTH / 2], 188);
+    assert_gray(buffer[WIDTH + WIDTH / 2], 188);
+    assert_gray(buffer[2 * WIDTH - 1], 255);
+
+    // Pixel types that can't blend in linear light still blend in sRGB, but the gradients are
+    // interpolated in linear light as they don't need blending
+    let buffer = render::<SrgbPixel>(&ui);
+    assert_gray(buffer[WIDTH / 2].0, 128);
+    assert_gray(buffer[WIDTH + WIDTH / 2].0, 188);
+
+    ui.window().set_gamma_correct_blending(false).unwrap();
+    let buffer = render::<Rgb8Pixel>(&ui);
+    assert_gray(buffer[WIDTH / 2], 128);
+}
@@ -0,0 +1,103 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use slint::{Image, Rgb8Pixel, Rgba8Pixel, SharedPixelBuffer};
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+const WIDTH: usize = 10;
+const HEIGHT: usize = 10;
+
+const RED: Rgb8Pixel = Rgb8Pixel { r: 255, g: 0, b: 0 };
+const GREEN: Rgb8Pixel = Rgb8Pixel { r: 0, g: 255, b: 0 };
+const BLUE: Rgb8Pixel = Rgb8Pixel { r: 0, g: 0, b: 255 };
+const WHITE: Rgb8Pixel = Rgb8Pixel { r: 255, g: 255, b: 255 };
+const BLACK: Rgb8Pixel = Rgb8Pixel { r: 0, g: 0, b: 0 };
+
+slint::slint! {
+    export component TestCase inherits Window {
+        in property <image> source;
+        in property <ImageFit> fit: tile;
+        background: black;
+        Image {
+            x: 2phx;
+            y: 2phx;
+            width: 6phx;
+            height: 6phx;
+            source: source;
+            image-fit: fit;
+            image-rendering: pixelated;
+        }
+    }
+}
+
+/// A 2x2 image with a red, a green, a blue, and a white pixel, row by row.
+fn checker() -> Image {
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(2, 2);
+    for (pixel, color) in buffer.make_mut_slice().iter_mut().zip([RED, GREEN, BLUE, WHITE]) {
+        *pixel = Rgba8Pixel { r: color.r, g: color.g, b: color.b, a: 255 };
+    }
+    Image::from_rgba8(buffer)
+}
+
+fn render(ui: &TestCase) -> Vec<Rgb8Pixel> {
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(WIDTH as _, HEIGHT as _));
+    ui.show().unwrap();
+    let mut buffer = vec![Rgb8Pixel::default(); WIDTH * HEIGHT];
+    window.request_redraw();
+    assert!(window.draw_if_needed(|renderer| {
+        renderer.render(buffer.as_mut_slice(), WIDTH);
+    }));
+    buffer
+}
+
+#[test]
+fn tile_repeats_the_image_at_its_size() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    ui.set_source(checker());
+    let buffer = render(&ui);
+
+    // The centered first tile is at 4,4, so the tiles start at the top left corner of the element
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let expected = if (2..8).contains(&x) && (2..8).contains(&y) {
+                [[RED, GREEN], [BLUE, WHITE]][y % 2][x % 2]
+            } else {
+                BLACK
+            };
+            assert_eq!(buffer[y * WIDTH + x], expected, "at {x},{y}");
+        }
+    }
+}
+
+#[test]
+fn fill_stretches_the_image_instead_of_tiling_it() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    ui.set_source(checker());
+    ui.set_fit(slint::private_unstable_api::re_exports::ImageFit::Fill);
+    let buffer = render(&ui);
+
+    // Each pixel of the image covers a 3x3 square
+    for y in 2..8 {
+        for x in 2..8 {
+            let expected = [[RED, GREEN], [BLUE, WHITE]][(y - 2) / 3][(x - 2) / 3];
+            assert_eq!(buffer[y * WIDTH + x], expected, "at {x},{y}");
+        }
+    }
+}
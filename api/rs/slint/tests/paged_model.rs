@@ -0,0 +1,63 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::{Model, PagedModel};
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// Runs the event loop until the futures spawned so far are done.
+fn run_pending_futures() {
+    slint::spawn_local(async { slint::quit_event_loop().unwrap() }).unwrap();
+    slint::run_event_loop_until_quit().unwrap();
+}
+
+#[test]
+fn paged_model() {
+    i_slint_backend_testing::init_integration_test_with_mock_time();
+
+    let requests = Rc::new(RefCell::new(Vec::<Range<usize>>::new()));
+    let model = PagedModel::new(25, 10, -1, {
+        let requests = requests.clone();
+        move |range: Range<usize>| {
+            requests.borrow_mut().push(range.clone());
+            async move { range.map(|row| row as i32).collect::<Vec<_>>() }
+        }
+    });
+    assert_eq!(model.row_count(), 25);
+
+    // Requesting a row loads its page and the pages around it
+    assert_eq!(model.row_data(12), Some(-1));
+    assert_eq!(model.row_data(25), None);
+    assert!(!model.is_row_loaded(12));
+    assert_eq!(requests.take(), [10..20, 20..25, 0..10]);
+
+    run_pending_futures();
+    assert!(model.is_row_loaded(12));
+    assert_eq!(model.row_data(12), Some(12));
+    assert_eq!(model.row_data(24), Some(24));
+    assert_eq!(model.row_data(3), Some(3));
+    assert!(requests.borrow().is_empty());
+
+    // Only the page requested most recently is kept
+    model.set_max_cached_pages(1);
+    assert!(model.is_row_loaded(3));
+    assert!(!model.is_row_loaded(12));
+    assert!(!model.is_row_loaded(24));
+
+    // The pages that were being loaded when reloading are discarded
+    model.set_prefetch_pages(0);
+    assert_eq!(model.row_data(12), Some(-1));
+    model.reload();
+    run_pending_futures();
+    assert!(!model.is_row_loaded(12));
+    assert!(!model.is_row_loaded(3));
+    assert_eq!(requests.take(), [10..20]);
+
+    model.set_row_count(5);
+    assert_eq!(model.row_count(), 5);
+    assert_eq!(model.row_data(4), Some(-1));
+    assert_eq!(requests.take(), [0..5]);
+    run_pending_futures();
+    assert_eq!(model.row_data(4), Some(4));
+}
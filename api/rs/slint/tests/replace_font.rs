@@ -0,0 +1,76 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use std::path::Path;
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+slint::slint! {
+    export component TestCase inherits Window {
+        text := Text { text: "Hello"; font-family: "Plaster"; font-size: 20px; }
+        out property <length> text-width: text.preferred-width;
+    }
+}
+
+fn font_path(path: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../..").join(path)
+}
+
+#[test]
+fn replace_and_unregister_fonts() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    let window = ui.window();
+    let fallback_width = ui.get_text_width();
+
+    window
+        .replace_font_from_path(
+            "Plaster",
+            &font_path("examples/slide_puzzle/plaster-font/Plaster-Regular.ttf"),
+        )
+        .unwrap();
+    let plaster_width = ui.get_text_width();
+    assert_ne!(plaster_width, fallback_width);
+
+    // When the new font can't be loaded, the previous one is kept
+    assert!(window.replace_font_from_path("Plaster", Path::new("does-not-exist.ttf")).is_err());
+    assert_eq!(ui.get_text_width(), plaster_width);
+    assert!(window.replace_font_from_memory("Plaster", b"not a font").is_err());
+    assert_eq!(ui.get_text_width(), plaster_width);
+
+    // Replacing the family with a font of another family removes the fonts of the family
+    window
+        .replace_font_from_path(
+            "Plaster",
+            &font_path("demos/home-automation/ui/fonts/Poppins-Regular.ttf"),
+        )
+        .unwrap();
+    assert_eq!(ui.get_text_width(), fallback_width);
+    assert!(window.unregister_font("Plaster").is_err());
+
+    window.unregister_font("Poppins").unwrap();
+    assert!(window.unregister_font("Poppins").is_err());
+
+    window
+        .replace_font_from_path(
+            "Plaster",
+            &font_path("examples/slide_puzzle/plaster-font/Plaster-Regular.ttf"),
+        )
+        .unwrap();
+    assert_eq!(ui.get_text_width(), plaster_width);
+    window.unregister_font("Plaster").unwrap();
+    assert_eq!(ui.get_text_width(), fallback_width);
+}
@@ -0,0 +1,46 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+slint::slint! {
+    // The Noto Sans of the printer demo has no Greek letters, Roboto has them
+    import "../../../demos/printerdemo/ui/fonts/NotoSans-Regular.ttf";
+    import "../../../examples/carousel/ui/fonts/Roboto-Regular.ttf";
+    export component TestCase inherits Window {
+        noto-text := Text { text: "αβγ"; font-family: "Noto Sans"; font-size: 20px; }
+        roboto-text := Text { text: "αβγ"; font-family: "Roboto"; font-size: 20px; }
+        out property <length> noto-width: noto-text.preferred-width;
+        out property <length> roboto-width: roboto-text.preferred-width;
+    }
+}
+
+#[test]
+fn script_font_families_with_software_renderer() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    let noto_width = ui.get_noto_width();
+    let roboto_width = ui.get_roboto_width();
+    assert_ne!(noto_width, roboto_width);
+
+    // The Greek text is laid out again with the family preferred for the Greek script
+    slint::set_script_font_families("grek", &["Roboto"]);
+    assert_eq!(ui.get_noto_width(), roboto_width);
+
+    slint::set_script_font_families("Grek", &[]);
+    assert_eq!(ui.get_noto_width(), noto_width);
+}
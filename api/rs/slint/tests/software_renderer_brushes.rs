@@ -0,0 +1,151 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use slint::{Brush, Image, ImagePatternBrush, Rgb8Pixel, Rgba8Pixel, SharedPixelBuffer};
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+const WIDTH: usize = 20;
+const HEIGHT: usize = 20;
+
+const RED: Rgb8Pixel = Rgb8Pixel { r: 255, g: 0, b: 0 };
+const GREEN: Rgb8Pixel = Rgb8Pixel { r: 0, g: 255, b: 0 };
+const BLUE: Rgb8Pixel = Rgb8Pixel { r: 0, g: 0, b: 255 };
+const WHITE: Rgb8Pixel = Rgb8Pixel { r: 255, g: 255, b: 255 };
+const BLACK: Rgb8Pixel = Rgb8Pixel { r: 0, g: 0, b: 0 };
+
+/// A 2x2 image with a red, a green, a blue, and a white pixel.
+fn checker_pattern() -> ImagePatternBrush {
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(2, 2);
+    for (pixel, color) in buffer.make_mut_slice().iter_mut().zip([RED, GREEN, BLUE, WHITE]) {
+        *pixel = Rgba8Pixel { r: color.r, g: color.g, b: color.b, a: 255 };
+    }
+    ImagePatternBrush::new(Image::from_rgba8(buffer))
+}
+
+/// Returns the color of the checker pattern at the given position, relative to the top-left
+/// corner of the pattern.
+fn checker_color(x: usize, y: usize) -> Rgb8Pixel {
+    [RED, GREEN, BLUE, WHITE][(y % 2) * 2 + x % 2]
+}
+
+slint::slint! {
+    export component TestCase inherits Window {
+        in property <brush> fill;
+        in property <brush> border-fill;
+        in property <length> border-width;
+        in property <length> border-radius;
+        background: black;
+        Rectangle {
+            x: 2phx;
+            y: 2phx;
+            width: 12phx;
+            height: 12phx;
+            background: fill;
+            border-color: border-fill;
+            border-width: border-width;
+            border-radius: border-radius;
+        }
+    }
+}
+
+fn render(ui: &TestCase) -> Vec<Rgb8Pixel> {
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(WIDTH as _, HEIGHT as _));
+    ui.show().unwrap();
+    let mut buffer = vec![Rgb8Pixel::default(); WIDTH * HEIGHT];
+    window.request_redraw();
+    assert!(window.draw_if_needed(|renderer| {
+        renderer.render(buffer.as_mut_slice(), WIDTH);
+    }));
+    buffer
+}
+
+#[test]
+fn image_pattern_rectangle() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    ui.set_fill(Brush::ImagePattern(checker_pattern()));
+    let buffer = render(&ui);
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let inside = (2..14).contains(&x) && (2..14).contains(&y);
+            let expected = if inside { checker_color(x - 2, y - 2) } else { BLACK };
+            assert_eq!(buffer[y * WIDTH + x], expected, "pixel {x},{y}");
+        }
+    }
+}
+
+#[test]
+fn image_pattern_offset_without_repeat() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    ui.set_fill(Brush::ImagePattern(
+        checker_pattern().with_offset(3., 4.).with_scale(2., 2.).with_repeat(false, false),
+    ));
+    let buffer = render(&ui);
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let inside = (5..9).contains(&x) && (6..10).contains(&y);
+            let expected = if inside { checker_color((x - 5) / 2, (y - 6) / 2) } else { BLACK };
+            assert_eq!(buffer[y * WIDTH + x], expected, "pixel {x},{y}");
+        }
+    }
+}
+
+#[test]
+fn image_pattern_border() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    ui.set_fill(Brush::SolidColor(slint::Color::from_rgb_u8(0, 0, 255)));
+    ui.set_border_fill(Brush::ImagePattern(checker_pattern()));
+    ui.set_border_width(3.);
+    let buffer = render(&ui);
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let inside = (2..14).contains(&x) && (2..14).contains(&y);
+            let in_border = inside && !((5..11).contains(&x) && (5..11).contains(&y));
+            let expected = if in_border {
+                checker_color(x - 2, y - 2)
+            } else if inside {
+                BLUE
+            } else {
+                BLACK
+            };
+            assert_eq!(buffer[y * WIDTH + x], expected, "pixel {x},{y}");
+        }
+    }
+}
+
+#[test]
+fn image_pattern_rounded_rectangle() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    ui.set_fill(Brush::ImagePattern(checker_pattern()));
+    ui.set_border_radius(4.);
+    ui.set_border_width(2.);
+    ui.set_border_fill(Brush::SolidColor(slint::Color::from_rgb_u8(0, 255, 0)));
+    let buffer = render(&ui);
+    // The pattern is visible inside of the border, and the border is drawn on top of it
+    for y in 6..10 {
+        for x in 6..10 {
+            assert_eq!(buffer[y * WIDTH + x], checker_color(x - 2, y - 2), "pixel {x},{y}");
+        }
+    }
+    assert_eq!(buffer[8 * WIDTH + 2], GREEN);
+    assert_eq!(buffer[2 * WIDTH + 8], GREEN);
+    assert_eq!(buffer[0], BLACK);
+}
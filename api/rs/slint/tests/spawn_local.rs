@@ -80,6 +80,19 @@ fn main() {
     })
     .unwrap();
     slint::run_event_loop().unwrap();
+
+    // test_invoke_from_event_loop_async
+    let main_thread = std::thread::current().id();
+    let thread = std::thread::spawn(move || {
+        let value = executor::block_on(slint::invoke_from_event_loop_async(move || {
+            assert_eq!(std::thread::current().id(), main_thread);
+            String::from("Hello")
+        }));
+        assert_eq!(value.as_deref(), Ok("Hello"));
+        slint::quit_event_loop().unwrap();
+    });
+    slint::run_event_loop_until_quit().unwrap();
+    thread.join().unwrap();
 }
 
 #[test]
@@ -0,0 +1,58 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter, WindowEvent};
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+slint::slint! {
+    import "../../../demos/printerdemo/ui/fonts/NotoSans-Regular.ttf";
+    export component TestCase inherits Window {
+        default-font-family: "Noto Sans";
+        default-size-text := Text { text: "Hello World"; }
+        explicit-size-text := Text { text: "Hello World"; font-size: 20px; }
+        out property <length> default-size-width: default-size-text.preferred-width;
+        out property <length> explicit-size-width: explicit-size-text.preferred-width;
+    }
+}
+
+#[test]
+fn text_scale_factor_applies_to_all_texts() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    let default_size_width = ui.get_default_size_width();
+    let explicit_size_width = ui.get_explicit_size_width();
+    assert!(default_size_width > 0.);
+    assert!(explicit_size_width > default_size_width);
+
+    assert_eq!(ui.window().text_scale_factor(), 1.);
+    ui.window().dispatch_event(WindowEvent::SystemFontChanged {
+        family: Default::default(),
+        text_scale_factor: 2.,
+    });
+    assert_eq!(ui.window().text_scale_factor(), 2.);
+
+    // Texts without a font-size use the default size of the renderer, which is scaled too
+    let ratio = ui.get_default_size_width() / default_size_width;
+    assert!((ratio - 2.).abs() < 0.1, "default size scaled by {ratio}");
+    let ratio = ui.get_explicit_size_width() / explicit_size_width;
+    assert!((ratio - 2.).abs() < 0.1, "explicit size scaled by {ratio}");
+
+    ui.window().dispatch_event(WindowEvent::SystemFontChanged {
+        family: Default::default(),
+        text_scale_factor: 1.,
+    });
+    assert_eq!(ui.get_default_size_width(), default_size_width);
+}
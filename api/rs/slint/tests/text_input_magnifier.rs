@@ -0,0 +1,94 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter, WindowEvent};
+use slint::{LogicalPosition, Rgb8Pixel};
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::ReusedBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+const SIZE: usize = 100;
+
+slint::slint! {
+    import "../../../demos/printerdemo/ui/fonts/NotoSans-Regular.ttf";
+    export component TestCase inherits Window {
+        background: black;
+        TextInput {
+            x: 10phx;
+            y: 60phx;
+            width: 80phx;
+            height: 20phx;
+            text: "Hello";
+            font-family: "Noto Sans";
+            font-size: 12phx;
+            color: white;
+            selection-background-color: blue;
+            selection-handles-enabled: true;
+            magnifier-enabled: true;
+        }
+    }
+}
+
+/// Renders the parts of the window that changed into `buffer`, which keeps the other parts.
+fn render(buffer: &mut [Rgb8Pixel]) {
+    let window = WINDOW.with(|x| x.clone());
+    assert!(window.draw_if_needed(|renderer| {
+        renderer.render(buffer, SIZE);
+    }));
+}
+
+fn touch(ui: &TestCase, event: &str, x: f32, y: f32) {
+    let position = LogicalPosition::new(x, y);
+    ui.window().dispatch_event(match event {
+        "press" => WindowEvent::TouchPressed { id: 1, position },
+        "release" => WindowEvent::TouchReleased { id: 1, position },
+        _ => WindowEvent::TouchMoved { id: 1, position },
+    });
+}
+
+#[test]
+fn magnifier_outside_of_text_input() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    WINDOW.with(|x| x.set_size(slint::PhysicalSize::new(SIZE as _, SIZE as _)));
+    ui.show().unwrap();
+    let mut buffer = vec![Rgb8Pixel::default(); SIZE * SIZE];
+    render(&mut buffer);
+
+    let pixel = |buffer: &[Rgb8Pixel], x: usize, y: usize| buffer[y * SIZE + x];
+    // Within the magnifier above the text input, but above the magnified text
+    let magnifier_top = (20, 20);
+    // Within the knob of the handle below the cursor at the start of the text, below the text input
+    let knob = (10, 82);
+
+    // Tapping at the start of the text shows the handle below the cursor
+    touch(&ui, "press", 11., 65.);
+    touch(&ui, "release", 11., 65.);
+    render(&mut buffer);
+    assert_eq!(pixel(&buffer, knob.0, knob.1), Rgb8Pixel { r: 0, g: 0, b: 255 });
+    assert_eq!(pixel(&buffer, magnifier_top.0, magnifier_top.1), Rgb8Pixel::default());
+
+    // Dragging the handle, from the part of its knob within the text input, shows the magnifier above it
+    touch(&ui, "press", 10., 78.);
+    touch(&ui, "move", 11., 78.);
+    render(&mut buffer);
+    let background = pixel(&buffer, magnifier_top.0, magnifier_top.1);
+    assert!(background.r > 200 && background.g > 200 && background.b > 200, "{background:?}");
+
+    // Releasing the handle hides the magnifier, and the window is redrawn where it was
+    touch(&ui, "release", 11., 78.);
+    render(&mut buffer);
+    assert_eq!(pixel(&buffer, magnifier_top.0, magnifier_top.1), Rgb8Pixel::default());
+    assert_eq!(pixel(&buffer, knob.0, knob.1), Rgb8Pixel { r: 0, g: 0, b: 255 });
+}
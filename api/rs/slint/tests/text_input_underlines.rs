@@ -0,0 +1,131 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter, WindowEvent};
+use slint::Rgb8Pixel;
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+const WIDTH: usize = 100;
+const HEIGHT: usize = 30;
+
+slint::slint! {
+    import "../../../demos/printerdemo/ui/fonts/NotoSans-Regular.ttf";
+    export component TestCase inherits Window {
+        in-out property <string> text <=> ti.text;
+        background: black;
+        ti := TextInput {
+            font-family: "Noto Sans";
+            font-size: 12phx;
+            color: white;
+        }
+
+        public function add-underline(start: int, end: int) {
+            ti.add-underline(start, end, Colors.red, TextUnderlineStyle.solid);
+        }
+
+        public function edit-at(offset: int) {
+            ti.focus();
+            ti.set-selection-offsets(offset, offset);
+        }
+    }
+}
+
+/// Renders the window and returns the range of columns where the underline is drawn, if any.
+fn underlined_columns(ui: &TestCase) -> Option<std::ops::Range<usize>> {
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(WIDTH as _, HEIGHT as _));
+    ui.show().unwrap();
+    let mut buffer = vec![Rgb8Pixel::default(); WIDTH * HEIGHT];
+    window.request_redraw();
+    assert!(window.draw_if_needed(|renderer| {
+        renderer.render(buffer.as_mut_slice(), WIDTH);
+    }));
+    let is_red = |pixel: &Rgb8Pixel| pixel.r > 200 && pixel.g < 100 && pixel.b < 100;
+    let columns = (0..WIDTH)
+        .filter(|x| (0..HEIGHT).any(|y| is_red(&buffer[y * WIDTH + x])))
+        .collect::<Vec<_>>();
+    Some(*columns.first()?..*columns.last()? + 1)
+}
+
+fn type_text(ui: &TestCase, text: &str) {
+    for ch in text.chars() {
+        ui.window().dispatch_event(WindowEvent::KeyPressed { text: ch.into() });
+        ui.window().dispatch_event(WindowEvent::KeyReleased { text: ch.into() });
+    }
+}
+
+#[test]
+fn underlines_follow_edits() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    ui.set_text("ab cd".into());
+    assert_eq!(underlined_columns(&ui), None);
+
+    ui.invoke_add_underline(3, 5);
+    let underline = underlined_columns(&ui).unwrap();
+
+    // Typing before the underlined range moves it
+    ui.invoke_edit_at(0);
+    type_text(&ui, "xx");
+    assert_eq!(ui.get_text(), "xxab cd");
+    let moved = underlined_columns(&ui).unwrap();
+    assert!(moved.start > underline.start, "{moved:?} {underline:?}");
+    assert!(moved.len().abs_diff(underline.len()) <= 1, "{moved:?} {underline:?}");
+
+    // Typing after it doesn't
+    ui.invoke_edit_at(7);
+    type_text(&ui, "e");
+    assert_eq!(ui.get_text(), "xxab cde");
+    assert_eq!(underlined_columns(&ui), Some(moved.clone()));
+
+    // Deleting the underlined text removes the underline
+    ui.invoke_edit_at(4);
+    for _ in 0..3 {
+        ui.window()
+            .dispatch_event(WindowEvent::KeyPressed { text: slint::platform::Key::Delete.into() });
+        ui.window()
+            .dispatch_event(WindowEvent::KeyReleased { text: slint::platform::Key::Delete.into() });
+    }
+    assert_eq!(ui.get_text(), "xxabe");
+    assert_eq!(underlined_columns(&ui), None);
+}
+
+#[test]
+fn underlines_are_dropped_when_the_text_is_set() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+    ui.set_text("ab cd".into());
+    ui.invoke_add_underline(3, 5);
+    assert!(underlined_columns(&ui).is_some());
+
+    // The ranges don't refer to the new text
+    ui.set_text("hello world".into());
+    assert_eq!(underlined_columns(&ui), None);
+
+    // Editing the new text doesn't bring them back
+    ui.invoke_edit_at(0);
+    type_text(&ui, "x");
+    assert_eq!(ui.get_text(), "xhello world");
+    assert_eq!(underlined_columns(&ui), None);
+
+    // New underlines refer to the new text
+    ui.invoke_add_underline(0, 1);
+    let underline = underlined_columns(&ui).unwrap();
+    assert!(underline.start < 3, "{underline:?}");
+    type_text(&ui, "y");
+    assert_eq!(ui.get_text(), "xyhello world");
+    assert_eq!(underlined_columns(&ui), Some(underline));
+}
@@ -0,0 +1,67 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use slint::{LogicalPosition, PathCommand};
+use std::rc::Rc;
+
+thread_local! {
+    static WINDOW: Rc<MinimalSoftwareWindow> =
+    MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+}
+
+struct TestPlatform;
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(WINDOW.with(|x| x.clone()))
+    }
+}
+
+slint::slint! {
+    import "../../../demos/printerdemo/ui/fonts/NotoSans-Regular.ttf";
+    export component TestCase inherits Window {}
+}
+
+/// Returns the start points of the contours of the outline.
+fn contour_starts(commands: &[PathCommand]) -> Vec<LogicalPosition> {
+    commands
+        .iter()
+        .filter_map(|command| match command {
+            PathCommand::MoveTo(position) => Some(*position),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn text_outline() {
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = TestCase::new().unwrap();
+
+    let outline = ui.window().text_outline("ii", Some("Noto Sans"), 10.).unwrap();
+    assert!(matches!(outline.commands().first(), Some(PathCommand::MoveTo(_))));
+    assert_eq!(outline.commands().last(), Some(&PathCommand::Close));
+    assert!(outline.to_svg_path().starts_with("M "));
+    assert!(outline.to_svg_path().ends_with(" Z"));
+
+    // Each "i" has two contours, the stem and the dot, all below the top of the text
+    let starts = contour_starts(outline.commands());
+    assert_eq!(starts.len(), 4);
+    assert!(starts.iter().all(|p| p.y > 0. && p.y < 15.), "{starts:?}");
+
+    // The positions are not rounded to whole pixels, so the outline scales with the font size
+    let advance = starts[2].x - starts[0].x;
+    let large_outline = ui.window().text_outline("ii", Some("Noto Sans"), 1000.).unwrap();
+    let large_starts = contour_starts(large_outline.commands());
+    let large_advance = large_starts[2].x - large_starts[0].x;
+    assert!((advance - large_advance / 100.).abs() < 0.001, "{advance} vs. {large_advance}");
+
+    // Lines are stacked below each other
+    let two_lines = contour_starts(
+        ui.window().text_outline("i\ni", Some("Noto Sans"), 10.).unwrap().commands(),
+    );
+    assert_eq!(two_lines.len(), 4);
+    assert_eq!(two_lines[0].x, two_lines[2].x);
+    assert!(two_lines[2].y > two_lines[0].y + 10.);
+}
@@ -0,0 +1,49 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+#![cfg(feature = "tokio")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn tokio_integration() {
+    i_slint_backend_testing::init_integration_test_with_mock_time();
+
+    let current_thread = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    assert!(slint::tokio::run_event_loop_until_quit(current_thread.handle()).is_err());
+
+    let runtime =
+        tokio::runtime::Builder::new_multi_thread().worker_threads(1).enable_all().build().unwrap();
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+    slint::tokio::on_unbounded_message(receiver, {
+        let received = received.clone();
+        move |message| received.borrow_mut().push(message)
+    })
+    .unwrap();
+
+    let main_thread = std::thread::current().id();
+    let task = runtime.spawn(async move {
+        let thread = slint::tokio::run_in_event_loop(|| std::thread::current().id()).await.unwrap();
+        assert_eq!(thread, main_thread);
+        for i in 1..=3 {
+            sender.send(i).unwrap();
+        }
+        let not_send = slint::tokio::spawn_in_event_loop(|| async {
+            let value = Rc::new(42);
+            // The Tokio timers work because the event loop runs in the context of the runtime
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+            *value
+        })
+        .await
+        .unwrap();
+        assert_eq!(not_send, 42);
+        slint::invoke_from_event_loop(|| slint::quit_event_loop().unwrap()).unwrap();
+    });
+
+    slint::tokio::run_event_loop_until_quit(runtime.handle()).unwrap();
+    runtime.block_on(task).unwrap();
+    assert_eq!(*received.borrow(), [1, 2, 3]);
+}
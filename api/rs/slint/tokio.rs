@@ -0,0 +1,193 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Integration of the Slint event loop with the [Tokio](https://docs.rs/tokio/latest/tokio/index.html) runtime.
+//!
+//! **Note:** This module is only available with the "tokio" feature
+//!
+//! The Slint event loop runs on the main thread, and Tokio runs its tasks on the worker threads of a
+//! multi-threaded runtime. This module connects the two:
+//!  - [`run_event_loop()`] runs the Slint event loop inside the context of a Tokio runtime, so that the futures
+//!    spawned with [`spawn_local()`](crate::spawn_local) can use the Tokio timers, sockets, and
+//!    [`tokio::spawn()`](::tokio::spawn) without [async_compat](https://docs.rs/async-compat/latest/async_compat/index.html).
+//!    It can also be called from `#[tokio::main]`.
+//!  - [`run_in_event_loop()`] and [`spawn_in_event_loop()`] let Tokio tasks run code on the Slint thread, such as
+//!    updating the properties of a component, and wait for its result.
+//!  - [`on_message()`], [`on_unbounded_message()`], and [`on_change()`] handle the values sent through Tokio
+//!    channels on the Slint thread, as they arrive.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! slint::slint!{
+//!     export component App inherits Window {
+//!         in property <string> status;
+//!         Text { text: status; }
+//!     }
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), slint::PlatformError> {
+//!     let app = App::new()?;
+//!     let (sender, receiver) = tokio::sync::mpsc::channel(16);
+//!     tokio::spawn(async move {
+//!         for i in 0.. {
+//!             tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+//!             if sender.send(format!("Working… {i}")).await.is_err() {
+//!                 break;
+//!             }
+//!         }
+//!     });
+//!     let app_weak = app.as_weak();
+//!     slint::tokio::on_message(receiver, move |status| {
+//!         app_weak.unwrap().set_status(status.into());
+//!     })
+//!     .unwrap();
+//!     app.show()?;
+//!     slint::tokio::run_event_loop(&tokio::runtime::Handle::current())
+//! }
+//! ```
+
+use crate::{EventLoopError, JoinHandle, PlatformError};
+use ::tokio::runtime::{Handle, RuntimeFlavor};
+use ::tokio::sync::{mpsc, oneshot, watch};
+use core::future::Future;
+
+/// Runs the Slint event loop with the given Tokio runtime entered, until the last window is closed.
+///
+/// The runtime must be multi-threaded, because its worker threads drive the timers and the I/O of the Tokio
+/// futures while the Slint event loop runs on the current thread. When called from a task of the runtime, such
+/// as from `#[tokio::main]`, the task is moved out of the worker thread with
+/// [`block_in_place()`](::tokio::task::block_in_place) while the event loop runs.
+///
+/// Returns an error if the runtime, or the runtime of the current thread, uses the current-thread scheduler.
+///
+/// See also [`run_event_loop_until_quit()`] and [`crate::run_event_loop()`].
+pub fn run_event_loop(runtime: &Handle) -> Result<(), PlatformError> {
+    run_in_runtime(runtime, crate::run_event_loop)
+}
+
+/// Runs the Slint event loop with the given Tokio runtime entered, until [`crate::quit_event_loop()`] is
+/// called, even if all windows are closed.
+///
+/// See [`run_event_loop()`] for the requirements on the runtime.
+pub fn run_event_loop_until_quit(runtime: &Handle) -> Result<(), PlatformError> {
+    run_in_runtime(runtime, crate::run_event_loop_until_quit)
+}
+
+fn run_in_runtime(
+    runtime: &Handle,
+    run: fn() -> Result<(), PlatformError>,
+) -> Result<(), PlatformError> {
+    let is_current_thread =
+        |handle: &Handle| handle.runtime_flavor() == RuntimeFlavor::CurrentThread;
+    if is_current_thread(runtime) || Handle::try_current().is_ok_and(|h| is_current_thread(&h)) {
+        return Err(PlatformError::Other(
+            "The Slint event loop can't run in a Tokio runtime with the current-thread scheduler"
+                .into(),
+        ));
+    }
+    ::tokio::task::block_in_place(|| {
+        let _guard = runtime.enter();
+        run()
+    })
+}
+
+/// Runs `func` on the thread of the Slint event loop, and returns its result.
+///
+/// This is the same as [`crate::invoke_from_event_loop_async()`]: it can be awaited from a Tokio task,
+/// or from any other thread, to update the user interface and get a value back from it. The event loop is
+/// woken up if it's waiting for events.
+///
+/// Returns [`EventLoopError::EventLoopTerminated`] if the event loop stopped before running `func`.
+///
+/// ```rust,no_run
+/// # slint::slint!{ export component App inherits Window { in-out property <int> counter; } }
+/// # async fn example(app_weak: slint::Weak<App>) -> Result<(), slint::EventLoopError> {
+/// let counter = slint::tokio::run_in_event_loop(move || {
+///     let app = app_weak.unwrap();
+///     app.set_counter(app.get_counter() + 1);
+///     app.get_counter()
+/// })
+/// .await?;
+/// # Ok(()) }
+/// ```
+pub async fn run_in_event_loop<R: Send + 'static>(
+    func: impl FnOnce() -> R + Send + 'static,
+) -> Result<R, EventLoopError> {
+    crate::invoke_from_event_loop_async(func).await
+}
+
+/// Spawns the future returned by `func` on the thread of the Slint event loop, and returns its output once it
+/// completes.
+///
+/// Unlike the futures spawned with [`tokio::spawn()`](::tokio::spawn), the future doesn't need to be `Send`, so
+/// it can hold the components and the models of the user interface across `.await` points. Only `func` and the
+/// output of the future cross threads.
+///
+/// Returns [`EventLoopError::EventLoopTerminated`] if the event loop stopped before the future completed.
+pub async fn spawn_in_event_loop<F, Fut>(func: F) -> Result<Fut::Output, EventLoopError>
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future + 'static,
+    Fut::Output: Send + 'static,
+{
+    let (sender, receiver) = oneshot::channel();
+    crate::invoke_from_event_loop(move || {
+        let spawned = crate::spawn_local(async move {
+            let _ = sender.send(func().await);
+        });
+        // On error, the sender is dropped and the receiver reports that the event loop terminated
+        drop(spawned);
+    })?;
+    receiver.await.map_err(|_| EventLoopError::EventLoopTerminated)
+}
+
+/// Calls `handler` on the thread of the Slint event loop with each message received from the channel, until all
+/// the senders are dropped.
+///
+/// This must be called from the thread of the Slint event loop. Abort the returned [`JoinHandle`] to stop
+/// receiving the messages.
+pub fn on_message<T: 'static>(
+    mut receiver: mpsc::Receiver<T>,
+    mut handler: impl FnMut(T) + 'static,
+) -> Result<JoinHandle<()>, EventLoopError> {
+    crate::spawn_local(async move {
+        while let Some(message) = receiver.recv().await {
+            handler(message);
+        }
+    })
+}
+
+/// Calls `handler` on the thread of the Slint event loop with each message received from the unbounded channel,
+/// until all the senders are dropped.
+///
+/// See [`on_message()`].
+pub fn on_unbounded_message<T: 'static>(
+    mut receiver: mpsc::UnboundedReceiver<T>,
+    mut handler: impl FnMut(T) + 'static,
+) -> Result<JoinHandle<()>, EventLoopError> {
+    crate::spawn_local(async move {
+        while let Some(message) = receiver.recv().await {
+            handler(message);
+        }
+    })
+}
+
+/// Calls `handler` on the thread of the Slint event loop with the current value of the watch channel, and then
+/// every time the value changes, until the sender is dropped. Only the latest value is reported when it changes
+/// several times before the handler can be called.
+///
+/// This must be called from the thread of the Slint event loop. Abort the returned [`JoinHandle`] to stop
+/// watching the value.
+pub fn on_change<T: 'static>(
+    mut receiver: watch::Receiver<T>,
+    mut handler: impl FnMut(&T) + 'static,
+) -> Result<JoinHandle<()>, EventLoopError> {
+    crate::spawn_local(async move {
+        handler(&receiver.borrow_and_update());
+        while receiver.changed().await.is_ok() {
+            handler(&receiver.borrow_and_update());
+        }
+    })
+}
@@ -279,6 +279,10 @@ export default defineConfig({
                                                 label: "Math",
                                                 slug: "reference/global-functions/math",
                                             },
+                                            {
+                                                label: "Fonts",
+                                                slug: "reference/global-functions/fonts",
+                                            },
                                             {
                                                 label: "animation-tick() / debug()",
                                                 slug: "reference/global-functions/builtinfunctions",
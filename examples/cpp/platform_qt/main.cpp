@@ -35,6 +35,10 @@ slint::PointerEventButton convert_button(Qt::MouseButtons b)
         return slint::PointerEventButton::Right;
     case Qt::MiddleButton:
         return slint::PointerEventButton::Middle;
+    case Qt::BackButton:
+        return slint::PointerEventButton::Back;
+    case Qt::ForwardButton:
+        return slint::PointerEventButton::Forward;
     default:
         return slint::PointerEventButton::Other;
     }
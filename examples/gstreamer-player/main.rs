@@ -3,10 +3,12 @@
 
 slint::include_modules!();
 
+#[cfg(not(feature = "gst-gl"))]
 use anyhow::{bail, Result};
 
 use gst::prelude::*;
 
+#[cfg(not(feature = "gst-gl"))]
 fn try_gstreamer_video_frame_to_pixel_buffer(
     frame: &gst_video::VideoFrame<gst_video::video_frame::Readable>,
 ) -> Result<slint::SharedPixelBuffer<slint::Rgb8Pixel>> {
@@ -31,7 +33,6 @@ fn try_gstreamer_video_frame_to_pixel_buffer(
 
 fn main() {
     let app = App::new().unwrap();
-    let app_weak = app.as_weak();
 
     gst::init().unwrap();
     let source = gst::ElementFactory::make("videotestsrc")
@@ -40,52 +41,114 @@ fn main() {
         .build()
         .expect("Could not create source element.");
 
-    let width: u32 = 1024;
-    let height: u32 = 1024;
+    let pipeline = gst::Pipeline::with_name("test-pipeline");
 
-    let appsink = gst_app::AppSink::builder()
-        .caps(
-            &gst_video::VideoCapsBuilder::new()
-                .format(gst_video::VideoFormat::Rgb)
-                .width(width as i32)
-                .height(height as i32)
-                .build(),
-        )
-        .build();
+    #[cfg(feature = "gst-gl")]
+    {
+        let (video_sink, appsink) =
+            slint::gstreamer::create_video_sink().expect("Unable to create the video sink");
+        pipeline.add_many([&source, &video_sink]).unwrap();
+        source.link(&video_sink).expect("Elements could not be linked.");
 
-    let pipeline = gst::Pipeline::with_name("test-pipeline");
+        let app_weak = app.as_weak();
+        appsink.set_callbacks(
+            gst_app::AppSinkCallbacks::builder()
+                .new_sample(move |_| {
+                    // The sample is pulled when rendering the next frame
+                    app_weak
+                        .upgrade_in_event_loop(|app| app.window().request_redraw())
+                        .map_err(|_| gst::FlowError::Flushing)?;
+                    Ok(gst::FlowSuccess::Ok)
+                })
+                .build(),
+        );
 
-    pipeline.add_many([&source, &appsink.upcast_ref()]).unwrap();
-    source.link(&appsink).expect("Elements could not be linked.");
-
-    appsink.set_callbacks(
-        gst_app::AppSinkCallbacks::builder()
-            .new_sample(move |appsink| {
-                let sample = appsink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
-                let buffer = sample.buffer_owned().unwrap(); // Probably copies!
-                let video_info =
-                    gst_video::VideoInfo::builder(gst_video::VideoFormat::Rgb, width, height)
-                        .build()
-                        .expect("couldn't build video info!");
-                let video_frame =
-                    gst_video::VideoFrame::from_buffer_readable(buffer, &video_info).unwrap();
-                let slint_frame = try_gstreamer_video_frame_to_pixel_buffer(&video_frame)
-                    .expect("Unable to convert the video frame to a slint video frame!");
-
-                app_weak
-                    .upgrade_in_event_loop(|app| {
-                        app.set_video_frame(slint::Image::from_rgb8(slint_frame))
-                    })
-                    .unwrap();
-
-                Ok(gst::FlowSuccess::Ok)
+        let app_weak = app.as_weak();
+        let mut importer = None;
+        app.window()
+            .set_rendering_notifier(move |state, graphics_api| match state {
+                slint::RenderingState::RenderingSetup => {
+                    // The pipeline is started once the GL context of the renderer is shared with it.
+                    match slint::gstreamer::VideoTextureImporter::new(graphics_api, &pipeline) {
+                        Ok(new_importer) => {
+                            importer = Some(new_importer);
+                            pipeline
+                                .set_state(gst::State::Playing)
+                                .expect("Unable to set the pipeline to the `Playing` state");
+                        }
+                        Err(err) => {
+                            eprintln!("Cannot show the video: {err}");
+                            slint::quit_event_loop().ok();
+                        }
+                    }
+                }
+                slint::RenderingState::BeforeRendering => {
+                    let (Some(importer), Some(app)) = (importer.as_mut(), app_weak.upgrade())
+                    else {
+                        return;
+                    };
+                    let Some(sample) = appsink.try_pull_sample(gst::ClockTime::ZERO) else {
+                        return;
+                    };
+                    match importer.import_sample(&sample) {
+                        Ok(frame) => app.set_video_frame(frame),
+                        Err(err) => eprintln!("Cannot show the video frame: {err}"),
+                    }
+                }
+                slint::RenderingState::RenderingTeardown => importer = None,
+                _ => {}
             })
-            .build(),
-    );
+            .expect("Unable to set rendering notifier");
+    }
+
+    #[cfg(not(feature = "gst-gl"))]
+    {
+        let app_weak = app.as_weak();
+        let width: u32 = 1024;
+        let height: u32 = 1024;
+
+        let appsink = gst_app::AppSink::builder()
+            .caps(
+                &gst_video::VideoCapsBuilder::new()
+                    .format(gst_video::VideoFormat::Rgb)
+                    .width(width as i32)
+                    .height(height as i32)
+                    .build(),
+            )
+            .build();
+
+        pipeline.add_many([&source, &appsink.upcast_ref()]).unwrap();
+        source.link(&appsink).expect("Elements could not be linked.");
+
+        appsink.set_callbacks(
+            gst_app::AppSinkCallbacks::builder()
+                .new_sample(move |appsink| {
+                    let sample = appsink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                    let buffer = sample.buffer_owned().unwrap(); // Probably copies!
+                    let video_info =
+                        gst_video::VideoInfo::builder(gst_video::VideoFormat::Rgb, width, height)
+                            .build()
+                            .expect("couldn't build video info!");
+                    let video_frame =
+                        gst_video::VideoFrame::from_buffer_readable(buffer, &video_info).unwrap();
+                    let slint_frame = try_gstreamer_video_frame_to_pixel_buffer(&video_frame)
+                        .expect("Unable to convert the video frame to a slint video frame!");
+
+                    app_weak
+                        .upgrade_in_event_loop(|app| {
+                            app.set_video_frame(slint::Image::from_rgb8(slint_frame))
+                        })
+                        .unwrap();
 
-    pipeline
-        .set_state(gst::State::Playing)
-        .expect("Unable to set the pipeline to the `Playing` state");
+                    Ok(gst::FlowSuccess::Ok)
+                })
+                .build(),
+        );
+
+        pipeline
+            .set_state(gst::State::Playing)
+            .expect("Unable to set the pipeline to the `Playing` state");
+    }
 
     app.run().unwrap();
 }
@@ -215,6 +215,8 @@ pub fn process_event(&self, event: &PollEvent<'_>) -> Result<ControlFlow<()>, Pl
                             .try_dispatch_event(WindowEvent::ScaleFactorChanged { scale_factor })?;
                     }
 
+                    self.dispatch_system_font()?;
+
                     self.renderer.set_window_handle(
                         Rc::new(w),
                         Rc::new(raw_window_handle::DisplayHandle::android()),
@@ -254,6 +256,7 @@ pub fn process_event(&self, event: &PollEvent<'_>) -> Result<ControlFlow<()>, Pl
                         size: self.size().to_logical(scale_factor),
                     })?;
                 }
+                self.dispatch_system_font()?;
             }
             PollEvent::Main(MainEvent::Destroy) => {
                 return Ok(ControlFlow::Break(()));
@@ -407,13 +410,39 @@ fn resize(&self) -> Result<(), PlatformError> {
             self.java_helper.get_view_rect().unwrap_or_else(|e| print_jni_error(&self.app, e))
         };
 
-        self.window.try_dispatch_event(WindowEvent::Resized {
-            size: size.to_logical(self.window.scale_factor()),
-        })?;
+        let scale_factor = self.window.scale_factor();
+        self.window
+            .try_dispatch_event(WindowEvent::Resized { size: size.to_logical(scale_factor) })?;
         self.offset.set(offset);
+
+        // The soft keyboard covers the bottom of the native window. Only the inset of the keyboard is
+        // used, so that the navigation bar doesn't count as a keyboard when it's hidden. Report it relative to the Slint view, even if it lies outside of it when the view is resized
+        // to the visible frame, so that the focused item stays visible.
+        let keyboard_height = self
+            .java_helper
+            .get_keyboard_height()
+            .unwrap_or_else(|e| print_jni_error(&self.app, e));
+        let keyboard_size = PhysicalSize { width: win.width() as u32, height: keyboard_height };
+        let keyboard_top = win.height() - keyboard_height as i32;
+        self.window.try_dispatch_event(WindowEvent::VirtualKeyboardChanged {
+            position: PhysicalPosition::new(-offset.x, keyboard_top - offset.y)
+                .to_logical(scale_factor),
+            size: keyboard_size.to_logical(scale_factor),
+        })?;
         Ok(())
     }
 
+    /// Applies the font size chosen in the accessibility settings. The renderer keeps choosing
+    /// the default family, which is the system font on Android.
+    fn dispatch_system_font(&self) -> Result<(), PlatformError> {
+        let text_scale_factor =
+            self.java_helper.font_scale().unwrap_or_else(|e| print_jni_error(&self.app, e));
+        self.window.try_dispatch_event(WindowEvent::SystemFontChanged {
+            family: Default::default(),
+            text_scale_factor: if text_scale_factor > 0. { text_scale_factor } else { 1. },
+        })
+    }
+
     pub fn do_render(&self) -> Result<(), PlatformError> {
         if let Some(win) = self.app.native_window() {
             let o = self.offset.get();
@@ -557,8 +586,8 @@ fn map_key_code(code: android_activity::input::Keycode) -> Option<SharedString>
         Keycode::DpadLeft => Some(Key::LeftArrow.into()),
         Keycode::DpadRight => Some(Key::RightArrow.into()),
         Keycode::DpadCenter => Some(Key::Return.into()),
-        Keycode::VolumeUp => None,
-        Keycode::VolumeDown => None,
+        Keycode::VolumeUp => Some(Key::AudioVolumeUp.into()),
+        Keycode::VolumeDown => Some(Key::AudioVolumeDown.into()),
         Keycode::Power => None,
         Keycode::Camera => None,
         Keycode::Clear => None,
@@ -618,10 +647,10 @@ fn map_key_code(code: android_activity::input::Keycode) -> Option<SharedString>
         Keycode::Menu => Some(Key::Menu.into()),
         Keycode::Notification => None,
         Keycode::Search => None,
-        Keycode::MediaPlayPause => None,
-        Keycode::MediaStop => None,
-        Keycode::MediaNext => None,
-        Keycode::MediaPrevious => None,
+        Keycode::MediaPlayPause => Some(Key::MediaPlayPause.into()),
+        Keycode::MediaStop => Some(Key::MediaStop.into()),
+        Keycode::MediaNext => Some(Key::MediaTrackNext.into()),
+        Keycode::MediaPrevious => Some(Key::MediaTrackPrevious.into()),
         Keycode::MediaRewind => None,
         Keycode::MediaFastForward => None,
         Keycode::Mute => None,
@@ -659,7 +688,7 @@ fn map_key_code(code: android_activity::input::Keycode) -> Option<SharedString>
         Keycode::MoveEnd => Some(Key::End.into()),
         Keycode::Insert => Some(Key::Insert.into()),
         Keycode::Forward => None,
-        Keycode::MediaPlay => None,
+        Keycode::MediaPlay => Some(Key::MediaPlayPause.into()),
         Keycode::MediaPause => None,
         Keycode::MediaClose => None,
         Keycode::MediaEject => None,
@@ -697,7 +726,7 @@ fn map_key_code(code: android_activity::input::Keycode) -> Option<SharedString>
         Keycode::NumpadEquals => Some("=".into()),
         Keycode::NumpadLeftParen => Some("(".into()),
         Keycode::NumpadRightParen => Some(")".into()),
-        Keycode::VolumeMute => None,
+        Keycode::VolumeMute => Some(Key::AudioVolumeMute.into()),
         Keycode::Info => None,
         Keycode::ChannelUp => None,
         Keycode::ChannelDown => None,
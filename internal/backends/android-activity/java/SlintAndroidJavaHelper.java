@@ -472,6 +472,11 @@ public class SlintAndroidJavaHelper {
         return nightModeFlags;
     }
 
+    // The factor by which the user enlarged the fonts in the accessibility settings
+    public float font_scale() {
+        return mActivity.getResources().getConfiguration().fontScale;
+    }
+
     // Get the geometry of the view minus the system bars and the keyboard
     public Rect get_view_rect() {
         Rect rect = new Rect();
@@ -490,6 +495,19 @@ public class SlintAndroidJavaHelper {
         return rect;
     }
 
+    // Get the height of the bottom inset of the soft keyboard, or 0 when it's hidden
+    public int get_keyboard_height() {
+        WindowInsets insets = mActivity.getWindow().getDecorView().getRootView().getRootWindowInsets();
+        if (insets == null) {
+            return 0;
+        }
+        if (android.os.Build.VERSION.SDK_INT >= 30) {
+            return insets.getInsets(WindowInsets.Type.ime()).bottom;
+        }
+        // The stable insets only contain the system bars, so the rest of the bottom inset is the keyboard
+        return Math.max(insets.getSystemWindowInsetBottom() - insets.getStableInsetBottom(), 0);
+    }
+
     public void show_action_menu() {
         mActivity.runOnUiThread(new Runnable() {
             @Override
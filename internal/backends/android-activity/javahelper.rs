@@ -271,6 +271,12 @@ pub fn color_scheme(&self) -> Result<i32, jni::errors::Error> {
         })
     }
 
+    pub fn font_scale(&self) -> Result<f32, jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            Ok(env.call_method(helper, "font_scale", "()F", &[])?.f()?)
+        })
+    }
+
     pub fn get_view_rect(&self) -> Result<(PhysicalPosition, PhysicalSize), jni::errors::Error> {
         self.with_jni_env(|env, helper| {
             let rect =
@@ -284,6 +290,12 @@ pub fn get_view_rect(&self) -> Result<(PhysicalPosition, PhysicalSize), jni::err
         })
     }
 
+    pub fn get_keyboard_height(&self) -> Result<u32, jni::errors::Error> {
+        self.with_jni_env(|env, helper| {
+            Ok(env.call_method(helper, "get_keyboard_height", "()I", &[])?.i()?.max(0) as u32)
+        })
+    }
+
     pub fn set_handle_color(&self, color: Color) -> Result<(), jni::errors::Error> {
         self.with_jni_env(|env, helper| {
             env.call_method(
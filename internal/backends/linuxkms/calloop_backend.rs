@@ -264,7 +264,13 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
                 adapter.clone().render_if_needed(mouse_position_property.as_ref())?;
             };
 
-            let next_timeout = i_slint_core::platform::duration_until_next_timer_update();
+            let mut next_timeout = i_slint_core::platform::duration_until_next_timer_update();
+            // A zero timeout means that a timer or an animation is due, otherwise the loop is idle
+            if next_timeout != Some(std::time::Duration::ZERO)
+                && i_slint_core::platform::run_idle_callbacks()
+            {
+                next_timeout = Some(std::time::Duration::ZERO);
+            }
             event_loop
                 .dispatch(next_timeout, &mut loop_data)
                 .map_err(|e| format!("Error dispatch events: {e}"))?;
@@ -4,7 +4,6 @@
 //! This module contains the code to receive input events from libinput
 
 use std::cell::RefCell;
-#[cfg(feature = "libseat")]
 use std::collections::HashMap;
 #[cfg(not(feature = "libseat"))]
 use std::fs::{File, OpenOptions};
@@ -18,13 +17,14 @@
 use std::rc::Rc;
 
 use i_slint_core::api::LogicalPosition;
-use i_slint_core::platform::{PlatformError, PointerEventButton, WindowEvent};
+use i_slint_core::platform::{PlatformError, PointerEventButton, PointerScrollDevice, WindowEvent};
 use i_slint_core::window::WindowAdapter;
 use i_slint_core::{Property, SharedString};
 use input::LibinputInterface;
 
 use input::event::keyboard::{KeyState, KeyboardEventTrait};
-use input::event::touch::TouchEventPosition;
+use input::event::pointer::{Axis, PointerScrollEvent};
+use input::event::touch::{TouchEventPosition, TouchEventSlot};
 use xkbcommon::*;
 
 use crate::fullscreenwindowadapter::FullscreenWindowAdapter;
@@ -111,11 +111,46 @@ fn close_restricted(&mut self, fd: OwnedFd) {
     }
 }
 
+/// The number of pixels scrolled by one mouse wheel tick, as in the other backends
+const SCROLL_LINE_HEIGHT: f32 = 60.;
+
+/// Returns the pixel deltas of a finger or continuous scroll event, positive when scrolling up or left.
+fn scroll_pixels(scroll_event: &impl PointerScrollEvent) -> (f32, f32) {
+    let pixels = |axis| {
+        if scroll_event.has_axis(axis) {
+            -scroll_event.scroll_value(axis) as f32
+        } else {
+            0.
+        }
+    };
+    (pixels(Axis::Horizontal), pixels(Axis::Vertical))
+}
+
+fn dispatch_scroll(
+    window: &i_slint_core::api::Window,
+    position: LogicalPosition,
+    device: PointerScrollDevice,
+    delta_x: f32,
+    delta_y: f32,
+) -> Result<(), std::io::Error> {
+    window
+        .try_dispatch_event(WindowEvent::ScrollDetailsChanged {
+            device,
+            line_delta_x: delta_x / SCROLL_LINE_HEIGHT,
+            line_delta_y: delta_y / SCROLL_LINE_HEIGHT,
+        })
+        .and_then(|_| {
+            window.try_dispatch_event(WindowEvent::PointerScrolled { position, delta_x, delta_y })
+        })
+        .map_err(std::io::Error::other)
+}
+
 pub struct LibInputHandler<'a> {
     libinput: input::Libinput,
     token: Option<calloop::Token>,
     mouse_pos: Pin<Rc<Property<Option<LogicalPosition>>>>,
-    last_touch_pos: LogicalPosition,
+    /// The last position of each finger, by seat slot
+    last_touch_pos: HashMap<u32, LogicalPosition>,
     window: &'a RefCell<Option<Rc<FullscreenWindowAdapter>>>,
     keystate: Option<xkb::State>,
 }
@@ -228,37 +263,85 @@ fn process_events<F>(
                             };
                             window.try_dispatch_event(event).map_err(Self::Error::other)?;
                         }
-                        input::event::PointerEvent::ScrollWheel(_) => todo!(),
-                        input::event::PointerEvent::ScrollFinger(_) => todo!(),
-                        input::event::PointerEvent::ScrollContinuous(_) => todo!(),
+                        input::event::PointerEvent::ScrollWheel(scroll_event) => {
+                            // libinput reports wheel ticks in 120ths, and positive values scroll down
+                            let lines = |axis| {
+                                if scroll_event.has_axis(axis) {
+                                    -scroll_event.scroll_value_v120(axis) as f32 / 120.
+                                } else {
+                                    0.
+                                }
+                            };
+                            let (line_delta_x, line_delta_y) =
+                                (lines(Axis::Horizontal), lines(Axis::Vertical));
+                            dispatch_scroll(
+                                window,
+                                self.mouse_pos.as_ref().get().unwrap_or_default(),
+                                PointerScrollDevice::Wheel,
+                                line_delta_x * SCROLL_LINE_HEIGHT,
+                                line_delta_y * SCROLL_LINE_HEIGHT,
+                            )?;
+                        }
+                        input::event::PointerEvent::ScrollFinger(scroll_event) => {
+                            let (delta_x, delta_y) = scroll_pixels(&scroll_event);
+                            dispatch_scroll(
+                                window,
+                                self.mouse_pos.as_ref().get().unwrap_or_default(),
+                                PointerScrollDevice::Touchpad,
+                                delta_x,
+                                delta_y,
+                            )?;
+                        }
+                        input::event::PointerEvent::ScrollContinuous(scroll_event) => {
+                            let (delta_x, delta_y) = scroll_pixels(&scroll_event);
+                            dispatch_scroll(
+                                window,
+                                self.mouse_pos.as_ref().get().unwrap_or_default(),
+                                PointerScrollDevice::Unknown,
+                                delta_x,
+                                delta_y,
+                            )?;
+                        }
                         _ => {}
                     }
                 }
                 input::Event::Touch(touch_event) => {
-                    if let Some(event) = match touch_event {
+                    // The seat slot identifies the finger across all the touch devices of the seat
+                    let events = match touch_event {
                         input::event::TouchEvent::Down(touch_down_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            let id = touch_down_event.seat_slot();
+                            let position = LogicalPosition::new(
                                 touch_down_event.x_transformed(screen_size.width as u32) as _,
                                 touch_down_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerPressed {
-                                position: self.last_touch_pos,
-                                button: PointerEventButton::Left,
-                            })
+                            self.last_touch_pos.insert(id, position);
+                            vec![WindowEvent::TouchPressed { id: id.into(), position }]
+                        }
+                        input::event::TouchEvent::Up(touch_up_event) => {
+                            let id = touch_up_event.seat_slot();
+                            let position = self.last_touch_pos.remove(&id).unwrap_or_default();
+                            vec![WindowEvent::TouchReleased { id: id.into(), position }]
                         }
-                        input::event::TouchEvent::Up(..) => Some(WindowEvent::PointerReleased {
-                            position: self.last_touch_pos,
-                            button: PointerEventButton::Left,
-                        }),
                         input::event::TouchEvent::Motion(touch_motion_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            let id = touch_motion_event.seat_slot();
+                            let position = LogicalPosition::new(
                                 touch_motion_event.x_transformed(screen_size.width as u32) as _,
                                 touch_motion_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerMoved { position: self.last_touch_pos })
+                            self.last_touch_pos.insert(id, position);
+                            vec![WindowEvent::TouchMoved { id: id.into(), position }]
                         }
-                        _ => None,
-                    } {
+                        input::event::TouchEvent::Cancel(..) => self
+                            .last_touch_pos
+                            .drain()
+                            .map(|(id, position)| WindowEvent::TouchReleased {
+                                id: id.into(),
+                                position,
+                            })
+                            .collect(),
+                        _ => Vec::new(),
+                    };
+                    for event in events {
                         window.try_dispatch_event(event).map_err(Self::Error::other)?;
                     }
                 }
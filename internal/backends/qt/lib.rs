@@ -300,6 +300,116 @@ fn clipboard_text(&self, _clipboard: i_slint_core::platform::Clipboard) -> Optio
         None
     }
 
+    #[cfg(not(no_qt))]
+    fn set_clipboard_image(
+        &self,
+        image: &i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        use cpp::cpp;
+        let is_selection: bool = match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => false,
+            i_slint_core::platform::Clipboard::SelectionClipboard => true,
+            _ => return,
+        };
+        let (width, height) = (image.width(), image.height());
+        let data = image.as_bytes().as_ptr();
+        cpp! {unsafe [data as "const uchar*", width as "uint32_t", height as "uint32_t", is_selection as "bool"] {
+            ensure_initialized();
+            if (is_selection && !QGuiApplication::clipboard()->supportsSelection())
+                return;
+            // The QImage doesn't own the pixels, so copy them
+            QImage image(data, width, height, width * 4, QImage::Format_RGBA8888);
+            QGuiApplication::clipboard()->setImage(image.copy(), is_selection ? QClipboard::Selection : QClipboard::Clipboard);
+        } }
+    }
+
+    #[cfg(not(no_qt))]
+    fn clipboard_image(
+        &self,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) -> Option<i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>> {
+        use cpp::cpp;
+        let is_selection: bool = match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => false,
+            i_slint_core::platform::Clipboard::SelectionClipboard => true,
+            _ => return None,
+        };
+        let size = cpp! {unsafe [is_selection as "bool"] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+            if (is_selection && !QGuiApplication::clipboard()->supportsSelection())
+                return QSize();
+            return QGuiApplication::clipboard()->image(is_selection ? QClipboard::Selection : QClipboard::Clipboard).size();
+        } };
+        if size.width <= 0 || size.height <= 0 {
+            return None;
+        }
+        let (width, height) = (size.width as u32, size.height as u32);
+        let mut buffer = i_slint_core::graphics::SharedPixelBuffer::<
+            i_slint_core::graphics::Rgba8Pixel,
+        >::new(width, height);
+        let data = buffer.make_mut_bytes().as_mut_ptr();
+        let copied = cpp! {unsafe [is_selection as "bool", data as "uchar*", width as "uint32_t", height as "uint32_t"] -> bool as "bool" {
+            QImage image = QGuiApplication::clipboard()->image(is_selection ? QClipboard::Selection : QClipboard::Clipboard)
+                .convertToFormat(QImage::Format_RGBA8888);
+            if (image.size() != QSize(width, height))
+                return false;
+            for (uint32_t y = 0; y < height; ++y)
+                memcpy(data + y * width * 4, image.constScanLine(y), width * 4);
+            return true;
+        } };
+        copied.then_some(buffer)
+    }
+
+    #[cfg(not(no_qt))]
+    fn set_clipboard_html(
+        &self,
+        html: &str,
+        alt_text: &str,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        use cpp::cpp;
+        let is_selection: bool = match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => false,
+            i_slint_core::platform::Clipboard::SelectionClipboard => true,
+            _ => return,
+        };
+        let html: qttypes::QString = html.into();
+        let alt_text: qttypes::QString = alt_text.into();
+        cpp! {unsafe [html as "QString", alt_text as "QString", is_selection as "bool"] {
+            ensure_initialized();
+            if (is_selection && !QGuiApplication::clipboard()->supportsSelection())
+                return;
+            auto mime_data = new QMimeData;
+            mime_data->setHtml(html);
+            mime_data->setText(alt_text);
+            QGuiApplication::clipboard()->setMimeData(mime_data, is_selection ? QClipboard::Selection : QClipboard::Clipboard);
+        } }
+    }
+
+    #[cfg(not(no_qt))]
+    fn clipboard_html(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
+        use cpp::cpp;
+        let is_selection: bool = match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => false,
+            i_slint_core::platform::Clipboard::SelectionClipboard => true,
+            _ => return None,
+        };
+        let has_html = cpp! {unsafe [is_selection as "bool"] -> bool as "bool" {
+            ensure_initialized();
+            if (is_selection && !QGuiApplication::clipboard()->supportsSelection())
+                return false;
+            auto mime_data = QGuiApplication::clipboard()->mimeData(is_selection ? QClipboard::Selection : QClipboard::Clipboard);
+            return mime_data && mime_data->hasHtml();
+        } };
+        has_html.then(|| {
+            cpp! { unsafe [is_selection as "bool"] -> qttypes::QString as "QString" {
+                return QGuiApplication::clipboard()->mimeData(is_selection ? QClipboard::Selection : QClipboard::Clipboard)->html();
+            }}
+            .into()
+        })
+    }
+
     #[cfg(not(no_qt))]
     fn click_interval(&self) -> core::time::Duration {
         let duration_ms = unsafe {
@@ -42,6 +42,7 @@ pub struct NativePalette {
     pub selection_foreground: Property<Brush>,
     pub border: Property<Brush>,
     pub color_scheme: Property<ColorScheme>,
+    pub system_accent_color: Property<Color>,
     pub style_change_listener: core::cell::Cell<*const u8>,
 }
 
@@ -66,6 +67,7 @@ pub fn new() -> Pin<Rc<Self>> {
             selection_background: Default::default(),
             selection_foreground: Default::default(),
             color_scheme: Default::default(),
+            system_accent_color: Default::default(),
             style_change_listener: core::cell::Cell::new(core::ptr::null()),
         })
     }
@@ -155,6 +157,14 @@ fn init_impl(self: Pin<&Self>) {
         let selection_foreground = Color::from_argb_encoded(selection_foreground);
         self.selection_foreground.set(Brush::from(selection_foreground));
 
+        self.system_accent_color.set(Color::from_argb_encoded(cpp!(unsafe[] -> u32 as "QRgb" {
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 6, 0)
+                return qApp->palette().color(QPalette::Accent).rgba();
+            #else
+                return qApp->palette().color(QPalette::Highlight).rgba();
+            #endif
+        })));
+
         self.color_scheme.set(
             if (background.red() as u32 + background.green() as u32 + background.blue() as u32) / 3
                 < 128
@@ -18,7 +18,8 @@
 use i_slint_core::item_tree::{ItemTreeRc, ItemTreeRef};
 use i_slint_core::items::{
     self, ColorScheme, FillRule, ImageRendering, ItemRc, ItemRef, Layer, MouseCursor, Opacity,
-    PointerEventButton, PopupClosePolicy, RenderingResult, TextOverflow, TextStrokeStyle, TextWrap,
+    PointerEventButton, PointerScrollDevice, PointerToolType, PopupClosePolicy, RenderingResult,
+    TextOverflow, TextStrokeStyle, TextWrap,
 };
 use i_slint_core::layout::Orientation;
 use i_slint_core::lengths::{
@@ -53,6 +54,8 @@
     #include <QtGui/QTextLayout>
     #include <QtGui/QImageReader>
     #include <QtGui/QCursor>
+    #include <QtGui/QDrag>
+    #include <QtCore/QMimeData>
     #include <QtCore/QBasicTimer>
     #include <QtCore/QTimer>
     #include <QtCore/QPointer>
@@ -100,6 +103,7 @@ struct SlintWidget : QWidget {
             // to draw the window background which is set on the palette.
             // (But the window background might not be opaque)
             setAttribute(Qt::WA_NoSystemBackground, false);
+            setAcceptDrops(true);
         }
 
         void paintEvent(QPaintEvent *) override {
@@ -200,10 +204,19 @@ struct SlintWidget : QWidget {
                 return;
             QPointF pos = event->position();
             QPoint delta = event->pixelDelta();
-            if (delta.isNull()) {
+            // Touchpads report pixel deltas, wheels only report angles, in eighths of a degree with 120 per tick
+            bool is_wheel = delta.isNull();
+            QPointF line_delta = QPointF(delta) / 60.;
+            if (is_wheel) {
                 delta = event->angleDelta();
+                line_delta = QPointF(delta) / 120.;
             }
-            rust!(Slint_mouseWheelEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPointF as "QPointF", delta: qttypes::QPoint as "QPoint"] {
+            rust!(Slint_mouseWheelEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPointF as "QPointF", delta: qttypes::QPoint as "QPoint", line_delta: qttypes::QPointF as "QPointF", is_wheel: bool as "bool"] {
+                rust_window.window.dispatch_event(WindowEvent::ScrollDetailsChanged {
+                    device: if is_wheel { PointerScrollDevice::Wheel } else { PointerScrollDevice::Touchpad },
+                    line_delta_x: line_delta.x as _,
+                    line_delta_y: line_delta.y as _,
+                });
                 let position = LogicalPoint::new(pos.x as _, pos.y as _);
                 rust_window.mouse_event(MouseEvent::Wheel{position, delta_x: delta.x as _, delta_y: delta.y as _})
             });
@@ -215,6 +228,85 @@ struct SlintWidget : QWidget {
                 rust_window.mouse_event(MouseEvent::Exit)
             });
         }
+        void dragEnterEvent(QDragEnterEvent *event) override {
+            dragMoveEvent(event);
+        }
+        void dragMoveEvent(QDragMoveEvent *event) override {
+            dispatchDragEvent(event, false);
+        }
+        void dropEvent(QDropEvent *event) override {
+            dispatchDragEvent(event, true);
+        }
+        void dragLeaveEvent(QDragLeaveEvent *) override {
+            if (!rust_window)
+                return;
+            rust!(Slint_dragLeaveEvent [rust_window: &QtWindow as "void*"] {
+                rust_window.window.dispatch_event(WindowEvent::DragExited)
+            });
+        }
+        void dispatchDragEvent(QDropEvent *event, bool dropped) {
+            if (!rust_window)
+                return;
+            const QMimeData *mime_data = event->mimeData();
+            // Prefer the types that DropArea elements usually accept
+            QString mime_type = mime_data->hasUrls() ? QStringLiteral("text/uri-list")
+                : mime_data->hasText() ? QStringLiteral("text/plain")
+                : mime_data->formats().value(0);
+            QString data = mime_type == QStringLiteral("text/plain") ? mime_data->text()
+                : QString::fromUtf8(mime_data->data(mime_type));
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            QPointF pos = event->position();
+            #else
+            QPointF pos = event->posF();
+            #endif
+            rust!(Slint_dragEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPointF as "QPointF", mime_type: qttypes::QString as "QString", data: qttypes::QString as "QString", dropped: bool as "bool"] {
+                let position = i_slint_core::api::LogicalPosition::new(pos.x as _, pos.y as _);
+                let (mime_type, data) = (mime_type.to_string().into(), data.to_string().into());
+                rust_window.window.dispatch_event(if dropped {
+                    WindowEvent::Dropped { position, mime_type, data }
+                } else {
+                    WindowEvent::DragMoved { position, mime_type, data }
+                })
+            });
+            event->acceptProposedAction();
+        }
+        void startDrag(QMimeData *mime_data) {
+            // QDrag::exec() runs a nested event loop, so start it once the current event is
+            // processed rather than from within the Slint callback that started the drag
+            QPointer<SlintWidget> self = this;
+            QTimer::singleShot(0, this, [self, mime_data] {
+                // The drag takes ownership of the mime data, and is deleted by Qt when it ends
+                auto drag = new QDrag(self.data());
+                drag->setMimeData(mime_data);
+                drag->exec(Qt::CopyAction | Qt::MoveAction | Qt::LinkAction, Qt::CopyAction);
+                if (!self || !self->rust_window)
+                    return;
+                // The drag consumed the release of the pointer: cancel the press of the items
+                // instead of clicking them
+                void *rust_window = self->rust_window;
+                rust!(Slint_dragFinished [rust_window: &QtWindow as "void*"] {
+                    rust_window.mouse_event(MouseEvent::Exit)
+                });
+            });
+        }
+        void tabletEvent(QTabletEvent *event) override {
+            // Ignore the event so that Qt synthesizes the mouse event that follows the details
+            event->ignore();
+            if (!rust_window)
+                return;
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            bool eraser = event->pointerType() == QPointingDevice::PointerType::Eraser;
+            #else
+            bool eraser = event->pointerType() == QTabletEvent::Eraser;
+            #endif
+            float pressure = event->pressure();
+            float tilt_x = event->xTilt();
+            float tilt_y = event->yTilt();
+            float twist = event->rotation();
+            rust!(Slint_tabletEvent [rust_window: &QtWindow as "void*", eraser: bool as "bool", pressure: f32 as "float", tilt_x: f32 as "float", tilt_y: f32 as "float", twist: f32 as "float"] {
+                rust_window.window.dispatch_event(tablet_pointer_details(eraser, pressure, tilt_x, tilt_y, twist));
+            });
+        }
 
         void keyPressEvent(QKeyEvent *event) override {
             if (!rust_window)
@@ -222,8 +314,9 @@ struct SlintWidget : QWidget {
             QString text =  event->text();
             int key = event->key();
             bool repeat = event->isAutoRepeat();
-            rust!(Slint_keyPress [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString", repeat: bool as "bool"] {
-                rust_window.key_event(key, text.clone(), false, repeat);
+            quint32 scancode = event->nativeScanCode();
+            rust!(Slint_keyPress [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString", repeat: bool as "bool", scancode: u32 as "quint32"] {
+                rust_window.key_event(key, text.clone(), false, repeat, scancode);
             });
         }
         void keyReleaseEvent(QKeyEvent *event) override {
@@ -236,8 +329,9 @@ struct SlintWidget : QWidget {
 
             QString text =  event->text();
             int key = event->key();
-            rust!(Slint_keyRelease [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString"] {
-                rust_window.key_event(key, text.clone(), true, false);
+            quint32 scancode = event->nativeScanCode();
+            rust!(Slint_keyRelease [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString", scancode: u32 as "quint32"] {
+                rust_window.key_event(key, text.clone(), true, false, scancode);
             });
         }
 
@@ -260,6 +354,9 @@ struct SlintWidget : QWidget {
                             ColorScheme::Light
                         });
                     }
+                    if let Some(accent_color) = rust_window.accent_color.get() {
+                        accent_color.as_ref().set(system_accent_color());
+                    }
                 });
             }
 
@@ -277,6 +374,15 @@ struct SlintWidget : QWidget {
             QWidget::changeEvent(event);
         }
 
+        bool event(QEvent *event) override {
+            if (rust_window && event->type() == QEvent::KeyboardLayoutChange) {
+                rust!(Slint_keyboardLayoutChange [rust_window: &QtWindow as "void*"] {
+                    rust_window.window.dispatch_event(WindowEvent::KeyboardLayoutChanged);
+                });
+            }
+            return QWidget::event(event);
+        }
+
         void closeEvent(QCloseEvent *event) override {
             if (!rust_window)
                 return;
@@ -333,17 +439,26 @@ struct SlintWidget : QWidget {
             int replacement_length = qMax(0, event->replacementLength());
             ime_text.mid(ime_cursor + replacement_start, replacement_length).toUtf8().size();
             int preedit_cursor = -1;
+            // The clause that is being converted is the one that the input method highlights
+            int preedit_clause_start = -1;
+            int preedit_clause_end = -1;
             for (const QInputMethodEvent::Attribute &attribute: event->attributes()) {
                 if (attribute.type == QInputMethodEvent::Cursor) {
                     if (attribute.length > 0) {
                         preedit_cursor = QStringView(preedit_string).left(attribute.start).toUtf8().size();
                     }
+                } else if (attribute.type == QInputMethodEvent::TextFormat && attribute.length > 0) {
+                    QTextCharFormat format = attribute.value.value<QTextFormat>().toCharFormat();
+                    if (format.background().style() != Qt::NoBrush) {
+                        preedit_clause_start = QStringView(preedit_string).left(attribute.start).toUtf8().size();
+                        preedit_clause_end = QStringView(preedit_string).left(attribute.start + attribute.length).toUtf8().size();
+                    }
                 }
             }
             event->accept();
             rust!(Slint_inputMethodEvent [rust_window: &QtWindow as "void*", commit_string: qttypes::QString as "QString",
                 preedit_string: qttypes::QString as "QString", replacement_start: i32 as "int", replacement_length: i32 as "int",
-                preedit_cursor: i32 as "int"] {
+                preedit_cursor: i32 as "int", preedit_clause_start: i32 as "int", preedit_clause_end: i32 as "int"] {
                     let runtime_window = WindowInner::from_pub(&rust_window.window);
 
                     let event = KeyEvent {
@@ -351,6 +466,7 @@ struct SlintWidget : QWidget {
                         text: i_slint_core::format!("{}", commit_string),
                         preedit_text: i_slint_core::format!("{}", preedit_string),
                         preedit_selection: (preedit_cursor >= 0).then_some(preedit_cursor..preedit_cursor),
+                        preedit_clause: (preedit_clause_start >= 0).then_some(preedit_clause_start..preedit_clause_end),
                         replacement_range: Some(replacement_start..replacement_start+replacement_length),
                         ..Default::default()
                     };
@@ -588,6 +704,53 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
                 return QBrush(qrg);
             }}
         }
+        i_slint_core::Brush::ConicGradient(g) => {
+            cpp_class!(unsafe struct QConicalGradient as "QConicalGradient");
+            // QConicalGradient goes counter-clockwise from the right, so the stops are reversed
+            let start_angle = (90. - g.from_angle()) as qttypes::qreal;
+            let mut qcg = cpp! {
+                unsafe [width as "qreal", height as "qreal", start_angle as "qreal"] -> QConicalGradient as "QConicalGradient" {
+                    return QConicalGradient(width / 2, height / 2, start_angle);
+                }
+            };
+            let count = g.stops().count();
+            for (idx, s) in g.stops().enumerate() {
+                let pos: f32 = 1. - mangle_position(s.position, idx, count);
+                let color: u32 = s.color.as_argb_encoded();
+                cpp! {unsafe [mut qcg as "QConicalGradient", pos as "float", color as "QRgb"] {
+                    qcg.setColorAt(pos, QColor::fromRgba(color));
+                }};
+            }
+            cpp! {unsafe [qcg as "QConicalGradient"] -> qttypes::QBrush as "QBrush" {
+                return QBrush(qcg);
+            }}
+        }
+        i_slint_core::Brush::ImagePattern(pattern) => {
+            let image_inner: &ImageInner = pattern.image().into();
+            let Some(pixmap) = image_to_pixmap(image_inner, None) else {
+                return qttypes::QBrush::default();
+            };
+            // Qt always repeats the texture of a brush, so the repeat options are ignored
+            let t = pattern.transform().cast::<qttypes::qreal>();
+            let (m11, m12, m21, m22, dx, dy) = (t.m11, t.m12, t.m21, t.m22, t.m31, t.m32);
+            let opacity = pattern.opacity() as qttypes::qreal;
+            cpp! {unsafe [pixmap as "QPixmap", m11 as "qreal", m12 as "qreal", m21 as "qreal", m22 as "qreal", dx as "qreal", dy as "qreal", opacity as "qreal"] -> qttypes::QBrush as "QBrush" {
+                QBrush brush;
+                if (opacity < 1) {
+                    QPixmap translucent(pixmap.size());
+                    translucent.fill(Qt::transparent);
+                    QPainter painter(&translucent);
+                    painter.setOpacity(opacity);
+                    painter.drawPixmap(0, 0, pixmap);
+                    painter.end();
+                    brush.setTexture(translucent);
+                } else {
+                    brush.setTexture(pixmap);
+                }
+                brush.setTransform(QTransform(m11, m12, m21, m22, dx, dy));
+                return brush;
+            }}
+        }
         _ => qttypes::QBrush::default(),
     }
 }
@@ -694,6 +857,9 @@ fn draw_text(
         let wrap = text.wrap() != TextWrap::NoWrap;
         let word_wrap = text.wrap() == TextWrap::WordWrap;
         let elide = text.overflow() == TextOverflow::Elide;
+        // 0 means no limit
+        let max_lines: i32 =
+            text.max_lines().map_or(0, |max_lines| max_lines.try_into().unwrap_or(i32::MAX));
         let (stroke_brush, stroke_width, stroke_style) = text.stroke();
         let stroke_visible = !stroke_brush.is_transparent();
         let stroke_brush: qttypes::QBrush = into_qbrush(stroke_brush, rect.width, rect.height);
@@ -703,11 +869,11 @@ fn draw_text(
             TextStrokeStyle::Center => stroke_width.get(),
         };
         let painter: &mut QPainterPtr = &mut self.painter;
-        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", stroke_brush as "QBrush", mut string as "QString", font as "QFont", elide as "bool", alignment as "Qt::Alignment", wrap as "bool", word_wrap as "bool", stroke_visible as "bool", stroke_outside as "bool", stroke_width as "float"] {
+        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", stroke_brush as "QBrush", mut string as "QString", font as "QFont", elide as "bool", max_lines as "int", alignment as "Qt::Alignment", wrap as "bool", word_wrap as "bool", stroke_visible as "bool", stroke_outside as "bool", stroke_width as "float"] {
             QString elided;
-            if (!elide) {
+            if (!elide && max_lines <= 0) {
                 elided = string;
-            } else if (!wrap) {
+            } else if (!wrap && max_lines <= 0) {
                 QFontMetrics fm(font);
                 while (!string.isEmpty()) {
                     int pos = string.indexOf('\n');
@@ -721,13 +887,15 @@ fn draw_text(
                     string = string.mid(pos + 1);
                 }
             } else {
-                // elide and word wrap: we need to add the ellipsis manually on the last line
+                // elide and word wrap, or a maximum number of lines: we need to add the ellipsis manually on the last line
                 string.replace(QChar('\n'), QChar::LineSeparator);
                 elided = string;
                 QFontMetrics fm(font);
                 QTextLayout layout(string, font);
                 QTextOption options;
-                if (word_wrap) {
+                if (!wrap) {
+                    options.setWrapMode(QTextOption::NoWrap);
+                } else if (word_wrap) {
                     options.setWrapMode(QTextOption::WordWrap);
                 } else {
                     options.setWrapMode(QTextOption::WrapAnywhere);
@@ -737,18 +905,22 @@ fn draw_text(
                 layout.beginLayout();
                 int leading = fm.leading();
                 qreal height = 0;
-                int last_line_begin = 0, last_line_size = 0;
+                int last_line_begin = 0, last_line_size = 0, line_count = 0;
                 while (true) {
                     auto line = layout.createLine();
                     if (!line.isValid()) {
                         last_line_begin = string.size();
                         break;
                     }
+                    if (max_lines > 0 && line_count >= max_lines) {
+                        break;
+                    }
                     line.setLineWidth(rect.width());
                     height += leading + line.height();
-                    if (height > rect.height()) {
+                    if (elide && height > rect.height()) {
                         break;
                     }
+                    line_count++;
                     last_line_begin = line.textStart();
                     last_line_size = line.textLength();
                 }
@@ -880,40 +1052,46 @@ fn draw_text_input(
         // convert byte offsets to offsets in Qt UTF-16 encoded string, as that's
         // what QTextLayout expects.
 
-        let (
-            selection_start_as_offset,
-            selection_end_as_offset,
-            selection_foreground_color,
-            selection_background_color,
-            underline_selection,
-        ): (usize, usize, u32, u32, bool) = if !visual_representation.preedit_range.is_empty() {
-            (
-                visual_representation.preedit_range.start,
-                visual_representation.preedit_range.end,
-                Color::default().as_argb_encoded(),
-                Color::default().as_argb_encoded(),
-                true,
-            )
-        } else {
-            (
-                visual_representation.selection_range.start,
-                visual_representation.selection_range.end,
-                text_input.selection_foreground_color().as_argb_encoded(),
-                text_input.selection_background_color().as_argb_encoded(),
-                false,
-            )
-        };
-
-        let selection_start_position: i32 = if selection_start_as_offset > 0 {
-            utf8_byte_offset_to_utf16_units(text.as_str(), selection_start_as_offset) as i32
+        // While composing, the clause that the input method converts is highlighted like a selection,
+        // and the whole composition is underlined.
+        let selection_range = if visual_representation.preedit_range.is_empty() {
+            visual_representation.selection_range.clone()
         } else {
-            0
+            visual_representation.preedit_clause_range.clone()
         };
-        let selection_end_position: i32 = if selection_end_as_offset > 0 {
-            utf8_byte_offset_to_utf16_units(text.as_str(), selection_end_as_offset) as i32
-        } else {
-            0
+        let selection_foreground_color: u32 =
+            text_input.selection_foreground_color().as_argb_encoded();
+        let selection_background_color: u32 =
+            text_input.selection_background_color().as_argb_encoded();
+
+        let to_utf16_offset = |byte_offset: usize| -> i32 {
+            if byte_offset > 0 {
+                utf8_byte_offset_to_utf16_units(text.as_str(), byte_offset) as i32
+            } else {
+                0
+            }
         };
+        let selection_start_position = to_utf16_offset(selection_range.start);
+        let selection_end_position = to_utf16_offset(selection_range.end);
+        let preedit_start_position = to_utf16_offset(visual_representation.preedit_range.start);
+        let preedit_end_position = to_utf16_offset(visual_representation.preedit_range.end);
+        // Each underline is passed as four values: start, length, color and whether it is wavy
+        let underlines: Vec<u32> = visual_representation
+            .underlines
+            .iter()
+            .flat_map(|(range, color, style)| {
+                let start = to_utf16_offset(range.start);
+                let end = to_utf16_offset(range.end);
+                [
+                    start as u32,
+                    (end - start) as u32,
+                    color.as_argb_encoded(),
+                    matches!(style, i_slint_core::items::TextUnderlineStyle::Wavy) as u32,
+                ]
+            })
+            .collect();
+        let underlines_ptr = underlines.as_ptr();
+        let underlines_len = underlines.len();
 
         let (text_cursor_width, cursor_position): (f32, i32) =
             if let Some(cursor_offset) = visual_representation.cursor_position {
@@ -934,13 +1112,16 @@ fn draw_text_input(
                 fill_brush as "QBrush",
                 selection_foreground_color as "QRgb",
                 selection_background_color as "QRgb",
-                underline_selection as "bool",
                 mut string as "QString",
                 flags as "int",
                 single_line as "bool",
                 font as "QFont",
                 selection_start_position as "int",
                 selection_end_position as "int",
+                preedit_start_position as "int",
+                preedit_end_position as "int",
+                underlines_ptr as "const uint32_t*",
+                underlines_len as "size_t",
                 cursor_position as "int",
                 text_cursor_width as "float"] {
             if (!single_line) {
@@ -950,6 +1131,15 @@ fn draw_text_input(
             do_text_layout(layout, flags, rect);
             (*painter)->setPen(QPen(fill_brush, 0));
             QVector<QTextLayout::FormatRange> selections;
+            if (preedit_end_position != preedit_start_position) {
+                QTextCharFormat fmt;
+                fmt.setFontUnderline(true);
+                selections << QTextLayout::FormatRange{
+                    preedit_start_position,
+                    preedit_end_position - preedit_start_position,
+                    fmt
+                };
+            }
             if (selection_end_position != selection_start_position) {
                 QTextCharFormat fmt;
                 if (qAlpha(selection_background_color) != 0) {
@@ -958,15 +1148,22 @@ fn draw_text_input(
                 if (qAlpha(selection_background_color) != 0) {
                     fmt.setForeground(QColor::fromRgba(selection_foreground_color));
                 }
-                if (underline_selection) {
-                    fmt.setFontUnderline(true);
-                }
                 selections << QTextLayout::FormatRange{
                     std::min(selection_end_position, selection_start_position),
                     std::abs(selection_end_position - selection_start_position),
                     fmt
                 };
             }
+            for (size_t i = 0; i + 3 < underlines_len; i += 4) {
+                QTextCharFormat fmt;
+                fmt.setUnderlineStyle(underlines_ptr[i + 3] ? QTextCharFormat::WaveUnderline : QTextCharFormat::SingleUnderline);
+                fmt.setUnderlineColor(QColor::fromRgba(underlines_ptr[i + 2]));
+                selections << QTextLayout::FormatRange{
+                    int(underlines_ptr[i]),
+                    int(underlines_ptr[i + 1]),
+                    fmt
+                };
+            }
             layout.draw(painter->get(), rect.topLeft(), selections);
             if (text_cursor_width > 0) {
                 layout.drawCursor(painter->get(), rect.topLeft(), cursor_position, text_cursor_width);
@@ -1631,6 +1828,7 @@ pub struct QtWindow {
     tree_structure_changed: RefCell<bool>,
 
     color_scheme: OnceCell<Pin<Box<Property<ColorScheme>>>>,
+    accent_color: OnceCell<Pin<Box<Property<Color>>>>,
 }
 
 impl Drop for QtWindow {
@@ -1665,6 +1863,7 @@ pub fn new() -> Rc<Self> {
                 cache: Default::default(),
                 tree_structure_changed: RefCell::new(false),
                 color_scheme: Default::default(),
+                accent_color: Default::default(),
             }
         });
         let widget_ptr = rc.widget_ptr();
@@ -1732,12 +1931,25 @@ fn mouse_event(&self, event: MouseEvent) {
         timer_event();
     }
 
-    fn key_event(&self, key: i32, text: qttypes::QString, released: bool, repeat: bool) {
+    fn key_event(
+        &self,
+        key: i32,
+        text: qttypes::QString,
+        released: bool,
+        repeat: bool,
+        scancode: u32,
+    ) {
         i_slint_core::animations::update_animations();
         let text: String = text.into();
 
         let text = qt_key_to_string(key as key_generated::Qt_Key, text);
 
+        // Qt doesn't know the W3C name of the physical key, only its native scan code
+        self.window.dispatch_event(WindowEvent::KeyDetailsChanged {
+            physical_key: Default::default(),
+            scancode,
+        });
+
         let event = if released {
             WindowEvent::KeyReleased { text }
         } else if repeat {
@@ -1908,7 +2120,9 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
         let Some(window_item) = WindowInner::from_pub(&self.window).window_item() else { return };
         let window_item = window_item.as_pin_ref();
         let no_frame = window_item.no_frame();
-        let always_on_top = window_item.always_on_top();
+        let window_level = properties.window_level();
+        let always_on_top = window_level == i_slint_core::items::WindowLevel::AlwaysOnTop;
+        let always_on_bottom = window_level == i_slint_core::items::WindowLevel::AlwaysOnBottom;
         let mut size = qttypes::QSize {
             width: window_item.width().get().ceil() as _,
             height: window_item.height().get().ceil() as _,
@@ -1931,23 +2145,39 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
         let background =
             into_qbrush(properties.background(), size.width.into(), size.height.into());
 
-        match (&window_item.icon()).into() {
-            &ImageInner::None => (),
-            r => {
-                if let Some(pixmap) = image_to_pixmap(r, None) {
-                    cpp! {unsafe [widget_ptr as "QWidget*", pixmap as "QPixmap"] {
-                        widget_ptr->setWindowIcon(QIcon(pixmap));
-                    }};
-                }
+        // A QIcon holds the pixmaps in all sizes, and Qt picks the best one for each place.
+        cpp_class!(unsafe struct QIcon as "QIcon");
+        let mut icon = QIcon::default();
+        for image in properties.icons() {
+            if let Some(pixmap) = image_to_pixmap((&image).into(), None) {
+                cpp! {unsafe [mut icon as "QIcon", pixmap as "QPixmap"] {
+                    icon.addPixmap(pixmap);
+                }};
             }
-        };
+        }
+        cpp! {unsafe [widget_ptr as "QWidget*", icon as "QIcon"] {
+            widget_ptr->setWindowIcon(icon);
+        }};
+
+        // Qt has no aspect ratio hint for top-level windows, so only the increments are mapped
+        let size_increment =
+            properties.resize_increments().map_or(qttypes::QSize::default(), |increments| {
+                qttypes::QSize {
+                    width: increments.width.round() as _,
+                    height: increments.height.round() as _,
+                }
+            });
+        cpp! {unsafe [widget_ptr as "QWidget*", size_increment as "QSize"] {
+            widget_ptr->setSizeIncrement(size_increment);
+        }};
 
         let fullscreen: bool = properties.is_fullscreen();
         let minimized: bool = properties.is_minimized();
         let maximized: bool = properties.is_maximized();
+        let opacity = properties.opacity() as f64;
 
         cpp! {unsafe [widget_ptr as "QWidget*",  title as "QString", size as "QSize", background as "QBrush", no_frame as "bool", always_on_top as "bool",
-                      fullscreen as "bool", minimized as "bool", maximized as "bool"] {
+                      always_on_bottom as "bool", fullscreen as "bool", minimized as "bool", maximized as "bool", opacity as "double"] {
 
             if (size != widget_ptr->size()) {
                 widget_ptr->resize(size.expandedTo({1, 1}));
@@ -1955,6 +2185,7 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
 
             widget_ptr->setWindowFlag(Qt::FramelessWindowHint, no_frame);
             widget_ptr->setWindowFlag(Qt::WindowStaysOnTopHint, always_on_top);
+            widget_ptr->setWindowFlag(Qt::WindowStaysOnBottomHint, always_on_bottom);
 
                         {
                 // Depending on the request, we either set or clear the bits.
@@ -1975,6 +2206,7 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
             }
 
             widget_ptr->setWindowTitle(title);
+            widget_ptr->setWindowOpacity(opacity);
             auto pal = widget_ptr->palette();
 
             #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
@@ -2025,6 +2257,29 @@ fn into_qsize(logical_size: i_slint_core::api::LogicalSize) -> qttypes::QSize {
 }
 
 impl WindowAdapterInternal for QtWindow {
+    fn screen_geometries(
+        &self,
+    ) -> Option<Vec<(i_slint_core::api::PhysicalPosition, i_slint_core::api::PhysicalSize)>> {
+        let count = cpp! {unsafe [] -> i32 as "int" {
+            return QGuiApplication::screens().size();
+        }};
+        Some(
+            (0..count)
+                .map(|index| {
+                    // The positions of the windows are set in the coordinates of the screens too,
+                    // see set_position()
+                    let rect = cpp! {unsafe [index as "int"] -> qttypes::QRectF as "QRectF" {
+                        return QGuiApplication::screens().at(index)->availableGeometry();
+                    }};
+                    (
+                        i_slint_core::api::PhysicalPosition::new(rect.x as _, rect.y as _),
+                        i_slint_core::api::PhysicalSize::new(rect.width as _, rect.height as _),
+                    )
+                })
+                .collect(),
+        )
+    }
+
     fn register_item_tree(&self) {
         self.tree_structure_changed.replace(true);
     }
@@ -2092,6 +2347,53 @@ fn set_mouse_cursor(&self, cursor: MouseCursor) {
         }};
     }
 
+    fn drag_window_frame(&self, region: i_slint_core::items::WindowFrameRegion) {
+        use i_slint_core::items::WindowFrameRegion;
+        let widget_ptr = self.widget_ptr();
+        // The values of Qt::Edge, where 0 moves the window instead.
+        let (top, left, right, bottom) = (0x1, 0x2, 0x4, 0x8);
+        let edges: u32 = match region {
+            WindowFrameRegion::Client | WindowFrameRegion::MaximizeButton => return,
+            WindowFrameRegion::TitleBar => 0,
+            WindowFrameRegion::ResizeNorth => top,
+            WindowFrameRegion::ResizeSouth => bottom,
+            WindowFrameRegion::ResizeEast => right,
+            WindowFrameRegion::ResizeWest => left,
+            WindowFrameRegion::ResizeNorthEast => top | right,
+            WindowFrameRegion::ResizeNorthWest => top | left,
+            WindowFrameRegion::ResizeSouthEast => bottom | right,
+            WindowFrameRegion::ResizeSouthWest => bottom | left,
+        };
+        cpp! {unsafe [widget_ptr as "QWidget*", edges as "uint"] {
+            if (auto w = widget_ptr->window()->windowHandle()) {
+                if (edges == 0) {
+                    w->startSystemMove();
+                } else {
+                    w->startSystemResize(Qt::Edges(QFlag(edges)));
+                }
+            }
+        }};
+    }
+
+    #[cfg(feature = "unstable-drag-api")]
+    fn start_drag(&self, data: i_slint_core::api::DragData) -> Result<(), PlatformError> {
+        let widget_ptr = self.widget_ptr();
+        let mime_data = cpp!(unsafe [] -> *mut std::ffi::c_void as "QMimeData*" {
+            return new QMimeData;
+        });
+        for (mime_type, data) in data.formats() {
+            let mime_type: qttypes::QString = mime_type.into();
+            let data = qttypes::QByteArray::from(data);
+            cpp! {unsafe [mime_data as "QMimeData*", mime_type as "QString", data as "QByteArray"] {
+                mime_data->setData(mime_type, data);
+            }};
+        }
+        cpp! {unsafe [widget_ptr as "SlintWidget*", mime_data as "QMimeData*"] {
+            widget_ptr->startDrag(mime_data);
+        }};
+        Ok(())
+    }
+
     fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest) {
         let widget_ptr = self.widget_ptr();
         let props = match request {
@@ -2166,6 +2468,13 @@ fn color_scheme(&self) -> ColorScheme {
         ds.as_ref().get()
     }
 
+    fn accent_color(&self) -> Color {
+        self.accent_color
+            .get_or_init(|| Box::pin(Property::new(system_accent_color())))
+            .as_ref()
+            .get()
+    }
+
     fn bring_to_front(&self) -> Result<(), i_slint_core::platform::PlatformError> {
         let widget_ptr = self.widget_ptr();
         cpp! {unsafe [widget_ptr as "QWidget*"] {
@@ -2174,6 +2483,33 @@ fn bring_to_front(&self) -> Result<(), i_slint_core::platform::PlatformError> {
         }};
         Ok(())
     }
+
+    fn request_user_attention(&self, _attention: i_slint_core::api::UserAttentionType) {
+        let widget_ptr = self.widget_ptr();
+        cpp! {unsafe [widget_ptr as "QWidget*"] {
+            QApplication::alert(widget_ptr);
+        }};
+    }
+
+    fn set_parent_window(&self, parent: Option<Rc<dyn WindowAdapter>>, modal: bool) {
+        let widget_ptr = self.widget_ptr();
+        let parent_widget_ptr = parent
+            .as_ref()
+            .and_then(|parent| parent.internal(i_slint_core::InternalToken))
+            .and_then(|parent| parent.as_any().downcast_ref::<QtWindow>())
+            .map_or(std::ptr::null_mut(), |parent| parent.widget_ptr().as_ptr());
+        cpp! {unsafe [widget_ptr as "QWidget*", parent_widget_ptr as "QWidget*", modal as "bool"] {
+            // The window handles only exist once the widgets are native windows
+            widget_ptr->winId();
+            QWindow *parent_window = nullptr;
+            if (parent_widget_ptr) {
+                parent_widget_ptr->winId();
+                parent_window = parent_widget_ptr->windowHandle();
+            }
+            widget_ptr->windowHandle()->setTransientParent(parent_window);
+            widget_ptr->setWindowModality(modal && parent_window ? Qt::WindowModal : Qt::NonModal);
+        }};
+    }
 }
 
 impl i_slint_core::renderer::RendererSealed for QtWindow {
@@ -2270,13 +2606,13 @@ fn text_input_byte_offset_for_position(
         visual_representation.map_byte_offset_from_byte_offset_in_visual_text(byte_offset)
     }
 
-    fn text_input_cursor_rect_for_byte_offset(
+    fn text_input_cursor_metrics_for_byte_offset(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
         byte_offset: usize,
         font_request: FontRequest,
         _scale_factor: ScaleFactor,
-    ) -> LogicalRect {
+    ) -> i_slint_core::items::TextCursorMetrics {
         let size = LogicalSize::new(text_input.width().get(), text_input.height().get());
         let rect: qttypes::QRectF = check_geometry!(size);
         let font: QFont = get_font(font_request);
@@ -2297,7 +2633,11 @@ fn text_input_cursor_rect_for_byte_offset(
             TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
         };
         let single_line: bool = text_input.single_line();
-        let r = cpp! { unsafe [font as "QFont", mut string as "QString", offset as "int", flags as "int", rect as "QRectF", single_line as "bool"]
+        let mut line_rect = qttypes::QRectF::default();
+        let mut baseline: f64 = 0.;
+        let mut line_index: i32 = 0;
+        let r = cpp! { unsafe [font as "QFont", mut string as "QString", offset as "int", flags as "int", rect as "QRectF", single_line as "bool",
+                mut line_rect as "QRectF", mut baseline as "double", mut line_index as "int"]
                 -> qttypes::QRectF as "QRectF" {
             if (!single_line) {
                 string.replace(QChar('\n'), QChar::LineSeparator);
@@ -2308,13 +2648,25 @@ fn text_input_cursor_rect_for_byte_offset(
             QTextLine textLine = layout.lineForTextPosition(offset);
             if (!textLine.isValid())
                 return QRectF();
-            return QRectF(textLine.x() + textLine.cursorToX(offset), layout.position().y() + textLine.y(), 1.0, textLine.height());
+            qreal y = layout.position().y() + textLine.y();
+            line_rect = QRectF(textLine.naturalTextRect().x(), y, textLine.naturalTextWidth(), textLine.height());
+            baseline = y + textLine.ascent();
+            line_index = textLine.lineNumber();
+            return QRectF(textLine.x() + textLine.cursorToX(offset), y, 1.0, textLine.height());
         }};
 
-        LogicalRect::new(
-            LogicalPoint::new(r.x as _, r.y as _),
-            LogicalSize::new(r.width as _, r.height as _),
-        )
+        let to_logical_rect = |r: qttypes::QRectF| {
+            LogicalRect::new(
+                LogicalPoint::new(r.x as _, r.y as _),
+                LogicalSize::new(r.width as _, r.height as _),
+            )
+        };
+        i_slint_core::items::TextCursorMetrics {
+            rect: to_logical_rect(r),
+            baseline: baseline as _,
+            line_index: line_index as _,
+            line_rect: to_logical_rect(line_rect),
+        }
     }
 
     fn register_font_from_memory(
@@ -2410,15 +2762,25 @@ fn accessible_item(item: Option<ItemRc>) -> Option<ItemRc> {
 
 fn get_font(request: FontRequest) -> QFont {
     let family: qttypes::QString = request.family.unwrap_or_default().as_str().into();
+    // Qt takes each character from the first family of the list that has a glyph for it, so the
+    // families preferred for the scripts come after the family of the text.
+    let mut script_families = qttypes::QStringList::new();
+    for script_family in i_slint_core::graphics::all_script_font_families() {
+        script_families.push(script_family.as_str().into());
+    }
     let pixel_size: f32 = request.pixel_size.map_or(0., |logical_size| logical_size.get());
     let weight: i32 = request.weight.unwrap_or(0);
     let letter_spacing: f32 =
         request.letter_spacing.map_or(0., |logical_spacing| logical_spacing.get());
     let italic: bool = request.italic;
-    cpp!(unsafe [family as "QString", pixel_size as "float", weight as "int", letter_spacing as "float", italic as "bool"] -> QFont as "QFont" {
+    cpp!(unsafe [family as "QString", script_families as "QStringList", pixel_size as "float", weight as "int", letter_spacing as "float", italic as "bool"] -> QFont as "QFont" {
         QFont f;
         if (!family.isEmpty())
             f.setFamily(family);
+    #if QT_VERSION >= QT_VERSION_CHECK(5, 13, 0)
+        if (!script_families.isEmpty())
+            f.setFamilies(QStringList(f.family()) + script_families);
+    #endif
         if (pixel_size > 0)
             f.setPixelSize(pixel_size);
         if (weight > 0) {
@@ -2685,9 +3047,62 @@ fn test_utf8_byte_offset_to_utf16_units() {
     }
 }
 
+/// Converts the details of a `QTabletEvent` to the event dispatched before the pointer event that Qt
+/// synthesizes from it.
+fn tablet_pointer_details(
+    eraser: bool,
+    pressure: f32,
+    tilt_x: f32,
+    tilt_y: f32,
+    twist: f32,
+) -> WindowEvent {
+    WindowEvent::PointerDetailsChanged {
+        tool_type: if eraser { PointerToolType::Eraser } else { PointerToolType::Pen },
+        pressure,
+        tilt_x,
+        tilt_y,
+        // Qt uses degrees between -180 and 180
+        twist: twist.rem_euclid(360.),
+    }
+}
+
+#[test]
+fn test_tablet_pointer_details() {
+    assert_eq!(
+        tablet_pointer_details(false, 0.25, -30., 45., -90.),
+        WindowEvent::PointerDetailsChanged {
+            tool_type: PointerToolType::Pen,
+            pressure: 0.25,
+            tilt_x: -30.,
+            tilt_y: 45.,
+            twist: 270.,
+        }
+    );
+    assert_eq!(
+        tablet_pointer_details(true, 1., 0., 0., 180.),
+        WindowEvent::PointerDetailsChanged {
+            tool_type: PointerToolType::Eraser,
+            pressure: 1.,
+            tilt_x: 0.,
+            tilt_y: 0.,
+            twist: 180.,
+        }
+    );
+}
+
 fn qt_password_character() -> char {
     char::from_u32(cpp! { unsafe [] -> i32 as "int" {
         return qApp->style()->styleHint(QStyle::SH_LineEdit_PasswordCharacter, nullptr, nullptr);
     }} as u32)
     .unwrap_or('●')
 }
+
+fn system_accent_color() -> Color {
+    Color::from_argb_encoded(cpp! { unsafe [] -> u32 as "QRgb" {
+        #if QT_VERSION >= QT_VERSION_CHECK(6, 6, 0)
+            return qApp->palette().color(QPalette::Accent).rgba();
+        #else
+            return qApp->palette().color(QPalette::Highlight).rgba();
+        #endif
+    }})
+}
@@ -7,6 +7,13 @@
 use i_slint_core::api::PlatformError;
 use i_slint_core::graphics::{RequestedGraphicsAPI, RequestedOpenGLVersion};
 
+#[cfg(all(
+    feature = "tray-icon",
+    feature = "i-slint-backend-winit",
+    not(any(target_arch = "wasm32", target_os = "ios", target_os = "android"))
+))]
+pub use i_slint_backend_winit::tray_icon;
+
 #[i_slint_core_macros::slint_doc]
 /// Use the BackendSelector to configure one of Slint's built-in [backends with a renderer](slint:backends_and_renderers)
 /// to accomodate specific needs of your application. This is a programmatic substitute for
@@ -25,6 +32,7 @@ pub struct BackendSelector {
     requested_graphics_api: Option<RequestedGraphicsAPI>,
     backend: Option<String>,
     renderer: Option<String>,
+    xdg_app_id: Option<String>,
     selected: bool,
 }
 
@@ -36,6 +44,7 @@ pub fn new() -> BackendSelector {
             requested_graphics_api: None,
             backend: None,
             renderer: None,
+            xdg_app_id: None,
             selected: false,
         }
     }
@@ -113,6 +122,20 @@ pub fn backend_name(mut self, name: String) -> Self {
         self
     }
 
+    /// Sets the application id for use on Wayland or X11 with [xdg](https://specifications.freedesktop.org/desktop-entry-spec/latest/)
+    /// compliant window managers. Use the name of the application's desktop file without the `.desktop`
+    /// extension, like `org.example.MyApp`, so that the window manager and the task bar show the icon
+    /// of the desktop file. On Wayland, this is the `app_id` of the windows, and on X11, it's their
+    /// `WM_CLASS`.
+    ///
+    /// This is the same as calling [`set_xdg_app_id()`](i_slint_core::api::set_xdg_app_id) after the
+    /// backend is selected, and has no effect on other platforms.
+    #[must_use]
+    pub fn xdg_app_id(mut self, app_id: String) -> Self {
+        self.xdg_app_id = Some(app_id);
+        self
+    }
+
     /// Completes the backend selection process and tries to combine with specified requirements
     /// with the different backends and renderers enabled at compile time. On success, the selected
     /// backend is automatically set to be active. Returns an error if the requirements could not be met.
@@ -176,7 +199,13 @@ fn select_internal(&mut self) -> Result<(), PlatformError> {
         };
 
         i_slint_core::platform::set_platform(backend)
-            .map_err(|set_platform_error| PlatformError::SetPlatformError(set_platform_error))
+            .map_err(|set_platform_error| PlatformError::SetPlatformError(set_platform_error))?;
+
+        if let Some(app_id) = self.xdg_app_id.take() {
+            i_slint_core::api::set_xdg_app_id(app_id)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -57,6 +57,20 @@ pub fn send_keyboard_string_sequence<
     )
 }
 
+/// Simulate the press and release of the directional button of a gamepad or a remote control
+/// that corresponds to the arrow `key`.
+pub fn send_directional_key<
+    X: vtable::HasStaticVTable<i_slint_core::item_tree::ItemTreeVTable>,
+    Component: Into<vtable::VRc<i_slint_core::item_tree::ItemTreeVTable, X>> + ComponentHandle,
+>(
+    component: &Component,
+    key: char,
+) {
+    let window = WindowInner::from_pub(component.window());
+    window.process_directional_key(key, true);
+    window.process_directional_key(key, false);
+}
+
 /// Applies the specified scale factor to the window that's associated with the given component.
 /// This overrides the value provided by the windowing system.
 pub fn set_window_scale_factor<
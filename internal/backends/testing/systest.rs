@@ -568,6 +568,8 @@ fn convert_pointer_event_button(
         proto::PointerEventButton::Left => i_slint_core::platform::PointerEventButton::Left,
         proto::PointerEventButton::Right => i_slint_core::platform::PointerEventButton::Right,
         proto::PointerEventButton::Middle => i_slint_core::platform::PointerEventButton::Middle,
+        proto::PointerEventButton::Back => i_slint_core::platform::PointerEventButton::Back,
+        proto::PointerEventButton::Forward => i_slint_core::platform::PointerEventButton::Forward,
     }
 }
 
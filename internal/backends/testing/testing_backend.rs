@@ -3,7 +3,7 @@
 
 use i_slint_core::api::PhysicalSize;
 use i_slint_core::graphics::euclid::{Point2D, Size2D};
-use i_slint_core::graphics::FontRequest;
+use i_slint_core::graphics::{FontRequest, Rgba8Pixel, SharedPixelBuffer};
 use i_slint_core::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
 use i_slint_core::platform::PlatformError;
 use i_slint_core::renderer::{Renderer, RendererSealed};
@@ -21,8 +21,18 @@ pub struct TestingBackendOptions {
     pub threading: bool,
 }
 
+/// The content of the default clipboard. Setting one of the fields replaces the others, like
+/// on real platforms.
+#[derive(Default)]
+struct ClipboardContent {
+    text: Option<String>,
+    html: Option<String>,
+    image: Option<SharedPixelBuffer<Rgba8Pixel>>,
+}
+
 pub struct TestingBackend {
-    clipboard: Mutex<Option<String>>,
+    clipboard: Mutex<ClipboardContent>,
+    primary_selection: Mutex<Option<String>>,
     queue: Option<Queue>,
     mock_time: bool,
 }
@@ -31,6 +41,7 @@ impl TestingBackend {
     pub fn new(options: TestingBackendOptions) -> Self {
         Self {
             clipboard: Mutex::default(),
+            primary_selection: Mutex::default(),
             queue: options.threading.then(|| Queue(Default::default(), std::thread::current())),
             mock_time: options.mock_time,
         }
@@ -46,6 +57,8 @@ fn create_window_adapter(
             size: Default::default(),
             ime_requests: Default::default(),
             mouse_cursor: Default::default(),
+            color_scheme: Box::pin(Default::default()),
+            accent_color: Box::pin(Default::default()),
         }))
     }
 
@@ -62,14 +75,70 @@ fn duration_since_start(&self) -> core::time::Duration {
     }
 
     fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clipboard) {
-        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
-            *self.clipboard.lock().unwrap() = Some(text.into());
+        match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => {
+                *self.clipboard.lock().unwrap() =
+                    ClipboardContent { text: Some(text.into()), ..Default::default() };
+            }
+            i_slint_core::platform::Clipboard::SelectionClipboard => {
+                *self.primary_selection.lock().unwrap() = Some(text.into());
+            }
+            _ => {}
         }
     }
 
     fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
+        match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => {
+                self.clipboard.lock().unwrap().text.clone()
+            }
+            i_slint_core::platform::Clipboard::SelectionClipboard => {
+                self.primary_selection.lock().unwrap().clone()
+            }
+            _ => None,
+        }
+    }
+
+    fn set_clipboard_image(
+        &self,
+        image: &SharedPixelBuffer<Rgba8Pixel>,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            *self.clipboard.lock().unwrap() =
+                ClipboardContent { image: Some(image.clone()), ..Default::default() };
+        }
+    }
+
+    fn clipboard_image(
+        &self,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            self.clipboard.lock().unwrap().image.clone()
+        } else {
+            None
+        }
+    }
+
+    fn set_clipboard_html(
+        &self,
+        html: &str,
+        alt_text: &str,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
         if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
-            self.clipboard.lock().unwrap().clone()
+            *self.clipboard.lock().unwrap() = ClipboardContent {
+                text: Some(alt_text.into()),
+                html: Some(html.into()),
+                image: None,
+            };
+        }
+    }
+
+    fn clipboard_html(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            self.clipboard.lock().unwrap().html.clone()
         } else {
             None
         }
@@ -89,6 +158,7 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
             match e {
                 Some(Event::Quit) => break Ok(()),
                 Some(Event::Event(e)) => e(),
+                None if i_slint_core::platform::run_idle_callbacks() => {}
                 None => match i_slint_core::platform::duration_until_next_timer_update() {
                     Some(duration) if !self.mock_time => std::thread::park_timeout(duration),
                     _ => std::thread::park(),
@@ -109,6 +179,10 @@ pub struct TestingWindow {
     size: Cell<PhysicalSize>,
     pub ime_requests: RefCell<Vec<InputMethodRequest>>,
     pub mouse_cursor: Cell<i_slint_core::items::MouseCursor>,
+    /// The color scheme of the system, which tests can set to simulate a change of the theme.
+    pub color_scheme: Pin<Box<i_slint_core::Property<i_slint_core::items::ColorScheme>>>,
+    /// The accent color of the system, which tests can set to simulate a change in the settings.
+    pub accent_color: Pin<Box<i_slint_core::Property<i_slint_core::Color>>>,
 }
 
 impl WindowAdapterInternal for TestingWindow {
@@ -123,6 +197,14 @@ fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest
     fn set_mouse_cursor(&self, cursor: i_slint_core::items::MouseCursor) {
         self.mouse_cursor.set(cursor);
     }
+
+    fn color_scheme(&self) -> i_slint_core::items::ColorScheme {
+        self.color_scheme.as_ref().get()
+    }
+
+    fn accent_color(&self) -> i_slint_core::Color {
+        self.accent_color.as_ref().get()
+    }
 }
 
 impl WindowAdapter for TestingWindow {
@@ -173,6 +255,17 @@ fn text_size(
         LogicalSize::new(text.len() as f32 * 10., 10.)
     }
 
+    fn text_line_ranges(
+        &self,
+        _font_request: i_slint_core::graphics::FontRequest,
+        text: &str,
+        _max_width: Option<LogicalLength>,
+        _scale_factor: ScaleFactor,
+        _text_wrap: TextWrap,
+    ) -> Result<Vec<core::ops::Range<usize>>, PlatformError> {
+        Ok(vec![0..text.len()])
+    }
+
     fn font_metrics(
         &self,
         _font_request: i_slint_core::graphics::FontRequest,
@@ -181,6 +274,14 @@ fn font_metrics(
         i_slint_core::items::FontMetrics { ascent: 7., descent: 3., x_height: 3., cap_height: 7. }
     }
 
+    fn line_spacing(
+        &self,
+        _font_request: FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> LogicalLength {
+        LogicalLength::new(10.)
+    }
+
     // this works only for single line text
     fn text_input_byte_offset_for_position(
         &self,
@@ -195,14 +296,25 @@ fn text_input_byte_offset_for_position(
     }
 
     // this works only for single line text
-    fn text_input_cursor_rect_for_byte_offset(
+    fn text_input_cursor_metrics_for_byte_offset(
         &self,
-        _text_input: Pin<&i_slint_core::items::TextInput>,
+        text_input: Pin<&i_slint_core::items::TextInput>,
         byte_offset: usize,
         _font_request: FontRequest,
         _scale_factor: ScaleFactor,
-    ) -> LogicalRect {
-        LogicalRect::new(Point2D::new(byte_offset as f32 * 10., 0.), Size2D::new(1., 10.))
+    ) -> i_slint_core::items::TextCursorMetrics {
+        i_slint_core::items::TextCursorMetrics {
+            rect: LogicalRect::new(
+                Point2D::new(byte_offset as f32 * 10., 0.),
+                Size2D::new(1., 10.),
+            ),
+            baseline: 7.,
+            line_index: 0,
+            line_rect: LogicalRect::new(
+                Point2D::default(),
+                Size2D::new(text_input.text().len() as f32 * 10., 10.),
+            ),
+        }
     }
 
     fn register_font_from_memory(
@@ -219,6 +331,26 @@ fn register_font_from_path(
         Ok(())
     }
 
+    fn unregister_font(&self, _family: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    fn replace_font_from_memory(
+        &self,
+        _family: &str,
+        _data: &'static [u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    fn replace_font_from_path(
+        &self,
+        _family: &str,
+        _path: &std::path::Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
     fn default_font_size(&self) -> LogicalLength {
         LogicalLength::new(10.)
     }
@@ -0,0 +1,66 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Reads the accent color that the user chose in the system settings, for `Palette.system-accent-color`.
+//!
+//! On Windows, it's the accent color of the DWM (the desktop window manager), and on macOS the
+//! `controlAccentColor` of AppKit. Neither platform notifies winit about changes, so they are read again
+//! when a window is activated. On Linux, the accent color is read from the XDG settings portal in
+//! `WinitWindowAdapter::spawn_accent_color_watcher()`, which also watches for changes.
+
+use i_slint_core::graphics::Color;
+
+/// Returns the accent color of the system, or a transparent color if it's unknown.
+pub fn system_accent_color() -> Color {
+    #[cfg(target_family = "windows")]
+    return windows_accent_color().unwrap_or_default();
+
+    #[cfg(target_os = "macos")]
+    return macos_accent_color().unwrap_or_default();
+
+    #[allow(unreachable_code)]
+    Color::default()
+}
+
+#[cfg(target_family = "windows")]
+fn windows_accent_color() -> Option<Color> {
+    use windows::core::w;
+    use windows::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+
+    let mut abgr = 0u32;
+    let mut size = core::mem::size_of::<u32>() as u32;
+    // Safety: the value is a DWORD, and the buffer is a u32 of the given size.
+    let result = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            w!("Software\\Microsoft\\Windows\\DWM"),
+            w!("AccentColor"),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut abgr as *mut u32 as *mut _),
+            Some(&mut size),
+        )
+    };
+    result.is_ok().then(|| {
+        let [red, green, blue, _] = abgr.to_le_bytes();
+        Color::from_rgb_u8(red, green, blue)
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn macos_accent_color() -> Option<Color> {
+    use objc2_app_kit::{NSColor, NSColorSpace};
+
+    // Safety: these are class methods and getters of AppKit without preconditions, called on the main thread.
+    unsafe {
+        let color =
+            NSColor::controlAccentColor().colorUsingColorSpace(&NSColorSpace::sRGBColorSpace())?;
+        let component = |value: f64| (value.clamp(0., 1.) * 255.).round() as u8;
+        Some(Color::from_argb_u8(
+            component(color.alphaComponent()),
+            component(color.redComponent()),
+            component(color.greenComponent()),
+            component(color.blueComponent()),
+        ))
+    }
+}
@@ -11,6 +11,10 @@ fn main() {
        supports_opengl: { all(any(enable_skia_renderer, feature = "renderer-femtovg"), not(target_os = "ios")) },
        use_winit_theme: { any(target_family = "windows", target_os = "macos", target_os = "ios", target_arch = "wasm32") },
        muda: { all(feature = "muda", any(target_os = "windows", target_os = "macos")) },
+       enable_global_shortcuts: { all(feature = "global-shortcuts", not(any(target_arch = "wasm32", target_os = "ios", target_os = "android"))) },
+       enable_tray_icon: { all(feature = "tray-icon", not(any(target_arch = "wasm32", target_os = "ios", target_os = "android"))) },
+       enable_headless: { all(feature = "renderer-software", not(target_arch = "wasm32")) },
+       enable_startup_notify: { all(unix, not(target_vendor = "apple"), not(target_os = "android"), any(feature = "wayland", feature = "x11")) },
     }
     // This uses `web_sys_unstable_api`, which is typically set via `RUST_FLAGS`
     println!("cargo:rustc-check-cfg=cfg(web_sys_unstable_apis)");
@@ -83,3 +83,59 @@ fn set_contents(
         }
     }
 }
+
+// copypasta only supports text, so images and HTML go through arboard. It's kept alive because on
+// Linux, it serves the content that this application copied for as long as it exists.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+thread_local! {
+    static RICH_CLIPBOARD: std::cell::RefCell<Option<arboard::Clipboard>> = Default::default();
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn with_rich_clipboard<R>(f: impl FnOnce(&mut arboard::Clipboard) -> Option<R>) -> Option<R> {
+    RICH_CLIPBOARD.with(|clipboard| {
+        let mut clipboard = clipboard.borrow_mut();
+        if clipboard.is_none() {
+            *clipboard = arboard::Clipboard::new().ok();
+        }
+        f(clipboard.as_mut()?)
+    })
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub fn set_image(
+    image: &i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>,
+) {
+    with_rich_clipboard(|clipboard| {
+        clipboard
+            .set_image(arboard::ImageData {
+                width: image.width() as usize,
+                height: image.height() as usize,
+                bytes: image.as_bytes().into(),
+            })
+            .ok()
+    });
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub fn image(
+) -> Option<i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>> {
+    with_rich_clipboard(|clipboard| {
+        let image = clipboard.get_image().ok()?;
+        Some(i_slint_core::graphics::SharedPixelBuffer::clone_from_slice(
+            &image.bytes,
+            image.width as u32,
+            image.height as u32,
+        ))
+    })
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub fn set_html(html: &str, alt_text: &str) {
+    with_rich_clipboard(|clipboard| clipboard.set_html(html, Some(alt_text)).ok());
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub fn html() -> Option<String> {
+    with_rich_clipboard(|clipboard| clipboard.get().html().ok())
+}
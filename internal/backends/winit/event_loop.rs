@@ -70,10 +70,22 @@ pub(crate) fn new(
         {
             use winit::platform::windows::EventLoopBuilderExtWindows;
             builder.with_any_thread(true);
+            #[cfg(muda)]
+            builder.with_msg_hook(crate::muda::translate_accelerator);
         }
 
         let instance =
             builder.build().map_err(|e| format!("Error initializing winit event loop: {e}"))?;
+
+        // Consume the token the application was launched with, so that it isn't passed on to child processes.
+        #[cfg(enable_startup_notify)]
+        {
+            use winit::platform::startup_notify::EventLoopExtStartupNotify;
+            if let Some(token) = instance.read_token_from_env() {
+                winit::platform::startup_notify::reset_activation_token_env();
+                STARTUP_ACTIVATION_TOKEN.with(|t| *t.borrow_mut() = Some(token));
+            }
+        }
         let event_loop_proxy = instance.create_proxy();
 
         #[cfg(not(target_arch = "wasm32"))]
@@ -155,6 +167,17 @@ fn is_wayland(&self) -> bool {
     pub(crate) static MAYBE_LOOP_INSTANCE: RefCell<Option<NotRunningEventLoop>> = RefCell::default();
 }
 
+#[cfg(enable_startup_notify)]
+thread_local! {
+    static STARTUP_ACTIVATION_TOKEN: RefCell<Option<winit::window::ActivationToken>> = RefCell::default();
+}
+
+/// Returns the xdg-activation or startup-notification token from the environment, if it wasn't used yet.
+#[cfg(enable_startup_notify)]
+pub(crate) fn take_startup_activation_token() -> Option<winit::window::ActivationToken> {
+    STARTUP_ACTIVATION_TOKEN.with(|t| t.borrow_mut().take())
+}
+
 scoped_tls_hkt::scoped_thread_local!(static CURRENT_WINDOW_TARGET : for<'a> &'a RunningEventLoop<'a>);
 
 pub(crate) enum GlobalEventLoopProxyOrEventQueue {
@@ -237,6 +260,18 @@ pub fn window_by_id(id: winit::window::WindowId) -> Option<Rc<WinitWindowAdapter
     ALL_WINDOWS.with(|windows| windows.borrow().get(&id).and_then(|weakref| weakref.upgrade()))
 }
 
+/// Returns the window of the application that has the focus, if any.
+#[cfg(enable_startup_notify)]
+pub(crate) fn active_window() -> Option<Rc<WinitWindowAdapter>> {
+    ALL_WINDOWS.with(|windows| {
+        windows
+            .borrow()
+            .values()
+            .filter_map(|weakref| weakref.upgrade())
+            .find(|w| WindowInner::from_pub(w.window()).active())
+    })
+}
+
 /// This enum captures run-time specific events that can be dispatched to the event loop in
 /// addition to the winit events.
 pub enum CustomEvent {
@@ -268,18 +303,94 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     }
 }
 
+/// Invokes the menu entry of the window or of the tray icon that the id of the event refers to.
+#[cfg(muda)]
+pub(crate) fn dispatch_muda_event(event: muda::MenuEvent) {
+    #[cfg(enable_tray_icon)]
+    if crate::tray_icon::dispatch_menu_event(&event.id().0) {
+        return;
+    }
+    if let Some((window, eid)) = event.id().0.split_once('|').and_then(|(w, e)| {
+        Some((
+            window_by_id(winit::window::WindowId::from(w.parse::<u64>().ok()?))?,
+            e.parse::<usize>().ok()?,
+        ))
+    }) {
+        if let Some(ma) = window.muda_adapter.borrow().as_ref() {
+            ma.invoke(eid);
+        }
+    };
+}
+
+/// Returns the name of the physical key as the `code` of the W3C UI Events, which winit's key codes
+/// follow except for the meta keys.
+pub(crate) fn physical_key_name(key: winit::keyboard::PhysicalKey) -> corelib::SharedString {
+    use winit::keyboard::{KeyCode, PhysicalKey};
+    match key {
+        PhysicalKey::Code(KeyCode::SuperLeft) => "MetaLeft".into(),
+        PhysicalKey::Code(KeyCode::SuperRight) => "MetaRight".into(),
+        PhysicalKey::Code(code) => corelib::format!("{code:?}"),
+        PhysicalKey::Unidentified(_) => Default::default(),
+    }
+}
+
+pub(crate) type WindowEventFilter =
+    Box<dyn FnMut(&corelib::api::Window, &WindowEvent) -> WinitWindowEventResult>;
+pub(crate) type DeviceEventFilter =
+    Box<dyn FnMut(winit::event::DeviceId, &winit::event::DeviceEvent) -> WinitWindowEventResult>;
+
+pub(crate) type WindowCreationHook = Box<
+    dyn Fn(
+        &corelib::api::Window,
+        winit::window::WindowAttributes,
+    ) -> winit::window::WindowAttributes,
+>;
+
+thread_local! {
+    /// The hook set with `BackendBuilder::with_window_creation_hook()`.
+    pub(crate) static WINDOW_CREATION_HOOK: std::cell::Cell<Option<WindowCreationHook>> = Default::default();
+    /// The filter set with `BackendBuilder::with_window_event_filter()`, which sees the events of all
+    /// windows before the filter of each window.
+    pub(crate) static WINDOW_EVENT_FILTER: std::cell::Cell<Option<WindowEventFilter>> = Default::default();
+    /// The filter set with `BackendBuilder::with_device_event_filter()`.
+    pub(crate) static DEVICE_EVENT_FILTER: std::cell::Cell<Option<DeviceEventFilter>> = Default::default();
+}
+
 #[derive(Default)]
 pub struct EventLoopState {
     // last seen cursor position
     cursor_pos: LogicalPoint,
     pressed: bool,
-    current_touch_id: Option<u64>,
 
     loop_error: Option<PlatformError>,
     current_resize_direction: Option<ResizeDirection>,
+
+    /// The files dragged over `file_drag_window`, as `file://` URIs in the `text/uri-list` format.
+    /// winit reports each file separately, so they are dispatched together in `about_to_wait`.
+    dragged_files: String,
+    file_drag_window: Option<winit::window::WindowId>,
+    /// Set when `dragged_files` changed: `Some(true)` when they were dropped, `Some(false)` when
+    /// they are hovering the window.
+    pending_file_drag: Option<bool>,
+    /// Follows the pointer while files are hovering a window on the platforms where winit doesn't
+    /// report its moves during the drag.
+    file_drag_timer: corelib::timers::Timer,
 }
 
 impl winit::application::ApplicationHandler<SlintUserEvent> for EventLoopState {
+    fn device_event(
+        &mut self,
+        _event_loop: &ActiveEventLoop,
+        device_id: winit::event::DeviceId,
+        event: winit::event::DeviceEvent,
+    ) {
+        if let Some(mut device_event_filter) = DEVICE_EVENT_FILTER.take() {
+            // Slint doesn't handle device events itself, so there's nothing to prevent
+            let _ = device_event_filter(device_id, &event);
+            DEVICE_EVENT_FILTER.set(Some(device_event_filter));
+        }
+    }
+
     fn resumed(&mut self, _event_loop: &ActiveEventLoop) {
         ALL_WINDOWS.with(|ws| {
             for (_, window_weak) in ws.borrow().iter() {
@@ -303,6 +414,16 @@ fn window_event(
         };
 
         if let Some(_winit_window) = window.winit_window() {
+            if let Some(mut window_event_filter) = WINDOW_EVENT_FILTER.take() {
+                let event_result = window_event_filter(window.window(), &event);
+                WINDOW_EVENT_FILTER.set(Some(window_event_filter));
+
+                match event_result {
+                    WinitWindowEventResult::PreventDefault => return,
+                    WinitWindowEventResult::Propagate => (),
+                }
+            }
+
             if let Some(mut window_event_filter) = window.window_event_filter.take() {
                 let event_result = window_event_filter(window.window(), &event);
                 window.window_event_filter.set(Some(window_event_filter));
@@ -330,6 +451,7 @@ fn window_event(
             }
             WindowEvent::Resized(size) => {
                 self.loop_error = window.resize_event(size).err();
+                window.apply_resize_constraints(size);
 
                 // Entering fullscreen, maximizing or minimizing the window will
                 // trigger a resize event. We need to update the internal window
@@ -357,9 +479,26 @@ fn window_event(
                         ))
                         .err();
                 }
+                #[cfg(any(target_family = "windows", target_os = "macos"))]
+                if have_focus {
+                    window.update_accent_color();
+                }
+                #[cfg(target_os = "macos")]
+                if have_focus && self.loop_error.is_none() {
+                    self.loop_error = window.dispatch_system_font().err();
+                }
+                #[cfg(target_family = "windows")]
+                if have_focus && self.loop_error.is_none() {
+                    self.loop_error = window.check_keyboard_layout().err();
+                }
             }
 
             WindowEvent::KeyboardInput { event, is_synthetic, .. } => {
+                #[cfg(target_family = "windows")]
+                if let Err(err) = window.check_keyboard_layout() {
+                    self.loop_error = Some(err);
+                    return;
+                }
                 let key_code = event.logical_key;
                 // For now: Match Qt's behavior of mapping command to control and control to meta (LWin/RWin).
                 #[cfg(target_vendor = "apple")]
@@ -390,37 +529,59 @@ macro_rules! winit_key_to_char {
             }
                 let text = i_slint_common::for_each_special_keys!(winit_key_to_char);
 
-                self.loop_error = window
-                    .window()
-                    .try_dispatch_event(match event.state {
-                        winit::event::ElementState::Pressed if event.repeat => {
-                            corelib::platform::WindowEvent::KeyPressRepeated { text }
-                        }
-                        winit::event::ElementState::Pressed => {
-                            if is_synthetic {
-                                // Synthetic event are sent when the focus is acquired, for all the keys currently pressed.
-                                // Don't forward these keys other than modifiers to the app
-                                use winit::keyboard::{Key::Named, NamedKey as N};
-                                if !matches!(
-                                    key_code,
-                                    Named(N::Control | N::Shift | N::Super | N::Alt | N::AltGraph),
-                                ) {
-                                    return;
-                                }
+                let key_event = match event.state {
+                    winit::event::ElementState::Pressed if event.repeat => {
+                        corelib::platform::WindowEvent::KeyPressRepeated { text }
+                    }
+                    winit::event::ElementState::Pressed => {
+                        if is_synthetic {
+                            // Synthetic event are sent when the focus is acquired, for all the keys currently pressed.
+                            // Don't forward these keys other than modifiers to the app
+                            use winit::keyboard::{Key::Named, NamedKey as N};
+                            if !matches!(
+                                key_code,
+                                Named(N::Control | N::Shift | N::Super | N::Alt | N::AltGraph),
+                            ) {
+                                return;
                             }
-                            corelib::platform::WindowEvent::KeyPressed { text }
-                        }
-                        winit::event::ElementState::Released => {
-                            corelib::platform::WindowEvent::KeyReleased { text }
                         }
+                        corelib::platform::WindowEvent::KeyPressed { text }
+                    }
+                    winit::event::ElementState::Released => {
+                        corelib::platform::WindowEvent::KeyReleased { text }
+                    }
+                };
+
+                #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+                let scancode = {
+                    use winit::platform::scancode::PhysicalKeyExtScancode;
+                    event.physical_key.to_scancode().unwrap_or_default()
+                };
+                #[cfg(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))]
+                let scancode = 0;
+                self.loop_error = window
+                    .window()
+                    .try_dispatch_event(corelib::platform::WindowEvent::KeyDetailsChanged {
+                        physical_key: physical_key_name(event.physical_key),
+                        scancode,
                     })
+                    .and_then(|_| window.window().try_dispatch_event(key_event))
                     .err();
             }
             WindowEvent::Ime(winit::event::Ime::Preedit(string, preedit_selection)) => {
+                // A non-empty range is the clause that the input method is converting (the target
+                // clause on Windows, the marked selection on macOS), which replaces the cursor.
+                let preedit_selection = preedit_selection.map(|e| e.0 as i32..e.1 as i32);
+                let preedit_clause = preedit_selection.clone().filter(|r| !r.is_empty());
                 let event = KeyEvent {
                     event_type: KeyEventType::UpdateComposition,
                     preedit_text: string.into(),
-                    preedit_selection: preedit_selection.map(|e| e.0 as i32..e.1 as i32),
+                    preedit_selection: if preedit_clause.is_some() {
+                        None
+                    } else {
+                        preedit_selection
+                    },
+                    preedit_clause,
                     ..Default::default()
                 };
                 runtime_window.process_key_input(event);
@@ -445,6 +606,38 @@ macro_rules! winit_key_to_char {
                 let position = position.to_logical(runtime_window.scale_factor() as f64);
                 self.cursor_pos = euclid::point2(position.x, position.y);
                 runtime_window.process_mouse_input(MouseEvent::Moved { position: self.cursor_pos });
+                if self.file_drag_window == Some(window_id) && self.pending_file_drag.is_none() {
+                    self.pending_file_drag = Some(false);
+                }
+            }
+            WindowEvent::HoveredFile(path) => {
+                if self.file_drag_window != Some(window_id) {
+                    self.dragged_files.clear();
+                    self.file_drag_window = Some(window_id);
+                }
+                self.dragged_files.push_str(&corelib::input::file_uri(&path));
+                self.dragged_files.push_str("\r\n");
+                self.pending_file_drag = Some(false);
+            }
+            WindowEvent::DroppedFile(path) => {
+                // The dropped files replace the hovered ones
+                if self.pending_file_drag != Some(true) {
+                    self.dragged_files.clear();
+                    self.file_drag_window = Some(window_id);
+                }
+                self.dragged_files.push_str(&corelib::input::file_uri(&path));
+                self.dragged_files.push_str("\r\n");
+                self.pending_file_drag = Some(true);
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.dragged_files.clear();
+                self.file_drag_window = None;
+                self.pending_file_drag = None;
+                self.file_drag_timer.stop();
+                self.loop_error = window
+                    .window()
+                    .try_dispatch_event(corelib::platform::WindowEvent::DragExited)
+                    .err();
             }
             WindowEvent::CursorLeft { .. } => {
                 // On the html canvas, we don't get the mouse move or release event when outside the canvas. So we have no choice but canceling the event
@@ -454,13 +647,30 @@ macro_rules! winit_key_to_char {
                 }
             }
             WindowEvent::MouseWheel { delta, .. } => {
-                let (delta_x, delta_y) = match delta {
-                    winit::event::MouseScrollDelta::LineDelta(lx, ly) => (lx * 60., ly * 60.),
+                let (delta_x, delta_y, details) = match delta {
+                    winit::event::MouseScrollDelta::LineDelta(lx, ly) => (
+                        lx * 60.,
+                        ly * 60.,
+                        corelib::platform::WindowEvent::ScrollDetailsChanged {
+                            device: corelib::platform::PointerScrollDevice::Wheel,
+                            line_delta_x: lx,
+                            line_delta_y: ly,
+                        },
+                    ),
                     winit::event::MouseScrollDelta::PixelDelta(d) => {
-                        let d = d.to_logical(runtime_window.scale_factor() as f64);
-                        (d.x, d.y)
+                        let d = d.to_logical::<f32>(runtime_window.scale_factor() as f64);
+                        (
+                            d.x,
+                            d.y,
+                            corelib::platform::WindowEvent::ScrollDetailsChanged {
+                                device: corelib::platform::PointerScrollDevice::Touchpad,
+                                line_delta_x: d.x / 60.,
+                                line_delta_y: d.y / 60.,
+                            },
+                        )
                     }
                 };
+                self.loop_error = window.window().try_dispatch_event(details).err();
                 runtime_window.process_mouse_input(MouseEvent::Wheel {
                     position: self.cursor_pos,
                     delta_x,
@@ -499,34 +709,41 @@ macro_rules! winit_key_to_char {
                 runtime_window.process_mouse_input(ev);
             }
             WindowEvent::Touch(touch) => {
-                if Some(touch.id) == self.current_touch_id || self.current_touch_id.is_none() {
-                    let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
-                    let position = euclid::point2(location.x, location.y);
-                    let ev = match touch.phase {
-                        winit::event::TouchPhase::Started => {
-                            self.pressed = true;
-                            if self.current_touch_id.is_none() {
-                                self.current_touch_id = Some(touch.id);
-                            }
-                            MouseEvent::Pressed {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
-                            self.pressed = false;
-                            self.current_touch_id = None;
-                            MouseEvent::Released {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
-                    };
-                    runtime_window.process_mouse_input(ev);
-                }
+                let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
+                let position = corelib::api::LogicalPosition::new(location.x, location.y);
+                let id = touch.id;
+                let ev = match touch.phase {
+                    winit::event::TouchPhase::Started => {
+                        corelib::platform::WindowEvent::TouchPressed { id, position }
+                    }
+                    winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
+                        corelib::platform::WindowEvent::TouchReleased { id, position }
+                    }
+                    winit::event::TouchPhase::Moved => {
+                        corelib::platform::WindowEvent::TouchMoved { id, position }
+                    }
+                };
+                // winit reports pens as touches too, with their pressure on the platforms that
+                // provide it, but without the tilt or the kind of the device. So pens are reported
+                // with the Touch tool type, which is documented for TouchArea's pointer-event.
+                let pressure = match (touch.phase, touch.force) {
+                    (winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled, _) => {
+                        0.
+                    }
+                    (_, Some(force)) => force.normalized() as f32,
+                    (_, None) => 0.5,
+                };
+                self.loop_error = window
+                    .window()
+                    .try_dispatch_event(corelib::platform::WindowEvent::PointerDetailsChanged {
+                        tool_type: corelib::platform::PointerToolType::Touch,
+                        pressure,
+                        tilt_x: 0.,
+                        tilt_y: 0.,
+                        twist: 0.,
+                    })
+                    .and_then(|_| window.window().try_dispatch_event(ev))
+                    .err();
             }
             WindowEvent::ScaleFactorChanged { scale_factor, inner_size_writer: _ } => {
                 if std::env::var("SLINT_SCALE_FACTOR").is_err() {
@@ -546,10 +763,19 @@ macro_rules! winit_key_to_char {
             }),
             WindowEvent::Occluded(x) => {
                 window.renderer.occluded(x);
+                self.loop_error = window
+                    .window()
+                    .try_dispatch_event(corelib::platform::WindowEvent::WindowOccludedChanged(x))
+                    .err();
 
-                // In addition to the hack done for WindowEvent::Resize, also do it for Occluded so we handle Minimized change
+                // In addition to the hack done for WindowEvent::Resize, also do it for Occluded so we handle Minimized change.
+                // This also resumes the rendering when the window becomes visible again.
                 window.window_state_event();
             }
+            #[cfg(enable_startup_notify)]
+            WindowEvent::ActivationTokenDone { serial, token } => {
+                window.activation_token_done(serial, token);
+            }
             _ => {}
         }
 
@@ -581,18 +807,7 @@ fn user_event(&mut self, event_loop: &ActiveEventLoop, event: SlintUserEvent) {
                 event_loop.set_control_flow(ControlFlow::Poll);
             }
             #[cfg(muda)]
-            CustomEvent::Muda(event) => {
-                if let Some((window, eid)) = event.id().0.split_once('|').and_then(|(w, e)| {
-                    Some((
-                        window_by_id(winit::window::WindowId::from(w.parse::<u64>().ok()?))?,
-                        e.parse::<usize>().ok()?,
-                    ))
-                }) {
-                    if let Some(ma) = window.muda_adapter.borrow().as_ref() {
-                        ma.invoke(eid);
-                    }
-                };
-            }
+            CustomEvent::Muda(event) => dispatch_muda_event(event),
         }
     }
 
@@ -603,19 +818,50 @@ fn new_events(&mut self, event_loop: &ActiveEventLoop, _cause: winit::event::Sta
     }
 
     fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        if let Some(dropped) = self.pending_file_drag.take() {
+            self.dispatch_file_drag(dropped);
+        }
+
+        // The earliest time at which a window limited by `Window::set_frame_rate_limit()` can render its next frame
+        let mut next_frame: Option<std::time::Duration> = None;
+        let mut has_active_animations = false;
         if !event_loop.exiting() {
             ALL_WINDOWS.with(|windows| {
                 for w in windows.borrow().iter().filter_map(|(_, w)| w.upgrade()) {
                     if w.window().has_active_animations() {
-                        w.request_redraw();
+                        has_active_animations = true;
+                        match WindowInner::from_pub(w.window()).duration_until_next_frame() {
+                            Some(delay) => {
+                                next_frame = Some(next_frame.map_or(delay, |d| d.min(delay)))
+                            }
+                            None => {
+                                w.request_redraw();
+                                // A redraw may have been held back by the frame rate limit
+                                w.resume_rendering();
+                            }
+                        }
                     }
                 }
             })
         }
 
+        // Run the idle callbacks only when there is nothing to animate, and keep polling while some are pending
+        if event_loop.control_flow() == ControlFlow::Wait
+            && !event_loop.exiting()
+            && !has_active_animations
+            && corelib::platform::run_idle_callbacks()
+        {
+            event_loop.set_control_flow(ControlFlow::Poll);
+        }
+
         if event_loop.control_flow() == ControlFlow::Wait {
-            if let Some(next_timer) = corelib::platform::duration_until_next_timer_update() {
-                event_loop.set_control_flow(ControlFlow::wait_duration(next_timer));
+            let next_timer = corelib::platform::duration_until_next_timer_update();
+            let next_wakeup = match (next_timer, next_frame) {
+                (Some(timer), Some(frame)) => Some(timer.min(frame)),
+                (timer, frame) => timer.or(frame),
+            };
+            if let Some(next_wakeup) = next_wakeup {
+                event_loop.set_control_flow(ControlFlow::wait_duration(next_wakeup));
             }
         }
     }
@@ -688,6 +934,51 @@ fn memory_warning(&mut self, event_loop: &ActiveEventLoop) {
 }
 
 impl EventLoopState {
+    /// Dispatches the files that winit reported as hovered or dropped since the last call.
+    fn dispatch_file_drag(&mut self, dropped: bool) {
+        let Some(window) = self.file_drag_window.and_then(window_by_id) else { return };
+        // The events of the drag carry no position, and the last `CursorMoved` may be stale
+        let pointer_position = |window: &WinitWindowAdapter| {
+            window.winit_window().and_then(|w| crate::file_drop::pointer_position(&w))
+        };
+        let position = pointer_position(&*window).unwrap_or(self.cursor_pos);
+        let position = corelib::api::LogicalPosition::new(position.x, position.y);
+        let mime_type: corelib::SharedString = "text/uri-list".into();
+        let data: corelib::SharedString = self.dragged_files.as_str().into();
+        let event = if dropped {
+            self.dragged_files.clear();
+            self.file_drag_window = None;
+            self.file_drag_timer.stop();
+            corelib::platform::WindowEvent::Dropped { position, mime_type, data }
+        } else {
+            if crate::file_drop::NEEDS_POLLING {
+                let window_weak = Rc::downgrade(&window);
+                let (mime_type, data) = (mime_type.clone(), data.clone());
+                let last_position = std::cell::Cell::new(position);
+                self.file_drag_timer.start(
+                    corelib::timers::TimerMode::Repeated,
+                    std::time::Duration::from_millis(50),
+                    move || {
+                        let Some(window) = window_weak.upgrade() else { return };
+                        let Some(position) = pointer_position(&*window) else { return };
+                        let position = corelib::api::LogicalPosition::new(position.x, position.y);
+                        if last_position.replace(position) != position {
+                            window.window().dispatch_event(
+                                corelib::platform::WindowEvent::DragMoved {
+                                    position,
+                                    mime_type: mime_type.clone(),
+                                    data: data.clone(),
+                                },
+                            );
+                        }
+                    },
+                );
+            }
+            corelib::platform::WindowEvent::DragMoved { position, mime_type, data }
+        };
+        self.loop_error = window.window().try_dispatch_event(event).err();
+    }
+
     /// Runs the event loop and renders the items in the provided `component` in its
     /// own window.
     #[allow(unused_mut)] // mut need changes for wasm
@@ -0,0 +1,71 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Reads the position of the pointer while files are dragged over a window.
+//!
+//! winit reports hovered and dropped files without a position, and doesn't deliver `CursorMoved`
+//! during a drag on Windows and macOS, because the drag is driven by the operating system. So the
+//! position is queried from the system there, in window coordinates. On the other platforms, the
+//! pointer events keep coming during the drag, and the last position they reported is used.
+
+use i_slint_core::lengths::LogicalPoint;
+
+/// Returns the position of the pointer relative to `window`, in logical coordinates, or `None` if
+/// it can't be queried on this platform.
+pub fn pointer_position(window: &winit::window::Window) -> Option<LogicalPoint> {
+    #[cfg(target_family = "windows")]
+    return windows_pointer_position(window);
+
+    #[cfg(target_os = "macos")]
+    return macos_pointer_position(window);
+
+    #[allow(unreachable_code)]
+    {
+        let _ = window;
+        None
+    }
+}
+
+/// Whether [`pointer_position()`] must be polled to follow the pointer during a drag.
+pub const NEEDS_POLLING: bool = cfg!(any(target_family = "windows", target_os = "macos"));
+
+#[cfg(target_family = "windows")]
+fn windows_pointer_position(window: &winit::window::Window) -> Option<LogicalPoint> {
+    use raw_window_handle::HasWindowHandle;
+    use windows::Win32::Foundation::{HWND, POINT};
+    use windows::Win32::Graphics::Gdi::ScreenToClient;
+    use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
+
+    let handle = window.window_handle().ok()?;
+    let raw_window_handle::RawWindowHandle::Win32(handle) = handle.as_raw() else { return None };
+    let mut point = POINT::default();
+    // Safety: the point is a valid POINT, and the handle belongs to the window, which is alive.
+    unsafe {
+        GetCursorPos(&mut point).ok()?;
+        if !ScreenToClient(HWND(handle.hwnd.get() as _), &mut point).as_bool() {
+            return None;
+        }
+    }
+    let position = winit::dpi::PhysicalPosition::new(point.x, point.y)
+        .to_logical::<f32>(window.scale_factor());
+    Some(LogicalPoint::new(position.x, position.y))
+}
+
+#[cfg(target_os = "macos")]
+fn macos_pointer_position(window: &winit::window::Window) -> Option<LogicalPoint> {
+    use raw_window_handle::HasWindowHandle;
+
+    let handle = window.window_handle().ok()?;
+    let raw_window_handle::RawWindowHandle::AppKit(handle) = handle.as_raw() else { return None };
+    // Safety: the handle points to the NSView of the window, which is alive, and winit only
+    // delivers events on the main thread.
+    unsafe {
+        let view = &*(handle.ns_view.as_ptr() as *const objc2_app_kit::NSView);
+        let location = view.window()?.mouseLocationOutsideOfEventStream();
+        let mut point = view.convertPoint_fromView(location, None);
+        if !view.isFlipped() {
+            point.y = view.bounds().size.height - point.y;
+        }
+        Some(LogicalPoint::new(point.x as f32, point.y as f32))
+    }
+}
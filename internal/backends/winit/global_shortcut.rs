@@ -0,0 +1,122 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Keyboard shortcuts that work system-wide, also when no window of the application has the focus.
+//!
+//! **Note:** This module is only available with the "global-shortcuts" feature
+//!
+//! A [`GlobalShortcut`] is registered with `RegisterHotKey` on Windows, with the Carbon hot key API
+//! on macOS, and by grabbing the key on X11. Wayland doesn't let applications register global
+//! shortcuts, so creating one fails there.
+//!
+//! The events are delivered in the Slint event loop, with separate events for pressing and releasing
+//! the shortcut, for example for a push-to-talk function.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use i_slint_backend_winit::global_shortcut::{GlobalShortcut, GlobalShortcutEvent};
+//! let mut shortcut = GlobalShortcut::new("Ctrl+Shift+Space").unwrap();
+//! shortcut.on_event(|event| match event {
+//!     GlobalShortcutEvent::Pressed => { /* start recording */ }
+//!     GlobalShortcutEvent::Released => { /* stop recording */ }
+//! });
+//! ```
+
+use global_hotkey::hotkey::HotKey;
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+use i_slint_core::api::{invoke_from_event_loop, PlatformError};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// An event of a [`GlobalShortcut`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum GlobalShortcutEvent {
+    /// The keys of the shortcut were pressed.
+    Pressed,
+    /// A key of the shortcut was released after it was pressed.
+    Released,
+}
+
+thread_local! {
+    static EVENT_HANDLERS: RefCell<HashMap<u32, Rc<dyn Fn(GlobalShortcutEvent)>>> = Default::default();
+    // The manager must be created and used in the thread of the event loop.
+    static MANAGER: RefCell<Option<Rc<GlobalHotKeyManager>>> = Default::default();
+}
+
+fn to_platform_error(error: impl std::fmt::Display) -> PlatformError {
+    PlatformError::Other(format!("Global shortcut error: {error}"))
+}
+
+/// Returns the manager of the hot keys, creating it and installing the event handler the first
+/// time.
+fn manager() -> Result<Rc<GlobalHotKeyManager>, PlatformError> {
+    MANAGER.with(|manager| {
+        if let Some(manager) = manager.borrow().as_ref() {
+            return Ok(manager.clone());
+        }
+        let new_manager = Rc::new(GlobalHotKeyManager::new().map_err(to_platform_error)?);
+        GlobalHotKeyEvent::set_event_handler(Some(|event: GlobalHotKeyEvent| {
+            let id = event.id();
+            let event = match event.state() {
+                HotKeyState::Pressed => GlobalShortcutEvent::Pressed,
+                HotKeyState::Released => GlobalShortcutEvent::Released,
+            };
+            // The event may come from another thread, so it is forwarded to the event loop
+            // where the handler lives. Errors mean that the event loop has quit.
+            invoke_from_event_loop(move || {
+                // Clone the handler so that it can replace itself or drop the shortcut
+                let handler = EVENT_HANDLERS.with(|handlers| handlers.borrow().get(&id).cloned());
+                if let Some(handler) = handler {
+                    handler(event);
+                }
+            })
+            .ok();
+        }));
+        *manager.borrow_mut() = Some(new_manager.clone());
+        Ok(new_manager)
+    })
+}
+
+/// A keyboard shortcut registered with the operating system.
+///
+/// The shortcut is unregistered when this object is dropped.
+pub struct GlobalShortcut {
+    hotkey: HotKey,
+    manager: Rc<GlobalHotKeyManager>,
+}
+
+impl GlobalShortcut {
+    /// Registers the shortcut, written as modifiers (`Ctrl`, `Shift`, `Alt`, `Meta`) and a key
+    /// separated by `+`, like `Ctrl+Shift+Space` or `Alt+F9`. Like for the key events, `Ctrl` is
+    /// the command key on macOS.
+    ///
+    /// This fails when the shortcut can't be parsed, or when it's already registered by this
+    /// or another application.
+    pub fn new(shortcut: &str) -> Result<Self, PlatformError> {
+        let hotkey: HotKey = crate::accelerator_string(shortcut)
+            .parse()
+            .map_err(|_| PlatformError::Other(format!("Invalid global shortcut: {shortcut:?}")))?;
+        let manager = manager()?;
+        manager.register(hotkey).map_err(to_platform_error)?;
+        Ok(Self { hotkey, manager })
+    }
+
+    /// Sets the callback that is invoked when the shortcut is pressed or released.
+    ///
+    /// The callback is invoked from the Slint event loop. Calling this function again replaces
+    /// the previous callback.
+    pub fn on_event(&mut self, handler: impl Fn(GlobalShortcutEvent) + 'static) {
+        EVENT_HANDLERS
+            .with(|handlers| handlers.borrow_mut().insert(self.hotkey.id(), Rc::new(handler)));
+    }
+}
+
+impl Drop for GlobalShortcut {
+    fn drop(&mut self) {
+        self.manager.unregister(self.hotkey).ok();
+        EVENT_HANDLERS.try_with(|handlers| handlers.borrow_mut().remove(&self.hotkey.id())).ok();
+    }
+}
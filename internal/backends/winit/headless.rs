@@ -0,0 +1,173 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The headless mode of the winit backend, for running applications where there's no display server, such as
+//! in CI containers.
+//!
+//! Windows are virtual: they have a fixed initial size and are rendered with the software renderer into
+//! buffers in memory. The event loop doesn't connect to the windowing system, it only processes timers,
+//! animations, and the events posted with `slint::invoke_from_event_loop()`.
+
+use i_slint_core::api::PhysicalSize;
+use i_slint_core::platform::{EventLoopProxy, PlatformError};
+use i_slint_core::software_renderer::{
+    MinimalSoftwareWindow, PremultipliedRgbaColor, RepaintBufferType,
+};
+use i_slint_core::window::WindowAdapter;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::{Rc, Weak};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The size of virtual windows when `SLINT_WINIT_HEADLESS` doesn't specify one.
+const DEFAULT_WINDOW_SIZE: PhysicalSize = PhysicalSize::new(800, 600);
+
+/// The interval at which windows with running animations are redrawn.
+const ANIMATION_FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Returns the window size if the `SLINT_WINIT_HEADLESS` environment variable requests the headless mode.
+/// The variable is either set to the size of the windows, such as `1024x768`, or to any other value for the
+/// default size.
+pub fn window_size_from_env() -> Option<PhysicalSize> {
+    let value = std::env::var("SLINT_WINIT_HEADLESS").ok()?;
+    let size = value
+        .split_once('x')
+        .and_then(|(width, height)| {
+            Some(PhysicalSize::new(width.parse().ok()?, height.parse().ok()?))
+        })
+        .filter(|size| size.width > 0 && size.height > 0);
+    Some(size.unwrap_or(DEFAULT_WINDOW_SIZE))
+}
+
+enum Event {
+    Quit,
+    Invoke(Box<dyn FnOnce() + Send>),
+}
+
+#[derive(Clone)]
+struct Queue(Arc<Mutex<VecDeque<Event>>>, std::thread::Thread);
+
+impl EventLoopProxy for Queue {
+    fn quit_event_loop(&self) -> Result<(), i_slint_core::api::EventLoopError> {
+        self.0.lock().unwrap().push_back(Event::Quit);
+        self.1.unpark();
+        Ok(())
+    }
+
+    fn invoke_from_event_loop(
+        &self,
+        event: Box<dyn FnOnce() + Send>,
+    ) -> Result<(), i_slint_core::api::EventLoopError> {
+        self.0.lock().unwrap().push_back(Event::Invoke(event));
+        self.1.unpark();
+        Ok(())
+    }
+}
+
+/// A virtual window and the buffer it's rendered into.
+struct HeadlessWindow {
+    adapter: Weak<MinimalSoftwareWindow>,
+    buffer: Vec<PremultipliedRgbaColor>,
+}
+
+pub struct HeadlessEventLoop {
+    window_size: PhysicalSize,
+    queue: Queue,
+    windows: RefCell<Vec<HeadlessWindow>>,
+}
+
+impl HeadlessEventLoop {
+    pub fn new(window_size: PhysicalSize) -> Self {
+        Self {
+            window_size,
+            queue: Queue(Default::default(), std::thread::current()),
+            windows: Default::default(),
+        }
+    }
+
+    pub fn create_window_adapter(&self) -> Rc<dyn WindowAdapter> {
+        let adapter = MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+        adapter.set_size(self.window_size);
+        self.windows
+            .borrow_mut()
+            .push(HeadlessWindow { adapter: Rc::downgrade(&adapter), buffer: Vec::new() });
+        adapter
+    }
+
+    pub fn new_event_loop_proxy(&self) -> Box<dyn EventLoopProxy> {
+        Box::new(self.queue.clone())
+    }
+
+    /// Processes events, timers, and animations, and renders the windows, until the event loop is quit or, if
+    /// a timeout is given, until the timeout elapsed.
+    pub fn run(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<core::ops::ControlFlow<()>, PlatformError> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            i_slint_core::platform::update_timers_and_animations();
+
+            let event = self.queue.0.lock().unwrap().pop_front();
+            match event {
+                Some(Event::Quit) => return Ok(core::ops::ControlFlow::Break(())),
+                Some(Event::Invoke(callback)) => {
+                    callback();
+                    continue;
+                }
+                None => {}
+            }
+
+            let has_active_animations = self.render_windows();
+
+            let mut wait = i_slint_core::platform::duration_until_next_timer_update();
+            if !has_active_animations && i_slint_core::platform::run_idle_callbacks() {
+                // More idle callbacks are pending, don't sleep
+                wait = Some(Duration::ZERO);
+            }
+            if has_active_animations {
+                wait =
+                    Some(wait.map_or(ANIMATION_FRAME_INTERVAL, |wait| {
+                        wait.min(ANIMATION_FRAME_INTERVAL)
+                    }));
+            }
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Ok(core::ops::ControlFlow::Continue(()));
+                }
+                wait = Some(wait.map_or(remaining, |wait| wait.min(remaining)));
+            }
+
+            match wait {
+                Some(wait) => std::thread::park_timeout(wait),
+                None => std::thread::park(),
+            }
+        }
+    }
+
+    /// Renders the windows that need to be redrawn, and returns true if any of them has active animations.
+    fn render_windows(&self) -> bool {
+        let mut has_active_animations = false;
+        // Rendering may evaluate bindings that create windows, so don't keep the list borrowed
+        let mut windows = std::mem::take(&mut *self.windows.borrow_mut());
+        windows.retain_mut(|window| {
+            let Some(adapter) = window.adapter.upgrade() else { return false };
+            adapter.draw_if_needed(|renderer| {
+                let size = adapter.size();
+                window.buffer.clear();
+                window
+                    .buffer
+                    .resize(size.width as usize * size.height as usize, Default::default());
+                renderer.render(&mut window.buffer, size.width as usize);
+            });
+            has_active_animations |= adapter.window().has_active_animations();
+            true
+        });
+        let mut current_windows = self.windows.borrow_mut();
+        windows.append(&mut current_windows);
+        *current_windows = windows;
+        has_active_animations
+    }
+}
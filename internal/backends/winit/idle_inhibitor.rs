@@ -0,0 +1,154 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Keeps the screen on and the system awake while a window asks for it with `Window::set_idle_inhibited()`.
+//!
+//! On Linux, the inhibition is requested through the `org.freedesktop.ScreenSaver` D-Bus interface, which
+//! GNOME, KDE Plasma, and most other desktops implement on X11 and Wayland. On Windows,
+//! `SetThreadExecutionState` is used, and on macOS an IOKit power assertion.
+
+// cspell:ignore IOPM
+
+use i_slint_core::SlintContext;
+
+const REASON: &str = "Presenting content";
+
+/// Inhibits the screen saver and idle sleep until it is dropped.
+pub struct IdleInhibitor {
+    /// Dropping the sender makes the task that holds the D-Bus connection release the inhibition.
+    #[cfg(not(use_winit_theme))]
+    _release: futures::channel::oneshot::Sender<()>,
+    #[cfg(target_os = "macos")]
+    assertion_id: u32,
+}
+
+impl IdleInhibitor {
+    #[allow(unused_variables)]
+    pub fn new(context: &SlintContext, application_name: &str) -> Option<Self> {
+        #[cfg(not(use_winit_theme))]
+        {
+            let application_name = application_name.to_string();
+            let (release_sender, release_receiver) = futures::channel::oneshot::channel::<()>();
+            context
+                .spawn_local(async move {
+                    let Ok(connection) = ashpd::zbus::Connection::session().await else { return };
+                    let Ok(proxy) = ashpd::zbus::Proxy::new(
+                        &connection,
+                        "org.freedesktop.ScreenSaver",
+                        "/org/freedesktop/ScreenSaver",
+                        "org.freedesktop.ScreenSaver",
+                    )
+                    .await
+                    else {
+                        return;
+                    };
+                    let Ok(cookie) =
+                        proxy.call::<_, _, u32>("Inhibit", &(application_name, REASON)).await
+                    else {
+                        return;
+                    };
+                    // Resolves when the IdleInhibitor is dropped
+                    release_receiver.await.ok();
+                    proxy.call::<_, _, ()>("UnInhibit", &(cookie,)).await.ok();
+                })
+                .ok()?;
+            Some(Self { _release: release_sender })
+        }
+
+        #[cfg(target_family = "windows")]
+        {
+            windows_execution_state::acquire();
+            Some(Self {})
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            let assertion_id = macos_power_assertion::create()?;
+            Some(Self { assertion_id })
+        }
+
+        #[cfg(not(any(not(use_winit_theme), target_family = "windows", target_os = "macos")))]
+        None
+    }
+}
+
+impl Drop for IdleInhibitor {
+    fn drop(&mut self) {
+        #[cfg(target_family = "windows")]
+        windows_execution_state::release();
+
+        #[cfg(target_os = "macos")]
+        macos_power_assertion::release(self.assertion_id);
+    }
+}
+
+/// The execution state applies to the whole thread, so it's only reset when no window inhibits idling anymore.
+#[cfg(target_family = "windows")]
+mod windows_execution_state {
+    use std::cell::Cell;
+    use windows::Win32::System::Power::{
+        SetThreadExecutionState, ES_CONTINUOUS, ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED,
+    };
+
+    thread_local! {
+        static INHIBITOR_COUNT: Cell<usize> = Cell::new(0);
+    }
+
+    pub fn acquire() {
+        if INHIBITOR_COUNT.replace(INHIBITOR_COUNT.get() + 1) == 0 {
+            // Safety: this function has no preconditions.
+            unsafe {
+                SetThreadExecutionState(ES_CONTINUOUS | ES_DISPLAY_REQUIRED | ES_SYSTEM_REQUIRED)
+            };
+        }
+    }
+
+    pub fn release() {
+        if INHIBITOR_COUNT.replace(INHIBITOR_COUNT.get() - 1) == 1 {
+            // Safety: this function has no preconditions.
+            unsafe { SetThreadExecutionState(ES_CONTINUOUS) };
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos_power_assertion {
+    use objc2_foundation::NSString;
+    use std::ffi::c_void;
+
+    const IOPM_ASSERTION_LEVEL_ON: u32 = 255;
+    const IO_RETURN_SUCCESS: i32 = 0;
+
+    #[link(name = "IOKit", kind = "framework")]
+    extern "C" {
+        fn IOPMAssertionCreateWithName(
+            assertion_type: *const c_void,
+            assertion_level: u32,
+            assertion_name: *const c_void,
+            assertion_id: *mut u32,
+        ) -> i32;
+        fn IOPMAssertionRelease(assertion_id: u32) -> i32;
+    }
+
+    pub fn create() -> Option<u32> {
+        // NSString is toll-free bridged with CFString
+        let assertion_type = NSString::from_str("PreventUserIdleDisplaySleep");
+        let assertion_name = NSString::from_str(super::REASON);
+        let mut assertion_id = 0;
+        // Safety: the strings are valid CFStrings for the duration of the call.
+        let result = unsafe {
+            IOPMAssertionCreateWithName(
+                &*assertion_type as *const NSString as *const c_void,
+                IOPM_ASSERTION_LEVEL_ON,
+                &*assertion_name as *const NSString as *const c_void,
+                &mut assertion_id,
+            )
+        };
+        (result == IO_RETURN_SUCCESS).then_some(assertion_id)
+    }
+
+    pub fn release(assertion_id: u32) {
+        // Safety: the assertion was created by create().
+        unsafe { IOPMAssertionRelease(assertion_id) };
+    }
+}
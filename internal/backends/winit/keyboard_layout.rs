@@ -0,0 +1,55 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Looks up the keyboard layout of Windows, for `Window::physical_key_text()` and to notice when the
+//! user switches layouts. Windows only notifies the focused window with `WM_INPUTLANGCHANGE`, which
+//! winit doesn't forward, so the layout is compared when the window is activated and on key presses.
+
+use i_slint_core::SharedString;
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    GetKeyboardLayout, MapVirtualKeyExW, ToUnicodeEx, MAPVK_VSC_TO_VK_EX,
+};
+use windows::Win32::UI::TextServices::HKL;
+
+/// Don't change the keyboard state, so that looking up a dead key doesn't affect the next key press.
+const TO_UNICODE_NO_STATE_CHANGE: u32 = 0x4;
+
+/// Returns the keyboard layout of the current thread, which is the one used for the key events.
+pub fn current_layout() -> HKL {
+    // Safety: this function has no preconditions.
+    unsafe { GetKeyboardLayout(0) }
+}
+
+/// Returns the text that the physical key named `physical_key` produces without modifiers in `layout`.
+pub fn physical_key_text(physical_key: &str, layout: HKL) -> Option<SharedString> {
+    use winit::platform::scancode::PhysicalKeyExtScancode;
+
+    // winit doesn't map the names back to keys, so look for the scan code with that name. The keys
+    // that produce text all have scan codes without the extended prefix.
+    let scancode = (1..0x80).find(|scancode| {
+        crate::event_loop::physical_key_name(winit::keyboard::PhysicalKey::from_scancode(*scancode))
+            == physical_key
+    })?;
+
+    // Safety: the key state and the buffer are valid for the duration of the calls.
+    let (virtual_key, result, buffer) = unsafe {
+        let virtual_key = MapVirtualKeyExW(scancode, MAPVK_VSC_TO_VK_EX, layout);
+        let mut buffer = [0u16; 8];
+        let result = ToUnicodeEx(
+            virtual_key,
+            scancode,
+            &[0u8; 256],
+            &mut buffer,
+            TO_UNICODE_NO_STATE_CHANGE,
+            layout,
+        );
+        (virtual_key, result, buffer)
+    };
+    if virtual_key == 0 {
+        return None;
+    }
+    // A dead key, like `^` in the French layout, returns -1 and its spacing character
+    let len = if result < 0 { 1 } else { result as usize };
+    let text = String::from_utf16(&buffer[..len.min(buffer.len())]).ok()?;
+    (!text.is_empty() && !text.chars().any(char::is_control)).then(|| text.into())
+}
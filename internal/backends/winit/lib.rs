@@ -16,9 +16,20 @@
 #[cfg(not(target_arch = "wasm32"))]
 use std::rc::Weak;
 
+mod accent_color;
 #[cfg(not(target_arch = "wasm32"))]
 mod clipboard;
 mod drag_resize_window;
+mod file_drop;
+#[cfg(enable_headless)]
+mod headless;
+mod idle_inhibitor;
+#[cfg(target_family = "windows")]
+mod keyboard_layout;
+#[cfg(any(target_family = "windows", target_os = "macos"))]
+mod system_font;
+#[cfg(target_family = "windows")]
+mod window_frame;
 mod winitwindowadapter;
 
 use i_slint_core::platform::PlatformError;
@@ -77,8 +88,44 @@ fn resume(
 
 #[cfg(enable_accesskit)]
 mod accesskit;
+#[cfg(enable_global_shortcuts)]
+pub mod global_shortcut;
 #[cfg(muda)]
 mod muda;
+#[cfg(enable_tray_icon)]
+pub mod tray_icon;
+
+/// Converts a keyboard shortcut written like the `shortcut` of a `MenuEntry`, such as
+/// `Ctrl+Shift+S`, to the syntax of the accelerators of muda and global-hotkey.
+///
+/// Like for the key events, Slint's control modifier is the command key on macOS.
+#[cfg(any(muda, enable_global_shortcuts))]
+pub(crate) fn accelerator_string(shortcut: &str) -> String {
+    let (modifiers, key) = match shortcut.strip_suffix("++") {
+        Some(modifiers) => (modifiers, "+"),
+        None => shortcut.rsplit_once('+').unwrap_or(("", shortcut)),
+    };
+    let key = match key {
+        "UpArrow" => "ArrowUp",
+        "DownArrow" => "ArrowDown",
+        "LeftArrow" => "ArrowLeft",
+        "RightArrow" => "ArrowRight",
+        "Return" => "Enter",
+        key => key,
+    };
+    let mut accelerator = String::new();
+    for modifier in modifiers.split('+').filter(|modifier| !modifier.is_empty()) {
+        accelerator.push_str(match modifier {
+            "Ctrl" | "Control" => "CmdOrCtrl",
+            "Meta" if cfg!(target_os = "macos") => "Ctrl",
+            "Meta" => "Super",
+            modifier => modifier,
+        });
+        accelerator.push('+');
+    }
+    accelerator.push_str(key);
+    accelerator
+}
 
 #[cfg(target_arch = "wasm32")]
 pub(crate) mod wasm_input_helper;
@@ -171,6 +218,11 @@ pub struct BackendBuilder {
         Option<Box<dyn Fn(winit::window::WindowAttributes) -> winit::window::WindowAttributes>>,
     renderer_name: Option<String>,
     event_loop_builder: Option<winit::event_loop::EventLoopBuilder<SlintUserEvent>>,
+    window_creation_hook: Option<event_loop::WindowCreationHook>,
+    window_event_filter: Option<event_loop::WindowEventFilter>,
+    device_event_filter: Option<event_loop::DeviceEventFilter>,
+    #[cfg(enable_headless)]
+    headless_window_size: Option<i_slint_core::api::PhysicalSize>,
 }
 
 impl BackendBuilder {
@@ -211,6 +263,35 @@ pub fn with_window_attributes_hook(
         self
     }
 
+    /// Configures this builder to use the specified hook that is called right before the winit window
+    /// of a Slint window is created, also when it's created again after the application was suspended.
+    ///
+    /// Unlike the hook set with [`Self::with_window_attributes_hook`], it receives the Slint window and
+    /// the final attributes, including the application id and the activation token, so that it can
+    /// override them or add platform specific attributes, such as the parent window or the class hints.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// let mut backend = i_slint_backend_winit::Backend::builder()
+    ///     .with_window_creation_hook(|_window, attributes| attributes.with_resizable(false))
+    ///     .build()
+    ///     .unwrap();
+    /// slint::platform::set_platform(Box::new(backend));
+    /// ```
+    #[must_use]
+    pub fn with_window_creation_hook(
+        mut self,
+        hook: impl Fn(
+                &i_slint_core::api::Window,
+                winit::window::WindowAttributes,
+            ) -> winit::window::WindowAttributes
+            + 'static,
+    ) -> Self {
+        self.window_creation_hook = Some(Box::new(hook));
+        self
+    }
+
     /// Configures this builder to use the specified event loop builder when creating the event
     /// loop during a subsequent call to [`Self::build`].
     #[must_use]
@@ -222,6 +303,66 @@ pub fn with_event_loop_builder(
         self
     }
 
+    /// Configures this builder to use the specified filter for the events of all windows, before Slint
+    /// and the filter of each window set with
+    /// [`WinitWindowAccessor::on_winit_window_event`] process them. This makes it possible to
+    /// integrate existing input handling code that works with winit events. Return
+    /// [`WinitWindowEventResult::PreventDefault`] to consume the event.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use i_slint_backend_winit::WinitWindowEventResult;
+    /// let mut backend = i_slint_backend_winit::Backend::builder()
+    ///     .with_window_event_filter(|_window, event| match event {
+    ///         winit::event::WindowEvent::DroppedFile(path) => {
+    ///             println!("dropped {}", path.display());
+    ///             WinitWindowEventResult::PreventDefault
+    ///         }
+    ///         _ => WinitWindowEventResult::Propagate,
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    /// slint::platform::set_platform(Box::new(backend));
+    /// ```
+    #[must_use]
+    pub fn with_window_event_filter(
+        mut self,
+        filter: impl FnMut(&i_slint_core::api::Window, &winit::event::WindowEvent) -> WinitWindowEventResult
+            + 'static,
+    ) -> Self {
+        self.window_event_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Configures this builder to use the specified filter for the [`winit::event::DeviceEvent`]s, such
+    /// as the raw motion of the mouse, which aren't tied to a window. Slint doesn't handle device events
+    /// itself, so the return value has no effect at the moment.
+    #[must_use]
+    pub fn with_device_event_filter(
+        mut self,
+        filter: impl FnMut(winit::event::DeviceId, &winit::event::DeviceEvent) -> WinitWindowEventResult
+            + 'static,
+    ) -> Self {
+        self.device_event_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Configures this builder to build a backend that runs without a windowing system, for example to run
+    /// integration tests in CI containers without a display server. Windows are virtual and have the
+    /// specified size, and they're rendered with the software renderer into memory, where
+    /// [`Window::take_snapshot()`](i_slint_core::api::Window::take_snapshot) can capture them. Accessing the
+    /// winit window with [`WinitWindowAccessor`] isn't possible, and the renderer name, the requested graphics
+    /// API, and the event loop builder are ignored.
+    ///
+    /// Setting the `SLINT_WINIT_HEADLESS` environment variable to a size such as `1024x768` has the same effect.
+    #[cfg(enable_headless)]
+    #[must_use]
+    pub fn with_headless_window_size(mut self, size: i_slint_core::api::PhysicalSize) -> Self {
+        self.headless_window_size = Some(size);
+        self
+    }
+
     /// Builds the backend with the parameters configured previously. Set the resulting backend
     /// with `slint::platform::set_platform()`:
     ///
@@ -235,9 +376,27 @@ pub fn with_event_loop_builder(
     /// slint::platform::set_platform(Box::new(backend));
     /// ```
     pub fn build(self) -> Result<Backend, PlatformError> {
+        #[cfg(enable_headless)]
+        if let Some(window_size) = self.headless_window_size.or_else(headless::window_size_from_env)
+        {
+            return Ok(Backend {
+                requested_graphics_api: None,
+                renderer_factory_fn: renderer::sw::WinitSoftwareRenderer::new_suspended,
+                event_loop_state: Default::default(),
+                window_attributes_hook: self.window_attributes_hook,
+                clipboard: Weak::new(),
+                proxy: None,
+                headless: Some(headless::HeadlessEventLoop::new(window_size)),
+            });
+        }
+
         let event_loop_builder =
             self.event_loop_builder.unwrap_or_else(winit::event_loop::EventLoop::with_user_event);
 
+        crate::event_loop::WINDOW_CREATION_HOOK.set(self.window_creation_hook);
+        crate::event_loop::WINDOW_EVENT_FILTER.set(self.window_event_filter);
+        crate::event_loop::DEVICE_EVENT_FILTER.set(self.device_event_filter);
+
         // Initialize the winit event loop and propagate errors if for example `DISPLAY` or `WAYLAND_DISPLAY` isn't set.
 
         let nre = crate::event_loop::NotRunningEventLoop::new(Some(event_loop_builder))?;
@@ -317,7 +476,9 @@ pub fn build(self) -> Result<Backend, PlatformError> {
             window_attributes_hook: self.window_attributes_hook,
             #[cfg(not(target_arch = "wasm32"))]
             clipboard: clipboard.into(),
-            proxy,
+            proxy: Some(proxy),
+            #[cfg(enable_headless)]
+            headless: None,
         })
     }
 }
@@ -335,7 +496,10 @@ pub struct Backend {
     requested_graphics_api: Option<RequestedGraphicsAPI>,
     renderer_factory_fn: fn() -> Box<dyn WinitCompatibleRenderer>,
     event_loop_state: std::cell::RefCell<Option<crate::event_loop::EventLoopState>>,
-    proxy: winit::event_loop::EventLoopProxy<SlintUserEvent>,
+    /// None in the headless mode, which doesn't create a winit event loop.
+    proxy: Option<winit::event_loop::EventLoopProxy<SlintUserEvent>>,
+    #[cfg(enable_headless)]
+    headless: Option<headless::HeadlessEventLoop>,
 
     /// This hook is called before a Window is created.
     ///
@@ -389,6 +553,11 @@ pub fn builder() -> BackendBuilder {
             window_attributes_hook: None,
             renderer_name: None,
             event_loop_builder: None,
+            window_creation_hook: None,
+            window_event_filter: None,
+            device_event_filter: None,
+            #[cfg(enable_headless)]
+            headless_window_size: None,
         }
     }
 }
@@ -426,6 +595,12 @@ fn send_event_via_global_event_loop_proxy(
 
 impl i_slint_core::platform::Platform for Backend {
     fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        #[cfg(enable_headless)]
+        if let Some(headless) = &self.headless {
+            return Ok(headless.create_window_adapter());
+        }
+        let proxy = self.proxy.as_ref().ok_or(PlatformError::NoEventLoopProvider)?;
+
         let mut attrs = WinitWindowAdapter::window_attributes(
             #[cfg(target_arch = "wasm32")]
             "canvas".into(),
@@ -440,16 +615,20 @@ fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError>
             attrs.clone(),
             self.requested_graphics_api.clone(),
             #[cfg(any(enable_accesskit, muda))]
-            self.proxy.clone(),
+            proxy.clone(),
         )
         .or_else(|e| {
-            try_create_window_with_fallback_renderer(attrs, &self.proxy)
+            try_create_window_with_fallback_renderer(attrs, proxy)
                 .ok_or_else(|| format!("Winit backend failed to find a suitable renderer: {e}"))
         })?;
         Ok(adapter)
     }
 
     fn run_event_loop(&self) -> Result<(), PlatformError> {
+        #[cfg(enable_headless)]
+        if let Some(headless) = &self.headless {
+            return headless.run(None).map(|_| ());
+        }
         let loop_state = self.event_loop_state.borrow_mut().take().unwrap_or_default();
         let new_state = loop_state.run()?;
         *self.event_loop_state.borrow_mut() = Some(new_state);
@@ -462,6 +641,10 @@ fn process_events(
         timeout: core::time::Duration,
         _: i_slint_core::InternalToken,
     ) -> Result<core::ops::ControlFlow<()>, PlatformError> {
+        #[cfg(enable_headless)]
+        if let Some(headless) = &self.headless {
+            return headless.run(Some(timeout));
+        }
         let loop_state = self.event_loop_state.borrow_mut().take().unwrap_or_default();
         let (new_state, status) = loop_state.pump_events(Some(timeout))?;
         *self.event_loop_state.borrow_mut() = Some(new_state);
@@ -480,6 +663,10 @@ fn process_events(
     }
 
     fn new_event_loop_proxy(&self) -> Option<Box<dyn EventLoopProxy>> {
+        #[cfg(enable_headless)]
+        if let Some(headless) = &self.headless {
+            return Some(headless.new_event_loop_proxy());
+        }
         struct Proxy;
         impl EventLoopProxy for Proxy {
             fn quit_event_loop(&self) -> Result<(), i_slint_core::api::EventLoopError> {
@@ -522,6 +709,50 @@ fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option
         let mut pair = clipboard_pair.borrow_mut();
         clipboard::select_clipboard(&mut pair, clipboard).and_then(|c| c.get_contents().ok())
     }
+
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+    fn set_clipboard_image(
+        &self,
+        image: &i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            clipboard::set_image(image);
+        }
+    }
+
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+    fn clipboard_image(
+        &self,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) -> Option<i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>> {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            clipboard::image()
+        } else {
+            None
+        }
+    }
+
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+    fn set_clipboard_html(
+        &self,
+        html: &str,
+        alt_text: &str,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            clipboard::set_html(html, alt_text);
+        }
+    }
+
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+    fn clipboard_html(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            clipboard::html()
+        } else {
+            None
+        }
+    }
 }
 
 /// Spawn the event loop, using [`winit::platform::web::EventLoopExtWebSys::spawn()`]
@@ -628,4 +859,26 @@ fn test_window_accessor_and_rwh() {
     use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
     assert!(handle.window_handle().is_ok());
     assert!(handle.display_handle().is_ok());
+
+    // A custom cursor set while the winit window doesn't exist is applied when it's created
+    app.show().unwrap();
+    std::env::set_var("SLINT_DESTROY_WINDOW_ON_HIDE", "1");
+    app.hide().unwrap();
+    assert!(!slint_window.has_winit_window());
+    let pixels = slint::SharedPixelBuffer::<slint::Rgba8Pixel>::new(4, 4);
+    let cursor = slint::CustomCursor::new(
+        slint::Image::from_rgba8(pixels),
+        slint::PhysicalPosition::new(2, 2),
+    );
+    slint_window.set_custom_cursor(Some(cursor)).unwrap();
+    app.show().unwrap();
+    assert!(slint_window.has_winit_window());
+    let window_adapter = i_slint_core::window::WindowInner::from_pub(slint_window).window_adapter();
+    let adapter = window_adapter
+        .internal(i_slint_core::InternalToken)
+        .and_then(|wa| wa.as_any().downcast_ref::<WinitWindowAdapter>())
+        .unwrap();
+    assert!(adapter.custom_cursor_frame().is_some());
+    slint_window.set_custom_cursor(None).unwrap();
+    assert!(adapter.custom_cursor_frame().is_none());
 }
@@ -6,11 +6,45 @@
 use winit::event_loop::EventLoopProxy;
 use winit::window::Window;
 
+/// Converts the shortcut of a menu entry, like `Ctrl+Shift+S`, to a muda accelerator.
+fn accelerator(shortcut: &str) -> Option<muda::accelerator::Accelerator> {
+    if shortcut.is_empty() {
+        return None;
+    }
+    crate::accelerator_string(shortcut).parse().ok()
+}
+
 pub struct MudaAdapter {
     entries: Vec<i_slint_core::items::MenuEntry>,
     menubar: vtable::VBox<MenuVTable>,
     // We need to keep menu alive, otherwise muda segfaults
     _menu: muda::Menu,
+    #[cfg(target_os = "windows")]
+    hwnd: Option<isize>,
+}
+
+#[cfg(target_os = "windows")]
+thread_local! {
+    /// The accelerator tables of the menu bars, by window handle.
+    static ACCELERATORS: std::cell::RefCell<std::collections::HashMap<isize, isize>> =
+        Default::default();
+}
+
+/// Message hook of the event loop that lets muda handle the keyboard shortcuts of the menu bars,
+/// which Windows only does for the messages passed to `TranslateAccelerator`.
+#[cfg(target_os = "windows")]
+pub fn translate_accelerator(msg: *const std::ffi::c_void) -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::{TranslateAcceleratorW, HACCEL, MSG};
+    let msg = msg as *const MSG;
+    let hwnd = unsafe { (*msg).hwnd };
+    let Some(haccel) = ACCELERATORS
+        .try_with(|accelerators| accelerators.borrow().get(&(hwnd.0 as isize)).copied())
+        .ok()
+        .flatten()
+    else {
+        return false;
+    };
+    unsafe { TranslateAcceleratorW(hwnd, HACCEL(haccel as _), msg) != 0 }
 }
 
 impl MudaAdapter {
@@ -32,15 +66,25 @@ fn generate_menu_entry(
             map.push(entry.clone());
             // the top level always has a sub menu regardless of entry.has_sub_menu
             if !entry.has_sub_menu && depth != 0 {
-                Box::new(muda::MenuItem::with_id(
-                    id.clone(),
-                    &entry.title,
-                    true, /*entry.enabled*/
-                    None,
-                ))
+                let accelerator = accelerator(&entry.shortcut);
+                if entry.checkable {
+                    Box::new(muda::CheckMenuItem::with_id(
+                        id.clone(),
+                        &entry.title,
+                        !entry.disabled,
+                        entry.checked,
+                        accelerator,
+                    ))
+                } else {
+                    Box::new(muda::MenuItem::with_id(
+                        id.clone(),
+                        &entry.title,
+                        !entry.disabled,
+                        accelerator,
+                    ))
+                }
             } else {
-                let sub_menu =
-                    muda::Submenu::with_id(id.clone(), &entry.title, true /*entry.enabled*/);
+                let sub_menu = muda::Submenu::with_id(id.clone(), &entry.title, !entry.disabled);
                 if depth < 15 {
                     let mut sub_entries = Default::default();
                     menu.sub_menu(Some(entry), &mut sub_entries);
@@ -78,18 +122,30 @@ fn generate_menu_entry(
         }));
 
         #[cfg(target_os = "windows")]
-        {
+        let hwnd = {
             use winit::raw_window_handle::*;
             if let RawWindowHandle::Win32(handle) = winit_window.window_handle().unwrap().as_raw() {
                 unsafe { menu.init_for_hwnd(handle.hwnd.get()).unwrap() };
+                ACCELERATORS.with(|accelerators| {
+                    accelerators.borrow_mut().insert(handle.hwnd.get(), menu.haccel() as isize)
+                });
+                Some(handle.hwnd.get())
+            } else {
+                None
             }
-        }
+        };
         #[cfg(target_os = "macos")]
         {
             menu.init_for_nsapp();
         }
 
-        Self { entries: map, menubar, _menu: menu }
+        Self {
+            entries: map,
+            menubar,
+            _menu: menu,
+            #[cfg(target_os = "windows")]
+            hwnd,
+        }
     }
 
     pub fn invoke(&self, entry_id: usize) {
@@ -97,3 +153,12 @@ pub fn invoke(&self, entry_id: usize) {
         self.menubar.activate(entry);
     }
 }
+
+#[cfg(target_os = "windows")]
+impl Drop for MudaAdapter {
+    fn drop(&mut self) {
+        if let Some(hwnd) = self.hwnd {
+            ACCELERATORS.try_with(|accelerators| accelerators.borrow_mut().remove(&hwnd)).ok();
+        }
+    }
+}
@@ -19,6 +19,8 @@
 pub struct GlutinFemtoVGRenderer {
     renderer: FemtoVGRenderer,
     suspended: Cell<bool>,
+    /// Whether the window wants vsync, shared with the OpenGL context that applies it when swapping buffers.
+    vsync_enabled: Rc<Cell<bool>>,
 }
 
 impl GlutinFemtoVGRenderer {
@@ -26,12 +28,14 @@ pub fn new_suspended() -> Box<dyn WinitCompatibleRenderer> {
         Box::new(Self {
             renderer: FemtoVGRenderer::new_without_context(),
             suspended: Cell::new(true),
+            vsync_enabled: Rc::new(Cell::new(true)),
         })
     }
 }
 
 impl super::WinitCompatibleRenderer for GlutinFemtoVGRenderer {
-    fn render(&self, _window: &i_slint_core::api::Window) -> Result<(), PlatformError> {
+    fn render(&self, window: &i_slint_core::api::Window) -> Result<(), PlatformError> {
+        self.vsync_enabled.set(window.vsync_enabled());
         self.renderer.render()
     }
 
@@ -52,6 +56,7 @@ fn resume(
                 window_attributes,
                 event_loop.event_loop(),
                 requested_graphics_api.map(TryInto::try_into).transpose()?,
+                self.vsync_enabled.clone(),
             )?)
         })?;
 
@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use std::{num::NonZeroU32, rc::Rc};
+use std::{cell::Cell, num::NonZeroU32, rc::Rc};
 
 use glutin::{
     config::GlConfig,
@@ -17,6 +17,10 @@ pub struct OpenGLContext {
     context: glutin::context::PossiblyCurrentContext,
     surface: glutin::surface::Surface<glutin::surface::WindowSurface>,
     winit_window: Rc<winit::window::Window>,
+    /// Whether the window wants vsync, updated by the renderer before each frame.
+    vsync_enabled: Rc<Cell<bool>>,
+    /// The vsync setting last applied to the surface.
+    applied_vsync: Cell<bool>,
 }
 
 unsafe impl i_slint_renderer_femtovg::OpenGLInterface for OpenGLContext {
@@ -29,6 +33,12 @@ fn ensure_current(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
         Ok(())
     }
     fn swap_buffers(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let vsync = self.vsync_enabled.get();
+        if self.applied_vsync.replace(vsync) != vsync {
+            // Ignore if the driver doesn't support changing the swap interval.
+            self.surface.set_swap_interval(&self.context, swap_interval(vsync)).ok();
+        }
+
         self.winit_window.pre_present_notify();
 
         self.surface.swap_buffers(&self.context).map_err(|glutin_error| -> PlatformError {
@@ -59,6 +69,7 @@ pub(crate) fn new_context(
         window_attributes: winit::window::WindowAttributes,
         event_loop: crate::event_loop::ActiveOrInactiveEventLoop<'_>,
         requested_opengl_version: Option<RequestedOpenGLVersion>,
+        vsync_enabled: Rc<Cell<bool>>,
     ) -> Result<(Rc<winit::window::Window>, Self), PlatformError> {
         let config_template_builder = glutin::config::ConfigTemplateBuilder::new();
 
@@ -240,15 +251,28 @@ pub(crate) fn new_context(
         }
 
         // Try to default to vsync and ignore if the driver doesn't support it.
-        surface
-            .set_swap_interval(
-                &context,
-                glutin::surface::SwapInterval::Wait(NonZeroU32::new(1).unwrap()),
-            )
-            .ok();
+        let vsync = vsync_enabled.get();
+        surface.set_swap_interval(&context, swap_interval(vsync)).ok();
 
         let window = Rc::new(window);
 
-        Ok((window.clone(), Self { context, surface, winit_window: window }))
+        Ok((
+            window.clone(),
+            Self {
+                context,
+                surface,
+                winit_window: window,
+                vsync_enabled,
+                applied_vsync: Cell::new(vsync),
+            },
+        ))
+    }
+}
+
+fn swap_interval(vsync: bool) -> glutin::surface::SwapInterval {
+    if vsync {
+        glutin::surface::SwapInterval::Wait(NonZeroU32::new(1).unwrap())
+    } else {
+        glutin::surface::SwapInterval::DontWait
     }
 }
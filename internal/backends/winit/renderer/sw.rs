@@ -56,6 +56,14 @@ fn blend(&mut self, color: PremultipliedRgbaColor) {
         *self = x.into();
     }
 
+    fn blend_linear(&mut self, color: PremultipliedRgbaColor) {
+        let mut x = PremultipliedRgbaColor::from(*self);
+        x.blend_linear(color);
+        *self = x.into();
+    }
+
+    const SUPPORTS_LINEAR_BLENDING: bool = true;
+
     fn from_rgb(r: u8, g: u8, b: u8) -> Self {
         Self(0xff000000 | ((r as u32) << 16) | ((g as u32) << 8) | (b as u32))
     }
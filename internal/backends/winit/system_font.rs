@@ -0,0 +1,142 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Reads the default font and the text scaling factor of the system settings on Windows and macOS,
+//! for `WindowEvent::SystemFontChanged`.
+//!
+//! On Windows, it's the message font and the "Make text bigger" accessibility setting. Windows
+//! broadcasts `WM_SETTINGCHANGE` to the top-level windows when they change, which winit doesn't
+//! forward, so [`watch_setting_changes()`] subclasses the window to receive it. macOS has no text
+//! scaling setting besides the size of the system font, and the renderers already use the system
+//! font by default, so the size is read again when a window is activated. On Linux, the settings
+//! are read from the XDG settings portal in `WinitWindowAdapter::spawn_system_font_watcher()`.
+
+use i_slint_core::SharedString;
+
+/// Returns the family of the default font of the system, or an empty string to let the renderer
+/// choose, and the factor by which the user asked to enlarge texts.
+pub fn system_font() -> (SharedString, f32) {
+    #[cfg(target_family = "windows")]
+    return windows_system_font();
+
+    #[cfg(target_os = "macos")]
+    return macos_system_font();
+
+    #[allow(unreachable_code)]
+    (SharedString::default(), 1.)
+}
+
+#[cfg(target_family = "windows")]
+fn windows_system_font() -> (SharedString, f32) {
+    use windows::core::w;
+    use windows::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        SystemParametersInfoW, NONCLIENTMETRICSW, SPI_GETNONCLIENTMETRICS,
+        SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+    };
+
+    let mut percent = 0u32;
+    let mut size = core::mem::size_of::<u32>() as u32;
+    // Safety: the value is a DWORD, and the buffer is a u32 of the given size.
+    let result = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            w!("Software\\Microsoft\\Accessibility"),
+            w!("TextScaleFactor"),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut percent as *mut u32 as *mut _),
+            Some(&mut size),
+        )
+    };
+    let text_scale_factor = if result.is_ok() && percent > 0 { percent as f32 / 100. } else { 1. };
+
+    let mut metrics = NONCLIENTMETRICSW {
+        cbSize: core::mem::size_of::<NONCLIENTMETRICSW>() as u32,
+        ..Default::default()
+    };
+    // Safety: the buffer is a NONCLIENTMETRICSW with its size set in cbSize.
+    let family = unsafe {
+        SystemParametersInfoW(
+            SPI_GETNONCLIENTMETRICS,
+            metrics.cbSize,
+            Some(&mut metrics as *mut NONCLIENTMETRICSW as *mut _),
+            SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+        )
+    }
+    .map(|()| {
+        let face_name = &metrics.lfMessageFont.lfFaceName;
+        let len = face_name.iter().position(|c| *c == 0).unwrap_or(face_name.len());
+        String::from_utf16_lossy(&face_name[..len]).into()
+    })
+    .unwrap_or_default();
+
+    (family, text_scale_factor)
+}
+
+#[cfg(target_os = "macos")]
+fn macos_system_font() -> (SharedString, f32) {
+    /// The size of the system font with the default settings.
+    const DEFAULT_SYSTEM_FONT_SIZE: f64 = 13.;
+
+    // Safety: this is a class method of AppKit without preconditions, called on the main thread.
+    let size = unsafe { objc2_app_kit::NSFont::systemFontSize() };
+    let text_scale_factor = if size > 0. { (size / DEFAULT_SYSTEM_FONT_SIZE) as f32 } else { 1. };
+    (SharedString::default(), text_scale_factor)
+}
+
+/// Calls `on_change` on the next iteration of the event loop whenever Windows reports a change of
+/// the system settings to `window`, until the window is destroyed.
+#[cfg(target_family = "windows")]
+pub fn watch_setting_changes(window: &winit::window::Window, on_change: impl Fn() + 'static) {
+    use raw_window_handle::HasWindowHandle;
+    use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, WPARAM};
+    use windows::Win32::UI::Shell::{DefSubclassProc, RemoveWindowSubclass, SetWindowSubclass};
+    use windows::Win32::UI::WindowsAndMessaging::{WM_NCDESTROY, WM_SETTINGCHANGE};
+
+    /// Identifies the subclass of the window among the ones of other libraries.
+    const SUBCLASS_ID: usize = 0x534c_4e54;
+
+    type Callback = std::rc::Rc<dyn Fn()>;
+
+    unsafe extern "system" fn subclass_proc(
+        hwnd: HWND,
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+        _id: usize,
+        data: usize,
+    ) -> LRESULT {
+        match msg {
+            WM_SETTINGCHANGE => {
+                // Don't dispatch events from within the window procedure, winit may be borrowing the window.
+                let on_change = (*(data as *const Callback)).clone();
+                i_slint_core::timers::Timer::single_shot(Default::default(), move || on_change());
+            }
+            WM_NCDESTROY => {
+                let _ = RemoveWindowSubclass(hwnd, Some(subclass_proc), SUBCLASS_ID);
+                drop(Box::from_raw(data as *mut Callback));
+            }
+            _ => {}
+        }
+        DefSubclassProc(hwnd, msg, wparam, lparam)
+    }
+
+    let Ok(handle) = window.window_handle() else { return };
+    let raw_window_handle::RawWindowHandle::Win32(handle) = handle.as_raw() else { return };
+    let data = Box::into_raw(Box::new(std::rc::Rc::new(on_change) as Callback));
+    // Safety: the handle belongs to the window, which is alive, and the data is freed when the
+    // window is destroyed.
+    unsafe {
+        if !SetWindowSubclass(
+            HWND(handle.hwnd.get() as _),
+            Some(subclass_proc),
+            SUBCLASS_ID,
+            data as usize,
+        )
+        .as_bool()
+        {
+            drop(Box::from_raw(data));
+        }
+    }
+}
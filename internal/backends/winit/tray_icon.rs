@@ -0,0 +1,365 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Icons in the system tray, also called status icons.
+//!
+//! **Note:** This module is only available with the "tray-icon" feature, and is re-exported as
+//! `slint::tray_icon` when the feature of the same name is enabled in the `slint` crate.
+//!
+//! A [`TrayIcon`] shows an icon with a tooltip and a context menu in the notification area of
+//! the task bar on Windows, in the status bar of the menu bar on macOS, and in the panel of
+//! Linux desktops that implement the StatusNotifierItem D-Bus protocol, such as KDE Plasma.
+//! It lets background utilities stay reachable without showing a window.
+//!
+//! Events are delivered in the Slint event loop. To keep running without any visible window,
+//! use [`slint::run_event_loop_until_quit()`](i_slint_core::api::run_event_loop_until_quit).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use i_slint_backend_winit::tray_icon::{TrayIcon, TrayIconEvent, TrayMenuEntry};
+//! # use i_slint_core::graphics::Image;
+//! let icon = Image::load_from_path(std::path::Path::new("icon.png")).unwrap();
+//! let mut tray = TrayIcon::new(&icon).unwrap();
+//! tray.set_tooltip("My Utility").unwrap();
+//! tray.set_menu(&[
+//!     TrayMenuEntry::Item { title: "Open".into(), enabled: true },
+//!     TrayMenuEntry::Separator,
+//!     TrayMenuEntry::Item { title: "Quit".into(), enabled: true },
+//! ])
+//! .unwrap();
+//! tray.on_event(|event| match event {
+//!     TrayIconEvent::Activated | TrayIconEvent::MenuItemActivated(0) => { /* show the window */ }
+//!     TrayIconEvent::MenuItemActivated(2) => {
+//!         i_slint_core::api::quit_event_loop().unwrap();
+//!     }
+//!     _ => {}
+//! });
+//! ```
+//!
+//! On macOS, the icon can only be created once the event loop runs, for example from a callback
+//! or from a function passed to [`invoke_from_event_loop()`](i_slint_core::api::invoke_from_event_loop).
+
+use i_slint_core::api::{invoke_from_event_loop, PlatformError};
+use i_slint_core::graphics::Image;
+use i_slint_core::SharedString;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// An event of a [`TrayIcon`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TrayIconEvent {
+    /// The icon was clicked with the primary mouse button.
+    Activated,
+    /// The item at the given index of the entries passed to [`TrayIcon::set_menu()`] was
+    /// selected in the context menu. Separators count as entries.
+    MenuItemActivated(usize),
+}
+
+/// An entry of the context menu of a [`TrayIcon`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrayMenuEntry {
+    /// An item that the user can select.
+    Item {
+        /// The text shown for the item.
+        title: SharedString,
+        /// When `false`, the item is shown grayed out and can't be selected.
+        enabled: bool,
+    },
+    /// A line that separates groups of items.
+    Separator,
+}
+
+thread_local! {
+    static EVENT_HANDLERS: RefCell<HashMap<usize, Rc<dyn Fn(TrayIconEvent)>>> = Default::default();
+}
+
+static NEXT_TRAY_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// Prefix of the ids of the context menu items, followed by `<tray id>|<index>`.
+#[cfg(muda)]
+const MENU_ID_PREFIX: &str = "slint-tray|";
+
+/// Invokes the handler of the tray icon with the given id, in the event loop.
+fn send_event(tray_id: usize, event: TrayIconEvent) {
+    // Errors mean that the event loop has quit.
+    invoke_from_event_loop(move || {
+        // Clone the handler so that it can replace itself or drop the tray icon
+        let handler = EVENT_HANDLERS.with(|handlers| handlers.borrow().get(&tray_id).cloned());
+        if let Some(handler) = handler {
+            handler(event);
+        }
+    })
+    .ok();
+}
+
+/// Handles the menu events of the tray icons that are delivered through muda, and returns false
+/// for the events that belong to other menus.
+#[cfg(muda)]
+pub(crate) fn dispatch_menu_event(id: &str) -> bool {
+    let Some(id) = id.strip_prefix(MENU_ID_PREFIX) else { return false };
+    if let Some((tray_id, index)) = id
+        .split_once('|')
+        .and_then(|(tray_id, index)| Some((tray_id.parse().ok()?, index.parse().ok()?)))
+    {
+        send_event(tray_id, TrayIconEvent::MenuItemActivated(index));
+    }
+    true
+}
+
+/// Converts the image to RGBA pixels, returned with the width and height.
+fn image_to_rgba(image: &Image) -> Result<(Vec<u8>, u32, u32), PlatformError> {
+    let buffer = image.to_rgba8().ok_or_else(|| {
+        PlatformError::Other("The tray icon must be an image with pixel data".into())
+    })?;
+    Ok((buffer.as_bytes().to_vec(), buffer.width(), buffer.height()))
+}
+
+/// An icon in the system tray.
+///
+/// The icon is removed from the system tray when this object is dropped.
+pub struct TrayIcon {
+    native: NativeTrayIcon,
+    id: usize,
+}
+
+impl TrayIcon {
+    /// Adds an icon showing the given image to the system tray.
+    ///
+    /// The image must have pixel data, like images loaded from PNG files. The system scales it
+    /// to the size of the tray, so an image of at least 32x32 pixels is recommended.
+    pub fn new(icon: &Image) -> Result<Self, PlatformError> {
+        let id = NEXT_TRAY_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(Self { native: NativeTrayIcon::new(id, icon)?, id })
+    }
+
+    /// Replaces the image of the icon.
+    pub fn set_icon(&mut self, icon: &Image) -> Result<(), PlatformError> {
+        self.native.set_icon(icon)
+    }
+
+    /// Sets the text shown when hovering the icon.
+    pub fn set_tooltip(&mut self, tooltip: &str) -> Result<(), PlatformError> {
+        self.native.set_tooltip(tooltip)
+    }
+
+    /// Sets the entries of the context menu that is shown when the icon is clicked with the
+    /// secondary mouse button. An empty list removes the menu.
+    pub fn set_menu(&mut self, entries: &[TrayMenuEntry]) -> Result<(), PlatformError> {
+        self.native.set_menu(entries)
+    }
+
+    /// Sets the callback that is invoked when the icon is clicked or an item of its menu is
+    /// selected.
+    ///
+    /// The callback is invoked from the Slint event loop. Calling this function again replaces
+    /// the previous callback.
+    pub fn on_event(&mut self, handler: impl Fn(TrayIconEvent) + 'static) {
+        EVENT_HANDLERS.with(|handlers| handlers.borrow_mut().insert(self.id, Rc::new(handler)));
+    }
+}
+
+impl Drop for TrayIcon {
+    fn drop(&mut self) {
+        EVENT_HANDLERS.try_with(|handlers| handlers.borrow_mut().remove(&self.id)).ok();
+    }
+}
+
+#[cfg(not(muda))]
+use ksni_impl::NativeTrayIcon;
+#[cfg(muda)]
+use muda_impl::NativeTrayIcon;
+
+/// Windows and macOS, where the tray-icon crate uses muda for the menu.
+#[cfg(muda)]
+mod muda_impl {
+    use super::*;
+    use tray_icon::menu::{IsMenuItem, Menu, MenuItem, PredefinedMenuItem};
+
+    fn to_platform_error(error: impl std::fmt::Display) -> PlatformError {
+        PlatformError::Other(format!("Tray icon error: {error}"))
+    }
+
+    fn native_icon(icon: &Image) -> Result<tray_icon::Icon, PlatformError> {
+        let (rgba, width, height) = image_to_rgba(icon)?;
+        tray_icon::Icon::from_rgba(rgba, width, height).map_err(to_platform_error)
+    }
+
+    /// The event handlers of tray-icon are global, so they are installed once for all icons.
+    fn install_event_handlers() {
+        static INSTALLED: std::sync::Once = std::sync::Once::new();
+        INSTALLED.call_once(|| {
+            tray_icon::TrayIconEvent::set_event_handler(Some(|event| {
+                if let tray_icon::TrayIconEvent::Click {
+                    id,
+                    button: tray_icon::MouseButton::Left,
+                    button_state: tray_icon::MouseButtonState::Up,
+                    ..
+                } = event
+                {
+                    if let Ok(tray_id) = id.0.parse() {
+                        send_event(tray_id, TrayIconEvent::Activated);
+                    }
+                }
+            }));
+            // The menu bars of the windows use the same handler, so dispatch all menu events
+            // like the winit event loop does.
+            tray_icon::menu::MenuEvent::set_event_handler(Some(|event| {
+                invoke_from_event_loop(move || crate::event_loop::dispatch_muda_event(event)).ok();
+            }));
+        });
+    }
+
+    pub(super) struct NativeTrayIcon {
+        tray: tray_icon::TrayIcon,
+        id: usize,
+    }
+
+    impl NativeTrayIcon {
+        pub fn new(id: usize, icon: &Image) -> Result<Self, PlatformError> {
+            install_event_handlers();
+            let tray = tray_icon::TrayIconBuilder::new()
+                .with_id(id.to_string())
+                .with_icon(native_icon(icon)?)
+                .with_menu_on_left_click(false)
+                .build()
+                .map_err(to_platform_error)?;
+            Ok(Self { tray, id })
+        }
+
+        pub fn set_icon(&mut self, icon: &Image) -> Result<(), PlatformError> {
+            self.tray.set_icon(Some(native_icon(icon)?)).map_err(to_platform_error)
+        }
+
+        pub fn set_tooltip(&mut self, tooltip: &str) -> Result<(), PlatformError> {
+            self.tray.set_tooltip(Some(tooltip)).map_err(to_platform_error)
+        }
+
+        pub fn set_menu(&mut self, entries: &[TrayMenuEntry]) -> Result<(), PlatformError> {
+            if entries.is_empty() {
+                self.tray.set_menu(None);
+                return Ok(());
+            }
+            let menu = Menu::new();
+            for (index, entry) in entries.iter().enumerate() {
+                let item: Box<dyn IsMenuItem> = match entry {
+                    TrayMenuEntry::Item { title, enabled } => Box::new(MenuItem::with_id(
+                        format!("{MENU_ID_PREFIX}{}|{index}", self.id),
+                        title,
+                        *enabled,
+                        None,
+                    )),
+                    TrayMenuEntry::Separator => Box::new(PredefinedMenuItem::separator()),
+                };
+                menu.append(&*item).map_err(to_platform_error)?;
+            }
+            self.tray.set_menu(Some(Box::new(menu)));
+            Ok(())
+        }
+    }
+}
+
+/// Linux and other Unix systems, with the StatusNotifierItem D-Bus protocol.
+#[cfg(not(muda))]
+mod ksni_impl {
+    use super::*;
+
+    struct KsniTray {
+        id: usize,
+        icon: Vec<ksni::Icon>,
+        tooltip: String,
+        menu: Vec<TrayMenuEntry>,
+    }
+
+    impl ksni::Tray for KsniTray {
+        fn id(&self) -> String {
+            format!("slint-tray-{}-{}", std::process::id(), self.id)
+        }
+
+        fn icon_pixmap(&self) -> Vec<ksni::Icon> {
+            self.icon.clone()
+        }
+
+        fn tool_tip(&self) -> ksni::ToolTip {
+            ksni::ToolTip { title: self.tooltip.clone(), ..Default::default() }
+        }
+
+        fn activate(&mut self, _x: i32, _y: i32) {
+            send_event(self.id, TrayIconEvent::Activated);
+        }
+
+        fn menu(&self) -> Vec<ksni::MenuItem<Self>> {
+            self.menu
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| match entry {
+                    TrayMenuEntry::Item { title, enabled } => ksni::menu::StandardItem {
+                        label: title.to_string(),
+                        enabled: *enabled,
+                        activate: Box::new(move |tray: &mut Self| {
+                            send_event(tray.id, TrayIconEvent::MenuItemActivated(index))
+                        }),
+                        ..Default::default()
+                    }
+                    .into(),
+                    TrayMenuEntry::Separator => ksni::MenuItem::Separator,
+                })
+                .collect()
+        }
+    }
+
+    /// StatusNotifierItem expects ARGB32 pixels in network byte order.
+    fn ksni_icon(icon: &Image) -> Result<ksni::Icon, PlatformError> {
+        let (mut data, width, height) = image_to_rgba(icon)?;
+        for pixel in data.chunks_exact_mut(4) {
+            pixel.rotate_right(1);
+        }
+        Ok(ksni::Icon { width: width as i32, height: height as i32, data })
+    }
+
+    pub(super) struct NativeTrayIcon {
+        handle: ksni::Handle<KsniTray>,
+    }
+
+    impl NativeTrayIcon {
+        pub fn new(id: usize, icon: &Image) -> Result<Self, PlatformError> {
+            let tray = KsniTray {
+                id,
+                icon: vec![ksni_icon(icon)?],
+                tooltip: Default::default(),
+                menu: Default::default(),
+            };
+            // The service runs the D-Bus connection in its own thread.
+            let service = ksni::TrayService::new(tray);
+            let handle = service.handle();
+            service.spawn();
+            Ok(Self { handle })
+        }
+
+        pub fn set_icon(&mut self, icon: &Image) -> Result<(), PlatformError> {
+            let icon = ksni_icon(icon)?;
+            self.handle.update(move |tray| tray.icon = vec![icon]);
+            Ok(())
+        }
+
+        pub fn set_tooltip(&mut self, tooltip: &str) -> Result<(), PlatformError> {
+            let tooltip = tooltip.to_string();
+            self.handle.update(move |tray| tray.tooltip = tooltip);
+            Ok(())
+        }
+
+        pub fn set_menu(&mut self, entries: &[TrayMenuEntry]) -> Result<(), PlatformError> {
+            let entries = entries.to_vec();
+            self.handle.update(move |tray| tray.menu = entries);
+            Ok(())
+        }
+    }
+
+    impl Drop for NativeTrayIcon {
+        fn drop(&mut self) {
+            self.handle.shutdown();
+        }
+    }
+}
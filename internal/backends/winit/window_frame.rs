@@ -0,0 +1,124 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Reports the `WindowFrameArea` elements to Windows as parts of the frame of the window.
+//!
+//! Windows asks which part of the window is under the pointer with `WM_NCHITTEST`, which winit
+//! doesn't let applications answer, so [`report_window_frame_regions()`] subclasses the window to
+//! answer it with the region of the `WindowFrameArea` under the pointer. Windows then moves and
+//! resizes the window itself, with the snapping of its own frame, and shows the snap layouts when
+//! the pointer hovers a `MaximizeButton` area.
+
+use crate::winitwindowadapter::WinitWindowAdapter;
+use i_slint_core::items::WindowFrameRegion;
+use i_slint_core::lengths::LogicalPoint;
+use i_slint_core::window::{WindowAdapter, WindowInner};
+use std::rc::Weak;
+
+/// Answers `WM_NCHITTEST` for `window` with the `WindowFrameArea` elements of `adapter`, until the
+/// window is destroyed.
+pub fn report_window_frame_regions(
+    window: &winit::window::Window,
+    adapter: Weak<WinitWindowAdapter>,
+) {
+    use raw_window_handle::HasWindowHandle;
+    use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, POINT, WPARAM};
+    use windows::Win32::Graphics::Gdi::ScreenToClient;
+    use windows::Win32::UI::Shell::{DefSubclassProc, RemoveWindowSubclass, SetWindowSubclass};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        HTBOTTOM, HTBOTTOMLEFT, HTBOTTOMRIGHT, HTCAPTION, HTCLIENT, HTLEFT, HTMAXBUTTON, HTRIGHT,
+        HTTOP, HTTOPLEFT, HTTOPRIGHT, WM_NCDESTROY, WM_NCHITTEST, WM_NCLBUTTONDOWN, WM_NCLBUTTONUP,
+    };
+
+    /// Identifies the subclass of the window among the ones of other libraries.
+    const SUBCLASS_ID: usize = 0x534c_4e46;
+
+    /// Returns the region of the `WindowFrameArea` at the position of the screen in `lparam`.
+    unsafe fn region_at(hwnd: HWND, lparam: LPARAM, data: usize) -> Option<WindowFrameRegion> {
+        let adapter = (*(data as *const Weak<WinitWindowAdapter>)).upgrade()?;
+        // The coordinates are signed, as screens can be left of or above the primary screen
+        let mut point = POINT {
+            x: (lparam.0 & 0xffff) as i16 as i32,
+            y: (lparam.0 >> 16 & 0xffff) as i16 as i32,
+        };
+        if !ScreenToClient(hwnd, &mut point).as_bool() {
+            return None;
+        }
+        let window = WindowInner::from_pub(adapter.window());
+        let scale_factor = window.scale_factor();
+        window.window_frame_region_at(LogicalPoint::new(
+            point.x as f32 / scale_factor,
+            point.y as f32 / scale_factor,
+        ))
+    }
+
+    unsafe extern "system" fn subclass_proc(
+        hwnd: HWND,
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+        _id: usize,
+        data: usize,
+    ) -> LRESULT {
+        match msg {
+            WM_NCHITTEST => {
+                let result = DefSubclassProc(hwnd, msg, wparam, lparam);
+                // Keep the borders of the system, if the window has some
+                if result.0 != HTCLIENT as isize {
+                    return result;
+                }
+                let code = match region_at(hwnd, lparam, data) {
+                    None | Some(WindowFrameRegion::Client) => HTCLIENT,
+                    Some(WindowFrameRegion::TitleBar) => HTCAPTION,
+                    Some(WindowFrameRegion::ResizeNorth) => HTTOP,
+                    Some(WindowFrameRegion::ResizeSouth) => HTBOTTOM,
+                    Some(WindowFrameRegion::ResizeEast) => HTRIGHT,
+                    Some(WindowFrameRegion::ResizeWest) => HTLEFT,
+                    Some(WindowFrameRegion::ResizeNorthEast) => HTTOPRIGHT,
+                    Some(WindowFrameRegion::ResizeNorthWest) => HTTOPLEFT,
+                    Some(WindowFrameRegion::ResizeSouthEast) => HTBOTTOMRIGHT,
+                    Some(WindowFrameRegion::ResizeSouthWest) => HTBOTTOMLEFT,
+                    Some(WindowFrameRegion::MaximizeButton) => HTMAXBUTTON,
+                };
+                return LRESULT(code as isize);
+            }
+            // Don't let the system draw its own maximize button, and maximize on release like it
+            WM_NCLBUTTONDOWN if wparam.0 == HTMAXBUTTON as usize => return LRESULT(0),
+            WM_NCLBUTTONUP if wparam.0 == HTMAXBUTTON as usize => {
+                // Don't dispatch events from within the window procedure, winit may be borrowing the window.
+                let adapter = (*(data as *const Weak<WinitWindowAdapter>)).clone();
+                i_slint_core::timers::Timer::single_shot(Default::default(), move || {
+                    if let Some(adapter) = adapter.upgrade() {
+                        let window = adapter.window();
+                        window.set_maximized(!window.is_maximized());
+                    }
+                });
+                return LRESULT(0);
+            }
+            WM_NCDESTROY => {
+                let _ = RemoveWindowSubclass(hwnd, Some(subclass_proc), SUBCLASS_ID);
+                drop(Box::from_raw(data as *mut Weak<WinitWindowAdapter>));
+            }
+            _ => {}
+        }
+        DefSubclassProc(hwnd, msg, wparam, lparam)
+    }
+
+    let Ok(handle) = window.window_handle() else { return };
+    let raw_window_handle::RawWindowHandle::Win32(handle) = handle.as_raw() else { return };
+    let data = Box::into_raw(Box::new(adapter));
+    // Safety: the handle belongs to the window, which is alive, and the data is freed when the
+    // window is destroyed.
+    unsafe {
+        if !SetWindowSubclass(
+            HWND(handle.hwnd.get() as _),
+            Some(subclass_proc),
+            SUBCLASS_ID,
+            data as usize,
+        )
+        .as_bool()
+        {
+            drop(Box::from_raw(data));
+        }
+    }
+}
@@ -76,6 +76,114 @@ fn physical_size_to_winit(size: PhysicalSize) -> winit::dpi::PhysicalSize<u32> {
     winit::dpi::PhysicalSize::new(size.width, size.height)
 }
 
+/// Shows the vibrancy of macOS behind the window, with an `NSVisualEffectView`.
+#[cfg(target_os = "macos")]
+fn apply_vibrancy(window: &winit::window::Window, effect: corelib::items::WindowBackgroundEffect) {
+    if effect == corelib::items::WindowBackgroundEffect::None {
+        window_vibrancy::clear_vibrancy(window).ok();
+    } else {
+        window_vibrancy::apply_vibrancy(
+            window,
+            window_vibrancy::NSVisualEffectMaterial::UnderWindowBackground,
+            None,
+            None,
+        )
+        .ok();
+    }
+}
+
+/// Sets the opacity of the whole window, including its decorations, where the platform supports it.
+#[cfg_attr(not(any(target_family = "windows", target_os = "macos")), allow(unused_variables))]
+fn apply_window_opacity(window: &winit::window::Window, opacity: f32) {
+    use raw_window_handle::HasWindowHandle;
+    let Ok(handle) = window.window_handle() else { return };
+    #[allow(clippy::single_match)]
+    match handle.as_raw() {
+        #[cfg(target_family = "windows")]
+        raw_window_handle::RawWindowHandle::Win32(handle) => {
+            use windows::Win32::Foundation::{COLORREF, HWND};
+            use windows::Win32::UI::WindowsAndMessaging::{
+                GetWindowLongW, SetLayeredWindowAttributes, SetWindowLongW, GWL_EXSTYLE, LWA_ALPHA,
+                WS_EX_LAYERED,
+            };
+            let hwnd = HWND(handle.hwnd.get() as _);
+            // Safety: the handle belongs to the window, which is alive.
+            unsafe {
+                let style = GetWindowLongW(hwnd, GWL_EXSTYLE);
+                SetWindowLongW(hwnd, GWL_EXSTYLE, style | WS_EX_LAYERED.0 as i32);
+                SetLayeredWindowAttributes(
+                    hwnd,
+                    COLORREF(0),
+                    (opacity * 255.).round() as u8,
+                    LWA_ALPHA,
+                )
+                .ok();
+            }
+        }
+        #[cfg(target_os = "macos")]
+        raw_window_handle::RawWindowHandle::AppKit(handle) => {
+            // Safety: the handle belongs to the window, which is alive.
+            let ns_view: &objc2_app_kit::NSView = unsafe { handle.ns_view.cast().as_ref() };
+            if let Some(ns_window) = ns_view.window() {
+                ns_window.setAlphaValue(opacity as f64);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Makes `parent` the owner of the window on Windows, or makes the window a child window of `parent` on macOS,
+/// so that the window stays above it. With `None`, the window becomes a top-level window again.
+fn apply_parent_window(window: &winit::window::Window, parent: Option<&winit::window::Window>) {
+    use raw_window_handle::HasWindowHandle;
+    let Ok(handle) = window.window_handle() else { return };
+    let parent_handle = parent.and_then(|parent| parent.window_handle().ok()).map(|h| h.as_raw());
+    #[allow(clippy::single_match)]
+    match handle.as_raw() {
+        #[cfg(target_family = "windows")]
+        raw_window_handle::RawWindowHandle::Win32(handle) => {
+            use windows::Win32::Foundation::HWND;
+            use windows::Win32::UI::WindowsAndMessaging::{SetWindowLongPtrW, GWLP_HWNDPARENT};
+            let owner = match parent_handle {
+                Some(raw_window_handle::RawWindowHandle::Win32(parent)) => parent.hwnd.get(),
+                _ => 0,
+            };
+            // Safety: the handles belong to windows that are alive.
+            unsafe { SetWindowLongPtrW(HWND(handle.hwnd.get() as _), GWLP_HWNDPARENT, owner as _) };
+        }
+        #[cfg(target_os = "macos")]
+        raw_window_handle::RawWindowHandle::AppKit(handle) => {
+            // Safety: the handles belong to windows that are alive.
+            unsafe {
+                let ns_view: &objc2_app_kit::NSView = handle.ns_view.cast().as_ref();
+                let Some(ns_window) = ns_view.window() else { return };
+                if let Some(previous_parent) = ns_window.parentWindow() {
+                    previous_parent.removeChildWindow(&ns_window);
+                }
+                if let Some(raw_window_handle::RawWindowHandle::AppKit(parent)) = parent_handle {
+                    let parent_view: &objc2_app_kit::NSView = parent.ns_view.cast().as_ref();
+                    if let Some(parent_window) = parent_view.window() {
+                        parent_window.addChildWindow_ordered(
+                            &ns_window,
+                            objc2_app_kit::NSWindowOrderingMode::NSWindowAbove,
+                        );
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the winit window of a window adapter of the winit backend, set with `Window::set_parent()`.
+fn parent_winit_window(parent: &Rc<dyn WindowAdapter>) -> Option<Rc<winit::window::Window>> {
+    parent
+        .internal(corelib::InternalToken)?
+        .as_any()
+        .downcast_ref::<WinitWindowAdapter>()?
+        .winit_window()
+}
+
 fn icon_to_winit(icon: corelib::graphics::Image) -> Option<winit::window::Icon> {
     let image_inner: &ImageInner = (&icon).into();
 
@@ -108,6 +216,14 @@ fn icon_to_winit(icon: corelib::graphics::Image) -> Option<winit::window::Icon>
     winit::window::Icon::from_rgba(rgba_pixels, pixel_buffer.width(), pixel_buffer.height()).ok()
 }
 
+fn video_mode_from_winit(mode: &winit::monitor::VideoModeHandle) -> corelib::api::VideoMode {
+    corelib::api::VideoMode {
+        size: corelib::api::PhysicalSize::new(mode.size().width, mode.size().height),
+        bit_depth: mode.bit_depth(),
+        refresh_rate_millihertz: mode.refresh_rate_millihertz(),
+    }
+}
+
 fn window_is_resizable(
     min_size: Option<corelib::api::LogicalSize>,
     max_size: Option<corelib::api::LogicalSize>,
@@ -140,11 +256,17 @@ fn as_window(&self) -> Option<Rc<winit::window::Window>> {
         }
     }
 
-    fn set_window_icon(&self, icon: Option<winit::window::Icon>) {
+    fn set_window_icon(
+        &self,
+        icon: Option<winit::window::Icon>,
+        taskbar_icon: Option<winit::window::Icon>,
+    ) {
         match self {
             Self::HasWindow { window, .. } => {
                 #[cfg(target_family = "windows")]
-                window.set_taskbar_icon(icon.as_ref().cloned());
+                window.set_taskbar_icon(taskbar_icon);
+                #[cfg(not(target_family = "windows"))]
+                let _ = taskbar_icon;
                 window.set_window_icon(icon);
             }
             Self::None(attributes) => attributes.borrow_mut().window_icon = icon,
@@ -186,6 +308,50 @@ fn set_window_level(&self, level: winit::window::WindowLevel) {
         }
     }
 
+    fn set_background_effect(&self, effect: corelib::items::WindowBackgroundEffect) {
+        #[cfg(target_family = "windows")]
+        let backdrop = {
+            use corelib::items::WindowBackgroundEffect;
+            use winit::platform::windows::BackdropType;
+            match effect {
+                WindowBackgroundEffect::None => BackdropType::Auto,
+                WindowBackgroundEffect::Blur | WindowBackgroundEffect::Acrylic => {
+                    BackdropType::TransientWindow
+                }
+                WindowBackgroundEffect::Mica => BackdropType::MainWindow,
+            }
+        };
+        #[cfg(not(any(target_family = "windows", target_os = "macos")))]
+        let blur = effect != corelib::items::WindowBackgroundEffect::None;
+        match self {
+            Self::HasWindow { window, .. } => {
+                #[cfg(target_family = "windows")]
+                {
+                    use winit::platform::windows::WindowExtWindows;
+                    window.set_system_backdrop(backdrop);
+                }
+                #[cfg(target_os = "macos")]
+                apply_vibrancy(window, effect);
+                #[cfg(not(any(target_family = "windows", target_os = "macos")))]
+                window.set_blur(blur);
+            }
+            // On macOS, the vibrancy is applied once the window exists, in ensure_window()
+            #[allow(unused_variables)]
+            Self::None(attributes) => {
+                #[cfg(target_family = "windows")]
+                {
+                    use winit::platform::windows::WindowAttributesExtWindows;
+                    let mut attributes = attributes.borrow_mut();
+                    *attributes = std::mem::take(&mut *attributes).with_system_backdrop(backdrop);
+                }
+                #[cfg(not(any(target_family = "windows", target_os = "macos")))]
+                {
+                    attributes.borrow_mut().blur = blur;
+                }
+            }
+        }
+    }
+
     fn set_visible(&self, visible: bool) {
         match self {
             Self::HasWindow { window, .. } => window.set_visible(visible),
@@ -224,6 +390,15 @@ fn set_resizable(&self, resizable: bool) {
         }
     }
 
+    fn set_resize_increments(&self, increments: Option<winit::dpi::PhysicalSize<f32>>) {
+        match self {
+            Self::HasWindow { window, .. } => window.set_resize_increments(increments),
+            Self::None(attributes) => {
+                attributes.borrow_mut().resize_increments = increments.map(Into::into);
+            }
+        }
+    }
+
     fn set_min_inner_size<S: Into<winit::dpi::Size>>(&self, min_inner_size: Option<S>) {
         match self {
             Self::HasWindow { window, .. } => window.set_min_inner_size(min_inner_size),
@@ -243,6 +418,15 @@ fn set_max_inner_size<S: Into<winit::dpi::Size>>(&self, max_inner_size: Option<S
     }
 }
 
+/// A custom cursor set with `set_custom_cursor()`. It's kept while the winit window doesn't exist,
+/// and applied when the window is created.
+struct CustomCursorState {
+    frames: Vec<winit::window::CustomCursor>,
+    current_frame: usize,
+    /// Cycles through the frames of animated cursors.
+    _animation_timer: corelib::timers::Timer,
+}
+
 /// GraphicsWindow is an implementation of the [WindowAdapter][`crate::eventloop::WindowAdapter`] trait. This is
 /// typically instantiated by entry factory functions of the different graphics back ends.
 pub struct WinitWindowAdapter {
@@ -250,12 +434,28 @@ pub struct WinitWindowAdapter {
     self_weak: Weak<Self>,
     pending_redraw: Cell<bool>,
     color_scheme: OnceCell<Pin<Box<Property<ColorScheme>>>>,
+    accent_color: OnceCell<Pin<Box<Property<Color>>>>,
     constraints: Cell<corelib::window::LayoutConstraints>,
     shown: Cell<bool>,
     window_level: Cell<winit::window::WindowLevel>,
+    background_effect: Cell<corelib::items::WindowBackgroundEffect>,
+    opacity: Cell<f32>,
+    /// The resize increments in physical pixels and the aspect ratio, applied in `apply_resize_constraints()`.
+    resize_increments: Cell<Option<winit::dpi::PhysicalSize<f32>>>,
+    aspect_ratio: Cell<Option<f32>>,
+    idle_inhibitor: RefCell<Option<crate::idle_inhibitor::IdleInhibitor>>,
+    /// The keyboard layout when the window was last activated or received a key press.
+    #[cfg(target_family = "windows")]
+    keyboard_layout: Cell<Option<windows::Win32::UI::TextServices::HKL>>,
     maximized: Cell<bool>,
     minimized: Cell<bool>,
     fullscreen: Cell<bool>,
+    /// The xdg-activation or startup-notification token to create the next winit window with.
+    #[cfg(enable_startup_notify)]
+    activation_token: RefCell<Option<winit::window::ActivationToken>>,
+    /// Activation tokens requested from this window on behalf of other windows, see `bring_to_front()`.
+    #[cfg(enable_startup_notify)]
+    pending_activations: RefCell<Vec<(winit::event_loop::AsyncRequestSerial, Weak<Self>)>>,
 
     pub(crate) renderer: Box<dyn WinitCompatibleRenderer>,
     requested_graphics_api: Option<RequestedGraphicsAPI>,
@@ -291,9 +491,20 @@ pub struct WinitWindowAdapter {
 
     winit_window_or_none: RefCell<WinitWindowOrNone>,
 
+    /// The cursor last requested by the items, shown again when the custom cursor is removed.
+    mouse_cursor: Cell<MouseCursor>,
+    /// Set while a custom cursor is shown, even when the winit window doesn't exist yet.
+    custom_cursor: RefCell<Option<CustomCursorState>>,
+
     #[cfg(not(use_winit_theme))]
     xdg_settings_watcher: RefCell<Option<i_slint_core::future::JoinHandle<()>>>,
 
+    #[cfg(not(use_winit_theme))]
+    system_font_watcher: RefCell<Option<i_slint_core::future::JoinHandle<()>>>,
+
+    #[cfg(not(use_winit_theme))]
+    accent_color_watcher: RefCell<Option<i_slint_core::future::JoinHandle<()>>>,
+
     #[cfg(muda)]
     pub(crate) muda_adapter: RefCell<Option<crate::muda::MudaAdapter>>,
 }
@@ -311,13 +522,27 @@ pub(crate) fn new(
             self_weak: self_weak.clone(),
             pending_redraw: Default::default(),
             color_scheme: Default::default(),
+            accent_color: Default::default(),
             constraints: Default::default(),
             shown: Default::default(),
             window_level: Default::default(),
+            background_effect: Default::default(),
+            opacity: Cell::new(1.),
+            resize_increments: Cell::new(None),
+            aspect_ratio: Cell::new(None),
+            idle_inhibitor: Default::default(),
+            #[cfg(target_family = "windows")]
+            keyboard_layout: Default::default(),
             maximized: Cell::default(),
             minimized: Cell::default(),
             fullscreen: Cell::default(),
+            #[cfg(enable_startup_notify)]
+            activation_token: Default::default(),
+            #[cfg(enable_startup_notify)]
+            pending_activations: Default::default(),
             winit_window_or_none: RefCell::new(WinitWindowOrNone::None(window_attributes.into())),
+            mouse_cursor: Cell::default(),
+            custom_cursor: Default::default(),
             size: Cell::default(),
             pending_requested_size: Cell::new(None),
             has_explicit_size: Default::default(),
@@ -331,6 +556,10 @@ pub(crate) fn new(
             window_event_filter: Cell::new(None),
             #[cfg(not(use_winit_theme))]
             xdg_settings_watcher: Default::default(),
+            #[cfg(not(use_winit_theme))]
+            system_font_watcher: Default::default(),
+            #[cfg(not(use_winit_theme))]
+            accent_color_watcher: Default::default(),
             #[cfg(muda)]
             muda_adapter: Default::default(),
         });
@@ -349,6 +578,11 @@ pub(crate) fn new(
             .unwrap_or_else(|| winit_window.scale_factor() as f32);
         self_rc.window().try_dispatch_event(WindowEvent::ScaleFactorChanged { scale_factor })?;
 
+        #[cfg(not(use_winit_theme))]
+        self_rc.system_font_watcher.replace(self_rc.spawn_system_font_watcher());
+        #[cfg(any(target_family = "windows", target_os = "macos"))]
+        self_rc.dispatch_system_font()?;
+
         Ok(self_rc)
     }
 
@@ -373,12 +607,29 @@ pub fn ensure_window(&self) -> Result<Rc<winit::window::Window>, PlatformError>
                 }
                 #[cfg(feature = "x11")]
                 {
+                    // The instance part of WM_CLASS must not be empty, or some window managers don't
+                    // match the window with the desktop file.
                     use winit::platform::x11::WindowAttributesExtX11;
-                    window_attributes = window_attributes.with_name(xdg_app_id.clone(), "");
+                    window_attributes =
+                        window_attributes.with_name(xdg_app_id.clone(), xdg_app_id.clone());
                 }
             }
         }
 
+        // The first window takes the token the application was launched with.
+        #[cfg(enable_startup_notify)]
+        if let Some(token) =
+            self.activation_token.take().or_else(crate::event_loop::take_startup_activation_token)
+        {
+            use winit::platform::startup_notify::WindowAttributesExtStartupNotify;
+            window_attributes = window_attributes.with_activation_token(token);
+        }
+
+        if let Some(hook) = crate::event_loop::WINDOW_CREATION_HOOK.take() {
+            window_attributes = hook(self.window(), window_attributes);
+            crate::event_loop::WINDOW_CREATION_HOOK.set(Some(hook));
+        }
+
         let mut winit_window_or_none = self.winit_window_or_none.borrow_mut();
 
         let winit_window =
@@ -400,6 +651,28 @@ pub fn ensure_window(&self) -> Result<Rc<winit::window::Window>, PlatformError>
             (self.self_weak.upgrade().unwrap()) as _,
         );
 
+        #[cfg(target_os = "macos")]
+        if self.background_effect.get() != corelib::items::WindowBackgroundEffect::None {
+            apply_vibrancy(&winit_window, self.background_effect.get());
+        }
+
+        if self.opacity.get() != 1. {
+            apply_window_opacity(&winit_window, self.opacity.get());
+        }
+
+        self.apply_custom_cursor(&winit_window);
+
+        #[cfg(target_family = "windows")]
+        {
+            let self_weak = self.self_weak.clone();
+            crate::system_font::watch_setting_changes(&winit_window, move || {
+                if let Some(adapter) = self_weak.upgrade() {
+                    adapter.dispatch_system_font().ok();
+                }
+            });
+            crate::window_frame::report_window_frame_regions(&winit_window, self.self_weak.clone());
+        }
+
         Ok(winit_window)
     }
 
@@ -503,10 +776,78 @@ pub fn draw(&self) -> Result<(), PlatformError> {
         Ok(())
     }
 
+    /// Returns true when the window is minimized or completely hidden, so that no frames are
+    /// requested, for example for animations.
+    fn rendering_paused(&self) -> bool {
+        let Some(window) = self.window.get() else { return false };
+        let window_inner = WindowInner::from_pub(window);
+        window_inner.is_occluded() || window_inner.is_minimized()
+    }
+
+    /// Called when the activation token requested with the given serial from this window is available.
+    #[cfg(enable_startup_notify)]
+    pub fn activation_token_done(
+        &self,
+        serial: winit::event_loop::AsyncRequestSerial,
+        token: winit::window::ActivationToken,
+    ) {
+        let target = {
+            let mut pending_activations = self.pending_activations.borrow_mut();
+            let Some(index) = pending_activations.iter().position(|(s, _)| *s == serial) else {
+                return;
+            };
+            pending_activations.remove(index).1
+        };
+        let Some(target) = target.upgrade() else { return };
+        match target.winit_window() {
+            Some(winit_window) => {
+                // winit only accepts activation tokens when creating windows, so ask the compositor to
+                // draw the attention to the window instead.
+                winit_window.set_minimized(false);
+                winit_window
+                    .request_user_attention(Some(winit::window::UserAttentionType::Informational));
+            }
+            // Hidden windows are destroyed on Wayland, so the token activates the window when it's shown.
+            None => *target.activation_token.borrow_mut() = Some(token),
+        }
+    }
+
+    /// Returns true when the next frame for animations must wait because of the limit set with
+    /// `Window::set_frame_rate_limit()`.
+    fn frame_deferred(&self) -> bool {
+        let Some(window) = self.window.get() else { return false };
+        window.has_active_animations()
+            && WindowInner::from_pub(window).duration_until_next_frame().is_some()
+    }
+
+    /// Requests the redraw that was held back while the window couldn't be seen or because of
+    /// the frame rate limit.
+    pub fn resume_rendering(&self) {
+        if self.pending_redraw.get() && !self.rendering_paused() && !self.frame_deferred() {
+            if let Some(window) = self.winit_window_or_none.borrow().as_window() {
+                window.request_redraw()
+            }
+        }
+    }
+
     pub fn winit_window(&self) -> Option<Rc<winit::window::Window>> {
         self.winit_window_or_none.borrow().as_window()
     }
 
+    /// Shows the current frame of the custom cursor, if any, in `winit_window`.
+    fn apply_custom_cursor(&self, winit_window: &winit::window::Window) {
+        if let Some(state) = self.custom_cursor.borrow().as_ref() {
+            winit_window.set_cursor_visible(true);
+            winit_window.set_cursor(state.frames[state.current_frame].clone());
+        }
+    }
+
+    /// Returns the frame of the custom cursor that's currently shown, if any.
+    #[cfg(test)]
+    pub(crate) fn custom_cursor_frame(&self) -> Option<winit::window::CustomCursor> {
+        self.custom_cursor.borrow().as_ref().map(|state| state.frames[state.current_frame].clone())
+    }
+
     #[cfg(target_arch = "wasm32")]
     pub fn input_method_focused(&self) -> bool {
         match self.virtual_keyboard_helper.try_borrow() {
@@ -544,6 +885,45 @@ fn resize_window(&self, size: winit::dpi::Size) -> Result<bool, PlatformError> {
         }
     }
 
+    /// Snaps the size the user resized the window to, to the `aspect-ratio` and, where winit doesn't
+    /// support resize increments, to the `resize-increment-width` and `resize-increment-height`.
+    pub fn apply_resize_constraints(&self, size: winit::dpi::PhysicalSize<u32>) {
+        let Some(winit_window) = self.winit_window() else { return };
+        if winit_window.fullscreen().is_some() || winit_window.is_maximized() {
+            return;
+        }
+
+        let mut new_size = size.cast::<f32>();
+        // winit supports resize increments on X11 and macOS only
+        let snap_to_increments = cfg!(target_family = "windows")
+            || crate::event_loop::with_window_target(|event_loop| Ok(event_loop.is_wayland()))
+                .unwrap_or(false);
+        if let Some(increments) = self.resize_increments.get().filter(|_| snap_to_increments) {
+            // The increments start at the minimum size, like with the size hints of X11
+            let min_size: winit::dpi::PhysicalSize<f32> =
+                self.constraints.get().min.map_or(Default::default(), |min| {
+                    logical_size_to_winit(min).to_physical(self.window().scale_factor() as f64)
+                });
+            let snap = |size: f32, min: f32, increment: f32| {
+                if increment >= 1. {
+                    min + ((size - min) / increment).round() * increment
+                } else {
+                    size
+                }
+            };
+            new_size.width = snap(new_size.width, min_size.width, increments.width);
+            new_size.height = snap(new_size.height, min_size.height, increments.height);
+        }
+        if let Some(aspect_ratio) = self.aspect_ratio.get() {
+            new_size.height = (new_size.width / aspect_ratio).round();
+        }
+
+        let new_size = new_size.cast::<u32>();
+        if new_size != size && new_size.width > 0 && new_size.height > 0 {
+            self.resize_window(new_size.into()).ok();
+        }
+    }
+
     pub fn resize_event(&self, size: winit::dpi::PhysicalSize<u32>) -> Result<(), PlatformError> {
         self.pending_resize_event_after_show.set(false);
         // When a window is minimized on Windows, we get a move event to an off-screen position
@@ -620,6 +1000,30 @@ pub fn window_state_event(&self) {
         if fullscreen != self.window().is_fullscreen() {
             self.window().set_fullscreen(fullscreen);
         }
+
+        // Without decorations, the application draws its own. Otherwise winit asks Wayland compositors for
+        // server-side decorations and draws the decorations itself when the compositor doesn't support them.
+        // These are part of the outer size of the window. Full-screen windows have no decorations, so keep
+        // the mode they had before.
+        if !fullscreen {
+            let window_inner = WindowInner::from_pub(self.window());
+            let decoration_mode = if !winit_window.is_decorated()
+                || (winit_window.outer_size() != winit_window.inner_size()
+                    && crate::event_loop::with_window_target(|event_loop| {
+                        Ok(event_loop.is_wayland())
+                    })
+                    .unwrap_or(false))
+            {
+                corelib::items::WindowDecorationMode::Client
+            } else {
+                corelib::items::WindowDecorationMode::Server
+            };
+            if decoration_mode != window_inner.decoration_mode() {
+                window_inner.set_decoration_mode(decoration_mode);
+            }
+        }
+
+        self.resume_rendering();
     }
 
     #[cfg(enable_accesskit)]
@@ -685,6 +1089,149 @@ fn spawn_xdg_settings_watcher(&self) -> Option<i_slint_core::future::JoinHandle<
             })
             .ok()
     }
+
+    /// Reads the accent color from the XDG settings portal, and updates it whenever it changes.
+    #[cfg(not(use_winit_theme))]
+    fn spawn_accent_color_watcher(&self) -> Option<i_slint_core::future::JoinHandle<()>> {
+        let window_inner = WindowInner::from_pub(self.window());
+        let self_weak = self.self_weak.clone();
+        window_inner
+            .context()
+            .spawn_local(async move {
+                let Ok(settings) = ashpd::desktop::settings::Settings::new().await else { return };
+
+                let convert = |color: ashpd::desktop::Color| {
+                    let component = |value: f64| (value.clamp(0., 1.) * 255.).round() as u8;
+                    Color::from_rgb_u8(
+                        component(color.red()),
+                        component(color.green()),
+                        component(color.blue()),
+                    )
+                };
+                let set_accent_color = |color| {
+                    if let Some(accent_color) =
+                        self_weak.upgrade().as_ref().and_then(|window| window.accent_color.get())
+                    {
+                        accent_color.as_ref().set(color);
+                    }
+                };
+
+                if let Ok(color) = settings.accent_color().await {
+                    set_accent_color(convert(color));
+                }
+
+                let Ok(mut accent_color_stream) = settings.receive_accent_color_changed().await
+                else {
+                    return;
+                };
+
+                loop {
+                    use futures::stream::StreamExt;
+
+                    let Some(color) = accent_color_stream.next().await else { break };
+                    set_accent_color(convert(color));
+                }
+            })
+            .ok()
+    }
+
+    /// Reads the accent color of Windows or macOS again, which the systems don't notify about.
+    #[cfg(any(target_family = "windows", target_os = "macos"))]
+    pub(crate) fn update_accent_color(&self) {
+        if let Some(accent_color) = self.accent_color.get() {
+            accent_color.as_ref().set(crate::accent_color::system_accent_color());
+        }
+    }
+
+    /// Reads the default font and the text scaling factor from the GNOME or KDE settings through
+    /// the XDG settings portal, and dispatches them again whenever they change.
+    #[cfg(not(use_winit_theme))]
+    fn spawn_system_font_watcher(&self) -> Option<i_slint_core::future::JoinHandle<()>> {
+        const GNOME_INTERFACE: &str = "org.gnome.desktop.interface";
+        const KDE_GENERAL: &str = "org.kde.kdeglobals.General";
+
+        async fn read_system_font(
+            settings: &ashpd::desktop::settings::Settings<'_>,
+        ) -> (corelib::SharedString, f32) {
+            // GNOME stores the font as "Family Size", KDE as a comma separated Qt font description.
+            let family = match settings.read::<String>(GNOME_INTERFACE, "font-name").await {
+                Ok(font_name) => font_name
+                    .rsplit_once(' ')
+                    .filter(|(_, size)| size.parse::<f32>().is_ok())
+                    .map_or(font_name.as_str(), |(family, _)| family)
+                    .into(),
+                Err(_) => settings
+                    .read::<String>(KDE_GENERAL, "font")
+                    .await
+                    .ok()
+                    .and_then(|font| font.split(',').next().map(Into::into))
+                    .unwrap_or_default(),
+            };
+            let text_scale_factor = settings
+                .read::<f64>(GNOME_INTERFACE, "text-scaling-factor")
+                .await
+                .ok()
+                .filter(|factor| *factor > 0.)
+                .map_or(1., |factor| factor as f32);
+            (family, text_scale_factor)
+        }
+
+        let window_inner = WindowInner::from_pub(self.window());
+        let self_weak = self.self_weak.clone();
+        window_inner
+            .context()
+            .spawn_local(async move {
+                let Ok(settings) = ashpd::desktop::settings::Settings::new().await else { return };
+
+                let dispatch = |(family, text_scale_factor)| {
+                    if let Some(window) = self_weak.upgrade() {
+                        window
+                            .window()
+                            .try_dispatch_event(WindowEvent::SystemFontChanged {
+                                family,
+                                text_scale_factor,
+                            })
+                            .ok();
+                    }
+                };
+
+                dispatch(read_system_font(&settings).await);
+
+                let Ok(mut setting_stream) = settings.receive_setting_changed().await else {
+                    return;
+                };
+
+                loop {
+                    use futures::stream::StreamExt;
+
+                    let Some(setting) = setting_stream.next().await else { break };
+                    if matches!(setting.namespace(), GNOME_INTERFACE | KDE_GENERAL) {
+                        dispatch(read_system_font(&settings).await);
+                    }
+                }
+            })
+            .ok()
+    }
+
+    /// Dispatches `KeyboardLayoutChanged` if the user switched layouts since the last call.
+    #[cfg(target_family = "windows")]
+    pub(crate) fn check_keyboard_layout(&self) -> Result<(), PlatformError> {
+        let layout = crate::keyboard_layout::current_layout();
+        match self.keyboard_layout.replace(Some(layout)) {
+            Some(previous) if previous != layout => {
+                self.window().try_dispatch_event(WindowEvent::KeyboardLayoutChanged)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Dispatches the default font and the text scaling factor of the Windows or macOS settings.
+    #[cfg(any(target_family = "windows", target_os = "macos"))]
+    pub(crate) fn dispatch_system_font(&self) -> Result<(), PlatformError> {
+        let (family, text_scale_factor) = crate::system_font::system_font();
+        self.window()
+            .try_dispatch_event(WindowEvent::SystemFontChanged { family, text_scale_factor })
+    }
 }
 
 impl WindowAdapter for WinitWindowAdapter {
@@ -706,6 +1253,10 @@ fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
         self.pending_redraw.set(false);
         if visible {
             let recreating_window = self.winit_window_or_none.borrow().as_window().is_none();
+            let has_explicit_position = match &*self.winit_window_or_none.borrow() {
+                WinitWindowOrNone::HasWindow { .. } => true,
+                WinitWindowOrNone::None(attributes) => attributes.borrow().position.is_some(),
+            };
 
             let winit_window = self.ensure_window()?;
 
@@ -756,8 +1307,25 @@ fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
                 self.resize_window(size.into())?;
             };
 
+            let parent = runtime_window.parent_window().as_ref().and_then(parent_winit_window);
+            if let Some(parent) = parent.as_ref().filter(|_| !has_explicit_position) {
+                // Like platforms do with dialogs, show the window centered on its parent
+                if let Ok(parent_position) = parent.outer_position() {
+                    let parent_size = parent.outer_size();
+                    let size = winit_window.outer_size();
+                    winit_window.set_outer_position(winit::dpi::PhysicalPosition::new(
+                        parent_position.x + (parent_size.width as i32 - size.width as i32) / 2,
+                        parent_position.y + (parent_size.height as i32 - size.height as i32) / 2,
+                    ));
+                }
+            }
+
             winit_window.set_visible(true);
 
+            if parent.is_some() {
+                apply_parent_window(&winit_window, parent.as_deref());
+            }
+
             // Make sure the dark color scheme property is up-to-date, as it may have been queried earlier when
             // the window wasn't mapped yet.
             if let Some(color_scheme_prop) = self.color_scheme.get() {
@@ -851,7 +1419,10 @@ fn size(&self) -> corelib::api::PhysicalSize {
     }
 
     fn request_redraw(&self) {
-        if !self.pending_redraw.replace(true) {
+        // While the window can't be seen or the frame rate limit is reached, the redraw stays
+        // pending until resume_rendering()
+        if !self.pending_redraw.replace(true) && !self.rendering_paused() && !self.frame_deferred()
+        {
             if let Some(window) = self.winit_window_or_none.borrow().as_window() {
                 window.request_redraw()
             }
@@ -869,16 +1440,31 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
 
         let winit_window_or_none = self.winit_window_or_none.borrow();
 
-        winit_window_or_none.set_window_icon(icon_to_winit(window_item.icon()));
-        winit_window_or_none.set_title(&properties.title());
-        winit_window_or_none.set_decorations(
-            !window_item.no_frame() || winit_window_or_none.fullscreen().is_some(),
+        let icons = properties.icons();
+        let smallest_icon = icons.iter().min_by_key(|icon| icon.size().width).cloned();
+        let largest_icon = icons.iter().max_by_key(|icon| icon.size().width).cloned();
+        // On Windows, the window icon is the small one in the title bar, and the taskbar has its own
+        // larger icon. Other platforms scale the window icon to the size they need.
+        let window_icon =
+            if cfg!(target_family = "windows") { smallest_icon } else { largest_icon.clone() };
+        winit_window_or_none.set_window_icon(
+            window_icon.and_then(icon_to_winit),
+            largest_icon.and_then(icon_to_winit),
         );
-
-        let new_window_level = if window_item.always_on_top() {
-            winit::window::WindowLevel::AlwaysOnTop
-        } else {
-            winit::window::WindowLevel::Normal
+        winit_window_or_none.set_title(&properties.title());
+        // With client-side decorations, the application draws the title bar, for example with a WindowFrameArea.
+        let client_decorations = window_item.no_frame()
+            || properties.preferred_decoration_mode()
+                == corelib::items::WindowDecorationMode::Client;
+        winit_window_or_none
+            .set_decorations(!client_decorations || winit_window_or_none.fullscreen().is_some());
+
+        let new_window_level = match properties.window_level() {
+            corelib::items::WindowLevel::Normal => winit::window::WindowLevel::Normal,
+            corelib::items::WindowLevel::AlwaysOnTop => winit::window::WindowLevel::AlwaysOnTop,
+            corelib::items::WindowLevel::AlwaysOnBottom => {
+                winit::window::WindowLevel::AlwaysOnBottom
+            }
         };
         // Only change the window level if it changes, to avoid https://github.com/slint-ui/slint/issues/3280
         // (Ubuntu 20.04's window manager always bringing the window to the front on x11)
@@ -886,6 +1472,19 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
             winit_window_or_none.set_window_level(new_window_level);
         }
 
+        let background_effect = properties.background_effect();
+        if self.background_effect.replace(background_effect) != background_effect {
+            winit_window_or_none.set_background_effect(background_effect);
+        }
+
+        // Applied in ensure_window() when the window doesn't exist yet
+        let opacity = properties.opacity();
+        if self.opacity.replace(opacity) != opacity {
+            if let Some(winit_window) = winit_window_or_none.as_window() {
+                apply_window_opacity(&winit_window, opacity);
+            }
+        }
+
         // Use our scale factor instead of winit's logical size to take a scale factor override into account.
         let sf = self.window().scale_factor();
 
@@ -914,8 +1513,13 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
             // when we create a surface that's bigger than the screen due to constraints (#532).
             if winit_window_or_none.fullscreen().is_none() {
                 // TODO: don't ignore error, propgate to caller
+                // Convert with our scale factor, which may be overridden, instead of winit's
                 let immediately_resized = self
-                    .resize_window(winit::dpi::LogicalSize::new(width, height).into())
+                    .resize_window(
+                        winit::dpi::LogicalSize::new(width, height)
+                            .to_physical::<u32>(sf as f64)
+                            .into(),
+                    )
                     .unwrap_or_default();
                 if immediately_resized {
                     // The resize event was already dispatched
@@ -955,8 +1559,19 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
         if m != self.minimized.get() {
             self.minimized.set(m);
             winit_window_or_none.set_minimized(m);
+            if !m {
+                self.resume_rendering();
+            }
         }
 
+        let resize_increments = properties
+            .resize_increments()
+            .map(|increments| logical_size_to_winit(increments).to_physical::<f32>(sf as f64));
+        if self.resize_increments.replace(resize_increments) != resize_increments {
+            winit_window_or_none.set_resize_increments(resize_increments);
+        }
+        self.aspect_ratio.set(properties.aspect_ratio());
+
         // If we're in fullscreen, don't try to resize the window but
         // maintain the surface size we've been assigned to from the
         // windowing system. Weston/Wayland don't like it when we create a
@@ -1012,7 +1627,16 @@ fn internal(&self, _: corelib::InternalToken) -> Option<&dyn WindowAdapterIntern
 }
 
 impl WindowAdapterInternal for WinitWindowAdapter {
+    fn refresh_interval(&self) -> Option<core::time::Duration> {
+        let millihertz = self.winit_window()?.current_monitor()?.refresh_rate_millihertz()?;
+        Some(core::time::Duration::from_secs(1000) / millihertz)
+    }
+
     fn set_mouse_cursor(&self, cursor: MouseCursor) {
+        self.mouse_cursor.set(cursor);
+        if self.custom_cursor.borrow().is_some() {
+            return;
+        }
         let winit_cursor = match cursor {
             MouseCursor::Default => winit::window::CursorIcon::Default,
             MouseCursor::None => winit::window::CursorIcon::Default,
@@ -1050,6 +1674,162 @@ fn set_mouse_cursor(&self, cursor: MouseCursor) {
         }
     }
 
+    fn drag_window_frame(&self, region: corelib::items::WindowFrameRegion) {
+        use corelib::items::WindowFrameRegion;
+        use winit::window::ResizeDirection;
+        let Some(winit_window) = self.winit_window() else { return };
+        let direction = match region {
+            WindowFrameRegion::Client | WindowFrameRegion::MaximizeButton => return,
+            WindowFrameRegion::TitleBar => {
+                let _ = winit_window.drag_window();
+                return;
+            }
+            WindowFrameRegion::ResizeNorth => ResizeDirection::North,
+            WindowFrameRegion::ResizeSouth => ResizeDirection::South,
+            WindowFrameRegion::ResizeEast => ResizeDirection::East,
+            WindowFrameRegion::ResizeWest => ResizeDirection::West,
+            WindowFrameRegion::ResizeNorthEast => ResizeDirection::NorthEast,
+            WindowFrameRegion::ResizeNorthWest => ResizeDirection::NorthWest,
+            WindowFrameRegion::ResizeSouthEast => ResizeDirection::SouthEast,
+            WindowFrameRegion::ResizeSouthWest => ResizeDirection::SouthWest,
+        };
+        let _ = winit_window.drag_resize_window(direction);
+    }
+
+    fn screen_geometries(
+        &self,
+    ) -> Option<Vec<(corelib::api::PhysicalPosition, corelib::api::PhysicalSize)>> {
+        let monitors = crate::event_loop::with_window_target(|event_loop| {
+            let monitors: Vec<_> = match event_loop.event_loop() {
+                crate::event_loop::ActiveOrInactiveEventLoop::Active(event_loop) => {
+                    event_loop.available_monitors().collect()
+                }
+                #[allow(deprecated)]
+                crate::event_loop::ActiveOrInactiveEventLoop::Inactive(event_loop) => {
+                    event_loop.available_monitors().collect()
+                }
+            };
+            Ok(monitors)
+        })
+        .ok()?;
+        // Some platforms don't report the monitors, so the position can't be checked
+        if monitors.is_empty() {
+            return None;
+        }
+        Some(
+            monitors
+                .iter()
+                .map(|monitor| {
+                    let (position, size) = (monitor.position(), monitor.size());
+                    (
+                        corelib::api::PhysicalPosition::new(position.x, position.y),
+                        corelib::api::PhysicalSize::new(size.width, size.height),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn video_modes(&self) -> Vec<corelib::api::VideoMode> {
+        let Some(monitor) = self.winit_window().and_then(|window| window.current_monitor()) else {
+            return Vec::new();
+        };
+        monitor.video_modes().map(|mode| video_mode_from_winit(&mode)).collect()
+    }
+
+    fn set_exclusive_fullscreen(
+        &self,
+        mode: Option<corelib::api::VideoMode>,
+    ) -> Result<(), PlatformError> {
+        let Some(mode) = mode else {
+            // Leaving the fullscreen state also leaves the exclusive fullscreen
+            self.window().set_fullscreen(false);
+            return Ok(());
+        };
+        let monitor = self
+            .winit_window()
+            .and_then(|window| window.current_monitor())
+            .ok_or("The window must be shown on a monitor to enter exclusive fullscreen")?;
+        let video_mode = monitor
+            .video_modes()
+            .find(|candidate| video_mode_from_winit(candidate) == mode)
+            .ok_or("The monitor of the window doesn't support the requested video mode")?;
+        self.winit_window_or_none
+            .borrow()
+            .set_fullscreen(Some(winit::window::Fullscreen::Exclusive(video_mode)));
+        self.window().set_fullscreen(true);
+        Ok(())
+    }
+
+    fn set_custom_cursor(
+        &self,
+        cursor: Option<corelib::api::CustomCursor>,
+    ) -> Result<(), PlatformError> {
+        let Some(cursor) = cursor else {
+            if self.custom_cursor.take().is_some() {
+                self.set_mouse_cursor(self.mouse_cursor.get());
+            }
+            return Ok(());
+        };
+
+        let hotspot = cursor.hotspot();
+        let frames = cursor
+            .frames()
+            .iter()
+            .map(|image| -> Result<_, Box<dyn std::error::Error + Send + Sync>> {
+                let pixels =
+                    image.to_rgba8().ok_or("The pixels of the cursor image are not available")?;
+                let source = winit::window::CustomCursor::from_rgba(
+                    pixels.as_bytes().to_vec(),
+                    pixels.width().try_into()?,
+                    pixels.height().try_into()?,
+                    hotspot.x.try_into()?,
+                    hotspot.y.try_into()?,
+                )?;
+                crate::event_loop::with_window_target(|event_loop| {
+                    Ok(match event_loop.event_loop() {
+                        crate::event_loop::ActiveOrInactiveEventLoop::Active(event_loop) => {
+                            event_loop.create_custom_cursor(source)
+                        }
+                        #[allow(deprecated)]
+                        crate::event_loop::ActiveOrInactiveEventLoop::Inactive(event_loop) => {
+                            event_loop.create_custom_cursor(source)
+                        }
+                    })
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| format!("Error creating the custom cursor: {err}"))?;
+
+        let animation_timer = corelib::timers::Timer::default();
+        if frames.len() > 1 {
+            let self_weak = self.self_weak.clone();
+            animation_timer.start(
+                corelib::timers::TimerMode::Repeated,
+                cursor.frame_duration(),
+                move || {
+                    let Some(this) = self_weak.upgrade() else { return };
+                    if let Some(state) = this.custom_cursor.borrow_mut().as_mut() {
+                        state.current_frame = (state.current_frame + 1) % state.frames.len();
+                    }
+                    if let Some(winit_window) = this.winit_window() {
+                        this.apply_custom_cursor(&winit_window);
+                    }
+                },
+            );
+        }
+        self.custom_cursor.replace(Some(CustomCursorState {
+            frames,
+            current_frame: 0,
+            _animation_timer: animation_timer,
+        }));
+        // Otherwise it's applied when the window is created, in ensure_window()
+        if let Some(winit_window) = self.winit_window() {
+            self.apply_custom_cursor(&winit_window);
+        }
+        Ok(())
+    }
+
     fn input_method_request(&self, request: corelib::window::InputMethodRequest) {
         #[cfg(not(target_arch = "wasm32"))]
         if let Some(winit_window) = self.winit_window_or_none.borrow().as_window() {
@@ -1101,6 +1881,21 @@ fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 
+    fn accent_color(&self) -> Color {
+        self.accent_color
+            .get_or_init(|| {
+                #[cfg(not(use_winit_theme))]
+                if let Some(old_watch) =
+                    self.accent_color_watcher.replace(self.spawn_accent_color_watcher())
+                {
+                    old_watch.abort()
+                }
+                Box::pin(Property::new(crate::accent_color::system_accent_color()))
+            })
+            .as_ref()
+            .get()
+    }
+
     fn color_scheme(&self) -> ColorScheme {
         self.color_scheme
             .get_or_init(|| {
@@ -1190,7 +1985,73 @@ fn display_handle_06_rc(
             .map_or(Err(raw_window_handle::HandleError::Unavailable), |window| Ok(window))
     }
 
+    fn set_idle_inhibited(&self, inhibited: bool) {
+        let mut idle_inhibitor = self.idle_inhibitor.borrow_mut();
+        if !inhibited {
+            *idle_inhibitor = None;
+        } else if idle_inhibitor.is_none() {
+            let window_inner = WindowInner::from_pub(self.window());
+            let application_name = window_inner.xdg_app_id().unwrap_or_else(|| "Slint".into());
+            *idle_inhibitor = crate::idle_inhibitor::IdleInhibitor::new(
+                window_inner.context(),
+                &application_name,
+            );
+        }
+    }
+
+    fn request_user_attention(&self, attention: corelib::api::UserAttentionType) {
+        if let Some(winit_window) = self.winit_window_or_none.borrow().as_window() {
+            winit_window.request_user_attention(Some(match attention {
+                corelib::api::UserAttentionType::Critical => {
+                    winit::window::UserAttentionType::Critical
+                }
+                corelib::api::UserAttentionType::Informational => {
+                    winit::window::UserAttentionType::Informational
+                }
+            }));
+        }
+    }
+
+    #[cfg(target_family = "windows")]
+    fn physical_key_text(&self, physical_key: &str) -> Option<corelib::SharedString> {
+        crate::keyboard_layout::physical_key_text(
+            physical_key,
+            crate::keyboard_layout::current_layout(),
+        )
+    }
+
+    fn set_parent_window(&self, parent: Option<Rc<dyn WindowAdapter>>, _modal: bool) {
+        // Otherwise, the parent is applied when the window is shown
+        if !self.shown.get() {
+            return;
+        }
+        if let Some(winit_window) = self.winit_window() {
+            let parent = parent.as_ref().and_then(parent_winit_window);
+            apply_parent_window(&winit_window, parent.as_deref());
+        }
+    }
+
     fn bring_to_front(&self) -> Result<(), PlatformError> {
+        // Wayland compositors only let a window take the focus with an xdg-activation token that
+        // was requested by the window that has the focus.
+        #[cfg(enable_startup_notify)]
+        if crate::event_loop::with_window_target(|event_loop| Ok(event_loop.is_wayland()))? {
+            if let Some(active_window) =
+                crate::event_loop::active_window().filter(|w| !core::ptr::eq(Rc::as_ptr(w), self))
+            {
+                if let Some(serial) = active_window.winit_window().and_then(|w| {
+                    use winit::platform::startup_notify::WindowExtStartupNotify;
+                    w.request_activation_token().ok()
+                }) {
+                    active_window
+                        .pending_activations
+                        .borrow_mut()
+                        .push((serial, self.self_weak.clone()));
+                    return Ok(());
+                }
+            }
+        }
+
         if let Some(winit_window) = self.winit_window_or_none.borrow().as_window() {
             winit_window.set_minimized(false);
             winit_window.focus_window();
@@ -1209,6 +2070,16 @@ fn drop(&mut self) {
         if let Some(xdg_watch_future) = self.xdg_settings_watcher.take() {
             xdg_watch_future.abort();
         }
+
+        #[cfg(not(use_winit_theme))]
+        if let Some(system_font_watch_future) = self.system_font_watcher.take() {
+            system_font_watch_future.abort();
+        }
+
+        #[cfg(not(use_winit_theme))]
+        if let Some(accent_color_watch_future) = self.accent_color_watcher.take() {
+            accent_color_watch_future.abort();
+        }
     }
 }
 
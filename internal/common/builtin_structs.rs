@@ -65,6 +65,19 @@ struct PointerEvent {
                     kind: PointerEventKind,
                     /// The keyboard modifiers pressed during the event
                     modifiers: KeyboardModifiers,
+                    /// The kind of device that produced the event
+                    tool_type: PointerToolType,
+                    /// The pressure of the pen or finger, between 0 and 1. Devices that don't report a pressure
+                    /// have 0.5 while a button is pressed, and 0 otherwise.
+                    pressure: f32,
+                    /// The angle between the pen and the screen in the plane of the x axis, in degrees between -90 and 90.
+                    /// Positive values tilt the pen towards the right.
+                    tilt_x: f32,
+                    /// The angle between the pen and the screen in the plane of the y axis, in degrees between -90 and 90.
+                    /// Positive values tilt the pen towards the bottom.
+                    tilt_y: f32,
+                    /// The clockwise rotation of the pen around its own axis, in degrees between 0 and 359.
+                    twist: f32,
                 }
                 private {
                 }
@@ -81,6 +94,13 @@ struct PointerScrollEvent {
                     delta_y: Coord,
                     /// The keyboard modifiers pressed during the event
                     modifiers: KeyboardModifiers,
+                    /// The kind of device that produced the event
+                    device: PointerScrollDevice,
+                    /// The number of lines in the horizontal direction, such as the number of notches a
+                    /// mouse wheel was turned. It's fractional for touchpads.
+                    line_delta_x: f32,
+                    /// The number of lines in the vertical direction.
+                    line_delta_y: f32,
                 }
                 private {
                 }
@@ -97,6 +117,13 @@ struct KeyEvent {
                     /// This field is set to true for key press events that are repeated,
                     /// i.e. the key is held down. It's always false for key release events.
                     repeat: bool,
+                    /// The name of the physical key, independent of the keyboard layout, as the `code` of the
+                    /// W3C UI Events (for example `KeyA` for the key left of `S`, or `ArrowLeft`).
+                    /// It's empty when the backend doesn't report it.
+                    physical_key: SharedString,
+                    /// The scan code of the physical key, specific to the platform. It's 0 when the backend
+                    /// doesn't report it.
+                    scancode: i32,
                 }
                 private {
                     /// Indicates whether the key was pressed or released
@@ -109,12 +136,48 @@ struct KeyEvent {
                     preedit_text: SharedString,
                     /// The selection within the preedit_text
                     preedit_selection: Option<core::ops::Range<i32>>,
+                    /// The clause of the preedit_text that the input method is currently converting, if any
+                    preedit_clause: Option<core::ops::Range<i32>>,
                     /// The new cursor position, when None, the cursor is put after the text that was just inserted
                     cursor_position: Option<i32>,
                     anchor_position: Option<i32>,
                 }
             }
 
+            /// This structure is passed to the `composition-updated` and `composition-committed` callbacks of the
+            /// `FocusScope` element.
+            struct CompositionEvent {
+                @name = "slint::private_api::CompositionEvent"
+                export {
+                    /// The text that the input method is composing (the pre-edit text), or the text to insert
+                    /// when the composition is committed.
+                    text: SharedString,
+                    /// The byte offset in `text` of the start of the clause that the input method is converting,
+                    /// or of the cursor. It's -1 when the input method doesn't show a cursor.
+                    selection_start: i32,
+                    /// The byte offset in `text` of the end of the clause that the input method is converting.
+                    /// It's equal to `selection_start` when there is only a cursor.
+                    selection_end: i32,
+                }
+                private {
+                }
+            }
+
+            /// This structure is passed to the `can-drop` and `dropped` callbacks of the `DropArea` element.
+            struct DropEvent {
+                @name = "slint::private_api::DropEvent"
+                export {
+                    /// The MIME type of the dragged data. Files dragged from the file manager have the
+                    /// type `text/uri-list`.
+                    mime_type: SharedString,
+                    /// The dragged data. For `text/uri-list`, these are the `file://` URIs of the files,
+                    /// one per line.
+                    data: SharedString,
+                }
+                private {
+                }
+            }
+
             /// Represents an item in a StandardListView and a StandardTableView.
             #[non_exhaustive]
             struct StandardListViewItem {
@@ -193,11 +256,16 @@ struct MenuEntry {
                     // icon: Image,
                     /// an opaque id that can be used to identify the menu entry
                     id: SharedString,
-                    // keyboard_shortcut: KeySequence,
-                    // /// whether the menu entry is enabled
-                    // enabled: bool,
                     /// Sub menu
                     has_sub_menu: bool,
+                    /// whether the menu entry is disabled and can't be activated
+                    disabled: bool,
+                    /// whether the menu entry shows a check mark when `checked` is set
+                    checkable: bool,
+                    /// whether the check mark of a checkable menu entry is shown
+                    checked: bool,
+                    /// The keyboard shortcut that activates the menu entry, like `Ctrl+Shift+S`
+                    shortcut: SharedString,
                 }
                 private {}
             }
@@ -58,6 +58,16 @@ enum TextOverflow {
                 Elide,
             }
 
+            /// This enum describes the direction in which the lines of a `Text` flow.
+            enum TextWritingMode {
+                /// The text is laid out in horizontal lines, from top to bottom.
+                HorizontalTb,
+                /// The text is laid out in vertical columns from top to bottom, and the columns from right to left,
+                /// as commonly used for Chinese and Japanese text. Punctuation is replaced with its vertical form.
+                /// Currently only supported by the Software renderer.
+                VerticalRl,
+            }
+
             /// This enum describes the positioning of a text stroke relative to the border of the glyphs in a `Text`.
             enum TextStrokeStyle {
                 /// The inside edge of the stroke is at the outer edge of the text.
@@ -66,6 +76,14 @@ enum TextStrokeStyle {
                 Center,
             }
 
+            /// This enum describes how the ranges of text decorated with `TextInput`'s `add-underline()` function are underlined.
+            enum TextUnderlineStyle {
+                /// A straight line below the text.
+                Solid,
+                /// A wavy line below the text, as commonly used to mark spelling mistakes.
+                Wavy,
+            }
+
             /// This enum describes whether an event was rejected or accepted by an event handler.
             enum EventResult {
                 /// The event is rejected by this event handler and may then be handled by the parent item
@@ -162,6 +180,30 @@ enum PointerEventButton {
                 Forward,
             }
 
+            /// This enum describes the kind of device that produced a pointer event.
+            #[non_exhaustive]
+            enum PointerToolType {
+                /// A mouse, or a device of unknown kind.
+                Mouse,
+                /// A finger on a touch screen.
+                Touch,
+                /// The tip of a pen or stylus.
+                Pen,
+                /// The eraser end of a pen, or a pen whose eraser button is held.
+                Eraser,
+            }
+
+            /// This enum describes the kind of device that produced a scroll event.
+            #[non_exhaustive]
+            enum PointerScrollDevice {
+                /// The backend didn't report the kind of device.
+                Unknown,
+                /// A mouse wheel, which scrolls by whole lines.
+                Wheel,
+                /// A touchpad, or another device that scrolls with pixel precision.
+                Touchpad,
+            }
+
             /// This enum represents different types of mouse cursors. It's a subset of the mouse cursors available in CSS.
             /// For details and pictograms see the [MDN Documentation for cursor](https://developer.mozilla.org/en-US/docs/Web/CSS/cursor#values).
             /// Depending on the backend and used OS unidirectional resize cursors may be replaced with bidirectional ones.
@@ -245,6 +287,9 @@ enum ImageFit {
                 /// The source image will still be scaled by the scale factor that applies to all elements in the window.
                 /// Any extra space will be left blank.
                 Preserve,
+                /// Repeats the source image at its size in logical pixels, horizontally and vertically, to fill the `Image` element.
+                /// The alignment sets where the first tile is placed. Use `horizontal-tiling` and `vertical-tiling` to repeat in only one direction.
+                Tile,
             }
 
             /// This enum specifies the horizontal alignment of the source image.
@@ -275,6 +320,18 @@ enum ImageRendering {
                 Pixelated,
             }
 
+            /// This enum describes the state of an image that is loaded in the background, such as with
+            /// `Image::load_from_path_async()` in Rust. Use it to show a placeholder while the image is loading,
+            /// or an error indicator if it couldn't be loaded.
+            enum ImageLoadingStatus {
+                /// The image is being loaded.
+                Loading,
+                /// The image was loaded successfully.
+                Loaded,
+                /// The image couldn't be loaded or decoded.
+                Error,
+            }
+
             /// This enum specifies how the source image will be tiled.
             enum ImageTiling {
                 /// The source image will not be tiled.
@@ -298,6 +355,102 @@ enum InputType {
                 Decimal,
             }
 
+            /// This enum describes which keys move the keyboard focus in a `Window`, for devices
+            /// without touch or pointer input, such as appliances with a rotary encoder and hardware keys.
+            /// Rotation of a rotary encoder always moves the focus to the next or previous item.
+            enum KeyNavigation {
+                /// Only the tab key moves the focus to the next or previous item.
+                Tab,
+                /// The arrow keys move the focus to the next (right and down) or previous (left and up)
+                /// item, when the focused item doesn't handle them.
+                Sequential,
+                /// The arrow keys move the focus to the closest item in that direction, when the focused item
+                /// doesn't handle them.
+                Spatial,
+            }
+
+            /// This enum describes what pressing a `WindowFrameArea` does, to implement the frame of a `Window`
+            /// with the `no-frame` property.
+            enum WindowFrameRegion {
+                /// The area behaves like the inside of the window, even within another `WindowFrameArea`.
+                Client,
+                /// Dragging the area moves the window, and double-clicking it maximizes or restores the window.
+                TitleBar,
+                /// Dragging the area resizes the window at its top edge.
+                ResizeNorth,
+                /// Dragging the area resizes the window at its bottom edge.
+                ResizeSouth,
+                /// Dragging the area resizes the window at its right edge.
+                ResizeEast,
+                /// Dragging the area resizes the window at its left edge.
+                ResizeWest,
+                /// Dragging the area resizes the window at its top right corner.
+                ResizeNorthEast,
+                /// Dragging the area resizes the window at its top left corner.
+                ResizeNorthWest,
+                /// Dragging the area resizes the window at its bottom right corner.
+                ResizeSouthEast,
+                /// Dragging the area resizes the window at its bottom left corner.
+                ResizeSouthWest,
+                /// Clicking the area maximizes or restores the window. On Windows 11, hovering it shows the snap layouts.
+                MaximizeButton,
+            }
+
+            /// This enum describes the edge or corner of a `Window` at which `start-system-resize()` resizes it.
+            enum WindowResizeEdge {
+                /// The top edge.
+                North,
+                /// The bottom edge.
+                South,
+                /// The right edge.
+                East,
+                /// The left edge.
+                West,
+                /// The top right corner.
+                NorthEast,
+                /// The top left corner.
+                NorthWest,
+                /// The bottom right corner.
+                SouthEast,
+                /// The bottom left corner.
+                SouthWest,
+            }
+
+            /// This enum describes the effect that the platform draws behind the transparent parts of the
+            /// background of a `Window`.
+            enum WindowBackgroundEffect {
+                /// The platform draws no effect.
+                None,
+                /// What is behind the window is blurred: vibrancy on macOS, acrylic on Windows 11, and
+                /// the blur-behind of KDE Plasma on Linux.
+                Blur,
+                /// The acrylic material of Windows 11, which blurs what is behind the window. Other
+                /// platforms use their blur effect.
+                Acrylic,
+                /// The mica material of Windows 11, which is tinted with the desktop wallpaper. Other
+                /// platforms use their blur effect.
+                Mica,
+            }
+
+            /// This enum describes who draws the decorations of a `Window`, such as the title bar and the borders.
+            enum WindowDecorationMode {
+                /// The windowing system draws the decorations.
+                Server,
+                /// The decorations are drawn inside of the window by the application, such as the decorations
+                /// that Slint draws on Wayland when the compositor doesn't support server-side decorations.
+                Client,
+            }
+
+            /// This enum describes whether a `Window` is kept above or below the windows of other applications.
+            enum WindowLevel {
+                /// The window is stacked with the other windows as usual.
+                Normal,
+                /// The window floats above the other windows, even when it isn't active.
+                AlwaysOnTop,
+                /// The window stays below the other windows, for example for a desktop widget.
+                AlwaysOnBottom,
+            }
+
             /// Enum representing the `alignment` property of a
             /// `HorizontalBox`, a `VerticalBox`,
             /// a `HorizontalLayout`, or `VerticalLayout`.
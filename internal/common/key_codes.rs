@@ -121,6 +121,13 @@ macro_rules! for_each_special_keys {
 //'\u{F745}'	# Find        # Qt_Key_Key_Find         #              ;
 //'\u{F746}'	# Help        # Qt_Key_Key_Help         #              ;
 //'\u{F747}'	# ModeSwitch  # Qt_Key_Key_Mode_switch  #            ;
+'\u{F748}'	# MediaPlayPause     # Qt_Key_Key_MediaTogglePlayPause|Qt_Key_Key_MediaPlay # MediaPlayPause     # XF86_AudioPlay        ;
+'\u{F749}'	# MediaStop          # Qt_Key_Key_MediaStop         # MediaStop          # XF86_AudioStop        ;
+'\u{F74A}'	# MediaTrackNext     # Qt_Key_Key_MediaNext         # MediaTrackNext     # XF86_AudioNext        ;
+'\u{F74B}'	# MediaTrackPrevious # Qt_Key_Key_MediaPrevious     # MediaTrackPrevious # XF86_AudioPrev        ;
+'\u{F74C}'	# AudioVolumeUp      # Qt_Key_Key_VolumeUp          # AudioVolumeUp      # XF86_AudioRaiseVolume ;
+'\u{F74D}'	# AudioVolumeDown    # Qt_Key_Key_VolumeDown        # AudioVolumeDown    # XF86_AudioLowerVolume ;
+'\u{F74E}'	# AudioVolumeMute    # Qt_Key_Key_VolumeMute        # AudioVolumeMute    # XF86_AudioMute        ;
 ];
     };
 }
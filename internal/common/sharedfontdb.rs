@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 pub use fontdb;
@@ -24,9 +25,49 @@ pub struct FontDatabase {
     pub default_font_family_ids: Vec<fontdb::ID>,
     // Same as default_font_families but reduced to unique family names
     default_font_family_names: Vec<String>,
+    // Faces added with register_font_from_memory/path, which unregister_font may remove again
+    registered_font_ids: HashSet<fontdb::ID>,
+}
+
+/// The styles that a renderer synthesizes because the face matched for a query lacks them, as is
+/// common for CJK fonts that ship a single weight.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FontSynthesis {
+    /// A bold weight was requested but the face is lighter: thicken the strokes.
+    pub bold: bool,
+    /// An italic or oblique style was requested but the face is upright: slant the glyphs.
+    pub oblique: bool,
+}
+
+impl FontSynthesis {
+    /// The fraction of the font size by which the strokes are thickened for a synthesized bold.
+    pub const BOLD_STRENGTH: f32 = 1. / 24.;
+    /// The tangent of the angle by which the glyphs are slanted for a synthesized oblique (about
+    /// 12 degrees, like the oblique faces of most families).
+    pub const OBLIQUE_SLANT: f32 = 0.21;
 }
 
 impl FontDatabase {
+    /// Returns the styles requested by `query` that the face `id` lacks. Bold is only synthesized
+    /// when the family has no bold face at all, so that a bold italic request that matched a
+    /// regular italic face keeps using the face designed for it.
+    pub fn synthesis(&self, id: fontdb::ID, query: &fontdb::Query<'_>) -> FontSynthesis {
+        let Some(face_info) = self.face(id) else { return Default::default() };
+        let family = face_info.families.first().map(|(name, _)| name);
+        FontSynthesis {
+            bold: query.weight >= fontdb::Weight::SEMIBOLD
+                && face_info.weight < fontdb::Weight::SEMIBOLD
+                && !self.faces().any(|other| {
+                    other.families.first().map(|(name, _)| name) == family
+                        && other.weight >= fontdb::Weight::SEMIBOLD
+                }),
+            // The query prefers faces of the requested style, so an upright face means that the
+            // family has no italic or oblique face.
+            oblique: query.style != fontdb::Style::Normal
+                && face_info.style == fontdb::Style::Normal,
+        }
+    }
+
     pub fn query_with_family(
         &self,
         query: fontdb::Query<'_>,
@@ -182,6 +223,7 @@ fn init_fontdb() -> FontDatabase {
         fontconfig_fallback_families,
         default_font_family_ids,
         default_font_family_names,
+        registered_font_ids: Default::default(),
     }
 }
 
@@ -189,28 +231,48 @@ fn init_fontdb() -> FontDatabase {
 /// for use with the `font-family` property. The provided slice must be a valid TrueType
 /// font.
 pub fn register_font_from_memory(data: &'static [u8]) -> Result<(), Box<dyn std::error::Error>> {
-    FONT_DB.with_borrow_mut(|db| {
-        db.make_mut().load_font_source(fontdb::Source::Binary(std::sync::Arc::new(data)))
-    });
+    FONT_DB.with_borrow_mut(|db| load_font_from_memory(db, data));
     Ok(())
 }
 
+/// Loads the faces of the font in `data`, and returns their ids.
+fn load_font_from_memory(db: &mut FontDatabase, data: &'static [u8]) -> Vec<fontdb::ID> {
+    let ids = db.make_mut().load_font_source(fontdb::Source::Binary(std::sync::Arc::new(data)));
+    db.registered_font_ids.extend(ids.iter().copied());
+    ids.to_vec()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub fn register_font_from_path(path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+    FONT_DB.with_borrow_mut(|db| load_font_from_path(db, path))?;
+    Ok(())
+}
+
+/// Loads the faces of the font at `path`, unless it's already loaded, and returns their ids.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_font_from_path(
+    db: &mut FontDatabase,
+    path: &std::path::Path,
+) -> Result<Vec<fontdb::ID>, Box<dyn std::error::Error>> {
     let requested_path = path.canonicalize().unwrap_or_else(|_| path.to_owned());
-    FONT_DB.with_borrow_mut(|db| {
-        for face_info in db.faces() {
-            match &face_info.source {
-                fontdb::Source::Binary(_) => {}
-                fontdb::Source::File(loaded_path) | fontdb::Source::SharedFile(loaded_path, ..) => {
-                    if *loaded_path == requested_path {
-                        return Ok(());
-                    }
-                }
+    let loaded_ids = db
+        .faces()
+        .filter(|face_info| match &face_info.source {
+            fontdb::Source::Binary(_) => false,
+            fontdb::Source::File(loaded_path) | fontdb::Source::SharedFile(loaded_path, ..) => {
+                *loaded_path == requested_path
             }
-        }
-        db.make_mut().load_font_file(requested_path).map_err(|e| e.into())
-    })
+        })
+        .map(|face_info| face_info.id)
+        .collect::<Vec<_>>();
+    if !loaded_ids.is_empty() {
+        return Ok(loaded_ids);
+    }
+    // Report a missing or unreadable file as an error, like fontdb::Database::load_font_file
+    std::fs::File::open(&requested_path)?;
+    let ids = db.make_mut().load_font_source(fontdb::Source::File(requested_path));
+    db.registered_font_ids.extend(ids.iter().copied());
+    Ok(ids.to_vec())
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -222,6 +284,76 @@ pub fn register_font_from_path(_path: &std::path::Path) -> Result<(), Box<dyn st
     .into());
 }
 
+/// Removes the registered faces of `family`, except the ones in `keep`, and returns whether any
+/// face was removed.
+fn remove_registered_faces(db: &mut FontDatabase, family: &str, keep: &[fontdb::ID]) -> bool {
+    let ids = db
+        .faces()
+        .filter(|face_info| {
+            db.registered_font_ids.contains(&face_info.id)
+                && !keep.contains(&face_info.id)
+                && face_info.families.iter().any(|(name, _)| name.eq_ignore_ascii_case(family))
+        })
+        .map(|face_info| face_info.id)
+        .collect::<Vec<_>>();
+    for id in &ids {
+        db.registered_font_ids.remove(id);
+        db.make_mut().remove_face(*id);
+    }
+    !ids.is_empty()
+}
+
+/// Removes the faces of the given family that were registered with [`register_font_from_memory`]
+/// or [`register_font_from_path`]. Fonts installed on the system are not affected.
+pub fn unregister_font(family: &str) -> Result<(), Box<dyn std::error::Error>> {
+    FONT_DB.with_borrow_mut(|db| {
+        if !remove_registered_faces(db, family, &[]) {
+            return Err(format!("No font of the family {family} was registered").into());
+        }
+        Ok(())
+    })
+}
+
+/// Registers the font in `data`, and then removes the other faces registered for `family`. If
+/// `data` doesn't contain a valid font, the faces of `family` are kept.
+pub fn replace_font_from_memory(
+    family: &str,
+    data: &'static [u8],
+) -> Result<(), Box<dyn std::error::Error>> {
+    FONT_DB.with_borrow_mut(|db| {
+        let ids = load_font_from_memory(db, data);
+        if ids.is_empty() {
+            return Err("The data doesn't contain a valid font".into());
+        }
+        remove_registered_faces(db, family, &ids);
+        Ok(())
+    })
+}
+
+/// Same as [`replace_font_from_memory`], with the font at `path`.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn replace_font_from_path(
+    family: &str,
+    path: &std::path::Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    FONT_DB.with_borrow_mut(|db| {
+        let ids = load_font_from_path(db, path)?;
+        if ids.is_empty() {
+            return Err(format!("{} doesn't contain a valid font", path.display()).into());
+        }
+        remove_registered_faces(db, family, &ids);
+        Ok(())
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn replace_font_from_path(
+    _family: &str,
+    path: &std::path::Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    register_font_from_path(path)
+}
+
 /// Font metrics in design space. Scale with desired pixel size and divided by units_per_em
 /// to obtain pixel metrics.
 #[derive(Clone)]
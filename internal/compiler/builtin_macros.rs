@@ -40,19 +40,10 @@ pub fn lower_macro(
                     has_error.get_or_insert((n.clone(), "Not enough arguments"));
                     0.
                 }
-                Some((Expression::NumberLiteral(val, Unit::None), _)) => val as f32,
-                // handle negative numbers
-                Some((Expression::UnaryOp { sub, op: '-' }, n)) => match *sub {
-                    Expression::NumberLiteral(val, Unit::None) => (-1.0 * val) as f32,
-                    _ => {
-                        has_error.get_or_insert((n, expected_argument_type_error));
-                        0.
-                    }
-                },
-                Some((_, n)) => {
+                Some((expr, n)) => number_literal(&expr).unwrap_or_else(|| {
                     has_error.get_or_insert((n, expected_argument_type_error));
                     0.
-                }
+                }),
             };
             let expr = Expression::EasingCurve(EasingCurve::CubicBezier(a(), a(), a(), a()));
             if let Some((_, n)) = sub_expr.next() {
@@ -64,11 +55,57 @@ pub fn lower_macro(
 
             expr
         }
+        BuiltinMacroFunction::Spring => spring_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Hsv => hsv_macro(n, sub_expr.collect(), diag),
     }
 }
 
+/// Returns the value of a number literal without unit, possibly negative
+fn number_literal(expr: &Expression) -> Option<f32> {
+    match expr {
+        Expression::NumberLiteral(val, Unit::None) => Some(*val as f32),
+        Expression::UnaryOp { sub, op: '-' } => number_literal(sub).map(|val| -val),
+        _ => None,
+    }
+}
+
+/// `spring(stiffness, damping)` or `spring(stiffness, damping, mass)`
+fn spring_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() < 2 {
+        diag.push_error("Not enough arguments".into(), &node);
+        return Expression::Invalid;
+    }
+    if let Some((_, n)) = args.get(3) {
+        diag.push_error("Too many arguments for spring".into(), n);
+        return Expression::Invalid;
+    }
+    let mut values = [0., 0., 1.];
+    for ((expr, n), value) in args.iter().zip(values.iter_mut()) {
+        match number_literal(expr) {
+            Some(v) => *value = v,
+            None => {
+                diag.push_error("Arguments to spring must be number literal".into(), n);
+                return Expression::Invalid;
+            }
+        }
+    }
+    let [stiffness, damping, mass] = values;
+    if stiffness <= 0. || mass <= 0. || damping < 0. {
+        diag.push_error(
+            "The stiffness and the mass of a spring must be positive, and its damping can't be negative"
+                .into(),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass))
+}
+
 fn min_max_macro(
     node: Option<NodeOrToken>,
     op: MinMaxOp,
@@ -46,9 +46,17 @@ pub enum BuiltinFunction {
     ClosePopupWindow,
     ShowPopupMenu,
     SetSelectionOffsets,
+    /// TextInput's add-underline(start, end, color, style)
+    AddTextUnderline,
     /// A function that belongs to an item (such as TextInput's select-all function).
     ItemMemberFunction(SmolStr),
+    /// Window's start-system-move()
+    StartSystemMove,
+    /// Window's start-system-resize(edge)
+    StartSystemResize,
     ItemFontMetrics,
+    /// Fonts.metrics(family, size)
+    FontMetrics,
     /// the "42".to_float()
     StringToFloat,
     /// the "42".is_float()
@@ -65,6 +73,7 @@ pub enum BuiltinFunction {
     Rgb,
     Hsv,
     ColorScheme,
+    AccentColor,
     SupportsNativeMenuBar,
     SetupNativeMenuBar,
     Use24HourFormat,
@@ -103,6 +112,8 @@ pub enum BuiltinMacroFunction {
     /// Add the right conversion operations so that the return type is the same as the argument type
     Abs,
     CubicBezier,
+    /// Transform `spring(stiffness, damping, mass)`, where the mass is optional, into an easing curve
+    Spring,
     /// The argument can be r,g,b,a or r,g,b and they can be percentages or integer.
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
@@ -163,8 +174,21 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
     ClosePopupWindow: (Type::ElementReference) -> Type::Void,
     ShowPopupMenu: (Type::ElementReference, Type::Model, typeregister::logical_point_type()) -> Type::Void,
     ItemMemberFunction(..): (Type::ElementReference) -> Type::Void,
+    StartSystemMove: (Type::ElementReference) -> Type::Void,
+    StartSystemResize: (
+        Type::ElementReference,
+        Type::Enumeration(typeregister::BUILTIN.with(|e| e.enums.WindowResizeEdge.clone()))
+    ) -> Type::Void,
     SetSelectionOffsets: (Type::ElementReference, Type::Int32, Type::Int32) -> Type::Void,
+    AddTextUnderline: (
+        Type::ElementReference,
+        Type::Int32,
+        Type::Int32,
+        Type::Color,
+        Type::Enumeration(typeregister::BUILTIN.with(|e| e.enums.TextUnderlineStyle.clone()))
+    ) -> Type::Void,
     ItemFontMetrics: (Type::ElementReference) -> typeregister::font_metrics_type(),
+    FontMetrics: (Type::String, Type::LogicalLength) -> typeregister::font_metrics_type(),
     StringToFloat: (Type::String) -> Type::Float32,
     StringIsFloat: (Type::String) -> Type::Bool,
     ImplicitLayoutInfo(..): (Type::ElementReference) -> typeregister::layout_info_type(),
@@ -213,6 +237,7 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
     ColorScheme: () -> Type::Enumeration(
         typeregister::BUILTIN.with(|e| e.enums.ColorScheme.clone()),
     ),
+    AccentColor: () -> Type::Color,
     SupportsNativeMenuBar: () -> Type::Bool,
     // entries, sub-menu, activate. But the types here are not accurate.
     SetupNativeMenuBar: (Type::Model, typeregister::noarg_callback_type(), typeregister::noarg_callback_type()) -> Type::Void,
@@ -249,6 +274,7 @@ fn is_const(&self) -> bool {
             BuiltinFunction::GetWindowDefaultFontSize => false,
             BuiltinFunction::AnimationTick => false,
             BuiltinFunction::ColorScheme => false,
+            BuiltinFunction::AccentColor => false,
             BuiltinFunction::SupportsNativeMenuBar => false,
             BuiltinFunction::SetupNativeMenuBar => false,
             BuiltinFunction::MonthDayCount => false,
@@ -278,9 +304,11 @@ fn is_const(&self) -> bool {
             BuiltinFunction::ShowPopupWindow
             | BuiltinFunction::ClosePopupWindow
             | BuiltinFunction::ShowPopupMenu => false,
-            BuiltinFunction::SetSelectionOffsets => false,
+            BuiltinFunction::SetSelectionOffsets | BuiltinFunction::AddTextUnderline => false,
             BuiltinFunction::ItemMemberFunction(..) => false,
+            BuiltinFunction::StartSystemMove | BuiltinFunction::StartSystemResize => false,
             BuiltinFunction::ItemFontMetrics => false, // depends also on Window's font properties
+            BuiltinFunction::FontMetrics => false,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
             BuiltinFunction::ColorRgbaStruct
             | BuiltinFunction::ColorHsvaStruct
@@ -320,6 +348,7 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::GetWindowDefaultFontSize => true,
             BuiltinFunction::AnimationTick => true,
             BuiltinFunction::ColorScheme => true,
+            BuiltinFunction::AccentColor => true,
             BuiltinFunction::SupportsNativeMenuBar => true,
             BuiltinFunction::SetupNativeMenuBar => false,
             BuiltinFunction::MonthDayCount => true,
@@ -349,9 +378,11 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::ShowPopupWindow
             | BuiltinFunction::ClosePopupWindow
             | BuiltinFunction::ShowPopupMenu => false,
-            BuiltinFunction::SetSelectionOffsets => false,
+            BuiltinFunction::SetSelectionOffsets | BuiltinFunction::AddTextUnderline => false,
             BuiltinFunction::ItemMemberFunction(..) => false,
+            BuiltinFunction::StartSystemMove | BuiltinFunction::StartSystemResize => false,
             BuiltinFunction::ItemFontMetrics => true,
+            BuiltinFunction::FontMetrics => true,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
             BuiltinFunction::ColorRgbaStruct
             | BuiltinFunction::ColorHsvaStruct
@@ -661,6 +692,13 @@ pub enum Expression {
         stops: Vec<(Expression, Expression)>,
     },
 
+    ConicGradient {
+        from_angle: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position,
+        /// as a fraction of a full turn
+        stops: Vec<(Expression, Expression)>,
+    },
+
     EnumerationValue(EnumerationValue),
 
     ReturnStatement(Option<Box<Expression>>),
@@ -802,6 +840,7 @@ pub fn ty(&self) -> Type {
             Expression::EasingCurve(_) => Type::Easing,
             Expression::LinearGradient { .. } => Type::Brush,
             Expression::RadialGradient { .. } => Type::Brush,
+            Expression::ConicGradient { .. } => Type::Brush,
             Expression::EnumerationValue(value) => Type::Enumeration(value.enumeration.clone()),
             // invalid because the expression is unreachable
             Expression::ReturnStatement(_) => Type::Invalid,
@@ -899,6 +938,13 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                     visitor(s);
                 }
             }
+            Expression::ConicGradient { from_angle, stops } => {
+                visitor(from_angle);
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
             Expression::EnumerationValue(_) => {}
             Expression::ReturnStatement(expr) => {
                 expr.as_deref().map(visitor);
@@ -1004,6 +1050,13 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                     visitor(s);
                 }
             }
+            Expression::ConicGradient { from_angle, stops } => {
+                visitor(from_angle);
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
             Expression::EnumerationValue(_) => {}
             Expression::ReturnStatement(expr) => {
                 expr.as_deref_mut().map(visitor);
@@ -1087,6 +1140,10 @@ pub fn is_constant(&self) -> bool {
             Expression::RadialGradient { stops } => {
                 stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
             }
+            Expression::ConicGradient { from_angle, stops } => {
+                from_angle.is_constant()
+                    && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
+            }
             Expression::EnumerationValue(_) => true,
             Expression::ReturnStatement(expr) => {
                 expr.as_ref().map_or(true, |expr| expr.is_constant())
@@ -1118,7 +1175,9 @@ pub fn maybe_convert_to(
         } else if ty.can_convert(&target_type) {
             let from = match (ty, &target_type) {
                 (Type::Brush, Type::Color) => match self {
-                    Expression::LinearGradient { .. } | Expression::RadialGradient { .. } => {
+                    Expression::LinearGradient { .. }
+                    | Expression::RadialGradient { .. }
+                    | Expression::ConicGradient { .. } => {
                         let message = format!("Narrowing conversion from {0} to {1}. This can lead to unexpected behavior because the {0} is a gradient", Type::Brush, Type::Color);
                         diag.push_warning(message, node);
                         self
@@ -1558,6 +1617,8 @@ pub enum EasingCurve {
     #[default]
     Linear,
     CubicBezier(f32, f32, f32, f32),
+    /// The stiffness, damping, and mass of the spring
+    Spring(f32, f32, f32),
     EaseInElastic,
     EaseOutElastic,
     EaseInOutElastic,
@@ -1711,6 +1772,17 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             }
             write!(f, ")")
         }
+        Expression::ConicGradient { from_angle, stops } => {
+            write!(f, "@conic-gradient(from ")?;
+            pretty_print(f, from_angle)?;
+            for (c, s) in stops {
+                write!(f, ", ")?;
+                pretty_print(f, c)?;
+                write!(f, "  ")?;
+                pretty_print(f, s)?;
+            }
+            write!(f, ")")
+        }
         Expression::EnumerationValue(e) => match e.enumeration.values.get(e.value) {
             Some(val) => write!(f, "{}.{}", e.enumeration.name, val),
             None => write!(f, "{}.{}", e.enumeration.name, e.value),
@@ -3332,6 +3332,10 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::CubicBezier, {}, {}, {}, {})",
             a, b, c, d
         ),
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => format!(
+            "slint::cbindgen_private::EasingCurve::spring({}, {}, {})",
+            stiffness, damping, mass
+        ),
         Expression::EasingCurve(EasingCurve::EaseInElastic) => "slint::cbindgen_private::EasingCurve::Tag::EaseInElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseOutElastic) => "slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseInOutElastic) => "slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic".into(),
@@ -3361,6 +3365,18 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 stops_it.join(", "), stops.len()
             )
         }
+        Expression::ConicGradient{from_angle, stops} => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let mut stops_it = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                format!("slint::private_api::GradientStop{{ {}, float({}), }}", color, position)
+            });
+            format!(
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::ConicGradientBrush({}, stops, {})); }}()",
+                stops_it.join(", "), from_angle, stops.len()
+            )
+        }
         Expression::EnumerationValue(value) => {
             let prefix = if value.enumeration.node.is_some() { "" } else {"slint::cbindgen_private::"};
             format!(
@@ -3599,6 +3615,9 @@ fn compile_builtin_function_call(
         BuiltinFunction::ColorScheme => {
             format!("{}.color_scheme()", access_window_field(ctx))
         }
+        BuiltinFunction::AccentColor => {
+            format!("{}.accent_color()", access_window_field(ctx))
+        }
         BuiltinFunction::SupportsNativeMenuBar => {
             format!("{}.supports_native_menu_bar()", access_window_field(ctx))
         }
@@ -3763,6 +3782,21 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to set-selection-offsets {:?}", arguments)
             }
         }
+        BuiltinFunction::AddTextUnderline => {
+            if let [llr::Expression::PropertyReference(pr), from, to, color, style] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let window = access_window_field(ctx);
+                let start = compile_expression(from, ctx);
+                let end = compile_expression(to, ctx);
+                let color = compile_expression(color, ctx);
+                let style = compile_expression(style, ctx);
+
+                format!("slint_textinput_add_underline(&{item}, &{window}.handle(), &{item_rc}, static_cast<int>({start}), static_cast<int>({end}), {color}, {style})")
+            } else {
+                panic!("internal error: invalid args to add-underline {:?}", arguments)
+            }
+        }
         BuiltinFunction::ItemMemberFunction(name) => {
             if let [llr::Expression::PropertyReference(pr)] = arguments {
                 let item = access_member(pr, ctx);
@@ -3781,6 +3815,13 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ItemMemberFunction {:?}", arguments)
             }
         }
+        BuiltinFunction::StartSystemMove => {
+            format!("{}.start_system_move()", access_window_field(ctx))
+        }
+        BuiltinFunction::StartSystemResize => {
+            let edge = compile_expression(&arguments[1], ctx);
+            format!("{}.start_system_resize({edge})", access_window_field(ctx))
+        }
         BuiltinFunction::ItemFontMetrics => {
             if let [llr::Expression::PropertyReference(pr)] = arguments {
                 let item_rc = access_item_rc(pr, ctx);
@@ -3790,6 +3831,14 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ItemFontMetrics {:?}", arguments)
             }
         }
+        BuiltinFunction::FontMetrics => {
+            format!(
+                "{}.font_metrics({}, {})",
+                access_window_field(ctx),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
         BuiltinFunction::ItemAbsolutePosition => {
             if let [llr::Expression::PropertyReference(pr)] = arguments {
                 let item_rc = access_item_rc(pr, ctx);
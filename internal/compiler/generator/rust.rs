@@ -270,6 +270,15 @@ fn generate_public_component(
         &llr.public_properties,
         &llr.private_properties,
         quote!(sp::VRc::as_pin_ref(&self.0)),
+        &PublicChangeHandlers {
+            key_prefix: String::new(),
+            globals: quote!(_self.globals.get().unwrap()),
+            self_weak: quote!(sp::VRc::downgrade(&self.0)),
+            upgrade: quote!(
+                let self_rc = self_weak.upgrade()?;
+                let _self = sp::VRc::as_pin_ref(&self_rc);
+            ),
+        },
         &ctx,
     );
 
@@ -374,6 +383,7 @@ struct SharedGlobals {
             #(#global_names : ::core::pin::Pin<sp::Rc<#global_types>>,)*
             window_adapter : sp::OnceCell<sp::WindowAdapterRc>,
             root_item_tree_weak : sp::VWeak<sp::ItemTreeVTable>,
+            change_handlers : slint::private_unstable_api::ChangeHandlers,
         }
         impl SharedGlobals {
             fn new(root_item_tree_weak : sp::VWeak<sp::ItemTreeVTable>) -> Self {
@@ -381,6 +391,7 @@ fn new(root_item_tree_weak : sp::VWeak<sp::ItemTreeVTable>) -> Self {
                     #(#global_names : #global_types::new(),)*
                     window_adapter : ::core::default::Default::default(),
                     root_item_tree_weak,
+                    change_handlers : ::core::default::Default::default(),
                 }
             }
 
@@ -556,11 +567,25 @@ fn handle_property_init(
     }
 }
 
+/// How the `on_<property>_changed` functions of the public API reach the `ChangeHandlers` of the
+/// `SharedGlobals`, and the component from a weak reference.
+struct PublicChangeHandlers {
+    /// Prefix of the key of the handlers, to distinguish the properties of globals
+    key_prefix: String,
+    /// Expression for the `SharedGlobals`, with `_self` in scope
+    globals: TokenStream,
+    /// Expression for the weak reference captured by the tracker
+    self_weak: TokenStream,
+    /// Statements that declare `_self` from `self_weak`, returning `None` if it's gone
+    upgrade: TokenStream,
+}
+
 /// Public API for Global and root component
 fn public_api(
     public_properties: &llr::PublicProperties,
     private_properties: &llr::PrivateProperties,
     self_init: TokenStream,
+    change_handlers: &PublicChangeHandlers,
     ctx: &EvaluationContext,
 ) -> TokenStream {
     let mut property_and_callback_accessors: Vec<TokenStream> = vec![];
@@ -595,6 +620,33 @@ pub fn #on_ident(&self, mut f: impl FnMut(#(#callback_args),*) -> #return_type +
                     )
                 }
             ));
+            // The handler returns immediately, so only callbacks without return value can have async handlers.
+            let on_async_ident = format_ident!("on_{}_async", prop_ident);
+            // Don't clash with the handler setter of a callback named `<callback_name>-async`
+            let async_name = format_ident!("{}_async", prop_ident);
+            let clashes = public_properties
+                .iter()
+                .any(|p| matches!(p.ty, Type::Callback(_)) && ident(&p.name) == async_name);
+            if matches!(callback.return_type, Type::Void) && !clashes {
+                let args_index =
+                    (0..callback_args.len()).map(proc_macro2::Literal::usize_unsuffixed);
+                property_and_callback_accessors.push(quote!(
+                    #[allow(dead_code)]
+                    #[cfg(target_has_atomic = "ptr")]
+                    pub fn #on_async_ident<F: core::future::Future<Output = ()> + 'static>(
+                        &self,
+                        mut f: impl FnMut(#(#callback_args),*) -> F + 'static,
+                    ) {
+                        let _self = #self_init;
+                        #[allow(unused)]
+                        #prop.set_handler(move |args| {
+                            slint::private_unstable_api::spawn_callback_future(
+                                f(#(args.#args_index.clone()),*),
+                            )
+                        })
+                    }
+                ));
+            }
         } else if let Type::Function(function) = &p.ty {
             let callback_args =
                 function.args.iter().map(|a| rust_primitive_type(a).unwrap()).collect::<Vec<_>>();
@@ -614,7 +666,8 @@ pub fn #caller_ident(&self, #(#args_name : #callback_args,)*) -> #return_type {
 
             let getter_ident = format_ident!("get_{}", prop_ident);
 
-            let prop_expression = primitive_property_value(&p.ty, MemberAccess::Direct(prop));
+            let prop_expression =
+                primitive_property_value(&p.ty, MemberAccess::Direct(prop.clone()));
 
             property_and_callback_accessors.push(quote!(
                 #[allow(dead_code)]
@@ -625,6 +678,34 @@ pub fn #getter_ident(&self) -> #rust_property_type {
                 }
             ));
 
+            // Don't clash with the handler setter of a callback named `<property_name>-changed`
+            let changed_name = format_ident!("{}_changed", prop_ident);
+            let clashes = public_properties
+                .iter()
+                .any(|p| matches!(p.ty, Type::Callback(_)) && ident(&p.name) == changed_name);
+            if !clashes {
+                let on_changed_ident = format_ident!("on_{}_changed", prop_ident);
+                let key = format!("{}{}", change_handlers.key_prefix, p.name);
+                let PublicChangeHandlers { globals, self_weak, upgrade, .. } = change_handlers;
+                let prop_expression = primitive_property_value(&p.ty, MemberAccess::Direct(prop));
+                property_and_callback_accessors.push(quote!(
+                    #[allow(dead_code)]
+                    pub fn #on_changed_ident(&self, mut f: impl FnMut(#rust_property_type) + 'static) {
+                        #[allow(unused_imports)]
+                        let _self = #self_init;
+                        #globals.change_handlers.set_handler(
+                            #key,
+                            #self_weak,
+                            move |self_weak| {
+                                #upgrade
+                                sp::Some(#prop_expression)
+                            },
+                            move |value: &#rust_property_type| f(value.clone()),
+                        )
+                    }
+                ));
+            }
+
             let setter_ident = format_ident!("set_{}", prop_ident);
             if !p.read_only {
                 let set_value = property_set_value_tokens(&p.prop, quote!(value), ctx);
@@ -636,6 +717,33 @@ pub fn #setter_ident(&self, value: #rust_property_type) {
                         #set_value
                     }
                 ));
+                let animatable = matches!(
+                    p.ty,
+                    Type::Float32
+                        | Type::Int32
+                        | Type::Color
+                        | Type::PhysicalLength
+                        | Type::LogicalLength
+                        | Type::Brush
+                        | Type::Angle
+                );
+                if animatable {
+                    let animate_ident = format_ident!("animate_{}", prop_ident);
+                    let value = set_primitive_property_value(&p.ty, quote!(value));
+                    let prop = access_member(&p.prop, ctx).unwrap();
+                    property_and_callback_accessors.push(quote!(
+                        #[allow(dead_code)]
+                        pub fn #animate_ident(
+                            &self,
+                            value: #rust_property_type,
+                            animation: slint::Animation,
+                        ) -> slint::AnimationHandle {
+                            #[allow(unused_imports)]
+                            let _self = #self_init;
+                            #prop.animate_value(#value as _, animation.into())
+                        }
+                    ));
+                }
             } else {
                 property_and_callback_accessors.push(quote!(
                     #[allow(dead_code)] fn #setter_ident(&self, _read_only_property : ()) { }
@@ -1427,6 +1535,15 @@ fn generate_global(global: &llr::GlobalComponent, root: &llr::CompilationUnit) -
             &global.public_properties,
             &global.private_properties,
             quote!(self.0.as_ref()),
+            &PublicChangeHandlers {
+                key_prefix: format!("{}::", global.name),
+                globals: quote!(_self.globals.get().unwrap().upgrade().unwrap()),
+                self_weak: quote!(_self.globals.get().unwrap().clone()),
+                upgrade: quote!(
+                    let self_rc = self_weak.upgrade()?.#global_id.clone();
+                    let _self = self_rc.as_ref();
+                ),
+            },
             &ctx,
         );
         let aliases = global.aliases.iter().map(|name| ident(name));
@@ -2509,6 +2626,9 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         Expression::EasingCurve(EasingCurve::CubicBezier(a, b, c, d)) => {
             quote!(sp::EasingCurve::CubicBezier([#a, #b, #c, #d]))
         }
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => {
+            quote!(sp::EasingCurve::Spring([#stiffness, #damping, #mass]))
+        }
         Expression::EasingCurve(EasingCurve::EaseInElastic) => {
             quote!(sp::EasingCurve::EaseInElastic)
         }
@@ -2548,6 +2668,17 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 sp::RadialGradientBrush::new_circle([#(#stops),*])
             ))
         }
+        Expression::ConicGradient { from_angle, stops } => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let stops = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                quote!(sp::GradientStop{ color: #color, position: #position as _ })
+            });
+            quote!(slint::Brush::ConicGradient(
+                sp::ConicGradientBrush::new(#from_angle as _, [#(#stops),*])
+            ))
+        }
         Expression::EnumerationValue(value) => {
             let base_ident = ident(&value.enumeration.name);
             let value_ident = ident(&value.to_pascal_case());
@@ -2816,6 +2947,23 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to set-selection-offsets {:?}", arguments)
             }
         }
+        BuiltinFunction::AddTextUnderline => {
+            if let [llr::Expression::PropertyReference(pr), from, to, color, style] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                let start = compile_expression(from, ctx);
+                let end = compile_expression(to, ctx);
+                let color = compile_expression(color, ctx);
+                let style = compile_expression(style, ctx);
+
+                item.then(|item| quote!(
+                    #item.add_underline(#window_adapter_tokens, #item_rc, #start as i32, #end as i32, #color, #style)
+                ))
+            } else {
+                panic!("internal error: invalid args to add-underline {:?}", arguments)
+            }
+        }
         BuiltinFunction::ItemMemberFunction(name) => {
             if let [Expression::PropertyReference(pr)] = arguments {
                 let item = access_member(pr, ctx);
@@ -2831,6 +2979,15 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ItemMemberFunction {:?}", arguments)
             }
         }
+        BuiltinFunction::StartSystemMove => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).start_system_move())
+        }
+        BuiltinFunction::StartSystemResize => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            let edge = compile_expression(&arguments[1], ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).start_system_resize(#edge))
+        }
         BuiltinFunction::ItemFontMetrics => {
             if let [Expression::PropertyReference(pr)] = arguments {
                 let item = access_member(pr, ctx);
@@ -2844,6 +3001,12 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ItemMemberFunction {:?}", arguments)
             }
         }
+        BuiltinFunction::FontMetrics => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            let (family, size) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window())
+                .font_metrics(#family, sp::LogicalLength::new(#size as sp::Coord)))
+        }
         BuiltinFunction::ImplicitLayoutInfo(orient) => {
             if let [Expression::PropertyReference(pr)] = arguments {
                 let item = access_member(pr, ctx);
@@ -2991,6 +3154,10 @@ fn compile_builtin_function_call(
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).color_scheme())
         }
+        BuiltinFunction::AccentColor => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).accent_color())
+        }
         BuiltinFunction::SupportsNativeMenuBar => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).supports_native_menu_bar())
@@ -3007,7 +3174,7 @@ fn compile_builtin_function_call(
             let activated = access_member(activated, ctx).unwrap();
             let inner_component_id = self::inner_component_id(ctx.current_sub_component.unwrap());
             quote! {
-                if sp::WindowInner::from_pub(#window_adapter_tokens.window()).supports_native_menu_bar() {
+                {
                     // May seem overkill to have an instance of the struct for each call, but there should only be one call per component anyway
                     struct MenuBarWrapper(sp::VWeakMapped<sp::ItemTreeVTable, #inner_component_id>);
                     const _ : () = {
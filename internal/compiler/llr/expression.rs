@@ -152,6 +152,13 @@ pub enum Expression {
         stops: Vec<(Expression, Expression)>,
     },
 
+    ConicGradient {
+        from_angle: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position,
+        /// as a fraction of a full turn
+        stops: Vec<(Expression, Expression)>,
+    },
+
     EnumerationValue(crate::langtype::EnumerationValue),
 
     LayoutCacheAccess {
@@ -304,6 +311,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::EasingCurve(_) => Type::Easing,
             Self::LinearGradient { .. } => Type::Brush,
             Self::RadialGradient { .. } => Type::Brush,
+            Self::ConicGradient { .. } => Type::Brush,
             Self::EnumerationValue(e) => Type::Enumeration(e.enumeration.clone()),
             Self::LayoutCacheAccess { .. } => Type::LogicalLength,
             Self::BoxLayoutFunction { sub_expression, .. } => sub_expression.ty(ctx),
@@ -376,6 +384,13 @@ macro_rules! visit_impl {
                     $visitor(b);
                 }
             }
+            Expression::ConicGradient { from_angle, stops } => {
+                $visitor(from_angle);
+                for (a, b) in stops {
+                    $visitor(a);
+                    $visitor(b);
+                }
+            }
             Expression::EnumerationValue(_) => {}
             Expression::LayoutCacheAccess { repeater_index, .. } => {
                 if let Some(repeater_index) = repeater_index {
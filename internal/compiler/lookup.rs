@@ -99,6 +99,7 @@ pub enum LookupResult {
 pub enum BuiltinNamespace {
     Colors,
     Math,
+    Fonts,
     Key,
     SlintInternal,
 }
@@ -162,6 +163,9 @@ fn for_each_entry<R>(
                 (ColorSpecific, ColorFunctions).for_each_entry(ctx, f)
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.for_each_entry(ctx, f),
+            LookupResult::Namespace(BuiltinNamespace::Fonts) => {
+                FontFunctions.for_each_entry(ctx, f)
+            }
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.for_each_entry(ctx, f),
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.for_each_entry(ctx, f)
@@ -177,6 +181,7 @@ fn lookup(&self, ctx: &LookupCtx, name: &SmolStr) -> Option<LookupResult> {
                 (ColorSpecific, ColorFunctions).lookup(ctx, name)
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.lookup(ctx, name),
+            LookupResult::Namespace(BuiltinNamespace::Fonts) => FontFunctions.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.lookup(ctx, name)
@@ -607,6 +612,51 @@ fn for_each_entry<R>(
 
 i_slint_common::for_each_special_keys!(special_keys_lookup);
 
+/// The easing curves that can be referred to by name, as in `easing.ease-in-out`
+const NAMED_EASING_CURVES: &[(&str, EasingCurve)] = {
+    use EasingCurve::CubicBezier;
+    &[
+        ("linear", EasingCurve::Linear),
+        ("ease-in-quad", CubicBezier(0.11, 0.0, 0.5, 0.0)),
+        ("ease-out-quad", CubicBezier(0.5, 1.0, 0.89, 1.0)),
+        ("ease-in-out-quad", CubicBezier(0.45, 0.0, 0.55, 1.0)),
+        ("ease", CubicBezier(0.25, 0.1, 0.25, 1.0)),
+        ("ease-in", CubicBezier(0.42, 0.0, 1.0, 1.0)),
+        ("ease-in-out", CubicBezier(0.42, 0.0, 0.58, 1.0)),
+        ("ease-out", CubicBezier(0.0, 0.0, 0.58, 1.0)),
+        ("ease-in-quart", CubicBezier(0.5, 0.0, 0.75, 0.0)),
+        ("ease-out-quart", CubicBezier(0.25, 1.0, 0.5, 1.0)),
+        ("ease-in-out-quart", CubicBezier(0.76, 0.0, 0.24, 1.0)),
+        ("ease-in-quint", CubicBezier(0.64, 0.0, 0.78, 0.0)),
+        ("ease-out-quint", CubicBezier(0.22, 1.0, 0.36, 1.0)),
+        ("ease-in-out-quint", CubicBezier(0.83, 0.0, 0.17, 1.0)),
+        ("ease-in-expo", CubicBezier(0.7, 0.0, 0.84, 0.0)),
+        ("ease-out-expo", CubicBezier(0.16, 1.0, 0.3, 1.0)),
+        ("ease-in-out-expo", CubicBezier(0.87, 0.0, 0.13, 1.0)),
+        ("ease-in-back", CubicBezier(0.36, 0.0, 0.66, -0.56)),
+        ("ease-out-back", CubicBezier(0.34, 1.56, 0.64, 1.0)),
+        ("ease-in-out-back", CubicBezier(0.68, -0.6, 0.32, 1.6)),
+        ("ease-in-sine", CubicBezier(0.12, 0.0, 0.39, 0.0)),
+        ("ease-out-sine", CubicBezier(0.61, 1.0, 0.88, 1.0)),
+        ("ease-in-out-sine", CubicBezier(0.37, 0.0, 0.63, 1.0)),
+        ("ease-in-circ", CubicBezier(0.55, 0.0, 1.0, 0.45)),
+        ("ease-out-circ", CubicBezier(0.0, 0.55, 0.45, 1.0)),
+        ("ease-in-out-circ", CubicBezier(0.85, 0.0, 0.15, 1.0)),
+        ("ease-in-elastic", EasingCurve::EaseInElastic),
+        ("ease-out-elastic", EasingCurve::EaseOutElastic),
+        ("ease-in-out-elastic", EasingCurve::EaseInOutElastic),
+        ("ease-in-bounce", EasingCurve::EaseInBounce),
+        ("ease-out-bounce", EasingCurve::EaseOutBounce),
+        ("ease-in-out-bounce", EasingCurve::EaseInOutBounce),
+    ]
+};
+
+/// Returns the easing curve with the given name, such as `ease-in-out`. The `cubic-bezier()`
+/// and `spring()` functions are not included.
+pub fn easing_curve_from_name(name: &str) -> Option<EasingCurve> {
+    NAMED_EASING_CURVES.iter().find(|(n, _)| *n == name).map(|(_, curve)| curve.clone())
+}
+
 struct EasingSpecific;
 impl LookupObject for EasingSpecific {
     fn for_each_entry<R>(
@@ -614,78 +664,10 @@ fn for_each_entry<R>(
         ctx: &LookupCtx,
         f: &mut impl FnMut(&SmolStr, LookupResult) -> Option<R>,
     ) -> Option<R> {
-        use EasingCurve::CubicBezier;
         let mut f = |n, e: Expression| f(&SmolStr::new_static(n), e.into());
-        None.or_else(|| f("linear", Expression::EasingCurve(EasingCurve::Linear)))
-            .or_else(|| {
-                f("ease-in-quad", Expression::EasingCurve(CubicBezier(0.11, 0.0, 0.5, 0.0)))
-            })
-            .or_else(|| {
-                f("ease-out-quad", Expression::EasingCurve(CubicBezier(0.5, 1.0, 0.89, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-out-quad", Expression::EasingCurve(CubicBezier(0.45, 0.0, 0.55, 1.0)))
-            })
-            .or_else(|| f("ease", Expression::EasingCurve(CubicBezier(0.25, 0.1, 0.25, 1.0))))
-            .or_else(|| f("ease-in", Expression::EasingCurve(CubicBezier(0.42, 0.0, 1.0, 1.0))))
-            .or_else(|| {
-                f("ease-in-out", Expression::EasingCurve(CubicBezier(0.42, 0.0, 0.58, 1.0)))
-            })
-            .or_else(|| f("ease-out", Expression::EasingCurve(CubicBezier(0.0, 0.0, 0.58, 1.0))))
-            .or_else(|| {
-                f("ease-in-quart", Expression::EasingCurve(CubicBezier(0.5, 0.0, 0.75, 0.0)))
-            })
-            .or_else(|| {
-                f("ease-out-quart", Expression::EasingCurve(CubicBezier(0.25, 1.0, 0.5, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-out-quart", Expression::EasingCurve(CubicBezier(0.76, 0.0, 0.24, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-quint", Expression::EasingCurve(CubicBezier(0.64, 0.0, 0.78, 0.0)))
-            })
-            .or_else(|| {
-                f("ease-out-quint", Expression::EasingCurve(CubicBezier(0.22, 1.0, 0.36, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-out-quint", Expression::EasingCurve(CubicBezier(0.83, 0.0, 0.17, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-expo", Expression::EasingCurve(CubicBezier(0.7, 0.0, 0.84, 0.0)))
-            })
-            .or_else(|| {
-                f("ease-out-expo", Expression::EasingCurve(CubicBezier(0.16, 1.0, 0.3, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-out-expo", Expression::EasingCurve(CubicBezier(0.87, 0.0, 0.13, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-back", Expression::EasingCurve(CubicBezier(0.36, 0.0, 0.66, -0.56)))
-            })
-            .or_else(|| {
-                f("ease-out-back", Expression::EasingCurve(CubicBezier(0.34, 1.56, 0.64, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-out-back", Expression::EasingCurve(CubicBezier(0.68, -0.6, 0.32, 1.6)))
-            })
-            .or_else(|| {
-                f("ease-in-sine", Expression::EasingCurve(CubicBezier(0.12, 0.0, 0.39, 0.0)))
-            })
-            .or_else(|| {
-                f("ease-out-sine", Expression::EasingCurve(CubicBezier(0.61, 1.0, 0.88, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-out-sine", Expression::EasingCurve(CubicBezier(0.37, 0.0, 0.63, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-circ", Expression::EasingCurve(CubicBezier(0.55, 0.0, 1.0, 0.45)))
-            })
-            .or_else(|| {
-                f("ease-out-circ", Expression::EasingCurve(CubicBezier(0.0, 0.55, 0.45, 1.0)))
-            })
-            .or_else(|| {
-                f("ease-in-out-circ", Expression::EasingCurve(CubicBezier(0.85, 0.0, 0.15, 1.0)))
-            })
+        NAMED_EASING_CURVES
+            .iter()
+            .find_map(|(name, curve)| f(*name, Expression::EasingCurve(curve.clone())))
             .or_else(|| {
                 f(
                     "cubic-bezier",
@@ -695,15 +677,14 @@ fn for_each_entry<R>(
                     ),
                 )
             })
-            .or_else(|| f("ease-in-elastic", Expression::EasingCurve(EasingCurve::EaseInElastic)))
-            .or_else(|| f("ease-out-elastic", Expression::EasingCurve(EasingCurve::EaseOutElastic)))
             .or_else(|| {
-                f("ease-in-out-elastic", Expression::EasingCurve(EasingCurve::EaseInOutElastic))
-            })
-            .or_else(|| f("ease-in-bounce", Expression::EasingCurve(EasingCurve::EaseInBounce)))
-            .or_else(|| f("ease-out-bounce", Expression::EasingCurve(EasingCurve::EaseOutBounce)))
-            .or_else(|| {
-                f("ease-in-out-bounce", Expression::EasingCurve(EasingCurve::EaseInOutBounce))
+                f(
+                    "spring",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Spring,
+                        ctx.current_token.clone(),
+                    ),
+                )
             })
     }
 }
@@ -759,6 +740,40 @@ fn for_each_entry<R>(
     }
 }
 
+struct FontFunctions;
+impl LookupObject for FontFunctions {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&SmolStr, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let sl = || ctx.current_token.as_ref().map(|t| t.to_source_location());
+        f(
+            &SmolStr::new_static("metrics"),
+            Expression::BuiltinFunctionReference(BuiltinFunction::FontMetrics, sl()).into(),
+        )
+    }
+}
+
+/// Returns the expression for `SlintInternal.color-scheme`: the color scheme of the system, unless
+/// the style is a `-light` or `-dark` variant.
+pub fn color_scheme_expression(ctx: &LookupCtx) -> Expression {
+    let style = ctx.type_loader.and_then(|tl| tl.compiler_config.style.as_ref());
+    let e = crate::typeregister::BUILTIN.with(|e| e.enums.ColorScheme.clone());
+    if style.is_some_and(|s| s.ends_with("-light")) {
+        Expression::EnumerationValue(e.try_value_from_string("light").unwrap())
+    } else if style.is_some_and(|s| s.ends_with("-dark")) {
+        Expression::EnumerationValue(e.try_value_from_string("dark").unwrap())
+    } else {
+        Expression::FunctionCall {
+            function: Expression::BuiltinFunctionReference(BuiltinFunction::ColorScheme, None)
+                .into(),
+            arguments: vec![],
+            source_location: ctx.current_token.as_ref().map(|t| t.to_source_location()),
+        }
+    }
+}
+
 struct SlintInternal;
 impl LookupObject for SlintInternal {
     fn for_each_entry<R>(
@@ -770,21 +785,16 @@ fn for_each_entry<R>(
         let sl = || ctx.current_token.as_ref().map(|t| t.to_source_location());
         let mut f = |n, e: Expression| f(&SmolStr::new_static(n), e.into());
         None.or_else(|| {
-            let style = ctx.type_loader.and_then(|tl| tl.compiler_config.style.as_ref());
+            let color_scheme = color_scheme_expression(ctx);
+            f("color-scheme", color_scheme)
+        })
+        .or_else(|| {
             f(
-                "color-scheme",
-                if style.is_some_and(|s| s.ends_with("-light")) {
-                    let e = crate::typeregister::BUILTIN.with(|e| e.enums.ColorScheme.clone());
-                    Expression::EnumerationValue(e.try_value_from_string("light").unwrap())
-                } else if style.is_some_and(|s| s.ends_with("-dark")) {
-                    let e = crate::typeregister::BUILTIN.with(|e| e.enums.ColorScheme.clone());
-                    Expression::EnumerationValue(e.try_value_from_string("dark").unwrap())
-                } else {
-                    Expression::FunctionCall {
-                        function: BFR(BuiltinFunction::ColorScheme, None).into(),
-                        arguments: vec![],
-                        source_location: sl(),
-                    }
+                "accent-color",
+                Expression::FunctionCall {
+                    function: BFR(BuiltinFunction::AccentColor, None).into(),
+                    arguments: vec![],
+                    source_location: sl(),
                 },
             )
         })
@@ -865,6 +875,7 @@ fn for_each_entry<R>(
         let mut f = |s, res| f(&SmolStr::new_static(s), res);
         None.or_else(|| f("Colors", LookupResult::Namespace(BuiltinNamespace::Colors)))
             .or_else(|| f("Math", LookupResult::Namespace(BuiltinNamespace::Math)))
+            .or_else(|| f("Fonts", LookupResult::Namespace(BuiltinNamespace::Fonts)))
             .or_else(|| f("Key", LookupResult::Namespace(BuiltinNamespace::Key)))
             .or_else(|| {
                 if ctx.type_register.expose_internal_types {
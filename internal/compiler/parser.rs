@@ -378,7 +378,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         StringTemplate -> [*Expression],
         /// `@image-url("foo.png")`
         AtImageUrl -> [],
-        /// `@linear-gradient(...)` or `@radial-gradient(...)`
+        /// `@linear-gradient(...)`, `@radial-gradient(...)` or `@conic-gradient(...)`
         AtGradient -> [*Expression],
         /// `@tr("foo", ...)`  // the string is a StringLiteral
         AtTr -> [?TrContext, ?TrPlural, *Expression],
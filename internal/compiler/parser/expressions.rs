@@ -232,13 +232,16 @@ fn parse_at_keyword(p: &mut impl Parser) {
         "radial-gradient" | "radial_gradient" => {
             parse_gradient(p);
         }
+        "conic-gradient" | "conic_gradient" => {
+            parse_gradient(p);
+        }
         "tr" => {
             parse_tr(p);
         }
         _ => {
             p.consume();
             p.test(SyntaxKind::Identifier); // consume the identifier, so that autocomplete works
-            p.error("Expected 'image-url', 'tr', 'linear-gradient' or 'radial-gradient' after '@'");
+            p.error("Expected 'image-url', 'tr', 'linear-gradient', 'radial-gradient' or 'conic-gradient' after '@'");
         }
     }
 }
@@ -340,6 +343,8 @@ fn parse_template_string(p: &mut impl Parser) {
 /// @linear-gradient(217deg, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
 /// @linear_gradient(217deg, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
 /// @radial-gradient(circle, #e66465, blue 50%, #9198e5)
+/// @conic-gradient(red, blue 120deg, red)
+/// @conic-gradient(from 90deg, red 0deg, blue 50%, red 1turn)
 /// ```
 fn parse_gradient(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::AtGradient);
@@ -420,6 +425,8 @@ fn consume_literal(p: &mut impl Parser) -> bool {
 /// @image-url("foo.png",)
 /// @image-url("foo.png", nine-slice(1 2 3 4))
 /// @image-url("foo.png", nine-slice(1))
+/// @image-url("foo.png", dark = "foo-dark.png")
+/// @image-url("foo.png", dark = "foo-dark.png", nine-slice(1 2))
 /// ```
 fn parse_image_url(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::AtImageUrl);
@@ -428,37 +435,69 @@ fn parse_image_url(p: &mut impl Parser) {
     if !(p.expect(SyntaxKind::LParent)) {
         return;
     }
+    if !parse_image_url_path(&mut *p) {
+        return;
+    }
+    loop {
+        if !p.test(SyntaxKind::Comma) {
+            if !p.test(SyntaxKind::RParent) {
+                p.error("Expected ')' or ','");
+                p.until(SyntaxKind::RParent);
+            }
+            return;
+        }
+        if p.test(SyntaxKind::RParent) {
+            return;
+        }
+        match p.peek().as_str() {
+            "nine-slice" => {
+                if !parse_nine_slice(&mut *p) {
+                    return;
+                }
+            }
+            "dark" => {
+                p.consume();
+                if !p.expect(SyntaxKind::Equal) {
+                    p.until(SyntaxKind::RParent);
+                    return;
+                }
+                if !parse_image_url_path(&mut *p) {
+                    return;
+                }
+            }
+            _ => {
+                p.error("Expected 'nine-slice(...)' or 'dark = \"...\"' argument");
+                p.until(SyntaxKind::RParent);
+                return;
+            }
+        }
+    }
+}
+
+/// Parses the string literal of a path in `@image-url`. Returns false and skips the rest of
+/// the `@image-url` on error.
+fn parse_image_url_path(p: &mut impl Parser) -> bool {
     let peek = p.peek();
     if peek.kind() != SyntaxKind::StringLiteral {
         p.error("@image-url must contain a plain path as a string literal");
         p.until(SyntaxKind::RParent);
-        return;
+        return false;
     }
     if !peek.as_str().starts_with('"') || !peek.as_str().ends_with('"') {
         p.error("@image-url must contain a plain path as a string literal, without any '\\{}' expressions");
         p.until(SyntaxKind::RParent);
-        return;
-    }
-    p.expect(SyntaxKind::StringLiteral);
-    if !p.test(SyntaxKind::Comma) {
-        if !p.test(SyntaxKind::RParent) {
-            p.error("Expected ')' or ','");
-            p.until(SyntaxKind::RParent);
-        }
-        return;
-    }
-    if p.test(SyntaxKind::RParent) {
-        return;
-    }
-    if p.peek().as_str() != "nine-slice" {
-        p.error("Expected 'nine-slice(...)' argument");
-        p.until(SyntaxKind::RParent);
-        return;
+        return false;
     }
-    p.consume();
+    p.expect(SyntaxKind::StringLiteral)
+}
+
+/// Parses the `nine-slice(...)` argument of `@image-url`. Returns false if the rest of the
+/// `@image-url` was skipped because of an error.
+fn parse_nine_slice(p: &mut impl Parser) -> bool {
+    p.consume(); // "nine-slice"
     if !p.expect(SyntaxKind::LParent) {
         p.until(SyntaxKind::RParent);
-        return;
+        return false;
     }
     let mut count = 0;
     loop {
@@ -468,7 +507,7 @@ fn parse_image_url(p: &mut impl Parser) {
                     p.error("Expected 1 or 2 or 4 numbers");
                 }
                 p.consume();
-                break;
+                return true;
             }
             SyntaxKind::NumberLiteral => {
                 count += 1;
@@ -477,16 +516,13 @@ fn parse_image_url(p: &mut impl Parser) {
             SyntaxKind::Comma | SyntaxKind::Colon => {
                 p.error("Arguments of nine-slice need to be separated by spaces");
                 p.until(SyntaxKind::RParent);
-                break;
+                return true;
             }
             _ => {
                 p.error("Expected number literal or ')'");
                 p.until(SyntaxKind::RParent);
-                break;
+                return true;
             }
         }
     }
-    if !p.expect(SyntaxKind::RParent) {
-        p.until(SyntaxKind::RParent);
-    }
 }
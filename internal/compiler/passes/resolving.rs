@@ -359,46 +359,19 @@ fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) ->
     }
 
     fn from_at_image_url_node(node: syntax_nodes::AtImageUrl, ctx: &mut LookupCtx) -> Self {
-        let s = match node
-            .child_text(SyntaxKind::StringLiteral)
-            .and_then(|x| crate::literals::unescape_string(&x))
-        {
-            Some(s) => s,
-            None => {
-                ctx.diag.push_error("Cannot parse string literal".into(), &node);
-                return Self::Invalid;
+        // The first string literal is the path, and the one after `dark =` the dark variant
+        let mut path = None;
+        let mut dark_path = None;
+        let mut after_dark = false;
+        for token in node.children_with_tokens().filter_map(|n| n.into_token()) {
+            match token.kind() {
+                SyntaxKind::Identifier => after_dark = token.text() == "dark",
+                SyntaxKind::StringLiteral if after_dark => dark_path = Some(token),
+                SyntaxKind::StringLiteral if path.is_none() => path = Some(token),
+                _ => {}
             }
-        };
-
-        if s.is_empty() {
-            return Expression::ImageReference {
-                resource_ref: ImageReference::None,
-                source_location: Some(node.to_source_location()),
-                nine_slice: None,
-            };
         }
 
-        let absolute_source_path = {
-            let path = std::path::Path::new(&s);
-            if crate::pathutils::is_absolute(path) {
-                s
-            } else {
-                ctx.type_loader
-                    .and_then(|loader| {
-                        loader.resolve_import_path(Some(&(*node).clone().into()), &s)
-                    })
-                    .map(|i| i.0.to_string_lossy().into())
-                    .unwrap_or_else(|| {
-                        crate::pathutils::join(
-                            &crate::pathutils::dirname(node.source_file.path()),
-                            path,
-                        )
-                        .map(|p| p.to_string_lossy().into())
-                        .unwrap_or(s.clone())
-                    })
-            }
-        };
-
         let nine_slice = node
             .children_with_tokens()
             .filter_map(|n| n.into_token())
@@ -431,10 +404,64 @@ fn from_at_image_url_node(node: syntax_nodes::AtImageUrl, ctx: &mut LookupCtx) -
             }
         };
 
-        Expression::ImageReference {
-            resource_ref: ImageReference::AbsolutePath(absolute_source_path),
-            source_location: Some(node.to_source_location()),
-            nine_slice,
+        let mut image_reference = |path: Option<crate::parser::SyntaxToken>| {
+            let Some(s) = path.and_then(|x| crate::literals::unescape_string(x.text())) else {
+                ctx.diag.push_error("Cannot parse string literal".into(), &node);
+                return Self::Invalid;
+            };
+
+            if s.is_empty() {
+                return Expression::ImageReference {
+                    resource_ref: ImageReference::None,
+                    source_location: Some(node.to_source_location()),
+                    nine_slice: None,
+                };
+            }
+
+            let absolute_source_path = {
+                let path = std::path::Path::new(&s);
+                if crate::pathutils::is_absolute(path) {
+                    s
+                } else {
+                    ctx.type_loader
+                        .and_then(|loader| {
+                            loader.resolve_import_path(Some(&(*node).clone().into()), &s)
+                        })
+                        .map(|i| i.0.to_string_lossy().into())
+                        .unwrap_or_else(|| {
+                            crate::pathutils::join(
+                                &crate::pathutils::dirname(node.source_file.path()),
+                                path,
+                            )
+                            .map(|p| p.to_string_lossy().into())
+                            .unwrap_or(s.clone())
+                        })
+                }
+            };
+
+            Expression::ImageReference {
+                resource_ref: ImageReference::AbsolutePath(absolute_source_path),
+                source_location: Some(node.to_source_location()),
+                nine_slice,
+            }
+        };
+
+        let image = image_reference(path);
+        if dark_path.is_none() {
+            return image;
+        }
+        let dark_image = image_reference(dark_path);
+        let color_scheme = crate::typeregister::BUILTIN.with(|e| e.enums.ColorScheme.clone());
+        Expression::Condition {
+            condition: Box::new(Expression::BinaryExpression {
+                lhs: Box::new(crate::lookup::color_scheme_expression(ctx)),
+                rhs: Box::new(Expression::EnumerationValue(
+                    color_scheme.try_value_from_string("dark").unwrap(),
+                )),
+                op: '=',
+            }),
+            true_expr: Box::new(dark_image),
+            false_expr: Box::new(image),
         }
     }
 
@@ -442,11 +469,13 @@ fn from_at_gradient(node: syntax_nodes::AtGradient, ctx: &mut LookupCtx) -> Self
         enum GradKind {
             Linear { angle: Box<Expression> },
             Radial,
+            Conic { from_angle: Box<Expression> },
         }
 
         let mut subs = node
             .children_with_tokens()
-            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression));
+            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression))
+            .peekable();
 
         let grad_token = node.child_token(SyntaxKind::Identifier).unwrap();
         let grad_text = grad_token.text();
@@ -495,8 +524,34 @@ enum GradKind {
                 return Expression::Invalid;
             }
             GradKind::Radial
+        } else if grad_text.starts_with("conic") {
+            let mut from_angle = Box::new(Expression::NumberLiteral(0., Unit::Deg));
+            if matches!(subs.peek(), Some(NodeOrToken::Node(n)) if n.text().to_string().trim() == "from")
+            {
+                subs.next();
+                let angle_expr = match subs.next() {
+                    Some(NodeOrToken::Node(n)) => syntax_nodes::Expression::from(n),
+                    _ => {
+                        ctx.diag.push_error("Expected angle expression after 'from'".into(), &node);
+                        return Expression::Invalid;
+                    }
+                };
+                if subs.next().map_or(false, |s| s.kind() != SyntaxKind::Comma) {
+                    ctx.diag
+                        .push_error("'from' angle must be followed by a comma".into(), &angle_expr);
+                    return Expression::Invalid;
+                }
+                from_angle = Box::new(
+                    Expression::from_expression_node(angle_expr.clone(), ctx).maybe_convert_to(
+                        Type::Angle,
+                        &angle_expr,
+                        ctx.diag,
+                    ),
+                );
+            }
+            GradKind::Conic { from_angle }
         } else {
-            // Parser should have ensured we have one of the linear or radial gradient
+            // Parser should have ensured we have one of the linear, radial or conic gradient
             panic!("Not a gradient {grad_text:?}");
         };
 
@@ -542,7 +597,18 @@ enum Stop {
                         break;
                     }
                     Stop::Color(col) => {
-                        stops.push((col, e.maybe_convert_to(Type::Float32, &n, ctx.diag)))
+                        let position = match grad_kind {
+                            // The position in a conic gradient is an angle, or a percentage of a turn
+                            GradKind::Conic { .. } if e.ty() != Type::Percent => {
+                                Expression::BinaryExpression {
+                                    lhs: Box::new(e.maybe_convert_to(Type::Angle, &n, ctx.diag)),
+                                    rhs: Box::new(Expression::NumberLiteral(360., Unit::Deg)),
+                                    op: '/',
+                                }
+                            }
+                            _ => e.maybe_convert_to(Type::Float32, &n, ctx.diag),
+                        };
+                        stops.push((col, position))
                     }
                 }
             }
@@ -602,6 +668,7 @@ enum Stop {
         match grad_kind {
             GradKind::Linear { angle } => Expression::LinearGradient { angle, stops },
             GradKind::Radial => Expression::RadialGradient { stops },
+            GradKind::Conic { from_angle } => Expression::ConicGradient { from_angle, stops },
         }
     }
 
@@ -482,6 +482,26 @@ struct $Name:ident {
             _ => unreachable!(),
         };
 
+        for window_type in ["Window", "Dialog"] {
+            match &mut register.elements.get_mut(window_type).unwrap() {
+                ElementType::Builtin(ref mut b) => {
+                    let window = Rc::get_mut(b).unwrap();
+                    for (name, function) in [
+                        ("start-system-move", BuiltinFunction::StartSystemMove),
+                        ("start-system-resize", BuiltinFunction::StartSystemResize),
+                    ] {
+                        window.properties.insert(
+                            name.into(),
+                            BuiltinPropertyInfo::new(Type::Function(function.ty())),
+                        );
+                        window.member_functions.insert(name.into(), function);
+                    }
+                }
+
+                _ => unreachable!(),
+            };
+        }
+
         match &mut register.elements.get_mut("ContextMenu").unwrap() {
             ElementType::Builtin(ref mut b) => {
                 let b = Rc::get_mut(b).unwrap();
@@ -526,6 +546,15 @@ struct $Name:ident {
                 text_input
                     .member_functions
                     .insert("set-selection-offsets".into(), BuiltinFunction::SetSelectionOffsets);
+                text_input.properties.insert(
+                    "add-underline".into(),
+                    BuiltinPropertyInfo::new(Type::Function(
+                        BuiltinFunction::AddTextUnderline.ty(),
+                    )),
+                );
+                text_input
+                    .member_functions
+                    .insert("add-underline".into(), BuiltinFunction::AddTextUnderline);
                 text_input.properties.insert("font-metrics".into(), font_metrics_prop.clone());
             }
 
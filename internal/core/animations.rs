@@ -6,7 +6,12 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
-use core::cell::Cell;
+use alloc::rc::{Rc, Weak};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::pin::Pin;
+use core::time::Duration;
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
@@ -143,12 +148,18 @@ pub fn solve_t_for_x(&self, x: S, t_range: core::ops::Range<S>, tolerance: S) ->
 /// The representation of an easing curve, for animations
 #[repr(C, u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[non_exhaustive]
 pub enum EasingCurve {
     /// The linear curve
     #[default]
     Linear,
     /// A Cubic bezier curve, with its 4 parameters
     CubicBezier([f32; 4]),
+    /// A physically-based spring, with its stiffness, damping, and mass.
+    ///
+    /// The spring starts at rest and is stretched over the duration of the animation. When the
+    /// animation has no duration, it lasts the time the spring takes to settle, see [`spring_duration()`].
+    Spring([f32; 3]),
     /// Easing curve as defined at: <https://easings.net/#easeInElastic>
     EaseInElastic,
     /// Easing curve as defined at: <https://easings.net/#easeOutElastic>
@@ -298,6 +309,261 @@ pub fn animation_tick() -> u64 {
     })
 }
 
+/// The parameters of a property animation started from Rust with the generated `animate_<property>()`
+/// functions. They're the same as the ones of the `animate` blocks of `.slint` files.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animation {
+    /// The time to wait before starting the animation
+    pub delay: Duration,
+    /// The time it takes for the animation to complete. Springs without a duration last until they come to rest.
+    pub duration: Duration,
+    /// The easing curve
+    pub easing: EasingCurve,
+    /// The number of times the animation runs, or a negative value to repeat it forever
+    pub iteration_count: f32,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self {
+            delay: Duration::ZERO,
+            duration: Duration::ZERO,
+            easing: EasingCurve::default(),
+            iteration_count: 1.,
+        }
+    }
+}
+
+impl From<Animation> for crate::items::PropertyAnimation {
+    fn from(animation: Animation) -> Self {
+        Self {
+            delay: animation.delay.as_millis() as _,
+            duration: animation.duration.as_millis() as _,
+            iteration_count: animation.iteration_count,
+            easing: animation.easing,
+            ..Default::default()
+        }
+    }
+}
+
+thread_local!(
+    /// The controls of the animations that were started, some of which may have finished since
+    static STARTED_ANIMATIONS: RefCell<Vec<Weak<AnimationControl>>> = RefCell::default()
+);
+
+thread_local!(
+    /// Set once [`AnimationHandle::running_animations()`] was called, so that all the property
+    /// animations started from then on get a control
+    static TRACK_ANIMATIONS: Cell<bool> = Cell::new(false)
+);
+
+/// Returns true if all the property animations need an [`AnimationControl`], and not only the
+/// ones for which a handle is requested
+pub(crate) fn tracking_animations() -> bool {
+    TRACK_ANIMATIONS.with(|track| track.get())
+}
+
+/// The state shared between a property animation and its [`AnimationHandle`]s
+pub(crate) struct AnimationControl {
+    /// The time the animation started, before its delay
+    start: Cell<Instant>,
+    /// How many milliseconds the time of the animation is behind the time of the animation driver
+    offset: Cell<i64>,
+    /// The time of the animation when it was paused
+    paused_at: Cell<Option<Instant>>,
+    /// Set when seeking, so that the animation is computed again from its start
+    restart: Cell<bool>,
+    /// The time of the animation and whether it finished, as of its last evaluation
+    current: Cell<Instant>,
+    finished: Cell<bool>,
+    total_duration: Cell<Option<Duration>>,
+    registered: Cell<bool>,
+    /// Changed when pausing, resuming, or seeking to evaluate the animation again
+    revision: Pin<Box<crate::Property<u32>>>,
+}
+
+impl AnimationControl {
+    pub(crate) fn new(start: Instant) -> Self {
+        Self {
+            start: Cell::new(start),
+            offset: Cell::new(0),
+            paused_at: Cell::new(None),
+            restart: Cell::new(false),
+            current: Cell::new(start),
+            finished: Cell::new(false),
+            total_duration: Cell::new(None),
+            registered: Cell::new(false),
+            revision: Box::pin(crate::Property::new(0)),
+        }
+    }
+
+    /// Called when the animation (re)starts at the given time
+    pub(crate) fn start(self: &Rc<Self>, start: Instant) {
+        self.start.set(start);
+        self.current.set(start);
+        self.offset.set(0);
+        self.paused_at.set(None);
+        self.restart.set(false);
+        self.finished.set(false);
+        if !self.registered.replace(true) {
+            STARTED_ANIMATIONS.with(|animations| {
+                let mut animations = animations.borrow_mut();
+                // Forget the finished animations from time to time
+                if animations.len() == animations.capacity() {
+                    animations.retain(|a| a.upgrade().is_some_and(|a| a.is_running()));
+                }
+                animations.push(Rc::downgrade(self));
+            });
+        }
+    }
+
+    /// Returns the time of the animation for the given time of the animation driver, and whether the animation
+    /// must be computed again from its start. Registers a dependency to the pause, resume, and seek operations.
+    pub(crate) fn animation_tick(&self, driver_tick: Instant) -> (Instant, bool) {
+        self.revision.as_ref().get();
+        (self.tick_at(driver_tick), self.restart.take())
+    }
+
+    fn tick_at(&self, driver_tick: Instant) -> Instant {
+        self.paused_at.get().unwrap_or_else(|| {
+            let tick = driver_tick.0 as i64 - self.offset.get();
+            Instant(tick.max(self.start.get().0 as i64) as u64)
+        })
+    }
+
+    /// Records the state of the animation after it was evaluated at `tick`
+    pub(crate) fn update(&self, tick: Instant, finished: bool, total_duration: Option<Duration>) {
+        self.current.set(tick);
+        self.finished.set(finished);
+        self.total_duration.set(total_duration);
+    }
+
+    pub(crate) fn start_time(&self) -> Instant {
+        self.start.get()
+    }
+
+    pub(crate) fn is_paused(&self) -> bool {
+        self.paused_at.get().is_some()
+    }
+
+    fn is_running(&self) -> bool {
+        !self.finished.get() || self.is_paused()
+    }
+
+    fn driver_tick() -> Instant {
+        CURRENT_ANIMATION_DRIVER.with(|driver| driver.global_instant.as_ref().get_untracked())
+    }
+
+    fn changed(&self) {
+        self.revision.as_ref().set(self.revision.as_ref().get_untracked().wrapping_add(1));
+    }
+}
+
+/// A handle to a property animation, to pause, resume, or seek it, and to query its progress.
+///
+/// Use [`Self::running_animations()`] to get the animations that are running, for example from a debugging
+/// tool, or the handle returned by the generated `animate_<property>()` functions of the components.
+///
+/// A finished animation can't be controlled anymore, except while it's paused.
+#[derive(Clone)]
+pub struct AnimationHandle(Rc<AnimationControl>);
+
+impl AnimationHandle {
+    pub(crate) fn new(control: Rc<AnimationControl>) -> Self {
+        Self(control)
+    }
+
+    /// Returns the property animations of the current thread that are running or paused.
+    ///
+    /// Keeping track of the animations has a cost, so the animations of the `animate` blocks are
+    /// only tracked once this function was called: call it a first time when the debugging tool
+    /// starts, and it returns the animations started since then, as well as the ones started with
+    /// the generated `animate_<property>()` functions.
+    pub fn running_animations() -> Vec<AnimationHandle> {
+        TRACK_ANIMATIONS.with(|track| track.set(true));
+        STARTED_ANIMATIONS.with(|animations| {
+            let mut animations = animations.borrow_mut();
+            animations.retain(|a| a.upgrade().is_some_and(|a| a.is_running()));
+            animations.iter().filter_map(|a| a.upgrade()).map(Self).collect()
+        })
+    }
+
+    /// Pauses the animation: the property keeps its current value until [`Self::resume()`] is called.
+    pub fn pause(&self) {
+        if !self.0.is_paused() {
+            self.0.paused_at.set(Some(self.0.tick_at(AnimationControl::driver_tick())));
+            self.0.changed();
+        }
+    }
+
+    /// Resumes the animation where it was paused.
+    pub fn resume(&self) {
+        if let Some(paused_at) = self.0.paused_at.take() {
+            self.0.offset.set(AnimationControl::driver_tick().0 as i64 - paused_at.0 as i64);
+            self.0.changed();
+            CURRENT_ANIMATION_DRIVER.with(|driver| driver.set_has_active_animations());
+        }
+    }
+
+    /// Returns true if the animation is paused.
+    pub fn is_paused(&self) -> bool {
+        self.0.is_paused()
+    }
+
+    /// Moves the animation forward or backward, to the given time since it started, including its delay.
+    /// A paused animation stays paused at that time.
+    pub fn seek(&self, elapsed: Duration) {
+        let time = self.0.start.get() + elapsed;
+        if self.0.is_paused() {
+            self.0.paused_at.set(Some(time));
+        } else {
+            self.0.offset.set(AnimationControl::driver_tick().0 as i64 - time.0 as i64);
+            CURRENT_ANIMATION_DRIVER.with(|driver| driver.set_has_active_animations());
+        }
+        self.0.restart.set(true);
+        self.0.changed();
+    }
+
+    /// Returns the time since the animation started, including its delay, as of the last frame.
+    pub fn elapsed(&self) -> Duration {
+        self.0.current.get() - self.0.start.get()
+    }
+
+    /// Returns how long the animation lasts, including its delay and all its iterations, or None if it's
+    /// repeated forever.
+    pub fn total_duration(&self) -> Option<Duration> {
+        self.0.total_duration.get()
+    }
+
+    /// Returns the progress of the animation, between 0 and 1, as of the last frame. This is always 0 for
+    /// the animations that are repeated forever.
+    pub fn progress(&self) -> f32 {
+        match self.total_duration() {
+            _ if self.0.finished.get() => 1.,
+            Some(total) if !total.is_zero() => {
+                (self.elapsed().as_secs_f32() / total.as_secs_f32()).clamp(0., 1.)
+            }
+            _ => 0.,
+        }
+    }
+
+    /// Returns true if the animation finished.
+    pub fn is_finished(&self) -> bool {
+        self.0.finished.get()
+    }
+}
+
+impl core::fmt::Debug for AnimationHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnimationHandle")
+            .field("elapsed", &self.elapsed())
+            .field("total_duration", &self.total_duration())
+            .field("paused", &self.is_paused())
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
 fn ease_out_bounce_curve(value: f32) -> f32 {
     const N1: f32 = 7.5625;
     const D1: f32 = 2.75;
@@ -316,6 +582,54 @@ fn ease_out_bounce_curve(value: f32) -> f32 {
     }
 }
 
+/// The rate at which the oscillations of a spring decay, in 1/s, and its damping ratio
+fn spring_decay_rate(stiffness: f32, damping: f32, mass: f32) -> (f32, f32) {
+    let natural_frequency = (stiffness / mass).sqrt();
+    let damping_ratio = damping / (2.0 * (stiffness * mass).sqrt());
+    let rate = if damping_ratio < 1.0 {
+        damping_ratio * natural_frequency
+    } else {
+        natural_frequency * (damping_ratio - (damping_ratio * damping_ratio - 1.0).sqrt())
+    };
+    (rate, damping_ratio)
+}
+
+/// Returns the time a spring with the given stiffness, damping, and mass takes to come to rest, which is
+/// when its oscillations are smaller than a thousandth of the distance it travels. The result is capped
+/// to 10 seconds for springs without enough damping.
+pub fn spring_duration(stiffness: f32, damping: f32, mass: f32) -> core::time::Duration {
+    const MAX_DURATION: f32 = 10.;
+    let (rate, _) = spring_decay_rate(stiffness, damping, mass);
+    let seconds = if rate > 0.0 { (1000.0f32.ln() / rate).min(MAX_DURATION) } else { MAX_DURATION };
+    if seconds.is_finite() {
+        core::time::Duration::from_secs_f32(seconds)
+    } else {
+        core::time::Duration::ZERO
+    }
+}
+
+/// The position of a spring going from 0 to 1, `time` seconds after it was released at rest
+fn spring_curve(stiffness: f32, damping: f32, mass: f32, time: f32) -> f32 {
+    let natural_frequency = (stiffness / mass).sqrt();
+    let (_, damping_ratio) = spring_decay_rate(stiffness, damping, mass);
+    let displacement = if (damping_ratio - 1.0).abs() < 1e-4 {
+        // critically damped
+        (-natural_frequency * time).exp() * (1.0 + natural_frequency * time)
+    } else if damping_ratio < 1.0 {
+        let damped_frequency = natural_frequency * (1.0 - damping_ratio * damping_ratio).sqrt();
+        (-damping_ratio * natural_frequency * time).exp()
+            * ((damped_frequency * time).cos()
+                + (damping_ratio * natural_frequency / damped_frequency)
+                    * (damped_frequency * time).sin())
+    } else {
+        let root = (damping_ratio * damping_ratio - 1.0).sqrt();
+        let r1 = -natural_frequency * (damping_ratio - root);
+        let r2 = -natural_frequency * (damping_ratio + root);
+        (r2 * (r1 * time).exp() - r1 * (r2 * time).exp()) / (r2 - r1)
+    };
+    1.0 - displacement
+}
+
 /// map a value between 0 and 1 to another value between 0 and 1 according to the curve
 pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
     match curve {
@@ -332,6 +646,16 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
             };
             curve.y(curve.solve_t_for_x(value, 0.0..1.0, 0.01))
         }
+        EasingCurve::Spring([stiffness, damping, mass]) => {
+            if *stiffness <= 0.0 || *mass <= 0.0 || *damping < 0.0 {
+                return value;
+            }
+            if value >= 1.0 {
+                return 1.0;
+            }
+            let duration = spring_duration(*stiffness, *damping, *mass).as_secs_f32();
+            spring_curve(*stiffness, *damping, *mass, value * duration)
+        }
         EasingCurve::EaseInElastic => {
             const C4: f32 = 2.0 * core::f32::consts::PI / 3.0;
 
@@ -17,6 +17,8 @@
 use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 /// A position represented in the coordinate space of logical pixels. That is the space before applying
 /// a display device specific scale factor.
@@ -303,6 +305,21 @@ pub enum RenderingState {
     RenderingTeardown,
 }
 
+/// This struct is provided to the callback set with [`Window::on_frame()`], before a frame
+/// of the window is rendered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct FrameInfo {
+    /// The time at which the frame is expected to be shown on the display, in the same time base as
+    /// [`Platform::duration_since_start()`](crate::platform::Platform::duration_since_start).
+    /// Animations should use this time to compute their state, so that they stay in sync with
+    /// the display.
+    pub predicted_presentation_time: core::time::Duration,
+    /// The expected time between two frames, if known. This is the refresh interval of the display,
+    /// or the interval set with [`Window::set_frame_rate_limit()`] if that is longer.
+    pub frame_interval: Option<core::time::Duration>,
+}
+
 /// Internal trait that's used to map rendering state callbacks to either a Rust-API provided
 /// impl FnMut or a struct that invokes a C callback and implements Drop to release the closure
 /// on the C++ side.
@@ -400,6 +417,236 @@ fn display_handle<'a>(
 #[repr(transparent)]
 pub struct Window(pub(crate) WindowInner);
 
+/// The metrics of a font at a given size, in logical pixels, as returned by [`Window::font_metrics()`].
+///
+/// Unlike the `FontMetrics` struct of the `font-metrics` property, it also has the line spacing.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TextMetrics {
+    /// The distance between the baseline and the top of the tallest glyph in the font.
+    pub ascent: f32,
+    /// The distance between the baseline and the bottom of the tallest glyph in the font.
+    /// This is usually negative.
+    pub descent: f32,
+    /// The distance between the baseline and the top of a lower-case `x`, or zero if not specified
+    /// by the font.
+    pub x_height: f32,
+    /// The distance between the baseline and the top of a regular upper-case glyph in the font,
+    /// or zero if not specified by the font.
+    pub cap_height: f32,
+    /// The distance between the baselines of two consecutive lines of text.
+    pub line_spacing: f32,
+}
+
+/// The size and the lines of a text, as returned by [`Window::measure_text()`].
+#[derive(Debug, Default, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TextMeasurement {
+    /// The size of the text in logical pixels: the width of the longest line and the height of
+    /// all lines.
+    pub size: LogicalSize,
+    /// The byte ranges of the lines in the text, without trailing whitespace and line breaks.
+    pub lines: Vec<core::ops::Range<usize>>,
+}
+
+/// A drawing command of a [`TextOutline`], with positions in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum PathCommand {
+    /// Starts a new contour at the position.
+    MoveTo(LogicalPosition),
+    /// Draws a line to the position.
+    LineTo(LogicalPosition),
+    /// Draws a quadratic Bézier curve with the control point to the position.
+    QuadraticTo {
+        /// The control point.
+        control: LogicalPosition,
+        /// The end point.
+        to: LogicalPosition,
+    },
+    /// Draws a cubic Bézier curve with the two control points to the position.
+    CubicTo {
+        /// The first control point.
+        control1: LogicalPosition,
+        /// The second control point.
+        control2: LogicalPosition,
+        /// The end point.
+        to: LogicalPosition,
+    },
+    /// Closes the current contour with a line to its start.
+    Close,
+}
+
+/// The outline of a text, as returned by [`Window::text_outline()`].
+///
+/// The positions are in logical pixels relative to the top-left corner of the text.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextOutline {
+    commands: Vec<PathCommand>,
+}
+
+impl TextOutline {
+    /// Creates an outline from its commands.
+    pub fn new(commands: Vec<PathCommand>) -> Self {
+        Self { commands }
+    }
+
+    /// Returns the commands drawing the contours of the glyphs.
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    /// Returns the commands in the SVG path syntax, suitable for assigning to the `commands`
+    /// property of a `Path` element in order to stroke, fill, or animate the text.
+    pub fn to_svg_path(&self) -> SharedString {
+        use core::fmt::Write;
+        let mut svg = alloc::string::String::new();
+        for command in &self.commands {
+            if !svg.is_empty() {
+                svg.push(' ');
+            }
+            let _ = match command {
+                PathCommand::MoveTo(p) => write!(svg, "M {} {}", p.x, p.y),
+                PathCommand::LineTo(p) => write!(svg, "L {} {}", p.x, p.y),
+                PathCommand::QuadraticTo { control: c, to } => {
+                    write!(svg, "Q {} {} {} {}", c.x, c.y, to.x, to.y)
+                }
+                PathCommand::CubicTo { control1: c1, control2: c2, to } => {
+                    write!(svg, "C {} {} {} {} {} {}", c1.x, c1.y, c2.x, c2.y, to.x, to.y)
+                }
+                PathCommand::Close => write!(svg, "Z"),
+            };
+        }
+        svg.into()
+    }
+}
+
+/// A mouse cursor made of one or more images, for [`Window::set_custom_cursor()`].
+///
+/// ```rust,no_run
+/// # let window: &slint::Window = todo!();
+/// let brush = slint::Image::load_from_path(std::path::Path::new("brush.png")).unwrap();
+/// // The tip of the brush is at the center of the 32x32 image
+/// let cursor = slint::CustomCursor::new(brush, slint::PhysicalPosition::new(16, 16));
+/// window.set_custom_cursor(Some(cursor)).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomCursor {
+    frames: Vec<crate::graphics::Image>,
+    hotspot: PhysicalPosition,
+    frame_duration: core::time::Duration,
+}
+
+impl CustomCursor {
+    /// Creates a cursor that shows `image`. `hotspot` is the position of the pixel in the image
+    /// that's placed at the position of the mouse pointer.
+    pub fn new(image: crate::graphics::Image, hotspot: PhysicalPosition) -> Self {
+        Self { frames: alloc::vec![image], hotspot, frame_duration: core::time::Duration::ZERO }
+    }
+
+    /// Creates an animated cursor that shows each of the `frames` for `frame_duration`, in a loop.
+    /// All frames share the same `hotspot`.
+    pub fn new_animated(
+        frames: impl IntoIterator<Item = crate::graphics::Image>,
+        hotspot: PhysicalPosition,
+        frame_duration: core::time::Duration,
+    ) -> Self {
+        Self { frames: frames.into_iter().collect(), hotspot, frame_duration }
+    }
+
+    /// Returns the images of the cursor. A cursor that's not animated has a single frame.
+    pub fn frames(&self) -> &[crate::graphics::Image] {
+        &self.frames
+    }
+
+    /// Returns the position of the pixel in the images that's placed at the position of the
+    /// mouse pointer.
+    pub fn hotspot(&self) -> PhysicalPosition {
+        self.hotspot
+    }
+
+    /// Returns how long each frame of an animated cursor is shown.
+    pub fn frame_duration(&self) -> core::time::Duration {
+        self.frame_duration
+    }
+}
+
+/// A mode of the monitor showing a window, as returned by [`Window::video_modes()`] and used by
+/// [`Window::set_exclusive_fullscreen()`].
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct VideoMode {
+    /// The resolution of the monitor in this mode.
+    pub size: PhysicalSize,
+    /// The number of bits per pixel.
+    pub bit_depth: u16,
+    /// The refresh rate in millihertz, for example 60000 for 60 Hz.
+    pub refresh_rate_millihertz: u32,
+}
+
+/// The data that [`Window::start_drag()`] offers to other applications, in one or more formats
+/// identified by their MIME type. The application receiving the drop picks the format it
+/// understands.
+///
+/// ```rust,no_run
+/// # let window: &slint::Window = todo!();
+/// let data = slint::DragData::new()
+///     .with_files([std::path::Path::new("/home/user/notes.txt")])
+///     .with_text("notes.txt");
+/// window.start_drag(data).unwrap();
+/// ```
+///
+/// It is only available when the `unstable-drag-api` Slint feature is enabled.
+#[cfg(feature = "unstable-drag-api")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DragData {
+    formats: Vec<(SharedString, Vec<u8>)>,
+}
+
+#[cfg(feature = "unstable-drag-api")]
+impl DragData {
+    /// Creates an empty set of data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `data` in the format `mime_type`, replacing the data previously added in that format.
+    pub fn with_data(mut self, mime_type: &str, data: impl Into<Vec<u8>>) -> Self {
+        let data = data.into();
+        match self.formats.iter_mut().find(|(existing, _)| existing.as_str() == mime_type) {
+            Some((_, existing)) => *existing = data,
+            None => self.formats.push((mime_type.into(), data)),
+        }
+        self
+    }
+
+    /// Adds `text` in the `text/plain;charset=utf-8` format.
+    pub fn with_text(self, text: &str) -> Self {
+        self.with_data("text/plain;charset=utf-8", text.as_bytes())
+    }
+
+    /// Adds the `paths` as `file://` URIs in the `text/uri-list` format, which file managers
+    /// accept. The paths should be absolute.
+    #[cfg(feature = "std")]
+    pub fn with_files(self, paths: impl IntoIterator<Item = impl AsRef<std::path::Path>>) -> Self {
+        let mut uri_list = String::new();
+        for path in paths {
+            uri_list.push_str(&crate::input::file_uri(path.as_ref()));
+            uri_list.push_str("\r\n");
+        }
+        self.with_data("text/uri-list", uri_list)
+    }
+
+    /// Returns the MIME types and the data in each format, in the order they were added.
+    pub fn formats(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.formats.iter().map(|(mime_type, data)| (mime_type.as_str(), data.as_slice()))
+    }
+
+    /// Returns true if no data was added.
+    pub fn is_empty(&self) -> bool {
+        self.formats.is_empty()
+    }
+}
+
 /// This enum describes whether a Window is allowed to be hidden when the user tries to close the window.
 /// It is the return type of the callback provided to [Window::on_close_requested].
 #[derive(Copy, Clone, Debug, PartialEq, Default)]
@@ -412,6 +659,47 @@ pub enum CloseRequestResponse {
     KeepWindowShown = 1,
 }
 
+/// This enum describes how urgently a window asks for the attention of the user with
+/// [`Window::request_user_attention()`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UserAttentionType {
+    /// The window needs the attention of the user until it's activated, for example for an incoming call.
+    /// On macOS, the dock icon bounces until the application is activated. On Windows, the taskbar button
+    /// flashes until the window is activated.
+    Critical,
+    /// The user may want to look at the window, for example because a build finished. On macOS, the dock
+    /// icon bounces once. On Windows, the taskbar button is highlighted until the window is activated.
+    Informational,
+}
+
+/// This enum describes data that is dragged over a window, or dropped on it, from another
+/// application. It's the argument of the callback provided to [`Window::on_drag_and_drop()`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum DragAndDropEvent {
+    /// The data was dragged into the window or moved within it.
+    Moved {
+        /// The position of the pointer, relative to the window.
+        position: LogicalPosition,
+        /// The MIME type of the data, for example `text/uri-list` for files.
+        mime_type: SharedString,
+        /// The dragged data.
+        data: SharedString,
+    },
+    /// The data was dropped on the window.
+    Dropped {
+        /// The position of the pointer, relative to the window.
+        position: LogicalPosition,
+        /// The MIME type of the data, for example `text/uri-list` for files.
+        mime_type: SharedString,
+        /// The dropped data.
+        data: SharedString,
+    },
+    /// The data was dragged out of the window, or the drag was cancelled.
+    Exited,
+}
+
 impl Window {
     /// Create a new window from a window adapter
     ///
@@ -465,6 +753,61 @@ pub fn hide(&self) -> Result<(), PlatformError> {
         self.0.hide()
     }
 
+    /// Restores the position, size, and maximized and fullscreen states of the window from the setting
+    /// with the given key, and stores them there and saves the settings whenever the window is hidden,
+    /// and when `slint::run_event_loop()` returns. Call this before showing the window.
+    ///
+    /// The position isn't restored if the window would be off the available screens, for example
+    /// because the screen it was on was disconnected.
+    ///
+    /// ```rust,no_run
+    /// # let window: &slint::Window = todo!();
+    /// let settings = slint::Settings::for_application("my-app").unwrap();
+    /// window.persist_geometry(&settings, "main-window");
+    /// window.show().unwrap();
+    /// ```
+    pub fn persist_geometry(&self, settings: &crate::settings::Settings, key: &str) {
+        self.0.persist_geometry(settings, key.into());
+    }
+
+    /// Prevents the screen saver from starting, the screen from being blanked, and the system from
+    /// going to sleep because the user is idle, while `inhibited` is true. Use this while the window
+    /// shows a video or a presentation. The inhibition ends when it's set to false or the window is
+    /// destroyed.
+    ///
+    /// This is supported by the winit backend on Windows, macOS, and Linux desktops that implement the
+    /// `org.freedesktop.ScreenSaver` D-Bus interface.
+    pub fn set_idle_inhibited(&self, inhibited: bool) {
+        if let Some(internal) = self.0.window_adapter().internal(crate::InternalToken) {
+            internal.set_idle_inhibited(inhibited);
+        }
+    }
+
+    /// Asks the windowing system to draw the attention of the user to the window, in the way that
+    /// is common on the platform, for example by flashing its taskbar button or bouncing the dock icon.
+    /// This does nothing when the window is already active.
+    ///
+    /// This is supported by the winit and Qt backends. The Qt backend doesn't distinguish between
+    /// the types of attention.
+    pub fn request_user_attention(&self, attention: UserAttentionType) {
+        if let Some(internal) = self.0.window_adapter().internal(crate::InternalToken) {
+            internal.request_user_attention(attention);
+        }
+    }
+
+    /// Asks the windowing system to bring the window to the front and give it the keyboard focus,
+    /// for example to switch from one window of the application to another.
+    ///
+    /// On Wayland, the compositor only activates the window when another window of the application
+    /// has the focus. The winit backend then passes an xdg-activation token from that window on. The
+    /// compositor may show a notification instead.
+    pub fn request_activation(&self) -> Result<(), PlatformError> {
+        match self.0.window_adapter().internal(crate::InternalToken) {
+            Some(internal) => internal.bring_to_front(),
+            None => Ok(()),
+        }
+    }
+
     /// This function allows registering a callback that's invoked during the different phases of
     /// rendering. This allows custom rendering on top or below of the scene.
     pub fn set_rendering_notifier(
@@ -480,6 +823,80 @@ pub fn on_close_requested(&self, callback: impl FnMut() -> CloseRequestResponse
         self.0.on_close_requested(callback);
     }
 
+    /// Returns the color scheme of the system, which the widget styles follow unless the `color-scheme`
+    /// property of `Palette` is set. It's [`ColorScheme::Unknown`] if the platform doesn't report it.
+    pub fn color_scheme(&self) -> ColorScheme {
+        self.0.color_scheme()
+    }
+
+    /// This function allows registering a callback that's invoked when the color scheme of the system
+    /// changes, for example when the user switches to a dark theme while the application is running.
+    /// The callback receives the new color scheme. Setting a new callback replaces the previous one.
+    pub fn on_color_scheme_changed(&self, callback: impl FnMut(ColorScheme) + 'static) {
+        self.0.on_color_scheme_changed(callback);
+    }
+
+    /// This function allows registering a callback that's invoked when the user switches to a
+    /// different keyboard layout, for example to update labels that show which keys to press.
+    ///
+    /// Note: This is supported with the Qt backend and with the winit backend on Windows.
+    pub fn on_keyboard_layout_changed(&self, callback: impl FnMut() + 'static) {
+        self.0.on_keyboard_layout_changed(callback);
+    }
+
+    /// Returns the text that the physical key named `physical_key` produces without modifiers in the
+    /// current keyboard layout, such as `"z"` for `"KeyY"` with a German layout. The names are the
+    /// `code` values of the W3C UI Events, as reported in the `physical-key` field of `KeyEvent`.
+    ///
+    /// Use this to label keyboard shortcuts that are defined by the position of the keys.
+    ///
+    /// Note: Only the winit backend on Windows can look up the keys in the layout. Otherwise, the
+    /// text is known once the key was pressed in this window, and `None` is returned until then.
+    pub fn physical_key_text(&self, physical_key: &str) -> Option<SharedString> {
+        self.0.physical_key_text(physical_key)
+    }
+
+    /// This function allows registering a callback that's invoked before each frame of the window
+    /// is rendered. Properties set from the callback are taken into account for that frame.
+    /// Use [`FrameInfo::predicted_presentation_time`] to synchronize custom animations with the
+    /// refresh of the display.
+    pub fn on_frame(&self, callback: impl FnMut(&FrameInfo) + 'static) {
+        self.0.on_frame(callback);
+    }
+
+    /// This function allows registering a callback that's invoked when data from another
+    /// application is dragged over the window, or dropped on it, before the `DropArea` elements
+    /// receive it. Use it to accept drops anywhere in the window.
+    ///
+    /// Note: The winit backend only reports dragged files, as `text/uri-list`, and doesn't
+    /// support drag and drop on Wayland. The Qt backend reports any data.
+    pub fn on_drag_and_drop(&self, callback: impl FnMut(&DragAndDropEvent) + 'static) {
+        self.0.on_drag_and_drop(callback);
+    }
+
+    /// Limits how many frames per second are rendered while animations are running, for example to
+    /// save power by rendering at 30 frames per second. Pass `None` to remove the limit, which
+    /// is the default.
+    ///
+    /// Note: Frames requested for other reasons, such as input events, are still rendered right away.
+    pub fn set_frame_rate_limit(&self, frames_per_second: Option<u32>) {
+        self.0.set_frame_rate_limit(frames_per_second);
+    }
+
+    /// Sets whether frames are presented in sync with the vertical refresh of the display,
+    /// which is the default. Disabling vsync reduces latency at the cost of tearing.
+    ///
+    /// Note: This is supported by the FemtoVG and Skia renderers with OpenGL, Direct3D, and Metal.
+    /// Other renderers ignore it.
+    pub fn set_vsync_enabled(&self, enabled: bool) {
+        self.0.set_vsync_enabled(enabled);
+    }
+
+    /// Returns whether frames are presented in sync with the vertical refresh of the display.
+    pub fn vsync_enabled(&self) -> bool {
+        self.0.vsync_enabled()
+    }
+
     /// This function issues a request to the windowing system to redraw the contents of the window.
     pub fn request_redraw(&self) {
         self.0.window_adapter().request_redraw()
@@ -491,6 +908,29 @@ pub fn scale_factor(&self) -> f32 {
         self.0.scale_factor()
     }
 
+    /// Overrides the scale factor reported by the platform for this window, or uses the reported
+    /// one again if `scale_factor` is `None`. For example, set `Some(1.0)` to map each logical pixel to
+    /// one physical pixel in a pixel art editor, or fix the scale factor of a display that reports a
+    /// wrong DPI.
+    ///
+    /// The logical size of the window is kept, so it's resized on the screen. Text layout and the
+    /// positions of input events use the new scale factor.
+    pub fn set_scale_factor_override(&self, scale_factor: Option<f32>) {
+        self.0.set_scale_factor_override(scale_factor);
+    }
+
+    /// Returns the scale factor set with [`Self::set_scale_factor_override()`].
+    pub fn scale_factor_override(&self) -> Option<f32> {
+        self.0.scale_factor_override()
+    }
+
+    /// Returns the factor by which the font sizes are multiplied, following the text scaling
+    /// setting of the system for accessibility. This is `1.0` unless the backend reported another
+    /// value with [`WindowEvent::SystemFontChanged`](crate::platform::WindowEvent::SystemFontChanged).
+    pub fn text_scale_factor(&self) -> f32 {
+        self.0.text_scale_factor()
+    }
+
     /// Returns the position of the window on the screen, in physical screen coordinates and including
     /// a window frame (if present).
     pub fn position(&self) -> PhysicalPosition {
@@ -528,6 +968,78 @@ pub fn set_fullscreen(&self, fullscreen: bool) {
         self.0.set_fullscreen(fullscreen);
     }
 
+    /// Sets the opacity of the whole window, including its decorations, between 0.0 (transparent) and
+    /// 1.0 (opaque). This is the `window-opacity` property of the `Window`, which can be animated,
+    /// for example to fade in a splash screen. Unlike the `opacity` of elements, the windowing system
+    /// applies it.
+    ///
+    /// This is supported by the Qt backend, and by the winit backend on Windows and macOS.
+    pub fn set_opacity(&self, opacity: f32) {
+        self.0.set_opacity(opacity);
+    }
+
+    /// Returns the opacity of the whole window, set with [`Self::set_opacity()`] or the
+    /// `window-opacity` property.
+    pub fn opacity(&self) -> f32 {
+        self.0.opacity()
+    }
+
+    /// Sets the icon of the window, shown in its title bar and in the taskbar, from images in
+    /// different sizes. The backend picks the size that fits best for each place, so provide for
+    /// example 16x16, 32x32, and 256x256 images.
+    ///
+    /// The icons replace the image of the `icon` property of the `Window`, which is used again
+    /// when `icons` is empty.
+    ///
+    /// ```rust,no_run
+    /// # let window: &slint::Window = todo!();
+    /// let icons = ["icon-16.png", "icon-32.png", "icon-256.png"]
+    ///     .map(|path| slint::Image::load_from_path(std::path::Path::new(path)).unwrap());
+    /// window.set_icons(icons);
+    /// ```
+    pub fn set_icons(&self, icons: impl IntoIterator<Item = crate::graphics::Image>) {
+        self.0.set_icons(icons.into_iter().collect());
+    }
+
+    /// Returns whether the window is kept above or below the windows of other applications.
+    /// This is [`WindowLevel::AlwaysOnTop`] when the `always-on-top` property of the `Window` is set.
+    pub fn window_level(&self) -> WindowLevel {
+        self.0.window_level()
+    }
+
+    /// Keeps the window above or below the windows of other applications, or stacks it with them
+    /// as usual. This sets the `window-level` property of the `Window`.
+    pub fn set_window_level(&self, level: WindowLevel) {
+        self.0.set_window_level(level);
+    }
+
+    /// Returns the modes that the monitor showing the window supports for
+    /// [`Self::set_exclusive_fullscreen()`]. The list is empty when the window isn't shown, or when
+    /// the backend doesn't support exclusive fullscreen.
+    pub fn video_modes(&self) -> Vec<VideoMode> {
+        self.0
+            .window_adapter()
+            .internal(crate::InternalToken)
+            .map_or_else(Vec::new, |internal| internal.video_modes())
+    }
+
+    /// Shows the window in exclusive fullscreen, which switches the monitor to `mode`, or leaves
+    /// it when `mode` is `None`. `mode` must be one of the [`Self::video_modes()`].
+    ///
+    /// Unlike with [`Self::set_fullscreen()`], the window doesn't share the monitor with the
+    /// compositor of the desktop, which lowers the latency, but switching to another application
+    /// is slower. [`Self::is_fullscreen()`] returns true while in exclusive fullscreen.
+    ///
+    /// Currently only the winit backend supports this function, and not on Wayland.
+    pub fn set_exclusive_fullscreen(&self, mode: Option<VideoMode>) -> Result<(), PlatformError> {
+        match self.0.window_adapter().internal(crate::InternalToken) {
+            Some(internal) => internal.set_exclusive_fullscreen(mode),
+            None => Err(PlatformError::Other(
+                "This backend does not support exclusive fullscreen".into(),
+            )),
+        }
+    }
+
     /// Returns if the window is currently maximized
     pub fn is_maximized(&self) -> bool {
         self.0.is_maximized()
@@ -548,6 +1060,58 @@ pub fn set_minimized(&self, minimized: bool) {
         self.0.set_minimized(minimized);
     }
 
+    /// Lets the windowing system move the window with the pointer, like when dragging the title bar
+    /// that it draws, including snapping the window to the edges of the screen. Call this while a
+    /// pointer button is pressed, for example from the `pointer-event` callback of a `TouchArea` in a
+    /// custom title bar. The move ends when the button is released.
+    ///
+    /// This is supported by the winit backend.
+    pub fn start_system_move(&self) {
+        self.0.start_system_move();
+    }
+
+    /// Lets the windowing system resize the window at the given edge or corner with the pointer,
+    /// like when dragging the frame that it draws. Call this while a pointer button is pressed, for
+    /// example from the `pointer-event` callback of a `TouchArea` in a custom resize grip. The resize
+    /// ends when the button is released.
+    ///
+    /// This is supported by the winit backend.
+    pub fn start_system_resize(&self, edge: WindowResizeEdge) {
+        self.0.start_system_resize(edge);
+    }
+
+    /// Makes this window a child window of `parent`, such as a settings or a confirmation dialog,
+    /// or a top-level window again when `parent` is `None`. The windowing system keeps the window
+    /// above its parent.
+    ///
+    /// The winit backend sets the owner of the window on Windows and makes it a child window on macOS,
+    /// and centers the window on its parent when it's shown for the first time. The Qt backend sets
+    /// the transient parent, which Wayland and X11 window managers use.
+    pub fn set_parent(&self, parent: Option<&Window>) {
+        self.0.set_parent(parent.map(|parent| parent.0.window_adapter()));
+    }
+
+    /// Makes this window modal to the window set with [`Self::set_parent()`]: while this window is
+    /// visible, the parent doesn't receive keyboard and pointer input, and clicking it activates
+    /// this window instead.
+    ///
+    /// ```rust,no_run
+    /// # use i_slint_core::api::Window;
+    /// # fn show_dialog(main_window: &Window, dialog: &Window) -> Result<(), i_slint_core::platform::PlatformError> {
+    /// dialog.set_parent(Some(main_window));
+    /// dialog.set_modal(true);
+    /// dialog.show()
+    /// # }
+    /// ```
+    pub fn set_modal(&self, modal: bool) {
+        self.0.set_modal(modal);
+    }
+
+    /// Returns whether the window was made modal with [`Self::set_modal()`].
+    pub fn is_modal(&self) -> bool {
+        self.0.is_modal()
+    }
+
     /// Dispatch a window event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -602,26 +1166,85 @@ pub fn try_dispatch_event(
             crate::platform::WindowEvent::PointerExited => {
                 self.0.process_mouse_input(MouseEvent::Exit)
             }
-
+            crate::platform::WindowEvent::PointerDetailsChanged {
+                tool_type,
+                pressure,
+                tilt_x,
+                tilt_y,
+                twist,
+            } => self.0.pointer_details.set(Some(crate::input::PointerDetails {
+                tool_type,
+                pressure,
+                tilt_x,
+                tilt_y,
+                twist,
+            })),
+
+            crate::platform::WindowEvent::TouchPressed { id, position } => {
+                self.0.process_touch_event(
+                    id,
+                    position.to_euclid(),
+                    crate::items::PointerEventKind::Down,
+                )
+            }
+            crate::platform::WindowEvent::TouchMoved { id, position } => {
+                self.0.process_touch_event(
+                    id,
+                    position.to_euclid(),
+                    crate::items::PointerEventKind::Move,
+                )
+            }
+            crate::platform::WindowEvent::TouchReleased { id, position } => self
+                .0
+                .process_touch_event(id, position.to_euclid(), crate::items::PointerEventKind::Up),
+
+            crate::platform::WindowEvent::ScrollDetailsChanged {
+                device,
+                line_delta_x,
+                line_delta_y,
+            } => self.0.scroll_details.set(Some(crate::input::ScrollDetails {
+                device,
+                line_delta_x,
+                line_delta_y,
+            })),
+
+            crate::platform::WindowEvent::KeyDetailsChanged { physical_key, scancode } => {
+                *self.0.key_details.borrow_mut() =
+                    crate::input::KeyDetails { physical_key, scancode };
+            }
             crate::platform::WindowEvent::KeyPressed { text } => {
+                let details = self.0.key_details.take();
                 self.0.process_key_input(crate::input::KeyEvent {
                     text,
                     repeat: false,
+                    physical_key: details.physical_key,
+                    scancode: details.scancode as i32,
                     event_type: KeyEventType::KeyPressed,
                     ..Default::default()
                 })
             }
             crate::platform::WindowEvent::KeyPressRepeated { text } => {
+                let details = self.0.key_details.take();
+                if !details.physical_key.is_empty()
+                    && self.0.keys_without_repeat.borrow().contains(&details.physical_key)
+                {
+                    return Ok(());
+                }
                 self.0.process_key_input(crate::input::KeyEvent {
                     text,
                     repeat: true,
+                    physical_key: details.physical_key,
+                    scancode: details.scancode as i32,
                     event_type: KeyEventType::KeyPressed,
                     ..Default::default()
                 })
             }
             crate::platform::WindowEvent::KeyReleased { text } => {
+                let details = self.0.key_details.take();
                 self.0.process_key_input(crate::input::KeyEvent {
                     text,
+                    physical_key: details.physical_key,
+                    scancode: details.scancode as i32,
                     event_type: KeyEventType::KeyReleased,
                     ..Default::default()
                 })
@@ -639,6 +1262,37 @@ pub fn try_dispatch_event(
                 }
             }
             crate::platform::WindowEvent::WindowActiveChanged(bool) => self.0.set_active(bool),
+            crate::platform::WindowEvent::WindowOccludedChanged(occluded) => {
+                self.0.set_occluded(occluded)
+            }
+            crate::platform::WindowEvent::VirtualKeyboardChanged { position, size } => {
+                self.0.set_virtual_keyboard_rect(crate::lengths::LogicalRect::new(
+                    position.to_euclid(),
+                    size.to_euclid(),
+                ))
+            }
+            crate::platform::WindowEvent::RotaryEncoderRotated { delta } => {
+                self.0.process_rotary_encoder(delta)
+            }
+            crate::platform::WindowEvent::SystemFontChanged { family, text_scale_factor } => {
+                self.0.set_system_font(family, text_scale_factor)
+            }
+            crate::platform::WindowEvent::DragMoved { position, mime_type, data } => {
+                self.0.process_drag_event(
+                    position.to_euclid(),
+                    crate::items::DropEvent { mime_type, data },
+                    false,
+                )
+            }
+            crate::platform::WindowEvent::Dropped { position, mime_type, data } => {
+                self.0.process_drag_event(
+                    position.to_euclid(),
+                    crate::items::DropEvent { mime_type, data },
+                    true,
+                )
+            }
+            crate::platform::WindowEvent::DragExited => self.0.process_drag_exit(),
+            crate::platform::WindowEvent::KeyboardLayoutChanged => self.0.keyboard_layout_changed(),
         };
         Ok(())
     }
@@ -676,14 +1330,302 @@ pub fn window_handle(&self) -> WindowHandle {
         }
     }
 
+    /// Applies a filter to the rendered content of the window to simulate how it is perceived with a
+    /// vision deficiency, for example to check that a color palette remains readable with a color
+    /// vision deficiency. Pass [`VisionSimulation::None`](crate::graphics::VisionSimulation::None)
+    /// to remove the filter.
+    ///
+    /// The color filters are applied in linear light. Currently only the Skia renderer supports this
+    /// function, the other renderers return an error.
+    pub fn set_vision_simulation(
+        &self,
+        simulation: crate::graphics::VisionSimulation,
+    ) -> Result<(), PlatformError> {
+        self.0.window_adapter().renderer().set_vision_simulation(simulation, None)
+    }
+
     /// Takes a snapshot of the window contents and returns it as RGBA8 encoded pixel buffer.
     ///
     /// Note that this function may be slow to call as it may need to re-render the scene.
     pub fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
         self.0.window_adapter().renderer().take_snapshot()
     }
+
+    /// Returns the outline of `text`, as shaped and rendered with the font the renderer selects for
+    /// `font_family` (or the default font if `None`) at the given `font_size` in logical pixels.
+    ///
+    /// The outline is in logical coordinates relative to the top-left corner of the text. Use
+    /// [`TextOutline::to_svg_path()`] to assign it to the `commands` property of a `Path` element
+    /// in order to stroke, fill, or animate the text.
+    ///
+    /// The Skia, FemtoVG, and software renderers support this function. The software renderer
+    /// returns an error for texts rendered with pre-rendered bitmap fonts, which have no outline.
+    pub fn text_outline(
+        &self,
+        text: &str,
+        font_family: Option<&str>,
+        font_size: f32,
+    ) -> Result<TextOutline, PlatformError> {
+        let font_request = self.0.apply_system_font(crate::graphics::FontRequest {
+            family: font_family.map(Into::into),
+            pixel_size: Some(crate::lengths::LogicalLength::new(font_size as _)),
+            ..Default::default()
+        });
+        let scale_factor = crate::lengths::ScaleFactor::new(self.0.scale_factor());
+        self.0.window_adapter().renderer().text_outline(text, font_request, scale_factor)
+    }
+
+    /// Returns the metrics of the font the renderer selects for `font_family` (or the default font
+    /// if `None`) at the given `font_size` in logical pixels, for example to align custom drawn
+    /// content with the baseline of a `Text`.
+    pub fn font_metrics(&self, font_family: Option<&str>, font_size: f32) -> TextMetrics {
+        let font_family: SharedString = font_family.unwrap_or_default().into();
+        let font_size = crate::lengths::LogicalLength::new(font_size as _);
+        let metrics = self.0.font_metrics(font_family.clone(), font_size);
+        TextMetrics {
+            ascent: metrics.ascent as _,
+            descent: metrics.descent as _,
+            x_height: metrics.x_height as _,
+            cap_height: metrics.cap_height as _,
+            line_spacing: self.0.line_spacing(font_family, font_size).get() as _,
+        }
+    }
+
+    /// Measures `text` as laid out with the font the renderer selects for `font_family` (or the
+    /// default font of the window if `None`) at the given `font_size` in logical pixels.
+    ///
+    /// When `wrap_width` is set, the text is wrapped at word boundaries so that no line is wider
+    /// than that. The result has the size of the text and the byte range of each line, using the
+    /// same shaping and line breaking as a `Text` element, for example to size a popup or to elide
+    /// a string before showing it.
+    ///
+    /// The Qt backend doesn't support this function.
+    pub fn measure_text(
+        &self,
+        text: &str,
+        font_family: Option<&str>,
+        font_size: f32,
+        wrap_width: Option<f32>,
+    ) -> Result<TextMeasurement, PlatformError> {
+        let font_request = self.0.apply_system_font(crate::graphics::FontRequest {
+            family: font_family
+                .map(Into::into)
+                .or_else(|| self.0.window_item().and_then(|item| item.as_pin_ref().font_family())),
+            pixel_size: Some(crate::lengths::LogicalLength::new(font_size as _)),
+            ..Default::default()
+        });
+        let scale_factor = crate::lengths::ScaleFactor::new(self.0.scale_factor());
+        let max_width = wrap_width.map(|width| crate::lengths::LogicalLength::new(width as _));
+        let text_wrap = crate::items::TextWrap::WordWrap;
+        let window_adapter = self.0.window_adapter();
+        let renderer = window_adapter.renderer();
+        let lines = renderer.text_line_ranges(
+            font_request.clone(),
+            text,
+            max_width,
+            scale_factor,
+            text_wrap,
+        )?;
+        let size = renderer.text_size(font_request, text, max_width, scale_factor, text_wrap);
+        Ok(TextMeasurement { size: LogicalSize::from_euclid(size), lines })
+    }
+
+    /// Returns a compact JSON description of what the window currently shows, to attach to crash
+    /// or error reports.
+    ///
+    /// The description contains the visible items of the window with their geometry and the
+    /// properties needed to draw them again, such as colors, texts, and image paths, but not the
+    /// pixels of the images. The text of password fields is masked. Open the description with
+    /// `slint-viewer --scene <file>` to see an approximation of the window.
+    ///
+    /// The format of the description may change in future versions, but it contains a `version`
+    /// field to tell the versions apart.
+    pub fn serialize_scene(&self) -> String {
+        self.0.serialize_scene()
+    }
+
+    /// Removes the fonts of the given family that were registered with an `import` statement in a
+    /// `.slint` file or with [`Self::replace_font_from_path()`]. The texts using that family are
+    /// laid out again with the fonts that remain, in all windows.
+    ///
+    /// The Qt backend doesn't support this function.
+    #[cfg(feature = "std")]
+    pub fn unregister_font(&self, family: &str) -> Result<(), PlatformError> {
+        self.0
+            .window_adapter()
+            .renderer()
+            .unregister_font(family)
+            .map_err(|err| PlatformError::Other(err.to_string()))?;
+        self.0.context().mark_fonts_dirty();
+        self.0.window_adapter().request_redraw();
+        Ok(())
+    }
+
+    /// Registers the TrueType or OpenType font at `path`, after removing the fonts previously
+    /// registered for `family`, if any. Use this to install or update a font while the application
+    /// is running, for example a branding font chosen by the user. The texts using that family are
+    /// laid out again with the new font, in all windows. If the font can't be loaded, an error is
+    /// returned and the fonts previously registered for `family` are kept.
+    ///
+    /// ```rust,no_run
+    /// # let window: &slint::Window = todo!();
+    /// window.replace_font_from_path("Brand Sans", std::path::Path::new("/path/to/brand.ttf")).unwrap();
+    /// ```
+    ///
+    /// The Qt backend doesn't support this function.
+    #[cfg(feature = "std")]
+    pub fn replace_font_from_path(
+        &self,
+        family: &str,
+        path: &std::path::Path,
+    ) -> Result<(), PlatformError> {
+        self.0
+            .window_adapter()
+            .renderer()
+            .replace_font_from_path(family, path)
+            .map_err(|err| PlatformError::Other(err.to_string()))?;
+        self.0.context().mark_fonts_dirty();
+        self.0.window_adapter().request_redraw();
+        Ok(())
+    }
+
+    /// Same as [`Self::replace_font_from_path()`], with the font data in memory.
+    #[cfg(feature = "std")]
+    pub fn replace_font_from_memory(
+        &self,
+        family: &str,
+        data: &'static [u8],
+    ) -> Result<(), PlatformError> {
+        self.0
+            .window_adapter()
+            .renderer()
+            .replace_font_from_memory(family, data)
+            .map_err(|err| PlatformError::Other(err.to_string()))?;
+        self.0.context().mark_fonts_dirty();
+        self.0.window_adapter().request_redraw();
+        Ok(())
+    }
+
+    /// Sets the factor by which the resolution of the rendered scene differs from the resolution of
+    /// the window surface.
+    ///
+    /// With a factor greater than 1, the scene is rendered at a higher resolution and scaled down
+    /// when presented, which smoothes the edges of shapes and text (supersampling). This is useful
+    /// for high quality screenshots with [`Self::take_snapshot()`]. With a factor smaller than 1,
+    /// the scene is rendered at a lower resolution and scaled up, which reduces the load on weak GPUs
+    /// at the cost of a blurry output. The default is 1.
+    ///
+    /// Images are still decoded for the scale factor of the window.
+    ///
+    /// Currently only the Skia and FemtoVG renderers support this function.
+    pub fn set_render_scale(&self, render_scale: f32) -> Result<(), PlatformError> {
+        if !(render_scale.is_finite() && render_scale > 0.) {
+            return Err(PlatformError::Other(alloc::format!(
+                "Invalid render scale {render_scale}, it must be greater than 0"
+            )));
+        }
+        self.0.window_adapter().renderer().set_render_scale(render_scale)
+    }
+
+    /// Enables or disables gamma-correct blending.
+    ///
+    /// By default, semi-transparent colors, anti-aliased edges, and gradients are blended by mixing
+    /// their sRGB encoded values, like most toolkits and browsers do. With gamma-correct blending,
+    /// the light intensities are mixed instead, which avoids dark fringes around anti-aliased
+    /// shapes and dull midpoints in gradients between saturated colors. The rendering then differs
+    /// from designs made with tools that blend in sRGB, and rendering is slower:
+    ///
+    ///  * The software renderer converts every blended pixel, and splits each gradient into
+    ///    several parts. Pixel types that don't implement `TargetPixel::blend_linear()` still
+    ///    blend in sRGB.
+    ///  * The Skia renderer draws into an intermediate surface: an sRGB framebuffer on the GPU,
+    ///    where supported, otherwise a surface with 16 bits per channel.
+    ///
+    /// The FemtoVG and Qt renderers don't support gamma-correct blending, and return an error
+    /// when enabling it.
+    pub fn set_gamma_correct_blending(&self, enabled: bool) -> Result<(), PlatformError> {
+        self.0.window_adapter().renderer().set_gamma_correct_blending(enabled)
+    }
+
+    /// Sets a cursor made of images, such as a brush-shaped cursor in a drawing application,
+    /// or removes it when `cursor` is `None`.
+    ///
+    /// While a custom cursor is set, it's shown instead of the cursor chosen with the
+    /// `mouse-cursor` property of the `TouchArea` elements under the mouse pointer.
+    ///
+    /// Currently only the winit backend supports this function.
+    pub fn set_custom_cursor(&self, cursor: Option<CustomCursor>) -> Result<(), PlatformError> {
+        if let Some(cursor) = &cursor {
+            if cursor.frames.is_empty() {
+                return Err(PlatformError::Other(
+                    "A custom cursor needs at least one image".into(),
+                ));
+            }
+            if cursor.frames.len() > 1 && cursor.frame_duration.is_zero() {
+                return Err(PlatformError::Other(
+                    "The frame duration of an animated cursor must not be zero".into(),
+                ));
+            }
+        }
+        match self.0.window_adapter().internal(crate::InternalToken) {
+            Some(internal) => internal.set_custom_cursor(cursor),
+            None => {
+                Err(PlatformError::Other("This backend does not support custom cursors".into()))
+            }
+        }
+    }
+
+    /// Starts dragging `data` out of the window, so that the user can drop it in another
+    /// application, for example files in the file manager. Call this function from the
+    /// `pointer-event` or `moved` callback of a `TouchArea` while the pointer is pressed.
+    ///
+    /// The drag starts once the current event is processed, and this function returns right
+    /// away. When the data was dropped or the drag was cancelled, the items that were pressed when
+    /// the drag started are released without being clicked.
+    ///
+    /// Only the Qt backend supports this function, the other backends return an error.
+    ///
+    /// It is only available when the `unstable-drag-api` Slint feature is enabled.
+    ///
+    /// **Warning**: This function is experimental and not covered by the semver guarantees of
+    /// Slint. It may change in minor releases, for example when more backends support it.
+    #[cfg(feature = "unstable-drag-api")]
+    pub fn start_drag(&self, data: DragData) -> Result<(), PlatformError> {
+        if data.is_empty() {
+            return Err(PlatformError::Other("There is no data to drag".into()));
+        }
+        match self.0.window_adapter().internal(crate::InternalToken) {
+            Some(internal) => internal.start_drag(data),
+            None => Err(PlatformError::Other(
+                "This backend does not support dragging data to other applications".into(),
+            )),
+        }
+    }
+
+    /// Enables or disables the repeated key presses that the operating system sends while the
+    /// physical key named `physical_key` is held down. The names are the `code` values of the W3C
+    /// UI Events, like `"KeyA"` or `"ArrowUp"`, as reported in the `physical-key` field of `KeyEvent`.
+    ///
+    /// When disabled, the `key-pressed` callbacks are only invoked once until the key is released,
+    /// even if the text of the key changes in the meantime because a modifier was pressed, which is
+    /// for example what emulators and games need. The delay and the rate of the repeats are the
+    /// ones of the system settings.
+    ///
+    /// Note: The repeats are only dropped with backends that report the physical keys, like the Qt
+    /// and winit backends.
+    pub fn set_key_repeat_enabled(&self, physical_key: impl Into<SharedString>, enabled: bool) {
+        let physical_key = physical_key.into();
+        let mut keys = self.0.keys_without_repeat.borrow_mut();
+        keys.retain(|k| *k != physical_key);
+        if !enabled {
+            keys.push(physical_key);
+        }
+    }
 }
 
+pub use crate::items::ColorScheme;
+pub use crate::items::WindowLevel;
+pub use crate::items::WindowResizeEdge;
 pub use crate::SharedString;
 
 #[i_slint_core_macros::slint_doc]
@@ -957,6 +1899,88 @@ pub fn invoke_from_event_loop(func: impl FnOnce() + Send + 'static) -> Result<()
     })
 }
 
+/// The state shared between the future returned by [`invoke_from_event_loop_async()`] and the function
+/// queued in the event loop.
+#[cfg(feature = "std")]
+struct AsyncInvocation<R> {
+    result: Option<Result<R, EventLoopError>>,
+    waker: Option<core::task::Waker>,
+}
+
+/// Sends the result of the function queued by [`invoke_from_event_loop_async()`], or reports that the event
+/// loop terminated if it's dropped without running the function.
+#[cfg(feature = "std")]
+struct AsyncInvocationSender<R>(std::sync::Arc<std::sync::Mutex<AsyncInvocation<R>>>);
+
+#[cfg(feature = "std")]
+impl<R> AsyncInvocationSender<R> {
+    fn send(&self, result: Result<R, EventLoopError>) {
+        let mut state = self.0.lock().unwrap();
+        if state.result.is_none() {
+            state.result = Some(result);
+        }
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R> Drop for AsyncInvocationSender<R> {
+    fn drop(&mut self) {
+        self.send(Err(EventLoopError::EventLoopTerminated));
+    }
+}
+
+/// Queues `func` to be invoked in the event loop, like [`invoke_from_event_loop()`], and returns a future that
+/// resolves to the value returned by `func`.
+///
+/// Use this from other threads to query the user interface, or to update it and wait for the update to be done,
+/// without setting up a channel for the result. The function is queued right away, and the returned future only
+/// waits for its result, so it can be awaited by any async runtime, or dropped if the result isn't needed.
+///
+/// The future resolves to an error if the function couldn't be queued, or if the event loop terminated
+/// before invoking it.
+///
+/// # Example
+/// ```rust
+/// slint::slint! { export component MyApp inherits Window { in-out property <int> counter; /* ... */ } }
+///
+/// // Called from a task of an async runtime running in another thread
+/// async fn increment(handle_weak: slint::Weak<MyApp>) -> Result<i32, slint::EventLoopError> {
+///     slint::invoke_from_event_loop_async(move || {
+///         let handle = handle_weak.unwrap();
+///         handle.set_counter(handle.get_counter() + 1);
+///         handle.get_counter()
+///     })
+///     .await
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn invoke_from_event_loop_async<R: Send + 'static>(
+    func: impl FnOnce() -> R + Send + 'static,
+) -> impl core::future::Future<Output = Result<R, EventLoopError>> {
+    let shared = std::sync::Arc::new(std::sync::Mutex::new(AsyncInvocation::<R> {
+        result: None,
+        waker: None,
+    }));
+    let sender = AsyncInvocationSender(shared.clone());
+    if let Err(err) = invoke_from_event_loop(move || sender.send(Ok(func()))) {
+        // The sender was dropped with the function, replace its error with the actual one
+        shared.lock().unwrap().result = Some(Err(err));
+    }
+    core::future::poll_fn(move |cx| {
+        let mut state = shared.lock().unwrap();
+        match state.result.take() {
+            Some(result) => core::task::Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                core::task::Poll::Pending
+            }
+        }
+    })
+}
+
 /// Schedules the main event loop for termination. This function is meant
 /// to be called from callbacks triggered by the UI. After calling the function,
 /// it will return immediately and once control is passed back to the event loop,
@@ -1085,9 +2109,140 @@ fn error_is_send() {
 
 /// Sets the application id for use on Wayland or X11 with [xdg](https://specifications.freedesktop.org/desktop-entry-spec/latest/)
 /// compliant window managers. This must be set before the window is shown, and has only an effect on Wayland or X11.
+///
+/// Use the name of the application's desktop file without the `.desktop` extension, like `org.example.MyApp`,
+/// so that the window manager and the task bar show the icon of the desktop file. On Wayland, this is the
+/// `app_id` of the windows, and on X11, it's their `WM_CLASS`.
+///
+/// This function returns [`PlatformError::NoPlatform`] when no backend was selected yet. Call it after
+/// creating the first component, or set the application id with `BackendSelector::xdg_app_id()`.
 pub fn set_xdg_app_id(app_id: impl Into<SharedString>) -> Result<(), PlatformError> {
     crate::context::with_global_context(
         || Err(crate::platform::PlatformError::NoPlatform),
         |ctx| ctx.set_xdg_app_id(app_id.into()),
     )
 }
+
+/// Sets the font families to use, in order of preference, for the characters of a script that
+/// the font of a text doesn't cover, instead of the fonts the operating system would pick. The
+/// texts that are already shown are laid out again and redrawn.
+///
+/// `script` is the four letter ISO 15924 code of the script, for example `"Hani"` for Han
+/// characters or `"Arab"` for Arabic. Families that are not installed or registered are
+/// ignored. An empty list removes the preference for the script.
+///
+/// ```rust,no_run
+/// slint::set_script_font_families("Hani", &["Noto Sans SC"]);
+/// slint::set_script_font_families("Arab", &["Vazirmatn"]);
+/// ```
+///
+/// The FemtoVG renderer and the software renderer with system fonts pick the families by the
+/// script of the characters. The Skia renderer and the Qt backend can't tell the scripts apart and
+/// try the families of all scripts, in the order they were set, for the characters the font of the
+/// text doesn't cover. The software renderer uses a single font per text, so it shows the whole
+/// text with the preferred family of the first character that the font of the text doesn't cover.
+pub fn set_script_font_families(script: &str, families: &[&str]) {
+    crate::graphics::set_script_font_families(
+        script,
+        families.iter().map(|family| SharedString::from(*family)).collect(),
+    );
+    // The font requests of all texts depend on this property, so it re-lays them out and redraws them
+    crate::context::GLOBAL_CONTEXT.with(|ctx| {
+        if let Some(ctx) = ctx.get() {
+            ctx.mark_fonts_dirty();
+        }
+    });
+}
+
+/// Copies `text` to the clipboard of the system, replacing its content.
+pub fn set_clipboard_text(text: &str) -> Result<(), PlatformError> {
+    crate::context::with_global_context(
+        || Err(crate::platform::PlatformError::NoPlatform),
+        |ctx| ctx.platform().set_clipboard_text(text, crate::platform::Clipboard::DefaultClipboard),
+    )
+}
+
+/// Returns the text in the clipboard of the system, or `None` if it doesn't contain text.
+pub fn clipboard_text() -> Result<Option<String>, PlatformError> {
+    crate::context::with_global_context(
+        || Err(crate::platform::PlatformError::NoPlatform),
+        |ctx| ctx.platform().clipboard_text(crate::platform::Clipboard::DefaultClipboard),
+    )
+}
+
+/// Copies `image` to the clipboard of the system, replacing its content, for example to paste
+/// a screenshot in another application.
+///
+/// Returns an error if the pixels of the image can't be obtained, for example for images that
+/// were created from borrowed OpenGL textures.
+pub fn set_clipboard_image(image: &crate::graphics::Image) -> Result<(), PlatformError> {
+    let pixels = image
+        .to_rgba8()
+        .ok_or_else(|| PlatformError::Other("The pixels of the image are not available".into()))?;
+    crate::context::with_global_context(
+        || Err(crate::platform::PlatformError::NoPlatform),
+        |ctx| {
+            ctx.platform()
+                .set_clipboard_image(&pixels, crate::platform::Clipboard::DefaultClipboard)
+        },
+    )
+}
+
+/// Returns the image in the clipboard of the system, or `None` if it doesn't contain an image,
+/// or if the backend doesn't support images in the clipboard.
+pub fn clipboard_image() -> Result<Option<crate::graphics::Image>, PlatformError> {
+    crate::context::with_global_context(
+        || Err(crate::platform::PlatformError::NoPlatform),
+        |ctx| {
+            ctx.platform()
+                .clipboard_image(crate::platform::Clipboard::DefaultClipboard)
+                .map(crate::graphics::Image::from_rgba8)
+        },
+    )
+}
+
+/// Copies `html` to the clipboard of the system, replacing its content. Applications that
+/// don't accept HTML paste `alt_text` instead.
+pub fn set_clipboard_html(html: &str, alt_text: &str) -> Result<(), PlatformError> {
+    crate::context::with_global_context(
+        || Err(crate::platform::PlatformError::NoPlatform),
+        |ctx| {
+            ctx.platform().set_clipboard_html(
+                html,
+                alt_text,
+                crate::platform::Clipboard::DefaultClipboard,
+            )
+        },
+    )
+}
+
+/// Returns the HTML in the clipboard of the system, or `None` if it doesn't contain HTML, or
+/// if the backend doesn't support HTML in the clipboard.
+pub fn clipboard_html() -> Result<Option<String>, PlatformError> {
+    crate::context::with_global_context(
+        || Err(crate::platform::PlatformError::NoPlatform),
+        |ctx| ctx.platform().clipboard_html(crate::platform::Clipboard::DefaultClipboard),
+    )
+}
+
+/// Sets the text of the primary selection, which is pasted with the middle mouse button on
+/// X11 and Wayland.
+///
+/// This does nothing on platforms that don't have a primary selection.
+pub fn set_primary_selection_text(text: &str) -> Result<(), PlatformError> {
+    crate::context::with_global_context(
+        || Err(crate::platform::PlatformError::NoPlatform),
+        |ctx| {
+            ctx.platform().set_clipboard_text(text, crate::platform::Clipboard::SelectionClipboard)
+        },
+    )
+}
+
+/// Returns the text of the primary selection, or `None` if it's empty or if the platform
+/// doesn't have a primary selection.
+pub fn primary_selection_text() -> Result<Option<String>, PlatformError> {
+    crate::context::with_global_context(
+        || Err(crate::platform::PlatformError::NoPlatform),
+        |ctx| ctx.platform().clipboard_text(crate::platform::Clipboard::SelectionClipboard),
+    )
+}
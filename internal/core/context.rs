@@ -24,6 +24,9 @@ pub(crate) struct SlintContextInner {
     pub(crate) translations_dirty: core::pin::Pin<Box<Property<usize>>>,
     pub(crate) translations_bundle_languages:
         core::cell::RefCell<Option<alloc::vec::Vec<&'static str>>>,
+    /// This property is read by all font requests, and marked dirty when registered fonts are
+    /// removed or replaced, so that every text gets measured and shaped again.
+    pub(crate) fonts_dirty: core::pin::Pin<Box<Property<()>>>,
     pub(crate) window_shown_hook:
         core::cell::RefCell<Option<Box<dyn FnMut(&Rc<dyn crate::platform::WindowAdapter>)>>>,
     #[cfg(all(unix, not(target_os = "macos")))]
@@ -44,6 +47,7 @@ pub fn new(platform: Box<dyn Platform + 'static>) -> Self {
             window_count: 0.into(),
             translations_dirty: Box::pin(Property::new_named(0, "SlintContext::translations")),
             translations_bundle_languages: Default::default(),
+            fonts_dirty: Box::pin(Property::new_named((), "SlintContext::fonts")),
             window_shown_hook: Default::default(),
             #[cfg(all(unix, not(target_os = "macos")))]
             xdg_app_id: Default::default(),
@@ -90,6 +94,11 @@ pub fn xdg_app_id(&self) -> Option<crate::SharedString> {
     pub fn xdg_app_id(&self) -> Option<crate::SharedString> {
         None
     }
+
+    /// Lays out and shapes all texts again, after registered fonts were removed or replaced.
+    pub fn mark_fonts_dirty(&self) {
+        self.0.fonts_dirty.mark_dirty();
+    }
 }
 
 /// Internal function to access the context.
@@ -19,6 +19,8 @@
 use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
 use alloc::format;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 pub use euclid;
 /// 2D Rectangle
@@ -136,6 +138,100 @@ pub fn clear(&mut self) {
         self.generation += 1;
     }
 }
+/// A filter applied to the rendered content of a window to simulate how it is perceived with a
+/// vision deficiency, for example to check that a color palette remains readable.
+///
+/// Apply it with [`Window::set_vision_simulation()`](crate::api::Window::set_vision_simulation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum VisionSimulation {
+    /// The content is rendered unchanged.
+    #[default]
+    None,
+    /// Simulates the absence of red cones (red-green color blindness).
+    Protanopia,
+    /// Simulates the absence of green cones (red-green color blindness).
+    Deuteranopia,
+    /// Simulates the absence of blue cones (blue-yellow color blindness).
+    Tritanopia,
+    /// Blurs the content to simulate low visual acuity.
+    Blur,
+}
+
+impl VisionSimulation {
+    /// Returns the row-major 3x3 matrix to apply to the linear red, green, and blue channels to
+    /// simulate the color vision deficiency, or `None` if this simulation doesn't change the colors.
+    ///
+    /// The matrix applies to linear light: the sRGB components must be linearized before, and
+    /// encoded again after, as done by [`Self::simulate_color()`].
+    ///
+    /// The matrices are the ones for a severity of 1.0 from Machado, Oliveira and Fernandes,
+    /// "A Physiologically-based Model for Simulation of Color Vision Deficiency" (2009).
+    pub fn color_matrix(self) -> Option<[[f32; 3]; 3]> {
+        match self {
+            Self::Protanopia => Some([
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ]),
+            Self::Deuteranopia => Some([
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ]),
+            Self::Tritanopia => Some([
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ]),
+            Self::None | Self::Blur => None,
+        }
+    }
+
+    /// Returns the color as perceived with the color vision deficiency.
+    pub fn simulate_color(self, color: Color) -> Color {
+        #[cfg(not(feature = "std"))]
+        use num_traits::float::Float;
+        let Some(matrix) = self.color_matrix() else { return color };
+        let to_linear = |c: u8| {
+            let c = c as f32 / 255.;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let to_srgb = |l: f32| {
+            let l = l.clamp(0., 1.);
+            let c = if l <= 0.0031308 { l * 12.92 } else { 1.055 * l.powf(1. / 2.4) - 0.055 };
+            (c * 255.).round() as u8
+        };
+        let rgb = [to_linear(color.red()), to_linear(color.green()), to_linear(color.blue())];
+        let [r, g, b] =
+            matrix.map(|row| to_srgb(row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]));
+        Color::from_argb_u8(color.alpha(), r, g, b)
+    }
+}
+
+#[test]
+fn vision_simulation_in_linear_light() {
+    let red = Color::from_rgb_u8(255, 0, 0);
+    assert_eq!(VisionSimulation::Protanopia.simulate_color(red), Color::from_rgb_u8(109, 95, 0));
+    let blue = Color::from_argb_u8(128, 0, 0, 255);
+    assert_eq!(
+        VisionSimulation::Protanopia.simulate_color(blue),
+        Color::from_argb_u8(128, 0, 89, 255)
+    );
+    // The rows of the matrices sum to 1, so the grays are unchanged
+    let gray = Color::from_rgb_u8(128, 128, 128);
+    for simulation in
+        [VisionSimulation::Protanopia, VisionSimulation::Deuteranopia, VisionSimulation::Tritanopia]
+    {
+        assert_eq!(simulation.simulate_color(gray), gray);
+    }
+    assert_eq!(VisionSimulation::Blur.simulate_color(red), red);
+}
+
 /// FontRequest collects all the developer-configurable properties for fonts, such as family, weight, etc.
 /// It is submitted as a request to the platform font system (i.e. CoreText on macOS) and in exchange the
 /// backend returns a `Box<dyn Font>`.
@@ -153,6 +249,67 @@ pub struct FontRequest {
     pub letter_spacing: Option<LogicalLength>,
     /// Whether to select an italic face of the font family.
     pub italic: bool,
+    /// The OpenType features to enable or disable when shaping the text.
+    pub features: Vec<FontFeature>,
+}
+
+/// An OpenType feature to apply when shaping text, such as `tnum` for tabular numerals or `liga`
+/// for standard ligatures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FontFeature {
+    /// The four letter tag of the feature.
+    pub tag: [u8; 4],
+    /// The value of the feature. `0` disables the feature, `1` enables it, and higher values
+    /// select an alternate for features such as `salt`.
+    pub value: u32,
+}
+
+impl FontFeature {
+    /// Returns the tag of the feature as a string.
+    pub fn tag_str(&self) -> &str {
+        core::str::from_utf8(&self.tag).unwrap_or_default()
+    }
+
+    /// Parses a comma separated list of features with the syntax of the CSS `font-feature-settings`
+    /// property, for example `"tnum, liga 0, ss01 on"`. A feature without value is enabled.
+    /// Entries that are not valid are ignored.
+    pub fn parse_list(features: &str) -> Vec<Self> {
+        features
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split_whitespace();
+                let tag = parts.next()?.trim_matches(|c| c == '"' || c == '\'');
+                let value = match parts.next() {
+                    None | Some("on") => 1,
+                    Some("off") => 0,
+                    Some(value) => value.parse().ok()?,
+                };
+                if parts.next().is_some() {
+                    return None;
+                }
+                let tag: [u8; 4] = tag.as_bytes().try_into().ok()?;
+                tag.iter().all(|c| c.is_ascii_graphic()).then_some(Self { tag, value })
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn parse_font_features() {
+    let features =
+        FontFeature::parse_list("tnum, liga 0, \"ss01\" on,smcp off, salt 3, toolong, kern x");
+    assert_eq!(
+        features,
+        [
+            FontFeature { tag: *b"tnum", value: 1 },
+            FontFeature { tag: *b"liga", value: 0 },
+            FontFeature { tag: *b"ss01", value: 1 },
+            FontFeature { tag: *b"smcp", value: 0 },
+            FontFeature { tag: *b"salt", value: 3 },
+        ]
+    );
+    assert_eq!(features[2].tag_str(), "ss01");
+    assert!(FontFeature::parse_list("").is_empty());
 }
 
 #[cfg(feature = "shared-fontdb")]
@@ -168,6 +325,76 @@ pub fn to_fontdb_query(&self) -> i_slint_common::sharedfontdb::fontdb::Query<'_>
     }
 }
 
+thread_local! {
+    /// The font families preferred for the characters of a script, by ISO 15924 code (such as
+    /// "Hani"), in the order the scripts were set.
+    static SCRIPT_FONT_FAMILIES: core::cell::RefCell<Vec<(SharedString, Vec<SharedString>)>> =
+        Default::default()
+}
+
+/// Sets the font families to try first, in order, for the characters of the script with the given
+/// ISO 15924 code, see `slint::set_script_font_families()`. The code is case insensitive. An empty
+/// list removes the preference.
+pub fn set_script_font_families(script: &str, families: Vec<SharedString>) {
+    let mut chars = script.chars();
+    let script: SharedString = chars
+        .next()
+        .map(|first| first.to_ascii_uppercase())
+        .into_iter()
+        .chain(chars.map(|ch| ch.to_ascii_lowercase()))
+        .collect::<alloc::string::String>()
+        .into();
+    SCRIPT_FONT_FAMILIES.with(|scripts| {
+        let mut scripts = scripts.borrow_mut();
+        scripts.retain(|(s, _)| *s != script);
+        if !families.is_empty() {
+            scripts.push((script, families));
+        }
+    });
+}
+
+/// Returns the font families preferred for the characters of the script with the given ISO 15924
+/// code, such as `Hani` or `Arab`.
+pub fn script_font_families(script: &str) -> Vec<SharedString> {
+    SCRIPT_FONT_FAMILIES.with(|scripts| {
+        scripts
+            .borrow()
+            .iter()
+            .find(|(s, _)| s == script)
+            .map(|(_, families)| families.clone())
+            .unwrap_or_default()
+    })
+}
+
+/// Returns the font families preferred for all scripts, without duplicates, for the renderers that
+/// fall back to a list of families for any character the requested font doesn't cover.
+pub fn all_script_font_families() -> Vec<SharedString> {
+    SCRIPT_FONT_FAMILIES.with(|scripts| {
+        let mut all = Vec::<SharedString>::new();
+        for family in scripts.borrow().iter().flat_map(|(_, families)| families) {
+            if !all.contains(family) {
+                all.push(family.clone());
+            }
+        }
+        all
+    })
+}
+
+#[test]
+fn test_script_font_families() {
+    set_script_font_families("hani", alloc::vec!["Noto Sans SC".into(), "Noto Sans".into()]);
+    set_script_font_families("ARAB", alloc::vec!["Vazirmatn".into(), "Noto Sans".into()]);
+    assert_eq!(script_font_families("Hani"), ["Noto Sans SC", "Noto Sans"]);
+    assert_eq!(script_font_families("Arab"), ["Vazirmatn", "Noto Sans"]);
+    assert!(script_font_families("Latn").is_empty());
+    assert_eq!(all_script_font_families(), ["Noto Sans SC", "Noto Sans", "Vazirmatn"]);
+
+    set_script_font_families("Hani", Vec::new());
+    assert!(script_font_families("Hani").is_empty());
+    assert_eq!(all_script_font_families(), ["Vazirmatn", "Noto Sans"]);
+    set_script_font_families("Arab", Vec::new());
+}
+
 /// Internal enum to specify which version of OpenGL to request
 /// from the windowing system.
 #[derive(Debug, Clone, PartialEq)]
@@ -5,10 +5,10 @@
 This module contains brush related types for the run-time library.
 */
 
-use super::Color;
+use super::{Color, Image};
 use crate::properties::InterpolatedPropertyValue;
 use crate::SharedVector;
-use euclid::default::{Point2D, Size2D};
+use euclid::default::{Point2D, Size2D, Transform2D};
 
 #[cfg(not(feature = "std"))]
 use num_traits::float::Float;
@@ -29,6 +29,12 @@ pub enum Brush {
     /// The radial gradient variant of a brush describes a circle variant centered
     /// in the middle
     RadialGradient(RadialGradientBrush),
+    /// The conic gradient variant of a brush describes the gradient stops for a fill
+    /// where the colors are swept clockwise around the center, starting at the specified angle.
+    ConicGradient(ConicGradientBrush),
+    /// The image pattern variant of a brush fills the shape with an image, that's transformed
+    /// and optionally repeated.
+    ImagePattern(ImagePatternBrush),
 }
 
 /// Construct a brush with transparent color
@@ -41,6 +47,7 @@ fn default() -> Self {
 impl Brush {
     /// If the brush is SolidColor, the contained color is returned.
     /// If the brush is a LinearGradient, the color of the first stop is returned.
+    /// If the brush is an ImagePattern, a transparent color is returned.
     pub fn color(&self) -> Color {
         match self {
             Brush::SolidColor(col) => *col,
@@ -50,6 +57,10 @@ pub fn color(&self) -> Color {
             Brush::RadialGradient(gradient) => {
                 gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
             }
+            Brush::ConicGradient(gradient) => {
+                gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
+            }
+            Brush::ImagePattern(_) => Color::default(),
         }
     }
 
@@ -66,6 +77,8 @@ pub fn is_transparent(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 0,
             Brush::LinearGradient(_) => false,
             Brush::RadialGradient(_) => false,
+            Brush::ConicGradient(_) => false,
+            Brush::ImagePattern(pattern) => pattern.opacity() <= 0.,
         }
     }
 
@@ -82,6 +95,8 @@ pub fn is_opaque(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 255,
             Brush::LinearGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
             Brush::RadialGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
+            Brush::ConicGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
+            Brush::ImagePattern(_) => false,
         }
     }
 
@@ -104,6 +119,14 @@ pub fn brighter(&self, factor: f32) -> Self {
                     GradientStop { color: s.color.brighter(factor), position: s.position }
                 })))
             }
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
+                g.stops().map(|s| GradientStop {
+                    color: s.color.brighter(factor),
+                    position: s.position,
+                }),
+            )),
+            Brush::ImagePattern(_) => self.clone(),
         }
     }
 
@@ -123,6 +146,12 @@ pub fn darker(&self, factor: f32) -> Self {
                 g.stops()
                     .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
             )),
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
+                g.stops()
+                    .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
+            )),
+            Brush::ImagePattern(_) => self.clone(),
         }
     }
 
@@ -147,6 +176,16 @@ pub fn transparentize(&self, amount: f32) -> Self {
                     GradientStop { color: s.color.transparentize(amount), position: s.position }
                 })))
             }
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
+                g.stops().map(|s| GradientStop {
+                    color: s.color.transparentize(amount),
+                    position: s.position,
+                }),
+            )),
+            Brush::ImagePattern(pattern) => {
+                Brush::ImagePattern(pattern.clone().with_opacity(pattern.opacity() * (1. - amount)))
+            }
         }
     }
 
@@ -168,6 +207,16 @@ pub fn with_alpha(&self, alpha: f32) -> Self {
                     GradientStop { color: s.color.with_alpha(alpha), position: s.position }
                 })))
             }
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
+                g.stops().map(|s| GradientStop {
+                    color: s.color.with_alpha(alpha),
+                    position: s.position,
+                }),
+            )),
+            Brush::ImagePattern(pattern) => {
+                Brush::ImagePattern(pattern.clone().with_opacity(alpha))
+            }
         }
     }
 }
@@ -221,6 +270,186 @@ pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
     }
 }
 
+/// The ConicGradientBrush describes a way of filling a shape with colors that are interpolated
+/// around the center of the shape, clockwise, starting from the top rotated by the gradient's angle.
+#[derive(Clone, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct ConicGradientBrush(SharedVector<GradientStop>);
+
+impl ConicGradientBrush {
+    /// Creates a new conic gradient, described by the angle of the start of the gradient and the
+    /// provided color stops.
+    ///
+    /// The angle needs to be specified in degrees, clockwise from the top. The positions of the
+    /// stops are fractions of a full turn, between 0 and 1, from the start angle.
+    pub fn new(from_angle: f32, stops: impl IntoIterator<Item = GradientStop>) -> Self {
+        let stop_iter = stops.into_iter();
+        let mut encoded_angle_and_stops = SharedVector::with_capacity(stop_iter.size_hint().0 + 1);
+        // The gradient's first stop is a fake stop to store the angle
+        encoded_angle_and_stops
+            .push(GradientStop { color: Default::default(), position: from_angle });
+        encoded_angle_and_stops.extend(stop_iter);
+        Self(encoded_angle_and_stops)
+    }
+    /// Returns the angle at which the conic gradient starts, in degrees.
+    pub fn from_angle(&self) -> f32 {
+        self.0[0].position
+    }
+    /// Returns the color stops of the conic gradient.
+    pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
+        // skip the first fake stop that just contains the angle
+        self.0.iter().skip(1)
+    }
+    /// Returns the color of the gradient in the direction of `angle`, in degrees clockwise from the top.
+    pub fn color_at_angle(&self, angle: f32) -> Color {
+        let turns = (angle - self.from_angle()) / 360.;
+        let position = turns - turns.floor();
+        let mut previous: Option<&GradientStop> = None;
+        for stop in self.stops() {
+            if stop.position >= position {
+                return match previous {
+                    Some(previous) if stop.position > previous.position => {
+                        previous.color.interpolate(
+                            &stop.color,
+                            (position - previous.position) / (stop.position - previous.position),
+                        )
+                    }
+                    _ => stop.color,
+                };
+            }
+            previous = Some(stop);
+        }
+        previous.map(|stop| stop.color).unwrap_or_default()
+    }
+}
+
+/// The ImagePatternBrush describes a way of filling a shape with an image. By default, the image
+/// starts at the top-left corner of the shape and is repeated in both directions, with one pixel of
+/// the image covering one logical pixel.
+#[derive(Clone, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct ImagePatternBrush(SharedVector<ImagePatternData>);
+
+/// The data of an [`ImagePatternBrush`], shared between the copies of the brush.
+/// cbindgen:derive-eq=false
+/// cbindgen:derive-neq=false
+#[doc(hidden)]
+#[derive(Clone, PartialEq, Debug)]
+#[repr(C)]
+pub struct ImagePatternData {
+    image: Image,
+    offset_x: f32,
+    offset_y: f32,
+    scale_x: f32,
+    scale_y: f32,
+    rotation: f32,
+    opacity: f32,
+    repeat_x: bool,
+    repeat_y: bool,
+}
+
+impl ImagePatternBrush {
+    /// Creates a new image pattern that repeats the image in both directions.
+    pub fn new(image: Image) -> Self {
+        Self(SharedVector::from_slice(&[ImagePatternData {
+            image,
+            offset_x: 0.,
+            offset_y: 0.,
+            scale_x: 1.,
+            scale_y: 1.,
+            rotation: 0.,
+            opacity: 1.,
+            repeat_x: true,
+            repeat_y: true,
+        }]))
+    }
+
+    fn pattern_mut(&mut self) -> &mut ImagePatternData {
+        &mut self.0.make_mut_slice()[0]
+    }
+
+    /// Returns a copy of this pattern with the top-left corner of the image moved by the specified
+    /// offset, in logical pixels, relative to the top-left corner of the shape.
+    #[must_use]
+    pub fn with_offset(mut self, x: f32, y: f32) -> Self {
+        let pattern = self.pattern_mut();
+        (pattern.offset_x, pattern.offset_y) = (x, y);
+        self
+    }
+
+    /// Returns a copy of this pattern with the image scaled by the specified factors.
+    #[must_use]
+    pub fn with_scale(mut self, x: f32, y: f32) -> Self {
+        let pattern = self.pattern_mut();
+        (pattern.scale_x, pattern.scale_y) = (x, y);
+        self
+    }
+
+    /// Returns a copy of this pattern with the image rotated clockwise by the specified angle, in
+    /// degrees, around its top-left corner.
+    #[must_use]
+    pub fn with_rotation(mut self, angle: f32) -> Self {
+        self.pattern_mut().rotation = angle;
+        self
+    }
+
+    /// Returns a copy of this pattern that repeats the image horizontally and vertically, or draws
+    /// it only once in the directions where the repetition is disabled.
+    #[must_use]
+    pub fn with_repeat(mut self, horizontal: bool, vertical: bool) -> Self {
+        let pattern = self.pattern_mut();
+        (pattern.repeat_x, pattern.repeat_y) = (horizontal, vertical);
+        self
+    }
+
+    /// Returns a copy of this pattern with the image drawn with the specified opacity, between 0 and 1.
+    #[must_use]
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.pattern_mut().opacity = opacity.clamp(0., 1.);
+        self
+    }
+
+    /// Returns the image of the pattern.
+    pub fn image(&self) -> &Image {
+        &self.0[0].image
+    }
+
+    /// Returns the offset of the image, in logical pixels.
+    pub fn offset(&self) -> (f32, f32) {
+        (self.0[0].offset_x, self.0[0].offset_y)
+    }
+
+    /// Returns the horizontal and vertical scale factors of the image.
+    pub fn scale(&self) -> (f32, f32) {
+        (self.0[0].scale_x, self.0[0].scale_y)
+    }
+
+    /// Returns the clockwise rotation of the image, in degrees.
+    pub fn rotation(&self) -> f32 {
+        self.0[0].rotation
+    }
+
+    /// Returns whether the image is repeated horizontally and vertically.
+    pub fn repeat(&self) -> (bool, bool) {
+        (self.0[0].repeat_x, self.0[0].repeat_y)
+    }
+
+    /// Returns the opacity of the image.
+    pub fn opacity(&self) -> f32 {
+        self.0[0].opacity
+    }
+
+    /// Returns the transformation that maps the pixels of the image to the logical coordinates of
+    /// the shape: the image is scaled, then rotated, and then moved by the offset.
+    pub fn transform(&self) -> Transform2D<f32> {
+        let (scale_x, scale_y) = self.scale();
+        let (offset_x, offset_y) = self.offset();
+        Transform2D::scale(scale_x, scale_y)
+            .then_rotate(euclid::Angle::degrees(self.rotation()))
+            .then_translate(euclid::vec2(offset_x, offset_y))
+    }
+}
+
 /// GradientStop describes a single color stop in a gradient. The colors between multiple
 /// stops are interpolated.
 #[repr(C)]
@@ -330,8 +559,65 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
                     Brush::RadialGradient(new_grad)
                 }
             }
-            (a @ Brush::LinearGradient(_), b @ Brush::RadialGradient(_))
-            | (a @ Brush::RadialGradient(_), b @ Brush::LinearGradient(_)) => {
+            (Brush::SolidColor(col), Brush::ConicGradient(grad)) => {
+                let mut new_grad = grad.clone();
+                for x in new_grad.0.make_mut_slice().iter_mut().skip(1) {
+                    x.color = col.interpolate(&x.color, t);
+                }
+                Brush::ConicGradient(new_grad)
+            }
+            (a @ Brush::ConicGradient(_), b @ Brush::SolidColor(_)) => {
+                Self::interpolate(b, a, 1. - t)
+            }
+            (Brush::ConicGradient(lhs), Brush::ConicGradient(rhs)) => {
+                if lhs.0.len() < rhs.0.len() {
+                    Self::interpolate(target_value, self, 1. - t)
+                } else {
+                    let mut new_grad = lhs.clone();
+                    let mut iter = new_grad.0.make_mut_slice().iter_mut();
+                    {
+                        let angle = &mut iter.next().unwrap().position;
+                        *angle = angle.interpolate(&rhs.from_angle(), t);
+                    }
+                    for s2 in rhs.stops() {
+                        let s1 = iter.next().unwrap();
+                        s1.color = s1.color.interpolate(&s2.color, t);
+                        s1.position = s1.position.interpolate(&s2.position, t);
+                    }
+                    for x in iter {
+                        x.position = x.position.interpolate(&1.0, t);
+                    }
+                    Brush::ConicGradient(new_grad)
+                }
+            }
+            (Brush::ImagePattern(lhs), Brush::ImagePattern(rhs)) if lhs.image() == rhs.image() => {
+                let (lhs_offset, rhs_offset) = (lhs.offset(), rhs.offset());
+                let (lhs_scale, rhs_scale) = (lhs.scale(), rhs.scale());
+                let (repeat_x, repeat_y) = if t < 0.5 { lhs.repeat() } else { rhs.repeat() };
+                Brush::ImagePattern(
+                    lhs.clone()
+                        .with_offset(
+                            lhs_offset.0.interpolate(&rhs_offset.0, t),
+                            lhs_offset.1.interpolate(&rhs_offset.1, t),
+                        )
+                        .with_scale(
+                            lhs_scale.0.interpolate(&rhs_scale.0, t),
+                            lhs_scale.1.interpolate(&rhs_scale.1, t),
+                        )
+                        .with_rotation(lhs.rotation().interpolate(&rhs.rotation(), t))
+                        .with_opacity(lhs.opacity().interpolate(&rhs.opacity(), t))
+                        .with_repeat(repeat_x, repeat_y),
+                )
+            }
+            (Brush::ImagePattern(_), _) | (_, Brush::ImagePattern(_)) => {
+                // Images can't be blended with other brushes, so switch half-way.
+                if t < 0.5 {
+                    self.clone()
+                } else {
+                    target_value.clone()
+                }
+            }
+            (a, b) => {
                 // Just go to an intermediate color.
                 let color = Color::interpolate(&b.color(), &a.color(), t);
                 if t < 0.5 {
@@ -356,4 +642,48 @@ fn test_linear_gradient_encoding() {
     let grad = LinearGradientBrush::new(256., stops.clone());
     assert_eq!(grad.angle(), 256.);
     assert!(grad.stops().eq(stops.iter()));
+    let grad = ConicGradientBrush::new(90., stops.clone());
+    assert_eq!(grad.from_angle(), 90.);
+    assert!(grad.stops().eq(stops.iter()));
+}
+
+#[test]
+fn test_conic_gradient_color_at_angle() {
+    let grad = ConicGradientBrush::new(
+        90.,
+        [
+            GradientStop { position: 0.0, color: Color::from_rgb_u8(255, 0, 0) },
+            GradientStop { position: 0.5, color: Color::from_rgb_u8(0, 0, 255) },
+        ],
+    );
+    assert_eq!(grad.color_at_angle(90.), Color::from_rgb_u8(255, 0, 0));
+    assert_eq!(grad.color_at_angle(180.), grad.color_at_angle(-180.));
+    assert_eq!(grad.color_at_angle(270.), Color::from_rgb_u8(0, 0, 255));
+    // After the last stop
+    assert_eq!(grad.color_at_angle(0.), Color::from_rgb_u8(0, 0, 255));
+    let half_way = grad.color_at_angle(180.);
+    assert!(half_way.red() > 100 && half_way.red() < 155 && half_way.green() == 0);
+}
+
+#[test]
+#[allow(clippy::float_cmp)] // The interpolated values are exact
+fn test_image_pattern() {
+    let image = Image::from_rgba8(super::SharedPixelBuffer::new(2, 2));
+    let pattern = ImagePatternBrush::new(image.clone())
+        .with_offset(10., 20.)
+        .with_scale(2., 2.)
+        .with_repeat(true, false);
+    assert_eq!(pattern.transform().transform_point(Point2D::new(1., 1.)), Point2D::new(12., 22.));
+    assert_eq!(pattern.repeat(), (true, false));
+
+    let brush = Brush::ImagePattern(pattern.clone());
+    assert!(!brush.is_transparent());
+    assert!(brush.transparentize(1.).is_transparent());
+    assert_eq!(brush.with_alpha(0.5), Brush::ImagePattern(pattern.clone().with_opacity(0.5)));
+
+    let target = Brush::ImagePattern(pattern.clone().with_offset(20., 40.).with_opacity(0.));
+    let Brush::ImagePattern(half_way) = brush.interpolate(&target, 0.5) else { panic!() };
+    assert_eq!(half_way.offset(), (15., 30.));
+    assert_eq!(half_way.opacity(), 0.5);
+    assert_eq!(brush.interpolate(&Brush::SolidColor(Color::default()), 0.25), brush);
 }
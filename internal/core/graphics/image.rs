@@ -14,8 +14,12 @@
 
 #[cfg(feature = "image-decoders")]
 pub mod cache;
+#[cfg(feature = "image-heif")]
+mod heif;
 #[cfg(target_arch = "wasm32")]
 mod htmlimage;
+#[cfg(feature = "image-decoders")]
+mod loader;
 #[cfg(feature = "svg")]
 mod svg;
 
@@ -48,6 +52,11 @@ pub struct OpaqueImageVTable {
     pub static NINE_SLICE_VT for NineSliceImage
 }
 
+OpaqueImageVTable_static! {
+    /// VTable for RC wrapped animated image helper struct.
+    pub static ANIMATED_IMAGE_VT for AnimatedImage
+}
+
 /// SharedPixelBuffer is a container for storing image data as pixels. It is
 /// internally reference counted and cheap to clone.
 ///
@@ -323,6 +332,9 @@ pub enum ImageCacheKey {
     URL(SharedString) = 2,
     /// The image is identified by the static address of its encoded data.
     EmbeddedData(usize) = 3,
+    /// The image is identified by its path on the file system and the last modification time stamp,
+    /// and by the maximum size it was scaled down to when decoding it.
+    ScaledPath(CachedPath, IntSize) = 4,
 }
 
 impl ImageCacheKey {
@@ -343,6 +355,7 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(..) => return None,
             ImageInner::NineSlice(nine) => vtable::VRc::borrow(nine).cache_key(),
+            ImageInner::AnimatedImage(animated) => vtable::VRc::borrow(animated).cache_key(),
         };
         if matches!(key, ImageCacheKey::Invalid) {
             None
@@ -376,6 +389,43 @@ fn cache_key(&self) -> ImageCacheKey {
     }
 }
 
+/// Represent an image with several frames, such as an animated GIF, with the duration of each frame
+pub struct AnimatedImage {
+    frames: alloc::vec::Vec<(ImageInner, core::time::Duration)>,
+    cache_key: ImageCacheKey,
+}
+
+impl AnimatedImage {
+    /// Creates an animated image from its frames and the time each of them is shown.
+    /// There must be at least one frame.
+    pub fn new(
+        frames: alloc::vec::Vec<(ImageInner, core::time::Duration)>,
+        cache_key: ImageCacheKey,
+    ) -> Self {
+        debug_assert!(!frames.is_empty());
+        Self { frames, cache_key }
+    }
+
+    /// Returns the number of frames
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns the frame at the given index, and the time it is shown.
+    pub fn frame(&self, index: usize) -> Option<(&ImageInner, core::time::Duration)> {
+        self.frames.get(index).map(|(frame, duration)| (frame, *duration))
+    }
+}
+
+impl OpaqueImage for AnimatedImage {
+    fn size(&self) -> IntSize {
+        self.frames.first().map_or_else(Default::default, |(frame, _)| frame.size())
+    }
+    fn cache_key(&self) -> ImageCacheKey {
+        self.cache_key.clone()
+    }
+}
+
 /// A resource is a reference to binary data, for example images. They can be accessible on the file
 /// system or embedded in the resulting binary. Or they might be URLs to a web server and a downloaded
 /// is necessary before they can be used.
@@ -400,6 +450,7 @@ pub enum ImageInner {
     #[cfg(not(target_arch = "wasm32"))]
     BorrowedOpenGLTexture(BorrowedOpenGLTexture) = 6,
     NineSlice(vtable::VRc<OpaqueImageVTable, NineSliceImage>) = 7,
+    AnimatedImage(vtable::VRc<OpaqueImageVTable, AnimatedImage>) = 8,
 }
 
 impl ImageInner {
@@ -488,6 +539,9 @@ pub fn render_to_buffer(
                 Some(SharedImageBuffer::RGBA8Premultiplied(buffer))
             }
             ImageInner::NineSlice(nine) => nine.0.render_to_buffer(None),
+            ImageInner::AnimatedImage(animated) => {
+                animated.frame(0)?.0.render_to_buffer(_target_size_for_scalable_source)
+            }
             _ => None,
         }
     }
@@ -517,6 +571,7 @@ pub fn size(&self) -> IntSize {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(BorrowedOpenGLTexture { size, .. }) => *size,
             ImageInner::NineSlice(nine) => nine.0.size(),
+            ImageInner::AnimatedImage(animated) => vtable::VRc::borrow(animated).size(),
         }
     }
 }
@@ -537,6 +592,7 @@ fn eq(&self, other: &Self) -> bool {
             #[cfg(not(target_arch = "wasm32"))]
             (Self::BorrowedOpenGLTexture(l0), Self::BorrowedOpenGLTexture(r0)) => l0 == r0,
             (Self::NineSlice(l), Self::NineSlice(r)) => l.0 == r.0 && l.1 == r.1,
+            (Self::AnimatedImage(l0), Self::AnimatedImage(r0)) => vtable::VRc::ptr_eq(l0, r0),
             _ => false,
         }
     }
@@ -671,6 +727,82 @@ pub fn load_from_path(path: &std::path::Path) -> Result<Self, LoadImageError> {
         })
     }
 
+    #[cfg(feature = "image-decoders")]
+    /// Load an Image from a path to a file containing an image, like [`Self::load_from_path()`], but read and
+    /// decode the file in a worker thread.
+    ///
+    /// The returned future resolves to the image once it's decoded, so that loading many images, such as the
+    /// thumbnails of a long list, doesn't block the event loop. Await it in the Slint thread, for example in a
+    /// future spawned with `slint::spawn_local()`, and use [`crate::items::ImageLoadingStatus`] to show the state
+    /// of the image in the UI until it's loaded.
+    ///
+    /// If the image is in the image cache already, the future resolves right away.
+    ///
+    /// ```rust,no_run
+    /// # use i_slint_core::graphics::Image;
+    /// # use i_slint_core::items::ImageLoadingStatus;
+    /// # async fn example() {
+    /// let (image, status) = match Image::load_from_path_async("cat.png".as_ref()).await {
+    ///     Ok(image) => (image, ImageLoadingStatus::Loaded),
+    ///     Err(_) => (Image::default(), ImageLoadingStatus::Error),
+    /// };
+    /// # }
+    /// ```
+    pub fn load_from_path_async(
+        path: &std::path::Path,
+    ) -> impl core::future::Future<Output = Result<Self, LoadImageError>> + 'static {
+        loader::load_from_path(path, None)
+    }
+
+    #[cfg(feature = "image-decoders")]
+    /// Load an Image from a path to a file containing an image, scaled down to fit in `max_size` while keeping its
+    /// aspect ratio. A width or height of zero doesn't limit that dimension.
+    ///
+    /// Use this to show large photos as thumbnails: the image is scaled down while it's decoded if the format
+    /// supports it, such as JPEG, so that the full size image is never kept in memory. Images smaller than
+    /// `max_size` and SVG images are loaded at their original size.
+    ///
+    /// ```rust,no_run
+    /// # use i_slint_core::graphics::{Image, IntSize};
+    /// let thumbnail =
+    ///     Image::load_from_path_with_max_size("photo.jpg".as_ref(), IntSize::new(200, 200)).unwrap();
+    /// assert!(thumbnail.size().width <= 200 && thumbnail.size().height <= 200);
+    /// ```
+    pub fn load_from_path_with_max_size(
+        path: &std::path::Path,
+        max_size: IntSize,
+    ) -> Result<Self, LoadImageError> {
+        self::cache::IMAGE_CACHE.with(|global_cache| {
+            let path: SharedString = path.to_str().ok_or(LoadImageError(()))?.into();
+            global_cache
+                .borrow_mut()
+                .load_scaled_image_from_path(&path, max_size)
+                .ok_or(LoadImageError(()))
+        })
+    }
+
+    #[cfg(feature = "image-decoders")]
+    /// Load an Image from a path to a file containing an image, scaled down to fit in `max_size`, like
+    /// [`Self::load_from_path_with_max_size()`], but read and decode the file in a worker thread like
+    /// [`Self::load_from_path_async()`].
+    pub fn load_from_path_with_max_size_async(
+        path: &std::path::Path,
+        max_size: IntSize,
+    ) -> impl core::future::Future<Output = Result<Self, LoadImageError>> + 'static {
+        loader::load_from_path(path, Some(max_size))
+    }
+
+    #[cfg(feature = "image-decoders")]
+    /// Load an Image from the encoded data of an image, such as the body of an HTTP response, and decode it in a
+    /// worker thread. The format is detected from the data.
+    ///
+    /// See [`Self::load_from_path_async()`].
+    pub fn load_from_data_async(
+        data: alloc::vec::Vec<u8>,
+    ) -> impl core::future::Future<Output = Result<Self, LoadImageError>> + 'static {
+        loader::load_from_data(data)
+    }
+
     /// Creates a new Image from the specified shared pixel buffer, where each pixel has three color
     /// channels (red, green and blue) encoded as u8.
     pub fn from_rgb8(buffer: SharedPixelBuffer<Rgb8Pixel>) -> Self {
@@ -817,6 +949,67 @@ pub fn load_from_svg_data(buffer: &[u8]) -> Result<Self, LoadImageError> {
         ))))
     }
 
+    /// Returns a copy of this SVG image in which `currentColor` is `color`.
+    ///
+    /// Monochrome icons usually draw with `currentColor`, so this recolors them at runtime, for example
+    /// to follow the colors of the theme with a single set of icons. The colors set explicitly in the
+    /// document are unchanged.
+    ///
+    /// Returns an error if this image isn't an SVG image.
+    #[cfg(feature = "svg")]
+    pub fn with_svg_color(&self, color: crate::Color) -> Result<Self, LoadImageError> {
+        match &self.0 {
+            ImageInner::Svg(svg) => Ok(Image(ImageInner::Svg(vtable::VRc::new(
+                svg.with_color(color).map_err(|_| LoadImageError(()))?,
+            )))),
+            _ => Err(LoadImageError(())),
+        }
+    }
+
+    /// Returns a copy of this SVG image in which the CSS custom properties used with `var()` in the
+    /// document have the given values. The names are given without the leading `--`.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::Image;
+    /// let svg = r#"<svg width="16" height="16" xmlns="http://www.w3.org/2000/svg">
+    ///     <rect width="16" height="16" fill="var(--accent, black)"/>
+    /// </svg>"#;
+    /// let icon = Image::load_from_svg_data(svg.as_bytes()).unwrap();
+    /// let red_icon = icon.with_svg_variables([("accent", "#ff0000")]).unwrap();
+    /// ```
+    ///
+    /// Returns an error if this image isn't an SVG image, or if the SVG can't be parsed with the values.
+    #[cfg(feature = "svg")]
+    pub fn with_svg_variables<'a>(
+        &self,
+        variables: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Self, LoadImageError> {
+        match &self.0 {
+            ImageInner::Svg(svg) => Ok(Image(ImageInner::Svg(vtable::VRc::new(
+                svg.with_variables(
+                    variables.into_iter().map(|(name, value)| (name.into(), value.into())),
+                )
+                .map_err(|_| LoadImageError(()))?,
+            )))),
+            _ => Err(LoadImageError(())),
+        }
+    }
+
+    /// Returns a copy of this SVG image with a different intrinsic size: the size of the document is
+    /// scaled to fit in `size` while keeping its aspect ratio. The image is rendered at that size when
+    /// it's not scaled, for example with `image-fit: preserve`.
+    ///
+    /// Returns an error if this image isn't an SVG image or if `size` is empty.
+    #[cfg(feature = "svg")]
+    pub fn with_svg_size(&self, size: IntSize) -> Result<Self, LoadImageError> {
+        match &self.0 {
+            ImageInner::Svg(svg) => Ok(Image(ImageInner::Svg(vtable::VRc::new(
+                svg.with_size(size).ok_or(LoadImageError(()))?,
+            )))),
+            _ => Err(LoadImageError(())),
+        }
+    }
+
     /// Sets the nine-slice edges of the image.
     ///
     /// [Nine-slice scaling](https://en.wikipedia.org/wiki/9-slice_scaling) is a method for scaling
@@ -843,6 +1036,35 @@ pub fn size(&self) -> IntSize {
         self.0.size()
     }
 
+    /// Returns the number of frames of an animated image, such as an animated GIF, or 1 if the
+    /// image is not animated.
+    pub fn frame_count(&self) -> usize {
+        match &self.0 {
+            ImageInner::AnimatedImage(animated) => animated.frame_count(),
+            _ => 1,
+        }
+    }
+
+    /// Returns the frame at the given index of an animated image, as a still image.
+    /// For an image that is not animated, the only frame is the image itself.
+    pub fn frame(&self, index: usize) -> Option<Image> {
+        match &self.0 {
+            ImageInner::AnimatedImage(animated) => {
+                animated.frame(index).map(|(frame, _)| Image(frame.clone()))
+            }
+            _ => (index == 0).then(|| self.clone()),
+        }
+    }
+
+    /// Returns how long the frame at the given index of an animated image is shown before the
+    /// next frame, or None if the image is not animated.
+    pub fn frame_duration(&self, index: usize) -> Option<core::time::Duration> {
+        match &self.0 {
+            ImageInner::AnimatedImage(animated) => animated.frame(index).map(|(_, d)| d),
+            _ => None,
+        }
+    }
+
     #[cfg(feature = "std")]
     /// Returns the path of the image on disk, if it was constructed via [`Self::load_from_path`].
     ///
@@ -858,16 +1080,27 @@ pub fn size(&self) -> IntSize {
     pub fn path(&self) -> Option<&std::path::Path> {
         match &self.0 {
             ImageInner::EmbeddedImage {
-                cache_key: ImageCacheKey::Path(CachedPath { path, .. }),
+                cache_key:
+                    ImageCacheKey::Path(CachedPath { path, .. })
+                    | ImageCacheKey::ScaledPath(CachedPath { path, .. }, _),
                 ..
             } => Some(std::path::Path::new(path.as_str())),
             ImageInner::NineSlice(nine) => match &nine.0 {
                 ImageInner::EmbeddedImage {
-                    cache_key: ImageCacheKey::Path(CachedPath { path, .. }),
+                    cache_key:
+                        ImageCacheKey::Path(CachedPath { path, .. })
+                        | ImageCacheKey::ScaledPath(CachedPath { path, .. }, _),
                     ..
                 } => Some(std::path::Path::new(path.as_str())),
                 _ => None,
             },
+            ImageInner::AnimatedImage(animated) => match &animated.cache_key {
+                ImageCacheKey::Path(CachedPath { path, .. })
+                | ImageCacheKey::ScaledPath(CachedPath { path, .. }, _) => {
+                    Some(std::path::Path::new(path.as_str()))
+                }
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -977,6 +1210,42 @@ fn test_image_size_from_svg() {
     assert_eq!(image.to_rgba8().unwrap().size(), image.size());
 }
 
+#[cfg(feature = "svg")]
+#[test]
+fn test_svg_color_and_size() {
+    let svg = r#"<svg width="10" height="20" xmlns="http://www.w3.org/2000/svg">
+        <rect width="10" height="10" fill="currentColor"/>
+        <rect y="10" width="10" height="10" fill="var(--accent, #00ff00)"/>
+    </svg>"#;
+    let image = Image::load_from_svg_data(svg.as_bytes()).unwrap();
+    let pixel = |image: &Image, x: usize, y: usize| {
+        let buffer = image.to_rgba8().unwrap();
+        buffer.as_slice()[y * buffer.width() as usize + x]
+    };
+    let black = Rgba8Pixel { r: 0, g: 0, b: 0, a: 255 };
+    let red = Rgba8Pixel { r: 255, g: 0, b: 0, a: 255 };
+    let green = Rgba8Pixel { r: 0, g: 255, b: 0, a: 255 };
+    let blue = Rgba8Pixel { r: 0, g: 0, b: 255, a: 255 };
+    assert_eq!(pixel(&image, 5, 5), black);
+    assert_eq!(pixel(&image, 5, 15), green);
+
+    let recolored = image.with_svg_color(crate::Color::from_rgb_u8(255, 0, 0)).unwrap();
+    assert_eq!(pixel(&recolored, 5, 5), red);
+    let recolored = recolored.with_svg_variables([("accent", "blue")]).unwrap();
+    assert_eq!(pixel(&recolored, 5, 5), red);
+    assert_eq!(pixel(&recolored, 5, 15), blue);
+
+    let resized = recolored.with_svg_size(IntSize::new(40, 40)).unwrap();
+    assert_eq!(resized.size(), IntSize::new(20, 40));
+    assert_eq!(resized.to_rgba8().unwrap().size(), resized.size());
+    assert_eq!(pixel(&resized, 10, 30), blue);
+    // The size is kept when the colors change
+    assert_eq!(resized.with_svg_color(Default::default()).unwrap().size(), resized.size());
+
+    assert!(image.with_svg_size(IntSize::new(0, 0)).is_err());
+    assert!(Image::default().with_svg_color(Default::default()).is_err());
+}
+
 #[cfg(feature = "svg")]
 #[test]
 fn test_image_invalid_svg() {
@@ -1153,7 +1422,7 @@ pub fn fit(
                 tiled: None,
             }
         }
-        ImageFit::Preserve => scale_factor.get(),
+        ImageFit::Preserve | ImageFit::Tile => scale_factor.get(),
         ImageFit::Contain => f32::min(target.width / o.width, target.height / o.height),
         ImageFit::Cover => f32::max(target.width / o.width, target.height / o.height),
     };
@@ -1293,16 +1562,23 @@ struct Rgba8Pixel {
     pub extern "C" fn slint_image_path(image: &Image) -> Option<&SharedString> {
         match &image.0 {
             ImageInner::EmbeddedImage { cache_key, .. } => match cache_key {
-                ImageCacheKey::Path(CachedPath { path, .. }) => Some(path),
+                ImageCacheKey::Path(CachedPath { path, .. })
+                | ImageCacheKey::ScaledPath(CachedPath { path, .. }, _) => Some(path),
                 _ => None,
             },
             ImageInner::NineSlice(nine) => match &nine.0 {
                 ImageInner::EmbeddedImage { cache_key, .. } => match cache_key {
-                    ImageCacheKey::Path(CachedPath { path, .. }) => Some(path),
+                    ImageCacheKey::Path(CachedPath { path, .. })
+                    | ImageCacheKey::ScaledPath(CachedPath { path, .. }, _) => Some(path),
                     _ => None,
                 },
                 _ => None,
             },
+            ImageInner::AnimatedImage(animated) => match &animated.cache_key {
+                ImageCacheKey::Path(CachedPath { path, .. })
+                | ImageCacheKey::ScaledPath(CachedPath { path, .. }, _) => Some(path),
+                _ => None,
+            },
             _ => None,
         }
     }
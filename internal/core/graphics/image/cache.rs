@@ -5,7 +5,10 @@
 This module contains image and caching related types for the run-time library.
 */
 
-use super::{CachedPath, Image, ImageCacheKey, ImageInner, SharedImageBuffer, SharedPixelBuffer};
+use super::{
+    AnimatedImage, CachedPath, Image, ImageCacheKey, ImageInner, IntSize, SharedImageBuffer,
+    SharedPixelBuffer,
+};
 use crate::{slice::Slice, SharedString};
 
 struct ImageWeightInBytes;
@@ -28,6 +31,10 @@ fn weight(&self, _key: &ImageCacheKey, value: &ImageInner) -> usize {
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(..) => 0, // Assume storage in GPU memory
             ImageInner::NineSlice(nine) => self.weight(_key, &nine.0),
+            ImageInner::AnimatedImage(animated) => (0..animated.frame_count())
+                .filter_map(|index| animated.frame(index))
+                .map(|(frame, _)| self.weight(_key, frame))
+                .sum(),
         }
     }
 }
@@ -70,13 +77,45 @@ fn lookup_image_in_cache_or_create(
         }))
     }
 
+    /// Returns the image decoded for the given key, if it's still in the cache.
+    pub(crate) fn get(&mut self, cache_key: &ImageCacheKey) -> Option<Image> {
+        self.0.get(cache_key).map(|inner| Image(inner.clone()))
+    }
+
     pub(crate) fn load_image_from_path(&mut self, path: &SharedString) -> Option<Image> {
         if path.is_empty() {
             return None;
         }
         let cache_key = ImageCacheKey::Path(CachedPath::new(path.as_str()));
+        self.load_image_from_path_with_key(path, cache_key, None)
+    }
+
+    /// Loads the image like [`Self::load_image_from_path()`], scaled down to fit in `max_size`.
+    pub(crate) fn load_scaled_image_from_path(
+        &mut self,
+        path: &SharedString,
+        max_size: IntSize,
+    ) -> Option<Image> {
+        if path.is_empty() {
+            return None;
+        }
+        if max_size.width == 0 && max_size.height == 0 {
+            return self.load_image_from_path(path);
+        }
+        let cache_key = ImageCacheKey::ScaledPath(CachedPath::new(path.as_str()), max_size);
+        self.load_image_from_path_with_key(path, cache_key, Some(max_size))
+    }
+
+    fn load_image_from_path_with_key(
+        &mut self,
+        path: &SharedString,
+        cache_key: ImageCacheKey,
+        max_size: Option<IntSize>,
+    ) -> Option<Image> {
         #[cfg(target_arch = "wasm32")]
         return self.lookup_image_in_cache_or_create(cache_key, |_| {
+            // The web browser decodes the image
+            let _ = max_size;
             return Some(ImageInner::HTMLImage(vtable::VRc::new(
                 super::htmlimage::HTMLImage::new(&path),
             )));
@@ -95,18 +134,43 @@ pub(crate) fn load_image_from_path(&mut self, path: &SharedString) -> Option<Ima
                 )));
             }
 
-            image::open(std::path::Path::new(&path.as_str())).map_or_else(
-                |decode_err| {
-                    eprintln!("Error loading image from {}: {}", &path, decode_err);
-                    None
-                },
-                |image| {
-                    Some(ImageInner::EmbeddedImage {
-                        cache_key,
-                        buffer: dynamic_image_to_shared_image_buffer(image),
-                    })
-                },
-            )
+            #[cfg(feature = "image-heif")]
+            if std::path::Path::new(path.as_str()).extension().is_some_and(|extension| {
+                super::heif::is_heif_extension(extension.as_encoded_bytes())
+            }) {
+                return super::heif::load_from_path(path).map_or_else(
+                    |err| {
+                        eprintln!("Error loading HEIF image from {}: {}", &path, err);
+                        None
+                    },
+                    |image| {
+                        let image = scale_down(image, max_size);
+                        let buffer = dynamic_image_to_shared_image_buffer(image);
+                        Some(ImageInner::EmbeddedImage { cache_key, buffer })
+                    },
+                );
+            }
+
+            image::ImageFormat::from_path(path.as_str())
+                .and_then(|format| {
+                    let data = std::fs::read(path.as_str()).map_err(image::ImageError::from)?;
+                    if let Some(frames) = decode_animated_image(format, &data, max_size) {
+                        return Ok(animated_image_from_frames(frames, cache_key));
+                    }
+                    let buffer = decode_still_image(
+                        std::io::Cursor::new(data.as_slice()),
+                        format,
+                        max_size,
+                    )?;
+                    Ok(ImageInner::EmbeddedImage { cache_key, buffer })
+                })
+                .map_or_else(
+                    |decode_err| {
+                        eprintln!("Error loading image from {}: {}", &path, decode_err);
+                        None
+                    },
+                    Some,
+                )
         });
     }
 
@@ -130,9 +194,30 @@ pub(crate) fn load_image_from_embedded_data(
                 )));
             }
 
+            #[cfg(feature = "image-heif")]
+            if super::heif::is_heif_extension(format.as_slice()) {
+                return super::heif::load_from_data(data.as_slice()).map_or_else(
+                    |heif_err| {
+                        eprintln!("Error decoding embedded HEIF image: {}", heif_err);
+                        None
+                    },
+                    |image| {
+                        let buffer = dynamic_image_to_shared_image_buffer(image);
+                        Some(ImageInner::EmbeddedImage { cache_key, buffer })
+                    },
+                );
+            }
+
             let format = std::str::from_utf8(format.as_slice())
                 .ok()
                 .and_then(image::ImageFormat::from_extension);
+            if let Some(frames) = format
+                .or_else(|| image::guess_format(data.as_slice()).ok())
+                .and_then(|format| decode_animated_image(format, data.as_slice(), None))
+            {
+                return Some(animated_image_from_frames(frames, cache_key));
+            }
+
             let maybe_image = if let Some(format) = format {
                 image::load_from_memory_with_format(data.as_slice(), format)
             } else {
@@ -142,7 +227,10 @@ pub(crate) fn load_image_from_embedded_data(
             match maybe_image {
                 Ok(image) => Some(ImageInner::EmbeddedImage {
                     cache_key,
-                    buffer: dynamic_image_to_shared_image_buffer(image),
+                    buffer: dynamic_image_to_shared_image_buffer(apply_exif_orientation(
+                        image,
+                        std::io::Cursor::new(data.as_slice()),
+                    )),
                 }),
                 Err(decode_err) => {
                     eprintln!("Error decoding embedded image: {}", decode_err);
@@ -153,7 +241,84 @@ pub(crate) fn load_image_from_embedded_data(
     }
 }
 
-fn dynamic_image_to_shared_image_buffer(dynamic_image: image::DynamicImage) -> SharedImageBuffer {
+/// Decodes a still image, scaled down to fit in `max_size` if specified, and with its EXIF orientation
+/// applied.
+pub(super) fn decode_still_image(
+    mut reader: impl std::io::BufRead + std::io::Seek,
+    format: image::ImageFormat,
+    max_size: Option<IntSize>,
+) -> image::ImageResult<SharedImageBuffer> {
+    let orientation = exif_orientation(&mut reader);
+    reader.rewind()?;
+    // The maximum size applies to the image once it's rotated
+    let max_size = max_size.map(|size| match orientation {
+        Some(5..=8) => IntSize::new(size.height, size.width),
+        _ => size,
+    });
+    let image = match max_size.map(max_dimensions) {
+        // Let the decoder skip the details that are lost when scaling down
+        Some((width, height)) if format == image::ImageFormat::Jpeg => {
+            let mut decoder = image::codecs::jpeg::JpegDecoder::new(reader)?;
+            decoder.scale(width.min(u16::MAX as u32) as u16, height.min(u16::MAX as u32) as u16)?;
+            image::DynamicImage::from_decoder(decoder)?
+        }
+        _ => image::load(reader, format)?,
+    };
+    let image = apply_orientation(scale_down(image, max_size), orientation);
+    Ok(dynamic_image_to_shared_image_buffer(image))
+}
+
+/// Returns the maximum width and height of an image scaled down to fit in `max_size`, where a zero
+/// width or height doesn't limit that dimension.
+fn max_dimensions(max_size: IntSize) -> (u32, u32) {
+    let unlimited_if_zero = |length: u32| if length == 0 { u32::MAX } else { length };
+    (unlimited_if_zero(max_size.width), unlimited_if_zero(max_size.height))
+}
+
+/// Scales the image down to fit in `max_size`, keeping its aspect ratio.
+pub(super) fn scale_down(
+    image: image::DynamicImage,
+    max_size: Option<IntSize>,
+) -> image::DynamicImage {
+    match max_size.map(max_dimensions) {
+        Some((width, height)) if image.width() > width || image.height() > height => {
+            image.thumbnail(width, height)
+        }
+        _ => image,
+    }
+}
+
+/// Rotates and mirrors the decoded image as specified by the EXIF orientation in its data, which is
+/// how the cameras of phones store the photos taken in portrait mode.
+fn apply_exif_orientation(
+    image: image::DynamicImage,
+    mut reader: impl std::io::BufRead + std::io::Seek,
+) -> image::DynamicImage {
+    apply_orientation(image, exif_orientation(&mut reader))
+}
+
+fn exif_orientation(reader: &mut (impl std::io::BufRead + std::io::Seek)) -> Option<u32> {
+    exif::Reader::new().read_from_container(reader).ok().and_then(|exif| {
+        exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)?.value.get_uint(0)
+    })
+}
+
+fn apply_orientation(image: image::DynamicImage, orientation: Option<u32>) -> image::DynamicImage {
+    match orientation {
+        Some(2) => image.fliph(),
+        Some(3) => image.rotate180(),
+        Some(4) => image.flipv(),
+        Some(5) => image.rotate90().fliph(),
+        Some(6) => image.rotate90(),
+        Some(7) => image.rotate270().fliph(),
+        Some(8) => image.rotate270(),
+        _ => image,
+    }
+}
+
+pub(super) fn dynamic_image_to_shared_image_buffer(
+    dynamic_image: image::DynamicImage,
+) -> SharedImageBuffer {
     if dynamic_image.color().has_alpha() {
         let rgba8image = dynamic_image.to_rgba8();
         SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
@@ -171,6 +336,106 @@ fn dynamic_image_to_shared_image_buffer(dynamic_image: image::DynamicImage) -> S
     }
 }
 
+/// Creates the animated image from frames returned by [`decode_animated_image()`].
+pub(super) fn animated_image_from_frames(
+    frames: Vec<(SharedImageBuffer, core::time::Duration)>,
+    cache_key: ImageCacheKey,
+) -> ImageInner {
+    let frames = frames
+        .into_iter()
+        .map(|(buffer, duration)| {
+            (ImageInner::EmbeddedImage { cache_key: ImageCacheKey::Invalid, buffer }, duration)
+        })
+        .collect();
+    ImageInner::AnimatedImage(vtable::VRc::new(AnimatedImage::new(frames, cache_key)))
+}
+
+/// Decodes all the frames of an animated GIF, APNG, or WebP image, scaled down to fit in `max_size` if
+/// specified. Returns None if the format doesn't support animations or if the image has less than two
+/// frames, so that it's loaded as a still image.
+pub(super) fn decode_animated_image(
+    format: image::ImageFormat,
+    data: &[u8],
+    max_size: Option<IntSize>,
+) -> Option<Vec<(SharedImageBuffer, core::time::Duration)>> {
+    use image::AnimationDecoder;
+    if !may_be_animated(format, data) {
+        return None;
+    }
+    let reader = std::io::Cursor::new(data);
+    let frames = match format {
+        image::ImageFormat::Gif => image::codecs::gif::GifDecoder::new(reader).ok()?.into_frames(),
+        image::ImageFormat::Png => {
+            let decoder = image::codecs::png::PngDecoder::new(reader).ok()?;
+            if !decoder.is_apng() {
+                return None;
+            }
+            decoder.apng().into_frames()
+        }
+        image::ImageFormat::WebP => {
+            let decoder = image::codecs::webp::WebPDecoder::new(reader).ok()?;
+            if !decoder.has_animation() {
+                return None;
+            }
+            decoder.into_frames()
+        }
+        _ => return None,
+    };
+    let frames = frames.collect_frames().ok()?;
+    if frames.len() < 2 {
+        return None;
+    }
+    let frames = frames
+        .into_iter()
+        .map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let mut duration = core::time::Duration::from_millis((numer / denom.max(1)) as u64);
+            // Like web browsers, use a sensible delay for the frames that don't specify one
+            if duration < core::time::Duration::from_millis(20) {
+                duration = core::time::Duration::from_millis(100);
+            }
+            let buffer = scale_down(image::DynamicImage::ImageRgba8(frame.into_buffer()), max_size)
+                .into_rgba8();
+            let buffer = SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
+                buffer.as_raw(),
+                buffer.width(),
+                buffer.height(),
+            ));
+            (buffer, duration)
+        })
+        .collect();
+    Some(frames)
+}
+
+/// Returns false if the encoded `data` of an image in `format` has a single frame, without decoding
+/// it. PNG and WebP images declare their animation in a chunk that comes before the image data.
+fn may_be_animated(format: image::ImageFormat, data: &[u8]) -> bool {
+    match format {
+        image::ImageFormat::Gif => true,
+        image::ImageFormat::Png => {
+            // Look for the acTL chunk, which must come before the first IDAT chunk
+            let mut chunks = data.get(8..).unwrap_or_default();
+            while let Some(header) = chunks.get(..8) {
+                match &header[4..] {
+                    b"acTL" => return true,
+                    b"IDAT" => return false,
+                    _ => {}
+                }
+                let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+                // Skip the header, the data, and the CRC of the chunk
+                chunks = chunks.get(12 + length as usize..).unwrap_or_default();
+            }
+            false
+        }
+        // The animation flag is in the VP8X chunk, which comes first in the files that have one
+        image::ImageFormat::WebP => {
+            data.get(12..16) == Some(&b"VP8X"[..])
+                && data.get(20).map_or(false, |flags| flags & 0b10 != 0)
+        }
+        _ => false,
+    }
+}
+
 /// Replace the cached image key with the given value
 pub fn replace_cached_image(key: ImageCacheKey, value: ImageInner) {
     if key == ImageCacheKey::Invalid {
@@ -195,6 +460,8 @@ fn test_path_cache_invalidation() {
         let red_image = image::RgbImage::from_pixel(10, 10, image::Rgb([255, 0, 0]));
         red_image.save(&test_path).unwrap();
         let red_slint_image = crate::graphics::Image::load_from_path(&test_path).unwrap();
+        // A PNG that isn't an APNG is a still image
+        assert_eq!(red_slint_image.frame_count(), 1);
         let buffer = red_slint_image.to_rgba8().unwrap();
         assert!(buffer
             .as_slice()
@@ -224,4 +491,167 @@ fn test_path_cache_invalidation() {
             .iter()
             .all(|pixel| *pixel == Rgba8Pixel { r: 0, g: 255, b: 0, a: 255 }));
     }
+
+    #[test]
+    fn test_animated_gif() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_path = temp_dir.path().join("animated.gif");
+
+        let mut encoder =
+            image::codecs::gif::GifEncoder::new(std::fs::File::create(&test_path).unwrap());
+        for (color, delay) in [([255, 0, 0, 255], 50), ([0, 0, 255, 255], 0)] {
+            encoder
+                .encode_frame(image::Frame::from_parts(
+                    image::RgbaImage::from_pixel(10, 10, image::Rgba(color)),
+                    0,
+                    0,
+                    image::Delay::from_numer_denom_ms(delay, 1),
+                ))
+                .unwrap();
+        }
+        drop(encoder);
+
+        let animated = crate::graphics::Image::load_from_path(&test_path).unwrap();
+        assert_eq!(animated.frame_count(), 2);
+        assert_eq!(animated.size(), crate::graphics::IntSize::new(10, 10));
+        assert_eq!(animated.path(), Some(test_path.as_path()));
+        assert_eq!(animated.frame_duration(0), Some(std::time::Duration::from_millis(50)));
+        // Frames without a delay are shown for 100ms
+        assert_eq!(animated.frame_duration(1), Some(std::time::Duration::from_millis(100)));
+        assert!(animated.frame(2).is_none());
+
+        let blue = animated.frame(1).unwrap().to_rgba8().unwrap();
+        assert!(blue
+            .as_slice()
+            .iter()
+            .all(|pixel| *pixel == Rgba8Pixel { r: 0, g: 0, b: 255, a: 255 }));
+        // The first frame is shown when the image is not animated by an Image element
+        let red = animated.to_rgba8().unwrap();
+        assert!(red
+            .as_slice()
+            .iter()
+            .all(|pixel| *pixel == Rgba8Pixel { r: 255, g: 0, b: 0, a: 255 }));
+    }
+
+    /// Checks that `animated` shows a red frame for 50ms and then a blue frame for 100ms.
+    fn assert_red_then_blue_frames(animated: &crate::graphics::Image) {
+        assert_eq!(animated.frame_count(), 2);
+        assert_eq!(animated.size(), crate::graphics::IntSize::new(10, 10));
+        assert_eq!(animated.frame_duration(0), Some(std::time::Duration::from_millis(50)));
+        assert_eq!(animated.frame_duration(1), Some(std::time::Duration::from_millis(100)));
+        for (index, color) in [(0, [255, 0, 0]), (1, [0, 0, 255])] {
+            let frame = animated.frame(index).unwrap().to_rgba8().unwrap();
+            assert!(
+                frame.as_slice().iter().all(|pixel| [pixel.r, pixel.g, pixel.b] == color),
+                "frame {index}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_animated_png() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_path = temp_dir.path().join("animated.png");
+
+        let mut encoder = png::Encoder::new(std::fs::File::create(&test_path).unwrap(), 10, 10);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_animated(2, 0).unwrap();
+        let mut writer = encoder.write_header().unwrap();
+        for (color, delay) in [([255, 0, 0, 255], 50), ([0, 0, 255, 255], 100)] {
+            writer.set_frame_delay(delay, 1000).unwrap();
+            writer.write_image_data(&color.repeat(10 * 10)).unwrap();
+        }
+        writer.finish().unwrap();
+
+        assert_red_then_blue_frames(&crate::graphics::Image::load_from_path(&test_path).unwrap());
+
+        // A PNG without an acTL chunk is a still image
+        let still_path = temp_dir.path().join("still.png");
+        image::RgbImage::from_pixel(10, 10, image::Rgb([0, 255, 0])).save(&still_path).unwrap();
+        assert_eq!(crate::graphics::Image::load_from_path(&still_path).unwrap().frame_count(), 1);
+    }
+
+    #[test]
+    fn test_animated_webp() {
+        /// Returns the lossless bitstream of a 10x10 image filled with `color`, where each
+        /// channel is coded with a prefix code of a single symbol, so the pixels take no bits.
+        fn solid_color_bitstream([r, g, b, a]: [u8; 4]) -> Vec<u8> {
+            let mut bits = Vec::new();
+            let mut write = |value: u32, count: u32| {
+                bits.extend((0..count).map(|bit| value >> bit & 1 == 1));
+            };
+            write(10 - 1, 14); // width
+            write(10 - 1, 14); // height
+            write(1, 1); // the alpha is used
+            write(0, 3); // version
+            write(0, 3); // no transform, no color cache and no meta prefix codes
+            for symbol in [g, r, b, a] {
+                write(0b101, 3); // a simple code of one 8-bit symbol
+                write(symbol.into(), 8);
+            }
+            write(0b001, 3); // the distance code, of one 1-bit symbol
+            write(0, 1);
+            let bytes = bits
+                .chunks(8)
+                .map(|byte| byte.iter().rev().fold(0u8, |value, bit| value << 1 | u8::from(*bit)));
+            [0x2f].into_iter().chain(bytes).collect()
+        }
+
+        fn chunk(fourcc: &[u8; 4], data: &[u8]) -> Vec<u8> {
+            let mut chunk = fourcc.to_vec();
+            chunk.extend((data.len() as u32).to_le_bytes());
+            chunk.extend(data);
+            if data.len() % 2 == 1 {
+                chunk.push(0);
+            }
+            chunk
+        }
+
+        let size_minus_one = [10 - 1, 0, 0];
+        // The alpha and animation flags, and the size of the canvas
+        let mut chunks = chunk(
+            b"VP8X",
+            &[[0b10010, 0, 0, 0].as_slice(), &size_minus_one, &size_minus_one].concat(),
+        );
+        // The background color and the loop count
+        chunks.extend(chunk(b"ANIM", &[0; 6]));
+        for (color, delay) in [([255, 0, 0, 255], 50u8), ([0, 0, 255, 255], 100)] {
+            // The position, size, duration, and blending of the frame, followed by its bitstream
+            let header = [[0; 6].as_slice(), &size_minus_one, &size_minus_one, &[delay, 0, 0, 0]];
+            let frame = [header.concat(), chunk(b"VP8L", &solid_color_bitstream(color))].concat();
+            chunks.extend(chunk(b"ANMF", &frame));
+        }
+        let webp = [b"WEBP".as_slice(), chunks.as_slice()].concat();
+        let data =
+            [b"RIFF".as_slice(), &(webp.len() as u32).to_le_bytes(), webp.as_slice()].concat();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_path = temp_dir.path().join("animated.webp");
+        std::fs::write(&test_path, data).unwrap();
+        assert_red_then_blue_frames(&crate::graphics::Image::load_from_path(&test_path).unwrap());
+    }
+
+    #[test]
+    fn test_load_with_max_size() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let max_size = crate::graphics::IntSize::new(40, 40);
+        for file_name in ["large.png", "large.jpg"] {
+            let test_path = temp_dir.path().join(file_name);
+            image::RgbImage::from_pixel(160, 80, image::Rgb([0, 0, 255])).save(&test_path).unwrap();
+
+            let scaled =
+                crate::graphics::Image::load_from_path_with_max_size(&test_path, max_size).unwrap();
+            assert_eq!(scaled.size(), crate::graphics::IntSize::new(40, 20));
+            assert_eq!(scaled.path(), Some(test_path.as_path()));
+            // The scaled image doesn't replace the full size image in the cache
+            let full_size = crate::graphics::Image::load_from_path(&test_path).unwrap();
+            assert_eq!(full_size.size(), crate::graphics::IntSize::new(160, 80));
+
+            let only_height = crate::graphics::IntSize::new(0, 40);
+            let scaled =
+                crate::graphics::Image::load_from_path_with_max_size(&test_path, only_height)
+                    .unwrap();
+            assert_eq!(scaled.size(), crate::graphics::IntSize::new(80, 40));
+        }
+    }
 }
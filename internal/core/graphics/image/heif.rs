@@ -0,0 +1,54 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use crate::SharedString;
+use libheif_rs::{ColorSpace, HeifContext, LibHeif, RgbChroma};
+
+/// Returns true if the file extension (or the format of embedded data) is the one of a HEIF image,
+/// ignoring the case since cameras commonly save `.HEIC` files.
+pub fn is_heif_extension(extension: &[u8]) -> bool {
+    extension.eq_ignore_ascii_case(b"heic") || extension.eq_ignore_ascii_case(b"heif")
+}
+
+pub fn load_from_path(path: &SharedString) -> Result<image::DynamicImage, std::io::Error> {
+    let heif_data = std::fs::read(std::path::Path::new(&path.as_str()))?;
+    load_from_data(&heif_data)
+}
+
+/// Decodes the primary image of a HEIF file. libheif applies the rotation and the mirroring
+/// stored in the file, so the EXIF orientation must not be applied again.
+pub fn load_from_data(slice: &[u8]) -> Result<image::DynamicImage, std::io::Error> {
+    use std::io::{Error, ErrorKind};
+    let heif_error = |e: libheif_rs::HeifError| Error::new(ErrorKind::InvalidData, e);
+
+    let context = HeifContext::read_from_bytes(slice).map_err(heif_error)?;
+    let handle = context.primary_image_handle().map_err(heif_error)?;
+    let has_alpha = handle.has_alpha_channel();
+    let chroma = if has_alpha { RgbChroma::Rgba } else { RgbChroma::Rgb };
+    let image =
+        LibHeif::new().decode(&handle, ColorSpace::Rgb(chroma), None).map_err(heif_error)?;
+    let Some(plane) = image.planes().interleaved else {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "the decoded HEIF image is not interleaved",
+        ));
+    };
+
+    let (width, height) = (plane.width, plane.height);
+    let bytes_per_pixel = if has_alpha { 4 } else { 3 };
+    let row_length = width as usize * bytes_per_pixel;
+    let data: Vec<u8> = plane
+        .data
+        .chunks(plane.stride)
+        .take(height as usize)
+        .filter_map(|row| row.get(..row_length))
+        .flatten()
+        .copied()
+        .collect();
+    let image: Option<image::DynamicImage> = if has_alpha {
+        image::RgbaImage::from_raw(width, height, data).map(Into::into)
+    } else {
+        image::RgbImage::from_raw(width, height, data).map(Into::into)
+    };
+    image.ok_or_else(|| Error::new(ErrorKind::InvalidData, "the HEIF image data is truncated"))
+}
@@ -0,0 +1,249 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module decodes images in worker threads, for [`Image::load_from_path_async()`] and
+[`Image::load_from_data_async()`].
+*/
+
+use super::cache::{self, IMAGE_CACHE};
+use super::{
+    CachedPath, Image, ImageCacheKey, ImageInner, IntSize, LoadImageError, SharedImageBuffer,
+};
+use core::future::Future;
+use core::task::{Poll, Waker};
+use core::time::Duration;
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// The threads decoding the images, shared by all the images being loaded. The jobs run in the order
+/// they were queued, a few at a time.
+struct WorkerPool {
+    jobs: Mutex<VecDeque<Job>>,
+    job_queued: Condvar,
+    has_threads: bool,
+}
+
+impl WorkerPool {
+    fn global() -> &'static Self {
+        static POOL: OnceLock<WorkerPool> = OnceLock::new();
+        POOL.get_or_init(|| {
+            let thread_count =
+                std::thread::available_parallelism().map_or(2, |count| count.get().clamp(1, 4));
+            let spawned = (0..thread_count)
+                .filter(|_| {
+                    std::thread::Builder::new()
+                        .name("Slint image loader".into())
+                        .spawn(|| Self::global().run())
+                        .is_ok()
+                })
+                .count();
+            Self { jobs: Default::default(), job_queued: Condvar::new(), has_threads: spawned > 0 }
+        })
+    }
+
+    fn run(&self) {
+        loop {
+            let job = {
+                let mut jobs = self.jobs.lock().unwrap();
+                loop {
+                    match jobs.pop_front() {
+                        Some(job) => break job,
+                        None => jobs = self.job_queued.wait(jobs).unwrap(),
+                    }
+                }
+            };
+            job();
+        }
+    }
+
+    fn queue(&self, job: Job) {
+        // Without threads, such as in a web browser, the image is decoded right away
+        if !self.has_threads {
+            job();
+            return;
+        }
+        self.jobs.lock().unwrap().push_back(job);
+        self.job_queued.notify_one();
+    }
+}
+
+/// The image decoded by a worker thread. It's converted to an [`ImageInner`] in the thread awaiting
+/// it, as the image types are not `Send`.
+enum DecodedImage {
+    Still(SharedImageBuffer),
+    Animated(Vec<(SharedImageBuffer, Duration)>),
+    #[cfg(feature = "svg")]
+    Svg(Vec<u8>),
+}
+
+impl DecodedImage {
+    fn into_image(self, cache_key: ImageCacheKey) -> Option<Image> {
+        let inner = match self {
+            DecodedImage::Still(buffer) => {
+                ImageInner::EmbeddedImage { cache_key: cache_key.clone(), buffer }
+            }
+            DecodedImage::Animated(frames) => {
+                cache::animated_image_from_frames(frames, cache_key.clone())
+            }
+            #[cfg(feature = "svg")]
+            DecodedImage::Svg(data) => ImageInner::Svg(vtable::VRc::new(
+                super::svg::load_from_data(&data, cache_key.clone()).ok()?,
+            )),
+        };
+        cache::replace_cached_image(cache_key, inner.clone());
+        Some(Image(inner))
+    }
+}
+
+/// Decodes the image in `data`, scaled down to fit in `max_size` if specified. The format is deduced
+/// from the file extension, if known, or else from the data.
+fn decode(
+    data: Vec<u8>,
+    extension: Option<&str>,
+    max_size: Option<IntSize>,
+) -> Option<DecodedImage> {
+    #[cfg(feature = "svg")]
+    if matches!(extension, Some("svg" | "svgz"))
+        || (extension.is_none()
+            && image::guess_format(&data).is_err()
+            && data.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'<'))
+    {
+        return Some(DecodedImage::Svg(data));
+    }
+
+    #[cfg(feature = "image-heif")]
+    if extension.is_some_and(|extension| super::heif::is_heif_extension(extension.as_bytes())) {
+        let image = cache::scale_down(super::heif::load_from_data(&data).ok()?, max_size);
+        return Some(DecodedImage::Still(cache::dynamic_image_to_shared_image_buffer(image)));
+    }
+
+    let format = extension
+        .and_then(image::ImageFormat::from_extension)
+        .or_else(|| image::guess_format(&data).ok())?;
+    if let Some(frames) = cache::decode_animated_image(format, &data, max_size) {
+        return Some(DecodedImage::Animated(frames));
+    }
+    cache::decode_still_image(std::io::Cursor::new(&data), format, max_size)
+        .ok()
+        .map(DecodedImage::Still)
+}
+
+struct PendingImage {
+    decoded: Option<Option<DecodedImage>>,
+    waker: Option<Waker>,
+}
+
+/// Returns a future that resolves to the image in the cache, or else to the image decoded by `decode`
+/// in a worker thread.
+fn load(
+    cache_key: ImageCacheKey,
+    decode: impl FnOnce() -> Option<DecodedImage> + Send + 'static,
+) -> impl Future<Output = Result<Image, LoadImageError>> + 'static {
+    let mut cached = IMAGE_CACHE.with(|global_cache| global_cache.borrow_mut().get(&cache_key));
+    let pending = Arc::new(Mutex::new(PendingImage { decoded: None, waker: None }));
+    if cached.is_none() {
+        let pending = pending.clone();
+        WorkerPool::global().queue(Box::new(move || {
+            let decoded = decode();
+            let mut pending = pending.lock().unwrap();
+            pending.decoded = Some(decoded);
+            if let Some(waker) = pending.waker.take() {
+                waker.wake();
+            }
+        }));
+    }
+    core::future::poll_fn(move |cx| {
+        if let Some(image) = cached.take() {
+            return Poll::Ready(Ok(image));
+        }
+        let mut pending = pending.lock().unwrap();
+        match pending.decoded.take() {
+            Some(decoded) => Poll::Ready(
+                decoded
+                    .and_then(|decoded| decoded.into_image(cache_key.clone()))
+                    .ok_or(LoadImageError(())),
+            ),
+            None => {
+                pending.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    })
+}
+
+pub(super) fn load_from_path(
+    path: &std::path::Path,
+    max_size: Option<IntSize>,
+) -> impl Future<Output = Result<Image, LoadImageError>> + 'static {
+    let max_size = max_size.filter(|size| size.width != 0 || size.height != 0);
+    let cache_key = match max_size {
+        Some(max_size) => ImageCacheKey::ScaledPath(CachedPath::new(path), max_size),
+        None => ImageCacheKey::Path(CachedPath::new(path)),
+    };
+    let path = path.to_owned();
+    load(cache_key, move || {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        decode(std::fs::read(&path).ok()?, extension, max_size)
+    })
+}
+
+pub(super) fn load_from_data(
+    data: Vec<u8>,
+) -> impl Future<Output = Result<Image, LoadImageError>> + 'static {
+    load(ImageCacheKey::Invalid, move || decode(data, None, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::Rgba8Pixel;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct ThreadWaker(std::thread::Thread);
+        impl std::task::Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+        let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+        let mut cx = core::task::Context::from_waker(&waker);
+        let mut future = core::pin::pin!(future);
+        loop {
+            if let Poll::Ready(result) = future.as_mut().poll(&mut cx) {
+                return result;
+            }
+            std::thread::park();
+        }
+    }
+
+    #[test]
+    fn test_load_async() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_path = temp_dir.path().join("red.png");
+        image::RgbImage::from_pixel(10, 10, image::Rgb([255, 0, 0])).save(&test_path).unwrap();
+
+        let red = block_on(Image::load_from_path_async(&test_path)).unwrap();
+        assert_eq!(red.path(), Some(test_path.as_path()));
+        assert!(red
+            .to_rgba8()
+            .unwrap()
+            .as_slice()
+            .iter()
+            .all(|pixel| *pixel == Rgba8Pixel { r: 255, g: 0, b: 0, a: 255 }));
+        // The second load comes from the cache
+        assert_eq!(block_on(Image::load_from_path_async(&test_path)), Ok(red.clone()));
+        assert_eq!(Image::load_from_path(&test_path), Ok(red));
+
+        let data = std::fs::read(&test_path).unwrap();
+        let from_data = block_on(Image::load_from_data_async(data)).unwrap();
+        assert_eq!(from_data.size(), crate::graphics::IntSize::new(10, 10));
+
+        assert!(
+            block_on(Image::load_from_path_async(&temp_dir.path().join("missing.png"))).is_err()
+        );
+        assert!(block_on(Image::load_from_data_async(b"not an image".to_vec())).is_err());
+    }
+}
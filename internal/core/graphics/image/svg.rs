@@ -2,14 +2,30 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use super::{ImageCacheKey, SharedImageBuffer, SharedPixelBuffer};
+use crate::graphics::{Color, IntSize};
 use crate::lengths::PhysicalPx;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::SharedString;
 use resvg::{tiny_skia, usvg};
+use std::borrow::Cow;
+use std::rc::Rc;
+
+/// The changes applied to the SVG document when it's parsed.
+#[derive(Clone, Default)]
+struct SvgStyle {
+    /// The value of `currentColor`
+    color: Option<Color>,
+    /// The values of the CSS custom properties, by name without the leading `--`
+    variables: Vec<(SharedString, SharedString)>,
+}
 
 pub struct ParsedSVG {
     svg_tree: usvg::Tree,
     cache_key: ImageCacheKey,
+    /// The SVG document, to parse it again with another style
+    source: Rc<[u8]>,
+    style: SvgStyle,
+    /// The size of the image, if it was changed from the size in the document
+    size: Option<IntSize>,
 }
 
 impl super::OpaqueImage for ParsedSVG {
@@ -29,8 +45,10 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 
 impl ParsedSVG {
     pub fn size(&self) -> crate::graphics::IntSize {
-        let size = self.svg_tree.size().to_int_size();
-        [size.width(), size.height()].into()
+        self.size.unwrap_or_else(|| {
+            let size = self.svg_tree.size().to_int_size();
+            [size.width(), size.height()].into()
+        })
     }
 
     pub fn cache_key(&self) -> ImageCacheKey {
@@ -45,6 +63,7 @@ pub fn render(
     ) -> Result<SharedImageBuffer, usvg::Error> {
         let tree = &self.svg_tree;
 
+        let size = size.or(self.size.map(euclid::Size2D::from_untyped));
         let (target_size, transform) = match size {
             Some(size) => {
                 let target_size = tiny_skia::IntSize::from_wh(size.width, size.height)
@@ -72,6 +91,125 @@ pub fn render(
         resvg::render(tree, transform, &mut skia_buffer);
         Ok(SharedImageBuffer::RGBA8Premultiplied(buffer))
     }
+
+    /// Parses the SVG again with `currentColor` set to `color`.
+    pub fn with_color(&self, color: Color) -> Result<Self, usvg::Error> {
+        let style = SvgStyle { color: Some(color), ..self.style.clone() };
+        self.with_style(style)
+    }
+
+    /// Parses the SVG again with the CSS custom properties set to `variables`, in addition to the ones
+    /// set previously.
+    pub fn with_variables(
+        &self,
+        variables: impl IntoIterator<Item = (SharedString, SharedString)>,
+    ) -> Result<Self, usvg::Error> {
+        let mut style = self.style.clone();
+        for (name, value) in variables {
+            style.variables.retain(|(n, _)| *n != name);
+            style.variables.push((name, value));
+        }
+        self.with_style(style)
+    }
+
+    fn with_style(&self, style: SvgStyle) -> Result<Self, usvg::Error> {
+        let parsed = parse(self.source.clone(), style, ImageCacheKey::Invalid)?;
+        Ok(Self { size: self.size, ..parsed })
+    }
+
+    /// Returns a copy of the SVG with the size that fits in `size` while keeping the aspect ratio,
+    /// or None if `size` is empty.
+    pub fn with_size(&self, size: IntSize) -> Option<Self> {
+        let size = self
+            .svg_tree
+            .size()
+            .to_int_size()
+            .scale_to(tiny_skia::IntSize::from_wh(size.width, size.height)?);
+        Some(Self {
+            svg_tree: self.svg_tree.clone(),
+            cache_key: ImageCacheKey::Invalid,
+            source: self.source.clone(),
+            style: self.style.clone(),
+            size: Some([size.width(), size.height()].into()),
+        })
+    }
+}
+
+fn parse(
+    source: Rc<[u8]>,
+    style: SvgStyle,
+    cache_key: ImageCacheKey,
+) -> Result<ParsedSVG, usvg::Error> {
+    let data = if source.starts_with(&[0x1f, 0x8b]) {
+        Cow::Owned(usvg::decompress_svgz(&source)?)
+    } else {
+        Cow::Borrowed(&*source)
+    };
+    // Substitute even without variables, so that the fallbacks apply
+    let data = if data.windows("var(".len()).any(|window| window == b"var(") {
+        let svg = core::str::from_utf8(&data).map_err(|_| usvg::Error::NotAnUtf8Str)?;
+        Cow::Owned(substitute_variables(svg, &style.variables).into_bytes())
+    } else {
+        data
+    };
+    // Sets the `color` property of the root element, which `currentColor` refers to
+    let style_sheet = style.color.map(|color| {
+        format!(
+            "svg {{ color: rgba({}, {}, {}, {}) }}",
+            color.red(),
+            color.green(),
+            color.blue(),
+            color.alpha() as f32 / 255.
+        )
+    });
+
+    i_slint_common::sharedfontdb::FONT_DB.with_borrow(|db| {
+        let option = usvg::Options { fontdb: (*db).clone(), style_sheet, ..Default::default() };
+        let svg_tree = usvg::Tree::from_data(&data, &option)?;
+        Ok(ParsedSVG { svg_tree, cache_key, source: source.clone(), style, size: None })
+    })
+}
+
+/// Replaces the `var(--name)` and `var(--name, fallback)` functions in `svg` with the value of the
+/// variable, or else with the fallback, as usvg doesn't support CSS custom properties.
+fn substitute_variables(svg: &str, variables: &[(SharedString, SharedString)]) -> String {
+    let mut result = String::with_capacity(svg.len());
+    let mut rest = svg;
+    while let Some(start) = rest.find("var(") {
+        result.push_str(&rest[..start]);
+        let arguments = &rest[start + "var(".len()..];
+        // Skip the parentheses of the functions in the fallback, such as `rgb()`
+        let mut depth = 0;
+        let Some(end) = arguments.find(|c| match c {
+            '(' => {
+                depth += 1;
+                false
+            }
+            ')' if depth == 0 => true,
+            ')' => {
+                depth -= 1;
+                false
+            }
+            _ => false,
+        }) else {
+            rest = &rest[start..];
+            break;
+        };
+        let (name, fallback) = match arguments[..end].split_once(',') {
+            Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+            None => (arguments[..end].trim(), None),
+        };
+        let value = name.strip_prefix("--").and_then(|name| {
+            variables.iter().find(|(n, _)| n.as_str() == name).map(|(_, value)| value.as_str())
+        });
+        match value.or(fallback) {
+            Some(value) => result.push_str(value),
+            None => result.push_str(&rest[start..start + "var(".len() + end + 1]),
+        }
+        rest = &arguments[end + 1..];
+    }
+    result.push_str(rest);
+    result
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -80,18 +218,27 @@ pub fn load_from_path(
     cache_key: ImageCacheKey,
 ) -> Result<ParsedSVG, std::io::Error> {
     let svg_data = std::fs::read(std::path::Path::new(&path.as_str()))?;
-
-    i_slint_common::sharedfontdb::FONT_DB.with_borrow(|db| {
-        let option = usvg::Options { fontdb: (*db).clone(), ..Default::default() };
-        usvg::Tree::from_data(&svg_data, &option)
-            .map(|svg| ParsedSVG { svg_tree: svg, cache_key })
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
-    })
+    parse(svg_data.into(), SvgStyle::default(), cache_key)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
 }
 
 pub fn load_from_data(slice: &[u8], cache_key: ImageCacheKey) -> Result<ParsedSVG, usvg::Error> {
-    i_slint_common::sharedfontdb::FONT_DB.with_borrow(|db| {
-        let option = usvg::Options { fontdb: (*db).clone(), ..Default::default() };
-        usvg::Tree::from_data(slice, &option).map(|svg| ParsedSVG { svg_tree: svg, cache_key })
-    })
+    parse(slice.into(), SvgStyle::default(), cache_key)
+}
+
+#[test]
+fn test_substitute_variables() {
+    let variables = [("accent".into(), "#ff0000".into())];
+    assert_eq!(
+        substitute_variables(r##"<path fill="var(--accent)" stroke="var(--other)"/>"##, &variables),
+        r##"<path fill="#ff0000" stroke="var(--other)"/>"##
+    );
+    assert_eq!(
+        substitute_variables(
+            "fill: var(--other, rgb(0, 0, 255)); stroke: var( --accent )",
+            &variables
+        ),
+        "fill: rgb(0, 0, 255); stroke: #ff0000"
+    );
+    assert_eq!(substitute_variables("var(--accent", &variables), "var(--accent");
 }
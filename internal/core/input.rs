@@ -21,6 +21,9 @@
 use core::cell::Cell;
 use core::pin::Pin;
 use core::time::Duration;
+#[cfg(not(feature = "std"))]
+#[allow(unused)]
+use num_traits::Float;
 
 /// A mouse or touch event
 ///
@@ -88,6 +91,38 @@ fn set_click_count(&mut self, count: u8) {
     }
 }
 
+/// The description of the pen or finger that produces the pointer event being processed, as
+/// reported by the backend with [`WindowEvent::PointerDetailsChanged`](crate::platform::WindowEvent::PointerDetailsChanged).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PointerDetails {
+    pub tool_type: crate::items::PointerToolType,
+    pub pressure: f32,
+    pub tilt_x: f32,
+    pub tilt_y: f32,
+    pub twist: f32,
+}
+
+/// The device of the scroll event being processed, as reported by the backend with
+/// [`WindowEvent::ScrollDetailsChanged`](crate::platform::WindowEvent::ScrollDetailsChanged).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ScrollDetails {
+    pub device: crate::items::PointerScrollDevice,
+    pub line_delta_x: f32,
+    pub line_delta_y: f32,
+}
+
+/// The number of logical pixels per line of a scroll event, to compute the line deltas when the
+/// backend doesn't report them.
+pub(crate) const SCROLL_LINE_HEIGHT: f32 = 60.;
+
+/// The physical key of the key event being processed, as reported by the backend with
+/// [`WindowEvent::KeyDetailsChanged`](crate::platform::WindowEvent::KeyDetailsChanged).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct KeyDetails {
+    pub physical_key: SharedString,
+    pub scancode: u32,
+}
+
 /// This value is returned by the `input_event` function of an Item
 /// to notify the run-time about how the event was handled and
 /// what the next steps are.
@@ -174,12 +209,50 @@ fn from(k: Key) -> Self {
                     char::from(k).into()
                 }
             }
+
+            /// Returns the code of the key with the given name in the `Key` enum.
+            pub(crate) fn from_name(name: &str) -> Option<char> {
+                match name {
+                    $(stringify!($name) => Some($name),)*
+                    _ => None,
+                }
+            }
         };
     }
 
     i_slint_common::for_each_special_keys!(declare_consts_for_special_keys);
 }
 
+/// Returns true if the key event is the keyboard shortcut of a menu entry, written as modifiers
+/// (`Ctrl`, `Shift`, `Alt`, `Meta`) and a key separated by `+`, like `Ctrl+Shift+S`. The key is a
+/// character or the name of a [`Key`](key_codes::Key), like `F5` or `Delete`.
+pub(crate) fn matches_shortcut(shortcut: &str, event: &KeyEvent) -> bool {
+    let (modifier_names, key) = match shortcut.strip_suffix("++") {
+        Some(modifier_names) => (modifier_names, "+"),
+        None => shortcut.rsplit_once('+').unwrap_or(("", shortcut)),
+    };
+    let Some(key) = key_codes::from_name(key).or_else(|| {
+        let mut chars = key.chars();
+        chars.next().filter(|_| chars.next().is_none())
+    }) else {
+        return false;
+    };
+    let mut modifiers = KeyboardModifiers::default();
+    for name in modifier_names.split('+').filter(|name| !name.is_empty()) {
+        match name {
+            "Ctrl" | "Control" => modifiers.control = true,
+            "Shift" => modifiers.shift = true,
+            "Alt" => modifiers.alt = true,
+            "Meta" => modifiers.meta = true,
+            _ => return false,
+        }
+    }
+    let mut text = event.text.chars();
+    let Some(pressed) = text.next().filter(|_| text.next().is_none()) else { return false };
+    // Letters are compared regardless of the case, which depends on the Shift key
+    pressed.to_lowercase().eq(key.to_lowercase()) && event.modifiers == modifiers
+}
+
 /// Internal struct to maintain the pressed/released state of the keys that
 /// map to keyboard modifiers.
 #[derive(Clone, Copy, Default, Debug)]
@@ -743,6 +816,229 @@ pub(crate) fn process_delayed_event(
     mouse_input_state
 }
 
+/// Converts `path` to a `file://` URI, as used in the `text/uri-list` format of dragged files,
+/// percent-encoding the characters that aren't allowed in URIs.
+#[cfg(feature = "std")]
+pub fn file_uri(path: &std::path::Path) -> alloc::string::String {
+    let path = path.to_string_lossy();
+    #[cfg(target_os = "windows")]
+    let path = path.replace('\\', "/");
+    let mut uri =
+        alloc::string::String::from(if path.starts_with('/') { "file://" } else { "file:///" });
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' | b':' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&alloc::format!("%{byte:02X}")),
+        }
+    }
+    uri
+}
+
+/// The fingers that touch the window, and the gesture they make.
+#[derive(Default)]
+pub(crate) struct TouchState {
+    /// The position of each finger, in window coordinates, in the order they touched the window.
+    points: Vec<(u64, LogicalPoint)>,
+    /// The finger that is delivered to the items as a mouse, until a gesture starts or it's released.
+    primary: Option<u64>,
+    gesture: Option<PinchGesture>,
+}
+
+/// A gesture made with two fingers in a `PinchGestureHandler`.
+struct PinchGesture {
+    handler: ItemWeak,
+    ids: [u64; 2],
+    /// The distance between the fingers when the gesture started.
+    start_distance: f32,
+    /// The center between the fingers when the gesture started, in window coordinates.
+    start_center: LogicalPoint,
+    /// The angle of the line between the fingers at the last update, in degrees.
+    last_angle: f32,
+    /// The rotation since the gesture started, in degrees.
+    rotation: f32,
+}
+
+impl TouchState {
+    /// Records a finger that touched the window. Returns true if it's the first one, which is
+    /// delivered to the items as a mouse.
+    pub(crate) fn press(&mut self, id: u64, position: LogicalPoint) -> bool {
+        self.points.retain(|(i, _)| *i != id);
+        self.points.push((id, position));
+        if self.points.len() == 1 {
+            self.primary = Some(id);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Records the new position of a finger. Returns true if it's delivered to the items as a mouse.
+    pub(crate) fn move_to(&mut self, id: u64, position: LogicalPoint) -> bool {
+        if let Some(point) = self.points.iter_mut().find(|(i, _)| *i == id) {
+            point.1 = position;
+        }
+        self.primary == Some(id)
+    }
+
+    /// Forgets a finger that was lifted. Returns true if it was delivered to the items as a mouse.
+    pub(crate) fn release(&mut self, id: u64) -> bool {
+        self.points.retain(|(i, _)| *i != id);
+        if self.points.is_empty() {
+            self.primary = None;
+        }
+        if self.primary == Some(id) {
+            self.primary = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Starts a gesture with the first two fingers in the topmost enabled `PinchGestureHandler` under
+    /// the first one, if there is one. The first finger then no longer acts as a mouse.
+    pub(crate) fn start_gesture(&mut self, root: &ItemRc) -> Option<ItemRc> {
+        if self.gesture.is_some() {
+            return None;
+        }
+        let [(first_id, first), (second_id, second), ..] = self.points[..] else { return None };
+        let handler = find_pinch_gesture_handler(root, first)?;
+        let line = second - first;
+        self.gesture = Some(PinchGesture {
+            handler: handler.downgrade(),
+            ids: [first_id, second_id],
+            start_distance: line.cast::<f32>().length(),
+            start_center: midpoint(first, second),
+            last_angle: (line.y as f32).atan2(line.x as f32).to_degrees(),
+            rotation: 0.,
+        });
+        self.primary = None;
+        Some(handler)
+    }
+
+    /// Returns the handler of the gesture, with the scale, the rotation in degrees, the pan, and the
+    /// center in window coordinates, if `id` is one of the fingers of the gesture.
+    pub(crate) fn update_gesture(
+        &mut self,
+        id: u64,
+    ) -> Option<(ItemRc, f32, f32, LogicalVector, LogicalPoint)> {
+        let gesture = self.gesture.as_mut()?;
+        if !gesture.ids.contains(&id) {
+            return None;
+        }
+        let position = |id| self.points.iter().find(|(i, _)| *i == id).map(|(_, p)| *p);
+        let (first, second) = (position(gesture.ids[0])?, position(gesture.ids[1])?);
+        let handler = gesture.handler.upgrade()?;
+        let line = second - first;
+        let angle = (line.y as f32).atan2(line.x as f32).to_degrees();
+        // Accumulate the changes so that the rotation doesn't jump when it goes past 180 degrees
+        let mut delta = angle - gesture.last_angle;
+        if delta > 180. {
+            delta -= 360.;
+        } else if delta < -180. {
+            delta += 360.;
+        }
+        gesture.rotation += delta;
+        gesture.last_angle = angle;
+        let scale = if gesture.start_distance > 0. {
+            line.cast::<f32>().length() / gesture.start_distance
+        } else {
+            1.
+        };
+        let center = midpoint(first, second);
+        Some((handler, scale, gesture.rotation, center - gesture.start_center, center))
+    }
+
+    /// Ends the gesture if `id` is one of its fingers, and returns its handler.
+    pub(crate) fn end_gesture(&mut self, id: u64) -> Option<ItemRc> {
+        if !self.gesture.as_ref()?.ids.contains(&id) {
+            return None;
+        }
+        self.gesture.take()?.handler.upgrade()
+    }
+}
+
+fn midpoint(a: LogicalPoint, b: LogicalPoint) -> LogicalPoint {
+    a.cast::<f32>().lerp(b.cast::<f32>(), 0.5).cast()
+}
+
+/// Returns the topmost enabled `PinchGestureHandler` at `position` (relative to the parent of `item_rc`).
+fn find_pinch_gesture_handler(item_rc: &ItemRc, position: LogicalPoint) -> Option<ItemRc> {
+    let geom = item_rc.geometry();
+    let contains = geom.contains(position);
+    if !contains && crate::item_rendering::is_clipping_item(item_rc.borrow()) {
+        return None;
+    }
+    let position = position - geom.origin.to_vector();
+
+    // Children are in front of their parent, and later siblings in front of earlier ones.
+    let mut child = item_rc.last_child();
+    while let Some(current) = child {
+        if let Some(found) = find_pinch_gesture_handler(&current, position) {
+            return Some(found);
+        }
+        child = current.previous_sibling();
+    }
+
+    let handler = item_rc.downcast::<crate::items::PinchGestureHandler>()?;
+    (contains && handler.as_pin_ref().enabled()).then(|| item_rc.clone())
+}
+
+/// Returns the region of the topmost enabled `WindowFrameArea` at `position` (relative to the parent
+/// of `item_rc`).
+pub(crate) fn find_window_frame_region(
+    item_rc: &ItemRc,
+    position: LogicalPoint,
+) -> Option<crate::items::WindowFrameRegion> {
+    let geom = item_rc.geometry();
+    let contains = geom.contains(position);
+    if !contains && crate::item_rendering::is_clipping_item(item_rc.borrow()) {
+        return None;
+    }
+    let position = position - geom.origin.to_vector();
+
+    // Children are in front of their parent, and later siblings in front of earlier ones.
+    let mut child = item_rc.last_child();
+    while let Some(current) = child {
+        if let Some(found) = find_window_frame_region(&current, position) {
+            return Some(found);
+        }
+        child = current.previous_sibling();
+    }
+
+    let area = item_rc.downcast::<crate::items::WindowFrameArea>()?;
+    let area = area.as_pin_ref();
+    (contains && area.enabled()).then(|| area.region())
+}
+
+/// Returns the topmost `DropArea` at `position` (relative to the parent of `item_rc`) that accepts
+/// the event, together with the position in its coordinates.
+pub(crate) fn find_drop_area(
+    item_rc: &ItemRc,
+    position: LogicalPoint,
+    event: &crate::items::DropEvent,
+) -> Option<(ItemRc, LogicalPoint)> {
+    let geom = item_rc.geometry();
+    let contains = geom.contains(position);
+    if !contains && crate::item_rendering::is_clipping_item(item_rc.borrow()) {
+        return None;
+    }
+    let position = position - geom.origin.to_vector();
+
+    // Children are in front of their parent, and later siblings in front of earlier ones.
+    let mut child = item_rc.last_child();
+    while let Some(current) = child {
+        if let Some(found) = find_drop_area(&current, position, event) {
+            return Some(found);
+        }
+        child = current.previous_sibling();
+    }
+
+    let area = item_rc.downcast::<crate::items::DropArea>()?;
+    (contains && area.as_pin_ref().accepts(event)).then(|| (item_rc.clone(), position))
+}
+
 fn send_mouse_event_to_item(
     mouse_event: MouseEvent,
     item_rc: ItemRc,
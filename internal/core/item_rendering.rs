@@ -317,6 +317,8 @@ pub trait RenderText {
     fn overflow(self: Pin<&Self>) -> TextOverflow;
     fn letter_spacing(self: Pin<&Self>) -> LogicalLength;
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle);
+    fn writing_mode(self: Pin<&Self>) -> TextWritingMode;
+    fn max_lines(self: Pin<&Self>) -> Option<usize>;
 }
 
 /// Trait used to render each items.
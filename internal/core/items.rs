@@ -45,6 +45,7 @@
 pub use self::component_container::*;
 mod flickable;
 pub use flickable::Flickable;
+pub(crate) use flickable::{scroll_above, scroll_into_view};
 mod text;
 pub use text::*;
 mod input_items;
@@ -65,8 +66,11 @@
 pub type KeyEventArg = (KeyEvent,);
 type PointerEventArg = (PointerEvent,);
 type PointerScrollEventArg = (PointerScrollEvent,);
+type DropEventArg = (DropEvent,);
+type CompositionEventArg = (CompositionEvent,);
 type PointArg = (Point,);
 type MenuEntryArg = (MenuEntry,);
+type StringArg = (SharedString,);
 type MenuEntryModel = crate::model::ModelRc<MenuEntry>;
 
 #[cfg(all(feature = "ffi", windows))]
@@ -540,6 +544,18 @@ fn slint_get_FocusScopeVTable() -> FocusScopeVTable for FocusScope
     fn slint_get_SwipeGestureHandlerVTable() -> SwipeGestureHandlerVTable for SwipeGestureHandler
 }
 
+declare_item_vtable! {
+    fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
+
+declare_item_vtable! {
+    fn slint_get_PinchGestureHandlerVTable() -> PinchGestureHandlerVTable for PinchGestureHandler
+}
+
+declare_item_vtable! {
+    fn slint_get_WindowFrameAreaVTable() -> WindowFrameAreaVTable for WindowFrameArea
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -954,12 +970,25 @@ pub struct WindowItem {
     pub title: Property<SharedString>,
     pub no_frame: Property<bool>,
     pub resize_border_width: Property<LogicalLength>,
+    pub resize_increment_width: Property<LogicalLength>,
+    pub resize_increment_height: Property<LogicalLength>,
+    pub aspect_ratio: Property<f32>,
     pub always_on_top: Property<bool>,
+    pub window_level: Property<WindowLevel>,
+    pub background_effect: Property<WindowBackgroundEffect>,
+    pub preferred_decoration_mode: Property<WindowDecorationMode>,
+    pub window_opacity: Property<f32>,
     pub full_screen: Property<bool>,
+    pub maximized: Property<bool>,
+    pub minimized: Property<bool>,
+    pub active: Property<bool>,
+    pub occluded: Property<bool>,
+    pub decoration_mode: Property<WindowDecorationMode>,
     pub icon: Property<crate::graphics::Image>,
     pub default_font_family: Property<SharedString>,
     pub default_font_size: Property<LogicalLength>,
     pub default_font_weight: Property<i32>,
+    pub key_navigation: Property<KeyNavigation>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -6,8 +6,8 @@
 //! The `Flickable` item
 
 use super::{
-    Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventResult, PointerEventButton, RenderingResult,
-    VoidArg,
+    Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventResult, PointerEventButton,
+    PointerScrollDevice, RenderingResult, VoidArg,
 };
 use crate::animations::{EasingCurve, Instant};
 use crate::input::{
@@ -24,6 +24,7 @@
 use crate::rtti::*;
 use crate::window::WindowAdapter;
 use crate::Callback;
+use crate::Coord;
 use crate::Property;
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
@@ -51,6 +52,9 @@ pub struct Flickable {
     pub viewport_height: Property<LogicalLength>,
 
     pub interactive: Property<bool>,
+    pub fling_deceleration: Property<f32>,
+    pub fling_max_velocity: Property<f32>,
+    pub overscroll_bounce: Property<bool>,
 
     pub flicked: Callback<VoidArg>,
 
@@ -185,6 +189,14 @@ fn deref(&self) -> &Self::Target {
 pub(super) const DURATION_THRESHOLD: Duration = Duration::from_millis(500);
 /// The delay to which press are forwarded to the inner item
 pub(super) const FORWARD_DELAY: Duration = Duration::from_millis(100);
+/// The duration of the animation that scrolls by one mouse wheel tick
+const WHEEL_ANIMATION_DURATION: Duration = Duration::from_millis(100);
+/// The duration of a fling when no `fling-deceleration` is set
+const FLING_DURATION: Duration = Duration::from_millis(250);
+/// The duration of the animation that brings the viewport back within the bounds after an overscroll
+const BOUNCE_BACK_DURATION: Duration = Duration::from_millis(300);
+/// How hard it is to pull the viewport past its bounds. Lower values give more resistance.
+const RUBBER_BAND_RESISTANCE: f32 = 0.55;
 
 #[derive(Default, Debug)]
 struct FlickableDataInner {
@@ -194,6 +206,12 @@ struct FlickableDataInner {
     pressed_viewport_pos: LogicalPoint,
     /// Set to true if the flickable is flicking and capturing all mouse event, not forwarding back to the children
     capture_events: bool,
+    /// The position the viewport animates to after a mouse wheel tick, and when that tick happened.
+    /// Ticks that follow quickly continue from that position rather than from the animated one.
+    wheel_target: Option<(LogicalPoint, Instant)>,
+    /// Incremented on each release, so that the bounce back scheduled after a fling is skipped when
+    /// another one started in the meantime.
+    fling_generation: u32,
 }
 
 #[derive(Default, Debug)]
@@ -309,7 +327,11 @@ fn handle_mouse(
                     };
 
                     if inner.capture_events || should_capture() {
-                        let new_pos = ensure_in_bound(flick, new_pos, flick_rc);
+                        let new_pos = if flick.overscroll_bounce() {
+                            overscroll_position(flick, new_pos, flick_rc)
+                        } else {
+                            ensure_in_bound(flick, new_pos, flick_rc)
+                        };
 
                         let old_pos = (x.get(), y.get());
                         x.set(new_pos.x_length());
@@ -346,13 +368,39 @@ fn handle_mouse(
                 } else {
                     LogicalVector::new(delta_x, delta_y)
                 };
-                let new_pos = ensure_in_bound(flick, old_pos + delta, flick_rc);
+                let is_wheel = window_adapter
+                    .window()
+                    .0
+                    .scroll_details
+                    .get()
+                    .is_some_and(|details| details.device == PointerScrollDevice::Wheel);
+                let now = crate::animations::current_tick();
+                let start_pos = match inner.wheel_target.take() {
+                    Some((target, time)) if is_wheel && now - time < WHEEL_ANIMATION_DURATION => {
+                        target
+                    }
+                    _ => old_pos,
+                };
+                let new_pos = ensure_in_bound(flick, start_pos + delta, flick_rc);
 
                 let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
                 let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
                 let old_pos = (viewport_x.get(), viewport_y.get());
-                viewport_x.set(new_pos.x_length());
-                viewport_y.set(new_pos.y_length());
+                if is_wheel {
+                    // Wheel ticks are coarse, so animate them instead of jumping by a whole line.
+                    // Pixel deltas from touchpads are already smooth and are applied as they come.
+                    let anim = PropertyAnimation {
+                        duration: WHEEL_ANIMATION_DURATION.as_millis() as _,
+                        easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
+                        ..PropertyAnimation::default()
+                    };
+                    viewport_x.set_animated_value(new_pos.x_length(), anim.clone());
+                    viewport_y.set_animated_value(new_pos.y_length(), anim);
+                    inner.wheel_target = Some((new_pos, now));
+                } else {
+                    viewport_x.set(new_pos.x_length());
+                    viewport_y.set(new_pos.y_length());
+                }
                 if old_pos.0 != new_pos.x_length() || old_pos.1 != new_pos.y_length() {
                     (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
                 }
@@ -367,6 +415,8 @@ fn mouse_released(
         event: MouseEvent,
         flick_rc: &ItemRc,
     ) {
+        inner.fling_generation = inner.fling_generation.wrapping_add(1);
+        let mut flung = false;
         if let (Some(pressed_time), Some(pos)) = (inner.pressed_time, event.position()) {
             let dist = (pos - inner.pressed_pos).cast::<f32>();
 
@@ -375,17 +425,38 @@ fn mouse_released(
                 && dist.square_length() > (DISTANCE_THRESHOLD.get() * DISTANCE_THRESHOLD.get()) as _
                 && millis > 1
             {
-                let speed = dist / (millis as f32);
+                // in logical pixels per millisecond
+                let mut speed = dist / (millis as f32);
+                let max_velocity = flick.fling_max_velocity() / 1000.;
+                if max_velocity > 0. && speed.length() > max_velocity {
+                    speed = speed * (max_velocity / speed.length());
+                }
 
-                let duration = 250;
-                let final_pos = ensure_in_bound(
-                    flick,
-                    (inner.pressed_viewport_pos.cast() + dist + speed * (duration as f32)).cast(),
-                    flick_rc,
-                );
+                // in logical pixels per millisecond squared
+                let deceleration = flick.fling_deceleration() / 1_000_000.;
+                let (duration, travel, easing) = if deceleration > 0. {
+                    // With a constant deceleration, the fling stops after speed / deceleration, and
+                    // travels half the distance it would have at a constant speed.
+                    // The easing curve is exactly the one of a constant deceleration.
+                    let duration = speed.length() / deceleration;
+                    (
+                        duration,
+                        speed * (duration / 2.),
+                        EasingCurve::CubicBezier([1. / 3., 2. / 3., 2. / 3., 1.]),
+                    )
+                } else {
+                    let duration = FLING_DURATION.as_millis() as f32;
+                    (duration, speed * duration, EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]))
+                };
+                let target = (inner.pressed_viewport_pos.cast() + dist + travel).cast();
+                let final_pos = if flick.overscroll_bounce() {
+                    overscroll_position(flick, target, flick_rc)
+                } else {
+                    ensure_in_bound(flick, target, flick_rc)
+                };
                 let anim = PropertyAnimation {
-                    duration,
-                    easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
+                    duration: duration as _,
+                    easing,
                     ..PropertyAnimation::default()
                 };
 
@@ -397,10 +468,37 @@ fn mouse_released(
                 if old_pos.0 != final_pos.x_length() || old_pos.1 != final_pos.y_length() {
                     (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
                 }
+                flung = true;
+
+                if final_pos != ensure_in_bound(flick, final_pos, flick_rc) {
+                    // The fling went past the bounds, so bounce back once it ends
+                    let generation = inner.fling_generation;
+                    let flick_weak = flick_rc.downgrade();
+                    crate::timers::Timer::single_shot(
+                        Duration::from_millis(duration as u64),
+                        move || {
+                            let Some(flick_rc) = flick_weak.upgrade() else { return };
+                            let Some(flick) = flick_rc.downcast::<Flickable>() else { return };
+                            let flick = flick.as_pin_ref();
+                            {
+                                let inner = flick.data.inner.borrow();
+                                if inner.fling_generation != generation
+                                    || inner.pressed_time.is_some()
+                                {
+                                    return;
+                                }
+                            }
+                            bounce_back(flick, &flick_rc);
+                        },
+                    );
+                }
             }
         }
         inner.capture_events = false; // FIXME: should only be set to false once the flick animation is over
         inner.pressed_time = None;
+        if !flung && flick.overscroll_bounce() {
+            bounce_back(flick, flick_rc);
+        }
     }
 }
 
@@ -421,6 +519,119 @@ fn ensure_in_bound(flick: Pin<&Flickable>, p: LogicalPoint, flick_rc: &ItemRc) -
     p.max(min).min(max)
 }
 
+/// Like [`ensure_in_bound`], but lets the viewport go past the bounds, with a resistance that increases
+/// the further it goes. This only applies in the directions in which the viewport is larger than the Flickable.
+fn overscroll_position(flick: Pin<&Flickable>, p: LogicalPoint, flick_rc: &ItemRc) -> LogicalPoint {
+    let bounded = ensure_in_bound(flick, p, flick_rc);
+    let geo = flick_rc.geometry();
+    let vw = (Flickable::FIELD_OFFSETS.viewport_width).apply_pin(flick).get();
+    let vh = (Flickable::FIELD_OFFSETS.viewport_height).apply_pin(flick).get();
+
+    // The offset approaches the size of the Flickable but never reaches it
+    let rubber_band = |overscroll: LogicalLength, size: LogicalLength| {
+        let (overscroll, size) = (overscroll.get() as f32, size.get() as f32);
+        if size <= 0. {
+            return LogicalLength::zero();
+        }
+        let offset = (1. - 1. / (overscroll.abs() * RUBBER_BAND_RESISTANCE / size + 1.)) * size;
+        LogicalLength::new((offset * overscroll.signum()) as _)
+    };
+
+    let mut x = bounded.x_length();
+    if vw > geo.width_length() {
+        x += rubber_band(p.x_length() - bounded.x_length(), geo.width_length());
+    }
+    let mut y = bounded.y_length();
+    if vh > geo.height_length() {
+        y += rubber_band(p.y_length() - bounded.y_length(), geo.height_length());
+    }
+    LogicalPoint::from_lengths(x, y)
+}
+
+/// Animates the viewport back within the bounds after it was dragged or flung past them.
+fn bounce_back(flick: Pin<&Flickable>, flick_rc: &ItemRc) {
+    let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
+    let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
+    let pos = LogicalPoint::from_lengths(viewport_x.get(), viewport_y.get());
+    let bounded = ensure_in_bound(flick, pos, flick_rc);
+    if bounded == pos {
+        return;
+    }
+    let anim = PropertyAnimation {
+        duration: BOUNCE_BACK_DURATION.as_millis() as _,
+        easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
+        ..PropertyAnimation::default()
+    };
+    viewport_x.set_animated_value(bounded.x_length(), anim.clone());
+    viewport_y.set_animated_value(bounded.y_length(), anim);
+    (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
+}
+
+/// Scrolls the Flickables that contain `item` so that its bottom edge is at or above `limit_y`, in
+/// window coordinates. This is used to keep the focused item visible above the virtual keyboard.
+pub(crate) fn scroll_above(item: &ItemRc, limit_y: LogicalLength) {
+    let geometry = item.geometry();
+    let bottom = item.map_to_window(geometry.origin).y_length() + geometry.height_length();
+    let mut overflow = bottom - limit_y;
+    let mut current = item.parent_item();
+    while overflow > LogicalLength::zero() {
+        let Some(parent) = current else { return };
+        if let Some(flick) = parent.downcast::<Flickable>() {
+            let flick = flick.as_pin_ref();
+            let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get();
+            let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
+            let old_y = viewport_y.get();
+            let new_pos = ensure_in_bound(
+                flick,
+                LogicalPoint::from_lengths(viewport_x, old_y - overflow),
+                &parent,
+            );
+            viewport_y.set(new_pos.y_length());
+            // Whatever this Flickable couldn't scroll is left for the outer ones
+            overflow -= old_y - new_pos.y_length();
+        }
+        current = parent.parent_item();
+    }
+}
+
+/// Scrolls the Flickables that contain `item` as little as possible so that it is visible in each of
+/// them. This is used to show the item that received the focus through key navigation.
+pub(crate) fn scroll_into_view(item: &ItemRc) {
+    // The offset needed to move the range min..max into 0..extent, preferring to show its start
+    fn offset(min: Coord, max: Coord, extent: Coord) -> Coord {
+        if min < 0 as Coord {
+            -min
+        } else if max > extent {
+            (extent - max).max(-min)
+        } else {
+            0 as Coord
+        }
+    }
+
+    // The geometry of the item in the coordinates of the parent of `current`
+    let mut rect = item.geometry();
+    let mut current = item.clone();
+    while let Some(parent) = current.parent_item() {
+        if let Some(flick) = parent.downcast::<Flickable>() {
+            let flick = flick.as_pin_ref();
+            let size = parent.geometry().size;
+            let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
+            let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
+            let old_pos = LogicalPoint::from_lengths(viewport_x.get(), viewport_y.get());
+            let delta = LogicalVector::new(
+                offset(rect.min_x(), rect.max_x(), size.width),
+                offset(rect.min_y(), rect.max_y(), size.height),
+            );
+            let new_pos = ensure_in_bound(flick, old_pos + delta, &parent);
+            viewport_x.set(new_pos.x_length());
+            viewport_y.set(new_pos.y_length());
+            rect = rect.translate(new_pos - old_pos);
+        }
+        rect = rect.translate(parent.geometry().origin.to_vector());
+        current = parent;
+    }
+}
+
 /// # Safety
 /// This must be called using a non-null pointer pointing to a chunk of memory big enough to
 /// hold a FlickableDataBox
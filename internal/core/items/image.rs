@@ -9,7 +9,7 @@
 */
 use super::{
     ImageFit, ImageHorizontalAlignment, ImageRendering, ImageTiling, ImageVerticalAlignment, Item,
-    ItemConsts, ItemRc, RenderingResult,
+    ItemConsts, ItemRc, ItemVTable, RenderingResult,
 };
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
@@ -24,9 +24,10 @@
 use crate::window::WindowAdapter;
 use crate::{Brush, Coord, Property};
 use alloc::rc::Rc;
-use const_field_offset::FieldOffsets;
+use const_field_offset::{AllowPin, FieldOffset, FieldOffsets};
 use core::pin::Pin;
 use i_slint_core_macros::*;
+use vtable::HasStaticVTable;
 
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
@@ -39,6 +40,9 @@ pub struct ImageItem {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub playing: Property<bool>,
+    pub looping: Property<bool>,
+    pub current_frame: Property<i32>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -106,6 +110,14 @@ fn render(
         size: LogicalSize,
     ) -> RenderingResult {
         (*backend).draw_image(self, self_rc, size, &self.cached_rendering_data);
+        schedule_next_frame::<Self>(
+            &self.source(),
+            self.playing(),
+            self.looping(),
+            self.current_frame(),
+            self_rc,
+            Self::FIELD_OFFSETS.current_frame,
+        );
         RenderingResult::ContinueRenderingChildren
     }
 }
@@ -116,7 +128,7 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        frame_to_show(self.source(), self.current_frame())
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -140,8 +152,63 @@ fn alignment(self: Pin<&Self>) -> (ImageHorizontalAlignment, ImageVerticalAlignm
     }
 
     fn tiling(self: Pin<&Self>) -> (ImageTiling, ImageTiling) {
-        Default::default()
+        tiling_for_fit(self.image_fit(), Default::default())
+    }
+}
+
+/// Returns the tiling to render with: `image-fit: tile` repeats the image in both directions, unless
+/// the tiling is set explicitly.
+fn tiling_for_fit(
+    image_fit: ImageFit,
+    tiling: (ImageTiling, ImageTiling),
+) -> (ImageTiling, ImageTiling) {
+    if image_fit == ImageFit::Tile && tiling == Default::default() {
+        (ImageTiling::Repeat, ImageTiling::Repeat)
+    } else {
+        tiling
+    }
+}
+
+/// Returns the frame of `source` to show when the `current-frame` property is `frame`.
+fn frame_to_show(source: crate::graphics::Image, frame: i32) -> crate::graphics::Image {
+    let frame_count = source.frame_count();
+    if frame_count < 2 {
+        return source;
+    }
+    source.frame(frame.rem_euclid(frame_count as i32) as usize).unwrap_or(source)
+}
+
+/// Called after rendering the frame of an animated image to advance the `current-frame` property
+/// once the frame was shown for its duration, which renders the next frame.
+fn schedule_next_frame<T: HasStaticVTable<ItemVTable> + 'static>(
+    source: &crate::graphics::Image,
+    playing: bool,
+    looping: bool,
+    frame: i32,
+    self_rc: &ItemRc,
+    current_frame: FieldOffset<T, Property<i32>, AllowPin>,
+) {
+    let frame_count = source.frame_count();
+    if !playing || frame_count < 2 {
+        return;
+    }
+    let index = frame.rem_euclid(frame_count as i32) as usize;
+    if !looping && index + 1 == frame_count {
+        return;
     }
+    let Some(duration) = source.frame_duration(index) else { return };
+    let next_frame = ((index + 1) % frame_count) as i32;
+    let self_weak = self_rc.downgrade();
+    crate::timers::Timer::single_shot(duration, move || {
+        let Some(item) = self_weak.upgrade().and_then(|item| item.downcast::<T>()) else {
+            return;
+        };
+        let current_frame = current_frame.apply_pin(item.as_pin_ref());
+        // The item is rendered several times while a frame is shown, only the first timer advances
+        if current_frame.get_untracked() == frame {
+            current_frame.set(next_frame);
+        }
+    });
 }
 
 impl ItemConsts for ImageItem {
@@ -162,6 +229,9 @@ pub struct ClippedImage {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub playing: Property<bool>,
+    pub looping: Property<bool>,
+    pub current_frame: Property<i32>,
     pub source_clip_x: Property<i32>,
     pub source_clip_y: Property<i32>,
     pub source_clip_width: Property<i32>,
@@ -243,6 +313,14 @@ fn render(
         size: LogicalSize,
     ) -> RenderingResult {
         (*backend).draw_image(self, self_rc, size, &self.cached_rendering_data);
+        schedule_next_frame::<Self>(
+            &self.source(),
+            self.playing(),
+            self.looping(),
+            self.current_frame(),
+            self_rc,
+            Self::FIELD_OFFSETS.current_frame,
+        );
         RenderingResult::ContinueRenderingChildren
     }
 }
@@ -253,7 +331,7 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        frame_to_show(self.source(), self.current_frame())
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -282,7 +360,7 @@ fn alignment(self: Pin<&Self>) -> (ImageHorizontalAlignment, ImageVerticalAlignm
     }
 
     fn tiling(self: Pin<&Self>) -> (ImageTiling, ImageTiling) {
-        (self.horizontal_tiling(), self.vertical_tiling())
+        tiling_for_fit(self.image_fit(), (self.horizontal_tiling(), self.vertical_tiling()))
     }
 }
 
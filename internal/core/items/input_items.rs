@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use super::{
-    EventResult, Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventArg, MouseCursor, PointerEvent,
-    PointerEventArg, PointerEventButton, PointerEventKind, PointerScrollEvent,
-    PointerScrollEventArg, RenderingResult, VoidArg,
+    CompositionEvent, CompositionEventArg, DropEvent, DropEventArg, EventResult, Item, ItemConsts,
+    ItemRc, ItemRendererRef, KeyEventArg, MouseCursor, PointerEvent, PointerEventArg,
+    PointerEventButton, PointerEventKind, PointerScrollDevice, PointerScrollEvent,
+    PointerScrollEventArg, PointerToolType, RenderingResult, VoidArg, WindowFrameRegion,
 };
 use crate::api::LogicalPosition;
 use crate::input::{
@@ -13,11 +14,13 @@
 };
 use crate::item_rendering::CachedRenderingData;
 use crate::layout::{LayoutInfo, Orientation};
-use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PointLengths};
+use crate::lengths::{
+    LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector, PointLengths,
+};
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
-use crate::window::{WindowAdapter, WindowInner};
-use crate::{Callback, Coord, Property};
+use crate::window::{InputMethodProperties, InputMethodRequest, WindowAdapter, WindowInner};
+use crate::{Callback, Coord, Property, SharedString};
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
 use core::cell::Cell;
@@ -74,11 +77,11 @@ fn input_event_filter_before_children(
             self.has_hover.set(false);
             if self.grabbed.replace(false) {
                 self.pressed.set(false);
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button: PointerEventButton::Other,
-                    kind: PointerEventKind::Cancel,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                self.emit_pointer_event(
+                    window_adapter,
+                    PointerEventButton::Other,
+                    PointerEventKind::Cancel,
+                );
             }
             return InputEventFilterResult::ForwardAndIgnore;
         }
@@ -120,22 +123,18 @@ fn input_event(
                     Self::FIELD_OFFSETS.pressed_y.apply_pin(self).set(position.y_length());
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(true);
                 }
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button,
-                    kind: PointerEventKind::Down,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                self.emit_pointer_event(window_adapter, button, PointerEventKind::Down);
 
                 InputEventResult::GrabMouse
             }
             MouseEvent::Exit => {
                 Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
                 if self.grabbed.replace(false) {
-                    Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                        button: PointerEventButton::Other,
-                        kind: PointerEventKind::Cancel,
-                        modifiers: window_adapter.window().0.modifiers.get().into(),
-                    },));
+                    self.emit_pointer_event(
+                        window_adapter,
+                        PointerEventButton::Other,
+                        PointerEventKind::Cancel,
+                    );
                 }
 
                 InputEventResult::EventAccepted
@@ -157,20 +156,16 @@ fn input_event(
                 if button == PointerEventButton::Left {
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
                 }
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button,
-                    kind: PointerEventKind::Up,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                self.emit_pointer_event(window_adapter, button, PointerEventKind::Up);
 
                 InputEventResult::EventAccepted
             }
             MouseEvent::Moved { .. } => {
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button: PointerEventButton::Other,
-                    kind: PointerEventKind::Move,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                self.emit_pointer_event(
+                    window_adapter,
+                    PointerEventButton::Other,
+                    PointerEventKind::Move,
+                );
                 return if self.grabbed.get() {
                     Self::FIELD_OFFSETS.moved.apply_pin(self).call(&());
                     InputEventResult::GrabMouse
@@ -179,11 +174,22 @@ fn input_event(
                 };
             }
             MouseEvent::Wheel { delta_x, delta_y, .. } => {
-                let modifiers = window_adapter.window().0.modifiers.get().into();
-                let r = Self::FIELD_OFFSETS
-                    .scroll_event
-                    .apply_pin(self)
-                    .call(&(PointerScrollEvent { delta_x, delta_y, modifiers },));
+                let window = &window_adapter.window().0;
+                let modifiers = window.modifiers.get().into();
+                let details = window.scroll_details.get().unwrap_or(crate::input::ScrollDetails {
+                    device: PointerScrollDevice::Unknown,
+                    line_delta_x: delta_x as f32 / crate::input::SCROLL_LINE_HEIGHT,
+                    line_delta_y: delta_y as f32 / crate::input::SCROLL_LINE_HEIGHT,
+                });
+                let r =
+                    Self::FIELD_OFFSETS.scroll_event.apply_pin(self).call(&(PointerScrollEvent {
+                        delta_x,
+                        delta_y,
+                        modifiers,
+                        device: details.device,
+                        line_delta_x: details.line_delta_x,
+                        line_delta_y: details.line_delta_y,
+                    },));
                 if self.grabbed.get() {
                     InputEventResult::GrabMouse
                 } else {
@@ -230,6 +236,40 @@ fn render(
     }
 }
 
+impl TouchArea {
+    /// Invokes the `pointer-event` callback, with the details of the pen or finger if the backend
+    /// reported them for the event being processed.
+    fn emit_pointer_event(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        button: PointerEventButton,
+        kind: PointerEventKind,
+    ) {
+        let window = &window_adapter.window().0;
+        let details = window.pointer_details.get().unwrap_or_else(|| {
+            let pressed = kind == PointerEventKind::Down
+                || (kind == PointerEventKind::Move && self.pressed());
+            crate::input::PointerDetails {
+                tool_type: PointerToolType::Mouse,
+                pressure: if pressed { 0.5 } else { 0. },
+                tilt_x: 0.,
+                tilt_y: 0.,
+                twist: 0.,
+            }
+        });
+        Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
+            button,
+            kind,
+            modifiers: window.modifiers.get().into(),
+            tool_type: details.tool_type,
+            pressure: details.pressure,
+            tilt_x: details.tilt_x,
+            tilt_y: details.tilt_y,
+            twist: details.twist,
+        },));
+    }
+}
+
 impl ItemConsts for TouchArea {
     const cached_rendering_data_offset: const_field_offset::FieldOffset<
         TouchArea,
@@ -244,9 +284,18 @@ impl ItemConsts for TouchArea {
 pub struct FocusScope {
     pub enabled: Property<bool>,
     pub has_focus: Property<bool>,
+    pub input_method_enabled: Property<bool>,
+    pub input_method_cursor_x: Property<LogicalLength>,
+    pub input_method_cursor_y: Property<LogicalLength>,
+    pub input_method_cursor_height: Property<LogicalLength>,
     pub key_pressed: Callback<KeyEventArg, EventResult>,
     pub key_released: Callback<KeyEventArg, EventResult>,
     pub focus_changed_event: Callback<VoidArg>,
+    pub composition_started: Callback<VoidArg>,
+    pub composition_updated: Callback<CompositionEventArg>,
+    pub composition_committed: Callback<CompositionEventArg>,
+    /// The text that the input method is composing, empty when there is no composition
+    preedit_text: Property<SharedString>,
     /// FIXME: remove this
     pub cached_rendering_data: CachedRenderingData,
 }
@@ -288,8 +337,8 @@ fn input_event(
     fn key_event(
         self: Pin<&Self>,
         event: &KeyEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
     ) -> KeyEventResult {
         let r = match event.event_type {
             KeyEventType::KeyPressed => {
@@ -298,10 +347,18 @@ fn key_event(
             KeyEventType::KeyReleased => {
                 Self::FIELD_OFFSETS.key_released.apply_pin(self).call(&(event.clone(),))
             }
+            KeyEventType::UpdateComposition | KeyEventType::CommitComposition
+                if self.input_method_enabled() =>
+            {
+                self.composition_event(event);
+                EventResult::Accept
+            }
             KeyEventType::UpdateComposition | KeyEventType::CommitComposition => {
                 EventResult::Reject
             }
         };
+        // The callbacks may have moved the cursor
+        self.update_ime_if_moved(window_adapter, self_rc);
         match r {
             EventResult::Accept => KeyEventResult::EventAccepted,
             EventResult::Reject => KeyEventResult::EventIgnored,
@@ -311,8 +368,8 @@ fn key_event(
     fn focus_event(
         self: Pin<&Self>,
         event: &FocusEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
     ) -> FocusEventResult {
         if !self.enabled() {
             return FocusEventResult::FocusIgnored;
@@ -321,10 +378,25 @@ fn focus_event(
         match event {
             FocusEvent::FocusIn | FocusEvent::WindowReceivedFocus => {
                 self.has_focus.set(true);
+                if self.input_method_enabled() {
+                    WindowInner::from_pub(window_adapter.window()).set_text_input_focused(true);
+                    if let Some(w) = window_adapter.internal(crate::InternalToken) {
+                        w.input_method_request(InputMethodRequest::Enable(
+                            self.ime_properties(window_adapter, self_rc),
+                        ));
+                    }
+                }
                 Self::FIELD_OFFSETS.focus_changed_event.apply_pin(self).call(&());
             }
             FocusEvent::FocusOut | FocusEvent::WindowLostFocus => {
                 self.has_focus.set(false);
+                if self.input_method_enabled() {
+                    WindowInner::from_pub(window_adapter.window()).set_text_input_focused(false);
+                    if let Some(w) = window_adapter.internal(crate::InternalToken) {
+                        w.input_method_request(InputMethodRequest::Disable);
+                    }
+                    self.preedit_text.set(Default::default());
+                }
                 Self::FIELD_OFFSETS.focus_changed_event.apply_pin(self).call(&());
             }
         }
@@ -333,14 +405,94 @@ fn focus_event(
 
     fn render(
         self: Pin<&Self>,
-        _backend: &mut ItemRendererRef,
-        _self_rc: &ItemRc,
+        backend: &mut ItemRendererRef,
+        self_rc: &ItemRc,
         _size: LogicalSize,
     ) -> RenderingResult {
+        crate::properties::evaluate_no_tracking(|| {
+            self.update_ime_if_moved(&backend.window().window_adapter(), self_rc);
+        });
         RenderingResult::ContinueRenderingChildren
     }
 }
 
+impl FocusScope {
+    /// Calls the composition callbacks for an event of the input method.
+    fn composition_event(self: Pin<&Self>, event: &KeyEvent) {
+        // Input methods may commit text and start a new composition with the same event
+        if !event.text.is_empty() {
+            let end = event.text.len() as i32;
+            Self::FIELD_OFFSETS.composition_committed.apply_pin(self).call(&(CompositionEvent {
+                text: event.text.clone(),
+                selection_start: end,
+                selection_end: end,
+            },));
+        }
+        if event.event_type == KeyEventType::CommitComposition {
+            self.preedit_text.set(Default::default());
+            return;
+        }
+        if self.preedit_text().is_empty() && !event.preedit_text.is_empty() {
+            Self::FIELD_OFFSETS.composition_started.apply_pin(self).call(&());
+        }
+        self.preedit_text.set(event.preedit_text.clone());
+        let selection = event.preedit_clause.clone().or_else(|| event.preedit_selection.clone());
+        Self::FIELD_OFFSETS.composition_updated.apply_pin(self).call(&(CompositionEvent {
+            text: event.preedit_text.clone(),
+            selection_start: selection.as_ref().map_or(-1, |r| r.start),
+            selection_end: selection.as_ref().map_or(-1, |r| r.end),
+        },));
+    }
+
+    /// Returns the position of the `input-method-cursor-*` properties in window coordinates.
+    fn ime_cursor_origin(self: Pin<&Self>, self_rc: &ItemRc) -> LogicalPoint {
+        let cursor =
+            LogicalPoint::from_lengths(self.input_method_cursor_x(), self.input_method_cursor_y());
+        self_rc.map_to_window(self_rc.geometry().origin + cursor.to_vector())
+    }
+
+    fn ime_properties(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputMethodProperties {
+        let cursor_origin = self.ime_cursor_origin(self_rc);
+        WindowInner::from_pub(window_adapter.window()).last_ime_origin.set(cursor_origin);
+        let height = self.input_method_cursor_height().get();
+        InputMethodProperties {
+            preedit_text: self.preedit_text(),
+            cursor_rect_origin: LogicalPosition::from_euclid(cursor_origin),
+            cursor_rect_size: crate::api::LogicalSize::new(0., height as _),
+            anchor_point: LogicalPosition::new(
+                cursor_origin.x as _,
+                (cursor_origin.y + height) as _,
+            ),
+            cursor_baseline: (cursor_origin.y + height) as _,
+            ..Default::default()
+        }
+    }
+
+    /// Updates the input method when the cursor moved, so that the candidate window follows it.
+    fn update_ime_if_moved(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) {
+        if !self.has_focus() || !self.input_method_enabled() {
+            return;
+        }
+        let cursor_origin = self.ime_cursor_origin(self_rc);
+        if cursor_origin == WindowInner::from_pub(window_adapter.window()).last_ime_origin.get() {
+            return;
+        }
+        if let Some(w) = window_adapter.internal(crate::InternalToken) {
+            w.input_method_request(InputMethodRequest::Update(
+                self.ime_properties(window_adapter, self_rc),
+            ));
+        }
+    }
+}
+
 impl ItemConsts for FocusScope {
     const cached_rendering_data_offset: const_field_offset::FieldOffset<
         FocusScope,
@@ -563,3 +715,385 @@ fn cancel_impl(self: Pin<&Self>) {
     let self_rc = ItemRc::new(self_component.clone(), self_index);
     s.cancel(window_adapter, &self_rc);
 }
+
+/// The implementation of the `DropArea` element
+#[repr(C)]
+#[derive(FieldOffsets, SlintElement, Default)]
+#[pin]
+pub struct DropArea {
+    pub enabled: Property<bool>,
+    pub contains_drag: Property<bool>,
+    pub drag_x: Property<LogicalLength>,
+    pub drag_y: Property<LogicalLength>,
+    pub can_drop: Callback<DropEventArg, bool>,
+    pub dropped: Callback<DropEventArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl DropArea {
+    /// Returns true if the area is enabled and the `can-drop` callback accepts the event. Without
+    /// a `can-drop` handler, everything is accepted.
+    pub(crate) fn accepts(self: Pin<&Self>, event: &DropEvent) -> bool {
+        let can_drop = Self::FIELD_OFFSETS.can_drop.apply_pin(self);
+        self.enabled() && (!can_drop.has_handler() || can_drop.call(&(event.clone(),)))
+    }
+
+    /// Called while a drag accepted by this area moves over it, with the position in the
+    /// coordinates of the area.
+    pub(crate) fn drag_moved(self: Pin<&Self>, position: LogicalPoint) {
+        Self::FIELD_OFFSETS.drag_x.apply_pin(self).set(position.x_length());
+        Self::FIELD_OFFSETS.drag_y.apply_pin(self).set(position.y_length());
+        Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(true);
+    }
+
+    pub(crate) fn drag_exited(self: Pin<&Self>) {
+        Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+    }
+
+    pub(crate) fn drop_at(self: Pin<&Self>, position: LogicalPoint, event: DropEvent) {
+        self.drag_moved(position);
+        self.drag_exited();
+        Self::FIELD_OFFSETS.dropped.apply_pin(self).call(&(event,));
+    }
+}
+
+/// The implementation of the `PinchGestureHandler` element
+#[repr(C)]
+#[derive(FieldOffsets, SlintElement, Default)]
+#[pin]
+pub struct PinchGestureHandler {
+    pub enabled: Property<bool>,
+    pub active: Property<bool>,
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+    pub pan_x: Property<LogicalLength>,
+    pub pan_y: Property<LogicalLength>,
+    pub center: Property<LogicalPosition>,
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub ended: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for PinchGestureHandler {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for PinchGestureHandler {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl PinchGestureHandler {
+    /// Called when a second finger touched the handler, with the center between the fingers in the
+    /// coordinates of the handler.
+    pub(crate) fn gesture_started(self: Pin<&Self>, center: LogicalPoint) {
+        self.set_gesture(1., 0., LogicalVector::zero(), center);
+        Self::FIELD_OFFSETS.active.apply_pin(self).set(true);
+        Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+    }
+
+    /// Called when one of the fingers of the gesture moved, with the values relative to the start
+    /// of the gesture. Disabling the handler ends the gesture.
+    pub(crate) fn gesture_updated(
+        self: Pin<&Self>,
+        scale: f32,
+        rotation: f32,
+        pan: LogicalVector,
+        center: LogicalPoint,
+    ) {
+        if !self.active() {
+            return;
+        }
+        if !self.enabled() {
+            self.gesture_ended();
+            return;
+        }
+        self.set_gesture(scale, rotation, pan, center);
+        Self::FIELD_OFFSETS.updated.apply_pin(self).call(&());
+    }
+
+    /// Called when one of the fingers of the gesture was lifted.
+    pub(crate) fn gesture_ended(self: Pin<&Self>) {
+        if !self.active() {
+            return;
+        }
+        Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+        Self::FIELD_OFFSETS.ended.apply_pin(self).call(&());
+    }
+
+    fn set_gesture(
+        self: Pin<&Self>,
+        scale: f32,
+        rotation: f32,
+        pan: LogicalVector,
+        center: LogicalPoint,
+    ) {
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(scale);
+        Self::FIELD_OFFSETS.rotation.apply_pin(self).set(rotation);
+        Self::FIELD_OFFSETS.pan_x.apply_pin(self).set(LogicalLength::new(pan.x));
+        Self::FIELD_OFFSETS.pan_y.apply_pin(self).set(LogicalLength::new(pan.y));
+        Self::FIELD_OFFSETS
+            .center
+            .apply_pin(self)
+            .set(crate::lengths::logical_position_to_api(center));
+    }
+}
+
+/// The implementation of the `WindowFrameArea` element
+#[repr(C)]
+#[derive(FieldOffsets, SlintElement, Default)]
+#[pin]
+pub struct WindowFrameArea {
+    pub enabled: Property<bool>,
+    pub region: Property<WindowFrameRegion>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for WindowFrameArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        // Like for the TouchArea, the children can still change the cursor
+        if self.enabled() && !matches!(event, MouseEvent::Exit) {
+            if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                x.set_mouse_cursor(self.mouse_cursor());
+            }
+        }
+        InputEventFilterResult::ForwardAndInterceptGrab
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        if matches!(event, MouseEvent::Exit) {
+            if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                x.set_mouse_cursor(MouseCursor::Default);
+            }
+        }
+        if !self.enabled() {
+            return InputEventResult::EventIgnored;
+        }
+
+        let region = self.region();
+        let window = window_adapter.window();
+        match event {
+            // A client area accepts the events, so that the areas it's in don't move the window
+            MouseEvent::Pressed { .. } if region == WindowFrameRegion::Client => {
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Pressed { button: PointerEventButton::Left, .. }
+                if region == WindowFrameRegion::MaximizeButton =>
+            {
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, position, .. }
+                if region == WindowFrameRegion::MaximizeButton =>
+            {
+                if LogicalRect::new(LogicalPoint::default(), self_rc.geometry().size)
+                    .contains(position)
+                {
+                    window.set_maximized(!window.is_maximized());
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Pressed { button: PointerEventButton::Left, click_count, .. } => {
+                if region == WindowFrameRegion::TitleBar && click_count % 2 == 1 {
+                    window.set_maximized(!window.is_maximized());
+                } else if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                    x.drag_window_frame(region);
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Pressed { .. } | MouseEvent::Released { .. } | MouseEvent::Moved { .. } => {
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Wheel { .. } | MouseEvent::Exit => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for WindowFrameArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl WindowFrameArea {
+    /// The cursor that shows what dragging the area does.
+    fn mouse_cursor(self: Pin<&Self>) -> MouseCursor {
+        match self.region() {
+            WindowFrameRegion::Client
+            | WindowFrameRegion::TitleBar
+            | WindowFrameRegion::MaximizeButton => MouseCursor::Default,
+            WindowFrameRegion::ResizeNorth => MouseCursor::NResize,
+            WindowFrameRegion::ResizeSouth => MouseCursor::SResize,
+            WindowFrameRegion::ResizeEast => MouseCursor::EResize,
+            WindowFrameRegion::ResizeWest => MouseCursor::WResize,
+            WindowFrameRegion::ResizeNorthEast => MouseCursor::NeResize,
+            WindowFrameRegion::ResizeNorthWest => MouseCursor::NwResize,
+            WindowFrameRegion::ResizeSouthEast => MouseCursor::SeResize,
+            WindowFrameRegion::ResizeSouthWest => MouseCursor::SwResize,
+        }
+    }
+}
@@ -9,9 +9,9 @@
 */
 use super::{
     EventResult, FontMetrics, InputType, Item, ItemConsts, ItemRc, ItemRef, KeyEventArg,
-    KeyEventResult, KeyEventType, PointArg, PointerEventButton, RenderingResult,
-    TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextVerticalAlignment, TextWrap,
-    VoidArg,
+    KeyEventResult, KeyEventType, PointArg, PointerEventButton, RenderingResult, StringArg,
+    TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextUnderlineStyle,
+    TextVerticalAlignment, TextWrap, TextWritingMode, VoidArg,
 };
 use crate::graphics::{Brush, Color, FontRequest};
 use crate::input::{
@@ -20,21 +20,32 @@
 };
 use crate::item_rendering::{CachedRenderingData, ItemRenderer, RenderText};
 use crate::layout::{LayoutInfo, Orientation};
-use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
+use crate::lengths::{
+    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor,
+};
 use crate::platform::Clipboard;
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
+use crate::textlayout::{
+    grapheme_boundary_at_or_before, next_grapheme_boundary, prev_grapheme_boundary,
+};
 use crate::window::{InputMethodProperties, InputMethodRequest, WindowAdapter, WindowInner};
 use crate::{Callback, Coord, Property, SharedString, SharedVector};
 use alloc::rc::Rc;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use const_field_offset::FieldOffsets;
 use core::cell::Cell;
 use core::pin::Pin;
 #[allow(unused)]
 use euclid::num::Ceil;
+use euclid::num::Zero;
 use i_slint_core_macros::*;
+#[cfg(not(feature = "std"))]
+#[allow(unused)]
+use num_traits::Float;
 use unicode_segmentation::UnicodeSegmentation;
 
 /// The implementation of the `Text` element
@@ -59,6 +70,9 @@ pub struct ComplexText {
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
     pub stroke_style: Property<TextStrokeStyle>,
+    pub font_features: Property<SharedString>,
+    pub writing_mode: Property<TextWritingMode>,
+    pub max_lines: Property<i32>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -144,7 +158,7 @@ fn text(self: Pin<&Self>) -> SharedString {
     fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
         let window_item = window.window_item();
 
-        FontRequest {
+        window.apply_system_font(FontRequest {
             family: {
                 let maybe_family = self.font_family();
                 if !maybe_family.is_empty() {
@@ -171,7 +185,8 @@ fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
             },
             letter_spacing: Some(self.letter_spacing()),
             italic: self.font_italic(),
-        }
+            features: crate::graphics::FontFeature::parse_list(&self.font_features()),
+        })
     }
 
     fn color(self: Pin<&Self>) -> Brush {
@@ -199,6 +214,14 @@ fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
         (self.stroke(), self.stroke_width(), self.stroke_style())
     }
+
+    fn writing_mode(self: Pin<&Self>) -> TextWritingMode {
+        self.writing_mode()
+    }
+
+    fn max_lines(self: Pin<&Self>) -> Option<usize> {
+        usize::try_from(self.max_lines()).ok().filter(|max_lines| *max_lines > 0)
+    }
 }
 
 impl ComplexText {
@@ -309,7 +332,7 @@ fn text(self: Pin<&Self>) -> SharedString {
     fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
         let window_item = window.window_item();
 
-        FontRequest {
+        window.apply_system_font(FontRequest {
             family: window_item.as_ref().and_then(|item| item.as_pin_ref().font_family()),
             weight: {
                 let weight = self.font_weight();
@@ -329,7 +352,8 @@ fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
             },
             letter_spacing: None,
             italic: false,
-        }
+            features: Default::default(),
+        })
     }
 
     fn color(self: Pin<&Self>) -> Brush {
@@ -357,6 +381,14 @@ fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
         Default::default()
     }
+
+    fn writing_mode(self: Pin<&Self>) -> TextWritingMode {
+        TextWritingMode::default()
+    }
+
+    fn max_lines(self: Pin<&Self>) -> Option<usize> {
+        None
+    }
 }
 
 impl SimpleText {
@@ -388,6 +420,23 @@ fn text_layout_info(
         )
     };
 
+    if text.writing_mode() == TextWritingMode::VerticalRl
+        && window_adapter.renderer().supports_vertical_text()
+    {
+        // Each character occupies a square cell of the height of the font, and each line of the
+        // text becomes a column.
+        let metrics = window_adapter.renderer().font_metrics(font_request, scale_factor);
+        let cell = metrics.ascent - metrics.descent;
+        let preferred = match orientation {
+            Orientation::Horizontal => text_string.split('\n').count() as Coord * cell,
+            Orientation::Vertical => {
+                text_string.split('\n').map(|line| line.chars().count()).max().unwrap_or(0) as Coord
+                    * cell
+            }
+        };
+        return LayoutInfo { preferred: preferred.ceil(), ..LayoutInfo::default() };
+    }
+
     // Stretch uses `round_layout` to explicitly align the top left and bottom right of layout nodes
     // to pixel boundaries. To avoid rounding down causing the minimum width to become so little that
     // letters will be cut off, apply the ceiling here.
@@ -417,6 +466,14 @@ fn text_layout_info(
                 TextWrap::NoWrap => implicit_size(None, TextWrap::NoWrap).height,
                 TextWrap::WordWrap => implicit_size(Some(width.get()), TextWrap::WordWrap).height,
                 TextWrap::CharWrap => implicit_size(Some(width.get()), TextWrap::CharWrap).height,
+            };
+            let h = match text.max_lines() {
+                Some(max_lines) => {
+                    let metrics =
+                        window_adapter.renderer().font_metrics(font_request, scale_factor);
+                    h.min((metrics.ascent - metrics.descent) * max_lines as Coord)
+                }
+                None => h,
             }
             .ceil();
             LayoutInfo { min: h, preferred: h, ..LayoutInfo::default() }
@@ -428,7 +485,8 @@ fn text_layout_info(
 #[derive(Default, Clone, Copy, PartialEq)]
 /// Similar as `Option<core::ops::Range<i32>>` but `repr(C)`
 ///
-/// This is the selection within a preedit
+/// This is the selection or the converted clause within a preedit, or the range of the last typed password
+/// characters that are shown
 struct PreEditSelection {
     valid: bool,
     start: i32,
@@ -447,6 +505,34 @@ fn as_option(self) -> Option<core::ops::Range<i32>> {
     }
 }
 
+/// A range of the text decorated with `add-underline()`, as byte offsets.
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq)]
+struct TextUnderline {
+    start: usize,
+    end: usize,
+    color: Color,
+    style: TextUnderlineStyle,
+}
+
+/// The end of the selection that a touch selection handle of a `TextInput` moves
+#[repr(u8)]
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+enum SelectionHandle {
+    #[default]
+    None,
+    Anchor,
+    Cursor,
+}
+
+/// The radius of the knob of the touch selection handles
+const SELECTION_HANDLE_RADIUS: LogicalLength = LogicalLength::new(6 as Coord);
+/// How much larger the text is shown in the magnifier
+const MAGNIFIER_ZOOM: Coord = 1.5 as Coord;
+/// The number of shapes drawn for the touch selection handles and the magnifier: the stem and the
+/// knob of two handles, and the background and the text of the magnifier
+const SELECTION_DECORATION_SHAPES: usize = 6;
+
 #[repr(C)]
 #[derive(Clone)]
 enum UndoItemKind {
@@ -462,6 +548,12 @@ struct UndoItem {
     cursor: usize,
     anchor: usize,
     kind: UndoItemKind,
+    /// Whether the item can be merged with adjacent items of the same kind, for runs of typed characters
+    /// or of deletions with backspace or delete. Pasted, cut, or replaced text is undone on its own.
+    mergeable: bool,
+    /// Whether the item is undone and redone together with the previous one, such as text that replaced
+    /// a selection together with the removal of the selection.
+    with_previous: bool,
 }
 
 /// The implementation of the `TextInput` element
@@ -481,6 +573,10 @@ pub struct TextInput {
     pub vertical_alignment: Property<TextVerticalAlignment>,
     pub wrap: Property<TextWrap>,
     pub input_type: Property<InputType>,
+    pub input_mask: Property<SharedString>,
+    pub password_character: Property<SharedString>,
+    pub password_reveal_duration: Property<i64>,
+    pub password_revealed: Property<bool>,
     pub letter_spacing: Property<LogicalLength>,
     pub width: Property<LogicalLength>,
     pub height: Property<LogicalLength>,
@@ -495,12 +591,36 @@ pub struct TextInput {
     pub edited: Callback<VoidArg>,
     pub key_pressed: Callback<KeyEventArg, EventResult>,
     pub key_released: Callback<KeyEventArg, EventResult>,
+    pub filter_input: Callback<StringArg, SharedString>,
     pub single_line: Property<bool>,
     pub read_only: Property<bool>,
+    pub can_undo: Property<bool>,
+    pub can_redo: Property<bool>,
+    pub selection_handles_enabled: Property<bool>,
+    pub magnifier_enabled: Property<bool>,
     pub preedit_text: Property<SharedString>,
     /// A selection within the preedit (cursor and anchor)
     preedit_selection: Property<PreEditSelection>,
+    /// The clause within the preedit that the input method is converting
+    preedit_clause: Property<PreEditSelection>,
+    /// The range of the last typed characters that are shown in a password field, for `password-reveal-duration`
+    revealed_password_range: Property<PreEditSelection>,
+    /// The ranges added with `add-underline()`, kept in sync with edits of the text
+    underlines: Property<SharedVector<TextUnderline>>,
+    /// The text that the ranges of `underlines` refer to. It differs from the text after the text
+    /// was set through the property, and the underlines are then dropped.
+    underlined_text: Cell<SharedString>,
+    /// Whether the touch selection handles are shown, after the text input was tapped
+    selection_handles_visible: Property<bool>,
+    /// The handle that is being dragged, during which the magnifier is shown
+    dragged_selection_handle: Property<SelectionHandle>,
     pub cached_rendering_data: CachedRenderingData,
+    /// The rendering data for the shapes of the touch selection handles and the magnifier, which are
+    /// drawn in addition to the text. Each shape has its own, as the renderers cache what they draw.
+    selection_handles_rendering_data: [CachedRenderingData; SELECTION_DECORATION_SHAPES],
+    /// The area of the window covered by the touch selection handles and the magnifier, which is
+    /// redrawn when they move or disappear.
+    selection_decorations_area: Cell<LogicalRect>,
     // The x position where the cursor wants to be.
     // It is not updated when moving up and down even when the line is shorter.
     preferred_x_pos: Cell<Coord>,
@@ -586,6 +706,18 @@ fn input_event(
             return InputEventResult::EventIgnored;
         }
         match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. }
+                if self.selection_handles_shown() =>
+            {
+                if let Some(handle) = self.selection_handle_at(position, window_adapter, self_rc) {
+                    self.dragged_selection_handle.set(handle);
+                    self.update_selection_decorations_area(window_adapter, self_rc);
+                    return InputEventResult::GrabMouse;
+                }
+                self.selection_handles_visible.set(false);
+                self.update_selection_decorations_area(window_adapter, self_rc);
+                return self.input_event(event, window_adapter, self_rc);
+            }
             MouseEvent::Pressed { position, button: PointerEventButton::Left, click_count } => {
                 let clicked_offset = self.byte_offset_for_position(position, window_adapter) as i32;
                 self.as_ref().pressed.set((click_count % 3) + 1);
@@ -616,8 +748,23 @@ fn input_event(
                 #[cfg(not(target_os = "android"))]
                 self.ensure_focus_and_ime(window_adapter, self_rc);
             }
+            MouseEvent::Released { button: PointerEventButton::Left, .. }
+                if self.dragged_selection_handle() != SelectionHandle::None =>
+            {
+                self.dragged_selection_handle.set(SelectionHandle::None);
+                self.update_selection_decorations_area(window_adapter, self_rc);
+            }
             MouseEvent::Released { button: PointerEventButton::Left, .. } => {
                 self.as_ref().pressed.set(0);
+                // The handles are for touch screens, where there's no pointer to select precisely
+                let touch =
+                    window_adapter.window().0.pointer_details.get().is_some_and(|details| {
+                        details.tool_type == crate::items::PointerToolType::Touch
+                    });
+                if self.selection_handles_enabled() && touch {
+                    self.selection_handles_visible.set(true);
+                    self.update_selection_decorations_area(window_adapter, self_rc);
+                }
                 self.copy_clipboard(window_adapter, Clipboard::SelectionClipboard);
                 #[cfg(target_os = "android")]
                 self.ensure_focus_and_ime(window_adapter, self_rc);
@@ -641,7 +788,16 @@ fn input_event(
                 if let Some(x) = window_adapter.internal(crate::InternalToken) {
                     x.set_mouse_cursor(super::MouseCursor::Default);
                 }
-                self.as_ref().pressed.set(0)
+                self.as_ref().pressed.set(0);
+                self.dragged_selection_handle.set(SelectionHandle::None);
+                self.update_selection_decorations_area(window_adapter, self_rc);
+            }
+            MouseEvent::Moved { position }
+                if self.dragged_selection_handle() != SelectionHandle::None =>
+            {
+                self.move_selection_handle(position, window_adapter, self_rc);
+                self.update_selection_decorations_area(window_adapter, self_rc);
+                return InputEventResult::GrabMouse;
             }
             MouseEvent::Moved { position } => {
                 if let Some(x) = window_adapter.internal(crate::InternalToken) {
@@ -687,6 +843,10 @@ fn key_event(
         }
         match event.event_type {
             KeyEventType::KeyPressed => {
+                // Typing hides the touch selection handles until the next tap
+                self.selection_handles_visible.set(false);
+                self.update_selection_decorations_area(window_adapter, self_rc);
+
                 // invoke first key_pressed callback to give the developer/designer the possibility to implement a custom behaviour
                 if Self::FIELD_OFFSETS.key_pressed.apply_pin(self).call(&(event.clone(),))
                     == EventResult::Accept
@@ -697,6 +857,23 @@ fn key_event(
                 match event.text_shortcut() {
                     Some(text_shortcut) if !self.read_only() => match text_shortcut {
                         TextShortcut::Move(direction) => {
+                            // Moving by word with the arrow keys follows the direction of the paragraph
+                            let direction = match direction {
+                                TextCursorDirection::ForwardByWord
+                                | TextCursorDirection::BackwardByWord
+                                    if is_rtl_paragraph(
+                                        &self.text(),
+                                        self.cursor_position(&self.text()),
+                                    ) =>
+                                {
+                                    if matches!(direction, TextCursorDirection::ForwardByWord) {
+                                        TextCursorDirection::BackwardByWord
+                                    } else {
+                                        TextCursorDirection::ForwardByWord
+                                    }
+                                }
+                                direction => direction,
+                            };
                             TextInput::move_cursor(
                                 self,
                                 direction,
@@ -705,6 +882,9 @@ fn key_event(
                                 window_adapter,
                                 self_rc,
                             );
+                            if event.modifiers.shift {
+                                self.copy_clipboard(window_adapter, Clipboard::SelectionClipboard);
+                            }
                             return KeyEventResult::EventAccepted;
                         }
                         TextShortcut::DeleteForward => {
@@ -717,10 +897,9 @@ fn key_event(
                             return KeyEventResult::EventAccepted;
                         }
                         TextShortcut::DeleteBackward => {
-                            // Special case: backspace breaks the grapheme and selects the previous character
                             TextInput::select_and_delete(
                                 self,
-                                TextCursorDirection::PreviousCharacter,
+                                TextCursorDirection::Backward,
                                 window_adapter,
                                 self_rc,
                             );
@@ -772,6 +951,7 @@ fn key_event(
                     match shortcut {
                         StandardShortcut::SelectAll => {
                             self.select_all(window_adapter, self_rc);
+                            self.copy_clipboard(window_adapter, Clipboard::SelectionClipboard);
                             return KeyEventResult::EventAccepted;
                         }
                         StandardShortcut::Copy => {
@@ -789,14 +969,17 @@ fn key_event(
                         StandardShortcut::Paste | StandardShortcut::Cut => {
                             return KeyEventResult::EventIgnored;
                         }
-                        StandardShortcut::Undo => {
+                        StandardShortcut::Undo if !self.read_only() => {
                             self.undo(window_adapter, self_rc);
                             return KeyEventResult::EventAccepted;
                         }
-                        StandardShortcut::Redo => {
+                        StandardShortcut::Redo if !self.read_only() => {
                             self.redo(window_adapter, self_rc);
                             return KeyEventResult::EventAccepted;
                         }
+                        StandardShortcut::Undo | StandardShortcut::Redo => {
+                            return KeyEventResult::EventIgnored;
+                        }
                         _ => (),
                     }
                 }
@@ -826,27 +1009,28 @@ fn key_event(
                     }
                 }
 
-                self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
+                let Some((text, new_cursor_pos)) = self.validate_input(&event.text) else {
+                    return KeyEventResult::EventIgnored;
+                };
 
-                let mut text: String = self.text().into();
+                let replaces_selection = self.has_selection();
+                self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
 
-                // FIXME: respect grapheme boundaries
-                let insert_pos = self.selection_anchor_and_cursor().1;
-                text.insert_str(insert_pos, &event.text);
+                let inserted = self.replace_text_with_input(
+                    text,
+                    real_cursor,
+                    real_anchor,
+                    !replaces_selection,
+                    replaces_selection,
+                );
 
-                self.add_undo_item(UndoItem {
-                    pos: insert_pos,
-                    text: event.text.clone(),
-                    cursor: real_cursor,
-                    anchor: real_anchor,
-                    kind: UndoItemKind::TextInsert,
-                });
+                if self.input_type() == InputType::Password {
+                    self.reveal_typed_password_characters(inserted, self_rc);
+                }
 
-                self.as_ref().text.set(text.into());
-                let new_cursor_pos = (insert_pos + event.text.len()) as i32;
-                self.as_ref().anchor_position_byte_offset.set(new_cursor_pos);
+                self.as_ref().anchor_position_byte_offset.set(new_cursor_pos as i32);
                 self.set_cursor_position(
-                    new_cursor_pos,
+                    new_cursor_pos as i32,
                     true,
                     TextChangeNotify::TriggerCallbacks,
                     window_adapter,
@@ -875,6 +1059,7 @@ fn key_event(
                 let cursor = self.cursor_position(&self.text()) as i32;
                 self.preedit_text.set(event.preedit_text.clone());
                 self.preedit_selection.set(event.preedit_selection.clone().into());
+                self.preedit_clause.set(event.preedit_clause.clone().into());
 
                 if let Some(r) = &event.replacement_range {
                     // Set the selection so the call to insert erases it
@@ -893,7 +1078,7 @@ fn key_event(
                         );
                     }
                 }
-                self.insert(&event.text, window_adapter, self_rc);
+                self.insert(&event.text, true, window_adapter, self_rc);
                 if let Some(cursor) = event.cursor_position {
                     self.anchor_position_byte_offset.set(event.anchor_position.unwrap_or(cursor));
                     self.set_cursor_position(
@@ -932,6 +1117,9 @@ fn focus_event(
             FocusEvent::FocusOut | FocusEvent::WindowLostFocus => {
                 self.has_focus.set(false);
                 self.hide_cursor();
+                self.selection_handles_visible.set(false);
+                self.dragged_selection_handle.set(SelectionHandle::None);
+                self.update_selection_decorations_area(window_adapter, self_rc);
                 if matches!(event, FocusEvent::FocusOut) {
                     self.as_ref()
                         .anchor_position_byte_offset
@@ -956,7 +1144,11 @@ fn render(
         size: LogicalSize,
     ) -> RenderingResult {
         crate::properties::evaluate_no_tracking(|| {
-            if self.has_focus() && self.text() != *backend.window().last_ime_text.borrow() {
+            if self.has_focus()
+                && (self.text() != *backend.window().last_ime_text.borrow()
+                    || self_rc.map_to_window(self_rc.geometry().origin)
+                        != backend.window().last_ime_origin.get())
+            {
                 let window_adapter = &backend.window().window_adapter();
                 if let Some(w) = window_adapter.internal(crate::InternalToken) {
                     w.input_method_request(InputMethodRequest::Update(
@@ -966,10 +1158,135 @@ fn render(
             }
         });
         (*backend).draw_text_input(self, self_rc, size);
+        if self.selection_handles_shown() {
+            self.render_selection_decorations(backend, self_rc, size);
+        }
         RenderingResult::ContinueRenderingChildren
     }
 }
 
+/// The geometry of the touch selection handles and of the magnifier, in the coordinates of the `TextInput`
+#[derive(Default)]
+struct SelectionDecorations {
+    /// The stem and the knob of the handle at the cursor, and of the one at the anchor if there's a selection
+    handles: [Option<(LogicalRect, LogicalRect)>; 2],
+    /// The rectangle of the magnifier and the cursor rectangle shown magnified in it
+    magnifier: Option<(LogicalRect, LogicalRect)>,
+}
+
+impl SelectionDecorations {
+    /// The rectangle covering all the decorations
+    fn area(&self) -> LogicalRect {
+        self.handles
+            .iter()
+            .flatten()
+            .flat_map(|(stem, knob)| [*stem, *knob])
+            .chain(self.magnifier.map(|(magnifier, _)| magnifier))
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the rectangle of the magnifier showing the text around `focus`: above the line of `focus` if
+/// there is space for it within `bounds`, otherwise below it.
+fn magnifier_rect(focus: LogicalRect, bounds: LogicalRect) -> LogicalRect {
+    let padding = SELECTION_HANDLE_RADIUS.get();
+    let size = LogicalSize::new(
+        focus.height() * 4 as Coord,
+        focus.height() * MAGNIFIER_ZOOM + padding * 2 as Coord,
+    );
+    let x = (focus.center().x - size.width / 2 as Coord)
+        .min(bounds.max_x() - size.width)
+        .max(bounds.min_x());
+    let above = focus.min_y() - padding - size.height;
+    let y = if above >= bounds.min_y() {
+        above
+    } else {
+        (focus.max_y() + padding).min(bounds.max_y() - size.height).max(bounds.min_y())
+    };
+    LogicalRect::new(LogicalPoint::new(x, y), size)
+}
+
+/// A rectangle with rounded corners in a single color, for the touch selection handles and the magnifier background
+struct SelectionHandleShape {
+    color: Color,
+    radius: LogicalLength,
+}
+
+impl crate::item_rendering::RenderBorderRectangle for SelectionHandleShape {
+    fn background(self: Pin<&Self>) -> Brush {
+        self.color.into()
+    }
+
+    fn border_width(self: Pin<&Self>) -> LogicalLength {
+        LogicalLength::zero()
+    }
+
+    fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius {
+        LogicalBorderRadius::new_uniform(self.radius.get())
+    }
+
+    fn border_color(self: Pin<&Self>) -> Brush {
+        Brush::default()
+    }
+}
+
+/// The text of a `TextInput` drawn larger in the magnifier
+struct MagnifiedText {
+    text: SharedString,
+    font_request: FontRequest,
+    color: Brush,
+    alignment: (TextHorizontalAlignment, TextVerticalAlignment),
+    wrap: TextWrap,
+    letter_spacing: LogicalLength,
+}
+
+impl RenderText for MagnifiedText {
+    fn target_size(self: Pin<&Self>) -> LogicalSize {
+        LogicalSize::default()
+    }
+
+    fn text(self: Pin<&Self>) -> SharedString {
+        self.text.clone()
+    }
+
+    fn font_request(self: Pin<&Self>, _window: &WindowInner) -> FontRequest {
+        self.font_request.clone()
+    }
+
+    fn color(self: Pin<&Self>) -> Brush {
+        self.color.clone()
+    }
+
+    fn alignment(self: Pin<&Self>) -> (TextHorizontalAlignment, TextVerticalAlignment) {
+        self.alignment
+    }
+
+    fn wrap(self: Pin<&Self>) -> TextWrap {
+        self.wrap
+    }
+
+    fn overflow(self: Pin<&Self>) -> TextOverflow {
+        TextOverflow::Clip
+    }
+
+    fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
+        self.letter_spacing
+    }
+
+    fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
+        Default::default()
+    }
+
+    fn writing_mode(self: Pin<&Self>) -> TextWritingMode {
+        TextWritingMode::HorizontalTb
+    }
+
+    fn max_lines(self: Pin<&Self>) -> Option<usize> {
+        None
+    }
+}
+
 impl ItemConsts for TextInput {
     const cached_rendering_data_offset: const_field_offset::FieldOffset<
         TextInput,
@@ -980,11 +1297,16 @@ impl ItemConsts for TextInput {
 pub enum TextCursorDirection {
     Forward,
     Backward,
+    /// Moves to the grapheme boundary that is visually to the left of the cursor, which is
+    /// the next one in right-to-left text.
+    VisualLeft,
+    /// Moves to the grapheme boundary that is visually to the right of the cursor, which is
+    /// the previous one in right-to-left text.
+    VisualRight,
     ForwardByWord,
     BackwardByWord,
     NextLine,
     PreviousLine,
-    PreviousCharacter, // breaks grapheme boundaries, so only used by delete-previous-char
     StartOfLine,
     EndOfLine,
     StartOfParagraph, // These don't care about wrapping
@@ -998,8 +1320,8 @@ impl core::convert::TryFrom<char> for TextCursorDirection {
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         Ok(match value {
-            key_codes::LeftArrow => Self::Backward,
-            key_codes::RightArrow => Self::Forward,
+            key_codes::LeftArrow => Self::VisualLeft,
+            key_codes::RightArrow => Self::VisualRight,
             key_codes::UpArrow => Self::PreviousLine,
             key_codes::DownArrow => Self::NextLine,
             // On macos this scrolls to the top or the bottom of the page
@@ -1058,6 +1380,21 @@ fn safe_byte_offset(unsafe_byte_offset: i32, text: &str) -> usize {
         .unwrap_or(text.len())
 }
 
+/// The position of the text cursor within a TextInput and of the line it's on, in coordinates
+/// relative to the TextInput.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TextCursorMetrics {
+    /// The rectangle of the cursor, which starts left to the character at the cursor position
+    /// and is as high as the line.
+    pub rect: LogicalRect,
+    /// The y coordinate of the baseline of the line with the cursor.
+    pub baseline: Coord,
+    /// The index of the visual line with the cursor, starting at 0 for the first line.
+    pub line_index: usize,
+    /// The box of the line with the cursor, as wide as the text on that line.
+    pub line_rect: LogicalRect,
+}
+
 /// This struct holds the fields needed for rendering a TextInput item after applying any
 /// on-going composition. This way the renderer's don't have to duplicate the code for extracting
 /// and applying the pre-edit text, cursor placement within, etc.
@@ -1069,8 +1406,15 @@ pub struct TextInputVisualRepresentation {
     /// is in progress. Renderers typically provide visual feedback for the currently composed text, such as
     /// by using underlines.
     pub preedit_range: core::ops::Range<usize>,
+    /// If set, this field specifies the range as byte offsets within the text of the clause of the composition
+    /// that the input method is currently converting. It is within `preedit_range`, and renderers highlight it,
+    /// typically with the selection colors in addition to the underline.
+    pub preedit_clause_range: core::ops::Range<usize>,
     /// If set, specifies the range as byte offsets within the text where to draw the selection.
     pub selection_range: core::ops::Range<usize>,
+    /// The ranges as byte offsets within the text that were added with `add-underline()`, with the
+    /// color and style of the line to draw below them.
+    pub underlines: Vec<(core::ops::Range<usize>, Color, TextUnderlineStyle)>,
     /// The position where to draw the cursor, as byte offset within the text.
     pub cursor_position: Option<usize>,
     /// The color of the (unselected) text
@@ -1078,50 +1422,81 @@ pub struct TextInputVisualRepresentation {
     /// The color of the blinking cursor
     pub cursor_color: Color,
     text_without_password: Option<String>,
-    password_character: char,
 }
 
 impl TextInputVisualRepresentation {
     /// If the given `TextInput` renders a password, then all characters in this `TextInputVisualRepresentation` are replaced
     /// with the password character and the selection/preedit-ranges/cursor position are adjusted.
-    /// If `password_character_fn` is Some, it is called lazily to query the password character, otherwise a default is used.
+    /// The password character is taken from the `password-character` property. If that's empty and `password_character_fn`
+    /// is Some, it is called lazily to query the password character, otherwise a default is used.
+    /// Characters that were just typed are kept visible for `password-reveal-duration`, and nothing is replaced
+    /// if `password-revealed` is true.
     fn apply_password_character_substitution(
         &mut self,
         text_input: Pin<&TextInput>,
         password_character_fn: Option<fn() -> char>,
     ) {
-        if !matches!(text_input.input_type(), InputType::Password) {
+        if !matches!(text_input.input_type(), InputType::Password) || text_input.password_revealed()
+        {
             return;
         }
 
-        let password_character = password_character_fn.map_or('●', |f| f());
-
-        let text = &mut self.text;
-        let fixup_range = |r: &mut core::ops::Range<usize>| {
+        let password_character = text_input
+            .password_character()
+            .chars()
+            .next()
+            .unwrap_or_else(|| password_character_fn.map_or('●', |f| f()));
+
+        // The revealed range refers to the text without pre-edit
+        let revealed_range = text_input
+            .revealed_password_range()
+            .as_option()
+            .filter(|_| self.preedit_range.is_empty())
+            .map(|r| r.start as usize..r.end as usize);
+
+        let masked_text: String = self
+            .text
+            .char_indices()
+            .map(|(offset, c)| {
+                if revealed_range.as_ref().is_some_and(|r| r.contains(&offset)) {
+                    c
+                } else {
+                    password_character
+                }
+            })
+            .collect();
+        let text = core::mem::replace(&mut self.text, masked_text);
+
+        // Each character of the text is represented by exactly one character in the masked text
+        let masked_text = &self.text;
+        let map_byte_offset = |byte_offset: usize| {
+            let char_index = text[..byte_offset].chars().count();
+            masked_text.char_indices().nth(char_index).map_or(masked_text.len(), |(r, _)| r)
+        };
+        for r in
+            [&mut self.preedit_range, &mut self.preedit_clause_range, &mut self.selection_range]
+                .into_iter()
+                .chain(self.underlines.iter_mut().map(|(r, ..)| r))
+        {
             if !core::ops::Range::is_empty(r) {
-                r.start = text[..r.start].chars().count() * password_character.len_utf8();
-                r.end = text[..r.end].chars().count() * password_character.len_utf8();
+                *r = map_byte_offset(r.start)..map_byte_offset(r.end);
             }
-        };
-        fixup_range(&mut self.preedit_range);
-        fixup_range(&mut self.selection_range);
+        }
         if let Some(cursor_pos) = self.cursor_position.as_mut() {
-            *cursor_pos = text[..*cursor_pos].chars().count() * password_character.len_utf8();
+            *cursor_pos = map_byte_offset(*cursor_pos);
         }
-        self.text_without_password = Some(core::mem::replace(
-            text,
-            core::iter::repeat(password_character).take(text.chars().count()).collect(),
-        ));
-        self.password_character = password_character;
+        self.text_without_password = Some(text);
     }
 
     /// Use this function to make a byte offset in the text used for rendering back to a byte offset in the
     /// TextInput's text. The offsets might differ for example for password text input fields.
     pub fn map_byte_offset_from_byte_offset_in_visual_text(&self, byte_offset: usize) -> usize {
         if let Some(text_without_password) = self.text_without_password.as_ref() {
+            let char_index =
+                self.text.char_indices().take_while(|(offset, _)| *offset < byte_offset).count();
             text_without_password
                 .char_indices()
-                .nth(byte_offset / self.password_character.len_utf8())
+                .nth(char_index)
                 .map_or(text_without_password.len(), |(r, _)| r)
         } else {
             byte_offset
@@ -1156,8 +1531,16 @@ fn move_cursor(
         let (anchor, cursor) = self.selection_anchor_and_cursor();
         let last_cursor_pos = self.cursor_position(&text);
 
-        let mut grapheme_cursor =
-            unicode_segmentation::GraphemeCursor::new(last_cursor_pos, text.len(), true);
+        let direction = match direction {
+            // Without any right-to-left text, visual and logical order are the same.
+            TextCursorDirection::VisualLeft if !contains_rtl(&text) => {
+                TextCursorDirection::Backward
+            }
+            TextCursorDirection::VisualRight if !contains_rtl(&text) => {
+                TextCursorDirection::Forward
+            }
+            direction => direction,
+        };
 
         let font_height = window_adapter
             .renderer()
@@ -1175,22 +1558,42 @@ fn move_cursor(
         let new_cursor_pos = match direction {
             TextCursorDirection::Forward => {
                 if anchor == cursor || anchor_mode == AnchorMode::KeepAnchor {
-                    grapheme_cursor
-                        .next_boundary(&text, 0)
-                        .ok()
-                        .flatten()
-                        .unwrap_or_else(|| text.len())
+                    next_grapheme_boundary(&text, last_cursor_pos)
                 } else {
                     cursor
                 }
             }
             TextCursorDirection::Backward => {
                 if anchor == cursor || anchor_mode == AnchorMode::KeepAnchor {
-                    grapheme_cursor.prev_boundary(&text, 0).ok().flatten().unwrap_or(0)
+                    prev_grapheme_boundary(&text, last_cursor_pos)
                 } else {
                     anchor
                 }
             }
+            TextCursorDirection::VisualLeft | TextCursorDirection::VisualRight => {
+                let to_left = matches!(direction, TextCursorDirection::VisualLeft);
+                if anchor != cursor && anchor_mode == AnchorMode::MoveAnchor {
+                    // Collapse the selection to its visual edge
+                    let anchor_x = self.cursor_rect_for_byte_offset(anchor, window_adapter).min_x();
+                    let cursor_x = self.cursor_rect_for_byte_offset(cursor, window_adapter).min_x();
+                    if (anchor_x < cursor_x) == to_left {
+                        anchor
+                    } else {
+                        cursor
+                    }
+                } else {
+                    self.visual_neighbor_boundary(&text, last_cursor_pos, to_left, window_adapter)
+                        .unwrap_or_else(|| {
+                            // At the visual edge of the line: continue on the adjacent line
+                            // in the direction of the paragraph.
+                            if to_left == is_rtl_paragraph(&text, last_cursor_pos) {
+                                next_grapheme_boundary(&text, last_cursor_pos)
+                            } else {
+                                prev_grapheme_boundary(&text, last_cursor_pos)
+                            }
+                        })
+                }
+            }
             TextCursorDirection::NextLine => {
                 reset_preferred_x_pos = false;
 
@@ -1211,33 +1614,20 @@ fn move_cursor(
                 cursor_xy_pos.x = self.preferred_x_pos.get();
                 self.byte_offset_for_position(cursor_xy_pos, window_adapter)
             }
-            TextCursorDirection::PreviousCharacter => {
-                let mut i = last_cursor_pos;
-                loop {
-                    i = i.checked_sub(1).unwrap_or_default();
-                    if text.is_char_boundary(i) {
-                        break i;
-                    }
-                }
-            }
             // Currently moving by word behaves like macos: next end of word(forward) or previous beginning of word(backward)
             TextCursorDirection::ForwardByWord => next_word_boundary(&text, last_cursor_pos + 1),
             TextCursorDirection::BackwardByWord => {
                 prev_word_boundary(&text, last_cursor_pos.saturating_sub(1))
             }
+            // The start and end of the line are logical: in right-to-left text, the start of
+            // the line is at the right edge.
             TextCursorDirection::StartOfLine => {
-                let cursor_rect = self.cursor_rect_for_byte_offset(last_cursor_pos, window_adapter);
-                let mut cursor_xy_pos = cursor_rect.center();
-
-                cursor_xy_pos.x = 0 as Coord;
-                self.byte_offset_for_position(cursor_xy_pos, window_adapter)
+                let (left, right) = self.line_edge_offsets(last_cursor_pos, window_adapter);
+                left.min(right)
             }
             TextCursorDirection::EndOfLine => {
-                let cursor_rect = self.cursor_rect_for_byte_offset(last_cursor_pos, window_adapter);
-                let mut cursor_xy_pos = cursor_rect.center();
-
-                cursor_xy_pos.x = Coord::MAX;
-                self.byte_offset_for_position(cursor_xy_pos, window_adapter)
+                let (left, right) = self.line_edge_offsets(last_cursor_pos, window_adapter);
+                left.max(right)
             }
             TextCursorDirection::StartOfParagraph => {
                 prev_paragraph_boundary(&text, last_cursor_pos.saturating_sub(1))
@@ -1270,6 +1660,106 @@ fn move_cursor(
         new_cursor_pos != last_cursor_pos
     }
 
+    /// Returns the byte offsets at the left and right edges of the line that contains `byte_offset`.
+    fn line_edge_offsets(
+        self: Pin<&Self>,
+        byte_offset: usize,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> (usize, usize) {
+        let mut pos = self.cursor_rect_for_byte_offset(byte_offset, window_adapter).center();
+        pos.x = 0 as Coord;
+        let left = self.byte_offset_for_position(pos, window_adapter);
+        pos.x = Coord::MAX;
+        let right = self.byte_offset_for_position(pos, window_adapter);
+        (left, right)
+    }
+
+    /// Returns the grapheme boundary within the paragraph of `byte_offset` that is on the same line
+    /// and visually the closest to the left (or right) of it, or None if the cursor is at the edge of
+    /// the line.
+    ///
+    /// The visual order of the grapheme clusters of the line is determined with the Unicode
+    /// bidirectional algorithm, so that the text is only laid out once to find the line.
+    fn visual_neighbor_boundary(
+        self: Pin<&Self>,
+        text: &str,
+        byte_offset: usize,
+        to_left: bool,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> Option<usize> {
+        let paragraph_start = prev_paragraph_boundary(text, byte_offset);
+        let paragraph_end = next_paragraph_boundary(text, byte_offset);
+        let line =
+            self.line_range(text, byte_offset, paragraph_start..paragraph_end, window_adapter);
+        let paragraph = &text[paragraph_start..paragraph_end];
+        let bidi_info = unicode_bidi::BidiInfo::new(paragraph, None);
+        let paragraph_info = bidi_info.paragraphs.first()?;
+        let (levels, runs) = bidi_info
+            .visual_runs(paragraph_info, line.start - paragraph_start..line.end - paragraph_start);
+
+        // The grapheme clusters of the line from left to right, with their logical byte range
+        let mut graphemes = Vec::new();
+        for run in runs {
+            let rtl = levels[run.start].is_rtl();
+            let run_graphemes = paragraph[run.clone()].grapheme_indices(true).map(|(offset, g)| {
+                let start = paragraph_start + run.start + offset;
+                (start, start + g.len(), rtl)
+            });
+            if rtl {
+                graphemes.extend(run_graphemes.rev());
+            } else {
+                graphemes.extend(run_graphemes);
+            }
+        }
+
+        // Like the renderers, place the cursor at the leading edge of the grapheme cluster that
+        // starts at a boundary, or else at the trailing edge of the one that ends there. The visual
+        // position is counted in grapheme clusters from the left edge of the line.
+        let mut positions = alloc::collections::BTreeMap::new();
+        for (index, (start, _, rtl)) in graphemes.iter().enumerate() {
+            positions.insert(*start, if *rtl { index + 1 } else { index });
+        }
+        for (index, (_, end, rtl)) in graphemes.iter().enumerate() {
+            positions.entry(*end).or_insert(if *rtl { index } else { index + 1 });
+        }
+
+        let current = *positions.get(&byte_offset)?;
+        let candidates = positions.iter().filter(|(offset, _)| **offset != byte_offset);
+        let neighbor = if to_left {
+            candidates.filter(|(_, position)| **position < current).max_by_key(|(_, p)| **p)
+        } else {
+            candidates.filter(|(_, position)| **position > current).min_by_key(|(_, p)| **p)
+        };
+        neighbor.map(|(offset, _)| *offset)
+    }
+
+    /// Returns the byte range of the visual line that contains `byte_offset`, including the
+    /// whitespace at which it wraps, or the whole `paragraph` if the renderer can't break the text
+    /// into lines.
+    fn line_range(
+        self: Pin<&Self>,
+        text: &str,
+        byte_offset: usize,
+        paragraph: core::ops::Range<usize>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> core::ops::Range<usize> {
+        let wrap = if self.single_line() { TextWrap::NoWrap } else { self.wrap() };
+        let max_width = (wrap != TextWrap::NoWrap).then(|| self.width());
+        let Ok(lines) = window_adapter.renderer().text_line_ranges(
+            self.font_request(window_adapter),
+            text,
+            max_width,
+            ScaleFactor::new(window_adapter.window().scale_factor()),
+            wrap,
+        ) else {
+            return paragraph;
+        };
+        let line_starts = lines.iter().map(|line| line.start);
+        let start = line_starts.clone().filter(|start| *start <= byte_offset).max().unwrap_or(0);
+        let end = line_starts.filter(|start| *start > byte_offset).min().unwrap_or(text.len());
+        start.max(paragraph.start)..end.min(paragraph.end)
+    }
+
     pub fn set_cursor_position(
         self: Pin<&Self>,
         new_position: i32,
@@ -1311,7 +1801,8 @@ fn select_and_delete(
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
     ) {
-        if !self.has_selection() {
+        let mergeable = !self.has_selection();
+        if mergeable {
             self.move_cursor(
                 step,
                 AnchorMode::KeepAnchor,
@@ -1320,7 +1811,12 @@ fn select_and_delete(
                 self_rc,
             );
         }
-        self.delete_selection(window_adapter, self_rc, TextChangeNotify::TriggerCallbacks);
+        self.delete_selection_impl(
+            window_adapter,
+            self_rc,
+            TextChangeNotify::TriggerCallbacks,
+            mergeable,
+        );
     }
 
     pub fn delete_selection(
@@ -1328,6 +1824,18 @@ pub fn delete_selection(
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
         trigger_callbacks: TextChangeNotify,
+    ) {
+        self.delete_selection_impl(window_adapter, self_rc, trigger_callbacks, false)
+    }
+
+    /// Deletes the selection. `mergeable` is true for deletions of single characters, which are undone together
+    /// with adjacent ones.
+    fn delete_selection_impl(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+        trigger_callbacks: TextChangeNotify,
+        mergeable: bool,
     ) {
         let text: String = self.text().into();
         if text.is_empty() {
@@ -1340,6 +1848,7 @@ pub fn delete_selection(
         }
 
         let removed_text: SharedString = text[anchor..cursor].into();
+        self.revealed_password_range.set(None.into());
         // save real anchor/cursor for undo/redo
         let (real_cursor, real_anchor) = {
             let text = self.text();
@@ -1349,6 +1858,7 @@ pub fn delete_selection(
         let text = [text.split_at(anchor).0, text.split_at(cursor).1].concat();
         self.text.set(text.into());
         self.anchor_position_byte_offset.set(anchor as i32);
+        self.shift_underlines(anchor, cursor - anchor, 0);
 
         self.add_undo_item(UndoItem {
             pos: anchor,
@@ -1356,6 +1866,8 @@ pub fn delete_selection(
             cursor: real_cursor,
             anchor: real_anchor,
             kind: UndoItemKind::TextRemove,
+            mergeable,
+            with_previous: false,
         });
 
         if trigger_callbacks == TextChangeNotify::TriggerCallbacks {
@@ -1389,9 +1901,12 @@ fn ime_properties(
         WindowInner::from_pub(window_adapter.window()).last_ime_text.replace(text.clone());
         let cursor_position = self.cursor_position(&text);
         let anchor_position = self.anchor_position(&text);
-        let cursor_relative = self.cursor_rect_for_byte_offset(cursor_position, window_adapter);
+        let cursor_metrics = self.cursor_metrics_for_byte_offset(cursor_position, window_adapter);
+        let cursor_relative = cursor_metrics.rect;
         let geometry = self_rc.geometry();
-        let origin = self_rc.map_to_window(geometry.origin).to_vector();
+        let origin = self_rc.map_to_window(geometry.origin);
+        WindowInner::from_pub(window_adapter.window()).last_ime_origin.set(origin);
+        let origin = origin.to_vector();
         let cursor_rect_origin =
             crate::api::LogicalPosition::from_euclid(cursor_relative.origin + origin);
         let cursor_rect_size = crate::api::LogicalSize::from_euclid(cursor_relative.size);
@@ -1410,6 +1925,8 @@ fn ime_properties(
             cursor_rect_origin,
             cursor_rect_size,
             anchor_point,
+            cursor_baseline: (cursor_metrics.baseline + origin.y) as _,
+            cursor_line_index: cursor_metrics.line_index,
             input_type: self.input_type(),
         }
     }
@@ -1433,9 +1950,12 @@ pub fn has_selection(self: Pin<&Self>) -> bool {
         anchor_pos != cursor_pos
     }
 
+    /// Inserts the text at the cursor, replacing the selection. `mergeable` is true when the text is typed, to
+    /// undo it together with adjacent typed text.
     fn insert(
         self: Pin<&Self>,
         text_to_insert: &str,
+        mergeable: bool,
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
     ) {
@@ -1448,27 +1968,19 @@ fn insert(
             (self.cursor_position(&text), self.anchor_position(&text))
         };
 
-        self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
-        let mut text: String = self.text().into();
-        let cursor_pos = self.selection_anchor_and_cursor().1;
-        let mut inserted_text: SharedString = text_to_insert.into();
-        if text_to_insert.contains('\n') && self.single_line() {
-            inserted_text = text_to_insert.replace('\n', " ").into();
-            text.insert_str(cursor_pos, &inserted_text);
+        let text_to_insert: SharedString = if text_to_insert.contains('\n') && self.single_line() {
+            text_to_insert.replace('\n', " ").into()
         } else {
-            text.insert_str(cursor_pos, text_to_insert);
-        }
+            text_to_insert.into()
+        };
+        let Some((text, cursor_pos)) = self.validate_input(&text_to_insert) else {
+            return;
+        };
 
-        self.add_undo_item(UndoItem {
-            pos: cursor_pos,
-            text: inserted_text,
-            cursor: real_cursor,
-            anchor: real_anchor,
-            kind: UndoItemKind::TextInsert,
-        });
+        let replaces_selection = self.has_selection();
+        self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
+        self.replace_text_with_input(text, real_cursor, real_anchor, mergeable, replaces_selection);
 
-        let cursor_pos = cursor_pos + text_to_insert.len();
-        self.text.set(text.into());
         self.anchor_position_byte_offset.set(cursor_pos as i32);
         self.set_cursor_position(
             cursor_pos as i32,
@@ -1480,6 +1992,92 @@ fn insert(
         Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
     }
 
+    /// Returns the text after replacing the selection with `text_to_insert`, and the cursor position after the
+    /// inserted text, once the `filter-input` callback and the `input-mask` are applied. Returns None if the
+    /// input is rejected.
+    fn validate_input(self: Pin<&Self>, text_to_insert: &str) -> Option<(String, usize)> {
+        let filter_input = Self::FIELD_OFFSETS.filter_input.apply_pin(self);
+        let filtered;
+        let text_to_insert = if filter_input.has_handler() {
+            filtered = filter_input.call(&(text_to_insert.into(),));
+            filtered.as_str()
+        } else {
+            text_to_insert
+        };
+        if text_to_insert.is_empty() {
+            return None;
+        }
+
+        let (start, end) = self.selection_anchor_and_cursor();
+        let text = self.text();
+        let input_mask = self.input_mask();
+        if input_mask.is_empty() {
+            let text = [&text[..start], text_to_insert, &text[end..]].concat();
+            Some((text, start + text_to_insert.len()))
+        } else {
+            apply_input_mask(&input_mask, &text[..start], text_to_insert, &text[end..])
+        }
+    }
+
+    /// Sets the text to `new_text`, which was returned by [`Self::validate_input()`], once the selection is removed.
+    /// Only the part that differs from the current text is recorded for undo, which is the inserted text unless the
+    /// input mask moved characters around. Returns the range of the inserted text in `new_text`.
+    fn replace_text_with_input(
+        self: Pin<&Self>,
+        new_text: String,
+        real_cursor: usize,
+        real_anchor: usize,
+        mergeable: bool,
+        replaces_selection: bool,
+    ) -> core::ops::Range<usize> {
+        let text = self.text();
+        let insert_pos = self.selection_anchor_and_cursor().1;
+        let prefix = text
+            .char_indices()
+            .zip(new_text.chars())
+            .take_while(|((_, a), b)| a == b)
+            .last()
+            .map_or(0, |((pos, c), _)| pos + c.len_utf8())
+            .min(insert_pos);
+        let suffix = text[prefix..]
+            .chars()
+            .rev()
+            .zip(new_text[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum::<usize>()
+            .min(text.len() - insert_pos);
+        let removed = &text[prefix..text.len() - suffix];
+        let inserted = prefix..new_text.len() - suffix;
+
+        if !removed.is_empty() {
+            self.add_undo_item(UndoItem {
+                pos: prefix,
+                text: removed.into(),
+                cursor: real_cursor,
+                anchor: real_anchor,
+                kind: UndoItemKind::TextRemove,
+                mergeable: false,
+                with_previous: replaces_selection,
+            });
+        }
+        if !inserted.is_empty() {
+            self.add_undo_item(UndoItem {
+                pos: prefix,
+                text: new_text[inserted.clone()].into(),
+                cursor: real_cursor,
+                anchor: real_anchor,
+                kind: UndoItemKind::TextInsert,
+                mergeable: mergeable && !replaces_selection && removed.is_empty(),
+                with_previous: replaces_selection || !removed.is_empty(),
+            });
+        }
+        let (removed, inserted_len) = (removed.len(), inserted.len());
+        self.text.set(new_text.into());
+        self.shift_underlines(prefix, removed, inserted_len);
+        inserted
+    }
+
     pub fn cut(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
         self.copy(window_adapter, self_rc);
         self.delete_selection(window_adapter, self_rc, TextChangeNotify::TriggerCallbacks);
@@ -1527,11 +2125,110 @@ pub fn clear_selection(self: Pin<&Self>, _: &Rc<dyn WindowAdapter>, _: &ItemRc)
         self.as_ref().anchor_position_byte_offset.set(self.as_ref().cursor_position_byte_offset());
     }
 
+    pub fn add_underline(
+        self: Pin<&Self>,
+        _: &Rc<dyn WindowAdapter>,
+        _: &ItemRc,
+        start: i32,
+        end: i32,
+        color: Color,
+        style: TextUnderlineStyle,
+    ) {
+        let text = self.text();
+        let start = safe_byte_offset(start, &text);
+        let end = safe_byte_offset(end, &text);
+        if start >= end {
+            return;
+        }
+        let mut underlines = if self.underlines_refer_to(&text) {
+            Self::FIELD_OFFSETS.underlines.apply_pin(self).get_untracked()
+        } else {
+            Default::default()
+        };
+        underlines.push(TextUnderline { start, end, color, style });
+        self.underlines.set(underlines);
+        self.underlined_text.set(text);
+    }
+
+    pub fn clear_underlines(self: Pin<&Self>, _: &Rc<dyn WindowAdapter>, _: &ItemRc) {
+        self.underlines.set(Default::default());
+        self.underlined_text.take();
+    }
+
+    /// Returns whether the ranges of the underlines refer to `text`, which is not the case anymore
+    /// after the text was set through the property.
+    fn underlines_refer_to(self: Pin<&Self>, text: &str) -> bool {
+        let underlined_text = self.underlined_text.take();
+        let result = underlined_text.as_str() == text;
+        self.underlined_text.set(underlined_text);
+        result
+    }
+
+    /// Moves the underlined ranges after `removed` bytes at `pos` were replaced with `inserted` bytes.
+    /// Text inserted at the edge of a range is not underlined, and ranges that were removed entirely are dropped.
+    fn shift_underlines(self: Pin<&Self>, pos: usize, removed: usize, inserted: usize) {
+        let underlines = Self::FIELD_OFFSETS.underlines.apply_pin(self).get_untracked();
+        if underlines.is_empty() {
+            return;
+        }
+        // The ranges refer to the text before this edit, unless the text was set through the
+        // property in between, in which case they are dropped.
+        let text = self.text();
+        let underlined_text = self.underlined_text.replace(text.clone());
+        if underlined_text.len() + inserted != text.len() + removed
+            || underlined_text.get(..pos) != text.get(..pos)
+            || underlined_text.get(pos + removed..) != text.get(pos + inserted..)
+        {
+            self.underlines.set(Default::default());
+            return;
+        }
+        let map_start = |offset: usize| {
+            if offset < pos {
+                offset
+            } else if offset < pos + removed {
+                pos + inserted
+            } else {
+                offset - removed + inserted
+            }
+        };
+        let map_end = |offset: usize| {
+            if offset <= pos {
+                offset
+            } else if offset <= pos + removed {
+                pos
+            } else {
+                offset - removed + inserted
+            }
+        };
+        self.underlines.set(
+            underlines
+                .iter()
+                .map(|u| TextUnderline {
+                    start: map_start(u.start),
+                    end: map_end(u.end),
+                    ..u.clone()
+                })
+                .filter(|u| u.start < u.end)
+                .collect(),
+        );
+    }
+
     pub fn select_word(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
         let text = self.text();
         let anchor = self.anchor_position(&text);
         let cursor = self.cursor_position(&text);
-        let (new_a, new_c) = if anchor <= cursor {
+        let (new_a, new_c) = if anchor == cursor {
+            let mut range = crate::textlayout::word_range_at(&text, cursor);
+            // Clicking on the right half of the last character of a word places the cursor
+            // after it: select that word rather than the whitespace or punctuation that follows.
+            if range.start == cursor && cursor > 0 && !is_word_segment(&text[range.clone()]) {
+                let previous = crate::textlayout::word_range_at(&text, cursor - 1);
+                if is_word_segment(&text[previous.clone()]) {
+                    range = previous;
+                }
+            }
+            (range.start, range.end)
+        } else if anchor < cursor {
             (prev_word_boundary(&text, anchor), next_word_boundary(&text, cursor))
         } else {
             (next_word_boundary(&text, anchor), prev_word_boundary(&text, cursor))
@@ -1582,6 +2279,13 @@ fn copy_clipboard(
         if anchor == cursor {
             return;
         }
+        // Selecting a hidden password must not expose it to other applications
+        if clipboard == Clipboard::SelectionClipboard
+            && self.input_type() == InputType::Password
+            && !self.password_revealed()
+        {
+            return;
+        }
         let text = self.text();
 
         WindowInner::from_pub(window_adapter.window())
@@ -1604,14 +2308,15 @@ fn paste_clipboard(
             WindowInner::from_pub(window_adapter.window()).ctx.platform().clipboard_text(clipboard)
         {
             self.preedit_text.set(Default::default());
-            self.insert(&text, window_adapter, self_rc);
+            self.insert(&text, false, window_adapter, self_rc);
         }
     }
 
     pub fn font_request(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) -> FontRequest {
-        let window_item = WindowInner::from_pub(window_adapter.window()).window_item();
+        let window = WindowInner::from_pub(window_adapter.window());
+        let window_item = window.window_item();
 
-        FontRequest {
+        window.apply_system_font(FontRequest {
             family: {
                 let maybe_family = self.font_family();
                 if !maybe_family.is_empty() {
@@ -1638,7 +2343,8 @@ pub fn font_request(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) ->
             },
             letter_spacing: Some(self.letter_spacing()),
             italic: self.font_italic(),
-        }
+            features: Default::default(),
+        })
     }
 
     /// Returns a [`TextInputVisualRepresentation`] struct that contains all the fields necessary for rendering the text input,
@@ -1650,21 +2356,51 @@ pub fn visual_representation(
     ) -> TextInputVisualRepresentation {
         let mut text: String = self.text().into();
 
+        // The touch selection handles and the magnifier are drawn after the text, this makes sure that the
+        // item is redrawn when they change.
+        let _ = (
+            self.selection_handles_shown(),
+            self.dragged_selection_handle(),
+            self.magnifier_enabled(),
+        );
+
         let preedit_text = self.preedit_text();
+        let mut preedit_clause_range = Default::default();
+        let underlines = self.underlines();
+        // The underlines are out of date if the text was set through the property
+        let mut underlines: Vec<_> = if self.underlines_refer_to(&text) {
+            underlines.iter().map(|u| (u.start..u.end, u.color, u.style)).collect()
+        } else {
+            Vec::new()
+        };
         let (preedit_range, selection_range, cursor_position) = if !preedit_text.is_empty() {
             let cursor_position = self.cursor_position(&text);
 
             text.insert_str(cursor_position, &preedit_text);
             let preedit_range = cursor_position..cursor_position + preedit_text.len();
+            for (r, ..) in underlines.iter_mut() {
+                if r.start >= cursor_position {
+                    r.start += preedit_text.len();
+                }
+                if r.end > cursor_position {
+                    r.end += preedit_text.len();
+                }
+            }
+            // Offsets reported by the input method may be out of bounds or not on a char boundary
+            let preedit_offset = |offset: i32| {
+                let offset = (offset.max(0) as usize).min(preedit_text.len());
+                cursor_position
+                    + crate::textlayout::grapheme_boundary_at_or_before(&preedit_text, offset)
+            };
 
-            if let Some(preedit_sel) = self.preedit_selection().as_option() {
-                let preedit_selection = cursor_position + preedit_sel.start as usize
-                    ..cursor_position + preedit_sel.end as usize;
-                (preedit_range, preedit_selection, Some(cursor_position + preedit_sel.end as usize))
-            } else {
-                let cur = preedit_range.end;
-                (preedit_range, cur..cur, None)
+            if let Some(clause) = self.preedit_clause().as_option() {
+                preedit_clause_range = preedit_offset(clause.start)..preedit_offset(clause.end);
             }
+
+            // The composition is not selectable, the selection within the pre-edit only places the cursor
+            let cursor = self.preedit_selection().as_option().map(|sel| preedit_offset(sel.end));
+            let cur = cursor.unwrap_or(preedit_range.end);
+            (preedit_range, cur..cur, cursor)
         } else {
             let preedit_range = Default::default();
             let (selection_anchor_pos, selection_cursor_pos) = self.selection_anchor_and_cursor();
@@ -1695,10 +2431,11 @@ pub fn visual_representation(
         let mut repr = TextInputVisualRepresentation {
             text,
             preedit_range,
+            preedit_clause_range,
             selection_range,
+            underlines,
             cursor_position,
             text_without_password: None,
-            password_character: Default::default(),
             text_color,
             cursor_color,
         };
@@ -1706,12 +2443,271 @@ pub fn visual_representation(
         repr
     }
 
+    fn selection_handles_shown(self: Pin<&Self>) -> bool {
+        self.selection_handles_enabled()
+            && self.selection_handles_visible()
+            && self.has_focus()
+            && self.enabled()
+            && self.preedit_text().is_empty()
+    }
+
+    /// Returns the part of the window in the coordinates of the item, within which the touch selection
+    /// handles and the magnifier are kept.
+    fn window_bounds(window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) -> LogicalRect {
+        let origin = self_rc.map_to_window(self_rc.geometry().origin);
+        let window = window_adapter.window();
+        let size = window.size().to_logical(window.scale_factor()).to_euclid();
+        LogicalRect::new(LogicalPoint::zero() - origin.to_vector(), size)
+    }
+
+    /// Returns the ends of the selection that have a touch selection handle, with the position of the
+    /// center of the handle's knob and the rectangle of the cursor at that end. The knob is above the start
+    /// of a selection, and below its end or below the cursor, but always within the window.
+    fn selection_handles(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> impl Iterator<Item = (SelectionHandle, LogicalPoint, LogicalRect)> {
+        let text = self.text();
+        let cursor = self.cursor_position(&text);
+        let anchor = self.anchor_position(&text);
+        let bounds = Self::window_bounds(window_adapter, self_rc);
+        let radius = SELECTION_HANDLE_RADIUS.get();
+        let knob = move |rect: LogicalRect, above: bool| {
+            let y = if above { rect.min_y() - radius } else { rect.max_y() + radius };
+            LogicalPoint::new(
+                rect.center().x.max(bounds.min_x() + radius).min(bounds.max_x() - radius),
+                y.max(bounds.min_y() + radius).min(bounds.max_y() - radius),
+            )
+        };
+
+        let cursor_rect = self.cursor_rect_for_byte_offset(cursor, window_adapter);
+        let anchor_handle = (anchor != cursor).then(|| {
+            let anchor_rect = self.cursor_rect_for_byte_offset(anchor, window_adapter);
+            (SelectionHandle::Anchor, knob(anchor_rect, anchor < cursor), anchor_rect)
+        });
+        core::iter::once((SelectionHandle::Cursor, knob(cursor_rect, anchor > cursor), cursor_rect))
+            .chain(anchor_handle)
+    }
+
+    /// Returns the handle whose knob is under the given position, allowing for the imprecision of touch input.
+    fn selection_handle_at(
+        self: Pin<&Self>,
+        position: LogicalPoint,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> Option<SelectionHandle> {
+        let hit_radius = SELECTION_HANDLE_RADIUS.get() * 3 as Coord;
+        self.selection_handles(window_adapter, self_rc)
+            .map(|(handle, knob, _)| (handle, (knob - position).square_length()))
+            .filter(|(_, distance)| *distance <= hit_radius * hit_radius)
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal))
+            .map(|(handle, _)| handle)
+    }
+
+    fn move_selection_handle(
+        self: Pin<&Self>,
+        position: LogicalPoint,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) {
+        let offset = self.byte_offset_for_position(position, window_adapter) as i32;
+        match self.dragged_selection_handle() {
+            SelectionHandle::Cursor => {
+                // Without a selection, the handle moves the cursor
+                if self.anchor_position_byte_offset() == self.cursor_position_byte_offset() {
+                    self.anchor_position_byte_offset.set(offset);
+                }
+                self.set_cursor_position(
+                    offset,
+                    true,
+                    TextChangeNotify::TriggerCallbacks,
+                    window_adapter,
+                    self_rc,
+                );
+            }
+            SelectionHandle::Anchor => self.anchor_position_byte_offset.set(offset),
+            SelectionHandle::None => {}
+        }
+    }
+
+    /// Returns the geometry of the touch selection handles, and of the magnifier while one of them is dragged.
+    fn selection_decorations(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> SelectionDecorations {
+        let radius = SELECTION_HANDLE_RADIUS.get();
+        let stem_width = (self.text_cursor_width().get() * 2 as Coord).max(2 as Coord);
+        let dragged = self.dragged_selection_handle();
+        let mut decorations = SelectionDecorations::default();
+
+        for (i, (handle, knob, cursor_rect)) in
+            self.selection_handles(window_adapter, self_rc).enumerate()
+        {
+            let stem = LogicalRect::new(
+                LogicalPoint::new(knob.x - stem_width / 2 as Coord, cursor_rect.min_y()),
+                LogicalSize::new(stem_width, cursor_rect.height()),
+            );
+            let knob = LogicalRect::new(
+                knob - euclid::vec2(radius, radius),
+                LogicalSize::new(radius * 2 as Coord, radius * 2 as Coord),
+            );
+            decorations.handles[i] = Some((stem, knob));
+            if handle == dragged && self.magnifier_enabled() {
+                let bounds = Self::window_bounds(window_adapter, self_rc);
+                decorations.magnifier = Some((magnifier_rect(cursor_rect, bounds), cursor_rect));
+            }
+        }
+        decorations
+    }
+
+    /// Marks the parts of the window where the touch selection handles and the magnifier were, and
+    /// where they are now, as dirty, as they are drawn outside of the geometry of the item.
+    fn update_selection_decorations_area(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) {
+        let origin = self_rc.map_to_window(self_rc.geometry().origin);
+        let area = if self.selection_handles_shown() {
+            self.selection_decorations(window_adapter, self_rc).area().translate(origin.to_vector())
+        } else {
+            LogicalRect::zero()
+        };
+        let previous = self.selection_decorations_area.replace(area);
+        if previous != area {
+            let mut region = crate::item_rendering::DirtyRegion::default();
+            region.add_rect(previous);
+            region.add_rect(area);
+            // The decorations are only drawn if the item itself is in the dirty region
+            region.add_rect(LogicalRect::new(origin, self_rc.geometry().size));
+            window_adapter.renderer().mark_dirty_region(region);
+        }
+    }
+
+    fn render_selection_decorations(
+        self: Pin<&Self>,
+        backend: &mut &mut dyn ItemRenderer,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) {
+        let window_adapter = backend.window().window_adapter();
+        let color = self.selection_background_color().with_alpha(1.);
+        let decorations = self.selection_decorations(&window_adapter, self_rc);
+
+        for (i, handle) in decorations.handles.iter().enumerate() {
+            let Some((stem, knob)) = *handle else { continue };
+            for (rect, shape, rendering_data) in [
+                (
+                    stem,
+                    SelectionHandleShape { color, radius: LogicalLength::zero() },
+                    &self.selection_handles_rendering_data[2 * i],
+                ),
+                (
+                    knob,
+                    SelectionHandleShape { color, radius: SELECTION_HANDLE_RADIUS },
+                    &self.selection_handles_rendering_data[2 * i + 1],
+                ),
+            ] {
+                backend.save_state();
+                backend.translate(rect.origin.to_vector());
+                let shape: Pin<&SelectionHandleShape> = Pin::new(&shape);
+                backend.draw_border_rectangle(shape, self_rc, rect.size, rendering_data);
+                backend.restore_state();
+            }
+        }
+
+        if let Some((magnifier, focus)) = decorations.magnifier {
+            self.render_magnifier(backend, self_rc, size, magnifier, focus);
+        }
+
+        // The decorations moved without an event, for example because the text changed
+        let origin = self_rc.map_to_window(self_rc.geometry().origin);
+        let area = decorations.area().translate(origin.to_vector());
+        if area != self.selection_decorations_area.get() {
+            self.update_selection_decorations_area(&window_adapter, self_rc);
+            window_adapter.request_redraw();
+        }
+    }
+
+    /// Draws the text around `focus` larger in the `magnifier` rectangle.
+    fn render_magnifier(
+        self: Pin<&Self>,
+        backend: &mut &mut dyn ItemRenderer,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+        magnifier: LogicalRect,
+        focus: LogicalRect,
+    ) {
+        let window_adapter = backend.window().window_adapter();
+        let padding = SELECTION_HANDLE_RADIUS.get();
+        let corner_radius = LogicalBorderRadius::new_uniform(padding);
+
+        let mut font_request = self.font_request(&window_adapter);
+        font_request.pixel_size = Some(
+            font_request.pixel_size.unwrap_or(LogicalLength::new(12 as Coord)) * MAGNIFIER_ZOOM,
+        );
+        font_request.letter_spacing = font_request.letter_spacing.map(|s| s * MAGNIFIER_ZOOM);
+        let text = MagnifiedText {
+            text: self.visual_representation(None).text.into(),
+            font_request,
+            color: self.color(),
+            alignment: (self.horizontal_alignment(), self.vertical_alignment()),
+            wrap: self.wrap(),
+            letter_spacing: self.letter_spacing() * MAGNIFIER_ZOOM,
+        };
+
+        backend.save_state();
+        backend.translate(magnifier.origin.to_vector());
+        backend.combine_clip(
+            LogicalRect::from_size(magnifier.size),
+            corner_radius,
+            LogicalLength::zero(),
+        );
+        let background = SelectionHandleShape {
+            color: Color::from_argb_u8(0xf0, 0xf8, 0xf8, 0xf8),
+            radius: LogicalLength::new(padding),
+        };
+        let background: Pin<&SelectionHandleShape> = Pin::new(&background);
+        backend.draw_border_rectangle(
+            background,
+            self_rc,
+            magnifier.size,
+            &self.selection_handles_rendering_data[4],
+        );
+        // Center the magnified focus in the magnifier
+        let focus_center = focus.center().to_vector() * MAGNIFIER_ZOOM;
+        backend.translate(
+            euclid::vec2(magnifier.width() / 2 as Coord, magnifier.height() / 2 as Coord)
+                - focus_center,
+        );
+        let text: Pin<&MagnifiedText> = Pin::new(&text);
+        backend.draw_text(
+            text,
+            self_rc,
+            size * MAGNIFIER_ZOOM,
+            &self.selection_handles_rendering_data[5],
+        );
+        backend.restore_state();
+    }
+
     fn cursor_rect_for_byte_offset(
         self: Pin<&Self>,
         byte_offset: usize,
         window_adapter: &Rc<dyn WindowAdapter>,
     ) -> LogicalRect {
-        window_adapter.renderer().text_input_cursor_rect_for_byte_offset(
+        self.cursor_metrics_for_byte_offset(byte_offset, window_adapter).rect
+    }
+
+    /// Returns the cursor rectangle for the given (UTF-8) byte offset in the text, together with
+    /// the baseline, the index, and the box of the line it's on.
+    pub fn cursor_metrics_for_byte_offset(
+        self: Pin<&Self>,
+        byte_offset: usize,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> TextCursorMetrics {
+        window_adapter.renderer().text_input_cursor_metrics_for_byte_offset(
             self,
             byte_offset,
             self.font_request(window_adapter),
@@ -1724,12 +2720,45 @@ pub fn byte_offset_for_position(
         pos: LogicalPoint,
         window_adapter: &Rc<dyn WindowAdapter>,
     ) -> usize {
-        window_adapter.renderer().text_input_byte_offset_for_position(
+        let byte_offset = window_adapter.renderer().text_input_byte_offset_for_position(
             self,
             pos,
             self.font_request(window_adapter),
             ScaleFactor::new(window_adapter.window().scale_factor()),
-        )
+        );
+        // Renderers may hit-test individual glyphs, but the cursor must never end up within a grapheme cluster
+        grapheme_boundary_at_or_before(&self.text(), byte_offset)
+    }
+
+    /// Shows the characters just typed at `range` in a password field for `password-reveal-duration`,
+    /// and hides them again afterwards.
+    fn reveal_typed_password_characters(
+        self: Pin<&Self>,
+        range: core::ops::Range<usize>,
+        self_rc: &ItemRc,
+    ) {
+        let duration = self.password_reveal_duration();
+        if duration <= 0 {
+            return;
+        }
+        let range = Some(range.start as i32..range.end as i32);
+        self.revealed_password_range.set(range.clone().into());
+        let self_weak = self_rc.downgrade();
+        crate::timers::Timer::single_shot(
+            core::time::Duration::from_millis(duration as u64),
+            move || {
+                let Some(text_input) =
+                    self_weak.upgrade().and_then(|item| item.downcast::<TextInput>())
+                else {
+                    return;
+                };
+                let text_input = text_input.as_pin_ref();
+                // A more recently typed character restarted the reveal
+                if text_input.revealed_password_range().as_option() == range {
+                    text_input.revealed_password_range.set(None.into());
+                }
+            },
+        );
     }
 
     /// When pressing the mouse (or releasing the finger, on android) we should take the focus if we don't have it already.
@@ -1753,7 +2782,11 @@ fn ensure_focus_and_ime(
     fn add_undo_item(self: Pin<&Self>, item: UndoItem) {
         let mut items = self.undo_items.take();
         // try to merge with the last item
-        if let Some(last) = items.make_mut_slice().last_mut() {
+        if let Some(last) = items
+            .make_mut_slice()
+            .last_mut()
+            .filter(|last| last.mergeable && item.mergeable && !item.with_previous)
+        {
             match (&item.kind, &last.kind) {
                 (UndoItemKind::TextInsert, UndoItemKind::TextInsert) => {
                     let is_new_line = item.text == "\n";
@@ -1768,11 +2801,14 @@ fn add_undo_item(self: Pin<&Self>, item: UndoItem) {
                 }
                 (UndoItemKind::TextRemove, UndoItemKind::TextRemove) => {
                     if item.pos + item.text.len() == last.pos {
+                        // backspace: prepend
                         last.pos = item.pos;
                         let old_text = last.text.clone();
                         last.text = item.text;
                         last.text += &old_text;
-                        // prepend
+                    } else if item.pos == last.pos {
+                        // delete: append
+                        last.text += &item.text;
                     } else {
                         items.push(item);
                     }
@@ -1786,95 +2822,117 @@ fn add_undo_item(self: Pin<&Self>, item: UndoItem) {
         }
 
         self.undo_items.set(items);
+        // A new edit makes the undone edits obsolete
+        self.redo_items.take();
+        self.update_undo_redo_state();
     }
 
-    fn undo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
-        let mut items = self.undo_items.take();
-        let Some(last) = items.pop() else {
-            return;
-        };
-
-        match last.kind {
-            UndoItemKind::TextInsert => {
-                let text: String = self.text().into();
-                let text = [text.split_at(last.pos).0, text.split_at(last.pos + last.text.len()).1]
-                    .concat();
-                self.text.set(text.into());
+    fn update_undo_redo_state(self: Pin<&Self>) {
+        let undo_items = self.undo_items.take();
+        self.can_undo.set(!undo_items.is_empty());
+        self.undo_items.set(undo_items);
+        let redo_items = self.redo_items.take();
+        self.can_redo.set(!redo_items.is_empty());
+        self.redo_items.set(redo_items);
+    }
 
-                self.anchor_position_byte_offset.set(last.anchor as i32);
-                self.set_cursor_position(
-                    last.cursor as i32,
-                    true,
-                    TextChangeNotify::TriggerCallbacks,
-                    window_adapter,
-                    self_rc,
-                );
+    /// Applies the item to the text, removing the inserted text or inserting the removed text when
+    /// `reverse` is true. Returns false if the item doesn't match the text, because the text was changed
+    /// through the property in the meantime.
+    fn apply_undo_item(
+        self: Pin<&Self>,
+        item: &UndoItem,
+        reverse: bool,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> bool {
+        let mut text: String = self.text().into();
+        let insert = matches!(item.kind, UndoItemKind::TextInsert) != reverse;
+        if insert {
+            if !text.is_char_boundary(item.pos) {
+                return false;
             }
-            UndoItemKind::TextRemove => {
-                let mut text: String = self.text().into();
-                text.insert_str(last.pos, &last.text);
-                self.text.set(text.into());
-
-                self.anchor_position_byte_offset.set(last.anchor as i32);
-                self.set_cursor_position(
-                    last.cursor as i32,
-                    true,
-                    TextChangeNotify::TriggerCallbacks,
-                    window_adapter,
-                    self_rc,
-                );
+            text.insert_str(item.pos, &item.text);
+        } else {
+            if text.get(item.pos..item.pos + item.text.len()) != Some(item.text.as_str()) {
+                return false;
             }
+            text.replace_range(item.pos..item.pos + item.text.len(), "");
         }
-        self.undo_items.set(items);
+        self.text.set(text.into());
+        if insert {
+            self.shift_underlines(item.pos, 0, item.text.len());
+        } else {
+            self.shift_underlines(item.pos, item.text.len(), 0);
+        }
+
+        self.anchor_position_byte_offset.set(item.anchor as i32);
+        self.set_cursor_position(
+            item.cursor as i32,
+            true,
+            TextChangeNotify::TriggerCallbacks,
+            window_adapter,
+            self_rc,
+        );
+        true
+    }
 
+    /// Reverts the last edit, or group of edits such as a run of typed characters.
+    pub fn undo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        let mut items = self.undo_items.take();
         let mut redo = self.redo_items.take();
-        redo.push(last);
+        let mut edited = false;
+        while let Some(last) = items.pop() {
+            if !self.apply_undo_item(&last, true, window_adapter, self_rc) {
+                items = Default::default();
+                redo = Default::default();
+                break;
+            }
+            edited = true;
+            let with_previous = last.with_previous;
+            redo.push(last);
+            if !with_previous {
+                break;
+            }
+        }
+        self.undo_items.set(items);
         self.redo_items.set(redo);
+        self.update_undo_redo_state();
+        if edited {
+            Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
+        }
     }
 
-    fn redo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+    /// Applies the last edit that was reverted with [`Self::undo()`] again.
+    pub fn redo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
         let mut items = self.redo_items.take();
-        let Some(last) = items.pop() else {
-            return;
-        };
-
-        match last.kind {
-            UndoItemKind::TextInsert => {
-                let mut text: String = self.text().into();
-                text.insert_str(last.pos, &last.text);
-                self.text.set(text.into());
-
-                self.anchor_position_byte_offset.set(last.anchor as i32);
-                self.set_cursor_position(
-                    last.cursor as i32,
-                    true,
-                    TextChangeNotify::TriggerCallbacks,
-                    window_adapter,
-                    self_rc,
-                );
+        let mut undo_items = self.undo_items.take();
+        let mut edited = false;
+        while let Some(last) = items.pop() {
+            if !self.apply_undo_item(&last, false, window_adapter, self_rc) {
+                items = Default::default();
+                undo_items = Default::default();
+                break;
             }
-            UndoItemKind::TextRemove => {
-                let text: String = self.text().into();
-                let text = [text.split_at(last.pos).0, text.split_at(last.pos + last.text.len()).1]
-                    .concat();
-                self.text.set(text.into());
-
-                self.anchor_position_byte_offset.set(last.anchor as i32);
-                self.set_cursor_position(
-                    last.cursor as i32,
-                    true,
-                    TextChangeNotify::TriggerCallbacks,
-                    window_adapter,
-                    self_rc,
-                );
+            edited = true;
+            undo_items.push(last);
+            if !items.as_slice().last().is_some_and(|next| next.with_previous) {
+                break;
             }
         }
-
         self.redo_items.set(items);
-
-        let mut undo_items = self.undo_items.take();
-        undo_items.push(last);
         self.undo_items.set(undo_items);
+        self.update_undo_redo_state();
+        if edited {
+            Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
+        }
+    }
+
+    /// Forgets all edits, so that they can't be undone or redone anymore.
+    pub fn clear_undo_history(self: Pin<&Self>, _: &Rc<dyn WindowAdapter>, _: &ItemRc) {
+        self.undo_items.take();
+        self.redo_items.take();
+        self.update_undo_redo_state();
     }
 
     pub fn font_metrics(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) -> FontMetrics {
@@ -1885,6 +2943,114 @@ pub fn font_metrics(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) ->
     }
 }
 
+/// Formats the text made of `before`, `inserted`, and `after` according to the `input-mask` of a `TextInput`.
+///
+/// In the mask, `0` stands for a digit, `A` for a letter, `N` for a letter or a digit, and `X` for any character.
+/// Any other character, or a character escaped with `\\`, is a literal that is added when the input reaches it.
+/// Characters that don't fit are rejected, except for literals of the mask, which are skipped when typed out of place.
+///
+/// Returns the formatted text and the position after the inserted text, or None if `inserted` doesn't fit or the
+/// text becomes longer than the mask.
+fn apply_input_mask(
+    mask: &str,
+    before: &str,
+    inserted: &str,
+    after: &str,
+) -> Option<(String, usize)> {
+    #[derive(PartialEq)]
+    enum Slot {
+        Literal(char),
+        Digit,
+        Letter,
+        Alphanumeric,
+        Any,
+    }
+
+    impl Slot {
+        fn accepts(&self, c: char) -> bool {
+            match self {
+                Slot::Literal(l) => *l == c,
+                Slot::Digit => c.is_ascii_digit(),
+                Slot::Letter => c.is_alphabetic(),
+                Slot::Alphanumeric => c.is_alphanumeric(),
+                Slot::Any => !c.is_control(),
+            }
+        }
+    }
+
+    let mut slots = Vec::new();
+    let mut mask_chars = mask.chars();
+    while let Some(c) = mask_chars.next() {
+        slots.push(match c {
+            '0' => Slot::Digit,
+            'A' => Slot::Letter,
+            'N' => Slot::Alphanumeric,
+            'X' => Slot::Any,
+            '\\' => match mask_chars.next() {
+                Some(c) => Slot::Literal(c),
+                None => break,
+            },
+            c => Slot::Literal(c),
+        });
+    }
+
+    let mut result = String::new();
+    let mut slot = 0;
+    let mut cursor = 0;
+    for (part, text) in [before, inserted, after].into_iter().enumerate() {
+        for c in text.chars() {
+            // The next slot that accepts the character, skipping literals that are added implicitly
+            let fitting_slot = slots[slot..]
+                .iter()
+                .position(|s| s.accepts(c) || !matches!(s, Slot::Literal(_)))
+                .map(|pos| slot + pos)
+                .filter(|pos| slots[*pos].accepts(c));
+            if let Some(fitting_slot) = fitting_slot {
+                for literal in &slots[slot..fitting_slot] {
+                    if let Slot::Literal(l) = literal {
+                        result.push(*l);
+                    }
+                }
+                result.push(c);
+                slot = fitting_slot + 1;
+            } else if slots.contains(&Slot::Literal(c)) {
+                // A separator typed out of place, or which moved while editing
+            } else if part == 0 {
+                // Text that was there before the mask was set
+            } else {
+                return None;
+            }
+        }
+        match part {
+            0 => cursor = result.len(),
+            // Nothing was inserted
+            1 if result.len() == cursor => return None,
+            1 => cursor = result.len(),
+            _ => {}
+        }
+    }
+    Some((result, cursor))
+}
+
+/// Returns true if the text contains a character with a strong right-to-left direction, as per the
+/// `R` and `AL` bidi classes of UAX #9.
+fn contains_rtl(text: &str) -> bool {
+    use unicode_bidi::BidiClass;
+    text.chars().any(|c| matches!(unicode_bidi::bidi_class(c), BidiClass::R | BidiClass::AL))
+}
+
+/// Returns true if the paragraph that contains `byte_offset` has a right-to-left base direction, which
+/// is determined by its first strong character (rule P2 of UAX #9).
+fn is_rtl_paragraph(text: &str, byte_offset: usize) -> bool {
+    let paragraph = &text[prev_paragraph_boundary(text, byte_offset)..];
+    let paragraph = &paragraph[..next_paragraph_boundary(paragraph, 0)];
+    unicode_bidi::get_base_direction(paragraph) == unicode_bidi::Direction::Rtl
+}
+
+fn is_word_segment(segment: &str) -> bool {
+    segment.chars().any(char::is_alphanumeric)
+}
+
 fn next_paragraph_boundary(text: &str, last_cursor_pos: usize) -> usize {
     text.as_bytes()
         .iter()
@@ -1967,6 +3133,75 @@ fn next_word_boundary(text: &str, last_cursor_pos: usize) -> usize {
     text_input.clear_selection(window_adapter, &self_rc);
 }
 
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_add_underline(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+    start: i32,
+    end: i32,
+    color: Color,
+    style: TextUnderlineStyle,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.add_underline(window_adapter, &self_rc, start, end, color, style);
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_clear_underlines(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.clear_underlines(window_adapter, &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_undo(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.undo(window_adapter, &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_redo(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.redo(window_adapter, &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_clear_undo_history(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.clear_undo_history(window_adapter, &self_rc);
+}
+
 #[cfg(feature = "ffi")]
 #[no_mangle]
 pub unsafe extern "C" fn slint_textinput_cut(
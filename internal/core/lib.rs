@@ -40,6 +40,7 @@
 pub mod renderer;
 #[cfg(feature = "rtti")]
 pub mod rtti;
+pub mod settings;
 pub mod sharedvector;
 pub mod slice;
 #[cfg(feature = "software-renderer")]
@@ -49,6 +50,7 @@
 pub mod textlayout;
 pub mod timers;
 pub mod translations;
+pub mod undo;
 pub mod window;
 
 #[doc(inline)]
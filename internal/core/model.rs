@@ -26,6 +26,11 @@
 
 mod adapters;
 mod model_peer;
+mod paged;
+mod text_lines;
+#[cfg(target_has_atomic = "ptr")]
+pub use paged::PagedModel;
+pub use text_lines::TextLinesModel;
 
 type ItemTreeRc<C> = vtable::VRc<crate::item_tree::ItemTreeVTable, C>;
 
@@ -358,6 +363,115 @@ pub fn set_vec(&self, new: impl Into<Vec<T>>) {
         self.notify.reset();
     }
 
+    /// Replace inner Vec with new data, like [`Self::set_vec`], but only notify the views about the rows
+    /// that were removed, inserted, or changed, so that they keep their scroll position and the state
+    /// of the other rows.
+    ///
+    /// Rows are matched by the key returned by `key`, which should be unique. A row whose key is in both
+    /// the old and the new data is kept, and reported as changed if its data differs. The fewest rows
+    /// are moved to put the kept rows in their new order, and a moved row is removed and inserted again.
+    ///
+    /// ```
+    /// # use i_slint_core::model::{Model, VecModel};
+    /// let model = VecModel::from(vec![(1, "one"), (2, "two"), (3, "three")]);
+    /// // Notifies the views that the row of 1 was removed, the one of 3 changed,
+    /// // and that the one of 4 was inserted.
+    /// model.set_vec_by_key(vec![(2, "two"), (3, "THREE"), (4, "four")], |row| row.0);
+    /// assert_eq!(model.row_data(1), Some((3, "THREE")));
+    /// ```
+    pub fn set_vec_by_key<K: Ord>(&self, new: impl Into<Vec<T>>, key: impl Fn(&T) -> K)
+    where
+        T: PartialEq,
+    {
+        let new = new.into();
+        let mut new_index = alloc::collections::BTreeMap::new();
+        for (index, value) in new.iter().enumerate() {
+            new_index.entry(key(value)).or_insert(index);
+        }
+
+        // The rows that can be kept, with the index of the new row with the same key. Then keep the
+        // largest set of them that is already in the new order.
+        let mut matched = alloc::vec![false; new.len()];
+        let candidates = self
+            .array
+            .borrow()
+            .iter()
+            .enumerate()
+            .filter_map(|(row, value)| {
+                let target = *new_index.get(&key(value))?;
+                (!core::mem::replace(&mut matched[target], true)).then_some((row, target))
+            })
+            .collect::<Vec<_>>();
+        let targets = candidates.iter().map(|(_, target)| *target).collect::<Vec<_>>();
+        let mut keep = alloc::vec![false; self.array.borrow().len()];
+        let mut kept_targets = Vec::new();
+        for i in longest_increasing_subsequence(&targets) {
+            keep[candidates[i].0] = true;
+            kept_targets.push(candidates[i].1);
+        }
+
+        // Remove the other rows, in runs of consecutive rows from the end
+        let mut end = keep.len();
+        while end > 0 {
+            if keep[end - 1] {
+                end -= 1;
+                continue;
+            }
+            let mut start = end - 1;
+            while start > 0 && !keep[start - 1] {
+                start -= 1;
+            }
+            self.array.borrow_mut().drain(start..end);
+            self.notify.row_removed(start, end - start);
+            end = start;
+        }
+
+        // Insert the new rows between the kept ones, and update the kept rows
+        let mut kept_targets = kept_targets.into_iter().peekable();
+        let mut pending = Vec::new();
+        let insert_pending = |pending: &mut Vec<T>, end: usize| {
+            if !pending.is_empty() {
+                let start = end - pending.len();
+                let count = pending.len();
+                self.array.borrow_mut().splice(start..start, pending.drain(..));
+                self.notify.row_added(start, count);
+            }
+        };
+        for (index, value) in new.into_iter().enumerate() {
+            if kept_targets.next_if_eq(&index).is_none() {
+                pending.push(value);
+                continue;
+            }
+            insert_pending(&mut pending, index);
+            let mut array = self.array.borrow_mut();
+            if array[index] != value {
+                array[index] = value;
+                drop(array);
+                self.notify.row_changed(index);
+            }
+        }
+        let len = self.array.borrow().len() + pending.len();
+        insert_pending(&mut pending, len);
+    }
+
+    /// Calls `f` to apply many changes to the model at once. The views are notified when `f` returns,
+    /// with the redundant notifications coalesced, see [`ModelNotify::begin_update()`].
+    ///
+    /// ```
+    /// # use i_slint_core::model::{Model, VecModel};
+    /// let model = VecModel::from(vec![1, 2, 3]);
+    /// // The views are notified once that three rows were added
+    /// model.batch(|model| {
+    ///     for value in 4..=6 {
+    ///         model.push(value);
+    ///     }
+    /// });
+    /// assert_eq!(model.row_count(), 6);
+    /// ```
+    pub fn batch<R>(&self, f: impl FnOnce(&Self) -> R) -> R {
+        self.notify.batch(|| f(self))
+    }
+
     /// Extend the model with the content of the iterator
     ///
     /// Similar to [`Vec::extend`]
@@ -404,6 +518,32 @@ pub fn extend_from_slice(&self, src: &[T]) {
     }
 }
 
+/// Returns the indices in `seq` of one of its longest strictly increasing subsequences.
+fn longest_increasing_subsequence(seq: &[usize]) -> Vec<usize> {
+    // `tails[k]` is the index of the smallest last element of an increasing subsequence of length k + 1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous = alloc::vec![None; seq.len()];
+    for (i, value) in seq.iter().enumerate() {
+        let position = tails.partition_point(|tail| seq[*tail] < *value);
+        if position > 0 {
+            previous[i] = Some(tails[position - 1]);
+        }
+        if position == tails.len() {
+            tails.push(i);
+        } else {
+            tails[position] = i;
+        }
+    }
+    let mut result = Vec::with_capacity(tails.len());
+    let mut current = tails.last().copied();
+    while let Some(i) = current {
+        result.push(i);
+        current = previous[i];
+    }
+    result.reverse();
+    result
+}
+
 impl<T> From<Vec<T>> for VecModel<T> {
     fn from(array: Vec<T>) -> Self {
         VecModel { array: RefCell::new(array), notify: Default::default() }
@@ -1462,6 +1602,91 @@ fn test_vecmodel_set_vec() {
         assert_eq!(model.iter().collect::<Vec<_>>(), vec![6, 8, 7, 9, 10, 11, 12, 13]);
     }
 
+    #[test]
+    fn test_vecmodel_set_vec_by_key() {
+        let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+
+        let model = Rc::new(VecModel::from(vec![10, 20, 30, 40]));
+        model.model_tracker().attach_peer(Pin::as_ref(&view).model_peer());
+        *view.model.borrow_mut() =
+            Some(std::rc::Rc::downgrade(&(model.clone() as Rc<dyn Model<Data = i32>>)));
+
+        // The key is the tens, so 30 and 31 are the same row
+        model.set_vec_by_key(vec![20, 31, 50, 10], |v| v / 10);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![20, 31, 50, 10]);
+        assert_eq!(&*view.removed_rows.borrow(), &[(3, 1, 3), (0, 1, 2)]);
+        assert_eq!(&*view.changed_rows.borrow(), &[(1, 2)]);
+        assert_eq!(&*view.added_rows.borrow(), &[(2, 2, 4)]);
+        assert_eq!(*view.reset.borrow(), 0);
+        view.clear();
+
+        model.set_vec_by_key(vec![20, 31, 50, 10], |v| v / 10);
+        assert!(view.changed_rows.borrow().is_empty());
+        assert!(view.added_rows.borrow().is_empty());
+        assert!(view.removed_rows.borrow().is_empty());
+        view.clear();
+
+        model.set_vec_by_key(vec![5, 10, 20, 70, 31, 50, 60], |v| v / 10);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![5, 10, 20, 70, 31, 50, 60]);
+        assert_eq!(&*view.removed_rows.borrow(), &[(3, 1, 3)]);
+        assert_eq!(&*view.added_rows.borrow(), &[(0, 2, 5), (3, 1, 6), (6, 1, 7)]);
+        assert!(view.changed_rows.borrow().is_empty());
+        view.clear();
+
+        model.set_vec_by_key(vec![], |v| v / 10);
+        assert_eq!(model.row_count(), 0);
+        assert_eq!(&*view.removed_rows.borrow(), &[(0, 7, 0)]);
+        assert_eq!(*view.reset.borrow(), 0);
+    }
+
+    #[test]
+    fn test_vecmodel_batch() {
+        let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+
+        let model = Rc::new(VecModel::from(vec![1, 2, 3, 4]));
+        model.model_tracker().attach_peer(Pin::as_ref(&view).model_peer());
+        *view.model.borrow_mut() =
+            Some(std::rc::Rc::downgrade(&(model.clone() as Rc<dyn Model<Data = i32>>)));
+
+        model.batch(|model| {
+            model.push(5);
+            model.push(6);
+            model.set_row_data(5, 7);
+            model.set_row_data(0, 8);
+            model.set_row_data(0, 9);
+            assert!(view.added_rows.borrow().is_empty());
+            assert!(view.changed_rows.borrow().is_empty());
+        });
+        assert_eq!(&*view.added_rows.borrow(), &[(4, 2, 6)]);
+        assert_eq!(&*view.changed_rows.borrow(), &[(0, 6)]);
+        view.clear();
+
+        // Nested batches notify when the outermost one ends
+        model.batch(|model| {
+            model.remove(0);
+            model.batch(|model| {
+                model.remove(0);
+                model.remove(3);
+                model.remove(2);
+            });
+            assert!(view.removed_rows.borrow().is_empty());
+        });
+        assert_eq!(&*view.removed_rows.borrow(), &[(0, 2, 2), (2, 2, 2)]);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![3, 4]);
+        view.clear();
+
+        // Many separate changes reset the views
+        model.batch(|model| {
+            for i in 0..20 {
+                let index = if i % 2 == 0 { 0 } else { model.row_count() };
+                model.insert(index, i);
+            }
+        });
+        assert!(view.added_rows.borrow().is_empty());
+        assert_eq!(*view.reset.borrow(), 1);
+        assert_eq!(model.row_count(), 22);
+    }
+
     #[test]
     fn test_vecmodel_clear() {
         let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
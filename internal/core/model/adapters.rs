@@ -576,6 +576,32 @@ fn cmp(&mut self, lhs: &D, rhs: &D) -> core::cmp::Ordering {
     }
 }
 
+/// Sorts the rows of a [`SortModel`] created with [`SortModel::new_with_sort_keys()`] by a list of
+/// keys, each a column with its own [`SortOrder`](crate::items::SortOrder).
+pub struct MultiKeySortHelper<F> {
+    compare: F,
+    keys: Vec<(usize, crate::items::SortOrder)>,
+}
+
+impl<F, D> SortHelper<D> for MultiKeySortHelper<F>
+where
+    F: FnMut(usize, &D, &D) -> core::cmp::Ordering + 'static,
+{
+    fn cmp(&mut self, lhs: &D, rhs: &D) -> core::cmp::Ordering {
+        for (column, order) in &self.keys {
+            let ordering = match order {
+                crate::items::SortOrder::Unsorted => continue,
+                crate::items::SortOrder::Ascending => (self.compare)(*column, lhs, rhs),
+                crate::items::SortOrder::Descending => (self.compare)(*column, rhs, lhs),
+            };
+            if ordering != core::cmp::Ordering::Equal {
+                return ordering;
+            }
+        }
+        core::cmp::Ordering::Equal
+    }
+}
+
 struct SortModelInner<M, S>
 where
     M: Model + 'static,
@@ -612,6 +638,30 @@ fn build_mapping_vec(&self) {
 
         self.sorted_rows_dirty.set(false);
     }
+
+    /// Sorts the rows again after the sort helper changed, starting from their current order, and
+    /// notifies the rows that moved instead of resetting the model.
+    fn resort(&self) {
+        if self.sorted_rows_dirty.get() {
+            return;
+        }
+
+        let old_mapping = self.mapping.borrow().clone();
+        let mut mapping = old_mapping.clone();
+        mapping.sort_by(|lhs, rhs| {
+            self.sort_helper.borrow_mut().cmp(
+                &self.wrapped_model.row_data(*lhs).unwrap(),
+                &self.wrapped_model.row_data(*rhs).unwrap(),
+            )
+        });
+        *self.mapping.borrow_mut() = mapping.clone();
+
+        for (row, (old, new)) in old_mapping.iter().zip(mapping.iter()).enumerate() {
+            if old != new {
+                self.notify.row_changed(row);
+            }
+        }
+    }
 }
 
 impl<M, S> ModelChangeListener for SortModelInner<M, S>
@@ -894,6 +944,76 @@ pub fn unsorted_row(&self, sorted_row: usize) -> usize {
     }
 }
 
+impl<M, F> SortModel<M, MultiKeySortHelper<F>>
+where
+    M: Model + 'static,
+    F: FnMut(usize, &M::Data, &M::Data) -> core::cmp::Ordering + 'static,
+{
+    /// Creates a new SortModel based on the given `wrapped_model`, sorted by a list of keys that can be
+    /// changed at run-time, for example when clicking the headers of a `StandardTableView`.
+    ///
+    /// A key is a column and a [`SortOrder`](crate::items::SortOrder). `compare` compares two rows
+    /// by the given column in ascending order. The model starts without keys, in the order of
+    /// `wrapped_model`.
+    ///
+    /// ```
+    /// # use slint::{Model, VecModel, SortModel, SortOrder};
+    /// let model = VecModel::from(vec![("Bob", 32), ("Alice", 25), ("Carol", 25)]);
+    /// let sorted_model = SortModel::new_with_sort_keys(model, |column, lhs, rhs| match column {
+    ///     0 => lhs.0.cmp(rhs.0),
+    ///     _ => lhs.1.cmp(&rhs.1),
+    /// });
+    /// sorted_model.set_sort_keys([(1, SortOrder::Descending), (0, SortOrder::Ascending)]);
+    /// assert_eq!(sorted_model.row_data(0), Some(("Bob", 32)));
+    /// assert_eq!(sorted_model.row_data(1), Some(("Alice", 25)));
+    /// assert_eq!(sorted_model.row_data(2), Some(("Carol", 25)));
+    /// ```
+    pub fn new_with_sort_keys(wrapped_model: M, compare: F) -> Self {
+        let sorted_model_inner = SortModelInner {
+            wrapped_model,
+            sort_helper: RefCell::new(MultiKeySortHelper { compare, keys: Vec::new() }),
+            mapping: RefCell::new(Vec::new()),
+            notify: Default::default(),
+            sorted_rows_dirty: Cell::new(true),
+        };
+
+        let container = Box::pin(ModelChangeListenerContainer::new(sorted_model_inner));
+
+        container.wrapped_model.model_tracker().attach_peer(container.as_ref().model_peer());
+
+        Self(container)
+    }
+
+    /// Returns the keys the rows are sorted by, the most significant first.
+    pub fn sort_keys(&self) -> Vec<(usize, crate::items::SortOrder)> {
+        self.0.sort_helper.borrow().keys.clone()
+    }
+
+    /// Sorts the rows by the given keys, the most significant first. Keys with
+    /// [`SortOrder::Unsorted`](crate::items::SortOrder::Unsorted) are ignored.
+    ///
+    /// The rows are sorted again starting from their current order, so rows that compare equal keep
+    /// their order, and the rows that moved are reported as changed instead of resetting the model,
+    /// which keeps the scroll position of the views.
+    pub fn set_sort_keys(&self, keys: impl IntoIterator<Item = (usize, crate::items::SortOrder)>) {
+        self.0.sort_helper.borrow_mut().keys = keys
+            .into_iter()
+            .filter(|(_, order)| *order != crate::items::SortOrder::Unsorted)
+            .collect();
+        self.0.resort();
+    }
+
+    /// Makes `column` the most significant sort key with the given order, and keeps the other keys
+    /// as less significant ones. This is what clicking the header of a table column usually does.
+    /// `SortOrder::Unsorted` removes the column from the keys.
+    pub fn set_primary_sort_key(&self, column: usize, order: crate::items::SortOrder) {
+        let mut keys = self.sort_keys();
+        keys.retain(|(c, _)| *c != column);
+        keys.insert(0, (column, order));
+        self.set_sort_keys(keys);
+    }
+}
+
 impl<M, S> Model for SortModel<M, S>
 where
     M: Model + 'static,
@@ -1066,6 +1186,52 @@ fn test_sorted_model_source_model() {
             assert_eq!(model.row_data(i), Some(*v), "Expected {} at index {}", v, i);
         }
     }
+
+    #[test]
+    fn test_sorted_model_sort_keys() {
+        use crate::items::SortOrder;
+        // The tens and the units are the columns
+        let wrapped_rc = Rc::new(VecModel::from(vec![12, 21, 11, 22]));
+        let model =
+            Rc::new(SortModel::new_with_sort_keys(wrapped_rc.clone(), |column, lhs, rhs| {
+                match column {
+                    0 => (lhs / 10).cmp(&(rhs / 10)),
+                    _ => (lhs % 10).cmp(&(rhs % 10)),
+                }
+            }));
+        let _checker = ModelChecker::new(model.clone());
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        let rows = || model.iter().collect::<Vec<i32>>();
+        assert_eq!(rows(), [12, 21, 11, 22]);
+
+        model.set_sort_keys([(1, SortOrder::Ascending), (0, SortOrder::Descending)]);
+        assert_eq!(model.sort_keys(), [(1, SortOrder::Ascending), (0, SortOrder::Descending)]);
+        assert_eq!(rows(), [21, 11, 22, 12]);
+        assert_eq!(*observer.changed_rows.borrow(), [0, 1, 2, 3]);
+        assert_eq!(*observer.reset.borrow(), 0);
+        observer.clear();
+
+        // Only the rows that moved are reported
+        model.set_primary_sort_key(0, SortOrder::Descending);
+        assert_eq!(model.sort_keys(), [(0, SortOrder::Descending), (1, SortOrder::Ascending)]);
+        assert_eq!(rows(), [21, 22, 11, 12]);
+        assert_eq!(*observer.changed_rows.borrow(), [1, 2]);
+        observer.clear();
+
+        // Rows that compare equal keep their order
+        model.set_sort_keys([(0, SortOrder::Ascending), (1, SortOrder::Unsorted)]);
+        assert_eq!(model.sort_keys(), [(0, SortOrder::Ascending)]);
+        assert_eq!(rows(), [11, 12, 21, 22]);
+
+        wrapped_rc.push(13);
+        wrapped_rc.push(20);
+        // Added rows are inserted before the rows that compare equal
+        assert_eq!(rows(), [13, 11, 12, 20, 21, 22]);
+        assert_eq!(*observer.reset.borrow(), 0);
+    }
 }
 
 /// Provides a reversed view of another [`Model`].
@@ -1329,6 +1495,34 @@ fn test_reversed_model_source_model() {
 
         check_content(&model, &[5, 4, 3, 2, 1]);
     }
+
+    #[test]
+    fn test_reversed_model_multiple_rows() {
+        // Like a chat that stores the messages oldest first and shows the newest first
+        let wrapped_rc = Rc::new(VecModel::from(vec![1, 2, 3, 4]));
+        let model = Rc::new(ReverseModel::new(wrapped_rc.clone()));
+        let _checker = ModelChecker::new(model.clone());
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        wrapped_rc.extend([5, 6, 7]);
+        assert_eq!(*observer.added_rows.borrow(), [(0, 3)]);
+        check_content(&model, &[7, 6, 5, 4, 3, 2, 1]);
+        observer.clear();
+
+        // Drop the oldest messages
+        wrapped_rc.set_vec_by_key(vec![4, 5, 6, 7], |v| *v);
+        assert_eq!(*observer.removed_rows.borrow(), [(4, 3)]);
+        assert!(observer.added_rows.borrow().is_empty());
+        check_content(&model, &[7, 6, 5, 4]);
+        observer.clear();
+
+        wrapped_rc.set_vec_by_key(vec![4, 7], |v| *v);
+        assert_eq!(*observer.removed_rows.borrow(), [(1, 2)]);
+        check_content(&model, &[7, 4]);
+        assert_eq!(*observer.reset.borrow(), 0);
+    }
 }
 
 #[test]
@@ -34,8 +34,24 @@ struct ModelNotifyInner {
     peers: DependencyListHead,
     // Sorted list of rows that track_row_data_changes() was called for
     tracked_rows: RefCell<Vec<usize>>,
+    // Number of nested begin_update() calls without their end_update()
+    batch_depth: Cell<usize>,
+    // The coalesced notifications sent when the outermost batch ends
+    pending: RefCell<Vec<PendingNotification>>,
 }
 
+/// A notification recorded during a batch, see [`ModelNotify::begin_update`]
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum PendingNotification {
+    Changed(usize),
+    Added(usize, usize),
+    Removed(usize, usize),
+    Reset,
+}
+
+/// When a batch leaves more added or removed ranges than this after coalescing, the peers are reset instead.
+const MAX_BATCHED_STRUCTURAL_CHANGES: usize = 16;
+
 /// Dispatch notifications from a [`Model`] to one or several [`ModelPeer`].
 /// Typically, you would want to put this in the implementation of the Model
 #[derive(Default)]
@@ -51,6 +67,9 @@ fn inner(&self) -> Pin<&ModelNotifyInner> {
     /// Notify the peers that a specific row was changed
     pub fn row_changed(&self, row: usize) {
         if let Some(inner) = self.inner.get() {
+            if inner.batch_depth.get() > 0 {
+                return Self::add_pending(inner, PendingNotification::Changed(row));
+            }
             if inner.tracked_rows.borrow().binary_search(&row).is_ok() {
                 inner.model_row_data_dirty_property.mark_dirty();
             }
@@ -63,6 +82,9 @@ pub fn row_changed(&self, row: usize) {
     /// Notify the peers that rows were added
     pub fn row_added(&self, index: usize, count: usize) {
         if let Some(inner) = self.inner.get() {
+            if inner.batch_depth.get() > 0 {
+                return Self::add_pending(inner, PendingNotification::Added(index, count));
+            }
             inner.model_row_count_dirty_property.mark_dirty();
             inner.tracked_rows.borrow_mut().clear();
             inner.model_row_data_dirty_property.mark_dirty();
@@ -75,6 +97,9 @@ pub fn row_added(&self, index: usize, count: usize) {
     /// Notify the peers that rows were removed
     pub fn row_removed(&self, index: usize, count: usize) {
         if let Some(inner) = self.inner.get() {
+            if inner.batch_depth.get() > 0 {
+                return Self::add_pending(inner, PendingNotification::Removed(index, count));
+            }
             inner.model_row_count_dirty_property.mark_dirty();
             inner.tracked_rows.borrow_mut().clear();
             inner.model_row_data_dirty_property.mark_dirty();
@@ -89,6 +114,9 @@ pub fn row_removed(&self, index: usize, count: usize) {
     /// everything needs to be reloaded
     pub fn reset(&self) {
         if let Some(inner) = self.inner.get() {
+            if inner.batch_depth.get() > 0 {
+                return Self::add_pending(inner, PendingNotification::Reset);
+            }
             inner.model_row_count_dirty_property.mark_dirty();
             inner.tracked_rows.borrow_mut().clear();
             inner.model_row_data_dirty_property.mark_dirty();
@@ -98,6 +126,87 @@ pub fn reset(&self) {
             })
         }
     }
+
+    /// Starts a batch of changes: until the matching call to [`Self::end_update()`], the notifications
+    /// are recorded instead of being sent to the peers. Batches can be nested.
+    ///
+    /// When the outermost batch ends, redundant notifications are dropped, consecutive added or removed
+    /// rows are merged into ranges, and the peers are reset instead if many ranges are left. This is
+    /// useful when applying many changes at once, such as adding rows one by one.
+    pub fn begin_update(&self) {
+        let inner = self.inner();
+        inner.batch_depth.set(inner.batch_depth.get() + 1);
+    }
+
+    /// Ends a batch of changes started with [`Self::begin_update()`]. When the outermost batch ends,
+    /// the peers are notified about the changes.
+    pub fn end_update(&self) {
+        let inner = self.inner();
+        let depth = inner.batch_depth.get();
+        debug_assert!(depth > 0, "ModelNotify::end_update() called without begin_update()");
+        inner.batch_depth.set(depth.saturating_sub(1));
+        if depth > 1 {
+            return;
+        }
+        let pending = core::mem::take(&mut *inner.pending.borrow_mut());
+        for notification in pending {
+            match notification {
+                PendingNotification::Changed(row) => self.row_changed(row),
+                PendingNotification::Added(index, count) => self.row_added(index, count),
+                PendingNotification::Removed(index, count) => self.row_removed(index, count),
+                PendingNotification::Reset => self.reset(),
+            }
+        }
+    }
+
+    /// Calls `f` within a batch of changes, see [`Self::begin_update()`].
+    pub fn batch<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct EndUpdateOnDrop<'a>(&'a ModelNotify);
+        impl Drop for EndUpdateOnDrop<'_> {
+            fn drop(&mut self) {
+                self.0.end_update();
+            }
+        }
+        self.begin_update();
+        let _guard = EndUpdateOnDrop(self);
+        f()
+    }
+
+    fn add_pending(inner: &ModelNotifyInner, notification: PendingNotification) {
+        use PendingNotification::*;
+        let mut pending = inner.pending.borrow_mut();
+        match (pending.last_mut(), notification) {
+            (Some(Reset), _) => {}
+            (_, Reset) => {
+                pending.clear();
+                pending.push(Reset);
+            }
+            (Some(Changed(last)), Changed(row)) if *last == row => {}
+            // The new row is already reported as added
+            (Some(Added(index, count)), Changed(row))
+                if (*index..*index + *count).contains(&row) => {}
+            (Some(Added(index, count)), Added(new_index, new_count))
+                if (*index..=*index + *count).contains(&new_index) =>
+            {
+                *count += new_count;
+            }
+            (Some(Removed(index, count)), Removed(new_index, new_count)) if new_index == *index => {
+                *count += new_count;
+            }
+            (Some(Removed(index, count)), Removed(new_index, new_count))
+                if new_index + new_count == *index =>
+            {
+                *index = new_index;
+                *count += new_count;
+            }
+            (_, notification) => pending.push(notification),
+        }
+        let structural = pending.iter().filter(|n| matches!(n, Added(..) | Removed(..))).count();
+        if structural > MAX_BATCHED_STRUCTURAL_CHANGES {
+            pending.clear();
+            pending.push(Reset);
+        }
+    }
 }
 
 impl ModelTracker for ModelNotify {
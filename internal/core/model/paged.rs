@@ -0,0 +1,266 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+#![cfg(target_has_atomic = "ptr")] // spawn_local is not available.
+
+//! This module contains the [`PagedModel`], a model that loads its rows in pages on demand.
+
+use super::*;
+use alloc::collections::BTreeMap;
+use core::future::Future;
+
+type PageLoader<T> = Box<dyn Fn(core::ops::Range<usize>) -> Pin<Box<dyn Future<Output = Vec<T>>>>>;
+
+enum Page<T> {
+    Loading,
+    Loaded(Vec<T>),
+}
+
+struct PagedModelInner<T> {
+    row_count: Cell<usize>,
+    page_size: usize,
+    prefetch_pages: Cell<usize>,
+    max_cached_pages: Cell<usize>,
+    placeholder: T,
+    loader: PageLoader<T>,
+    /// The pages that are loaded or being loaded, with the value of `access_counter` when they
+    /// were last requested.
+    pages: RefCell<BTreeMap<usize, (Page<T>, u64)>>,
+    access_counter: Cell<u64>,
+    /// Incremented when the cached pages are discarded, so that the pages still being loaded are
+    /// discarded as well when they arrive.
+    generation: Cell<u64>,
+    notify: ModelNotify,
+}
+
+/// A [`Model`] for a large number of rows that are loaded asynchronously, one page of rows at a time.
+///
+/// This is meant to be used with a virtualized view, such as a `ListView` or a `StandardTableView`,
+/// to show the rows of a database table or of a remote service without loading all of them. The
+/// application provides the number of rows and a function that returns a future loading the rows in a
+/// range. The future is spawned on the Slint event loop with `slint::spawn_local()` when one of its
+/// rows is requested by the view, which only happens for the visible rows.
+///
+/// Until its page is loaded, a row reports a copy of the placeholder passed to [`Self::new()`]. The
+/// model also loads the pages before and after the requested one, so that the rows are usually ready
+/// before they're scrolled into view, and it keeps only the pages that were requested most recently.
+///
+/// Use [`ModelExt::map`] to convert the rows to the struct used in the `.slint` file.
+///
+/// ## Example
+///
+/// ```no_run
+/// # use slint::{Model, PagedModel, SharedString};
+/// async fn query_names(offset: usize, limit: usize) -> Vec<SharedString> {
+///     // Query the database here
+///     (offset..offset + limit).map(|i| slint::format!("Customer {i}")).collect()
+/// }
+///
+/// let model = PagedModel::new(1_000_000, 100, SharedString::from("Loading…"), |range| {
+///     query_names(range.start, range.len())
+/// });
+/// assert_eq!(model.row_count(), 1_000_000);
+/// // The first request returns the placeholder and starts loading the page
+/// assert_eq!(model.row_data(4242).unwrap(), "Loading…");
+/// ```
+pub struct PagedModel<T>(Rc<PagedModelInner<T>>);
+
+impl<T: Clone + 'static> PagedModel<T> {
+    /// Creates a new model with `row_count` rows, loaded `page_size` rows at a time with the future
+    /// returned by `loader`. The range passed to `loader` is never empty and ends at `row_count` at
+    /// most. If the future returns fewer rows than requested, the missing rows keep the `placeholder`.
+    pub fn new<F: Future<Output = Vec<T>> + 'static>(
+        row_count: usize,
+        page_size: usize,
+        placeholder: T,
+        loader: impl Fn(core::ops::Range<usize>) -> F + 'static,
+    ) -> Self {
+        Self(Rc::new(PagedModelInner {
+            row_count: Cell::new(row_count),
+            page_size: page_size.max(1),
+            prefetch_pages: Cell::new(1),
+            max_cached_pages: Cell::new(32),
+            placeholder,
+            loader: Box::new(move |range| Box::pin(loader(range))),
+            pages: Default::default(),
+            access_counter: Default::default(),
+            generation: Default::default(),
+            notify: Default::default(),
+        }))
+    }
+
+    /// Returns the number of rows loaded at a time.
+    pub fn page_size(&self) -> usize {
+        self.0.page_size
+    }
+
+    /// Sets how many pages before and after the page of a requested row are loaded as well. The
+    /// default is 1.
+    pub fn set_prefetch_pages(&self, pages: usize) {
+        self.0.prefetch_pages.set(pages);
+    }
+
+    /// Sets how many loaded pages are kept. When more pages are loaded, the pages that were requested
+    /// least recently are discarded, and loaded again if they're requested again. The default is 32.
+    ///
+    /// This should be larger than the number of pages visible at once plus the prefetched pages.
+    pub fn set_max_cached_pages(&self, pages: usize) {
+        self.0.max_cached_pages.set(pages.max(1));
+        self.0.evict_pages(None);
+    }
+
+    /// Changes the number of rows. All loaded pages are discarded.
+    pub fn set_row_count(&self, row_count: usize) {
+        self.0.row_count.set(row_count);
+        self.0.clear_pages();
+        self.0.notify.reset();
+    }
+
+    /// Discards all loaded pages, for example after the data source has changed. The visible rows show
+    /// the placeholder until they're loaded again.
+    pub fn reload(&self) {
+        let pages = self.0.clear_pages();
+        let page_size = self.0.page_size;
+        let row_count = self.0.row_count.get();
+        for page in pages {
+            for row in page * page_size..((page + 1) * page_size).min(row_count) {
+                self.0.notify.row_changed(row);
+            }
+        }
+    }
+
+    /// Returns true if the given row is loaded, and false if it reports the placeholder.
+    pub fn is_row_loaded(&self, row: usize) -> bool {
+        let page_size = self.0.page_size;
+        match self.0.pages.borrow().get(&(row / page_size)) {
+            Some((Page::Loaded(rows), _)) => row % page_size < rows.len(),
+            _ => false,
+        }
+    }
+}
+
+impl<T: Clone + 'static> PagedModelInner<T> {
+    /// Starts loading the page if it's not loaded or being loaded yet.
+    fn load_page(self: &Rc<Self>, page: usize) {
+        let start = page * self.page_size;
+        let row_count = self.row_count.get();
+        if start >= row_count || self.pages.borrow().contains_key(&page) {
+            return;
+        }
+        self.pages.borrow_mut().insert(page, (Page::Loading, self.access_counter.get()));
+
+        let fut = (self.loader)(start..(start + self.page_size).min(row_count));
+        let self_weak = Rc::downgrade(self);
+        let generation = self.generation.get();
+        let spawned = crate::context::GLOBAL_CONTEXT.with(|ctx| {
+            ctx.get().map(|ctx| {
+                ctx.spawn_local(async move {
+                    let rows = fut.await;
+                    if let Some(inner) = self_weak.upgrade() {
+                        inner.page_loaded(page, generation, rows);
+                    }
+                })
+            })
+        });
+        if !matches!(spawned, Some(Ok(_))) {
+            crate::debug_log!("PagedModel: can't load rows without an event loop");
+        }
+    }
+
+    fn page_loaded(&self, page: usize, generation: u64, rows: Vec<T>) {
+        if generation != self.generation.get() {
+            return;
+        }
+        let count = {
+            let mut pages = self.pages.borrow_mut();
+            let Some((state @ Page::Loading, _)) = pages.get_mut(&page) else { return };
+            let count = rows.len().min(self.page_size);
+            *state = Page::Loaded(rows);
+            count
+        };
+        self.evict_pages(Some(page));
+        let start = page * self.page_size;
+        for row in start..(start + count).min(self.row_count.get()) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    /// Discards the least recently requested loaded pages, except `keep`, until at most
+    /// `max_cached_pages` are left.
+    fn evict_pages(&self, keep: Option<usize>) {
+        let mut pages = self.pages.borrow_mut();
+        loop {
+            let loaded = pages.values().filter(|(page, _)| matches!(page, Page::Loaded(_)));
+            if loaded.count() <= self.max_cached_pages.get() {
+                break;
+            }
+            let oldest = pages
+                .iter()
+                .filter(|(index, (page, _))| {
+                    Some(**index) != keep && matches!(page, Page::Loaded(_))
+                })
+                .min_by_key(|(_, (_, last_access))| *last_access)
+                .map(|(index, _)| *index);
+            match oldest {
+                Some(oldest) => pages.remove(&oldest),
+                None => break,
+            };
+        }
+    }
+
+    /// Discards all pages and returns the indices of the pages that were loaded.
+    fn clear_pages(&self) -> Vec<usize> {
+        self.generation.set(self.generation.get() + 1);
+        let pages = core::mem::take(&mut *self.pages.borrow_mut());
+        pages
+            .into_iter()
+            .filter_map(|(index, (page, _))| matches!(page, Page::Loaded(_)).then_some(index))
+            .collect()
+    }
+}
+
+impl<T: Clone + 'static> Model for PagedModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.0.row_count.get()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        if row >= self.0.row_count.get() {
+            return None;
+        }
+        let page = row / self.0.page_size;
+        let access = self.0.access_counter.get() + 1;
+        self.0.access_counter.set(access);
+        let data = match self.0.pages.borrow_mut().get_mut(&page) {
+            Some((state, last_access)) => {
+                *last_access = access;
+                match state {
+                    Page::Loaded(rows) => rows.get(row % self.0.page_size).cloned(),
+                    Page::Loading => None,
+                }
+            }
+            None => None,
+        };
+
+        self.0.load_page(page);
+        let prefetch = self.0.prefetch_pages.get();
+        for offset in 1..=prefetch {
+            self.0.load_page(page + offset);
+            if let Some(before) = page.checked_sub(offset) {
+                self.0.load_page(before);
+            }
+        }
+
+        Some(data.unwrap_or_else(|| self.0.placeholder.clone()))
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
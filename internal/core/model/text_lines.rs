@@ -0,0 +1,300 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`TextLinesModel`], a model exposing the lines of a text together
+//! with per-line style metadata.
+
+use super::*;
+
+/// A [`Model`] that provides the lines of a text, each associated with a style of type `S`.
+///
+/// This is meant to be used with a virtualized view, such as a `ListView`, to show large texts
+/// with per-line decorations, for example the output of a diff or a log file. The text is kept as a
+/// single string together with the offsets of each line, so that setting the text of tens of thousands
+/// of lines doesn't allocate one string per line. The string for a line is only created when a row is
+/// requested by the view, which only happens for the visible lines.
+///
+/// Each row is a tuple of the text of the line (without the line terminator) and its style.
+/// Styles default to `S::default()` and can be changed for individual lines or ranges of lines,
+/// which only notifies the view about the lines that actually changed.
+///
+/// Use [`ModelExt::map`] to convert the rows to the struct used in the `.slint` file.
+///
+/// ## Example
+///
+/// ```
+/// # use slint::{Model, TextLinesModel, Color};
+/// #[derive(Clone, Default, PartialEq, Debug)]
+/// struct DiffStyle {
+///     background: Color,
+/// }
+///
+/// let model = TextLinesModel::<DiffStyle>::new("fn main() {\n-    old();\n+    new();\n}");
+/// assert_eq!(model.row_count(), 4);
+///
+/// model.set_line_style(1, DiffStyle { background: Color::from_rgb_u8(0xff, 0xd0, 0xd0) });
+/// model.set_line_style(2, DiffStyle { background: Color::from_rgb_u8(0xd0, 0xff, 0xd0) });
+///
+/// let (text, style) = model.row_data(2).unwrap();
+/// assert_eq!(text, "+    new();");
+/// assert_eq!(style.background, Color::from_rgb_u8(0xd0, 0xff, 0xd0));
+/// assert_eq!(model.row_data(3).unwrap().1, DiffStyle::default());
+/// ```
+pub struct TextLinesModel<S> {
+    text: RefCell<SharedString>,
+    /// The byte range of each line within `text`, excluding the line terminator.
+    lines: RefCell<Vec<core::ops::Range<usize>>>,
+    styles: RefCell<Vec<S>>,
+    notify: ModelNotify,
+}
+
+impl<S: Clone + Default + 'static> Default for TextLinesModel<S> {
+    fn default() -> Self {
+        Self::new(SharedString::default())
+    }
+}
+
+impl<S: Clone + Default + 'static> TextLinesModel<S> {
+    /// Creates a new model with the lines of `text`, all having the default style.
+    pub fn new(text: impl Into<SharedString>) -> Self {
+        let text = text.into();
+        let lines = line_ranges(&text, 0).collect::<Vec<_>>();
+        let styles = alloc::vec![S::default(); lines.len()];
+        Self {
+            text: RefCell::new(text),
+            lines: RefCell::new(lines),
+            styles: RefCell::new(styles),
+            notify: Default::default(),
+        }
+    }
+
+    /// Replaces the text of the model. All lines are reset to the default style.
+    pub fn set_text(&self, text: impl Into<SharedString>) {
+        let text = text.into();
+        let lines = line_ranges(&text, 0).collect::<Vec<_>>();
+        *self.styles.borrow_mut() = alloc::vec![S::default(); lines.len()];
+        *self.lines.borrow_mut() = lines;
+        *self.text.borrow_mut() = text;
+        self.notify.reset();
+    }
+
+    /// Returns the complete text of the model.
+    pub fn text(&self) -> SharedString {
+        self.text.borrow().clone()
+    }
+
+    /// Appends the lines of `text` at the end of the model, with the default style.
+    ///
+    /// The new text always starts on a new line. This is useful for views that follow
+    /// a growing log, as only the added rows are reported to the view. The text is appended in
+    /// place, so appending many times only costs the size of the appended text, except once after
+    /// [`Self::text()`] was called while the returned string is alive.
+    pub fn append_lines(&self, text: &str) {
+        let mut current = self.text.borrow_mut();
+        let was_empty = current.is_empty();
+        if !was_empty {
+            current.push_str("\n");
+        }
+        let start = current.len();
+        current.push_str(text);
+
+        let mut lines = self.lines.borrow_mut();
+        let old_count = if was_empty { 0 } else { lines.len() };
+        lines.truncate(old_count);
+        lines.extend(line_ranges(&current[start..], start));
+        let count = lines.len() - old_count;
+        self.styles.borrow_mut().resize(lines.len(), S::default());
+        drop(lines);
+        drop(current);
+        if old_count == 0 {
+            self.notify.reset();
+        } else {
+            self.notify.row_added(old_count, count);
+        }
+    }
+
+    /// Returns the style of the given line, or None if the line is out of bounds.
+    pub fn line_style(&self, line: usize) -> Option<S> {
+        self.styles.borrow().get(line).cloned()
+    }
+
+    /// Sets the style of a single line. Does nothing if the line is out of bounds.
+    pub fn set_line_style(&self, line: usize, style: S) {
+        if let Some(s) = self.styles.borrow_mut().get_mut(line) {
+            *s = style;
+        } else {
+            return;
+        }
+        self.notify.row_changed(line);
+    }
+
+    /// Sets the same style for every line in `range`. The range is clamped to the number of lines.
+    pub fn set_lines_style(&self, range: core::ops::Range<usize>, style: S) {
+        let mut styles = self.styles.borrow_mut();
+        let range = range.start.min(styles.len())..range.end.min(styles.len());
+        for s in &mut styles[range.clone()] {
+            *s = style.clone();
+        }
+        drop(styles);
+        for line in range {
+            self.notify.row_changed(line);
+        }
+    }
+
+    /// Resets all lines to the default style.
+    pub fn clear_styles(&self) {
+        let mut styles = self.styles.borrow_mut();
+        let count = styles.len();
+        styles.clear();
+        styles.resize(count, S::default());
+        drop(styles);
+        self.notify.reset();
+    }
+}
+
+/// Returns the byte ranges of the lines in `text`, offset by `offset`.
+/// A trailing `\r` is considered part of the line terminator.
+fn line_ranges(text: &str, offset: usize) -> impl Iterator<Item = core::ops::Range<usize>> + '_ {
+    let mut start = 0;
+    text.split('\n').map(move |line| {
+        let line_start = start;
+        start += line.len() + 1;
+        let len = line.strip_suffix('\r').unwrap_or(line).len();
+        offset + line_start..offset + line_start + len
+    })
+}
+
+impl<S: Clone + Default + 'static> Model for TextLinesModel<S> {
+    type Data = (SharedString, S);
+
+    fn row_count(&self) -> usize {
+        self.lines.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let range = self.lines.borrow().get(row)?.clone();
+        let style = self.styles.borrow().get(row)?.clone();
+        Some((SharedString::from(&self.text.borrow()[range]), style))
+    }
+
+    /// Replaces the text and the style of a line. If the text contains line terminators, the line
+    /// is split into several lines, which all get the style.
+    fn set_row_data(&self, row: usize, (line, style): Self::Data) {
+        if row >= self.row_count() {
+            return;
+        }
+        let range = self.lines.borrow()[row].clone();
+        let mut added = 0;
+        if self.text.borrow()[range.clone()] != *line {
+            let mut text = self.text.borrow_mut();
+            let new_text = [&text[..range.start], line.as_str(), &text[range.end..]].concat();
+            let delta = line.len() as isize - range.len() as isize;
+            *text = new_text.into();
+            let mut lines = self.lines.borrow_mut();
+            let new_lines = line_ranges(&line, range.start).collect::<Vec<_>>();
+            added = new_lines.len() - 1;
+            lines.splice(row..row + 1, new_lines);
+            for l in &mut lines[row + 1 + added..] {
+                l.start = l.start.wrapping_add_signed(delta);
+                l.end = l.end.wrapping_add_signed(delta);
+            }
+        }
+        self.styles.borrow_mut().splice(row..row + 1, core::iter::repeat(style).take(added + 1));
+        self.notify.row_changed(row);
+        if added > 0 {
+            self.notify.row_added(row + 1, added);
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str, style: u32) -> (SharedString, u32) {
+        (text.into(), style)
+    }
+
+    #[test]
+    fn text_lines_model() {
+        let model = Rc::new(TextLinesModel::<u32>::new("a\r\nbb\n\nccc"));
+        assert_eq!(
+            model.iter().collect::<Vec<_>>(),
+            [line("a", 0), line("bb", 0), line("", 0), line("ccc", 0)]
+        );
+
+        model.set_line_style(1, 5);
+        model.set_lines_style(2..10, 3);
+        assert_eq!(model.iter().map(|(_, s)| s).collect::<Vec<_>>(), [0, 5, 3, 3]);
+
+        model.set_row_data(1, line("hello", 1));
+        assert_eq!(model.text(), "a\r\nhello\n\nccc");
+        assert_eq!(model.row_data(3), Some(line("ccc", 3)));
+
+        model.append_lines("d\ne");
+        assert_eq!(model.row_count(), 6);
+        assert_eq!(model.row_data(5), Some(line("e", 0)));
+
+        model.clear_styles();
+        assert_eq!(model.line_style(1), Some(0));
+
+        model.set_text("");
+        assert_eq!(model.row_count(), 1);
+        model.append_lines("x");
+        assert_eq!(model.iter().collect::<Vec<_>>(), [line("x", 0)]);
+    }
+
+    #[test]
+    fn text_lines_model_append_in_place() {
+        let model = TextLinesModel::<u32>::default();
+        model.append_lines("first");
+        let text_ptr = model.text.borrow().as_ptr();
+        for i in 0..1000 {
+            model.append_lines(&i.to_string());
+        }
+        assert_eq!(model.row_count(), 1001);
+        assert_eq!(model.row_data(1000), Some(line("999", 0)));
+        assert!(model.text().starts_with("first\n0\n1\n"));
+        // The text grows in place, it's only reallocated when it runs out of capacity
+        let mut reallocations = 0;
+        let mut previous_ptr = text_ptr;
+        for i in 0..1000 {
+            model.append_lines(&i.to_string());
+            let ptr = model.text.borrow().as_ptr();
+            if ptr != previous_ptr {
+                reallocations += 1;
+                previous_ptr = ptr;
+            }
+        }
+        assert!(reallocations < 10, "{reallocations}");
+    }
+
+    #[test]
+    fn text_lines_model_set_row_data_with_newlines() {
+        let model = TextLinesModel::<u32>::new("a\nb\nc");
+        model.set_line_style(2, 7);
+        model.set_row_data(1, line("x\r\ny\nz", 4));
+        assert_eq!(model.text(), "a\nx\r\ny\nz\nc");
+        assert_eq!(
+            model.iter().collect::<Vec<_>>(),
+            [line("a", 0), line("x", 4), line("y", 4), line("z", 4), line("c", 7)]
+        );
+
+        // The lines after the split ones are still found
+        model.set_row_data(4, line("end", 1));
+        assert_eq!(model.text(), "a\nx\r\ny\nz\nend");
+        model.set_row_data(2, line("", 2));
+        assert_eq!(
+            model.iter().collect::<Vec<_>>(),
+            [line("a", 0), line("x", 4), line("", 2), line("z", 4), line("end", 1)]
+        );
+    }
+}
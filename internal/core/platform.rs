@@ -122,6 +122,39 @@ fn clipboard_text(&self, _clipboard: Clipboard) -> Option<String> {
         None
     }
 
+    /// Sends the given image into the system clipboard.
+    ///
+    /// If the platform doesn't support images or the specified clipboard, this function should do nothing
+    fn set_clipboard_image(
+        &self,
+        _image: &crate::graphics::SharedPixelBuffer<crate::graphics::Rgba8Pixel>,
+        _clipboard: Clipboard,
+    ) {
+    }
+
+    /// Returns a copy of the image stored in the system clipboard, if any.
+    ///
+    /// If the platform doesn't support images or the specified clipboard, the function should return None
+    fn clipboard_image(
+        &self,
+        _clipboard: Clipboard,
+    ) -> Option<crate::graphics::SharedPixelBuffer<crate::graphics::Rgba8Pixel>> {
+        None
+    }
+
+    /// Sends the given HTML into the system clipboard, with `alt_text` for the applications that
+    /// only accept plain text.
+    ///
+    /// If the platform doesn't support HTML or the specified clipboard, this function should do nothing
+    fn set_clipboard_html(&self, _html: &str, _alt_text: &str, _clipboard: Clipboard) {}
+
+    /// Returns a copy of the HTML stored in the system clipboard, if any.
+    ///
+    /// If the platform doesn't support HTML or the specified clipboard, the function should return None
+    fn clipboard_html(&self, _clipboard: Clipboard) -> Option<String> {
+        None
+    }
+
     /// This function is called when debug() is used in .slint files. The implementation
     /// should direct the output to some developer visible terminal. The default implementation
     /// uses stderr if available, or `console.log` when targeting wasm.
@@ -130,7 +163,7 @@ fn debug_log(&self, _arguments: core::fmt::Arguments) {
     }
 }
 
-/// The clip board, used in [`Platform::clipboard_text`] and [Platform::set_clipboard_text`]
+/// The clip board, used in [`Platform::clipboard_text`] and [`Platform::set_clipboard_text`]
 #[repr(u8)]
 #[non_exhaustive]
 #[derive(PartialEq, Clone, Default)]
@@ -253,6 +286,19 @@ pub fn update_timers_and_animations() {
     crate::properties::ChangeTracker::run_change_handlers();
 }
 
+/// Invokes the callbacks registered with [`invoke_when_idle()`](crate::timers::invoke_when_idle), in the
+/// order they were registered, for at most 50 milliseconds or until the next timer is activated. At
+/// least one pending callback is invoked. Returns true if callbacks are still pending.
+///
+/// Call this in your own event loop implementation before going to sleep, when there is no event to
+/// process and no window to render or animate. Don't go to sleep if it returns true.
+pub fn run_idle_callbacks() -> bool {
+    const MAX_IDLE_PERIOD: core::time::Duration = core::time::Duration::from_millis(50);
+    let budget = duration_until_next_timer_update()
+        .map_or(MAX_IDLE_PERIOD, |next_timer| next_timer.min(MAX_IDLE_PERIOD));
+    crate::timers::run_idle_callbacks(budget)
+}
+
 /// Returns the duration before the next timer is expected to be activated. This is the
 /// largest amount of time that you can wait before calling [`update_timers_and_animations()`].
 ///
@@ -276,6 +322,8 @@ pub fn duration_until_next_timer_update() -> Option<core::time::Duration> {
 // reexport key enum to the public api
 pub use crate::input::key_codes::Key;
 pub use crate::input::PointerEventButton;
+pub use crate::items::PointerScrollDevice;
+pub use crate::items::PointerToolType;
 
 /// A event that describes user input or windowing system events.
 ///
@@ -315,6 +363,63 @@ pub enum WindowEvent {
     },
     /// The pointer exited the window.
     PointerExited,
+    /// Describes the device that produces the next scroll event.
+    ///
+    /// Backends that know whether the scroll comes from a mouse wheel or from a touchpad dispatch this
+    /// event right before the [`WindowEvent::PointerScrolled`] event. The values are reported in the
+    /// `PointerScrollEvent` of the `TouchArea`, and the `Flickable` animates scrolling with a wheel while
+    /// it follows the touchpad directly. They only apply to that next scroll event.
+    ScrollDetailsChanged {
+        /// The kind of device.
+        device: PointerScrollDevice,
+        /// The number of lines to scroll in the horizontal direction, such as the number of notches the
+        /// wheel was turned. Use the pixel delta divided by 60 for touchpads.
+        line_delta_x: f32,
+        /// The number of lines to scroll in the vertical direction.
+        line_delta_y: f32,
+    },
+    /// Describes the pen or the finger that produces the next pointer event.
+    ///
+    /// Backends that know more about the device than its position dispatch this event right before
+    /// the [`WindowEvent::PointerPressed`], [`WindowEvent::PointerMoved`], or [`WindowEvent::PointerReleased`]
+    /// event of a pen or a touch. The values are reported in the `PointerEvent` of the `TouchArea` and
+    /// only apply to that next pointer event; pointer events without them come from a mouse.
+    PointerDetailsChanged {
+        /// The kind of device.
+        tool_type: PointerToolType,
+        /// The pressure, between 0 and 1.
+        pressure: f32,
+        /// The tilt of a pen in the plane of the x axis, in degrees between -90 and 90.
+        tilt_x: f32,
+        /// The tilt of a pen in the plane of the y axis, in degrees between -90 and 90.
+        tilt_y: f32,
+        /// The clockwise rotation of a pen around its axis, in degrees between 0 and 359.
+        twist: f32,
+    },
+    /// A finger touched the window.
+    ///
+    /// Backends that support several fingers dispatch this and the other touch events instead of
+    /// pointer events for the touches. The first finger is delivered to the elements like a mouse with
+    /// the left button pressed, and a second finger starts a gesture in the `PinchGestureHandler` under
+    /// the first one. Dispatch [`WindowEvent::PointerDetailsChanged`] before the touch event to report
+    /// the pressure of the finger.
+    TouchPressed {
+        /// Identifies the finger until it's released.
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger that touches the window moved.
+    TouchMoved {
+        /// The id of the finger in the [`WindowEvent::TouchPressed`] event.
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger was lifted from the window, or the touch was cancelled.
+    TouchReleased {
+        /// The id of the finger in the [`WindowEvent::TouchPressed`] event.
+        id: u64,
+        position: LogicalPosition,
+    },
     /// A key was pressed.
     KeyPressed {
         /// The unicode representation of the key pressed.
@@ -348,6 +453,18 @@ pub enum WindowEvent {
         /// ```
         text: SharedString,
     },
+    /// Describes the physical key of the next key event.
+    ///
+    /// Backends that know which physical key produced a key event dispatch this event right before the
+    /// [`WindowEvent::KeyPressed`], [`WindowEvent::KeyPressRepeated`], or [`WindowEvent::KeyReleased`] event.
+    /// The values are reported in the `KeyEvent` of the `FocusScope` and only apply to that next key event.
+    KeyDetailsChanged {
+        /// The name of the physical key, as the `code` of the W3C UI Events, like `KeyA` or `ArrowLeft`.
+        /// Use an empty string if it's unknown.
+        physical_key: SharedString,
+        /// The scan code of the physical key, specific to the platform, or 0 if it's unknown.
+        scancode: u32,
+    },
     /// The window's scale factor has changed. This can happen for example when the display's resolution
     /// changes, the user selects a new scale factor in the system settings, or the window is moved to a
     /// different screen.
@@ -378,6 +495,82 @@ pub enum WindowEvent {
     /// The backend should dispatch this event with true when the window gains focus
     /// and false when the window loses focus.
     WindowActiveChanged(bool),
+
+    /// The window became completely hidden by other windows or off screen, or visible again.
+    ///
+    /// The backend should dispatch this event with true when nothing of the window can be seen,
+    /// for example to let the application pause animations, and false when it becomes visible.
+    WindowOccludedChanged(bool),
+
+    /// The area of the window covered by an on-screen keyboard changed.
+    ///
+    /// Backends for platforms with a virtual keyboard should dispatch this event when the keyboard
+    /// is shown, resized, or hidden, with an empty size when it is hidden. While the keyboard is shown,
+    /// the `Flickable`s or `ScrollView`s containing the focused item are scrolled so that the item
+    /// stays visible above the keyboard.
+    VirtualKeyboardChanged {
+        /// The position of the top-left corner of the keyboard, in window coordinates.
+        position: LogicalPosition,
+        /// The size of the keyboard.
+        size: LogicalSize,
+    },
+
+    /// A rotary encoder, such as the knob of an appliance or the crown of a watch, was rotated by
+    /// `delta` steps. Positive values move the keyboard focus to the next item, negative values to
+    /// the previous item, and the `Flickable`s containing the newly focused item are scrolled to
+    /// show it. Dispatch a key event for `Key::Return` when the encoder is pressed.
+    RotaryEncoderRotated {
+        /// The number of steps, positive for clockwise rotation.
+        delta: i32,
+    },
+
+    /// The default font or the text scaling factor of the system settings changed.
+    ///
+    /// Backends should dispatch this event after creating the window, and again whenever the user
+    /// changes these settings, so that the texts of running applications are updated. The family
+    /// is used for the elements that don't set a `font-family`, and the font sizes are multiplied
+    /// by `text_scale_factor`.
+    SystemFontChanged {
+        /// The family of the default font of the system, or an empty string to let the renderer
+        /// choose.
+        family: SharedString,
+        /// The factor by which the user asked to enlarge texts, for accessibility. Use `1.0` if
+        /// the system has no such setting.
+        text_scale_factor: f32,
+    },
+
+    /// Data dragged from another application, such as files from the file manager, entered the
+    /// window or moved within it.
+    ///
+    /// The data is passed to the callback set with
+    /// [`Window::on_drag_and_drop()`](`crate::api::Window::on_drag_and_drop()`), and offered to the
+    /// `DropArea` elements under `position`. Dispatch [`WindowEvent::DragExited`] when the drag
+    /// leaves the window or is cancelled.
+    DragMoved {
+        position: LogicalPosition,
+        /// The MIME type of the dragged data, `text/uri-list` for files.
+        mime_type: SharedString,
+        /// The dragged data. For `text/uri-list`, the `file://` URIs of the files, each followed
+        /// by `\r\n`.
+        data: SharedString,
+    },
+    /// The dragged data was dropped at `position`. This ends the drag, so it must not be followed
+    /// by [`WindowEvent::DragExited`].
+    Dropped {
+        position: LogicalPosition,
+        /// The MIME type of the dropped data, `text/uri-list` for files.
+        mime_type: SharedString,
+        /// The dropped data, in the same format as for [`WindowEvent::DragMoved`].
+        data: SharedString,
+    },
+    /// The drag left the window or was cancelled.
+    DragExited,
+
+    /// The user switched to a different keyboard layout.
+    ///
+    /// This invokes the callback set with [`Window::on_keyboard_layout_changed()`](`crate::api::Window::on_keyboard_layout_changed()`),
+    /// for example to update labels that show which keys to press.
+    KeyboardLayoutChanged,
 }
 
 impl WindowEvent {
@@ -3,6 +3,7 @@
 
 use super::*;
 use crate::{
+    animations::{AnimationControl, AnimationHandle},
     items::{AnimationDirection, PropertyAnimation},
     lengths::LogicalLength,
 };
@@ -21,17 +22,70 @@ pub(super) struct PropertyValueAnimationData<T> {
     details: PropertyAnimation,
     start_time: crate::animations::Instant,
     state: AnimationState,
+    /// Only created when a handle to the animation is requested, or all animations are tracked
+    control: Option<Rc<AnimationControl>>,
 }
 
 impl<T: InterpolatedPropertyValue + Clone> PropertyValueAnimationData<T> {
     pub fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
         let start_time = crate::animations::current_tick();
+        let control = crate::animations::tracking_animations()
+            .then(|| Rc::new(AnimationControl::new(start_time)));
 
-        Self { from_value, to_value, details, start_time, state: AnimationState::Delaying }
+        Self { from_value, to_value, details, start_time, state: AnimationState::Delaying, control }
     }
 
+    /// Starts the animation at the given time
+    pub fn start(&mut self, start_time: crate::animations::Instant) {
+        self.state = AnimationState::Delaying;
+        self.start_time = start_time;
+        if let Some(control) = &self.control {
+            control.start(start_time);
+            control.update(start_time, false, self.total_duration());
+        }
+    }
+
+    /// Returns the interpolated value, and whether the animation is finished. A paused animation is never
+    /// finished, so that it can still be resumed or sought.
     pub fn compute_interpolated_value(&mut self) -> (T, bool) {
-        let new_tick = crate::animations::current_tick();
+        let driver_tick = crate::animations::current_tick();
+        let Some(control) = self.control.clone() else {
+            return self.compute_interpolated_value_at(driver_tick);
+        };
+        let (tick, restart) = control.animation_tick(driver_tick);
+        if restart {
+            self.state = AnimationState::Delaying;
+            self.start_time = control.start_time();
+        }
+        let (value, finished) = self.compute_interpolated_value_at(tick);
+        control.update(tick, finished, self.total_duration());
+        (value, finished && !control.is_paused())
+    }
+
+    /// Returns true if the animation is paused, so it doesn't need new frames
+    pub fn is_paused(&self) -> bool {
+        self.control.as_ref().is_some_and(|control| control.is_paused())
+    }
+
+    /// Returns a handle to the animation. Call it before [`Self::start()`].
+    pub fn handle(&mut self) -> AnimationHandle {
+        let start_time = self.start_time;
+        let control =
+            self.control.get_or_insert_with(|| Rc::new(AnimationControl::new(start_time)));
+        AnimationHandle::new(control.clone())
+    }
+
+    /// The duration of the delay and all the iterations, or None if the animation repeats forever
+    fn total_duration(&self) -> Option<core::time::Duration> {
+        let iteration_count = self.details.iteration_count;
+        if iteration_count < 0. {
+            return None;
+        }
+        let duration = self.duration().max(0) as f64 * iteration_count as f64;
+        Some(core::time::Duration::from_millis(self.details.delay.max(0) as u64 + duration as u64))
+    }
+
+    fn compute_interpolated_value_at(&mut self, new_tick: crate::animations::Instant) -> (T, bool) {
         let mut time_progress = new_tick.duration_since(self.start_time).as_millis() as u64;
         let reversed = |iteration: u64| -> bool {
             match self.details.direction {
@@ -46,7 +100,7 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
             AnimationState::Delaying => {
                 if self.details.delay <= 0 {
                     self.state = AnimationState::Animating { current_iteration: 0 };
-                    return self.compute_interpolated_value();
+                    return self.compute_interpolated_value_at(new_tick);
                 }
 
                 let delay = self.details.delay as u64;
@@ -63,16 +117,17 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
 
                     // Decide on next state:
                     self.state = AnimationState::Animating { current_iteration: 0 };
-                    self.compute_interpolated_value()
+                    self.compute_interpolated_value_at(new_tick)
                 }
             }
             AnimationState::Animating { mut current_iteration } => {
-                if self.details.duration <= 0 || self.details.iteration_count == 0. {
+                let duration = self.duration();
+                if duration <= 0 || self.details.iteration_count == 0. {
                     self.state = AnimationState::Done { interation_count: 0 };
-                    return self.compute_interpolated_value();
+                    return self.compute_interpolated_value_at(new_tick);
                 }
 
-                let duration = self.details.duration as u64;
+                let duration = duration as u64;
                 if time_progress >= duration {
                     // wrap around
                     current_iteration += time_progress / duration;
@@ -87,8 +142,7 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                     self.state = AnimationState::Animating { current_iteration };
 
                     let progress = {
-                        let progress =
-                            (time_progress as f32 / self.details.duration as f32).clamp(0., 1.);
+                        let progress = (time_progress as f32 / duration as f32).clamp(0., 1.);
                         if reversed(current_iteration) {
                             1. - progress
                         } else {
@@ -102,7 +156,7 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                 } else {
                     self.state =
                         AnimationState::Done { interation_count: current_iteration.max(1) - 1 };
-                    self.compute_interpolated_value()
+                    self.compute_interpolated_value_at(new_tick)
                 }
             }
             AnimationState::Done { interation_count } => {
@@ -115,9 +169,20 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
         }
     }
 
+    /// The duration of one iteration, in milliseconds. Springs without a duration last until they settle.
+    fn duration(&self) -> i64 {
+        match self.details.easing {
+            crate::animations::EasingCurve::Spring([stiffness, damping, mass])
+                if self.details.duration <= 0 =>
+            {
+                crate::animations::spring_duration(stiffness, damping, mass).as_millis() as i64
+            }
+            _ => self.details.duration as i64,
+        }
+    }
+
     fn reset(&mut self) {
-        self.state = AnimationState::Delaying;
-        self.start_time = crate::animations::current_tick();
+        self.start(crate::animations::current_tick());
     }
 }
 
@@ -148,11 +213,12 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
         );
         match self.state.get() {
             AnimatedBindingState::Animating => {
-                let (val, finished) = self.animation_data.borrow_mut().compute_interpolated_value();
+                let mut animation_data = self.animation_data.borrow_mut();
+                let (val, finished) = animation_data.compute_interpolated_value();
                 *(value as *mut T) = val;
                 if finished {
                     self.state.set(AnimatedBindingState::NotAnimating)
-                } else {
+                } else if !animation_data.is_paused() {
                     crate::animations::CURRENT_ANIMATION_DRIVER
                         .with(|driver| driver.set_has_active_animations());
                 }
@@ -168,14 +234,14 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
                 animation_data.from_value = value.clone();
                 self.original_binding.update((&mut animation_data.to_value) as *mut T as *mut ());
                 if let Some((details, start_time)) = (self.compute_animation_details)() {
-                    animation_data.start_time = start_time;
+                    animation_data.start(start_time);
                     animation_data.details = details;
                 }
                 let (val, finished) = animation_data.compute_interpolated_value();
                 *value = val;
                 if finished {
                     self.state.set(AnimatedBindingState::NotAnimating)
-                } else {
+                } else if !animation_data.is_paused() {
                     crate::animations::CURRENT_ANIMATION_DRIVER
                         .with(|driver| driver.set_has_active_animations());
                 }
@@ -246,22 +312,44 @@ impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
     /// be marked as dirty.
     pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
         // FIXME if the current value is a dirty binding, we must run it, but we do not have the context
-        let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
+        let mut data = properties_animations::PropertyValueAnimationData::new(
             self.get_internal(),
             value,
             animation_data,
-        ));
+        );
+        data.start(crate::animations::current_tick());
+        self.set_animation_binding(data);
+    }
+
+    /// Same as [`Self::set_animated_value()`], but returns a handle to pause, resume, or seek the animation.
+    pub fn animate_value(&self, value: T, animation_data: PropertyAnimation) -> AnimationHandle {
+        let mut data = properties_animations::PropertyValueAnimationData::new(
+            self.get_internal(),
+            value,
+            animation_data,
+        );
+        let handle = data.handle();
+        data.start(crate::animations::current_tick());
+        self.set_animation_binding(data);
+        handle
+    }
+
+    fn set_animation_binding(&self, data: properties_animations::PropertyValueAnimationData<T>) {
+        let d = RefCell::new(data);
         // Safety: the BindingCallable will cast its argument to T
         unsafe {
             self.handle.set_binding(
                 move |val: *mut ()| {
-                    let (value, finished) = d.borrow_mut().compute_interpolated_value();
+                    let mut d = d.borrow_mut();
+                    let (value, finished) = d.compute_interpolated_value();
                     *(val as *mut T) = value;
                     if finished {
                         BindingResult::RemoveBinding
                     } else {
-                        crate::animations::CURRENT_ANIMATION_DRIVER
-                            .with(|driver| driver.set_has_active_animations());
+                        if !d.is_paused() {
+                            crate::animations::CURRENT_ANIMATION_DRIVER
+                                .with(|driver| driver.set_has_active_animations());
+                        }
                         BindingResult::KeepBinding
                     }
                 },
@@ -432,6 +520,39 @@ fn properties_test_animation_negative_delay_triggered_by_set() {
         compo.width.handle.access(|binding| assert!(binding.is_none()));
     }
 
+    #[test]
+    fn properties_test_spring_animation_without_duration() {
+        let compo = Component::new_test_component();
+
+        // A critically damped spring, that doesn't overshoot
+        let easing = crate::animations::EasingCurve::Spring([100., 20., 1.]);
+        let animation_details = PropertyAnimation { easing, ..PropertyAnimation::default() };
+        let spring_duration = crate::animations::spring_duration(100., 20., 1.);
+        assert_eq!(spring_duration.as_millis(), 690);
+
+        compo.width.set(100);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(200, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + spring_duration / 2));
+        let width = get_prop_value(&compo.width);
+        assert!(width > 150 && width < 200, "{width}");
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + spring_duration));
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        // An underdamped spring overshoots
+        let bouncy = crate::animations::EasingCurve::Spring([100., 5., 1.]);
+        assert!((0..100)
+            .map(|i| crate::animations::easing_curve(&bouncy, i as f32 / 100.))
+            .any(|v| v > 1.1));
+        assert_eq!(crate::animations::easing_curve(&bouncy, 0.), 0.);
+        assert_eq!(crate::animations::easing_curve(&bouncy, 1.), 1.);
+    }
+
     #[test]
     fn properties_test_animation_triggered_by_set() {
         let compo = Component::new_test_component();
@@ -472,6 +593,77 @@ fn properties_test_animation_triggered_by_set() {
         compo.width.handle.access(|binding| assert!(binding.is_none()));
     }
 
+    #[test]
+    fn properties_test_animation_handle() {
+        use crate::animations::{AnimationHandle, CURRENT_ANIMATION_DRIVER};
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+        let start_time = crate::animations::current_tick();
+        let handle = compo.width.animate_value(200, animation_details);
+        assert_eq!(AnimationHandle::running_animations().len(), 1);
+        assert_eq!(handle.total_duration(), Some(DURATION));
+
+        CURRENT_ANIMATION_DRIVER.with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 125);
+        assert_eq!(handle.elapsed(), DURATION / 4);
+        assert_eq!(handle.progress(), 0.25);
+
+        // A paused animation keeps its value
+        handle.pause();
+        assert!(handle.is_paused());
+        CURRENT_ANIMATION_DRIVER.with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 125);
+
+        handle.seek(DURATION * 3 / 4);
+        assert_eq!(get_prop_value(&compo.width), 175);
+        CURRENT_ANIMATION_DRIVER.with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 175);
+
+        // Resuming continues from where the animation was paused
+        handle.resume();
+        assert!(!handle.is_paused());
+        CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION / 8));
+        assert_eq!(get_prop_value(&compo.width), 188);
+
+        // Seek backward while running
+        handle.seek(DURATION / 2);
+        assert_eq!(get_prop_value(&compo.width), 150);
+        assert!(!handle.is_finished());
+
+        CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 2 + DURATION / 8));
+        assert_eq!(get_prop_value(&compo.width), 200);
+        assert!(handle.is_finished());
+        assert_eq!(handle.progress(), 1.);
+        assert!(AnimationHandle::running_animations().is_empty());
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn properties_test_animation_tracking() {
+        use crate::animations::AnimationHandle;
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            ..PropertyAnimation::default()
+        };
+
+        // Without a handle, the animations are only tracked once they were asked for
+        compo.width.set_animated_value(200, animation_details.clone());
+        compo.width.handle.access(|binding| assert!(binding.is_some()));
+        assert!(AnimationHandle::running_animations().is_empty());
+        compo.width.set_animated_value(100, animation_details);
+        assert_eq!(AnimationHandle::running_animations().len(), 1);
+    }
+
     #[test]
     fn properties_test_delayed_animation_triggered_by_set() {
         let compo = Component::new_test_component();
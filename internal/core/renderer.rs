@@ -4,13 +4,15 @@
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
 use alloc::rc::Rc;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use core::pin::Pin;
 
 use crate::api::PlatformError;
 use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
 use crate::item_tree::ItemTreeRef;
 use crate::items::TextWrap;
-use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalSize, ScaleFactor};
 use crate::window::WindowAdapter;
 
 /// This trait represents a Renderer that can render a slint scene.
@@ -25,6 +27,9 @@ impl<T: RendererSealed> Renderer for T {}
 /// Implementation details behind [`Renderer`], but since this
 /// trait is not exported in the public API, it is not possible for the
 /// users to re-implement these functions.
+///
+/// The exception is the `unstable-renderer-api` feature of the `slint` crate, which exports
+/// this trait, without semver guarantees, for renderers implemented outside of Slint.
 pub trait RendererSealed {
     /// Returns the size of the given text in logical pixels.
     /// When set, `max_width` means that one need to wrap the text, so it does not go further than that,
@@ -45,6 +50,21 @@ fn font_metrics(
         scale_factor: ScaleFactor,
     ) -> crate::items::FontMetrics;
 
+    /// Returns the distance between the baselines of two consecutive lines of text laid out with
+    /// the given font, which includes the line gap of the font.
+    fn line_spacing(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> LogicalLength {
+        use crate::lengths::SizeLengths;
+        let height = |text| {
+            self.text_size(font_request.clone(), text, None, scale_factor, TextWrap::NoWrap)
+                .height_length()
+        };
+        height("x\nx") - height("x")
+    }
+
     /// Returns the (UTF-8) byte offset in the text property that refers to the character that contributed to
     /// the glyph cluster that's visually nearest to the given coordinate. This is used for hit-testing,
     /// for example when receiving a mouse click into a text field. Then this function returns the "cursor"
@@ -58,15 +78,16 @@ fn text_input_byte_offset_for_position(
     ) -> usize;
 
     /// That's the opposite of [`Self::text_input_byte_offset_for_position`]
-    /// It takes a (UTF-8) byte offset in the text property, and returns a Rectangle
-    /// left to the char. It is one logical pixel wide and ends at the baseline.
-    fn text_input_cursor_rect_for_byte_offset(
+    /// It takes a (UTF-8) byte offset in the text property, and returns the rectangle of the
+    /// cursor left to the char, as high as the line, together with the baseline, the index
+    /// and the box of that line.
+    fn text_input_cursor_metrics_for_byte_offset(
         &self,
         text_input: Pin<&crate::items::TextInput>,
         byte_offset: usize,
         font_request: crate::graphics::FontRequest,
         scale_factor: ScaleFactor,
-    ) -> LogicalRect;
+    ) -> crate::items::TextCursorMetrics;
 
     /// Clear the caches for the items that are being removed
     fn free_graphics_resources(
@@ -104,6 +125,36 @@ fn register_font_from_path(
         Err("This renderer does not support registering custom fonts.".into())
     }
 
+    #[cfg(feature = "std")]
+    /// This function removes the fonts of the given family that were registered with
+    /// [`Self::register_font_from_memory`] or [`Self::register_font_from_path`], and discards
+    /// the glyphs and shaped texts cached for them.
+    fn unregister_font(&self, _family: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Err("This renderer does not support unregistering custom fonts.".into())
+    }
+
+    #[cfg(feature = "std")]
+    /// This function registers the font in `data` like [`Self::register_font_from_memory`], and
+    /// then removes the other fonts registered for `family`, like [`Self::unregister_font`]. If the
+    /// font can't be registered, the fonts of `family` are kept.
+    fn replace_font_from_memory(
+        &self,
+        _family: &str,
+        _data: &'static [u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err("This renderer does not support replacing custom fonts.".into())
+    }
+
+    #[cfg(feature = "std")]
+    /// Same as [`Self::replace_font_from_memory`], with the font at `path`.
+    fn replace_font_from_path(
+        &self,
+        _family: &str,
+        _path: &std::path::Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err("This renderer does not support replacing custom fonts.".into())
+    }
+
     fn register_bitmap_font(&self, _font_data: &'static crate::graphics::BitmapFont) {
         crate::debug_log!("Internal error: The current renderer cannot load fonts build with the `EmbedForSoftwareRenderer` option. Please use the software Renderer, or disable that option when building your slint files");
     }
@@ -125,6 +176,65 @@ fn resize(&self, _size: crate::api::PhysicalSize) -> Result<(), PlatformError> {
         Ok(())
     }
 
+    /// Re-implement this function to return true if the renderer draws the texts with
+    /// `writing-mode: vertical-rl` in columns. Otherwise they're laid out and sized horizontally.
+    fn supports_vertical_text(&self) -> bool {
+        false
+    }
+
+    /// Re-implement this function to support Window::text_outline(), i.e. return the outline of
+    /// the given text, laid out with the font matching the request, in logical coordinates
+    /// relative to the top-left corner of the text.
+    fn text_outline(
+        &self,
+        _text: &str,
+        _font_request: crate::graphics::FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> Result<crate::api::TextOutline, PlatformError> {
+        Err("This renderer does not support converting text to paths".into())
+    }
+
+    /// Re-implement this function to support Window::measure_text(), i.e. return the byte ranges
+    /// of the lines of the text, without trailing whitespace, as the renderer breaks them when
+    /// wrapping at `max_width`.
+    fn text_line_ranges(
+        &self,
+        _font_request: crate::graphics::FontRequest,
+        _text: &str,
+        _max_width: Option<LogicalLength>,
+        _scale_factor: ScaleFactor,
+        _text_wrap: TextWrap,
+    ) -> Result<Vec<core::ops::Range<usize>>, PlatformError> {
+        Err("This renderer does not support measuring text".into())
+    }
+
+    /// Re-implement this function to support Window::set_vision_simulation(), i.e. apply a
+    /// [`VisionSimulation`](crate::graphics::VisionSimulation) filter to the rendered content of the
+    /// window, or only to the area covered by `item` if it's set.
+    fn set_vision_simulation(
+        &self,
+        _simulation: crate::graphics::VisionSimulation,
+        _item: Option<crate::item_tree::ItemWeak>,
+    ) -> Result<(), PlatformError> {
+        Err("This renderer does not support vision simulation filters".into())
+    }
+
+    /// Re-implement this function to support Window::set_render_scale(), i.e. render the scene at
+    /// `render_scale` times the resolution of the surface and scale it when presenting.
+    fn set_render_scale(&self, _render_scale: f32) -> Result<(), PlatformError> {
+        Err("This renderer does not support a render scale".into())
+    }
+
+    /// Re-implement this function to support Window::set_gamma_correct_blending(), i.e. blend
+    /// semi-transparent colors in linear light instead of in the sRGB color space.
+    fn set_gamma_correct_blending(&self, enabled: bool) -> Result<(), PlatformError> {
+        if enabled {
+            Err("This renderer does not support gamma-correct blending".into())
+        } else {
+            Ok(())
+        }
+    }
+
     /// Re-implement this function to support Window::take_snapshot(), i.e. return
     /// the contents of the window in an image buffer.
     fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
@@ -0,0 +1,653 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    Support for saving and restoring the settings of the application, such as the geometry of its windows.
+*/
+
+#![warn(missing_docs)]
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::rc::{Rc, Weak};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+
+use crate::api::{PhysicalPosition, PhysicalSize, PlatformError, Window};
+use crate::window::{WindowAdapter, WindowInner};
+use crate::SharedString;
+
+/// The part of a window that must be on a screen for its stored position to be restored, in
+/// physical pixels: enough to grab its title bar and move it.
+const MIN_VISIBLE_SIZE: u32 = 50;
+
+/// The key-value pairs of the settings, sorted by key.
+pub type SettingsMap = BTreeMap<SharedString, SharedString>;
+
+/// A place where [`Settings`] are stored.
+///
+/// Implement this trait to store the settings somewhere else than in the default storage of the platform.
+pub trait SettingsStorage {
+    /// Returns all the stored settings, or an empty map if none were stored yet.
+    fn load(&self) -> Result<SettingsMap, PlatformError>;
+    /// Stores the settings, replacing all the previously stored ones.
+    fn save(&self, settings: &SettingsMap) -> Result<(), PlatformError>;
+}
+
+struct SettingsInner {
+    storage: Box<dyn SettingsStorage>,
+    values: RefCell<SettingsMap>,
+    modified: Cell<bool>,
+}
+
+/// Settings of the application that are kept between runs, as key-value pairs of strings.
+///
+/// The settings are loaded from a [`SettingsStorage`] when created, and written back with [`Self::save()`].
+/// Use [`Self::for_application()`] to store them where the platform usually keeps them: in a file in the
+/// configuration directory of the user, in the registry on Windows, or in the local storage of the browser
+/// on the web.
+///
+/// `Settings` is a handle: clones refer to the same settings.
+///
+/// ## Example
+///
+/// ```no_run
+/// let settings = slint::Settings::for_application("my-app").unwrap();
+/// let count: u32 = settings.get("launch-count").and_then(|v| v.parse().ok()).unwrap_or(0);
+/// settings.set("launch-count", (count + 1).to_string());
+/// settings.save().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Settings(Rc<SettingsInner>);
+
+impl Settings {
+    /// Loads the settings from the given storage.
+    pub fn new(storage: impl SettingsStorage + 'static) -> Result<Self, PlatformError> {
+        let values = storage.load()?;
+        Ok(Self(Rc::new(SettingsInner {
+            storage: Box::new(storage),
+            values: RefCell::new(values),
+            modified: Cell::new(false),
+        })))
+    }
+
+    /// Loads the settings of the application with the given name from the default storage of the platform:
+    ///  - On Windows with the `settings-registry` feature, the `HKEY_CURRENT_USER\Software\<name>` key of the
+    ///    registry, see [`RegistrySettingsStorage`].
+    ///  - On the web, the local storage of the browser, see [`WebSettingsStorage`].
+    ///  - Otherwise, the `<name>/settings.ini` file in the configuration directory of the user, see
+    ///    [`FileSettingsStorage::for_application()`].
+    #[cfg(feature = "std")]
+    pub fn for_application(name: &str) -> Result<Self, PlatformError> {
+        #[cfg(all(feature = "settings-registry", target_family = "windows"))]
+        return Self::new(RegistrySettingsStorage::new(format!("Software\\{name}")));
+        #[cfg(target_arch = "wasm32")]
+        return Self::new(WebSettingsStorage::new(format!("{name}/")));
+        #[cfg(not(any(
+            all(feature = "settings-registry", target_family = "windows"),
+            target_arch = "wasm32"
+        )))]
+        return Self::new(FileSettingsStorage::for_application(name)?);
+    }
+
+    /// Returns the value of the setting with the given key, if any.
+    pub fn get(&self, key: &str) -> Option<SharedString> {
+        self.0.values.borrow().get(key).cloned()
+    }
+
+    /// Sets the value of the setting with the given key. Call [`Self::save()`] to store it.
+    pub fn set(&self, key: &str, value: impl Into<SharedString>) {
+        let value = value.into();
+        let previous = self.0.values.borrow_mut().insert(key.into(), value.clone());
+        if previous.as_ref() != Some(&value) {
+            self.0.modified.set(true);
+        }
+    }
+
+    /// Removes the setting with the given key, and returns its value. Call [`Self::save()`] to store the change.
+    pub fn remove(&self, key: &str) -> Option<SharedString> {
+        let previous = self.0.values.borrow_mut().remove(key);
+        if previous.is_some() {
+            self.0.modified.set(true);
+        }
+        previous
+    }
+
+    /// Returns the keys of all the settings, sorted.
+    pub fn keys(&self) -> Vec<SharedString> {
+        self.0.values.borrow().keys().cloned().collect()
+    }
+
+    /// Writes the settings to their storage, if they were changed since they were loaded or last saved.
+    pub fn save(&self) -> Result<(), PlatformError> {
+        if self.0.modified.get() {
+            self.0.storage.save(&self.0.values.borrow())?;
+            self.0.modified.set(false);
+        }
+        Ok(())
+    }
+
+    /// Restores the position, size, and maximized and fullscreen states of the window from the setting with the
+    /// given key, as stored by [`Self::store_window_geometry()`]. Call this before showing the window.
+    ///
+    /// Returns false if no geometry was stored.
+    pub fn restore_window_geometry(&self, window: &Window, key: &str) -> bool {
+        let Some(geometry) = self.get(key).and_then(|value| WindowGeometry::parse(&value)) else {
+            return false;
+        };
+        // The window may have been on a screen that was disconnected since. Let the window system
+        // place it then.
+        let screens = WindowInner::from_pub(window)
+            .window_adapter()
+            .internal(crate::InternalToken)
+            .and_then(|internal| internal.screen_geometries());
+        if screens.map_or(true, |screens| geometry.is_on_screen(&screens)) {
+            window.set_position(geometry.position);
+        }
+        window.set_size(geometry.size);
+        if geometry.maximized {
+            window.set_maximized(true);
+        }
+        if geometry.fullscreen {
+            window.set_fullscreen(true);
+        }
+        true
+    }
+
+    /// Stores the position, size, and maximized and fullscreen states of the window in the setting with the given
+    /// key. When the window is maximized or fullscreen, the previously stored position and size are kept, so that
+    /// the window gets its normal geometry back when it's restored and then un-maximized.
+    pub fn store_window_geometry(&self, window: &Window, key: &str) {
+        let maximized = window.is_maximized();
+        let fullscreen = window.is_fullscreen();
+        let previous = self.get(key).and_then(|value| WindowGeometry::parse(&value));
+        let (position, size) = match previous {
+            Some(previous) if maximized || fullscreen => (previous.position, previous.size),
+            _ => (window.position(), window.size()),
+        };
+        if size.width == 0 || size.height == 0 {
+            return;
+        }
+        let geometry = WindowGeometry { position, size, maximized, fullscreen };
+        self.set(key, geometry.to_shared_string());
+    }
+}
+
+thread_local!(
+    /// The windows for which `Window::persist_geometry()` was called
+    static PERSISTED_WINDOWS: RefCell<Vec<Weak<dyn WindowAdapter>>> = RefCell::default()
+);
+
+pub(crate) fn register_persisted_window(window_adapter: Weak<dyn WindowAdapter>) {
+    PERSISTED_WINDOWS.with(|windows| {
+        let mut windows = windows.borrow_mut();
+        windows.retain(|window| window.strong_count() > 0);
+        windows.push(window_adapter);
+    });
+}
+
+/// Stores the geometry of the shown windows for which [`Window::persist_geometry()`] was called,
+/// and saves their settings. This is called when the event loop ends, because the windows that
+/// are still shown then are never hidden.
+pub fn store_window_geometries() {
+    let windows = PERSISTED_WINDOWS.with(|windows| windows.borrow().clone());
+    for window_adapter in windows.iter().filter_map(Weak::upgrade) {
+        WindowInner::from_pub(window_adapter.window()).store_geometry();
+    }
+}
+
+/// The geometry of a window, stored as `x,y,width,height` followed by the `maximized` and `fullscreen` flags.
+struct WindowGeometry {
+    position: PhysicalPosition,
+    size: PhysicalSize,
+    maximized: bool,
+    fullscreen: bool,
+}
+
+impl WindowGeometry {
+    /// Returns true if enough of the window is on one of the given screens to be moved by the user.
+    fn is_on_screen(&self, screens: &[(PhysicalPosition, PhysicalSize)]) -> bool {
+        // The length of the overlap of two ranges, negative if they don't overlap
+        let overlap = |start: i32, length: u32, other_start: i32, other_length: u32| {
+            let end = (start as i64 + length as i64).min(other_start as i64 + other_length as i64);
+            end - (start as i64).max(other_start as i64)
+        };
+        let (min_width, min_height) =
+            (self.size.width.min(MIN_VISIBLE_SIZE), self.size.height.min(MIN_VISIBLE_SIZE));
+        screens.iter().any(|(position, size)| {
+            overlap(self.position.x, self.size.width, position.x, size.width) >= min_width as i64
+                && overlap(self.position.y, self.size.height, position.y, size.height)
+                    >= min_height as i64
+        })
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.split(',').map(str::trim);
+        let x = parts.next()?.parse().ok()?;
+        let y = parts.next()?.parse().ok()?;
+        let width = parts.next()?.parse().ok()?;
+        let height = parts.next()?.parse().ok()?;
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let (mut maximized, mut fullscreen) = (false, false);
+        for flag in parts {
+            match flag {
+                "maximized" => maximized = true,
+                "fullscreen" => fullscreen = true,
+                _ => {}
+            }
+        }
+        Some(Self {
+            position: PhysicalPosition::new(x, y),
+            size: PhysicalSize::new(width, height),
+            maximized,
+            fullscreen,
+        })
+    }
+
+    fn to_shared_string(&self) -> SharedString {
+        let mut value = crate::format!(
+            "{},{},{},{}",
+            self.position.x,
+            self.position.y,
+            self.size.width,
+            self.size.height
+        );
+        if self.maximized {
+            value.push_str(",maximized");
+        }
+        if self.fullscreen {
+            value.push_str(",fullscreen");
+        }
+        value
+    }
+}
+
+/// Stores the settings in a text file, one `key=value` pair per line.
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub struct FileSettingsStorage {
+    path: std::path::PathBuf,
+}
+
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+impl FileSettingsStorage {
+    /// Stores the settings in the file with the given path. The file and its directory are created when saving.
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Stores the settings of the application with the given name in the `<name>/settings.ini` file of the
+    /// configuration directory of the user: `~/Library/Application Support` on macOS, `%APPDATA%` on Windows,
+    /// and `$XDG_CONFIG_HOME` or `~/.config` otherwise.
+    pub fn for_application(name: &str) -> Result<Self, PlatformError> {
+        let env_path = |var: &str| {
+            std::env::var_os(var).filter(|v| !v.is_empty()).map(std::path::PathBuf::from)
+        };
+        let config_dir = if cfg!(target_os = "macos") {
+            env_path("HOME").map(|home| home.join("Library/Application Support"))
+        } else if cfg!(target_family = "windows") {
+            env_path("APPDATA")
+        } else {
+            env_path("XDG_CONFIG_HOME")
+                .or_else(|| env_path("HOME").map(|home| home.join(".config")))
+        };
+        let config_dir = config_dir.ok_or_else(|| {
+            PlatformError::from("Can't find the configuration directory of the user")
+        })?;
+        Ok(Self::new(config_dir.join(name).join("settings.ini")))
+    }
+
+    /// Returns the path of the file.
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+impl SettingsStorage for FileSettingsStorage {
+    fn load(&self) -> Result<SettingsMap, PlatformError> {
+        let content = match std::fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Default::default()),
+            Err(err) => {
+                return Err(
+                    format!("Error reading settings from {}: {err}", self.path.display()).into()
+                )
+            }
+        };
+        Ok(content.lines().filter_map(parse_line).collect())
+    }
+
+    fn save(&self, settings: &SettingsMap) -> Result<(), PlatformError> {
+        let error = |err: std::io::Error| -> PlatformError {
+            format!("Error writing settings to {}: {err}", self.path.display()).into()
+        };
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(error)?;
+        }
+        let mut content = String::new();
+        for (key, value) in settings {
+            escape_into(&mut content, key, true);
+            content.push('=');
+            escape_into(&mut content, value, false);
+            content.push('\n');
+        }
+        // Write to a temporary file first so that the settings aren't lost if writing fails half-way
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        std::fs::write(&temporary, content).map_err(error)?;
+        std::fs::rename(&temporary, &self.path).map_err(error)
+    }
+}
+
+/// Escapes the backslashes and line breaks, and the `=` signs of keys.
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+fn escape_into(out: &mut String, text: &str, is_key: bool) {
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '=' if is_key => out.push_str("\\="),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Parses a `key=value` line written by `FileSettingsStorage::save()`.
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+fn parse_line(line: &str) -> Option<(SharedString, SharedString)> {
+    let mut key = String::new();
+    let mut value = String::new();
+    let mut in_value = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        let out = if in_value { &mut value } else { &mut key };
+        match c {
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                c => out.push(c),
+            },
+            '=' if !in_value => in_value = true,
+            c => out.push(c),
+        }
+    }
+    in_value.then(|| (key.into(), value.into()))
+}
+
+/// Stores the settings as string values of a key of the Windows registry, under `HKEY_CURRENT_USER`.
+#[cfg(all(feature = "settings-registry", target_family = "windows"))]
+pub struct RegistrySettingsStorage {
+    key: windows::core::HSTRING,
+}
+
+#[cfg(all(feature = "settings-registry", target_family = "windows"))]
+impl RegistrySettingsStorage {
+    /// Stores the settings in the given key of `HKEY_CURRENT_USER`, such as `Software\MyCompany\MyApp`. The key is
+    /// created when saving.
+    pub fn new(key: impl AsRef<str>) -> Self {
+        Self { key: key.as_ref().into() }
+    }
+}
+
+#[cfg(all(feature = "settings-registry", target_family = "windows"))]
+#[allow(unsafe_code)]
+impl SettingsStorage for RegistrySettingsStorage {
+    fn load(&self) -> Result<SettingsMap, PlatformError> {
+        use windows::core::{PCWSTR, PWSTR};
+        use windows::Win32::System::Registry::*;
+
+        let mut hkey = HKEY::default();
+        // Safety: the key name is a valid string, and hkey is closed below
+        if unsafe { RegOpenKeyExW(HKEY_CURRENT_USER, &self.key, 0, KEY_READ, &mut hkey) }.is_err() {
+            // The key doesn't exist until the settings are saved the first time
+            return Ok(Default::default());
+        }
+        let mut settings = SettingsMap::new();
+        // The maximum length of a value name, see "Registry element size limits"
+        let mut name = [0u16; 16384];
+        for index in 0.. {
+            let mut name_len = name.len() as u32;
+            let mut value_type = 0u32;
+            // Safety: the name buffer has the given length, and the data isn't queried
+            let result = unsafe {
+                RegEnumValueW(
+                    hkey,
+                    index,
+                    PWSTR(name.as_mut_ptr()),
+                    &mut name_len,
+                    None,
+                    Some(&mut value_type),
+                    None,
+                    None,
+                )
+            };
+            if result.is_err() {
+                break;
+            }
+            if value_type != REG_SZ.0 {
+                continue;
+            }
+            let name = &name[..name_len as usize];
+            let mut name_z = name.to_vec();
+            name_z.push(0);
+            let mut size = 0u32;
+            // Safety: the first call only queries the size of the value, in bytes
+            let result = unsafe {
+                RegGetValueW(
+                    hkey,
+                    PCWSTR::null(),
+                    PCWSTR(name_z.as_ptr()),
+                    RRF_RT_REG_SZ,
+                    None,
+                    None,
+                    Some(&mut size),
+                )
+            };
+            if result.is_err() {
+                continue;
+            }
+            let mut data = vec![0u16; (size as usize).div_ceil(2)];
+            // Safety: the buffer has the size queried above
+            let result = unsafe {
+                RegGetValueW(
+                    hkey,
+                    PCWSTR::null(),
+                    PCWSTR(name_z.as_ptr()),
+                    RRF_RT_REG_SZ,
+                    None,
+                    Some(data.as_mut_ptr() as *mut _),
+                    Some(&mut size),
+                )
+            };
+            if result.is_ok() {
+                let value = &data[..(size as usize / 2).saturating_sub(1)];
+                settings.insert(
+                    String::from_utf16_lossy(name).into(),
+                    String::from_utf16_lossy(value).into(),
+                );
+            }
+        }
+        // Safety: hkey was opened above
+        let _ = unsafe { RegCloseKey(hkey) };
+        Ok(settings)
+    }
+
+    fn save(&self, settings: &SettingsMap) -> Result<(), PlatformError> {
+        use windows::core::HSTRING;
+        use windows::Win32::System::Registry::*;
+
+        let error = |err: windows::core::Error| -> PlatformError {
+            format!("Error writing settings to the registry: {err}").into()
+        };
+        for key in self.load()?.keys().filter(|key| !settings.contains_key(*key)) {
+            // Safety: the key and value names are valid strings
+            unsafe {
+                RegDeleteKeyValueW(HKEY_CURRENT_USER, &self.key, &HSTRING::from(key.as_str()))
+            }
+            .ok()
+            .map_err(error)?;
+        }
+        for (key, value) in settings {
+            let data: Vec<u16> = value.encode_utf16().chain(core::iter::once(0)).collect();
+            // Safety: the data is a nul-terminated string of the given size in bytes
+            unsafe {
+                RegSetKeyValueW(
+                    HKEY_CURRENT_USER,
+                    &self.key,
+                    &HSTRING::from(key.as_str()),
+                    REG_SZ.0,
+                    Some(data.as_ptr() as *const _),
+                    (data.len() * 2) as u32,
+                )
+            }
+            .ok()
+            .map_err(error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Stores the settings in the local storage of the browser, with a prefix added to the keys.
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
+pub struct WebSettingsStorage {
+    prefix: String,
+}
+
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
+impl WebSettingsStorage {
+    /// Stores the settings in the local storage, with the given prefix added to their keys so that they don't clash
+    /// with the other data of the web page.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self { prefix: prefix.into() }
+    }
+
+    fn local_storage() -> Result<web_sys::Storage, PlatformError> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or_else(|| PlatformError::from("The local storage of the browser isn't available"))
+    }
+
+    fn stored_keys(&self, storage: &web_sys::Storage) -> Vec<String> {
+        let len = storage.length().unwrap_or(0);
+        (0..len)
+            .filter_map(|index| storage.key(index).ok().flatten())
+            .filter(|key| key.starts_with(&self.prefix))
+            .collect()
+    }
+}
+
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
+impl SettingsStorage for WebSettingsStorage {
+    fn load(&self) -> Result<SettingsMap, PlatformError> {
+        let storage = Self::local_storage()?;
+        Ok(self
+            .stored_keys(&storage)
+            .into_iter()
+            .filter_map(|key| {
+                let value = storage.get_item(&key).ok().flatten()?;
+                Some((key[self.prefix.len()..].into(), value.into()))
+            })
+            .collect())
+    }
+
+    fn save(&self, settings: &SettingsMap) -> Result<(), PlatformError> {
+        let storage = Self::local_storage()?;
+        for key in self.stored_keys(&storage) {
+            if !settings.contains_key(&key[self.prefix.len()..]) {
+                let _ = storage.remove_item(&key);
+            }
+        }
+        for (key, value) in settings {
+            storage.set_item(&format!("{}{key}", self.prefix), value).map_err(|_| {
+                PlatformError::from("Error writing settings to the local storage of the browser")
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default, Clone)]
+    struct MemoryStorage(Rc<RefCell<SettingsMap>>, Rc<Cell<usize>>);
+
+    impl SettingsStorage for MemoryStorage {
+        fn load(&self) -> Result<SettingsMap, PlatformError> {
+            Ok(self.0.borrow().clone())
+        }
+        fn save(&self, settings: &SettingsMap) -> Result<(), PlatformError> {
+            *self.0.borrow_mut() = settings.clone();
+            self.1.set(self.1.get() + 1);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn set_and_save() {
+        let storage = MemoryStorage::default();
+        storage.0.borrow_mut().insert("a".into(), "1".into());
+        let settings = Settings::new(storage.clone()).unwrap();
+        assert_eq!(settings.get("a").as_deref(), Some("1"));
+
+        // Nothing changed, so nothing is written
+        settings.set("a", "1");
+        settings.save().unwrap();
+        assert_eq!(storage.1.get(), 0);
+
+        settings.set("b", "2");
+        assert_eq!(settings.remove("a").as_deref(), Some("1"));
+        assert_eq!(settings.keys(), alloc::vec![SharedString::from("b")]);
+        settings.save().unwrap();
+        assert_eq!(storage.1.get(), 1);
+        assert_eq!(storage.0.borrow().get("b").map(|v| v.as_str()), Some("2"));
+        assert!(!storage.0.borrow().contains_key("a"));
+    }
+
+    #[test]
+    fn window_geometry_format() {
+        let geometry = WindowGeometry::parse("-10, 20,800,600,maximized").unwrap();
+        assert_eq!(geometry.position, PhysicalPosition::new(-10, 20));
+        assert_eq!(geometry.size, PhysicalSize::new(800, 600));
+        assert!(geometry.maximized && !geometry.fullscreen);
+        assert_eq!(geometry.to_shared_string(), "-10,20,800,600,maximized");
+        assert!(WindowGeometry::parse("1,2,0,600").is_none());
+        assert!(WindowGeometry::parse("garbage").is_none());
+    }
+
+    #[test]
+    fn window_geometry_on_screen() {
+        let screens = [
+            (PhysicalPosition::new(0, 0), PhysicalSize::new(1920, 1080)),
+            (PhysicalPosition::new(1920, 0), PhysicalSize::new(1280, 1024)),
+        ];
+        let on_screen = |value: &str| WindowGeometry::parse(value).unwrap().is_on_screen(&screens);
+        assert!(on_screen("100,100,800,600"));
+        assert!(on_screen("2000,100,800,600"));
+        // Mostly off the screens, but the title bar can still be grabbed
+        assert!(on_screen("3150,-10,800,600"));
+        // On a monitor that was disconnected, or with too little left on the screens
+        assert!(!on_screen("-1920,0,800,600"));
+        assert!(!on_screen("3180,100,800,600"));
+        assert!(!on_screen("100,1060,800,600"));
+    }
+
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    #[test]
+    fn file_storage() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = FileSettingsStorage::new(dir.path().join("app").join("settings.ini"));
+        assert!(storage.load().unwrap().is_empty());
+
+        let mut settings = SettingsMap::new();
+        settings.insert("key=with\\equal".into(), "two\nlines = \\".into());
+        settings.insert("empty".into(), "".into());
+        storage.save(&settings).unwrap();
+        assert_eq!(storage.load().unwrap(), settings);
+    }
+}
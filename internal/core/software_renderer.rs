@@ -24,7 +24,7 @@
 use crate::item_rendering::{
     CachedRenderingData, DirtyRegion, PartialRenderingState, RenderBorderRectangle, RenderImage,
 };
-use crate::items::{ItemRc, TextOverflow, TextWrap};
+use crate::items::{ItemRc, TextOverflow, TextUnderlineStyle, TextWrap, TextWritingMode};
 use crate::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
     PhysicalPx, PointLengths, RectLengths, ScaleFactor, SizeLengths,
@@ -381,6 +381,10 @@ pub struct SoftwareRenderer {
     maybe_window_adapter: RefCell<Option<Weak<dyn crate::window::WindowAdapter>>>,
     rotation: Cell<RenderingRotation>,
     rendering_metrics_collector: Option<Rc<RenderingMetricsCollector>>,
+    corner_cache: RefCell<draw_functions::RoundedCornerCache>,
+    gamma_correct_blending: Cell<bool>,
+    /// Whether the warning about a pixel type that can't blend in linear light was logged
+    linear_blending_warning_logged: Cell<bool>,
 }
 
 impl Default for SoftwareRenderer {
@@ -390,6 +394,9 @@ fn default() -> Self {
             maybe_window_adapter: Default::default(),
             rotation: Default::default(),
             rendering_metrics_collector: RenderingMetricsCollector::new("software"),
+            corner_cache: Default::default(),
+            gamma_correct_blending: Default::default(),
+            linear_blending_warning_logged: Default::default(),
         }
     }
 }
@@ -447,7 +454,15 @@ pub fn rendering_rotation(&self) -> RenderingRotation {
     ///
     /// Returns the physical dirty region for this frame, excluding the extra_draw_region,
     /// in the window frame of reference. It is affected by the screen rotation.
-    pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> PhysicalRegion {
+    pub fn render<T: TargetPixel>(&self, buffer: &mut [T], pixel_stride: usize) -> PhysicalRegion {
+        if self.blends_linear::<T>() {
+            self.render_impl(LinearBlendPixel::wrap_slice(buffer), pixel_stride)
+        } else {
+            self.render_impl(buffer, pixel_stride)
+        }
+    }
+
+    fn render_impl(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> PhysicalRegion {
         let Some(window) = self.maybe_window_adapter.borrow().as_ref().and_then(|w| w.upgrade())
         else {
             return Default::default();
@@ -480,6 +495,7 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
             },
             "buffer of size {} with stride {pixel_stride} is too small to handle a window of size {size:?}", buffer.len()
         );
+        let mut corner_cache = self.corner_cache.borrow_mut();
         let buffer_renderer = SceneBuilder::new(
             size,
             factor,
@@ -489,8 +505,10 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                 stride: pixel_stride,
                 dirty_range_cache: vec![],
                 dirty_region: Default::default(),
+                corner_cache: &mut corner_cache,
             },
             rotation,
+            self.gamma_correct_blending.get(),
         );
         let mut renderer = self.partial_rendering_state.create_partial_renderer(buffer_renderer);
 
@@ -594,7 +612,7 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
     /// renderer.render_by_line(FrameBuffer{ frame_buffer: the_frame_buffer, stride: display_width });
     /// # }
     /// ```
-    pub fn render_by_line(&self, line_buffer: impl LineBufferProvider) -> PhysicalRegion {
+    pub fn render_by_line<L: LineBufferProvider>(&self, line_buffer: L) -> PhysicalRegion {
         let Some(window) = self.maybe_window_adapter.borrow().as_ref().and_then(|w| w.upgrade())
         else {
             return Default::default();
@@ -608,19 +626,88 @@ pub fn render_by_line(&self, line_buffer: impl LineBufferProvider) -> PhysicalRe
             let factor = ScaleFactor::new(window_inner.scale_factor());
             let size = LogicalSize::from_lengths(window_item.width(), window_item.height()).cast()
                 * factor;
-            render_window_frame_by_line(
-                window_inner,
-                window_item.background(),
-                size.cast(),
-                self,
-                line_buffer,
-            )
+            if self.blends_linear::<L::TargetPixel>() {
+                render_window_frame_by_line(
+                    window_inner,
+                    window_item.background(),
+                    size.cast(),
+                    self,
+                    LinearBlendLineBufferProvider(line_buffer),
+                )
+            } else {
+                render_window_frame_by_line(
+                    window_inner,
+                    window_item.background(),
+                    size.cast(),
+                    self,
+                    line_buffer,
+                )
+            }
         } else {
             PhysicalRegion { ..Default::default() }
         }
     }
 }
 
+impl SoftwareRenderer {
+    /// Returns whether gamma-correct blending is enabled and supported by the pixels of type `T`.
+    fn blends_linear<T: TargetPixel>(&self) -> bool {
+        if !self.gamma_correct_blending.get() {
+            return false;
+        }
+        if !T::SUPPORTS_LINEAR_BLENDING && !self.linear_blending_warning_logged.replace(true) {
+            crate::debug_log!(
+                "Gamma-correct blending is enabled, but the target pixel type doesn't implement TargetPixel::blend_linear(). Blending in the sRGB color space instead."
+            );
+        }
+        T::SUPPORTS_LINEAR_BLENDING
+    }
+}
+
+/// A pixel that blends in linear light, used when gamma-correct blending is enabled.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+struct LinearBlendPixel<T>(T);
+
+impl<T: TargetPixel> LinearBlendPixel<T> {
+    #[allow(unsafe_code)]
+    fn wrap_slice(slice: &mut [T]) -> &mut [Self] {
+        // Safety: LinearBlendPixel is a transparent wrapper around T
+        unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut Self, slice.len()) }
+    }
+}
+
+impl<T: TargetPixel> TargetPixel for LinearBlendPixel<T> {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        self.0.blend_linear(color)
+    }
+
+    const SUPPORTS_LINEAR_BLENDING: bool = T::SUPPORTS_LINEAR_BLENDING;
+
+    fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self(T::from_rgb(red, green, blue))
+    }
+
+    fn background() -> Self {
+        Self(T::background())
+    }
+}
+
+struct LinearBlendLineBufferProvider<L>(L);
+
+impl<L: LineBufferProvider> LineBufferProvider for LinearBlendLineBufferProvider<L> {
+    type TargetPixel = LinearBlendPixel<L::TargetPixel>;
+
+    fn process_line(
+        &mut self,
+        line: usize,
+        range: core::ops::Range<usize>,
+        render_fn: impl FnOnce(&mut [Self::TargetPixel]),
+    ) {
+        self.0.process_line(line, range, |buffer| render_fn(LinearBlendPixel::wrap_slice(buffer)))
+    }
+}
+
 #[doc(hidden)]
 impl RendererSealed for SoftwareRenderer {
     fn text_size(
@@ -634,6 +721,17 @@ fn text_size(
         fonts::text_size(font_request, text, max_width, scale_factor, text_wrap)
     }
 
+    fn text_line_ranges(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        text: &str,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> Result<Vec<core::ops::Range<usize>>, PlatformError> {
+        Ok(fonts::text_line_ranges(font_request, text, max_width, scale_factor, text_wrap))
+    }
+
     fn font_metrics(
         &self,
         font_request: crate::graphics::FontRequest,
@@ -642,6 +740,24 @@ fn font_metrics(
         fonts::font_metrics(font_request, scale_factor)
     }
 
+    #[cfg_attr(not(feature = "software-renderer-systemfonts"), allow(unused_variables))]
+    fn text_outline(
+        &self,
+        text: &str,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Result<crate::api::TextOutline, PlatformError> {
+        match fonts::match_font(&font_request, text, scale_factor) {
+            fonts::Font::PixelFont(_) => {
+                Err("Text rendered with pre-rendered bitmap fonts has no vector outline".into())
+            }
+            #[cfg(feature = "software-renderer-systemfonts")]
+            fonts::Font::VectorFont(vf) => {
+                Ok(crate::api::TextOutline::new(vf.text_outline(text, scale_factor)))
+            }
+        }
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&crate::items::TextInput>,
@@ -651,7 +767,7 @@ fn text_input_byte_offset_for_position(
     ) -> usize {
         let visual_representation = text_input.visual_representation(None);
 
-        let font = fonts::match_font(&font_request, scale_factor);
+        let font = fonts::match_font(&font_request, &visual_representation.text, scale_factor);
 
         let width = (text_input.width().cast() * scale_factor).cast();
         let height = (text_input.height().cast() * scale_factor).cast();
@@ -674,6 +790,8 @@ fn text_input_byte_offset_for_position(
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
                     single_line: false,
+                    writing_mode: TextWritingMode::HorizontalTb,
+                    max_lines: None,
                 };
 
                 visual_representation.map_byte_offset_from_byte_offset_in_visual_text(
@@ -694,6 +812,8 @@ fn text_input_byte_offset_for_position(
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
                     single_line: false,
+                    writing_mode: TextWritingMode::HorizontalTb,
+                    max_lines: None,
                 };
 
                 visual_representation.map_byte_offset_from_byte_offset_in_visual_text(
@@ -703,21 +823,21 @@ fn text_input_byte_offset_for_position(
         }
     }
 
-    fn text_input_cursor_rect_for_byte_offset(
+    fn text_input_cursor_metrics_for_byte_offset(
         &self,
         text_input: Pin<&crate::items::TextInput>,
         byte_offset: usize,
         font_request: crate::graphics::FontRequest,
         scale_factor: ScaleFactor,
-    ) -> LogicalRect {
+    ) -> crate::items::TextCursorMetrics {
         let visual_representation = text_input.visual_representation(None);
 
-        let font = fonts::match_font(&font_request, scale_factor);
+        let font = fonts::match_font(&font_request, &visual_representation.text, scale_factor);
 
         let width = (text_input.width().cast() * scale_factor).cast();
         let height = (text_input.height().cast() * scale_factor).cast();
 
-        let (cursor_position, cursor_height) = match font {
+        let (cursor_line, cursor_height, ascent) = match font {
             fonts::Font::PixelFont(pf) => {
                 let layout = fonts::text_layout_for_font(&pf, &font_request, scale_factor);
 
@@ -731,9 +851,11 @@ fn text_input_cursor_rect_for_byte_offset(
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
                     single_line: false,
+                    writing_mode: TextWritingMode::HorizontalTb,
+                    max_lines: None,
                 };
 
-                (paragraph.cursor_pos_for_byte_offset(byte_offset), pf.height())
+                (paragraph.cursor_line_for_byte_offset(byte_offset), pf.height(), pf.ascent())
             }
             #[cfg(feature = "software-renderer-systemfonts")]
             fonts::Font::VectorFont(vf) => {
@@ -749,22 +871,31 @@ fn text_input_cursor_rect_for_byte_offset(
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
                     single_line: false,
+                    writing_mode: TextWritingMode::HorizontalTb,
+                    max_lines: None,
                 };
 
-                (paragraph.cursor_pos_for_byte_offset(byte_offset), vf.height())
+                (paragraph.cursor_line_for_byte_offset(byte_offset), vf.height(), vf.ascent())
             }
         };
 
-        (PhysicalRect::new(
-            PhysicalPoint::from_lengths(cursor_position.0, cursor_position.1),
-            PhysicalSize::from_lengths(
-                (text_input.text_cursor_width().cast() * scale_factor).cast(),
-                cursor_height,
-            ),
-        )
-        .cast()
-            / scale_factor)
-            .cast()
+        let (cursor_x, cursor_y) = cursor_line.cursor_pos;
+        let to_logical = |rect: PhysicalRect| (rect.cast() / scale_factor).cast();
+        crate::items::TextCursorMetrics {
+            rect: to_logical(PhysicalRect::new(
+                PhysicalPoint::from_lengths(cursor_x, cursor_y),
+                PhysicalSize::from_lengths(
+                    (text_input.text_cursor_width().cast() * scale_factor).cast(),
+                    cursor_height,
+                ),
+            )),
+            baseline: ((cursor_y + ascent).cast() / scale_factor).get() as _,
+            line_index: cursor_line.line_index,
+            line_rect: to_logical(PhysicalRect::new(
+                PhysicalPoint::from_lengths(cursor_line.line_x, cursor_y),
+                PhysicalSize::from_lengths(cursor_line.line_width, cursor_height),
+            )),
+        }
     }
 
     fn free_graphics_resources(
@@ -800,15 +931,60 @@ fn register_font_from_path(
         self::fonts::systemfonts::register_font_from_path(path)
     }
 
+    #[cfg(feature = "software-renderer-systemfonts")]
+    fn unregister_font(&self, family: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self::fonts::systemfonts::unregister_font(family)?;
+        self.partial_rendering_state.clear_cache();
+        Ok(())
+    }
+
+    #[cfg(feature = "software-renderer-systemfonts")]
+    fn replace_font_from_memory(
+        &self,
+        family: &str,
+        data: &'static [u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self::fonts::systemfonts::replace_font_from_memory(family, data)?;
+        self.partial_rendering_state.clear_cache();
+        Ok(())
+    }
+
+    #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
+    fn replace_font_from_path(
+        &self,
+        family: &str,
+        path: &std::path::Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self::fonts::systemfonts::replace_font_from_path(family, path)?;
+        self.partial_rendering_state.clear_cache();
+        Ok(())
+    }
+
     fn default_font_size(&self) -> LogicalLength {
         self::fonts::DEFAULT_FONT_SIZE
     }
 
+    fn supports_vertical_text(&self) -> bool {
+        true
+    }
+
     fn set_window_adapter(&self, window_adapter: &Rc<dyn WindowAdapter>) {
         *self.maybe_window_adapter.borrow_mut() = Some(Rc::downgrade(window_adapter));
         self.partial_rendering_state.clear_cache();
     }
 
+    fn set_gamma_correct_blending(&self, enabled: bool) -> Result<(), PlatformError> {
+        if self.gamma_correct_blending.replace(enabled) != enabled {
+            self.partial_rendering_state.force_screen_refresh();
+            if let Some(window_adapter) =
+                self.maybe_window_adapter.borrow().as_ref().and_then(|w| w.upgrade())
+            {
+                window_adapter.request_redraw();
+            }
+        }
+        Ok(())
+    }
+
     fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
         let Some(window_adapter) =
             self.maybe_window_adapter.borrow().as_ref().and_then(|w| w.upgrade())
@@ -855,6 +1031,7 @@ fn render_window_frame_by_line(
     mut line_buffer: impl LineBufferProvider,
 ) -> PhysicalRegion {
     let mut scene = prepare_scene(window, size, renderer);
+    let mut corner_cache = renderer.corner_cache.borrow_mut();
 
     let to_draw_tr = scene.dirty_region.bounding_rect();
 
@@ -928,6 +1105,7 @@ fn render_window_frame_by_line(
                                     range_buffer,
                                     extra_left_clip,
                                     extra_right_clip,
+                                    &mut corner_cache,
                                 );
                             }
                             SceneCommand::Gradient { gradient_index } => {
@@ -941,6 +1119,17 @@ fn render_window_frame_by_line(
                                     extra_left_clip,
                                 );
                             }
+                            SceneCommand::ConicGradient { gradient_index } => {
+                                let g = &scene.vectors.conic_gradients[gradient_index as usize];
+
+                                draw_functions::draw_conic_gradient_line(
+                                    &PhysicalRect { origin: span.pos, size: span.size },
+                                    scene.current_line,
+                                    g,
+                                    range_buffer,
+                                    extra_left_clip,
+                                );
+                            }
                         }
                     }
                 },
@@ -966,6 +1155,7 @@ fn prepare_scene(
         window,
         PrepareScene::default(),
         software_renderer.rotation.get(),
+        software_renderer.gamma_correct_blending.get(),
     );
     let mut renderer =
         software_renderer.partial_rendering_state.create_partial_renderer(prepare_scene);
@@ -1037,6 +1227,7 @@ trait ProcessScene {
     fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRectangle);
     fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand);
     fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand);
+    fn process_conic_gradient(&mut self, geometry: PhysicalRect, gradient: ConicGradientCommand);
 }
 
 struct RenderToBuffer<'a, TargetPixel> {
@@ -1044,6 +1235,7 @@ struct RenderToBuffer<'a, TargetPixel> {
     stride: usize,
     dirty_range_cache: Vec<core::ops::Range<i16>>,
     dirty_region: PhysicalRegion,
+    corner_cache: &'a mut draw_functions::RoundedCornerCache,
 }
 
 impl<'a, T: TargetPixel> RenderToBuffer<'a, T> {
@@ -1116,6 +1308,7 @@ fn process_rectangle(&mut self, geometry: PhysicalRect, color: PremultipliedRgba
     }
 
     fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, rr: RoundedRectangle) {
+        let mut corner_cache = core::mem::take(self.corner_cache);
         self.foreach_ranges(&geometry, |line, buffer, extra_left_clip, extra_right_clip| {
             draw_functions::draw_rounded_rectangle_line(
                 &geometry,
@@ -1124,8 +1317,10 @@ fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, rr: RoundedRecta
                 buffer,
                 extra_left_clip,
                 extra_right_clip,
+                &mut corner_cache,
             );
         });
+        *self.corner_cache = corner_cache;
     }
 
     fn process_gradient(&mut self, geometry: PhysicalRect, g: GradientCommand) {
@@ -1139,6 +1334,18 @@ fn process_gradient(&mut self, geometry: PhysicalRect, g: GradientCommand) {
             );
         });
     }
+
+    fn process_conic_gradient(&mut self, geometry: PhysicalRect, g: ConicGradientCommand) {
+        self.foreach_ranges(&geometry, |line, buffer, extra_left_clip, _extra_right_clip| {
+            draw_functions::draw_conic_gradient_line(
+                &geometry,
+                PhysicalLength::new(line),
+                &g,
+                buffer,
+                extra_left_clip,
+            );
+        });
+    }
 }
 
 #[derive(Default)]
@@ -1212,6 +1419,20 @@ fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand
             });
         }
     }
+
+    fn process_conic_gradient(&mut self, geometry: PhysicalRect, gradient: ConicGradientCommand) {
+        let size = geometry.size;
+        if !size.is_empty() {
+            let gradient_index = self.vectors.conic_gradients.len() as u16;
+            self.vectors.conic_gradients.push(gradient);
+            self.items.push(SceneItem {
+                pos: geometry.origin,
+                size,
+                z: self.items.len() as u16,
+                command: SceneCommand::ConicGradient { gradient_index },
+            });
+        }
+    }
 }
 
 struct SceneBuilder<'a, T> {
@@ -1221,6 +1442,26 @@ struct SceneBuilder<'a, T> {
     scale_factor: ScaleFactor,
     window: &'a WindowInner,
     rotation: RotationInfo,
+    /// Whether the gradients are interpolated in linear light, for gamma-correct blending
+    linear_light_gradients: bool,
+}
+
+/// Returns the brush with stops added to its gradient, so that the software renderer, which interpolates in
+/// the sRGB color space, interpolates it like in linear light.
+fn linear_light_gradient(brush: Brush) -> Brush {
+    match brush {
+        Brush::LinearGradient(g) => {
+            Brush::LinearGradient(crate::graphics::LinearGradientBrush::new(
+                g.angle(),
+                draw_functions::linear_light_gradient_stops(g.stops()),
+            ))
+        }
+        Brush::ConicGradient(g) => Brush::ConicGradient(crate::graphics::ConicGradientBrush::new(
+            g.from_angle(),
+            draw_functions::linear_light_gradient_stops(g.stops()),
+        )),
+        brush => brush,
+    }
 }
 
 impl<'a, T: ProcessScene> SceneBuilder<'a, T> {
@@ -1230,6 +1471,7 @@ fn new(
         window: &'a WindowInner,
         processor: T,
         orientation: RenderingRotation,
+        linear_light_gradients: bool,
     ) -> Self {
         Self {
             processor,
@@ -1245,6 +1487,7 @@ fn new(
             scale_factor,
             window,
             rotation: RotationInfo { orientation, screen_size },
+            linear_light_gradients,
         }
     }
 
@@ -1430,6 +1673,86 @@ fn draw_image_impl(
         };
     }
 
+    /// Draws the image of the pattern of the shape `geom` in `area`, like a tiled image. The
+    /// rotation of the pattern is not supported.
+    fn draw_image_pattern(
+        &mut self,
+        pattern: &crate::graphics::ImagePatternBrush,
+        geom: LogicalRect,
+        area: LogicalRect,
+    ) {
+        if area.is_empty() {
+            return;
+        }
+        let image_inner: &ImageInner = pattern.image().into();
+        let image_size = image_inner.size();
+        if image_size.is_empty() || matches!(image_inner, ImageInner::NineSlice(..)) {
+            return;
+        }
+        let (scale_x, scale_y) = pattern.scale();
+        let source_to_target_x = scale_x * self.scale_factor.get();
+        let source_to_target_y = scale_y * self.scale_factor.get();
+        if source_to_target_x <= 0. || source_to_target_y <= 0. {
+            return;
+        }
+        let (offset_x, offset_y) = pattern.offset();
+        let (repeat_x, repeat_y) = pattern.repeat();
+        let target = geom.cast::<f32>() * self.scale_factor;
+        let area = area.cast::<f32>() * self.scale_factor;
+
+        // Returns the start and the length of the image in the area, and the coordinate of the
+        // first pixel in the image, for an image whose top-left corner is at `origin`
+        let axis = |start: f32, length: f32, origin: f32, scale: f32, size: u32, repeat: bool| {
+            if repeat {
+                let first =
+                    num_traits::Euclid::rem_euclid(&((start - origin) / scale), &(size as f32));
+                Some((start, length, first as u32))
+            } else {
+                let clipped_start = origin.max(start);
+                let end = (origin + size as f32 * scale).min(start + length);
+                (end > clipped_start).then(|| {
+                    (clipped_start, end - clipped_start, ((clipped_start - origin) / scale) as u32)
+                })
+            }
+        };
+        let Some((x, width, first_x)) = axis(
+            area.origin.x,
+            area.size.width,
+            target.origin.x + offset_x * self.scale_factor.get(),
+            source_to_target_x,
+            image_size.width,
+            repeat_x,
+        ) else {
+            return;
+        };
+        let Some((y, height, first_y)) = axis(
+            area.origin.y,
+            area.size.height,
+            target.origin.y + offset_y * self.scale_factor.get(),
+            source_to_target_y,
+            image_size.height,
+            repeat_y,
+        ) else {
+            return;
+        };
+
+        let alpha = self.current_state.alpha;
+        self.current_state.alpha *= pattern.opacity();
+        self.draw_image_impl(
+            image_inner,
+            crate::graphics::FitResult {
+                clip_rect: euclid::Rect::from_size(image_size.cast()),
+                source_to_target_x,
+                source_to_target_y,
+                size: euclid::size2(width, height),
+                offset: euclid::point2(x, y),
+                tiled: Some(euclid::point2(first_x, first_y)),
+            },
+            Color::default(),
+        );
+        self.current_state.alpha = alpha;
+    }
+
     fn draw_text_paragraph<Font>(
         &mut self,
         paragraph: &TextParagraphLayout<'_, Font>,
@@ -1443,6 +1766,8 @@ fn draw_text_paragraph<Font>(
         paragraph
             .layout_lines::<()>(
                 |glyphs, line_x, line_y, _, sel| {
+                    // The glyphs of horizontal lines are on the baseline, and the ones of vertical
+                    // columns are positioned below each other from the top of the column.
                     let baseline_y = line_y + paragraph.layout.font.ascent();
                     if let (Some(sel), Some(selection)) = (sel, &selection) {
                         let geometry = euclid::rect(
@@ -1459,7 +1784,65 @@ fn draw_text_paragraph<Font>(
                         }
                     }
                     let scale_delta = paragraph.layout.font.scale_delta();
+                    let underline_thickness =
+                        (paragraph.layout.font.height() / 16).max(PhysicalLength::new(1));
                     for positioned_glyph in glyphs {
+                        if selection
+                            .as_ref()
+                            .is_some_and(|s| s.preedit.contains(&positioned_glyph.text_byte_offset))
+                        {
+                            let geometry = euclid::rect(
+                                (line_x + positioned_glyph.x).get(),
+                                (baseline_y + underline_thickness).get(),
+                                positioned_glyph.advance.get(),
+                                underline_thickness.get(),
+                            );
+                            if let Some(clipped_src) = geometry.intersection(&physical_clip.cast())
+                            {
+                                let geometry =
+                                    clipped_src.translate(offset.cast()).transformed(self.rotation);
+                                self.processor.process_rectangle(geometry, color.into());
+                            }
+                        }
+                        for (range, underline_color, style) in
+                            selection.iter().flat_map(|s| s.underlines.iter())
+                        {
+                            if !range.contains(&positioned_glyph.text_byte_offset) {
+                                continue;
+                            }
+                            let thickness = underline_thickness.get();
+                            let y = (baseline_y + underline_thickness).get();
+                            let start_x = (line_x + positioned_glyph.x).get();
+                            let end_x = start_x + positioned_glyph.advance.get();
+                            // A wavy line is approximated with segments alternating between two
+                            // heights, aligned on the line so that it continues across glyphs
+                            let step = match style {
+                                TextUnderlineStyle::Solid => None,
+                                TextUnderlineStyle::Wavy => Some(thickness * 2),
+                            };
+                            let mut x = start_x;
+                            while x < end_x {
+                                let (segment_end, segment_y) = match step {
+                                    Some(step) => (
+                                        (x - x.rem_euclid(step) + step).min(end_x),
+                                        if x.div_euclid(step) % 2 == 0 { y } else { y + thickness },
+                                    ),
+                                    None => (end_x, y),
+                                };
+                                let geometry =
+                                    euclid::rect(x, segment_y, segment_end - x, thickness);
+                                if let Some(clipped_src) =
+                                    geometry.intersection(&physical_clip.cast())
+                                {
+                                    let geometry = clipped_src
+                                        .translate(offset.cast())
+                                        .transformed(self.rotation);
+                                    self.processor
+                                        .process_rectangle(geometry, (*underline_color).into());
+                                }
+                                x = segment_end;
+                            }
+                        }
                         let Some(glyph) =
                             paragraph.layout.font.render_glyph(positioned_glyph.glyph_id)
                         else {
@@ -1471,7 +1854,7 @@ fn draw_text_paragraph<Font>(
                         let target_rect = PhysicalRect::new(
                             PhysicalPoint::from_lengths(
                                 line_x + positioned_glyph.x - gl_x,
-                                baseline_y - gl_y - glyph.height,
+                                baseline_y + positioned_glyph.y - gl_y - glyph.height,
                             ),
                             glyph.size(),
                         )
@@ -1584,7 +1967,7 @@ fn draw_text_paragraph<Font>(
                     }
                     core::ops::ControlFlow::Continue(())
                 },
-                selection.as_ref().map(|s| s.selection.clone()),
+                selection.as_ref().map(|s| s.selection.clone()).filter(|s| !s.is_empty()),
             )
             .ok();
     }
@@ -1608,6 +1991,10 @@ struct SelectionInfo {
     selection_color: Color,
     selection_background: Color,
     selection: core::ops::Range<usize>,
+    /// The range of the input method's composition, which is underlined
+    preedit: core::ops::Range<usize>,
+    /// The ranges added with `add-underline()`
+    underlines: Vec<(core::ops::Range<usize>, Color, TextUnderlineStyle)>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -1632,7 +2019,38 @@ fn draw_rectangle(
                 None => return,
             };
 
-            let background = rect.background();
+            let mut background = rect.background();
+            if self.linear_light_gradients {
+                background = linear_light_gradient(background);
+            }
+            if let Brush::ImagePattern(pattern) = &background {
+                self.draw_image_pattern(pattern, geom, geom);
+                return;
+            }
+            if let Brush::ConicGradient(g) = &background {
+                let offset = self.current_state.offset.to_vector();
+                let act_rect = (clipped.translate(offset).cast() * self.scale_factor)
+                    .round()
+                    .cast()
+                    .transformed(self.rotation);
+                let full_rect: PhysicalRect = (geom.translate(offset).cast() * self.scale_factor)
+                    .round()
+                    .cast()
+                    .transformed(self.rotation);
+                let axis_angle = (360. - self.rotation.orientation.angle()) % 360.;
+                let gradient = crate::graphics::ConicGradientBrush::new(
+                    g.from_angle() - axis_angle,
+                    g.stops().map(|s| crate::graphics::GradientStop {
+                        color: self.alpha_color(s.color),
+                        position: s.position,
+                    }),
+                );
+                self.processor.process_conic_gradient(
+                    act_rect,
+                    ConicGradientCommand { gradient, center: full_rect.cast::<f32>().center() },
+                );
+                return;
+            }
             if let Brush::LinearGradient(g) = background {
                 let geom2 = (geom.cast() * self.scale_factor).transformed(self.rotation);
                 let clipped2 = (clipped.cast() * self.scale_factor).transformed(self.rotation);
@@ -1756,10 +2174,42 @@ fn draw_border_rectangle(
         if self.should_draw(&geom) {
             let mut border = rect.border_width();
             let radius = rect.border_radius();
+            let background = rect.background();
+            let border_brush = rect.border_color();
             // FIXME: gradients
-            let color = self.alpha_color(rect.background().color());
+            let color = self.alpha_color(background.color());
+
+            if let Brush::ImagePattern(border_pattern) = &border_brush {
+                // Patterns can't be clipped to the rounded corners, so the border is drawn with
+                // square corners
+                let b = border.get().max(0 as _);
+                let inner = geom.inflate(-b, -b);
+                if let Brush::ImagePattern(pattern) = &background {
+                    self.draw_image_pattern(pattern, geom, inner);
+                } else if color.alpha() > 0 {
+                    if let Some(r) = inner.intersection(&self.current_state.clip) {
+                        let geometry = (r.translate(self.current_state.offset.to_vector()).cast()
+                            * self.scale_factor)
+                            .round()
+                            .cast()
+                            .transformed(self.rotation);
+                        self.processor.process_rectangle(geometry, color.into());
+                    }
+                }
+                let b = b.min(geom.width() / 2 as Coord).min(geom.height() / 2 as Coord);
+                for strip in [
+                    euclid::rect(0 as _, 0 as _, geom.width(), b),
+                    euclid::rect(0 as _, geom.height() - b, geom.width(), b),
+                    euclid::rect(0 as _, b, b, geom.height() - b - b),
+                    euclid::rect(geom.width() - b, b, b, geom.height() - b - b),
+                ] {
+                    self.draw_image_pattern(border_pattern, geom, strip);
+                }
+                return;
+            }
+
             let border_color = if border.get() as f32 > 0.01 {
-                self.alpha_color(rect.border_color().color())
+                self.alpha_color(border_brush.color())
             } else {
                 Color::default()
             };
@@ -1788,6 +2238,17 @@ fn draw_border_rectangle(
                 }
             }
 
+            if let Brush::ImagePattern(pattern) = &background {
+                // The color of the pattern is transparent, so the code below only draws the border.
+                // The pattern itself is not clipped to the rounded corners.
+                let area = if border_color.alpha == 255 {
+                    geom.inflate(-border.get(), -border.get())
+                } else {
+                    geom
+                };
+                self.draw_image_pattern(pattern, geom, area);
+            }
+
             if !radius.is_zero() {
                 let radius = radius
                     .min(LogicalBorderRadius::from_length(geom.width_length() / 2 as Coord))
@@ -1957,7 +2418,7 @@ fn draw_text(
         };
         let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
 
-        let font = fonts::match_font(&font_request, self.scale_factor);
+        let font = fonts::match_font(&font_request, &string, self.scale_factor);
 
         match font {
             fonts::Font::PixelFont(pf) => {
@@ -1974,6 +2435,8 @@ fn draw_text(
                     wrap: text.wrap(),
                     overflow: text.overflow(),
                     single_line: false,
+                    writing_mode: text.writing_mode(),
+                    max_lines: text.max_lines(),
                 };
 
                 self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
@@ -1993,6 +2456,8 @@ fn draw_text(
                     wrap: text.wrap(),
                     overflow: text.overflow(),
                     single_line: false,
+                    writing_mode: text.writing_mode(),
+                    max_lines: text.max_lines(),
                 };
 
                 self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
@@ -2025,17 +2490,32 @@ fn draw_text_input(
         };
         let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
 
-        let font = fonts::match_font(&font_request, self.scale_factor);
-
         let text_visual_representation = text_input.visual_representation(None);
+        let font =
+            fonts::match_font(&font_request, &text_visual_representation.text, self.scale_factor);
         let color = self.alpha_color(text_visual_representation.text_color.color());
 
+        // While composing, the clause that the input method converts is highlighted like a selection
+        let preedit = text_visual_representation.preedit_range.clone();
+        let selection_range = if preedit.is_empty() {
+            text_visual_representation.selection_range.clone()
+        } else {
+            text_visual_representation.preedit_clause_range.clone()
+        };
+        let underlines: Vec<_> = text_visual_representation
+            .underlines
+            .iter()
+            .map(|(range, color, style)| (range.clone(), self.alpha_color(*color), *style))
+            .collect();
         let selection =
-            (!text_visual_representation.selection_range.is_empty()).then_some(SelectionInfo {
-                selection_background: self.alpha_color(text_input.selection_background_color()),
-                selection_color: self.alpha_color(text_input.selection_foreground_color()),
-                selection: text_visual_representation.selection_range.clone(),
-            });
+            (!selection_range.is_empty() || !preedit.is_empty() || !underlines.is_empty())
+                .then_some(SelectionInfo {
+                    selection_background: self.alpha_color(text_input.selection_background_color()),
+                    selection_color: self.alpha_color(text_input.selection_foreground_color()),
+                    selection: selection_range,
+                    preedit,
+                    underlines,
+                });
 
         let cursor_pos_and_height = match font {
             fonts::Font::PixelFont(pf) => {
@@ -2049,6 +2529,8 @@ fn draw_text_input(
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
                     single_line: text_input.single_line(),
+                    writing_mode: TextWritingMode::HorizontalTb,
+                    max_lines: None,
                 };
 
                 self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
@@ -2069,6 +2551,8 @@ fn draw_text_input(
                     wrap: text_input.wrap(),
                     overflow: TextOverflow::Clip,
                     single_line: text_input.single_line(),
+                    writing_mode: TextWritingMode::HorizontalTb,
+                    max_lines: None,
                 };
 
                 self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
@@ -2218,7 +2702,7 @@ fn draw_cached_pixmap(
 
     fn draw_string(&mut self, string: &str, color: Color) {
         let font_request = Default::default();
-        let font = fonts::match_font(&font_request, self.scale_factor);
+        let font = fonts::match_font(&font_request, string, self.scale_factor);
         let clip = self.current_state.clip.cast() * self.scale_factor;
 
         match font {
@@ -2235,6 +2719,8 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     wrap: Default::default(),
                     overflow: Default::default(),
                     single_line: false,
+                    writing_mode: TextWritingMode::HorizontalTb,
+                    max_lines: None,
                 };
 
                 self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
@@ -2253,6 +2739,8 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     wrap: Default::default(),
                     overflow: Default::default(),
                     single_line: false,
+                    writing_mode: TextWritingMode::HorizontalTb,
+                    max_lines: None,
                 };
 
                 self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
@@ -10,8 +10,11 @@
 use crate::graphics::{PixelFormat, Rgb8Pixel};
 use crate::lengths::{PointLengths, SizeLengths};
 use crate::Color;
+use alloc::vec::Vec;
 use derive_more::{Add, Mul, Sub};
 use integer_sqrt::IntegerSquareRoot;
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
 
 /// Draw one line of the texture in the line buffer
 ///
@@ -309,6 +312,103 @@ fn fetch_blend_pixel(
     }
 }
 
+/// This is an integer shifted by 4 bits.
+/// Note: this is not a "fixed point" because multiplication and sqrt operation operate to
+/// the shifted integer
+#[derive(Clone, Copy, PartialEq, Ord, PartialOrd, Eq, Add, Sub, Mul)]
+struct Shifted(u32);
+impl Shifted {
+    const ONE: Self = Shifted(1 << 4);
+    #[track_caller]
+    fn new(value: impl TryInto<u32> + core::fmt::Debug + Copy) -> Self {
+        Self(value.try_into().unwrap_or_else(|_| panic!("Overflow {value:?}")) << 4)
+    }
+    fn floor(self) -> u32 {
+        self.0 >> 4
+    }
+    fn ceil(self) -> u32 {
+        (self.0 + Self::ONE.0 - 1) >> 4
+    }
+    fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+    fn sqrt(self) -> Self {
+        Self(self.0.integer_sqrt())
+    }
+}
+impl core::ops::Mul for Shifted {
+    type Output = Shifted;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+/// The intersections of a line of pixels with a rounded corner, as distance from the edge of the
+/// rectangle: the outer curve crosses the line between `x1` and `x2`, and the inner curve of the
+/// border between `x3` and `x4`.
+type CornerLine = (Shifted, Shifted, Shifted, Shifted);
+
+/// Computes the [`CornerLine`] of a corner with radius `r` and the given border width, for the line
+/// that is `y` pixels away from the edge of the rectangle.
+fn compute_corner_line(r: i16, border: i16, y: i16) -> CornerLine {
+    const ONE: Shifted = Shifted::ONE;
+    let border = Shifted::new(border);
+    let r = Shifted::new(r);
+    // `y` is how far away from the center of the circle the current line is.
+    let y = r - Shifted::new(y);
+    // Circle equation: x = √(r² - y²)
+    // Coordinate from the left edge: x' = r - x
+    let x2 = r - (r * r).saturating_sub(y * y).sqrt();
+    let x1 = r - (r * r).saturating_sub((y - ONE) * (y - ONE)).sqrt();
+    let r2 = r.saturating_sub(border);
+    let x4 = r - (r2 * r2).saturating_sub(y * y).sqrt();
+    let x3 = r - (r2 * r2).saturating_sub((y - ONE) * (y - ONE)).sqrt();
+    (x1, x2, x3, x4)
+}
+
+/// Cache of the anti-aliased profile of rounded corners, keyed by radius and border width.
+///
+/// Computing the corner of a rounded rectangle requires several square roots for every line,
+/// which dominates the rendering time of rounded rectangles and borders on microcontrollers.
+/// The profile doesn't depend on the colors, so one entry serves all rectangles with the same
+/// radius and border width, and the cache is kept across frames by the [`super::SoftwareRenderer`].
+#[derive(Default)]
+pub(super) struct RoundedCornerCache {
+    /// (radius, border width, one `CornerLine` per line of the corner).
+    /// The most recently used entry is the last one.
+    entries: Vec<(i16, i16, Vec<CornerLine>)>,
+}
+
+impl RoundedCornerCache {
+    /// The maximum number of distinct corners that are kept in the cache
+    const MAX_ENTRIES: usize = 8;
+    /// Larger corners are computed on each line so that the cache stays small
+    const MAX_RADIUS: i16 = 128;
+
+    fn corner_line(&mut self, radius: i16, border: i16, y: i16) -> CornerLine {
+        if radius > Self::MAX_RADIUS || !(0..radius).contains(&y) {
+            return compute_corner_line(radius, border, y);
+        }
+        let index = match self.entries.iter().rposition(|(r, b, _)| *r == radius && *b == border) {
+            Some(index) if index + 1 == self.entries.len() => index,
+            Some(index) => {
+                let entry = self.entries.remove(index);
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+            None => {
+                if self.entries.len() >= Self::MAX_ENTRIES {
+                    self.entries.remove(0);
+                }
+                let lines = (0..radius).map(|y| compute_corner_line(radius, border, y)).collect();
+                self.entries.push((radius, border, lines));
+                self.entries.len() - 1
+            }
+        };
+        self.entries[index].2[y as usize]
+    }
+}
+
 /// draw one line of the rounded rectangle in the line buffer
 #[allow(clippy::unnecessary_cast)] // Coord
 pub(super) fn draw_rounded_rectangle_line(
@@ -318,37 +418,8 @@ pub(super) fn draw_rounded_rectangle_line(
     line_buffer: &mut [impl TargetPixel],
     extra_left_clip: i16,
     extra_right_clip: i16,
+    corner_cache: &mut RoundedCornerCache,
 ) {
-    /// This is an integer shifted by 4 bits.
-    /// Note: this is not a "fixed point" because multiplication and sqrt operation operate to
-    /// the shifted integer
-    #[derive(Clone, Copy, PartialEq, Ord, PartialOrd, Eq, Add, Sub, Mul)]
-    struct Shifted(u32);
-    impl Shifted {
-        const ONE: Self = Shifted(1 << 4);
-        #[track_caller]
-        pub fn new(value: impl TryInto<u32> + core::fmt::Debug + Copy) -> Self {
-            Self(value.try_into().unwrap_or_else(|_| panic!("Overflow {value:?}")) << 4)
-        }
-        pub fn floor(self) -> u32 {
-            self.0 >> 4
-        }
-        pub fn ceil(self) -> u32 {
-            (self.0 + Self::ONE.0 - 1) >> 4
-        }
-        pub fn saturating_sub(self, other: Self) -> Self {
-            Self(self.0.saturating_sub(other.0))
-        }
-        pub fn sqrt(self) -> Self {
-            Self(self.0.integer_sqrt())
-        }
-    }
-    impl core::ops::Mul for Shifted {
-        type Output = Shifted;
-        fn mul(self, rhs: Self) -> Self::Output {
-            Self(self.0 * rhs.0)
-        }
-    }
     let width = line_buffer.len();
     let y1 = (line - span.origin.y_length()) + rr.top_clip;
     let y2 = (span.origin.y_length() + span.size.height_length() - line) + rr.bottom_clip
@@ -373,19 +444,7 @@ fn mul(self, rhs: Self) -> Self::Output {
         (Shifted::new(width) + Shifted::new(rr.right_clip.get() + extra_right_clip))
             .saturating_sub(x)
     };
-    let calculate_xxxx = |r: i16, y: i16| {
-        let r = Shifted::new(r);
-        // `y` is how far away from the center of the circle the current line is.
-        let y = r - Shifted::new(y);
-        // Circle equation: x = √(r² - y²)
-        // Coordinate from the left edge: x' = r - x
-        let x2 = r - (r * r).saturating_sub(y * y).sqrt();
-        let x1 = r - (r * r).saturating_sub((y - ONE) * (y - ONE)).sqrt();
-        let r2 = r.saturating_sub(border);
-        let x4 = r - (r2 * r2).saturating_sub(y * y).sqrt();
-        let x3 = r - (r2 * r2).saturating_sub((y - ONE) * (y - ONE)).sqrt();
-        (x1, x2, x3, x4)
-    };
+    let mut calculate_xxxx = |r: i16, y: i16| corner_cache.corner_line(r, rr.width.get(), y);
 
     let (x1, x2, x3, x4, x5, x6, x7, x8) = if let Some(r) = rr.radius.as_uniform() {
         let (x1, x2, x3, x4) =
@@ -540,6 +599,24 @@ fn interpolate_color(
     }
 }
 
+/// Draws one line of a conic gradient
+pub(super) fn draw_conic_gradient_line(
+    rect: &PhysicalRect,
+    line: PhysicalLength,
+    g: &super::ConicGradientCommand,
+    buffer: &mut [impl TargetPixel],
+    extra_left_clip: i16,
+) {
+    // The angles are measured from the center of the pixels
+    let dy = line.get() as f32 + 0.5 - g.center.y;
+    let x = (rect.min_x() + extra_left_clip) as f32 + 0.5 - g.center.x;
+    for (i, pix) in buffer.iter_mut().enumerate() {
+        // Clockwise from the top, as y goes down
+        let angle = (x + i as f32).atan2(-dy).to_degrees();
+        pix.blend(g.gradient.color_at_angle(angle).into());
+    }
+}
+
 pub(super) fn draw_gradient_line(
     rect: &PhysicalRect,
     line: PhysicalLength,
@@ -707,6 +784,137 @@ fn blend_slice(slice: &mut [Self], color: PremultipliedRgbaColor) {
     fn background() -> Self {
         Self::from_rgb(0, 0, 0)
     }
+
+    /// Blend a single pixel with a color, mixing the light intensities instead of the sRGB encoded values.
+    ///
+    /// This is called instead of [`Self::blend()`] when gamma-correct blending is enabled with
+    /// [`Window::set_gamma_correct_blending()`](crate::api::Window::set_gamma_correct_blending),
+    /// if [`Self::SUPPORTS_LINEAR_BLENDING`] is `true`. The default implementation calls [`Self::blend()`].
+    fn blend_linear(&mut self, color: PremultipliedRgbaColor) {
+        self.blend(color)
+    }
+
+    /// Whether [`Self::blend_linear()`] is implemented. Otherwise, the software renderer blends in the sRGB
+    /// color space even when gamma-correct blending is enabled, and logs a warning.
+    const SUPPORTS_LINEAR_BLENDING: bool = false;
+}
+
+/// The linear light intensity, between 0 and 65535, of each 8 bit sRGB encoded value.
+const SRGB_TO_LINEAR: [u16; 256] = [
+    0, 20, 40, 60, 80, 99, 119, 139, 159, 179, 199, 219, 241, 264, 288, 313, 340, 367, 396, 427,
+    458, 491, 526, 562, 599, 637, 677, 718, 761, 805, 851, 898, 947, 997, 1048, 1101, 1156, 1212,
+    1270, 1330, 1391, 1453, 1517, 1583, 1651, 1720, 1790, 1863, 1937, 2013, 2090, 2170, 2250, 2333,
+    2418, 2504, 2592, 2681, 2773, 2866, 2961, 3058, 3157, 3258, 3360, 3464, 3570, 3678, 3788, 3900,
+    4014, 4129, 4247, 4366, 4488, 4611, 4736, 4864, 4993, 5124, 5257, 5392, 5530, 5669, 5810, 5953,
+    6099, 6246, 6395, 6547, 6700, 6856, 7014, 7174, 7335, 7500, 7666, 7834, 8004, 8177, 8352, 8528,
+    8708, 8889, 9072, 9258, 9445, 9635, 9828, 10022, 10219, 10417, 10619, 10822, 11028, 11235,
+    11446, 11658, 11873, 12090, 12309, 12530, 12754, 12980, 13209, 13440, 13673, 13909, 14146,
+    14387, 14629, 14874, 15122, 15371, 15623, 15878, 16135, 16394, 16656, 16920, 17187, 17456,
+    17727, 18001, 18277, 18556, 18837, 19121, 19407, 19696, 19987, 20281, 20577, 20876, 21177,
+    21481, 21787, 22096, 22407, 22721, 23038, 23357, 23678, 24002, 24329, 24658, 24990, 25325,
+    25662, 26001, 26344, 26688, 27036, 27386, 27739, 28094, 28452, 28813, 29176, 29542, 29911,
+    30282, 30656, 31033, 31412, 31794, 32179, 32567, 32957, 33350, 33745, 34143, 34544, 34948,
+    35355, 35764, 36176, 36591, 37008, 37429, 37852, 38278, 38706, 39138, 39572, 40009, 40449,
+    40891, 41337, 41785, 42236, 42690, 43147, 43606, 44069, 44534, 45002, 45473, 45947, 46423,
+    46903, 47385, 47871, 48359, 48850, 49344, 49841, 50341, 50844, 51349, 51858, 52369, 52884,
+    53401, 53921, 54445, 54971, 55500, 56032, 56567, 57105, 57646, 58190, 58737, 59287, 59840,
+    60396, 60955, 61517, 62082, 62650, 63221, 63795, 64372, 64952, 65535,
+];
+
+/// Returns the sRGB encoded value whose intensity is the closest to `linear`.
+fn linear_to_srgb(linear: u32) -> u8 {
+    let i = SRGB_TO_LINEAR.partition_point(|l| (*l as u32) < linear);
+    if i == 0 {
+        0
+    } else if i == SRGB_TO_LINEAR.len() {
+        u8::MAX
+    } else if linear - (SRGB_TO_LINEAR[i - 1] as u32) < (SRGB_TO_LINEAR[i] as u32) - linear {
+        (i - 1) as u8
+    } else {
+        i as u8
+    }
+}
+
+/// Returns the color at `t`, between 0 and 1, of a gradient from `color1` to `color2` interpolated in linear light.
+fn mix_linear(color1: Color, color2: Color, t: f32) -> Color {
+    let (alpha1, alpha2) = (color1.alpha() as f32, color2.alpha() as f32);
+    let alpha = alpha1 + (alpha2 - alpha1) * t;
+    if alpha <= 0. {
+        return Color::default();
+    }
+    let channel = |value1: u8, value2: u8| {
+        // Interpolate the intensities premultiplied with the alpha, like the colors are blended
+        let intensity = (SRGB_TO_LINEAR[value1 as usize] as f32 * alpha1 * (1. - t)
+            + SRGB_TO_LINEAR[value2 as usize] as f32 * alpha2 * t)
+            / alpha;
+        linear_to_srgb(intensity as u32)
+    };
+    Color::from_argb_u8(
+        (alpha + 0.5) as u8,
+        channel(color1.red(), color2.red()),
+        channel(color1.green(), color2.green()),
+        channel(color1.blue(), color2.blue()),
+    )
+}
+
+/// The number of parts into which a gradient between two stops is split so that, interpolated in the sRGB color
+/// space, it approximates the interpolation in linear light.
+const LINEAR_LIGHT_GRADIENT_STEPS: usize = 8;
+
+/// Returns the stops of a gradient with stops added between the given ones, so that the gradient, which the
+/// software renderer interpolates in the sRGB color space, looks like it was interpolated in linear light.
+pub(super) fn linear_light_gradient_stops<'a>(
+    stops: impl Iterator<Item = &'a crate::graphics::GradientStop>,
+) -> Vec<crate::graphics::GradientStop> {
+    let mut result = Vec::new();
+    let mut previous: Option<crate::graphics::GradientStop> = None;
+    for stop in stops {
+        if let Some(previous) = previous.filter(|p| p.color != stop.color) {
+            for step in 1..LINEAR_LIGHT_GRADIENT_STEPS {
+                let t = step as f32 / LINEAR_LIGHT_GRADIENT_STEPS as f32;
+                result.push(crate::graphics::GradientStop {
+                    color: mix_linear(previous.color, stop.color, t),
+                    position: previous.position + (stop.position - previous.position) * t,
+                });
+            }
+        }
+        result.push(*stop);
+        previous = Some(*stop);
+    }
+    result
+}
+
+/// Blends `color` over `background` in linear light. Both colors are premultiplied and sRGB encoded.
+pub fn blend_linear(
+    background: PremultipliedRgbaColor,
+    color: PremultipliedRgbaColor,
+) -> PremultipliedRgbaColor {
+    if color.alpha == 0 {
+        return background;
+    }
+    if color.alpha == u8::MAX || background.alpha == 0 {
+        return color;
+    }
+    let unpremultiply = |value: u8, alpha: u8| {
+        ((value as u32 * 255 + alpha as u32 / 2) / alpha as u32).min(255) as usize
+    };
+    let inverse_alpha = (u8::MAX - color.alpha) as u32;
+    let alpha = color.alpha as u32 + background.alpha as u32 * inverse_alpha / 255;
+    let channel = |background_value: u8, value: u8| {
+        // The intensities, premultiplied with the alpha
+        let value = SRGB_TO_LINEAR[unpremultiply(value, color.alpha)] as u32 * color.alpha as u32;
+        let background_value = SRGB_TO_LINEAR[unpremultiply(background_value, background.alpha)]
+            as u32
+            * background.alpha as u32;
+        let mixed = value + background_value / 255 * inverse_alpha;
+        (linear_to_srgb(mixed / alpha) as u32 * alpha / 255) as u8
+    };
+    PremultipliedRgbaColor {
+        red: channel(background.red, color.red),
+        green: channel(background.green, color.green),
+        blue: channel(background.blue, color.blue),
+        alpha: alpha as u8,
+    }
 }
 
 impl TargetPixel for crate::graphics::image::Rgb8Pixel {
@@ -720,6 +928,15 @@ fn blend(&mut self, color: PremultipliedRgbaColor) {
     fn from_rgb(r: u8, g: u8, b: u8) -> Self {
         Self::new(r, g, b)
     }
+
+    fn blend_linear(&mut self, color: PremultipliedRgbaColor) {
+        let background =
+            PremultipliedRgbaColor { red: self.r, green: self.g, blue: self.b, alpha: u8::MAX };
+        let result = blend_linear(background, color);
+        *self = Self::new(result.red, result.green, result.blue);
+    }
+
+    const SUPPORTS_LINEAR_BLENDING: bool = true;
 }
 
 impl TargetPixel for PremultipliedRgbaColor {
@@ -739,6 +956,12 @@ fn from_rgb(r: u8, g: u8, b: u8) -> Self {
     fn background() -> Self {
         Self { red: 0, green: 0, blue: 0, alpha: 0 }
     }
+
+    fn blend_linear(&mut self, color: PremultipliedRgbaColor) {
+        *self = blend_linear(*self, color);
+    }
+
+    const SUPPORTS_LINEAR_BLENDING: bool = true;
 }
 
 /// A 16bit pixel that has 5 red bits, 6 green bits and  5 blue bits
@@ -796,6 +1019,14 @@ fn blend(&mut self, color: PremultipliedRgbaColor) {
     fn from_rgb(r: u8, g: u8, b: u8) -> Self {
         Self(((r as u16 & 0b11111000) << 8) | ((g as u16 & 0b11111100) << 3) | (b as u16 >> 3))
     }
+
+    fn blend_linear(&mut self, color: PremultipliedRgbaColor) {
+        let mut pixel = Rgb8Pixel::from(*self);
+        pixel.blend_linear(color);
+        *self = pixel.into();
+    }
+
+    const SUPPORTS_LINEAR_BLENDING: bool = true;
 }
 
 impl From<Rgb8Pixel> for Rgb565Pixel {
@@ -820,3 +1051,92 @@ fn rgb565() {
     let pix888: Rgb8Pixel = pix565.into();
     assert_eq!(pix565, pix888.into());
 }
+
+#[test]
+fn linear_blending() {
+    let white = PremultipliedRgbaColor { red: 255, green: 255, blue: 255, alpha: 255 };
+    let half_white = PremultipliedRgbaColor { red: 128, green: 128, blue: 128, alpha: 128 };
+    let black = Rgb8Pixel::new(0, 0, 0);
+
+    for value in 0..=255 {
+        assert_eq!(linear_to_srgb(SRGB_TO_LINEAR[value as usize] as u32), value);
+    }
+
+    // Half of the light intensity is brighter than half of the encoded value
+    let mut pixel = black;
+    pixel.blend(half_white);
+    assert_eq!(pixel, Rgb8Pixel::new(128, 128, 128));
+    let mut pixel = black;
+    pixel.blend_linear(half_white);
+    assert_eq!(pixel, Rgb8Pixel::new(188, 188, 188));
+
+    // Opaque and fully transparent colors are not affected
+    let mut pixel = black;
+    pixel.blend_linear(white);
+    assert_eq!(pixel, Rgb8Pixel::new(255, 255, 255));
+    pixel.blend_linear(PremultipliedRgbaColor::default());
+    assert_eq!(pixel, Rgb8Pixel::new(255, 255, 255));
+
+    // Blending onto a transparent pixel keeps the color
+    let mut pixel = PremultipliedRgbaColor::background();
+    pixel.blend_linear(half_white);
+    assert_eq!((pixel.red, pixel.alpha), (half_white.red, half_white.alpha));
+}
+
+#[test]
+fn linear_light_gradient() {
+    use crate::graphics::GradientStop;
+    let black = Color::from_rgb_u8(0, 0, 0);
+    let white = Color::from_rgb_u8(255, 255, 255);
+    let stops = [
+        GradientStop { color: black, position: 0. },
+        GradientStop { color: white, position: 0.5 },
+        GradientStop { color: white, position: 1. },
+    ];
+    let result = linear_light_gradient_stops(stops.iter());
+
+    // Stops are only added between different colors
+    assert_eq!(result.len(), LINEAR_LIGHT_GRADIENT_STEPS + 2);
+    assert_eq!(result.first(), Some(&stops[0]));
+    assert_eq!(result[LINEAR_LIGHT_GRADIENT_STEPS..], stops[1..]);
+    // Half of the light intensity, in the middle, is brighter than half of the encoded value
+    let middle = result[LINEAR_LIGHT_GRADIENT_STEPS / 2];
+    assert_eq!(middle.position, 0.25);
+    assert_eq!(middle.color, Color::from_rgb_u8(188, 188, 188));
+    assert!(result
+        .windows(2)
+        .all(|w| w[0].position < w[1].position && w[0].color.red() <= w[1].color.red()));
+
+    // Transparent stops don't darken the colors next to them
+    let transparent = Color::from_argb_u8(0, 0, 0, 0);
+    let stops = [
+        GradientStop { color: white, position: 0. },
+        GradientStop { color: transparent, position: 1. },
+    ];
+    let result = linear_light_gradient_stops(stops.iter());
+    assert!(result[1..LINEAR_LIGHT_GRADIENT_STEPS].iter().all(|s| s.color.with_alpha(1.) == white));
+}
+
+#[test]
+fn rounded_corner_cache() {
+    let mut cache = RoundedCornerCache::default();
+    for (radius, border) in [(10, 0), (10, 2), (25, 3)] {
+        for y in 0..radius {
+            assert!(cache.corner_line(radius, border, y) == compute_corner_line(radius, border, y));
+        }
+    }
+    assert_eq!(cache.entries.len(), 3);
+    // Large radii are not cached
+    assert!(cache.corner_line(500, 1, 3) == compute_corner_line(500, 1, 3));
+    assert_eq!(cache.entries.len(), 3);
+
+    for radius in 1..20 {
+        cache.corner_line(radius, 1, 0);
+    }
+    assert_eq!(cache.entries.len(), RoundedCornerCache::MAX_ENTRIES);
+    // The least recently used entries were evicted
+    assert_eq!(
+        cache.entries.first().map(|e| e.0),
+        Some(20 - RoundedCornerCache::MAX_ENTRIES as i16)
+    );
+}
@@ -107,7 +107,10 @@ fn cap_height(&self) -> PhysicalLength {
     }
 }
 
-pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
+/// Returns the font to draw `text` with for `request`. With system fonts, the text may be drawn with
+/// a family preferred for the script of its characters, see `slint::set_script_font_families()`.
+#[cfg_attr(not(feature = "software-renderer-systemfonts"), allow(unused_variables))]
+pub fn match_font(request: &FontRequest, text: &str, scale_factor: ScaleFactor) -> Font {
     let requested_weight = request
         .weight
         .and_then(|weight| weight.try_into().ok())
@@ -133,7 +136,7 @@ pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
         Some(bitmap_font) => bitmap_font,
         None => {
             #[cfg(feature = "software-renderer-systemfonts")]
-            if let Some(vectorfont) = systemfonts::match_font(request, scale_factor) {
+            if let Some(vectorfont) = systemfonts::match_font(request, text, scale_factor) {
                 return vectorfont.into();
             }
             if let Some(fallback_bitmap_font) = BITMAP_FONTS.with(|fonts| {
@@ -148,7 +151,7 @@ pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
                 fallback_bitmap_font
             } else {
                 #[cfg(feature = "software-renderer-systemfonts")]
-                return systemfonts::fallbackfont(request, scale_factor).into();
+                return systemfonts::fallbackfont(request, text, scale_factor).into();
                 #[cfg(not(feature = "software-renderer-systemfonts"))]
                 panic!("No font fallback found. The software renderer requires enabling the `EmbedForSoftwareRenderer` option when compiling slint files.")
             }
@@ -194,7 +197,7 @@ pub fn text_size(
     scale_factor: ScaleFactor,
     text_wrap: TextWrap,
 ) -> LogicalSize {
-    let font = match_font(&font_request, scale_factor);
+    let font = match_font(&font_request, text, scale_factor);
     let (longest_line_width, height) = match font {
         Font::PixelFont(pf) => {
             let layout = text_layout_for_font(&pf, &font_request, scale_factor);
@@ -218,11 +221,40 @@ pub fn text_size(
     (PhysicalSize::from_lengths(longest_line_width, height).cast() / scale_factor).cast()
 }
 
+pub fn text_line_ranges(
+    font_request: FontRequest,
+    text: &str,
+    max_width: Option<LogicalLength>,
+    scale_factor: ScaleFactor,
+    text_wrap: TextWrap,
+) -> Vec<core::ops::Range<usize>> {
+    let font = match_font(&font_request, text, scale_factor);
+    match font {
+        Font::PixelFont(pf) => {
+            let layout = text_layout_for_font(&pf, &font_request, scale_factor);
+            layout.line_ranges(
+                text,
+                max_width.map(|max_width| (max_width.cast() * scale_factor).cast()),
+                text_wrap,
+            )
+        }
+        #[cfg(feature = "software-renderer-systemfonts")]
+        Font::VectorFont(vf) => {
+            let layout = text_layout_for_font(&vf, &font_request, scale_factor);
+            layout.line_ranges(
+                text,
+                max_width.map(|max_width| (max_width.cast() * scale_factor).cast()),
+                text_wrap,
+            )
+        }
+    }
+}
+
 pub fn font_metrics(
     font_request: FontRequest,
     scale_factor: ScaleFactor,
 ) -> crate::items::FontMetrics {
-    let font = match_font(&font_request, scale_factor);
+    let font = match_font(&font_request, "", scale_factor);
 
     let ascent: LogicalLength = (font.ascent().cast() / scale_factor).cast();
     let descent: LogicalLength = (font.descent().cast() / scale_factor).cast();
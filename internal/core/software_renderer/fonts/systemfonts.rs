@@ -41,8 +41,38 @@ fn get_or_create_fontdue_font(fontdb: &fontdb::Database, id: fontdb::ID) -> Rc<f
     })
 }
 
+/// Returns the face of the family preferred with `slint::set_script_font_families()` for the
+/// script of the first character of `text` that the face `id` doesn't cover, if any. The software
+/// renderer draws a text with a single font, so that face is used for the whole text.
+fn script_font_for_text(
+    fonts: &sharedfontdb::FontDatabase,
+    id: fontdb::ID,
+    query: fontdb::Query<'_>,
+    text: &str,
+) -> Option<fontdb::ID> {
+    use unicode_script::UnicodeScript;
+    if crate::graphics::all_script_font_families().is_empty() {
+        return None;
+    }
+    fonts
+        .with_face_data(id, |face_data, font_index| {
+            let face = sharedfontdb::ttf_parser::Face::parse(face_data, font_index).ok()?;
+            text.chars()
+                .filter(|ch| {
+                    !ch.is_control() && !ch.is_whitespace() && face.glyph_index(*ch).is_none()
+                })
+                .find_map(|ch| {
+                    crate::graphics::script_font_families(ch.script().short_name())
+                        .iter()
+                        .find_map(|family| fonts.query_with_family(query, Some(family)))
+                })
+        })
+        .flatten()
+}
+
 pub fn match_font(
     request: &super::FontRequest,
+    text: &str,
     scale_factor: super::ScaleFactor,
 ) -> Option<VectorFont> {
     request.family.as_ref().and_then(|family_str| {
@@ -54,14 +84,22 @@ pub fn match_font(
         sharedfontdb::FONT_DB.with(|fonts| {
             let borrowed_fontdb = fonts.borrow();
             borrowed_fontdb.query_with_family(query, Some(family_str)).map(|font_id| {
+                let font_id =
+                    script_font_for_text(&borrowed_fontdb, font_id, query, text).unwrap_or(font_id);
                 let fontdue_font = get_or_create_fontdue_font(&borrowed_fontdb, font_id);
                 VectorFont::new(font_id, fontdue_font.clone(), requested_pixel_size)
+                    .with_features(&request.features)
+                    .with_synthesis(borrowed_fontdb.synthesis(font_id, &query))
             })
         })
     })
 }
 
-pub fn fallbackfont(font_request: &super::FontRequest, scale_factor: ScaleFactor) -> VectorFont {
+pub fn fallbackfont(
+    font_request: &super::FontRequest,
+    text: &str,
+    scale_factor: ScaleFactor,
+) -> VectorFont {
     let requested_pixel_size: PhysicalLength =
         (font_request.pixel_size.unwrap_or(super::DEFAULT_FONT_SIZE).cast() * scale_factor).cast();
 
@@ -71,34 +109,55 @@ pub fn fallbackfont(font_request: &super::FontRequest, scale_factor: ScaleFactor
         let fallback_font_id = fonts
             .query_with_family(query, None)
             .expect("fatal: query for fallback font returned empty font list");
+        let fallback_font_id =
+            script_font_for_text(&fonts, fallback_font_id, query, text).unwrap_or(fallback_font_id);
 
         let fontdue_font = get_or_create_fontdue_font(&fonts, fallback_font_id);
         VectorFont::new(fallback_font_id, fontdue_font, requested_pixel_size)
+            .with_features(&font_request.features)
+            .with_synthesis(fonts.synthesis(fallback_font_id, &query))
     })
 }
 
 pub fn register_font_from_memory(data: &'static [u8]) -> Result<(), Box<dyn std::error::Error>> {
-    sharedfontdb::FONT_DB.with_borrow_mut(|fonts| {
-        fonts.make_mut().load_font_source(fontdb::Source::Binary(std::sync::Arc::new(data)))
-    });
-    Ok(())
+    sharedfontdb::register_font_from_memory(data)
 }
 
 #[cfg(not(target_family = "wasm"))]
 pub fn register_font_from_path(path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
-    let requested_path = path.canonicalize().unwrap_or_else(|_| path.to_owned());
-    sharedfontdb::FONT_DB.with_borrow_mut(|fonts| {
-        for face_info in fonts.faces() {
-            match &face_info.source {
-                fontdb::Source::Binary(_) => {}
-                fontdb::Source::File(loaded_path) | fontdb::Source::SharedFile(loaded_path, ..) => {
-                    if *loaded_path == requested_path {
-                        return Ok(());
-                    }
-                }
-            }
-        }
-
-        fonts.make_mut().load_font_file(requested_path).map_err(|e| e.into())
-    })
+    sharedfontdb::register_font_from_path(path)
+}
+
+pub fn unregister_font(family: &str) -> Result<(), Box<dyn std::error::Error>> {
+    sharedfontdb::unregister_font(family)?;
+    drop_removed_fontdue_fonts();
+    Ok(())
+}
+
+pub fn replace_font_from_memory(
+    family: &str,
+    data: &'static [u8],
+) -> Result<(), Box<dyn std::error::Error>> {
+    sharedfontdb::replace_font_from_memory(family, data)?;
+    drop_removed_fontdue_fonts();
+    Ok(())
+}
+
+#[cfg(not(target_family = "wasm"))]
+pub fn replace_font_from_path(
+    family: &str,
+    path: &std::path::Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    sharedfontdb::replace_font_from_path(family, path)?;
+    drop_removed_fontdue_fonts();
+    Ok(())
+}
+
+/// Drops the fontdue fonts of the faces removed from the font database.
+fn drop_removed_fontdue_fonts() {
+    sharedfontdb::FONT_DB.with_borrow(|fonts| {
+        FONTDUE_FONTS.with_borrow_mut(|fontdue_fonts| {
+            fontdue_fonts.retain(|id, _| fonts.face(*id).is_some())
+        })
+    });
 }
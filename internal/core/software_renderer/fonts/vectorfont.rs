@@ -7,7 +7,7 @@
 use crate::software_renderer::fixed::Fixed;
 use crate::software_renderer::PhysicalLength;
 use crate::textlayout::{Glyph, TextShaper};
-use i_slint_common::sharedfontdb::{self, fontdb};
+use i_slint_common::sharedfontdb::{self, fontdb, FontSynthesis};
 
 use super::RenderableGlyph;
 
@@ -16,7 +16,7 @@
 type FontLength = euclid::Length<i32, FontUnit>;
 type FontScaleFactor = euclid::Scale<f32, FontUnit, PhysicalPx>;
 
-type GlyphCacheKey = (fontdb::ID, PhysicalLength, core::num::NonZeroU16);
+type GlyphCacheKey = (fontdb::ID, PhysicalLength, FontSynthesis, core::num::NonZeroU16);
 
 struct RenderableGlyphWeightScale;
 
@@ -55,6 +55,8 @@ pub struct VectorFont {
     pixel_size: PhysicalLength,
     x_height: PhysicalLength,
     cap_height: PhysicalLength,
+    features: Vec<rustybuzz::Feature>,
+    synthesis: FontSynthesis,
 }
 
 impl VectorFont {
@@ -86,11 +88,131 @@ pub fn new(
                         pixel_size,
                         x_height: (x_height.cast() * scale).cast(),
                         cap_height: (cap_height.cast() * scale).cast(),
+                        features: Vec::new(),
+                        synthesis: Default::default(),
                     }
                 })
                 .unwrap()
         })
     }
+
+    /// Sets the OpenType features applied when shaping text with this font.
+    pub fn with_features(mut self, features: &[crate::graphics::FontFeature]) -> Self {
+        self.features = features
+            .iter()
+            .map(|f| {
+                rustybuzz::Feature::new(rustybuzz::ttf_parser::Tag::from_bytes(&f.tag), f.value, ..)
+            })
+            .collect();
+        self
+    }
+
+    /// Sets the styles to synthesize when rendering glyphs, because the face lacks them.
+    pub fn with_synthesis(mut self, synthesis: FontSynthesis) -> Self {
+        self.synthesis = synthesis;
+        self
+    }
+}
+
+impl VectorFont {
+    /// Returns the outline of the glyphs of `text`, with the lines separated by '\n', in logical
+    /// coordinates relative to the top-left corner of the text.
+    ///
+    /// The text is shaped and positioned in font design units instead of the whole pixels used
+    /// for rendering, so that the outline is exact at any scale.
+    pub fn text_outline(
+        &self,
+        text: &str,
+        scale_factor: crate::lengths::ScaleFactor,
+    ) -> Vec<crate::api::PathCommand> {
+        use crate::api::{LogicalPosition, PathCommand};
+
+        struct PathBuilder<'a> {
+            commands: &'a mut Vec<PathCommand>,
+            /// The origin of the glyph on the baseline, in logical pixels.
+            origin: (f32, f32),
+            /// Converts font design units to logical pixels.
+            scale: f32,
+            slant: f32,
+        }
+
+        impl PathBuilder<'_> {
+            fn point(&self, x: f32, y: f32) -> LogicalPosition {
+                LogicalPosition::new(
+                    self.origin.0 + (x + y * self.slant) * self.scale,
+                    self.origin.1 - y * self.scale,
+                )
+            }
+        }
+
+        impl rustybuzz::ttf_parser::OutlineBuilder for PathBuilder<'_> {
+            fn move_to(&mut self, x: f32, y: f32) {
+                self.commands.push(PathCommand::MoveTo(self.point(x, y)));
+            }
+
+            fn line_to(&mut self, x: f32, y: f32) {
+                self.commands.push(PathCommand::LineTo(self.point(x, y)));
+            }
+
+            fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+                let command =
+                    PathCommand::QuadraticTo { control: self.point(x1, y1), to: self.point(x, y) };
+                self.commands.push(command);
+            }
+
+            fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+                let command = PathCommand::CubicTo {
+                    control1: self.point(x1, y1),
+                    control2: self.point(x2, y2),
+                    to: self.point(x, y),
+                };
+                self.commands.push(command);
+            }
+
+            fn close(&mut self) {
+                self.commands.push(PathCommand::Close);
+            }
+        }
+
+        let mut commands = Vec::new();
+        sharedfontdb::FONT_DB.with(|db| {
+            db.borrow().with_face_data(self.id, |face_data, font_index| {
+                let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                let scale = self.scale.get() / scale_factor.get();
+                let ascender = face.ascender() as f32;
+                let line_height = face.height() as f32;
+                let rb_face = rustybuzz::Face::from_face(face.clone());
+                let mut builder = PathBuilder {
+                    commands: &mut commands,
+                    origin: (0., 0.),
+                    scale,
+                    slant: if self.synthesis.oblique { FontSynthesis::OBLIQUE_SLANT } else { 0. },
+                };
+
+                for (line_index, line) in text.split('\n').enumerate() {
+                    let mut buffer = rustybuzz::UnicodeBuffer::new();
+                    buffer.push_str(line);
+                    let glyph_buffer = rustybuzz::shape(&rb_face, &self.features, buffer);
+                    let baseline_y = line_index as f32 * line_height + ascender;
+                    let mut pen_x = 0.;
+                    for (info, position) in
+                        glyph_buffer.glyph_infos().iter().zip(glyph_buffer.glyph_positions())
+                    {
+                        builder.origin = (
+                            (pen_x + position.x_offset as f32) * scale,
+                            (baseline_y - position.y_offset as f32) * scale,
+                        );
+                        face.outline_glyph(
+                            rustybuzz::ttf_parser::GlyphId(info.glyph_id as u16),
+                            &mut builder,
+                        );
+                        pen_x += position.x_advance as f32;
+                    }
+                }
+            })
+        });
+        commands
+    }
 }
 
 impl TextShaper for VectorFont {
@@ -110,7 +232,7 @@ fn shape_text<GlyphStorage: core::iter::Extend<Glyph<PhysicalLength>>>(
                     let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
                     let rb_face = rustybuzz::Face::from_face(face);
 
-                    let glyph_buffer = rustybuzz::shape(&rb_face, &[], buffer);
+                    let glyph_buffer = rustybuzz::shape(&rb_face, &self.features, buffer);
 
                     let output_glyph_generator = glyph_buffer
                         .glyph_infos()
@@ -196,7 +318,7 @@ fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<super::Rendera
         GLYPH_CACHE.with(|cache| {
             let mut cache = cache.borrow_mut();
 
-            let cache_key = (self.id, self.pixel_size, glyph_id);
+            let cache_key = (self.id, self.pixel_size, self.synthesis, glyph_id);
 
             if let Some(entry) = cache.get(&cache_key) {
                 Some(entry.clone())
@@ -204,16 +326,31 @@ fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<super::Rendera
                 let (metrics, alpha_map) =
                     self.fontdue_font.rasterize_indexed(glyph_id.get(), self.pixel_size.get() as _);
 
-                let alpha_map: Rc<[u8]> = alpha_map.into();
+                let mut bitmap = GlyphBitmap {
+                    xmin: metrics.xmin,
+                    ymin: metrics.ymin,
+                    width: metrics.width,
+                    height: metrics.height,
+                    alpha_map,
+                };
+                if self.synthesis.bold {
+                    let strength = self.pixel_size.get() as f32 * FontSynthesis::BOLD_STRENGTH;
+                    bitmap = bitmap.embolden((strength.round() as usize).max(1));
+                }
+                if self.synthesis.oblique {
+                    bitmap = bitmap.slant(FontSynthesis::OBLIQUE_SLANT);
+                }
+
+                let alpha_map: Rc<[u8]> = bitmap.alpha_map.into();
 
                 let glyph = super::RenderableGlyph {
-                    x: Fixed::from_integer(metrics.xmin.try_into().unwrap()),
-                    y: Fixed::from_integer(metrics.ymin.try_into().unwrap()),
-                    width: PhysicalLength::new(metrics.width.try_into().unwrap()),
-                    height: PhysicalLength::new(metrics.height.try_into().unwrap()),
+                    x: Fixed::from_integer(bitmap.xmin.try_into().unwrap()),
+                    y: Fixed::from_integer(bitmap.ymin.try_into().unwrap()),
+                    width: PhysicalLength::new(bitmap.width.try_into().unwrap()),
+                    height: PhysicalLength::new(bitmap.height.try_into().unwrap()),
                     alpha_map: alpha_map.into(),
                     sdf: false,
-                    pixel_stride: metrics.width.try_into().unwrap(),
+                    pixel_stride: bitmap.width.try_into().unwrap(),
                 };
 
                 cache.put_with_weight(cache_key, glyph.clone()).ok();
@@ -226,3 +363,81 @@ fn scale_delta(&self) -> super::Fixed<u16, 8> {
         super::Fixed::from_integer(1)
     }
 }
+
+/// The coverage of a rasterized glyph, with the rows from top to bottom. `xmin` and `ymin` are the
+/// offset of the bottom-left corner from the origin of the glyph on the baseline, with y up.
+struct GlyphBitmap {
+    xmin: i32,
+    ymin: i32,
+    width: usize,
+    height: usize,
+    alpha_map: Vec<u8>,
+}
+
+impl GlyphBitmap {
+    /// Thickens the vertical strokes by `amount` pixels, to synthesize a bold weight.
+    fn embolden(self, amount: usize) -> Self {
+        if self.width == 0 || self.height == 0 {
+            return self;
+        }
+        let width = self.width + amount;
+        let mut alpha_map = alloc::vec![0u8; width * self.height];
+        for (source, target) in
+            self.alpha_map.chunks_exact(self.width).zip(alpha_map.chunks_exact_mut(width))
+        {
+            for (x, coverage) in source.iter().enumerate() {
+                for target in &mut target[x..=x + amount] {
+                    *target = (*target).max(*coverage);
+                }
+            }
+        }
+        Self { width, alpha_map, ..self }
+    }
+
+    /// Shifts the rows to the right in proportion to their height above the baseline, to
+    /// synthesize an oblique style. Fractional shifts are blended between neighboring pixels.
+    fn slant(self, slant: f32) -> Self {
+        if self.width == 0 || self.height == 0 {
+            return self;
+        }
+        // The shift of the center of a row, with row 0 at the top
+        let shift = |row: usize| (self.ymin as f32 + (self.height - row) as f32 - 0.5) * slant;
+        let min_shift = shift(self.height - 1).min(shift(0)).floor() as i32;
+        let max_shift = shift(self.height - 1).max(shift(0)).ceil() as i32;
+        let width = self.width + (max_shift - min_shift) as usize + 1;
+        let mut alpha_map = alloc::vec![0u8; width * self.height];
+        for (row, (source, target)) in self
+            .alpha_map
+            .chunks_exact(self.width)
+            .zip(alpha_map.chunks_exact_mut(width))
+            .enumerate()
+        {
+            let offset = shift(row) - min_shift as f32;
+            let whole = offset.floor() as usize;
+            let fraction = offset - offset.floor();
+            for (x, coverage) in source.iter().enumerate() {
+                let coverage = *coverage as f32;
+                let left = &mut target[x + whole];
+                *left = (*left as f32 + coverage * (1. - fraction)).min(255.) as u8;
+                let right = &mut target[x + whole + 1];
+                *right = (*right as f32 + coverage * fraction).min(255.) as u8;
+            }
+        }
+        Self { xmin: self.xmin + min_shift, width, alpha_map, ..self }
+    }
+}
+
+#[test]
+fn synthesized_glyph_bitmaps() {
+    let bitmap =
+        || GlyphBitmap { xmin: 0, ymin: 0, width: 2, height: 2, alpha_map: vec![255, 0, 0, 255] };
+
+    let bold = bitmap().embolden(1);
+    assert_eq!(bold.width, 3);
+    assert_eq!(bold.alpha_map, vec![255, 255, 0, 0, 255, 255]);
+
+    // The top row is shifted by three quarters of a pixel, the bottom row by a quarter
+    let oblique = bitmap().slant(0.5);
+    assert_eq!((oblique.xmin, oblique.width), (0, 4));
+    assert_eq!(oblique.alpha_map, vec![63, 191, 0, 0, 0, 191, 63, 0]);
+}
@@ -21,6 +21,7 @@ pub struct SceneVectors {
     pub rounded_rectangles: Vec<RoundedRectangle>,
     pub shared_buffers: Vec<SharedBufferCommand>,
     pub gradients: Vec<GradientCommand>,
+    pub conic_gradients: Vec<ConicGradientCommand>,
 }
 
 pub struct Scene {
@@ -280,6 +281,10 @@ pub enum SceneCommand {
     Gradient {
         gradient_index: u16,
     },
+    /// gradient_index is an index in the [`SceneVectors::conic_gradients`] array
+    ConicGradient {
+        gradient_index: u16,
+    },
 }
 
 pub struct SceneTexture<'a> {
@@ -430,3 +435,12 @@ pub struct GradientCommand {
     pub top_clip: PhysicalLength,
     pub bottom_clip: PhysicalLength,
 }
+
+/// Fills the rectangle with the colors of a conic gradient around `center`
+#[derive(Debug)]
+pub struct ConicGradientCommand {
+    /// The gradient, with the opacity applied to the colors and the angle rotated like the screen
+    pub gradient: crate::graphics::ConicGradientBrush,
+    /// The center of the gradient in the buffer
+    pub center: euclid::Point2D<f32, crate::lengths::PhysicalPx>,
+}
@@ -26,7 +26,9 @@
 
 use euclid::num::{One, Zero};
 
-use crate::items::{TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap};
+use crate::items::{
+    TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap, TextWritingMode,
+};
 
 #[cfg(feature = "unicode-linebreak")]
 mod linebreak_unicode;
@@ -40,6 +42,10 @@
 
 mod fragments;
 mod glyphclusters;
+mod graphemes;
+pub use graphemes::{
+    grapheme_boundary_at_or_before, next_grapheme_boundary, prev_grapheme_boundary,
+};
 mod shaping;
 use shaping::ShapeBuffer;
 pub use shaping::{AbstractFont, FontMetrics, Glyph, TextShaper};
@@ -78,6 +84,20 @@ pub fn text_size(
 
         (max_line_width, self.font.height() * line_count.into())
     }
+
+    // Returns the byte ranges of the lines of the given text, without trailing whitespace, when
+    // rendered with the specified font and optionally constrained by the provided `max_width`.
+    pub fn line_ranges(
+        &self,
+        text: &str,
+        max_width: Option<Font::Length>,
+        text_wrap: TextWrap,
+    ) -> Vec<core::ops::Range<usize>> {
+        let shape_buffer = ShapeBuffer::new(self, text);
+        TextLineBreaker::<Font>::new(text, &shape_buffer, max_width, None, text_wrap)
+            .map(|line| line.byte_range)
+            .collect()
+    }
 }
 
 pub struct PositionedGlyph<Length> {
@@ -88,6 +108,20 @@ pub struct PositionedGlyph<Length> {
     pub text_byte_offset: usize,
 }
 
+/// The position of the cursor and the line it's on, as returned by
+/// [`TextParagraphLayout::cursor_line_for_byte_offset`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CursorLine<Length> {
+    /// The leading edge of the glyph at the cursor position
+    pub cursor_pos: (Length, Length),
+    /// The index of the line, starting at 0 for the first line
+    pub line_index: usize,
+    /// The x coordinate where the line starts
+    pub line_x: Length,
+    /// The width of the line, including trailing whitespace
+    pub line_width: Length,
+}
+
 pub struct TextParagraphLayout<'a, Font: AbstractFont> {
     pub string: &'a str,
     pub layout: TextLayout<'a, Font>,
@@ -98,6 +132,9 @@ pub struct TextParagraphLayout<'a, Font: AbstractFont> {
     pub wrap: TextWrap,
     pub overflow: TextOverflow,
     pub single_line: bool,
+    pub writing_mode: TextWritingMode,
+    /// When set, the text is truncated after that many lines, and the last line ends with `…`.
+    pub max_lines: Option<usize>,
 }
 
 impl<'a, Font: AbstractFont> TextParagraphLayout<'a, Font> {
@@ -115,9 +152,13 @@ pub fn layout_lines<R>(
         ) -> core::ops::ControlFlow<R>,
         selection: Option<core::ops::Range<usize>>,
     ) -> Result<Font::Length, R> {
+        if self.writing_mode == TextWritingMode::VerticalRl {
+            return self.layout_columns(line_callback);
+        }
+
         let wrap = self.wrap != TextWrap::NoWrap;
         let elide = self.overflow == TextOverflow::Elide;
-        let elide_glyph = if elide {
+        let elide_glyph = if elide || self.max_lines.is_some() {
             self.layout.font.glyph_for_char('…').filter(|glyph| glyph.glyph_id.is_some())
         } else {
             None
@@ -132,7 +173,10 @@ pub fn layout_lines<R>(
                 self.string,
                 &shape_buffer,
                 if wrap { Some(self.max_width) } else { None },
-                if elide { Some(self.layout.font.max_lines(self.max_height)) } else { None },
+                match (elide.then(|| self.layout.font.max_lines(self.max_height)), self.max_lines) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                },
                 self.wrap,
             )
         };
@@ -156,13 +200,15 @@ pub fn layout_lines<R>(
         };
 
         let mut y = baseline_y;
+        let mut line_count = 0;
 
         let mut process_line = |line: &TextLine<Font::Length>, glyphs: &[Glyph<Font::Length>]| {
+            line_count += 1;
             let elide_long_line =
                 elide && (self.single_line || !wrap) && line.text_width > self.max_width;
-            let elide_last_line = elide
-                && line.glyph_range.end < glyphs.len()
-                && y + self.layout.font.height() * two > self.max_height;
+            let elide_last_line = line.glyph_range.end < glyphs.len()
+                && ((elide && y + self.layout.font.height() * two > self.max_height)
+                    || self.max_lines == Some(line_count));
 
             let text_width = || {
                 if elide_long_line || elide_last_line {
@@ -275,37 +321,148 @@ pub fn layout_lines<R>(
         Ok(baseline_y)
     }
 
+    /// Layout the string in vertical columns for [`TextWritingMode::VerticalRl`], and call the
+    /// `column_callback` with each column like `layout_lines` does with lines. The columns are
+    /// ordered from right to left, and the glyphs within a column are positioned from top to
+    /// bottom in square cells of the height of the font. Columns wrap at `max_height` unless
+    /// wrapping is disabled.
+    ///
+    /// Only the cells that fit in `max_height`, plus a partially visible one, are laid out, and the
+    /// layout stops at the first column past the left edge, which also keeps the positions within
+    /// the range of the length type.
+    fn layout_columns<R>(
+        &self,
+        mut column_callback: impl FnMut(
+            &mut dyn Iterator<Item = PositionedGlyph<Font::Length>>,
+            Font::Length,
+            Font::Length,
+            &TextLine<Font::Length>,
+            Option<core::ops::Range<Font::Length>>,
+        ) -> core::ops::ControlFlow<R>,
+    ) -> Result<Font::Length, R> {
+        let font = self.layout.font;
+        let cell = font.height();
+        let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
+        let visible_rows = font.max_lines(self.max_height).max(1);
+        let rows_per_column = if self.wrap != TextWrap::NoWrap { visible_rows } else { usize::MAX };
+        // The length of `rows` cells, at most `max_height`
+        let rows_length = |rows: usize| {
+            let rows = i16::try_from(rows.min(visible_rows)).unwrap_or(i16::MAX);
+            cell * rows.into()
+        };
+
+        let mut column_x = self.max_width - cell;
+        let mut paragraph_start = 0;
+        for paragraph in self.string.split('\n') {
+            if column_x + cell <= Font::Length::zero() {
+                break;
+            }
+            let chars = paragraph
+                .char_indices()
+                .filter(|(_, ch)| *ch != '\r')
+                .map(|(offset, ch)| (paragraph_start + offset, ch))
+                .collect::<Vec<_>>();
+            let paragraph_end = paragraph_start + paragraph.len();
+
+            let mut column_start: usize = 0;
+            loop {
+                let column_chars = &chars
+                    [column_start..chars.len().min(column_start.saturating_add(rows_per_column))];
+                let byte_range = match (column_chars.first(), column_chars.last()) {
+                    (Some((start, _)), Some((end, ch))) => *start..*end + ch.len_utf8(),
+                    _ => paragraph_end..paragraph_end,
+                };
+                let column_height = rows_length(column_chars.len());
+                let column_y = match self.vertical_alignment {
+                    TextVerticalAlignment::Top => Font::Length::zero(),
+                    TextVerticalAlignment::Center => self.max_height / two - column_height / two,
+                    TextVerticalAlignment::Bottom => self.max_height - column_height,
+                };
+
+                let mut glyphs = column_chars.iter().take(visible_rows + 1).enumerate().filter_map(
+                    |(row, (offset, ch))| {
+                        let glyph = font
+                            .glyph_for_char(vertical_presentation_form(*ch))
+                            .filter(|glyph| glyph.glyph_id.is_some())
+                            .or_else(|| font.glyph_for_char(*ch))?;
+                        Some(PositionedGlyph {
+                            x: (cell - glyph.advance) / two,
+                            y: rows_length(row),
+                            advance: cell,
+                            glyph_id: glyph.glyph_id?,
+                            text_byte_offset: *offset,
+                        })
+                    },
+                );
+                let line = TextLine::for_byte_range(byte_range, column_height);
+                if let core::ops::ControlFlow::Break(break_val) =
+                    column_callback(&mut glyphs, column_x, column_y, &line, None)
+                {
+                    return Err(break_val);
+                }
+                column_x = column_x - cell;
+
+                column_start += column_chars.len();
+                if column_start >= chars.len() || column_x + cell <= Font::Length::zero() {
+                    break;
+                }
+            }
+            paragraph_start = paragraph_end + 1;
+        }
+
+        Ok(Font::Length::zero())
+    }
+
     /// Returns the leading edge of the glyph at the given byte offset
     pub fn cursor_pos_for_byte_offset(&self, byte_offset: usize) -> (Font::Length, Font::Length) {
-        let mut last_glyph_right_edge = Font::Length::zero();
-        let mut last_line_y = Font::Length::zero();
+        self.cursor_line_for_byte_offset(byte_offset).cursor_pos
+    }
+
+    /// Returns the leading edge of the glyph at the given byte offset, together with the line it's on
+    pub fn cursor_line_for_byte_offset(&self, byte_offset: usize) -> CursorLine<Font::Length> {
+        let mut last_line = CursorLine {
+            cursor_pos: (Font::Length::zero(), Font::Length::zero()),
+            line_index: 0,
+            line_x: Font::Length::zero(),
+            line_width: Font::Length::zero(),
+        };
+        let mut line_index = 0;
 
         match self.layout_lines(
             |glyphs, line_x, line_y, line, _| {
-                last_glyph_right_edge = euclid::approxord::min(
-                    self.max_width,
-                    line_x + line.width_including_trailing_whitespace(),
-                );
-                last_line_y = line_y;
+                let line_width = line.width_including_trailing_whitespace();
+                last_line = CursorLine {
+                    cursor_pos: (
+                        euclid::approxord::min(self.max_width, line_x + line_width),
+                        line_y,
+                    ),
+                    line_index,
+                    line_x,
+                    line_width,
+                };
+                line_index += 1;
                 if byte_offset >= line.byte_range.end + line.trailing_whitespace_bytes {
                     return core::ops::ControlFlow::Continue(());
                 }
 
                 for positioned_glyph in glyphs {
                     if positioned_glyph.text_byte_offset == byte_offset {
-                        return core::ops::ControlFlow::Break((
-                            euclid::approxord::min(self.max_width, line_x + positioned_glyph.x),
-                            last_line_y,
-                        ));
+                        return core::ops::ControlFlow::Break(CursorLine {
+                            cursor_pos: (
+                                euclid::approxord::min(self.max_width, line_x + positioned_glyph.x),
+                                line_y,
+                            ),
+                            ..last_line
+                        });
                     }
                 }
 
-                core::ops::ControlFlow::Break((last_glyph_right_edge, last_line_y))
+                core::ops::ControlFlow::Break(last_line)
             },
             None,
         ) {
-            Ok(_) => (last_glyph_right_edge, last_line_y),
-            Err(position) => position,
+            Ok(_) => last_line,
+            Err(line) => line,
         }
     }
 
@@ -349,6 +506,73 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
     }
 }
 
+/// Returns the vertical presentation form of CJK punctuation, such as `︒` for `。`, to use when
+/// the text is laid out vertically. Other characters are returned unchanged.
+pub fn vertical_presentation_form(ch: char) -> char {
+    match ch {
+        '，' => '︐',
+        '、' => '︑',
+        '。' => '︒',
+        '：' => '︓',
+        '；' => '︔',
+        '！' => '︕',
+        '？' => '︖',
+        '〖' => '︗',
+        '〗' => '︘',
+        '…' => '︙',
+        '‥' => '︰',
+        '—' => '︱',
+        '–' => '︲',
+        '（' => '︵',
+        '）' => '︶',
+        '｛' => '︷',
+        '｝' => '︸',
+        '〔' => '︹',
+        '〕' => '︺',
+        '【' => '︻',
+        '】' => '︼',
+        '《' => '︽',
+        '》' => '︾',
+        '〈' => '︿',
+        '〉' => '﹀',
+        '「' => '﹁',
+        '」' => '﹂',
+        '『' => '﹃',
+        '』' => '﹄',
+        '［' => '﹇',
+        '］' => '﹈',
+        _ => ch,
+    }
+}
+
+/// Returns the byte range of the segment of `text` that contains `byte_offset`, according to the
+/// word boundaries of the Unicode text segmentation algorithm (UAX #29). A segment is either a word,
+/// a run of whitespace, or a single punctuation character. If `byte_offset` is at the end of the
+/// text, the last segment is returned.
+pub fn word_range_at(text: &str, byte_offset: usize) -> core::ops::Range<usize> {
+    use unicode_segmentation::UnicodeSegmentation;
+    let mut last = 0..0;
+    for (offset, segment) in text.split_word_bound_indices() {
+        last = offset..offset + segment.len();
+        if last.contains(&byte_offset) {
+            break;
+        }
+    }
+    last
+}
+
+#[test]
+fn test_word_range_at() {
+    let text = "Hello,  wörld";
+    assert_eq!(word_range_at(text, 0), 0..5);
+    assert_eq!(word_range_at(text, 3), 0..5);
+    assert_eq!(word_range_at(text, 5), 5..6);
+    assert_eq!(word_range_at(text, 7), 6..8);
+    assert_eq!(word_range_at(text, 10), 8..14);
+    assert_eq!(word_range_at(text, 14), 8..14);
+    assert_eq!(word_range_at("", 0), 0..0);
+}
+
 #[test]
 fn test_no_linebreak_opportunity_at_eot() {
     let mut it = LineBreakIterator::new("Hello World");
@@ -440,6 +664,8 @@ fn test_elision() {
         wrap: TextWrap::NoWrap,
         overflow: TextOverflow::Elide,
         single_line: true,
+        writing_mode: TextWritingMode::HorizontalTb,
+        max_lines: None,
     };
     paragraph
         .layout_lines::<()>(
@@ -482,6 +708,8 @@ fn test_exact_fit() {
         wrap: TextWrap::NoWrap,
         overflow: TextOverflow::Elide,
         single_line: true,
+        writing_mode: TextWritingMode::HorizontalTb,
+        max_lines: None,
     };
     paragraph
         .layout_lines::<()>(
@@ -524,6 +752,8 @@ fn test_no_line_separators_characters_rendered() {
         wrap: TextWrap::NoWrap,
         overflow: TextOverflow::Clip,
         single_line: true,
+        writing_mode: TextWritingMode::HorizontalTb,
+        max_lines: None,
     };
     paragraph
         .layout_lines::<()>(
@@ -569,6 +799,8 @@ fn test_cursor_position() {
         wrap: TextWrap::WordWrap,
         overflow: TextOverflow::Clip,
         single_line: false,
+        writing_mode: TextWritingMode::HorizontalTb,
+        max_lines: None,
     };
 
     assert_eq!(paragraph.cursor_pos_for_byte_offset(0), (0., 0.));
@@ -609,9 +841,16 @@ fn test_cursor_position_with_newline() {
         wrap: TextWrap::WordWrap,
         overflow: TextOverflow::Clip,
         single_line: false,
+        writing_mode: TextWritingMode::HorizontalTb,
+        max_lines: None,
     };
 
     assert_eq!(paragraph.cursor_pos_for_byte_offset(5), (5. * 10., 0.));
+
+    let world = paragraph.cursor_line_for_byte_offset(8);
+    assert_eq!(world.cursor_pos, (2. * 10., 10.));
+    assert_eq!(world.line_index, 1);
+    assert_eq!((world.line_x, world.line_width), (0., 5. * 10.));
 }
 
 #[test]
@@ -629,6 +868,8 @@ fn byte_offset_for_empty_line() {
         wrap: TextWrap::WordWrap,
         overflow: TextOverflow::Clip,
         single_line: false,
+        writing_mode: TextWritingMode::HorizontalTb,
+        max_lines: None,
     };
 
     assert_eq!(paragraph.byte_offset_for_position((0., 10.)), 6);
@@ -651,6 +892,8 @@ fn test_byte_offset() {
         wrap: TextWrap::WordWrap,
         overflow: TextOverflow::Clip,
         single_line: false,
+        writing_mode: TextWritingMode::HorizontalTb,
+        max_lines: None,
     };
 
     assert_eq!(paragraph.byte_offset_for_position((0., 0.)), 0);
@@ -700,3 +943,159 @@ fn test_byte_offset() {
     assert_eq!(paragraph.byte_offset_for_position((45., 10.)), end_offset);
     assert_eq!(paragraph.byte_offset_for_position((0., 20.)), end_offset);
 }
+
+#[test]
+fn test_vertical_columns() {
+    let font = FixedTestFont;
+    let text = "縦書き。\n二行";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 100.,
+        max_height: 30.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+        writing_mode: TextWritingMode::VerticalRl,
+        max_lines: None,
+    };
+
+    let mut columns = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, column_x, column_y, column, _| {
+                let chars = glyphs
+                    .map(|glyph| {
+                        assert_eq!(glyph.x, 0.);
+                        (char::from_u32(glyph.glyph_id.get() as u32).unwrap(), glyph.y)
+                    })
+                    .collect::<Vec<_>>();
+                columns.push((column_x, column_y, column.byte_range.clone(), chars));
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(
+        columns,
+        [
+            (90., 0., 0..9, vec![('縦', 0.), ('書', 10.), ('き', 20.)]),
+            (80., 0., 9..12, vec![('︒', 0.)]),
+            (70., 0., 13..19, vec![('二', 0.), ('行', 10.)]),
+        ]
+    );
+}
+
+#[test]
+fn test_max_lines() {
+    let font = FixedTestFont;
+    let text = "This is a longer piece of text";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 10. * 10.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+        writing_mode: TextWritingMode::HorizontalTb,
+        max_lines: Some(2),
+    };
+
+    let mut lines = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, _, _, _, _| {
+                lines.push(
+                    glyphs
+                        .map(|glyph| char::from_u32(glyph.glyph_id.get() as u32).unwrap())
+                        .collect::<String>(),
+                );
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(lines, ["This is a ", "longer…"]);
+}
+
+#[test]
+fn test_vertical_columns_only_lay_out_visible_cells() {
+    let font = FixedTestFont;
+    let text = "縦".repeat(100) + "\n二\n三\n四";
+
+    let paragraph = TextParagraphLayout {
+        string: &text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 25.,
+        max_height: 35.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Bottom,
+        wrap: TextWrap::NoWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+        writing_mode: TextWritingMode::VerticalRl,
+        max_lines: None,
+    };
+
+    let mut columns = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, column_x, column_y, _, _| {
+                let rows = glyphs.map(|glyph| glyph.y).collect::<Vec<_>>();
+                columns.push((column_x, column_y, rows));
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+
+    // The long column is cut after the partially visible cell, and aligned as if it filled the
+    // height. The layout stops after the column that is partially visible on the left.
+    assert_eq!(
+        columns,
+        [(15., 5., vec![0., 10., 20., 30.]), (5., 25., vec![0.]), (-5., 25., vec![0.])]
+    );
+}
+
+#[test]
+fn test_horizontal_glyphs_on_baseline() {
+    // The renderers add the vertical position of the glyphs to the baseline, which must be a no-op
+    // for horizontal lines.
+    let font = FixedTestFont;
+    let paragraph = TextParagraphLayout {
+        string: "Hello World\nSecond line",
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 50.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Elide,
+        single_line: false,
+        writing_mode: TextWritingMode::HorizontalTb,
+        max_lines: None,
+    };
+    let mut glyph_count = 0;
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, _, _, _, _| {
+                for glyph in glyphs {
+                    assert_eq!(glyph.y, 0.);
+                    glyph_count += 1;
+                }
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+    assert!(glyph_count > 0);
+}
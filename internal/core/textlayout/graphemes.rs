@@ -0,0 +1,78 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Extended grapheme cluster segmentation used for cursor movement and editing, so that emoji
+//! sequences (ZWJ, skin tone modifiers, flags) and combining marks are never split.
+
+use unicode_segmentation::GraphemeCursor;
+
+/// Returns the largest char boundary in `text` that is not after `byte_offset`.
+fn floor_char_boundary(text: &str, byte_offset: usize) -> usize {
+    let mut byte_offset = byte_offset.min(text.len());
+    while !text.is_char_boundary(byte_offset) {
+        byte_offset -= 1;
+    }
+    byte_offset
+}
+
+/// Returns the byte offset of the first grapheme boundary after `byte_offset`, or the length of the
+/// text if `byte_offset` is within the last grapheme cluster.
+pub fn next_grapheme_boundary(text: &str, byte_offset: usize) -> usize {
+    GraphemeCursor::new(floor_char_boundary(text, byte_offset), text.len(), true)
+        .next_boundary(text, 0)
+        .ok()
+        .flatten()
+        .unwrap_or(text.len())
+}
+
+/// Returns the byte offset of the last grapheme boundary before `byte_offset`, or 0 if `byte_offset`
+/// is within the first grapheme cluster.
+pub fn prev_grapheme_boundary(text: &str, byte_offset: usize) -> usize {
+    let byte_offset = byte_offset.min(text.len());
+    let mut cursor = GraphemeCursor::new(floor_char_boundary(text, byte_offset), text.len(), true);
+    if cursor.cur_cursor() < byte_offset && cursor.is_boundary(text, 0).unwrap_or(false) {
+        return cursor.cur_cursor();
+    }
+    cursor.prev_boundary(text, 0).ok().flatten().unwrap_or(0)
+}
+
+/// Returns `byte_offset` if it is a grapheme boundary, otherwise the start of the grapheme cluster
+/// that contains it.
+pub fn grapheme_boundary_at_or_before(text: &str, byte_offset: usize) -> usize {
+    let byte_offset = floor_char_boundary(text, byte_offset);
+    let mut cursor = GraphemeCursor::new(byte_offset, text.len(), true);
+    if cursor.is_boundary(text, 0).unwrap_or(true) {
+        byte_offset
+    } else {
+        cursor.prev_boundary(text, 0).ok().flatten().unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_grapheme_boundaries() {
+    // Family emoji made of three code points joined with zero width joiners, followed by a thumbs up
+    // with a skin tone modifier and a devanagari consonant with a vowel sign.
+    let text = "a👨\u{200D}👩\u{200D}👧👍🏽नि";
+    let family = 1..1 + "👨\u{200D}👩\u{200D}👧".len();
+    let thumbs = family.end..family.end + "👍🏽".len();
+    let devanagari = thumbs.end..text.len();
+
+    assert_eq!(next_grapheme_boundary(text, 0), family.start);
+    assert_eq!(next_grapheme_boundary(text, family.start), family.end);
+    assert_eq!(next_grapheme_boundary(text, family.start + 4), family.end);
+    assert_eq!(next_grapheme_boundary(text, thumbs.start), thumbs.end);
+    assert_eq!(next_grapheme_boundary(text, devanagari.start), text.len());
+    assert_eq!(next_grapheme_boundary(text, text.len()), text.len());
+
+    assert_eq!(prev_grapheme_boundary(text, text.len()), devanagari.start);
+    assert_eq!(prev_grapheme_boundary(text, devanagari.start), thumbs.start);
+    assert_eq!(prev_grapheme_boundary(text, thumbs.start), family.start);
+    assert_eq!(prev_grapheme_boundary(text, thumbs.start - 1), family.start);
+    assert_eq!(prev_grapheme_boundary(text, family.start), 0);
+    assert_eq!(prev_grapheme_boundary(text, 0), 0);
+
+    assert_eq!(grapheme_boundary_at_or_before(text, family.start + 5), family.start);
+    assert_eq!(grapheme_boundary_at_or_before(text, thumbs.start + 4), thumbs.start);
+    assert_eq!(grapheme_boundary_at_or_before(text, thumbs.end), thumbs.end);
+    assert_eq!(grapheme_boundary_at_or_before(text, text.len() + 3), text.len());
+}
@@ -43,6 +43,12 @@ pub fn is_empty(&self) -> bool {
 }
 
 impl<Length: Clone + Copy + Default + core::ops::AddAssign> TextLine<Length> {
+    /// Creates a line that covers `byte_range` without being built from shaped fragments, such as
+    /// a column of vertical text.
+    pub(crate) fn for_byte_range(byte_range: Range<usize>, text_width: Length) -> Self {
+        Self { byte_range, text_width, ..Default::default() }
+    }
+
     pub fn add_fragment(&mut self, fragment: &TextFragment<Length>) {
         if self.byte_range.is_empty() {
             self.byte_range = fragment.byte_range.clone();
@@ -175,6 +175,52 @@ pub fn interval(&self) -> core::time::Duration {
             .unwrap_or_default()
     }
 
+    /// Allows the timer to fire up to `tolerance` after its interval elapsed, so that it can be
+    /// coalesced with other timers and the event loop wakes up less often. This saves power for
+    /// periodic refreshes that don't need to be precise, like a clock showing minutes or a status
+    /// polled every few seconds. The timer still fires as soon as its interval elapsed if the event
+    /// loop wakes up for another reason.
+    ///
+    /// The tolerance is 0 by default. It's kept when the timer is restarted or started again.
+    /// Does nothing if the timer was never started.
+    pub fn set_tolerance(&self, tolerance: core::time::Duration) {
+        if let Some(id) = self.id() {
+            CURRENT_TIMERS.with(|timers| timers.borrow_mut().set_tolerance(id, tolerance));
+        }
+    }
+
+    /// Returns the tolerance set with [`Self::set_tolerance()`].
+    pub fn tolerance(&self) -> core::time::Duration {
+        self.id()
+            .map(|timer_id| {
+                CURRENT_TIMERS.with(|timers| timers.borrow().timers[timer_id].tolerance)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Sets whether a [`Repeated`](TimerMode::Repeated) timer is scheduled relative to the time it
+    /// was due, instead of the time it actually fired. When enabled, the delays of the event loop
+    /// don't accumulate: a timer with an interval of one second started at 0 fires close to 1s, 2s,
+    /// 3s, and so on, and the periods missed while the event loop was blocked are skipped.
+    ///
+    /// This is disabled by default. It's kept when the timer is restarted or started again.
+    /// Does nothing if the timer was never started.
+    pub fn set_drift_corrected(&self, drift_corrected: bool) {
+        if let Some(id) = self.id() {
+            CURRENT_TIMERS
+                .with(|timers| timers.borrow_mut().timers[id].drift_corrected = drift_corrected);
+        }
+    }
+
+    /// Returns true if the timer is drift corrected, see [`Self::set_drift_corrected()`].
+    pub fn is_drift_corrected(&self) -> bool {
+        self.id()
+            .map(|timer_id| {
+                CURRENT_TIMERS.with(|timers| timers.borrow().timers[timer_id].drift_corrected)
+            })
+            .unwrap_or_default()
+    }
+
     fn id(&self) -> Option<usize> {
         self.id.get().map(|v| usize::from(v) - 1)
     }
@@ -210,6 +256,10 @@ enum CallbackVariant {
 
 struct TimerData {
     duration: core::time::Duration,
+    /// How late the timer may fire, to be coalesced with other timers
+    tolerance: core::time::Duration,
+    /// Repeated timers are scheduled from the time they were due instead of the time they fired
+    drift_corrected: bool,
     mode: TimerMode,
     running: bool,
     /// Set to true when it is removed when the callback is still running
@@ -224,6 +274,8 @@ struct TimerData {
 struct ActiveTimer {
     id: usize,
     timeout: Instant,
+    /// The latest time at which the timer should fire: its timeout plus its tolerance
+    deadline: Instant,
 }
 
 /// TimerList provides the interface to the event loop for activating times and
@@ -237,23 +289,25 @@ pub struct TimerList {
 }
 
 impl TimerList {
-    /// Returns the timeout of the timer that should fire the soonest, or None if there
+    /// Returns the time at which the event loop needs to activate the timers, or None if there
     /// is no timer active.
+    ///
+    /// This is the earliest deadline of the active timers: timers with a tolerance may wait to be
+    /// activated together with the timers that are due later.
     pub fn next_timeout() -> Option<Instant> {
         CURRENT_TIMERS.with(|timers| {
-            timers
-                .borrow()
-                .active_timers
-                .first()
-                .map(|first_active_timer| first_active_timer.timeout)
+            timers.borrow().active_timers.iter().map(|active_timer| active_timer.deadline).min()
         })
     }
 
     /// Activates any expired timers by calling their callback function. Returns true if any timers were
     /// activated; false otherwise.
     pub fn maybe_activate_timers(now: Instant) -> bool {
-        // Shortcut: Is there any timer worth activating?
-        if TimerList::next_timeout().map(|timeout| now < timeout).unwrap_or(false) {
+        // Shortcut: Is there any timer worth activating? All the timers that are due are activated,
+        // even if their tolerance would allow them to wait.
+        let first_timeout =
+            CURRENT_TIMERS.with(|timers| timers.borrow().active_timers.first().map(|t| t.timeout));
+        if first_timeout.map(|timeout| now < timeout).unwrap_or(false) {
             return false;
         }
 
@@ -280,7 +334,11 @@ pub fn maybe_activate_timers(now: Instant) -> bool {
                     timer.being_activated = true;
 
                     if matches!(timers.timers[expired_timer.id].mode, TimerMode::Repeated) {
-                        timers.activate_timer(expired_timer.id);
+                        if timers.timers[expired_timer.id].drift_corrected {
+                            timers.reactivate_timer_without_drift(*expired_timer, now);
+                        } else {
+                            timers.activate_timer(expired_timer.id);
+                        }
                     } else {
                         timers.timers[expired_timer.id].running = false;
                     }
@@ -353,6 +411,8 @@ fn start_or_restart_timer(
     ) -> usize {
         let mut timer_data = TimerData {
             duration,
+            tolerance: Default::default(),
+            drift_corrected: false,
             mode,
             running: false,
             removed: false,
@@ -362,6 +422,8 @@ fn start_or_restart_timer(
         let inactive_timer_id = if let Some(id) = id {
             self.deactivate_timer(id);
             timer_data.being_activated = self.timers[id].being_activated;
+            timer_data.tolerance = self.timers[id].tolerance;
+            timer_data.drift_corrected = self.timers[id].drift_corrected;
             self.timers[id] = timer_data;
             id
         } else {
@@ -386,10 +448,24 @@ fn deactivate_timer(&mut self, id: usize) {
     }
 
     fn activate_timer(&mut self, id: usize) {
-        self.register_active_timer(ActiveTimer {
-            id,
-            timeout: Instant::now() + self.timers[id].duration,
-        });
+        self.activate_timer_at(id, Instant::now() + self.timers[id].duration);
+    }
+
+    fn activate_timer_at(&mut self, id: usize, timeout: Instant) {
+        let deadline = timeout + self.timers[id].tolerance;
+        self.register_active_timer(ActiveTimer { id, timeout, deadline });
+    }
+
+    /// Schedules the next period of a repeated timer one interval after the time it was due,
+    /// skipping the periods that were missed.
+    fn reactivate_timer_without_drift(&mut self, expired_timer: ActiveTimer, now: Instant) {
+        let interval = self.timers[expired_timer.id].duration.as_millis() as u64;
+        if interval == 0 {
+            return self.activate_timer(expired_timer.id);
+        }
+        let missed_periods = now.0.saturating_sub(expired_timer.timeout.0) / interval;
+        let timeout = Instant(expired_timer.timeout.0 + (missed_periods + 1) * interval);
+        self.activate_timer_at(expired_timer.id, timeout);
     }
 
     fn register_active_timer(&mut self, new_active_timer: ActiveTimer) {
@@ -412,6 +488,13 @@ fn remove_timer(&mut self, id: usize) -> CallbackVariant {
         }
     }
 
+    fn set_tolerance(&mut self, id: usize, tolerance: core::time::Duration) {
+        self.timers[id].tolerance = tolerance;
+        if let Some(active_timer) = self.active_timers.iter_mut().find(|t| t.id == id) {
+            active_timer.deadline = active_timer.timeout + tolerance;
+        }
+    }
+
     fn set_interval(&mut self, id: usize, duration: core::time::Duration) {
         let timer = &self.timers[id];
         if timer.running {
@@ -429,6 +512,128 @@ fn set_interval(&mut self, id: usize, duration: core::time::Duration) {
 
 thread_local!(static CURRENT_TIMERS : RefCell<TimerList> = RefCell::default());
 
+/// The time that a callback registered with [`invoke_when_idle()`] can spend before the event loop
+/// needs to handle events or render again.
+#[derive(Debug, Clone, Copy)]
+pub struct IdleDeadline {
+    end: Instant,
+    did_timeout: bool,
+}
+
+impl IdleDeadline {
+    /// Returns how much time is left in the current idle period. This is zero when the callback is
+    /// invoked because its timeout expired.
+    pub fn time_remaining(&self) -> core::time::Duration {
+        core::time::Duration::from_millis(self.end.0.saturating_sub(Instant::now().0))
+    }
+
+    /// Returns true if the callback is invoked because its timeout expired, although the event loop
+    /// wasn't idle.
+    pub fn did_timeout(&self) -> bool {
+        self.did_timeout
+    }
+}
+
+type IdleCallback = Box<dyn FnOnce(&IdleDeadline)>;
+
+#[derive(Default)]
+struct IdleCallbackList {
+    callbacks: Vec<(u64, IdleCallback)>,
+    next_id: u64,
+}
+
+impl IdleCallbackList {
+    fn take(&mut self, id: Option<u64>) -> Option<IdleCallback> {
+        let index = match id {
+            Some(id) => self.callbacks.iter().position(|(i, _)| *i == id)?,
+            None if self.callbacks.is_empty() => return None,
+            None => 0,
+        };
+        Some(self.callbacks.remove(index).1)
+    }
+}
+
+thread_local!(static IDLE_CALLBACKS : RefCell<IdleCallbackList> = RefCell::default());
+
+/// Identifies a callback registered with [`invoke_when_idle()`], to cancel it.
+#[derive(Debug)]
+pub struct IdleCallbackHandle(u64);
+
+impl IdleCallbackHandle {
+    /// Cancels the callback. Returns false if it was invoked already.
+    pub fn cancel(self) -> bool {
+        IDLE_CALLBACKS.with(|list| list.borrow_mut().take(Some(self.0)).is_some())
+    }
+}
+
+/// Registers a callback that is invoked once, when the event loop has no events to handle and no
+/// frame to render, so that it never delays the reactions to the user.
+///
+/// Use this for incremental background work such as indexing or prefetching images: do a small
+/// amount of work while [`IdleDeadline::time_remaining()`] isn't zero, and register the callback
+/// again if there is more work to do. The callbacks registered while the idle callbacks run are
+/// invoked in the next idle period.
+///
+/// If `timeout` is set, the callback is invoked after that duration even if the event loop never
+/// becomes idle, and [`IdleDeadline::did_timeout()`] returns true.
+///
+/// Like timers, idle callbacks can only be used in the thread that runs the Slint event loop. The
+/// winit, LinuxKMS, and testing backends detect when the event loop is idle. With other backends,
+/// the callbacks are only invoked when their timeout expires, unless the platform implementation
+/// calls [`run_idle_callbacks()`](crate::platform::run_idle_callbacks).
+///
+/// ## Example
+/// ```rust,no_run
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use std::{cell::RefCell, rc::Rc};
+/// fn index_files(files: Rc<RefCell<Vec<std::path::PathBuf>>>) {
+///     slint::invoke_when_idle(None, move |deadline| {
+///         while !deadline.time_remaining().is_zero() {
+///             let Some(file) = files.borrow_mut().pop() else { return };
+///             // ... index the file
+///         }
+///         index_files(files);
+///     });
+/// }
+/// ```
+pub fn invoke_when_idle(
+    timeout: Option<core::time::Duration>,
+    callback: impl FnOnce(&IdleDeadline) + 'static,
+) -> IdleCallbackHandle {
+    let id = IDLE_CALLBACKS.with(|list| {
+        let mut list = list.borrow_mut();
+        let id = list.next_id;
+        list.next_id += 1;
+        list.callbacks.push((id, Box::new(callback)));
+        id
+    });
+    if let Some(timeout) = timeout {
+        Timer::single_shot(timeout, move || {
+            if let Some(callback) = IDLE_CALLBACKS.with(|list| list.borrow_mut().take(Some(id))) {
+                callback(&IdleDeadline { end: Instant::now(), did_timeout: true });
+            }
+        });
+    }
+    IdleCallbackHandle(id)
+}
+
+/// Invokes the pending idle callbacks, in the order they were registered, until `budget` is spent.
+/// At least one callback is invoked. Returns true if callbacks are still pending.
+pub(crate) fn run_idle_callbacks(budget: core::time::Duration) -> bool {
+    let deadline = IdleDeadline { end: Instant::now() + budget, did_timeout: false };
+    let pending = IDLE_CALLBACKS.with(|list| list.borrow().callbacks.len());
+    for i in 0..pending {
+        if i > 0 && deadline.time_remaining().is_zero() {
+            break;
+        }
+        match IDLE_CALLBACKS.with(|list| list.borrow_mut().take(None)) {
+            Some(callback) => callback(&deadline),
+            None => break,
+        }
+    }
+    IDLE_CALLBACKS.with(|list| !list.borrow().callbacks.is_empty())
+}
+
 #[cfg(feature = "ffi")]
 pub(crate) mod ffi {
     #![allow(unsafe_code)]
@@ -1142,3 +1347,94 @@ struct SharedState {
  */
 #[cfg(doctest)]
 const _STOP_FUTURE_TIMER_DURING_ACTIVATION_OF_EARLIER: () = ();
+
+/**
+ * Test that the idle callbacks run in order, within their budget, or when their timeout expires.
+```rust
+i_slint_backend_testing::init_no_event_loop();
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+let calls = Rc::new(RefCell::new(Vec::new()));
+let calls_ = calls.clone();
+slint::invoke_when_idle(None, move |deadline| {
+    calls_.borrow_mut().push(("first", deadline.did_timeout()));
+    let calls_ = calls_.clone();
+    // Registered during the idle period, so it runs in the next one
+    slint::invoke_when_idle(None, move |_| calls_.borrow_mut().push(("again", false)));
+});
+let calls_ = calls.clone();
+let cancelled = slint::invoke_when_idle(None, move |_| calls_.borrow_mut().push(("cancelled", false)));
+let calls_ = calls.clone();
+slint::invoke_when_idle(Some(Duration::from_millis(100)), move |deadline| {
+    calls_.borrow_mut().push(("timeout", deadline.did_timeout()));
+});
+assert!(cancelled.cancel());
+
+i_slint_core::tests::slint_mock_elapsed_time(150);
+assert_eq!(*calls.borrow(), [("timeout", true)]);
+
+assert!(slint::platform::run_idle_callbacks());
+assert_eq!(*calls.borrow(), [("timeout", true), ("first", false)]);
+assert!(!slint::platform::run_idle_callbacks());
+assert_eq!(*calls.borrow(), [("timeout", true), ("first", false), ("again", false)]);
+```
+ */
+#[cfg(doctest)]
+const _IDLE_CALLBACKS: () = ();
+
+/**
+ * Test that timers with a tolerance are coalesced, and that drift corrected timers don't drift.
+```rust
+i_slint_backend_testing::init_no_event_loop();
+use slint::{Timer, TimerMode};
+use std::{rc::Rc, cell::RefCell, time::Duration};
+
+let fired = Rc::new(RefCell::new(Vec::new()));
+let precise = Timer::default();
+precise.start(TimerMode::SingleShot, Duration::from_millis(100), {
+    let fired = fired.clone();
+    move || fired.borrow_mut().push("precise")
+});
+let coarse = Timer::default();
+coarse.start(TimerMode::SingleShot, Duration::from_millis(80), {
+    let fired = fired.clone();
+    move || fired.borrow_mut().push("coarse")
+});
+coarse.set_tolerance(Duration::from_millis(50));
+assert_eq!(coarse.tolerance(), Duration::from_millis(50));
+// The coarse timer waits for the precise one
+assert_eq!(slint::platform::duration_until_next_timer_update(), Some(Duration::from_millis(100)));
+i_slint_core::tests::slint_mock_elapsed_time(100);
+assert_eq!(*fired.borrow(), ["coarse", "precise"]);
+
+// A due coarse timer fires when the event loop wakes up for another reason
+fired.borrow_mut().clear();
+coarse.restart();
+assert_eq!(slint::platform::duration_until_next_timer_update(), Some(Duration::from_millis(130)));
+i_slint_core::tests::slint_mock_elapsed_time(90);
+assert_eq!(*fired.borrow(), ["coarse"]);
+
+let count = Rc::new(RefCell::new(0));
+let drift_corrected = Timer::default();
+drift_corrected.start(TimerMode::Repeated, Duration::from_millis(100), {
+    let count = count.clone();
+    move || *count.borrow_mut() += 1
+});
+drift_corrected.set_drift_corrected(true);
+assert!(drift_corrected.is_drift_corrected());
+i_slint_core::tests::slint_mock_elapsed_time(150);
+assert_eq!(*count.borrow(), 1);
+// The next period is due 100ms after the first one was due, not after it fired
+i_slint_core::tests::slint_mock_elapsed_time(50);
+assert_eq!(*count.borrow(), 2);
+// The missed periods are skipped
+i_slint_core::tests::slint_mock_elapsed_time(350);
+assert_eq!(*count.borrow(), 3);
+i_slint_core::tests::slint_mock_elapsed_time(40);
+assert_eq!(*count.borrow(), 3);
+i_slint_core::tests::slint_mock_elapsed_time(10);
+assert_eq!(*count.borrow(), 4);
+```
+ */
+#[cfg(doctest)]
+const _TIMER_TOLERANCE_AND_DRIFT_CORRECTION: () = ();
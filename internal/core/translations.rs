@@ -144,6 +144,116 @@ fn test_format() {
     }
 }
 
+#[test]
+fn test_pseudo_localization() {
+    assert_eq!(accented("Save"), "[Šåṽé~~]");
+    assert_eq!(accented("Hello {0}, {{n}}"), "[Ĥéļļö {0}, {{ñ}}~~~~~]");
+    set_pseudo_localization(PseudoLocalization::RightToLeft);
+    assert_eq!(pseudo_localize("Open"), "\u{202E}Open\u{202C}");
+    set_pseudo_localization(PseudoLocalization::None);
+    assert_eq!(pseudo_localize("Open"), "Open");
+}
+
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::thread_local;
+
+/// The pseudo-localization mode applied to all translated strings.
+///
+/// Pseudo-localization transforms the strings in place of real translations, to find truncated
+/// labels, hard-coded strings, and layouts that don't mirror before translations are available.
+/// Use [`set_pseudo_localization()`] to select it at run-time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum PseudoLocalization {
+    /// Strings are shown as translated.
+    #[default]
+    None,
+    /// Latin letters are replaced with accented variants, and the string is made about 40% longer and
+    /// surrounded with brackets, so that `Save` becomes `[Šåṽé~~]`.
+    Accented,
+    /// The string is shown right-to-left, by surrounding it with the Unicode right-to-left override
+    /// and pop directional formatting characters.
+    RightToLeft,
+}
+
+thread_local! {
+    static PSEUDO_LOCALIZATION: core::cell::Cell<PseudoLocalization> = const { core::cell::Cell::new(PseudoLocalization::None) }
+}
+
+/// Selects the pseudo-localization mode applied to all translated strings, and re-translates
+/// the strings currently shown.
+pub fn set_pseudo_localization(mode: PseudoLocalization) {
+    if PSEUDO_LOCALIZATION.with(|current| current.replace(mode)) != mode {
+        mark_all_translations_dirty();
+    }
+}
+
+/// Applies the current pseudo-localization mode to a string that still contains its `{}` placeholders.
+fn pseudo_localize(translated: &str) -> alloc::borrow::Cow<'_, str> {
+    match PSEUDO_LOCALIZATION.with(|mode| mode.get()) {
+        PseudoLocalization::None => translated.into(),
+        PseudoLocalization::Accented => accented(translated).into(),
+        PseudoLocalization::RightToLeft => alloc::format!("\u{202E}{translated}\u{202C}").into(),
+    }
+}
+
+fn accented(translated: &str) -> alloc::string::String {
+    const UPPER: [char; 26] = [
+        'Å', 'Ɓ', 'Ç', 'Đ', 'É', 'Ƒ', 'Ğ', 'Ĥ', 'Ï', 'Ĵ', 'Ķ', 'Ļ', 'Ṁ', 'Ñ', 'Ö', 'Þ', 'Ǫ', 'Ŕ',
+        'Š', 'Ţ', 'Û', 'Ṽ', 'Ŵ', 'Ẋ', 'Ý', 'Ž',
+    ];
+    const LOWER: [char; 26] = [
+        'å', 'ƀ', 'ç', 'đ', 'é', 'ƒ', 'ğ', 'ĥ', 'ï', 'ĵ', 'ķ', 'ļ', 'ṁ', 'ñ', 'ö', 'þ', 'ǫ', 'ŕ',
+        'š', 'ţ', 'û', 'ṽ', 'ŵ', 'ẋ', 'ý', 'ž',
+    ];
+
+    let mut result = alloc::string::String::with_capacity(translated.len() * 2);
+    result.push('[');
+    let mut visible_chars = 0usize;
+    let mut chars = translated.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // Placeholders are copied verbatim, and escaped braces too
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push_str("{{");
+                visible_chars += 1;
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push_str("}}");
+                visible_chars += 1;
+            }
+            '{' => {
+                result.push(c);
+                for c in chars.by_ref() {
+                    result.push(c);
+                    if c == '}' {
+                        break;
+                    }
+                }
+            }
+            'A'..='Z' => {
+                result.push(UPPER[(c as u8 - b'A') as usize]);
+                visible_chars += 1;
+            }
+            'a'..='z' => {
+                result.push(LOWER[(c as u8 - b'a') as usize]);
+                visible_chars += 1;
+            }
+            _ => {
+                result.push(c);
+                visible_chars += 1;
+            }
+        }
+    }
+    for _ in 0..(visible_chars * 2).div_ceil(5) {
+        result.push('~');
+    }
+    result.push(']');
+    result
+}
+
 struct WithPlural<'a, T: ?Sized>(&'a T, i32);
 
 enum DisplayOrInt<T> {
@@ -190,8 +300,11 @@ pub fn translate(
     #![allow(unused)]
     let mut output = SharedString::default();
     let translated = if plural.is_empty() || n == 1 { original } else { plural };
+    #[cfg(not(all(target_family = "unix", feature = "gettext-rs")))]
+    global_translation_property();
     #[cfg(all(target_family = "unix", feature = "gettext-rs"))]
     let translated = translate_gettext(original, contextid, domain, n, plural);
+    let translated = pseudo_localize(&translated);
     use core::fmt::Write;
     write!(output, "{}", formatter::format(&translated, &WithPlural(arguments, n))).unwrap();
     output
@@ -282,8 +395,9 @@ pub fn translate_from_bundle(
     else {
         return output;
     };
+    let translated = pseudo_localize(translated);
     use core::fmt::Write;
-    write!(output, "{}", formatter::format(translated, arguments)).unwrap();
+    write!(output, "{}", formatter::format(&translated, arguments)).unwrap();
     output
 }
 
@@ -307,8 +421,9 @@ pub fn translate_from_bundle_with_plural(
     else {
         return output;
     };
+    let translated = pseudo_localize(translated);
     use core::fmt::Write;
-    write!(output, "{}", formatter::format(translated, &WithPlural(arguments, n))).unwrap();
+    write!(output, "{}", formatter::format(&translated, &WithPlural(arguments, n))).unwrap();
     output
 }
 
@@ -443,8 +558,9 @@ pub extern "C" fn slint_translations_mark_dirty() {
         else {
             return;
         };
+        let translated = pseudo_localize(translated);
         use core::fmt::Write;
-        write!(output, "{}", formatter::format(translated, arguments.as_slice())).unwrap();
+        write!(output, "{}", formatter::format(&translated, arguments.as_slice())).unwrap();
     }
     /// strs is all the strings variant of all languages.
     /// indices is the array of indices such that for each language, the corresponding indice is one past the last index of the string for that language.
@@ -482,8 +598,9 @@ pub extern "C" fn slint_translations_mark_dirty() {
         else {
             return;
         };
+        let translated = pseudo_localize(translated);
         use core::fmt::Write;
-        write!(output, "{}", formatter::format(translated, &WithPlural(arguments.as_slice(), n)))
+        write!(output, "{}", formatter::format(&translated, &WithPlural(arguments.as_slice(), n)))
             .unwrap();
     }
 
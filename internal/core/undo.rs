@@ -0,0 +1,479 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    Support for undo and redo of changes to properties and models.
+*/
+
+#![warn(missing_docs)]
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+
+use crate::model::{Model, ModelRc, VecModel};
+use crate::SharedString;
+
+struct Change {
+    undo: Box<dyn Fn()>,
+    redo: Box<dyn Fn()>,
+    /// Consecutive changes with the same key are merged into a single change.
+    coalesce_key: Option<SharedString>,
+}
+
+struct Transaction {
+    text: SharedString,
+    changes: Vec<Change>,
+}
+
+impl Transaction {
+    fn undo(&self) {
+        for change in self.changes.iter().rev() {
+            (change.undo)();
+        }
+    }
+
+    fn redo(&self) {
+        for change in &self.changes {
+            (change.redo)();
+        }
+    }
+
+    /// Merges `change` into the last change of this transaction if they have the same key.
+    /// Returns the change back if it could not be merged.
+    fn coalesce(&mut self, change: Change) -> Option<Change> {
+        match self.changes.last_mut() {
+            Some(last)
+                if change.coalesce_key.is_some() && last.coalesce_key == change.coalesce_key =>
+            {
+                last.redo = change.redo;
+                None
+            }
+            _ => Some(change),
+        }
+    }
+}
+
+#[derive(Default)]
+struct UndoStackInner {
+    undo: VecDeque<Transaction>,
+    redo: Vec<Transaction>,
+    /// The transaction being recorded by [`UndoStack::transaction`], if any.
+    open: Option<Transaction>,
+    /// Whether the last transaction on the undo stack may still receive coalesced changes.
+    can_coalesce: bool,
+}
+
+/// An UndoStack records changes to properties and models as they are made, and can revert and
+/// re-apply them.
+///
+/// Each change is recorded together with a function that applies a value, so the stack doesn't need
+/// to know anything about the components of the application: the setters generated for the
+/// properties of a component, or the functions of a [`Model`], are used directly. Use
+/// [`Self::transaction()`] to group several changes into a single step that is undone at once,
+/// and the `_coalesced` variants to merge consecutive changes of the same value, for example
+/// while dragging a slider, into a single step.
+///
+/// The number of steps kept is bounded by the [limit](Self::set_limit); the oldest steps
+/// are dropped when it is reached.
+///
+/// Changes recorded while undoing or redoing, for example from a `changed` handler reacting to a
+/// property reverted by the stack, are ignored.
+///
+/// ## Example
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{Model, UndoStack, VecModel};
+/// use std::rc::Rc;
+///
+/// slint::slint! {
+///     export component Editor {
+///         in-out property <length> shape-x;
+///     }
+/// }
+/// let editor = Editor::new().unwrap();
+/// let shapes = Rc::new(VecModel::from(vec![1, 2, 3]));
+/// let undo_stack = UndoStack::default();
+///
+/// let weak = editor.as_weak();
+/// undo_stack.transaction("Move and remove", || {
+///     undo_stack.set_value(editor.get_shape_x(), 42., move |x| weak.unwrap().set_shape_x(x));
+///     undo_stack.remove_row(&shapes, 0);
+/// });
+/// assert_eq!(editor.get_shape_x(), 42.);
+/// assert_eq!(shapes.row_count(), 2);
+///
+/// assert_eq!(undo_stack.undo_text().as_deref(), Some("Move and remove"));
+/// undo_stack.undo();
+/// assert_eq!(editor.get_shape_x(), 0.);
+/// assert_eq!(shapes.iter().collect::<Vec<_>>(), [1, 2, 3]);
+///
+/// undo_stack.redo();
+/// assert_eq!(editor.get_shape_x(), 42.);
+/// ```
+pub struct UndoStack {
+    inner: RefCell<UndoStackInner>,
+    limit: Cell<usize>,
+    /// Set while undoing or redoing, to ignore the changes recorded meanwhile.
+    replaying: Cell<bool>,
+    changed_callback: RefCell<Option<Box<dyn Fn()>>>,
+}
+
+impl Default for UndoStack {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl UndoStack {
+    /// Creates a new UndoStack that keeps at most `limit` steps.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            inner: Default::default(),
+            limit: Cell::new(limit),
+            replaying: Cell::new(false),
+            changed_callback: Default::default(),
+        }
+    }
+
+    /// Returns the maximum number of steps that can be undone.
+    pub fn limit(&self) -> usize {
+        self.limit.get()
+    }
+
+    /// Sets the maximum number of steps that can be undone, dropping the oldest steps if needed.
+    pub fn set_limit(&self, limit: usize) {
+        self.limit.set(limit);
+        let mut inner = self.inner.borrow_mut();
+        let excess = inner.undo.len().saturating_sub(limit);
+        inner.undo.drain(..excess);
+        drop(inner);
+        self.notify_changed();
+    }
+
+    /// Sets a callback that is invoked whenever the undo or redo steps change, for example to
+    /// update the enabled state of the undo and redo actions of the user interface.
+    pub fn on_changed(&self, callback: impl Fn() + 'static) {
+        *self.changed_callback.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Records a change made by `apply` to a value: `apply` is called with `new_value` right away,
+    /// with `old_value` when the change is undone, and with `new_value` again when it is redone.
+    pub fn set_value<T: Clone + 'static>(
+        &self,
+        old_value: T,
+        new_value: T,
+        apply: impl Fn(T) + 'static,
+    ) {
+        self.set_value_impl(None, old_value, new_value, apply)
+    }
+
+    /// Same as [`Self::set_value()`], but if the last recorded change has the same `key`,
+    /// the two changes are merged: undoing them restores the value from before the first change.
+    ///
+    /// The merging stops when another change is recorded, or after an undo, a redo, or a call
+    /// to [`Self::end_coalescing()`].
+    pub fn set_value_coalesced<T: Clone + 'static>(
+        &self,
+        key: impl Into<SharedString>,
+        old_value: T,
+        new_value: T,
+        apply: impl Fn(T) + 'static,
+    ) {
+        self.set_value_impl(Some(key.into()), old_value, new_value, apply)
+    }
+
+    fn set_value_impl<T: Clone + 'static>(
+        &self,
+        coalesce_key: Option<SharedString>,
+        old_value: T,
+        new_value: T,
+        apply: impl Fn(T) + 'static,
+    ) {
+        apply(new_value.clone());
+        let apply = Rc::new(apply);
+        let apply2 = apply.clone();
+        self.record(Change {
+            undo: Box::new(move || apply(old_value.clone())),
+            redo: Box::new(move || apply2(new_value.clone())),
+            coalesce_key,
+        });
+    }
+
+    /// Calls [`Model::set_row_data()`] on the model and records the change.
+    pub fn set_row_data<T: Clone + 'static>(&self, model: &ModelRc<T>, row: usize, data: T) {
+        let Some(old_data) = model.row_data(row) else { return };
+        let model = model.clone();
+        self.set_value(old_data, data, move |data| model.set_row_data(row, data));
+    }
+
+    /// Inserts a row in the model and records the change.
+    pub fn insert_row<T: Clone + 'static>(&self, model: &Rc<VecModel<T>>, index: usize, data: T) {
+        model.insert(index, data.clone());
+        let undo_model = model.clone();
+        let redo_model = model.clone();
+        self.record(Change {
+            undo: Box::new(move || {
+                undo_model.remove(index);
+            }),
+            redo: Box::new(move || redo_model.insert(index, data.clone())),
+            coalesce_key: None,
+        });
+    }
+
+    /// Appends a row to the model and records the change.
+    pub fn push_row<T: Clone + 'static>(&self, model: &Rc<VecModel<T>>, data: T) {
+        self.insert_row(model, model.row_count(), data)
+    }
+
+    /// Removes a row from the model and records the change.
+    pub fn remove_row<T: Clone + 'static>(&self, model: &Rc<VecModel<T>>, index: usize) {
+        let data = model.remove(index);
+        let undo_model = model.clone();
+        let redo_model = model.clone();
+        self.record(Change {
+            undo: Box::new(move || undo_model.insert(index, data.clone())),
+            redo: Box::new(move || {
+                redo_model.remove(index);
+            }),
+            coalesce_key: None,
+        });
+    }
+
+    /// Records a custom change. The change must already have been made: `undo` is called when
+    /// it is undone, and `redo` when it is redone.
+    pub fn record_change(&self, undo: impl Fn() + 'static, redo: impl Fn() + 'static) {
+        self.record(Change { undo: Box::new(undo), redo: Box::new(redo), coalesce_key: None });
+    }
+
+    /// Calls `f` and groups all the changes recorded during the call into a single step,
+    /// described by `text`. Nested transactions are merged into the outermost one.
+    pub fn transaction<R>(&self, text: impl Into<SharedString>, f: impl FnOnce() -> R) -> R {
+        let nested = {
+            let mut inner = self.inner.borrow_mut();
+            let nested = inner.open.is_some();
+            if !nested {
+                inner.open = Some(Transaction { text: text.into(), changes: Vec::new() });
+            }
+            nested
+        };
+        let result = f();
+        if !nested {
+            let transaction = self.inner.borrow_mut().open.take();
+            if let Some(transaction) = transaction.filter(|t| !t.changes.is_empty()) {
+                self.push_transaction(transaction, false);
+            }
+        }
+        result
+    }
+
+    /// Prevents the next change from being merged with the previous one, even if they have the
+    /// same coalescing key. Call this for example when the user releases a slider.
+    pub fn end_coalescing(&self) {
+        self.inner.borrow_mut().can_coalesce = false;
+    }
+
+    fn record(&self, change: Change) {
+        if self.replaying.get() {
+            return;
+        }
+        let mut inner = self.inner.borrow_mut();
+        if let Some(open) = inner.open.as_mut() {
+            if let Some(change) = open.coalesce(change) {
+                open.changes.push(change);
+            }
+            return;
+        }
+        let change = if inner.can_coalesce {
+            match inner.undo.back_mut() {
+                Some(last) => last.coalesce(change),
+                None => Some(change),
+            }
+        } else {
+            Some(change)
+        };
+        drop(inner);
+        match change {
+            Some(change) => {
+                let coalescing = change.coalesce_key.is_some();
+                let transaction =
+                    Transaction { text: Default::default(), changes: [change].into() };
+                self.push_transaction(transaction, coalescing);
+            }
+            None => {
+                self.inner.borrow_mut().redo.clear();
+                self.notify_changed();
+            }
+        }
+    }
+
+    fn push_transaction(&self, transaction: Transaction, can_coalesce: bool) {
+        let mut inner = self.inner.borrow_mut();
+        inner.redo.clear();
+        inner.undo.push_back(transaction);
+        if inner.undo.len() > self.limit.get() {
+            inner.undo.pop_front();
+        }
+        inner.can_coalesce = can_coalesce;
+        drop(inner);
+        self.notify_changed();
+    }
+
+    /// Returns true if there is a step that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.inner.borrow().undo.is_empty()
+    }
+
+    /// Returns true if there is a step that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.inner.borrow().redo.is_empty()
+    }
+
+    /// Returns the text of the step that would be undone, if any. The text is empty for steps not
+    /// recorded within a [`Self::transaction()`].
+    pub fn undo_text(&self) -> Option<SharedString> {
+        self.inner.borrow().undo.back().map(|t| t.text.clone())
+    }
+
+    /// Returns the text of the step that would be redone, if any.
+    pub fn redo_text(&self) -> Option<SharedString> {
+        self.inner.borrow().redo.last().map(|t| t.text.clone())
+    }
+
+    /// Reverts the last step. Returns false if there was nothing to undo.
+    pub fn undo(&self) -> bool {
+        let Some(transaction) = self.inner.borrow_mut().undo.pop_back() else { return false };
+        self.replay(|| transaction.undo());
+        let mut inner = self.inner.borrow_mut();
+        inner.redo.push(transaction);
+        inner.can_coalesce = false;
+        drop(inner);
+        self.notify_changed();
+        true
+    }
+
+    /// Re-applies the last undone step. Returns false if there was nothing to redo.
+    pub fn redo(&self) -> bool {
+        let Some(transaction) = self.inner.borrow_mut().redo.pop() else { return false };
+        self.replay(|| transaction.redo());
+        let mut inner = self.inner.borrow_mut();
+        inner.undo.push_back(transaction);
+        inner.can_coalesce = false;
+        drop(inner);
+        self.notify_changed();
+        true
+    }
+
+    /// Forgets all the steps.
+    pub fn clear(&self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.undo.clear();
+        inner.redo.clear();
+        inner.can_coalesce = false;
+        drop(inner);
+        self.notify_changed();
+    }
+
+    fn replay(&self, f: impl FnOnce()) {
+        let was_replaying = self.replaying.replace(true);
+        f();
+        self.replaying.set(was_replaying);
+    }
+
+    fn notify_changed(&self) {
+        if let Some(callback) = self.changed_callback.borrow().as_ref() {
+            callback();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_redo_values() {
+        let value = Rc::new(Cell::new(0));
+        let setter = {
+            let value = value.clone();
+            move |v| value.set(v)
+        };
+        let stack = UndoStack::new(2);
+        assert!(!stack.can_undo());
+
+        stack.set_value(value.get(), 1, setter.clone());
+        stack.set_value(value.get(), 2, setter.clone());
+        stack.set_value(value.get(), 3, setter.clone());
+        assert_eq!(value.get(), 3);
+
+        // Only two steps are kept
+        assert!(stack.undo());
+        assert!(stack.undo());
+        assert!(!stack.undo());
+        assert_eq!(value.get(), 1);
+
+        assert!(stack.redo());
+        assert_eq!(value.get(), 2);
+        // A new change drops the steps that can be redone
+        stack.set_value(value.get(), 5, setter);
+        assert!(!stack.can_redo());
+        assert!(stack.undo());
+        assert_eq!(value.get(), 2);
+    }
+
+    #[test]
+    fn coalescing() {
+        let value = Rc::new(Cell::new(0));
+        let setter = {
+            let value = value.clone();
+            move |v| value.set(v)
+        };
+        let changes = Rc::new(Cell::new(0));
+        let stack = UndoStack::default();
+        stack.on_changed({
+            let changes = changes.clone();
+            move || changes.set(changes.get() + 1)
+        });
+
+        for v in 1..=5 {
+            stack.set_value_coalesced("slider", value.get(), v, setter.clone());
+        }
+        stack.end_coalescing();
+        stack.set_value_coalesced("slider", value.get(), 10, setter.clone());
+        assert_eq!(changes.get(), 6);
+
+        stack.undo();
+        assert_eq!(value.get(), 5);
+        stack.undo();
+        assert_eq!(value.get(), 0);
+        assert!(!stack.can_undo());
+    }
+
+    #[test]
+    fn transactions_and_models() {
+        let model = Rc::new(VecModel::from(alloc::vec![1, 2, 3]));
+        let model_rc = ModelRc::from(model.clone());
+        let stack = UndoStack::default();
+
+        stack.transaction("edit", || {
+            stack.set_row_data(&model_rc, 0, 10);
+            stack.remove_row(&model, 1);
+            stack.transaction("nested", || stack.push_row(&model, 4));
+        });
+        stack.insert_row(&model, 0, 0);
+        assert_eq!(model.iter().collect::<Vec<_>>(), [0, 10, 3, 4]);
+        assert_eq!(stack.undo_text().as_deref(), Some(""));
+
+        stack.undo();
+        assert_eq!(stack.undo_text().as_deref(), Some("edit"));
+        stack.undo();
+        assert_eq!(model.iter().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(stack.redo_text().as_deref(), Some("edit"));
+
+        stack.redo();
+        assert_eq!(model.iter().collect::<Vec<_>>(), [10, 3, 4]);
+    }
+}
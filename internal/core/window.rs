@@ -15,8 +15,10 @@
     MouseInputState, TextCursorBlinker,
 };
 use crate::item_tree::{ItemRc, ItemTreeRc, ItemTreeRef, ItemTreeVTable, ItemTreeWeak, ItemWeak};
-use crate::items::{ColorScheme, InputType, ItemRef, MenuEntry, MouseCursor, PopupClosePolicy};
-use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, SizeLengths};
+use crate::items::{
+    ColorScheme, InputType, ItemRef, KeyNavigation, MenuEntry, MouseCursor, PopupClosePolicy,
+};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, PointLengths, SizeLengths};
 use crate::properties::{Property, PropertyTracker};
 use crate::renderer::Renderer;
 use crate::{Callback, Coord, SharedString, SharedVector};
@@ -29,9 +31,13 @@
 use core::num::NonZeroU32;
 use core::pin::Pin;
 use euclid::num::Zero;
+#[cfg(not(feature = "std"))]
+#[allow(unused)]
+use num_traits::Float;
 use vtable::VRcMapped;
 
 pub mod popup;
+mod scene_dump;
 
 fn next_focus_item(item: ItemRc) -> ItemRc {
     item.next_focus_item()
@@ -190,6 +196,56 @@ fn create_popup(&self, _geometry: LogicalRect) -> Option<Rc<dyn WindowAdapter>>
     // TODO: Make the enum public and make public
     fn set_mouse_cursor(&self, _cursor: MouseCursor) {}
 
+    /// Set the cursor made of images that replaces the one set with [`Self::set_mouse_cursor`],
+    /// or remove it when `cursor` is `None`.
+    fn set_custom_cursor(
+        &self,
+        _cursor: Option<crate::api::CustomCursor>,
+    ) -> Result<(), PlatformError> {
+        Err(PlatformError::Other("This backend does not support custom cursors".into()))
+    }
+
+    /// Start moving the window with the pointer for a `TitleBar` region, or resizing it at the
+    /// edge or corner of a `Resize*` region. Called when a `WindowFrameArea` is pressed, and by
+    /// `start-system-move()` and `start-system-resize()`.
+    fn drag_window_frame(&self, _region: crate::items::WindowFrameRegion) {}
+
+    /// Returns the position and size of the area of each screen where windows can be placed, in the
+    /// coordinates of [`crate::api::Window::set_position`], or `None` if the backend doesn't know.
+    fn screen_geometries(
+        &self,
+    ) -> Option<Vec<(crate::api::PhysicalPosition, crate::api::PhysicalSize)>> {
+        None
+    }
+
+    /// Returns the modes of the monitor showing the window. See [`crate::api::Window::video_modes`].
+    fn video_modes(&self) -> Vec<crate::api::VideoMode> {
+        Vec::new()
+    }
+
+    /// Show the window in exclusive fullscreen with the given mode, or leave exclusive fullscreen.
+    /// See [`crate::api::Window::set_exclusive_fullscreen`].
+    fn set_exclusive_fullscreen(
+        &self,
+        _mode: Option<crate::api::VideoMode>,
+    ) -> Result<(), PlatformError> {
+        Err(PlatformError::Other("This backend does not support exclusive fullscreen".into()))
+    }
+
+    /// Start dragging `data` to other applications. See [`crate::api::Window::start_drag`].
+    #[cfg(feature = "unstable-drag-api")]
+    fn start_drag(&self, _data: crate::api::DragData) -> Result<(), PlatformError> {
+        Err(PlatformError::Other(
+            "This backend does not support dragging data to other applications".into(),
+        ))
+    }
+
+    /// Returns the time between two refreshes of the display showing the window, if known.
+    /// This is used to predict when a frame is presented, see [`crate::api::FrameInfo`].
+    fn refresh_interval(&self) -> Option<core::time::Duration> {
+        None
+    }
+
     /// This method allow editable input field to communicate with the platform about input methods
     fn input_method_request(&self, _: InputMethodRequest) {}
 
@@ -208,6 +264,12 @@ fn color_scheme(&self) -> ColorScheme {
         ColorScheme::Unknown
     }
 
+    /// Returns the accent color that the user chose in the system settings, or a transparent color if
+    /// it's unknown
+    fn accent_color(&self) -> crate::Color {
+        Default::default()
+    }
+
     /// Returns whether we can have a native menu bar
     fn supports_native_menu_bar(&self) -> bool {
         false
@@ -233,10 +295,28 @@ fn display_handle_06_rc(
         Err(raw_window_handle_06::HandleError::NotSupported)
     }
 
+    /// Makes the window a transient child of `parent`, which the windowing system keeps it above, or a
+    /// top-level window again when `parent` is `None`. When `modal` is true, the windowing system may
+    /// present the window as a modal dialog of the parent. Called before the window is shown.
+    fn set_parent_window(&self, _parent: Option<Rc<dyn WindowAdapter>>, _modal: bool) {}
+
+    /// Returns the text that the physical key named `physical_key`, like `KeyA`, produces without
+    /// modifiers in the current keyboard layout, or `None` if it's unknown.
+    fn physical_key_text(&self, _physical_key: &str) -> Option<SharedString> {
+        None
+    }
+
     /// Brings the window to the front and focuses it.
     fn bring_to_front(&self) -> Result<(), PlatformError> {
         Ok(())
     }
+
+    /// Keeps the screen on and prevents the system from going to sleep while `inhibited` is true,
+    /// see `Window::set_idle_inhibited()`.
+    fn set_idle_inhibited(&self, _inhibited: bool) {}
+
+    /// Draws the attention of the user to the window, see `Window::request_user_attention()`.
+    fn request_user_attention(&self, _attention: crate::api::UserAttentionType) {}
 }
 
 /// This is the parameter from [`WindowAdapterInternal::input_method_request()`] which lets the editable text input field
@@ -277,6 +357,10 @@ pub struct InputMethodProperties {
     pub cursor_rect_size: crate::api::LogicalSize,
     /// The position of the anchor (bottom). Only meaningful if anchor_position is Some
     pub anchor_point: LogicalPosition,
+    /// The y coordinate of the baseline of the line with the cursor, in window coordinates.
+    pub cursor_baseline: f32,
+    /// The index of the visual line with the cursor, starting at 0 for the first line.
+    pub cursor_line_index: usize,
     /// The type of input for the text edit.
     pub input_type: InputType,
 }
@@ -303,6 +387,21 @@ pub fn title(&self) -> SharedString {
         self.0.window_item().map(|w| w.as_pin_ref().title()).unwrap_or_default()
     }
 
+    /// Returns the images for the icon of the window, in different sizes. These are the images
+    /// set with [`crate::api::Window::set_icons()`], or else the `icon` property of the Window.
+    pub fn icons(&self) -> Vec<crate::graphics::Image> {
+        let icons = self.0.icons.borrow();
+        if !icons.is_empty() {
+            return icons.clone();
+        }
+        self.0
+            .window_item()
+            .map(|w| w.as_pin_ref().icon())
+            .filter(|icon| icon.size().width > 0)
+            .into_iter()
+            .collect()
+    }
+
     /// The background color or brush of the Window
     pub fn background(&self) -> crate::Brush {
         self.0
@@ -330,6 +429,25 @@ pub fn layout_constraints(&self) -> LayoutConstraints {
         }
     }
 
+    /// Returns the steps in which the user resizes the window, from the `resize-increment-width` and
+    /// `resize-increment-height` properties, or `None` if the size can change freely.
+    pub fn resize_increments(&self) -> Option<crate::api::LogicalSize> {
+        let window_item = self.0.window_item()?;
+        let window_item = window_item.as_pin_ref();
+        let width = window_item.resize_increment_width().get() as f32;
+        let height = window_item.resize_increment_height().get() as f32;
+        (width > 0. || height > 0.)
+            .then(|| crate::api::LogicalSize::new(width.max(0.), height.max(0.)))
+    }
+
+    /// Returns the ratio between the width and the height the window keeps when the user resizes it,
+    /// from the `aspect-ratio` property, or `None` if it's not locked.
+    pub fn aspect_ratio(&self) -> Option<f32> {
+        let window_item = self.0.window_item()?;
+        Some(window_item.as_pin_ref().aspect_ratio())
+            .filter(|ratio| ratio.is_finite() && *ratio > 0.)
+    }
+
     /// Returns true if the window should be shown fullscreen; false otherwise.
     #[deprecated(note = "Please use `is_fullscreen` instead")]
     pub fn fullscreen(&self) -> bool {
@@ -341,6 +459,26 @@ pub fn is_fullscreen(&self) -> bool {
         self.0.is_fullscreen()
     }
 
+    /// Returns whether the window should be kept above or below the windows of other applications.
+    pub fn window_level(&self) -> crate::items::WindowLevel {
+        self.0.window_level()
+    }
+
+    /// Returns the effect that the platform should draw behind the transparent parts of the window.
+    pub fn background_effect(&self) -> crate::items::WindowBackgroundEffect {
+        self.0.window_item().map(|w| w.as_pin_ref().background_effect()).unwrap_or_default()
+    }
+
+    /// Returns the opacity of the whole window, between 0 (transparent) and 1 (opaque).
+    pub fn opacity(&self) -> f32 {
+        self.0.opacity()
+    }
+
+    /// Returns who should draw the decorations of the window, if the windowing system gives the choice.
+    pub fn preferred_decoration_mode(&self) -> crate::items::WindowDecorationMode {
+        self.0.window_item().map(|w| w.as_pin_ref().preferred_decoration_mode()).unwrap_or_default()
+    }
+
     /// true if the window is in a maximized state, otherwise false
     pub fn is_maximized(&self) -> bool {
         self.0.maximized.get()
@@ -419,6 +557,10 @@ struct WindowPinnedFields {
     active: Property<bool>,
     #[pin]
     text_input_focused: Property<bool>,
+    #[pin]
+    system_font_family: Property<SharedString>,
+    #[pin]
+    text_scale_factor: Property<f32>,
 }
 
 /// Inner datastructure for the [`crate::api::Window`]
@@ -429,11 +571,26 @@ pub struct WindowInner {
     strong_component_ref: RefCell<Option<ItemTreeRc>>,
     mouse_input_state: Cell<MouseInputState>,
     pub(crate) modifiers: Cell<InternalKeyboardModifierState>,
+    /// The pen or finger of the pointer event being processed, `None` for a mouse.
+    pub(crate) pointer_details: Cell<Option<crate::input::PointerDetails>>,
+    /// The device of the scroll event being processed, `None` if the backend didn't report it.
+    pub(crate) scroll_details: Cell<Option<crate::input::ScrollDetails>>,
+    /// The physical key of the next key event.
+    pub(crate) key_details: RefCell<crate::input::KeyDetails>,
+    /// The physical keys whose repeated presses are dropped, set with `Window::set_key_repeat_enabled()`.
+    pub(crate) keys_without_repeat: RefCell<Vec<SharedString>>,
+    /// The text that the physical keys produced without modifiers in the current keyboard layout,
+    /// for `Window::physical_key_text()` when the backend can't look it up.
+    physical_key_texts: RefCell<Vec<(SharedString, SharedString)>>,
 
     /// ItemRC that currently have the focus. (possibly a, instance of TextInput)
     pub focus_item: RefCell<crate::item_tree::ItemWeak>,
     /// The last text that was sent to the input method
     pub(crate) last_ime_text: RefCell<SharedString>,
+    /// The position in the window that the input method was last anchored at: the origin of the
+    /// TextInput, or the cursor of a FocusScope. The input method is updated when it moves, for
+    /// example when the text is scrolled.
+    pub(crate) last_ime_origin: Cell<LogicalPoint>,
     /// Don't let ComponentContainers's instantiation change the focus.
     /// This is a workaround for a recursion when instantiating ComponentContainer because the
     /// init code for the component might have code that sets the focus, but we don't want that
@@ -444,13 +601,47 @@ pub struct WindowInner {
     pinned_fields: Pin<Box<WindowPinnedFields>>,
     maximized: Cell<bool>,
     minimized: Cell<bool>,
+    occluded: Cell<bool>,
+    /// The scale factor reported by the platform, which is used unless it's overridden.
+    platform_scale_factor: Cell<f32>,
+    scale_factor_override: Cell<Option<f32>>,
+    decoration_mode: Cell<crate::items::WindowDecorationMode>,
+    /// The window set with `Window::set_parent()`.
+    parent_window: RefCell<Option<Weak<dyn WindowAdapter>>>,
+    modal: Cell<bool>,
+    /// The modal windows that were shown with this window as parent. While one of them is visible,
+    /// this window doesn't receive input.
+    modal_children: RefCell<Vec<Weak<dyn WindowAdapter>>>,
+    /// The icons set with `Window::set_icons()`, which replace the `icon` property.
+    icons: RefCell<Vec<crate::graphics::Image>>,
 
     /// Stack of currently active popups
     active_popups: RefCell<Vec<PopupWindow>>,
     next_popup_id: Cell<NonZeroU32>,
     had_popup_on_press: Cell<bool>,
     close_requested: Callback<(), CloseRequestResponse>,
+    keyboard_layout_changed: Callback<()>,
+    drag_and_drop: Callback<(crate::api::DragAndDropEvent,)>,
+    /// Runs the callback set with `Window::on_color_scheme_changed()`.
+    color_scheme_tracker: crate::properties::ChangeTracker,
+    frame_requested: Callback<(crate::api::FrameInfo,)>,
+    /// The minimum time between two frames for animations, set with `Window::set_frame_rate_limit()`.
+    min_frame_interval: Cell<Option<core::time::Duration>>,
+    /// The time at which the last frame started to render.
+    last_frame_time: Cell<Option<crate::animations::Instant>>,
+    vsync_enabled: Cell<bool>,
     click_state: ClickState,
+    /// The area of the window covered by the virtual keyboard, empty when it is hidden.
+    virtual_keyboard_rect: Cell<LogicalRect>,
+    /// The `DropArea` that accepted the data currently dragged over the window.
+    drop_area: RefCell<ItemWeak>,
+    /// The fingers that touch the window, for backends that dispatch touch events.
+    touch_state: RefCell<crate::input::TouchState>,
+    /// The menu bar, when it is rendered by Slint instead of being native, for its shortcuts.
+    menubar: RefCell<Option<vtable::VBox<MenuVTable>>>,
+    /// The settings and the key in which the geometry of the window is stored when it's hidden,
+    /// see [`crate::api::Window::persist_geometry`].
+    geometry_persistence: RefCell<Option<(crate::settings::Settings, SharedString)>>,
     pub(crate) ctx: once_cell::unsync::Lazy<crate::SlintContext>,
 }
 
@@ -489,6 +680,11 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             strong_component_ref: Default::default(),
             mouse_input_state: Default::default(),
             modifiers: Default::default(),
+            pointer_details: Default::default(),
+            scroll_details: Default::default(),
+            key_details: Default::default(),
+            keys_without_repeat: Default::default(),
+            physical_key_texts: Default::default(),
             pinned_fields: Box::pin(WindowPinnedFields {
                 redraw_tracker,
                 window_properties_tracker,
@@ -498,17 +694,46 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
                     false,
                     "i_slint_core::Window::text_input_focused",
                 ),
+                system_font_family: Property::new_named(
+                    Default::default(),
+                    "i_slint_core::Window::system_font_family",
+                ),
+                text_scale_factor: Property::new_named(
+                    1.,
+                    "i_slint_core::Window::text_scale_factor",
+                ),
             }),
             maximized: Cell::new(false),
             minimized: Cell::new(false),
+            occluded: Cell::new(false),
+            platform_scale_factor: Cell::new(1.),
+            scale_factor_override: Cell::new(None),
+            decoration_mode: Default::default(),
+            parent_window: Default::default(),
+            modal: Cell::new(false),
+            modal_children: Default::default(),
+            icons: Default::default(),
             focus_item: Default::default(),
             last_ime_text: Default::default(),
+            last_ime_origin: Default::default(),
             cursor_blinker: Default::default(),
             active_popups: Default::default(),
             next_popup_id: Cell::new(NonZeroU32::MIN),
             had_popup_on_press: Default::default(),
             close_requested: Default::default(),
+            keyboard_layout_changed: Default::default(),
+            drag_and_drop: Default::default(),
+            color_scheme_tracker: Default::default(),
+            frame_requested: Default::default(),
+            min_frame_interval: Default::default(),
+            last_frame_time: Default::default(),
+            vsync_enabled: Cell::new(true),
             click_state: ClickState::default(),
+            virtual_keyboard_rect: Default::default(),
+            drop_area: Default::default(),
+            touch_state: Default::default(),
+            menubar: Default::default(),
+            geometry_persistence: Default::default(),
             prevent_focus_change: Default::default(),
             // The ctx is lazy so that a Window can be initialized before the backend.
             // (for example in test_empty_window)
@@ -539,6 +764,11 @@ pub fn set_component(&self, component: &ItemTreeRc) {
             if default_font_size_prop.get().get() <= 0 as Coord {
                 default_font_size_prop.set(window_adapter.renderer().default_font_size());
             }
+            window_item.maximized.set(self.maximized.get());
+            window_item.minimized.set(self.minimized.get());
+            window_item.active.set(self.active());
+            window_item.occluded.set(self.occluded.get());
+            window_item.decoration_mode.set(self.decoration_mode.get());
         }
         self.set_window_item_geometry(
             window_adapter.size().to_logical(self.scale_factor()).to_euclid(),
@@ -578,6 +808,15 @@ pub fn active_popups(&self) -> core::cell::Ref<'_, [PopupWindow]> {
     pub fn process_mouse_input(&self, mut event: MouseEvent) {
         crate::animations::update_animations();
 
+        if let Some(modal_child) = self.modal_child() {
+            if matches!(event, MouseEvent::Pressed { .. }) {
+                // Like with native modal dialogs, clicking the parent activates the dialog
+                let _ = modal_child.window().request_activation();
+            }
+            // Only let the items under the mouse know that it isn't over them anymore
+            event = MouseEvent::Exit;
+        }
+
         // handle multiple press release
         event = self.click_state.check_repeat(event, self.ctx.platform().click_interval());
 
@@ -654,6 +893,9 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         }
 
         self.mouse_input_state.set(mouse_input_state);
+        // The details of a pen, finger, or scroll device only apply to the event that follows them
+        self.pointer_details.set(None);
+        self.scroll_details.set(None);
 
         match close_policy {
             PopupClosePolicy::CloseOnClick => {
@@ -682,13 +924,185 @@ pub(crate) fn process_delayed_event(&self) {
         ));
     }
 
+    /// Offers the data dragged at `position` to the `DropArea` elements under it, and drops it on
+    /// the one that accepts it if `dropped` is true.
+    pub(crate) fn process_drag_event(
+        &self,
+        position: LogicalPoint,
+        event: crate::items::DropEvent,
+        dropped: bool,
+    ) {
+        crate::animations::update_animations();
+        let window_position = crate::api::LogicalPosition::from_euclid(position);
+        let (mime_type, data) = (event.mime_type.clone(), event.data.clone());
+        self.drag_and_drop.call(&(if dropped {
+            crate::api::DragAndDropEvent::Dropped { position: window_position, mime_type, data }
+        } else {
+            crate::api::DragAndDropEvent::Moved { position: window_position, mime_type, data }
+        },));
+        let target = self.try_component().and_then(|component| {
+            crate::input::find_drop_area(&ItemRc::new(component, 0), position, &event)
+        });
+        let new_area = match (&target, dropped) {
+            (Some((item, _)), false) => item.downgrade(),
+            _ => Default::default(),
+        };
+        let previous_area = self.drop_area.replace(new_area).upgrade();
+        if let Some(previous_area) = previous_area {
+            if target.as_ref().map_or(true, |(item, _)| *item != previous_area) {
+                if let Some(area) = previous_area.downcast::<crate::items::DropArea>() {
+                    area.as_pin_ref().drag_exited();
+                }
+            }
+        }
+        if let Some((item, position)) = target {
+            if let Some(area) = item.downcast::<crate::items::DropArea>() {
+                if dropped {
+                    area.as_pin_ref().drop_at(position, event);
+                } else {
+                    area.as_pin_ref().drag_moved(position);
+                }
+            }
+        }
+        crate::properties::ChangeTracker::run_change_handlers();
+    }
+
+    /// Called when the dragged data leaves the window without being dropped.
+    pub(crate) fn process_drag_exit(&self) {
+        self.drag_and_drop.call(&(crate::api::DragAndDropEvent::Exited,));
+        let area = self.drop_area.take().upgrade();
+        if let Some(area) = area.as_ref().and_then(|item| item.downcast::<crate::items::DropArea>())
+        {
+            area.as_pin_ref().drag_exited();
+        }
+        crate::properties::ChangeTracker::run_change_handlers();
+    }
+
+    /// Receives a finger that touched the window, moved, or was lifted. The first finger is
+    /// delivered to the items as a mouse, and the first two fingers make a gesture in the
+    /// `PinchGestureHandler` under the first one.
+    pub(crate) fn process_touch_event(
+        &self,
+        id: u64,
+        position: LogicalPoint,
+        kind: crate::items::PointerEventKind,
+    ) {
+        use crate::items::{PinchGestureHandler, PointerEventButton, PointerEventKind};
+
+        let mouse_event = {
+            let mut touch_state = self.touch_state.borrow_mut();
+            let button = PointerEventButton::Left;
+            match kind {
+                PointerEventKind::Down => touch_state
+                    .press(id, position)
+                    .then_some(MouseEvent::Pressed { position, button, click_count: 0 }),
+                PointerEventKind::Move => {
+                    touch_state.move_to(id, position).then_some(MouseEvent::Moved { position })
+                }
+                PointerEventKind::Up | PointerEventKind::Cancel => touch_state
+                    .release(id)
+                    .then_some(MouseEvent::Released { position, button, click_count: 0 }),
+            }
+        };
+        if let Some(event) = mouse_event {
+            if self.pointer_details.get().is_none() {
+                self.pointer_details.set(Some(crate::input::PointerDetails {
+                    tool_type: crate::items::PointerToolType::Touch,
+                    pressure: if matches!(event, MouseEvent::Released { .. }) { 0. } else { 0.5 },
+                    tilt_x: 0.,
+                    tilt_y: 0.,
+                    twist: 0.,
+                }));
+            }
+            self.process_mouse_input(event);
+        } else {
+            crate::animations::update_animations();
+            self.pointer_details.set(None);
+        }
+
+        let handler_position = |handler: &ItemRc, position: LogicalPoint| {
+            position - handler.map_to_window(handler.geometry().origin).to_vector()
+        };
+        match kind {
+            PointerEventKind::Down if self.modal_child().is_none() => {
+                let handler = self.try_component().and_then(|component| {
+                    self.touch_state.borrow_mut().start_gesture(&ItemRc::new(component, 0))
+                });
+                if let Some(handler_rc) = handler {
+                    // The first finger no longer acts as a mouse
+                    self.process_mouse_input(MouseEvent::Exit);
+                    let update = self.touch_state.borrow_mut().update_gesture(id);
+                    if let (Some(handler), Some((_, _, _, _, center))) =
+                        (handler_rc.downcast::<PinchGestureHandler>(), update)
+                    {
+                        handler.as_pin_ref().gesture_started(handler_position(&handler_rc, center));
+                    }
+                }
+            }
+            PointerEventKind::Move => {
+                let update = self.touch_state.borrow_mut().update_gesture(id);
+                if let Some((handler_rc, scale, rotation, pan, center)) = update {
+                    if let Some(handler) = handler_rc.downcast::<PinchGestureHandler>() {
+                        handler.as_pin_ref().gesture_updated(
+                            scale,
+                            rotation,
+                            pan,
+                            handler_position(&handler_rc, center),
+                        );
+                    }
+                }
+            }
+            PointerEventKind::Up | PointerEventKind::Cancel => {
+                let handler = self.touch_state.borrow_mut().end_gesture(id);
+                if let Some(handler) =
+                    handler.as_ref().and_then(|item| item.downcast::<PinchGestureHandler>())
+                {
+                    handler.as_pin_ref().gesture_ended();
+                }
+            }
+            _ => {}
+        }
+        crate::properties::ChangeTracker::run_change_handlers();
+    }
+
     /// Receive a key event and pass it to the items of the component to
     /// change their state.
     ///
     /// Arguments:
     /// * `event`: The key event received by the windowing system.
     /// * `component`: The Slint compiled component that provides the tree of items.
-    pub fn process_key_input(&self, mut event: KeyEvent) {
+    pub fn process_key_input(&self, event: KeyEvent) {
+        self.process_key_input_with_navigation(event, None)
+    }
+
+    /// Receives the press or the release of a directional button of a gamepad or a remote
+    /// control, as the arrow `key`.
+    ///
+    /// It's passed to the focus item like a key event. If the focus item doesn't handle the
+    /// press, the focus moves to the closest item in that direction, whatever the `key-navigation`
+    /// of the window.
+    pub fn process_directional_key(&self, key: char, pressed: bool) {
+        self.process_key_input_with_navigation(
+            KeyEvent {
+                text: key.into(),
+                event_type: if pressed {
+                    KeyEventType::KeyPressed
+                } else {
+                    KeyEventType::KeyReleased
+                },
+                ..Default::default()
+            },
+            Some(KeyNavigation::Spatial),
+        )
+    }
+
+    /// Processes a key event, moving the focus with the arrow keys according to `key_navigation`,
+    /// or to the `key-navigation` of the window if it's `None`.
+    fn process_key_input_with_navigation(
+        &self,
+        mut event: KeyEvent,
+        key_navigation: Option<KeyNavigation>,
+    ) {
         if let Some(updated_modifier) = self
             .modifiers
             .get()
@@ -700,6 +1114,14 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
 
         event.modifiers = self.modifiers.get().into();
 
+        if event.event_type == KeyEventType::KeyPressed {
+            self.record_physical_key_text(&event);
+        }
+
+        if self.modal_child().is_some() {
+            return;
+        }
+
         let mut item = self.focus_item.borrow().clone().upgrade();
 
         if item.as_ref().is_some_and(|i| !i.is_visible()) {
@@ -718,9 +1140,36 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
             item = focus_item.parent_item();
         }
 
-        // Make Tab/Backtab handle keyboard focus
+        if event.event_type == KeyEventType::KeyPressed && self.activate_menu_shortcut(&event) {
+            crate::properties::ChangeTracker::run_change_handlers();
+            return;
+        }
+
+        // Make Tab/Backtab handle keyboard focus, and the arrow keys depending on the key-navigation
+        let key_navigation = key_navigation.unwrap_or_else(|| self.key_navigation());
         let extra_mod = event.modifiers.control || event.modifiers.meta || event.modifiers.alt;
-        if event.text.starts_with(key_codes::Tab)
+        let navigation_key = event
+            .text
+            .chars()
+            .next()
+            .filter(|c| {
+                matches!(
+                    *c,
+                    key_codes::LeftArrow
+                        | key_codes::RightArrow
+                        | key_codes::UpArrow
+                        | key_codes::DownArrow
+                )
+            })
+            .filter(|_| {
+                event.event_type == KeyEventType::KeyPressed
+                    && !event.modifiers.shift
+                    && !extra_mod
+                    && key_navigation != KeyNavigation::Tab
+            });
+        if let Some(key) = navigation_key {
+            self.move_focus_with_arrow_key(key, key_navigation);
+        } else if event.text.starts_with(key_codes::Tab)
             && !event.modifiers.shift
             && !extra_mod
             && event.event_type == KeyEventType::KeyPressed
@@ -791,6 +1240,28 @@ pub fn set_focus_item(&self, new_focus_item: &ItemRc, set_focus: bool) {
         if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
             window_adapter.handle_focus_change(old, new);
         }
+        self.reveal_focus_item();
+    }
+
+    /// Sets the area of the window covered by the virtual keyboard, and scrolls the focused item
+    /// into view if the keyboard covers it.
+    pub fn set_virtual_keyboard_rect(&self, rect: LogicalRect) {
+        self.virtual_keyboard_rect.set(rect);
+        self.reveal_focus_item();
+    }
+
+    /// Scrolls the Flickables containing the focus item so that it isn't covered by the virtual keyboard.
+    fn reveal_focus_item(&self) {
+        let keyboard = self.virtual_keyboard_rect.get();
+        if keyboard.is_empty() {
+            return;
+        }
+        let Some(focus_item) = self.focus_item.borrow().upgrade() else { return };
+        let geometry = focus_item.geometry();
+        let item_rect = LogicalRect::new(focus_item.map_to_window(geometry.origin), geometry.size);
+        if item_rect.intersects(&keyboard) {
+            crate::items::scroll_above(&focus_item, keyboard.origin.y_length());
+        }
     }
 
     /// Take the focus_item out of this Window
@@ -887,6 +1358,99 @@ pub fn focus_previous_item(&self) {
         }
     }
 
+    /// Returns the value of the `key-navigation` property of the window.
+    fn key_navigation(&self) -> KeyNavigation {
+        self.window_item().map_or(KeyNavigation::Tab, |item| item.as_pin_ref().key_navigation())
+    }
+
+    /// Moves the focus by `delta` items in response to the rotation of a rotary encoder, and
+    /// scrolls the newly focused item into view.
+    pub fn process_rotary_encoder(&self, delta: i32) {
+        for _ in 0..delta.unsigned_abs() {
+            if delta > 0 {
+                self.focus_next_item();
+            } else {
+                self.focus_previous_item();
+            }
+        }
+        if let Some(focus_item) = self.focus_item.borrow().upgrade() {
+            crate::items::scroll_into_view(&focus_item);
+        }
+        crate::properties::ChangeTracker::run_change_handlers();
+    }
+
+    /// Moves the focus for an arrow key that the focus item didn't handle, and scrolls the newly
+    /// focused item into view.
+    fn move_focus_with_arrow_key(&self, key: char, key_navigation: KeyNavigation) {
+        match key_navigation {
+            KeyNavigation::Spatial => self.focus_item_in_direction(key),
+            _ if matches!(key, key_codes::RightArrow | key_codes::DownArrow) => {
+                self.focus_next_item()
+            }
+            _ => self.focus_previous_item(),
+        }
+        if let Some(focus_item) = self.focus_item.borrow().upgrade() {
+            crate::items::scroll_into_view(&focus_item);
+        }
+    }
+
+    /// Moves the focus to the item that is closest to the focus item in the direction of the arrow `key`.
+    fn focus_item_in_direction(&self, key: char) {
+        let Some(current) = self.focus_item.borrow().upgrade() else {
+            self.focus_next_item();
+            return;
+        };
+        let center = |item: &ItemRc| {
+            let geometry = item.geometry();
+            LogicalRect::new(item.map_to_window(geometry.origin), geometry.size).center()
+        };
+        let origin = center(&current);
+
+        // Score all the other items in the focus chain that are in the direction of the key,
+        // preferring the ones that are the least off the axis.
+        let mut candidates = Vec::new();
+        let first = next_focus_item(current.clone());
+        let mut item = first.clone();
+        loop {
+            if item == current {
+                break;
+            }
+            if item.is_visible() {
+                let position = center(&item);
+                let (along, across) = match key {
+                    key_codes::LeftArrow => (origin.x - position.x, position.y - origin.y),
+                    key_codes::RightArrow => (position.x - origin.x, position.y - origin.y),
+                    key_codes::UpArrow => (origin.y - position.y, position.x - origin.x),
+                    _ => (position.y - origin.y, position.x - origin.x),
+                };
+                if along > 0 as Coord {
+                    candidates.push((along + 2 as Coord * across.abs(), item.clone()));
+                }
+            }
+            item = next_focus_item(item);
+            if item == first {
+                break;
+            }
+        }
+        if candidates.is_empty() {
+            return;
+        }
+        candidates.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+
+        let old = self.take_focus_item();
+        let new =
+            candidates.into_iter().map(|(_, item)| item).chain(core::iter::once(current)).find(
+                |item| {
+                    self.publish_focus_item(&Some(item.clone()))
+                        == crate::input::FocusEventResult::FocusAccepted
+                },
+            );
+        let window_adapter = self.window_adapter();
+        if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
+            window_adapter.handle_focus_change(old, new);
+        }
+    }
+
     /// Marks the window to be the active window. This typically coincides with the keyboard
     /// focus. One exception though is when a popup is shown, in which case the window may
     /// remain active but temporarily loose focus to the popup.
@@ -894,6 +1458,9 @@ pub fn focus_previous_item(&self) {
     /// This results in WindowFocusReceived and WindowFocusLost events.
     pub fn set_active(&self, have_focus: bool) {
         self.pinned_fields.as_ref().project_ref().active.set(have_focus);
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().active.set(have_focus);
+        }
 
         let event = if have_focus {
             crate::input::FocusEvent::WindowReceivedFocus
@@ -942,6 +1509,7 @@ pub fn draw_contents<T>(
         render_components: impl FnOnce(&[(&ItemTreeRc, LogicalPoint)]) -> T,
     ) -> Option<T> {
         let component_rc = self.try_component()?;
+        self.begin_frame();
         Some(self.pinned_fields.as_ref().project_ref().redraw_tracker.evaluate_as_dependency_root(
             || {
                 if !self
@@ -966,6 +1534,72 @@ pub fn draw_contents<T>(
         ))
     }
 
+    /// Records the start of a frame and invokes the callback set with `Window::on_frame()`, before the
+    /// items are rendered so that the properties it changes are shown in this frame.
+    fn begin_frame(&self) {
+        let now = crate::animations::Instant::now();
+        self.last_frame_time.set(Some(now));
+        let refresh_interval = self
+            .window_adapter()
+            .internal(crate::InternalToken)
+            .and_then(|internal| internal.refresh_interval());
+        let frame_interval = match (refresh_interval, self.min_frame_interval.get()) {
+            (Some(refresh), Some(limit)) => Some(refresh.max(limit)),
+            (refresh, limit) => refresh.or(limit),
+        };
+        // With vsync, the frame is shown at the next refresh of the display
+        let presentation_delay =
+            refresh_interval.filter(|_| self.vsync_enabled.get()).unwrap_or_default();
+        self.frame_requested.call(&(crate::api::FrameInfo {
+            predicted_presentation_time: core::time::Duration::from_millis(now.0)
+                + presentation_delay,
+            frame_interval,
+        },));
+    }
+
+    /// Sets the callback that is invoked when data from another application is dragged over the
+    /// window or dropped on it.
+    pub fn on_drag_and_drop(
+        &self,
+        mut callback: impl FnMut(&crate::api::DragAndDropEvent) + 'static,
+    ) {
+        self.drag_and_drop.set_handler(move |(event,)| callback(event));
+    }
+
+    /// Sets the callback that is invoked before each frame is rendered.
+    pub fn on_frame(&self, mut callback: impl FnMut(&crate::api::FrameInfo) + 'static) {
+        self.frame_requested.set_handler(move |(info,)| callback(info));
+    }
+
+    /// Limits how often the window is redrawn for animations, or removes the limit with `None`.
+    pub fn set_frame_rate_limit(&self, frames_per_second: Option<u32>) {
+        self.min_frame_interval.set(
+            frames_per_second
+                .filter(|fps| *fps > 0)
+                .map(|fps| core::time::Duration::from_secs(1) / fps),
+        );
+    }
+
+    /// Returns the time to wait before the next frame for animations can be rendered to respect the
+    /// limit set with `Window::set_frame_rate_limit()`, or `None` if it can be rendered right away.
+    pub fn duration_until_next_frame(&self) -> Option<core::time::Duration> {
+        let interval = self.min_frame_interval.get()?;
+        let elapsed = crate::animations::Instant::now() - self.last_frame_time.get()?;
+        interval.checked_sub(elapsed).filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Returns false if the renderer should present frames without waiting for the vertical blank of
+    /// the display.
+    pub fn vsync_enabled(&self) -> bool {
+        self.vsync_enabled.get()
+    }
+
+    /// Sets whether the renderer presents frames in sync with the vertical blank of the display.
+    pub fn set_vsync_enabled(&self, enabled: bool) {
+        self.vsync_enabled.set(enabled);
+        self.window_adapter().request_redraw();
+    }
+
     /// Registers the window with the windowing system, in order to render the component's items and react
     /// to input events once the event loop spins.
     pub fn show(&self) -> Result<(), PlatformError> {
@@ -976,6 +1610,14 @@ pub fn show(&self) -> Result<(), PlatformError> {
             }
         }
 
+        if let Some(parent) = self.parent_window().filter(|_| self.modal.get()) {
+            let mut modal_children =
+                WindowInner::from_pub(parent.window()).modal_children.borrow_mut();
+            if !modal_children.iter().any(|child| child.ptr_eq(&self.window_adapter_weak)) {
+                modal_children.push(self.window_adapter_weak.clone());
+            }
+        }
+
         self.update_window_properties();
         self.window_adapter().set_visible(true)?;
         // Make sure that the window's inner size is in sync with the root window item's
@@ -989,8 +1631,49 @@ pub fn show(&self) -> Result<(), PlatformError> {
         Ok(())
     }
 
+    /// Returns the window set with [`Self::set_parent()`], if it still exists.
+    pub fn parent_window(&self) -> Option<Rc<dyn WindowAdapter>> {
+        self.parent_window.borrow().as_ref().and_then(Weak::upgrade)
+    }
+
+    /// Makes the window a child window of `parent`, or a top-level window again when `parent` is `None`.
+    pub fn set_parent(&self, parent: Option<Rc<dyn WindowAdapter>>) {
+        *self.parent_window.borrow_mut() = parent.as_ref().map(Rc::downgrade);
+        if let Some(x) = self.window_adapter().internal(crate::InternalToken) {
+            x.set_parent_window(parent, self.modal.get());
+        }
+    }
+
+    /// Returns whether the window blocks the input to its parent while it's visible.
+    pub fn is_modal(&self) -> bool {
+        self.modal.get()
+    }
+
+    /// Sets whether the window blocks the input to its parent while it's visible.
+    pub fn set_modal(&self, modal: bool) {
+        if self.modal.replace(modal) != modal {
+            if let Some(x) = self.window_adapter().internal(crate::InternalToken) {
+                x.set_parent_window(self.parent_window(), modal);
+            }
+        }
+    }
+
+    /// Returns the visible modal window that blocks the input to this window, if there's one.
+    fn modal_child(&self) -> Option<Rc<dyn WindowAdapter>> {
+        let mut modal_children = self.modal_children.borrow_mut();
+        modal_children.retain(|child| child.strong_count() > 0);
+        let this = self.window_adapter_weak.upgrade()?;
+        modal_children.iter().filter_map(Weak::upgrade).find(|child| {
+            let child = WindowInner::from_pub(child.window());
+            child.is_visible()
+                && child.modal.get()
+                && child.parent_window().map_or(false, |parent| Rc::ptr_eq(&parent, &this))
+        })
+    }
+
     /// De-registers the window with the windowing system.
     pub fn hide(&self) -> Result<(), PlatformError> {
+        self.store_geometry();
         let result = self.window_adapter().set_visible(false);
         let was_visible = self.strong_component_ref.borrow_mut().take().is_some();
         if was_visible {
@@ -1011,6 +1694,80 @@ pub fn color_scheme(&self) -> ColorScheme {
             .map_or(ColorScheme::Unknown, |x| x.color_scheme())
     }
 
+    /// Returns the accent color of the system, or a transparent color if it's unknown
+    pub fn accent_color(&self) -> crate::Color {
+        self.window_adapter()
+            .internal(crate::InternalToken)
+            .map_or(Default::default(), |x| x.accent_color())
+    }
+
+    /// Sets the callback that's run when the color scheme of the system changes, for example when
+    /// the user switches to a dark theme in the system settings.
+    pub fn on_color_scheme_changed(&self, callback: impl FnMut(ColorScheme) + 'static) {
+        self.color_scheme_tracker.init(
+            (self.window_adapter_weak.clone(), RefCell::new(callback)),
+            |(window_adapter_weak, _)| {
+                window_adapter_weak.upgrade().map_or(ColorScheme::Unknown, |window_adapter| {
+                    WindowInner::from_pub(window_adapter.window()).color_scheme()
+                })
+            },
+            |(_, callback), color_scheme| (callback.borrow_mut())(*color_scheme),
+        );
+    }
+
+    /// Returns the metrics of the font of the given family at the given size. An empty family or a
+    /// zero size select the default font family or size of the window.
+    pub fn font_metrics(
+        &self,
+        font_family: SharedString,
+        font_size: LogicalLength,
+    ) -> crate::items::FontMetrics {
+        let font_request = self.font_request(font_family, font_size);
+        let scale_factor = crate::lengths::ScaleFactor::new(self.scale_factor());
+        self.window_adapter().renderer().font_metrics(font_request, scale_factor)
+    }
+
+    /// Returns the distance between the baselines of two consecutive lines of text laid out with
+    /// the font of the given family at the given size, which includes the line gap of the font.
+    /// An empty family or a zero size select the default font family or size of the window.
+    pub fn line_spacing(
+        &self,
+        font_family: SharedString,
+        font_size: LogicalLength,
+    ) -> LogicalLength {
+        let font_request = self.font_request(font_family, font_size);
+        let scale_factor = crate::lengths::ScaleFactor::new(self.scale_factor());
+        self.window_adapter().renderer().line_spacing(font_request, scale_factor)
+    }
+
+    fn font_request(
+        &self,
+        font_family: SharedString,
+        font_size: LogicalLength,
+    ) -> crate::graphics::FontRequest {
+        let window_item = self.window_item();
+        let font_request = crate::graphics::FontRequest {
+            family: if font_family.is_empty() {
+                window_item.as_ref().and_then(|item| item.as_pin_ref().font_family())
+            } else {
+                Some(font_family)
+            },
+            pixel_size: if font_size.get() == 0 as Coord {
+                window_item.as_ref().and_then(|item| item.as_pin_ref().font_size())
+            } else {
+                Some(font_size)
+            },
+            ..Default::default()
+        };
+        self.apply_system_font(font_request)
+    }
+
+    /// Returns the visible items of the window with their geometry and main properties, as
+    /// a JSON document. See [`Window::serialize_scene()`].
+    pub fn serialize_scene(&self) -> alloc::string::String {
+        scene_dump::serialize_scene(self)
+    }
+
     /// Return wether the platform supports native menu bars
     pub fn supports_native_menu_bar(&self) -> bool {
         self.window_adapter()
@@ -1018,11 +1775,45 @@ pub fn supports_native_menu_bar(&self) -> bool {
             .map_or(false, |x| x.supports_native_menu_bar())
     }
 
-    /// Setup the native menu bar
+    /// Setup the native menu bar, or handle the keyboard shortcuts of the menu bar rendered by
+    /// Slint if the platform doesn't support native menu bars
     pub fn setup_menubar(&self, menubar: vtable::VBox<MenuVTable>) {
-        if let Some(x) = self.window_adapter().internal(crate::InternalToken) {
-            x.setup_menubar(menubar);
+        match self.window_adapter().internal(crate::InternalToken) {
+            Some(x) if x.supports_native_menu_bar() => x.setup_menubar(menubar),
+            _ => *self.menubar.borrow_mut() = Some(menubar),
+        }
+    }
+
+    /// Activates the enabled entry of the menu bar rendered by Slint that has the shortcut of
+    /// the key event, and returns true if there was one.
+    fn activate_menu_shortcut(&self, event: &KeyEvent) -> bool {
+        fn find_entry(
+            menu: vtable::VRef<MenuVTable>,
+            parent: Option<&MenuEntry>,
+            event: &KeyEvent,
+            depth: usize,
+        ) -> Option<MenuEntry> {
+            let mut entries = Default::default();
+            menu.sub_menu(parent, &mut entries);
+            entries.iter().filter(|entry| !entry.disabled).find_map(|entry| {
+                // the top level always has a sub menu regardless of entry.has_sub_menu
+                if entry.has_sub_menu || depth == 0 {
+                    // the menus can be infinitely deep
+                    (depth < 15).then(|| find_entry(menu, Some(entry), event, depth + 1)).flatten()
+                } else {
+                    crate::input::matches_shortcut(&entry.shortcut, event).then(|| entry.clone())
+                }
+            })
         }
+
+        // Take the menu bar so that the activated callback can set up another one
+        let Some(menubar) = self.menubar.take() else { return false };
+        let entry = find_entry(menubar.borrow(), None, event, 0);
+        if let Some(entry) = &entry {
+            menubar.activate(entry);
+        }
+        self.menubar.borrow_mut().get_or_insert(menubar);
+        entry.is_some()
     }
 
     /// Show a popup at the given position relative to the item and returns its ID.
@@ -1217,8 +2008,70 @@ pub fn scale_factor(&self) -> f32 {
     }
 
     /// Sets the scale factor for the window. This is set by the backend or for testing.
+    /// It's ignored while the scale factor is overridden with `Window::set_scale_factor_override()`.
     pub(crate) fn set_scale_factor(&self, factor: f32) {
-        self.pinned_fields.scale_factor.set(factor)
+        self.platform_scale_factor.set(factor);
+        if self.scale_factor_override.get().is_none() {
+            self.pinned_fields.scale_factor.set(factor)
+        }
+    }
+
+    /// Returns the scale factor set with `Window::set_scale_factor_override()`.
+    pub fn scale_factor_override(&self) -> Option<f32> {
+        self.scale_factor_override.get()
+    }
+
+    /// Replaces the scale factor reported by the platform, or uses it again if `factor` is `None`.
+    /// The logical size of the window is kept, so the window is resized on the screen.
+    pub fn set_scale_factor_override(&self, factor: Option<f32>) {
+        let factor = factor.filter(|f| f.is_finite() && *f > 0.);
+        self.scale_factor_override.set(factor);
+        let scale_factor = factor.unwrap_or(self.platform_scale_factor.get());
+        if scale_factor != self.scale_factor() {
+            self.pinned_fields.scale_factor.set(scale_factor);
+            // Let the backend resize the window to the logical size in the new scale factor
+            self.update_window_properties();
+            self.window_adapter().request_redraw();
+        }
+    }
+
+    /// Returns the factor by which the user asked to enlarge texts in the system settings.
+    pub fn text_scale_factor(&self) -> f32 {
+        self.pinned_fields.as_ref().project_ref().text_scale_factor.get()
+    }
+
+    pub(crate) fn set_system_font(&self, family: SharedString, text_scale_factor: f32) {
+        self.pinned_fields.system_font_family.set(family);
+        self.pinned_fields.text_scale_factor.set(text_scale_factor);
+    }
+
+    /// Completes a font request resolved from an element and the window item with the default
+    /// font family and the text scale factor of the system settings.
+    pub fn apply_system_font(
+        &self,
+        mut font_request: crate::graphics::FontRequest,
+    ) -> crate::graphics::FontRequest {
+        // Register a dependency, so that the texts are laid out again when fonts are unregistered
+        self.context().0.fonts_dirty.as_ref().get();
+        let pinned_fields = self.pinned_fields.as_ref().project_ref();
+        if font_request.family.is_none() {
+            let family = pinned_fields.system_font_family.get();
+            if !family.is_empty() {
+                font_request.family = Some(family);
+            }
+        }
+        let text_scale_factor = pinned_fields.text_scale_factor.get();
+        if text_scale_factor != 1. {
+            // Texts without a font-size use the default size of the renderer, which must be scaled too
+            let size = font_request.pixel_size.or_else(|| {
+                self.window_adapter_weak
+                    .upgrade()
+                    .map(|adapter| adapter.renderer().default_font_size())
+            });
+            font_request.pixel_size = size
+                .map(|size| LogicalLength::new((size.get() as f32 * text_scale_factor) as Coord));
+        }
+        font_request
     }
 
     /// Reads the global property `TextInputInterface.text-input-focused`
@@ -1272,6 +2125,54 @@ pub fn request_close(&self) -> bool {
         }
     }
 
+    /// Sets the callback that's run when the user switches to a different keyboard layout.
+    pub fn on_keyboard_layout_changed(&self, mut callback: impl FnMut() + 'static) {
+        self.keyboard_layout_changed.set_handler(move |()| callback());
+    }
+
+    /// Forgets the texts of the physical keys, which belong to the previous layout, and runs the
+    /// keyboard_layout_changed callback.
+    pub(crate) fn keyboard_layout_changed(&self) {
+        self.physical_key_texts.borrow_mut().clear();
+        self.keyboard_layout_changed.call(&());
+    }
+
+    /// Returns the text that the physical key named `physical_key` produces without modifiers in the
+    /// current keyboard layout. Asks the backend, and otherwise falls back to the text that the key
+    /// produced when it was last pressed in this layout.
+    pub fn physical_key_text(&self, physical_key: &str) -> Option<SharedString> {
+        self.window_adapter()
+            .internal(crate::InternalToken)
+            .and_then(|internal| internal.physical_key_text(physical_key))
+            .or_else(|| {
+                self.physical_key_texts
+                    .borrow()
+                    .iter()
+                    .find(|(key, _)| key == physical_key)
+                    .map(|(_, text)| text.clone())
+            })
+    }
+
+    fn record_physical_key_text(&self, event: &KeyEvent) {
+        let modifiers = &event.modifiers;
+        if event.physical_key.is_empty()
+            || modifiers.alt
+            || modifiers.control
+            || modifiers.meta
+            || modifiers.shift
+            // Special keys, like the arrow keys, are encoded as control or private use characters
+            || event.text.is_empty()
+            || event.text.chars().any(|c| c.is_control() || ('\u{e000}'..='\u{f8ff}').contains(&c))
+        {
+            return;
+        }
+        let mut texts = self.physical_key_texts.borrow_mut();
+        match texts.iter_mut().find(|(key, _)| *key == event.physical_key) {
+            Some((_, text)) => *text = event.text.clone(),
+            None => texts.push((event.physical_key.clone(), event.text.clone())),
+        }
+    }
+
     /// Returns if the window is currently maximized
     pub fn is_fullscreen(&self) -> bool {
         if let Some(window_item) = self.window_item() {
@@ -1289,6 +2190,45 @@ pub fn set_fullscreen(&self, enabled: bool) {
         }
     }
 
+    /// Returns the value of the `window-opacity` property, clamped between 0 and 1.
+    pub fn opacity(&self) -> f32 {
+        self.window_item().map_or(1., |w| w.as_pin_ref().window_opacity().clamp(0., 1.))
+    }
+
+    /// Sets the `window-opacity` property.
+    pub fn set_opacity(&self, opacity: f32) {
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().window_opacity.set(opacity);
+            self.update_window_properties()
+        }
+    }
+
+    /// Returns the level of the window, which is `AlwaysOnTop` when the `always-on-top` property
+    /// is set.
+    pub fn window_level(&self) -> crate::items::WindowLevel {
+        match self.window_item() {
+            Some(window_item) if window_item.as_pin_ref().always_on_top() => {
+                crate::items::WindowLevel::AlwaysOnTop
+            }
+            Some(window_item) => window_item.as_pin_ref().window_level(),
+            None => Default::default(),
+        }
+    }
+
+    /// Sets the icons of the window, which replace the `icon` property unless `icons` is empty.
+    pub fn set_icons(&self, icons: Vec<crate::graphics::Image>) {
+        self.icons.replace(icons);
+        self.update_window_properties()
+    }
+
+    /// Set whether the window is kept above or below the windows of other applications.
+    pub fn set_window_level(&self, level: crate::items::WindowLevel) {
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().window_level.set(level);
+            self.update_window_properties()
+        }
+    }
+
     /// Returns if the window is currently maximized
     pub fn is_maximized(&self) -> bool {
         self.maximized.get()
@@ -1297,6 +2237,9 @@ pub fn is_maximized(&self) -> bool {
     /// Set the window as maximized or unmaximized
     pub fn set_maximized(&self, maximized: bool) {
         self.maximized.set(maximized);
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().maximized.set(maximized);
+        }
         self.update_window_properties()
     }
 
@@ -1308,9 +2251,75 @@ pub fn is_minimized(&self) -> bool {
     /// Set the window as minimized or unminimized
     pub fn set_minimized(&self, minimized: bool) {
         self.minimized.set(minimized);
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().minimized.set(minimized);
+        }
         self.update_window_properties()
     }
 
+    /// Returns the region of the topmost enabled `WindowFrameArea` at `position`, for the backends
+    /// that report these areas to the windowing system as part of the frame of the window.
+    pub fn window_frame_region_at(
+        &self,
+        position: LogicalPoint,
+    ) -> Option<crate::items::WindowFrameRegion> {
+        let component = self.try_component()?;
+        crate::input::find_window_frame_region(&ItemRc::new(component, 0), position)
+    }
+
+    /// Lets the windowing system move the window with the pointer, until the pressed pointer button is released.
+    pub fn start_system_move(&self) {
+        if let Some(x) = self.window_adapter().internal(crate::InternalToken) {
+            x.drag_window_frame(crate::items::WindowFrameRegion::TitleBar);
+        }
+    }
+
+    /// Lets the windowing system resize the window at the given edge or corner with the pointer, until the
+    /// pressed pointer button is released.
+    pub fn start_system_resize(&self, edge: crate::items::WindowResizeEdge) {
+        use crate::items::{WindowFrameRegion, WindowResizeEdge};
+        let region = match edge {
+            WindowResizeEdge::North => WindowFrameRegion::ResizeNorth,
+            WindowResizeEdge::South => WindowFrameRegion::ResizeSouth,
+            WindowResizeEdge::East => WindowFrameRegion::ResizeEast,
+            WindowResizeEdge::West => WindowFrameRegion::ResizeWest,
+            WindowResizeEdge::NorthEast => WindowFrameRegion::ResizeNorthEast,
+            WindowResizeEdge::NorthWest => WindowFrameRegion::ResizeNorthWest,
+            WindowResizeEdge::SouthEast => WindowFrameRegion::ResizeSouthEast,
+            WindowResizeEdge::SouthWest => WindowFrameRegion::ResizeSouthWest,
+        };
+        if let Some(x) = self.window_adapter().internal(crate::InternalToken) {
+            x.drag_window_frame(region);
+        }
+    }
+
+    /// Returns if the window is currently occluded
+    pub fn is_occluded(&self) -> bool {
+        self.occluded.get()
+    }
+
+    /// Records whether the window is completely hidden by other windows or off screen, as
+    /// reported by the windowing system.
+    pub fn set_occluded(&self, occluded: bool) {
+        self.occluded.set(occluded);
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().occluded.set(occluded);
+        }
+    }
+
+    /// Returns who draws the decorations of the window.
+    pub fn decoration_mode(&self) -> crate::items::WindowDecorationMode {
+        self.decoration_mode.get()
+    }
+
+    /// Records who draws the decorations of the window, as reported by the windowing system.
+    pub fn set_decoration_mode(&self, mode: crate::items::WindowDecorationMode) {
+        self.decoration_mode.set(mode);
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().decoration_mode.set(mode);
+        }
+    }
+
     /// Returns the (context global) xdg app id for use with wayland and x11.
     pub fn xdg_app_id(&self) -> Option<SharedString> {
         self.ctx.xdg_app_id()
@@ -1321,6 +2330,29 @@ pub fn window_adapter(&self) -> Rc<dyn WindowAdapter> {
         self.window_adapter_weak.upgrade().unwrap()
     }
 
+    /// Restores the geometry of the window from the settings, and stores it there when the window is
+    /// hidden. See [`crate::api::Window::persist_geometry`].
+    pub fn persist_geometry(&self, settings: &crate::settings::Settings, key: SharedString) {
+        settings.restore_window_geometry(self.window_adapter().window(), &key);
+        let first_time = self.geometry_persistence.replace(Some((settings.clone(), key))).is_none();
+        if first_time {
+            crate::settings::register_persisted_window(self.window_adapter_weak.clone());
+        }
+    }
+
+    /// Stores the geometry of the window in the settings given to [`Self::persist_geometry`] and
+    /// saves them, if the window is shown.
+    pub(crate) fn store_geometry(&self) {
+        if let Some((settings, key)) = self.geometry_persistence.borrow().as_ref() {
+            if self.strong_component_ref.borrow().is_some() {
+                settings.store_window_geometry(self.window_adapter().window(), key);
+                if let Err(err) = settings.save() {
+                    crate::debug_log!("Error saving the geometry of the window: {err}");
+                }
+            }
+        }
+    }
+
     /// Private access to the WindowInner for a given window.
     pub fn from_pub(window: &crate::api::Window) -> &Self {
         &window.0
@@ -1689,6 +2721,30 @@ fn call(&self) -> CloseRequestResponse {
             .map_or(ColorScheme::Unknown, |x| x.color_scheme())
     }
 
+    /// Return the accent color of the system, or a transparent color if it's unknown
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_accent_color(
+        handle: *const WindowAdapterRcOpaque,
+        out: &mut crate::Color,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        *out = window_adapter
+            .internal(crate::InternalToken)
+            .map_or(Default::default(), |x| x.accent_color());
+    }
+
+    /// Returns the metrics of the font of the given family at the given size
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_font_metrics(
+        handle: *const WindowAdapterRcOpaque,
+        font_family: &SharedString,
+        font_size: f32,
+    ) -> crate::items::FontMetrics {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window())
+            .font_metrics(font_family.clone(), LogicalLength::new(font_size as _))
+    }
+
     /// Return wether the platform supports native menu bars
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_supports_native_menu_bar(
@@ -1708,9 +2764,8 @@ fn call(&self) -> CloseRequestResponse {
         menu_instance: NonNull<c_void>,
     ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        window_adapter
-            .internal(crate::InternalToken)
-            .map(|x| x.setup_menubar(vtable::VBox::from_raw(vtable, menu_instance.cast())));
+        WindowInner::from_pub(window_adapter.window())
+            .setup_menubar(vtable::VBox::from_raw(vtable, menu_instance.cast()));
     }
 
     /// Return the default-font-size property of the WindowItem
@@ -1810,6 +2865,23 @@ fn call(&self) -> CloseRequestResponse {
         window_adapter.window().set_maximized(value)
     }
 
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_start_system_move(
+        handle: *const WindowAdapterRcOpaque,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).start_system_move()
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_start_system_resize(
+        handle: *const WindowAdapterRcOpaque,
+        edge: crate::items::WindowResizeEdge,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).start_system_resize(edge)
+    }
+
     /// Takes a snapshot of the window contents and returns it as RGBA8 encoded pixel buffer.
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_take_snapshot(
@@ -0,0 +1,227 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Serialization of the visible items of a window into a compact JSON document, to attach to crash
+//! reports. The `slint-viewer --scene` command shows such a document again.
+//!
+//! The document has the following shape, where each item has a `type`, its geometry relative to
+//! the window, the properties relevant for its type, and its visible `children`:
+//! ```json
+//! { "version": 1, "width": 200, "height": 100, "scale_factor": 1,
+//!   "items": [{ "type": "Rectangle", "x": 0, "y": 0, "width": 200, "height": 100,
+//!               "background": "#ff0000ff", "children": [] }] }
+//! ```
+//! Pixel data of images is not included, only their path and size.
+
+use core::fmt::Write;
+use core::pin::Pin;
+
+use crate::graphics::Image;
+use crate::item_tree::ItemRc;
+use crate::items::*;
+use crate::lengths::{LogicalLength, LogicalPoint};
+use crate::{Brush, Color};
+use alloc::string::String;
+
+/// Version of the format, to increment when the meaning of existing fields changes.
+const FORMAT_VERSION: u32 = 1;
+
+/// Texts longer than that are truncated, to keep the document compact.
+const MAX_TEXT_LENGTH: usize = 200;
+
+pub(super) fn serialize_scene(window: &super::WindowInner) -> String {
+    let mut out = String::new();
+    let size = window.window_adapter().size().to_logical(window.scale_factor());
+    let _ = write!(
+        out,
+        r#"{{"version":{FORMAT_VERSION},"width":{},"height":{},"scale_factor":{},"items":["#,
+        size.width,
+        size.height,
+        window.scale_factor()
+    );
+    crate::properties::evaluate_no_tracking(|| {
+        if let Some(component) = window.try_component() {
+            serialize_item(&mut out, &ItemRc::new(component, 0), LogicalPoint::default());
+        }
+    });
+    out.push_str("]}");
+    out
+}
+
+fn serialize_item(out: &mut String, item: &ItemRc, parent_origin: LogicalPoint) {
+    let geometry = item.geometry().translate(parent_origin.to_vector());
+    let item_ref = item.borrow();
+    let _ = write!(
+        out,
+        r#"{{"type":"{}","x":{},"y":{},"width":{},"height":{}"#,
+        item_type_name(item_ref),
+        geometry.origin.x,
+        geometry.origin.y,
+        geometry.size.width,
+        geometry.size.height
+    );
+    serialize_properties(out, item_ref);
+    out.push_str(r#","children":["#);
+    let mut first = true;
+    let mut child = item.first_child();
+    while let Some(current) = child {
+        if current.is_visible() {
+            if !first {
+                out.push(',');
+            }
+            first = false;
+            serialize_item(out, &current, geometry.origin);
+        }
+        child = current.next_sibling();
+    }
+    out.push_str("]}");
+}
+
+fn item_type_name(item: Pin<ItemRef>) -> &'static str {
+    macro_rules! type_name {
+        ($($ty:ident => $name:literal,)*) => {
+            $(if ItemRef::downcast_pin::<$ty>(item).is_some() {
+                return $name;
+            })*
+        };
+    }
+    type_name! {
+        Rectangle => "Rectangle",
+        BorderRectangle => "BorderRectangle",
+        BasicBorderRectangle => "BorderRectangle",
+        ComplexText => "Text",
+        SimpleText => "Text",
+        TextInput => "TextInput",
+        ImageItem => "Image",
+        ClippedImage => "Image",
+        Clip => "Clip",
+        Opacity => "Opacity",
+        BoxShadow => "BoxShadow",
+        Flickable => "Flickable",
+        WindowItem => "Window",
+    }
+    "Item"
+}
+
+fn serialize_properties(out: &mut String, item: Pin<ItemRef>) {
+    if let Some(rect) = ItemRef::downcast_pin::<Rectangle>(item) {
+        write_brush(out, "background", &rect.background());
+    } else if let Some(rect) = ItemRef::downcast_pin::<BorderRectangle>(item) {
+        write_border_rectangle(
+            out,
+            rect.background(),
+            rect.border_color(),
+            rect.border_width(),
+            rect.border_radius(),
+        );
+    } else if let Some(rect) = ItemRef::downcast_pin::<BasicBorderRectangle>(item) {
+        write_border_rectangle(
+            out,
+            rect.background(),
+            rect.border_color(),
+            rect.border_width(),
+            rect.border_radius(),
+        );
+    } else if let Some(text) = ItemRef::downcast_pin::<ComplexText>(item) {
+        write_text(out, &text.text(), &text.color(), text.font_size().get());
+    } else if let Some(text) = ItemRef::downcast_pin::<SimpleText>(item) {
+        write_text(out, &text.text(), &text.color(), text.font_size().get());
+    } else if let Some(text_input) = ItemRef::downcast_pin::<TextInput>(item) {
+        // The text as displayed, with the password characters
+        let text = text_input.visual_representation(None).text;
+        write_text(out, &text, &text_input.color(), text_input.font_size().get());
+    } else if let Some(image) = ItemRef::downcast_pin::<ImageItem>(item) {
+        write_image(out, &image.source(), &image.colorize());
+    } else if let Some(image) = ItemRef::downcast_pin::<ClippedImage>(item) {
+        write_image(out, &image.source(), &image.colorize());
+    } else if let Some(clip) = ItemRef::downcast_pin::<Clip>(item) {
+        let _ = write!(out, r#","clip":{}"#, clip.clip());
+    } else if let Some(opacity) = ItemRef::downcast_pin::<Opacity>(item) {
+        let _ = write!(out, r#","opacity":{}"#, opacity.opacity());
+    } else if let Some(shadow) = ItemRef::downcast_pin::<BoxShadow>(item) {
+        write_color(out, "color", shadow.color());
+        let _ = write!(
+            out,
+            r#","offset_x":{},"offset_y":{},"blur":{},"border_radius":{}"#,
+            shadow.offset_x().get(),
+            shadow.offset_y().get(),
+            shadow.blur().get(),
+            shadow.border_radius().get()
+        );
+    } else if let Some(window_item) = ItemRef::downcast_pin::<WindowItem>(item) {
+        write_brush(out, "background", &window_item.background());
+    }
+}
+
+fn write_border_rectangle(
+    out: &mut String,
+    background: Brush,
+    border_color: Brush,
+    border_width: LogicalLength,
+    border_radius: LogicalLength,
+) {
+    write_brush(out, "background", &background);
+    write_brush(out, "border_color", &border_color);
+    let _ = write!(
+        out,
+        r#","border_width":{},"border_radius":{}"#,
+        border_width.get(),
+        border_radius.get()
+    );
+}
+
+fn write_text(out: &mut String, text: &str, color: &Brush, font_size: crate::Coord) {
+    out.push_str(r#","text":"#);
+    match text.char_indices().nth(MAX_TEXT_LENGTH) {
+        Some((end, _)) => write_string(out, &alloc::format!("{}…", &text[..end])),
+        None => write_string(out, text),
+    }
+    write_brush(out, "color", color);
+    let _ = write!(out, r#","font_size":{font_size}"#);
+}
+
+fn write_image(out: &mut String, source: &Image, colorize: &Brush) {
+    #[cfg(feature = "std")]
+    if let Some(path) = source.path() {
+        out.push_str(r#","source":"#);
+        write_string(out, &path.to_string_lossy());
+    }
+    let size = source.size();
+    let _ = write!(out, r#","source_width":{},"source_height":{}"#, size.width, size.height);
+    if !colorize.is_transparent() {
+        write_brush(out, "colorize", colorize);
+    }
+}
+
+/// Gradients are written as their first color, which is enough to recognize the scene.
+fn write_brush(out: &mut String, name: &str, brush: &Brush) {
+    write_color(out, name, brush.color());
+}
+
+fn write_color(out: &mut String, name: &str, color: Color) {
+    let _ = write!(
+        out,
+        r##","{name}":"#{:02x}{:02x}{:02x}{:02x}""##,
+        color.red(),
+        color.green(),
+        color.blue(),
+        color.alpha()
+    );
+}
+
+fn write_string(out: &mut String, string: &str) {
+    out.push('"');
+    for ch in string.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", ch as u32);
+            }
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
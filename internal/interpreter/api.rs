@@ -24,7 +24,8 @@
 // keep in sync with api/rs/slint/lib.rs
 pub use i_slint_backend_selector::api::*;
 pub use i_slint_core::graphics::{
-    Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
+    Brush, Color, ConicGradientBrush, GradientStop, Image, ImagePatternBrush, LoadImageError,
+    Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer, VisionSimulation,
 };
 use i_slint_core::items::*;
 
@@ -1290,6 +1291,24 @@ pub fn set_property(&self, name: &str, value: Value) -> Result<(), SetPropertyEr
         d.set_property(comp.borrow(), &name, value)
     }
 
+    /// Returns the names of the public properties that can be set with [`Self::set_property()`].
+    #[cfg(feature = "timeline")]
+    pub(crate) fn settable_properties(&self) -> Vec<String> {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let d = comp.description();
+        let elem = d.original.root_element.borrow();
+        elem.property_declarations
+            .iter()
+            .filter(|(_, decl)| {
+                decl.expose_in_public_api
+                    && decl.property_type.is_property_type()
+                    && decl.visibility != i_slint_compiler::object_tree::PropertyVisibility::Output
+            })
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+
     /// Set a handler for the callback with the given name. A callback with that
     /// name must be defined in the document otherwise an error will be returned.
     ///
@@ -1501,6 +1520,39 @@ pub fn component_positions(
         crate::highlight::component_positions(&self.inner, path, offset)
     }
 
+    /// Set the value of the property `name` of the element with the given `id`, as declared
+    /// in the root component (`foo` for `foo := Rectangle {}`) or qualified with the
+    /// name of the component that declares it (`MyButton::foo`).
+    #[cfg(feature = "timeline")]
+    pub(crate) fn set_element_property(
+        &self,
+        id: &str,
+        name: &str,
+        value: Value,
+    ) -> Result<(), SetPropertyError> {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let description = comp.description();
+        let qualified_id = if id.contains("::") {
+            SmolStr::new(id)
+        } else {
+            smol_str::format_smolstr!("{}::{}", description.original.id, id)
+        };
+        let element = description
+            .original_elements
+            .iter()
+            .find(|e| {
+                e.borrow().debug.iter().any(|d| d.qualified_id.as_ref() == Some(&qualified_id))
+            })
+            .ok_or(SetPropertyError::NoSuchProperty)?;
+        crate::eval::store_property(
+            comp.borrow_instance(),
+            element,
+            &normalize_identifier(name),
+            value,
+        )
+    }
+
     /// Find the position of the `element`.
     ///
     /// WARNING: this is not part of the public API
@@ -1629,7 +1681,9 @@ pub enum InvokeError {
 /// events from the windowing system in order to render to the screen
 /// and react to user input.
 pub fn run_event_loop() -> Result<(), PlatformError> {
-    i_slint_backend_selector::with_platform(|b| b.run_event_loop())
+    let result = i_slint_backend_selector::with_platform(|b| b.run_event_loop());
+    i_slint_core::settings::store_window_geometries();
+    result
 }
 
 /// Spawns a [`Future`] to execute in the Slint event loop.
@@ -0,0 +1,179 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the breakpoints set on bindings and callbacks, used by the live preview
+//! to report the values with which a binding was evaluated.
+//!
+//! Breakpoints are identified by the path of the file and the offset of the binding expression
+//! (or of the callback connection) in that file, and are applied to the components instantiated
+//! after they were set.
+//!
+//! The evaluation can't be suspended without blocking the event loop, so a hit pauses the
+//! breakpoints instead: the handler is not called again until [`resume`] is called, which lets
+//! the preview show the state of the first hit until the user continues.
+
+// Without the `internal` feature, nothing can set breakpoints
+#![cfg_attr(not(feature = "internal"), allow(dead_code))]
+
+use crate::dynamic_item_tree::InstanceRef;
+use crate::Value;
+use i_slint_compiler::expression_tree::{BindingExpression, Expression};
+use i_slint_compiler::namedreference::NamedReference;
+use i_slint_compiler::object_tree::ElementRc;
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// Reported when a binding or callback with a breakpoint is evaluated.
+#[derive(Debug, Clone)]
+pub struct BreakpointHit {
+    /// The path of the file containing the binding.
+    pub path: PathBuf,
+    /// The offset of the binding in the file, as passed to [`set_breakpoints`].
+    pub offset: u32,
+    /// The name of the property or callback, qualified with the id of its element.
+    pub binding: String,
+    /// The values of the properties referenced by the binding, and of the arguments of a callback.
+    pub values: Vec<(String, Value)>,
+    /// The value the binding evaluated to, or the value returned by the callback.
+    pub result: Value,
+}
+
+struct Breakpoints {
+    /// The locations as passed to [`set_breakpoints`], and their canonical path
+    locations: Vec<(PathBuf, PathBuf, u32)>,
+    handler: Rc<dyn Fn(BreakpointHit)>,
+    paused: Rc<Cell<bool>>,
+}
+
+thread_local! {
+    static BREAKPOINTS: RefCell<Option<Breakpoints>> = const { RefCell::new(None) };
+}
+
+/// Sets the locations of the breakpoints, replacing the existing ones. `handler` is called
+/// when a binding or callback with a breakpoint is evaluated, after which the breakpoints are
+/// paused until [`resume`] is called.
+///
+/// This only affects the components instantiated afterwards.
+pub fn set_breakpoints(locations: Vec<(PathBuf, u32)>, handler: impl Fn(BreakpointHit) + 'static) {
+    let locations = locations
+        .into_iter()
+        .map(|(path, offset)| {
+            let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+            (path, canonical, offset)
+        })
+        .collect::<Vec<_>>();
+    BREAKPOINTS.with(|b| {
+        *b.borrow_mut() = (!locations.is_empty()).then(|| Breakpoints {
+            locations,
+            handler: Rc::new(handler),
+            paused: Default::default(),
+        })
+    });
+}
+
+/// Lets the breakpoints report the next evaluation after a hit.
+pub fn resume() {
+    BREAKPOINTS.with(|b| {
+        if let Some(b) = b.borrow().as_ref() {
+            b.paused.set(false);
+        }
+    });
+}
+
+/// Returns true if a breakpoint was hit and [`resume`] wasn't called since.
+pub fn is_paused() -> bool {
+    BREAKPOINTS.with(|b| b.borrow().as_ref().is_some_and(|b| b.paused.get()))
+}
+
+/// Removes all the breakpoints.
+pub fn clear_breakpoints() {
+    BREAKPOINTS.with(|b| *b.borrow_mut() = None);
+}
+
+/// A breakpoint on a binding of an instantiated component.
+pub(crate) struct Breakpoint {
+    path: PathBuf,
+    offset: u32,
+    binding: String,
+    references: Vec<NamedReference>,
+    handler: Rc<dyn Fn(BreakpointHit)>,
+    paused: Rc<Cell<bool>>,
+}
+
+impl Breakpoint {
+    /// Returns the breakpoint set on the binding of `property` in `element`, if any.
+    pub(crate) fn for_binding(
+        binding: &BindingExpression,
+        element: &ElementRc,
+        property: &str,
+    ) -> Option<Rc<Self>> {
+        let (path, offset, handler, paused) = BREAKPOINTS.with(|b| {
+            let b = b.borrow();
+            let b = b.as_ref()?;
+            let span = binding.span.as_ref()?;
+            let path = span.source_file.as_ref()?.path();
+            let offset = span.span.offset as u32;
+            // The paths of the source files are already canonical when loaded from the disk
+            b.locations
+                .iter()
+                .any(|(p, canonical, o)| *o == offset && (p == path || canonical == path))
+                .then(|| (path.to_owned(), offset, b.handler.clone(), b.paused.clone()))
+        })?;
+
+        let mut references = Vec::<NamedReference>::new();
+        binding.expression.visit_recursive(&mut |e| {
+            if let Expression::PropertyReference(nr) = e {
+                if !references.contains(nr) {
+                    references.push(nr.clone());
+                }
+            }
+        });
+
+        Some(Rc::new(Self {
+            path,
+            offset,
+            binding: format!("{}.{property}", element_name(element)),
+            references,
+            handler,
+            paused,
+        }))
+    }
+
+    /// Reports the evaluation of the binding to the handler.
+    pub(crate) fn hit(&self, instance: InstanceRef, args: &[Value], result: &Value) {
+        if self.paused.replace(true) {
+            return;
+        }
+        let mut values = args
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("arg{i}"), v.clone()))
+            .collect::<Vec<_>>();
+        for nr in &self.references {
+            let element = nr.element();
+            if let Ok(value) = crate::eval::load_property(instance, &element, nr.name()) {
+                values.push((format!("{}.{}", element_name(&element), nr.name()), value));
+            }
+        }
+        (self.handler)(BreakpointHit {
+            path: self.path.clone(),
+            offset: self.offset,
+            binding: self.binding.clone(),
+            values,
+            result: result.clone(),
+        });
+    }
+}
+
+/// The name of the element as written in the source: its id, or its type if it has none.
+fn element_name(element: &ElementRc) -> String {
+    let element = element.borrow();
+    match element.debug.first() {
+        Some(debug) => match &debug.qualified_id {
+            Some(id) => id.rsplit("::").next().unwrap_or(id).to_string(),
+            None => debug.type_name.clone(),
+        },
+        None => element.id.to_string(),
+    }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use crate::api::{CompilationResult, ComponentDefinition, Value};
+use crate::breakpoints::Breakpoint;
 use crate::global_component::CompiledGlobalCollection;
 use crate::{dynamic_type, eval};
 use core::ptr::NonNull;
@@ -996,6 +997,9 @@ fn generate_rtti() -> HashMap<&'static str, Rc<ItemRTTI>> {
             rtti_for::<TouchArea>(),
             rtti_for::<FocusScope>(),
             rtti_for::<SwipeGestureHandler>(),
+            rtti_for::<DropArea>(),
+            rtti_for::<PinchGestureHandler>(),
+            rtti_for::<WindowFrameArea>(),
             rtti_for::<Path>(),
             rtti_for::<Flickable>(),
             rtti_for::<WindowItem>(),
@@ -1465,6 +1469,7 @@ pub fn animation_for_property(
 fn make_callback_eval_closure(
     expr: Expression,
     self_weak: &ErasedItemTreeBoxWeak,
+    breakpoint: Option<Rc<Breakpoint>>,
 ) -> impl Fn(&[Value]) -> Value {
     let self_weak = self_weak.clone();
     move |args| {
@@ -1474,13 +1479,18 @@ fn make_callback_eval_closure(
         let instance_ref = self_.borrow_instance();
         let mut local_context =
             eval::EvalLocalContext::from_function_arguments(instance_ref, args.to_vec());
-        eval::eval_expression(&expr, &mut local_context)
+        let result = eval::eval_expression(&expr, &mut local_context);
+        if let Some(breakpoint) = &breakpoint {
+            breakpoint.hit(instance_ref, args, &result);
+        }
+        result
     }
 }
 
 fn make_binding_eval_closure(
     expr: Expression,
     self_weak: &ErasedItemTreeBoxWeak,
+    breakpoint: Option<Rc<Breakpoint>>,
 ) -> impl Fn() -> Value {
     let self_weak = self_weak.clone();
     move || {
@@ -1488,10 +1498,14 @@ fn make_binding_eval_closure(
         generativity::make_guard!(guard);
         let self_ = self_rc.unerase(guard);
         let instance_ref = self_.borrow_instance();
-        eval::eval_expression(
+        let result = eval::eval_expression(
             &expr,
             &mut eval::EvalLocalContext::from_component_instance(instance_ref),
-        )
+        );
+        if let Some(breakpoint) = &breakpoint {
+            breakpoint.hit(instance_ref, &[], &result);
+        }
+        result
     }
 }
 
@@ -1603,6 +1617,7 @@ pub fn instantiate(
                 elem,
                 &elem.borrow().enclosing_component.upgrade().unwrap().root_element,
             );
+            let breakpoint = Breakpoint::for_binding(binding, elem, prop_name);
             let elem = elem.borrow();
             let is_const = binding.analysis.as_ref().map_or(false, |a| a.is_const);
 
@@ -1617,7 +1632,8 @@ pub fn instantiate(
                         description.custom_callbacks.get(prop_name).filter(|_| is_root)
                     {
                         let callback = callback_offset.apply(instance_ref.as_ref());
-                        callback.set_handler(make_callback_eval_closure(expr, &self_weak));
+                        callback
+                            .set_handler(make_callback_eval_closure(expr, &self_weak, breakpoint));
                     } else {
                         let item_within_component = &description.items[&elem.id];
                         let item = item_within_component.item_from_item_tree(instance_ref.as_ptr());
@@ -1626,7 +1642,7 @@ pub fn instantiate(
                         {
                             callback.set_handler(
                                 item,
-                                Box::new(make_callback_eval_closure(expr, &self_weak)),
+                                Box::new(make_callback_eval_closure(expr, &self_weak, breakpoint)),
                             );
                         } else {
                             panic!("unknown callback {}", prop_name)
@@ -1643,7 +1659,7 @@ pub fn instantiate(
                             as *const Property<i_slint_core::properties::StateInfo>),
                     );
                     let e = binding.expression.clone();
-                    let state_binding = make_binding_eval_closure(e, &self_weak);
+                    let state_binding = make_binding_eval_closure(e, &self_weak, breakpoint);
                     i_slint_core::properties::set_state_binding(prop, move || {
                         state_binding().try_into().unwrap()
                     });
@@ -1659,13 +1675,16 @@ pub fn instantiate(
                             &binding.expression,
                             &mut eval::EvalLocalContext::from_component_instance(instance_ref),
                         );
+                        if let Some(breakpoint) = &breakpoint {
+                            breakpoint.hit(instance_ref, &[], &v);
+                        }
                         prop_info.set(item, v, None).unwrap();
                     } else {
                         let e = binding.expression.clone();
                         prop_info
                             .set_binding(
                                 item,
-                                Box::new(make_binding_eval_closure(e, &self_weak)),
+                                Box::new(make_binding_eval_closure(e, &self_weak, breakpoint)),
                                 maybe_animation,
                             )
                             .unwrap();
@@ -1688,23 +1707,19 @@ pub fn instantiate(
                     }
                     if !matches!(binding.expression, Expression::Invalid) {
                         if is_const {
-                            prop_rtti
-                                .set(
-                                    item,
-                                    eval::eval_expression(
-                                        &binding.expression,
-                                        &mut eval::EvalLocalContext::from_component_instance(
-                                            instance_ref,
-                                        ),
-                                    ),
-                                    maybe_animation.as_animation(),
-                                )
-                                .unwrap();
+                            let v = eval::eval_expression(
+                                &binding.expression,
+                                &mut eval::EvalLocalContext::from_component_instance(instance_ref),
+                            );
+                            if let Some(breakpoint) = &breakpoint {
+                                breakpoint.hit(instance_ref, &[], &v);
+                            }
+                            prop_rtti.set(item, v, maybe_animation.as_animation()).unwrap();
                         } else {
                             let e = binding.expression.clone();
                             prop_rtti.set_binding(
                                 item,
-                                Box::new(make_binding_eval_closure(e, &self_weak)),
+                                Box::new(make_binding_eval_closure(e, &self_weak, breakpoint)),
                                 maybe_animation,
                             );
                         }
@@ -1722,7 +1737,7 @@ pub fn instantiate(
 
         let repeater = rep_in_comp.offset.apply_pin(instance_ref.instance);
         let expr = rep_in_comp.model.clone();
-        let model_binding_closure = make_binding_eval_closure(expr, &self_weak);
+        let model_binding_closure = make_binding_eval_closure(expr, &self_weak, None);
         repeater.set_model_binding(move || {
             let m = model_binding_closure();
             i_slint_core::model::ModelRc::new(crate::value_model::ValueModel::new(m))
@@ -4,7 +4,9 @@
 use crate::api::{SetPropertyError, Struct, Value};
 use crate::dynamic_item_tree::InstanceRef;
 use core::pin::Pin;
-use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush};
+use corelib::graphics::{
+    ConicGradientBrush, GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush,
+};
 use corelib::items::{ColorScheme, ItemRef, MenuEntry, PropertyAnimation};
 use corelib::model::{Model, ModelExt, ModelRc, VecModel};
 use corelib::rtti::AnimatedBindingKind;
@@ -333,18 +335,7 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
         Expression::ReadLocalVariable { name, .. } => {
             local_context.local_variables.get(name).unwrap().clone()
         }
-        Expression::EasingCurve(curve) => Value::EasingCurve(match curve {
-            EasingCurve::Linear => corelib::animations::EasingCurve::Linear,
-            EasingCurve::EaseInElastic => corelib::animations::EasingCurve::EaseInElastic,
-            EasingCurve::EaseOutElastic => corelib::animations::EasingCurve::EaseOutElastic,
-            EasingCurve::EaseInOutElastic => corelib::animations::EasingCurve::EaseInOutElastic,
-            EasingCurve::EaseInBounce => corelib::animations::EasingCurve::EaseInBounce,
-            EasingCurve::EaseOutBounce => corelib::animations::EasingCurve::EaseOutBounce,
-            EasingCurve::EaseInOutBounce => corelib::animations::EasingCurve::EaseInOutBounce,
-            EasingCurve::CubicBezier(a, b, c, d) => {
-                corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
-            }
-        }),
+        Expression::EasingCurve(curve) => Value::EasingCurve(easing_curve(curve)),
         Expression::LinearGradient{angle, stops} => {
             let angle = eval_expression(angle, local_context);
             Value::Brush(Brush::LinearGradient(LinearGradientBrush::new(angle.try_into().unwrap(), stops.iter().map(|(color, stop)| {
@@ -360,6 +351,14 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 GradientStop{ color, position }
             }))))
         }
+        Expression::ConicGradient{from_angle, stops} => {
+            let from_angle = eval_expression(from_angle, local_context);
+            Value::Brush(Brush::ConicGradient(ConicGradientBrush::new(from_angle.try_into().unwrap(), stops.iter().map(|(color, stop)| {
+                let color = eval_expression(color, local_context).try_into().unwrap();
+                let position = eval_expression(stop, local_context).try_into().unwrap();
+                GradientStop{ color, position }
+            }))))
+        }
         Expression::EnumerationValue(value) => {
             Value::EnumerationValue(value.enumeration.name.to_string(), value.to_string())
         }
@@ -401,6 +400,25 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
     }
 }
 
+/// Converts the easing curve of the compiler to the one of the run-time
+pub(crate) fn easing_curve(curve: &EasingCurve) -> corelib::animations::EasingCurve {
+    match curve {
+        EasingCurve::Linear => corelib::animations::EasingCurve::Linear,
+        EasingCurve::EaseInElastic => corelib::animations::EasingCurve::EaseInElastic,
+        EasingCurve::EaseOutElastic => corelib::animations::EasingCurve::EaseOutElastic,
+        EasingCurve::EaseInOutElastic => corelib::animations::EasingCurve::EaseInOutElastic,
+        EasingCurve::EaseInBounce => corelib::animations::EasingCurve::EaseInBounce,
+        EasingCurve::EaseOutBounce => corelib::animations::EasingCurve::EaseOutBounce,
+        EasingCurve::EaseInOutBounce => corelib::animations::EasingCurve::EaseInOutBounce,
+        EasingCurve::CubicBezier(a, b, c, d) => {
+            corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
+        }
+        EasingCurve::Spring(stiffness, damping, mass) => {
+            corelib::animations::EasingCurve::Spring([*stiffness, *damping, *mass])
+        }
+    }
+}
+
 fn call_builtin_function(
     f: BuiltinFunction,
     arguments: &[Expression],
@@ -801,6 +819,65 @@ fn call_builtin_function(
                 panic!("internal error: first argument to set-selection-offsets must be an element")
             }
         }
+        BuiltinFunction::AddTextUnderline => {
+            if arguments.len() != 5 {
+                panic!("internal error: incorrect argument count to add-underline function call")
+            }
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot invoke member function on item from a global component")
+                }
+            };
+            if let Expression::ElementReference(element) = &arguments[0] {
+                generativity::make_guard!(guard);
+
+                let elem = element.upgrade().unwrap();
+                let enclosing_component = enclosing_component_for_element(&elem, component, guard);
+                let description = enclosing_component.description;
+                let item_info = &description.items[elem.borrow().id.as_str()];
+                let item_ref =
+                    unsafe { item_info.item_from_item_tree(enclosing_component.as_ptr()) };
+
+                let item_comp = enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
+                let item_rc = corelib::items::ItemRc::new(
+                    vtable::VRc::into_dyn(item_comp),
+                    item_info.item_index(),
+                );
+
+                let window_adapter = component.window_adapter();
+
+                if let Some(textinput) =
+                    ItemRef::downcast_pin::<corelib::items::TextInput>(item_ref)
+                {
+                    let start: i32 =
+                        eval_expression(&arguments[1], local_context).try_into().expect(
+                            "internal error: second argument to add-underline must be an integer",
+                        );
+                    let end: i32 = eval_expression(&arguments[2], local_context).try_into().expect(
+                        "internal error: third argument to add-underline must be an integer",
+                    );
+                    let color: corelib::Color = eval_expression(&arguments[3], local_context)
+                        .try_into()
+                        .expect("internal error: fourth argument to add-underline must be a color");
+                    let style: corelib::items::TextUnderlineStyle =
+                        eval_expression(&arguments[4], local_context).try_into().expect(
+                            "internal error: fifth argument to add-underline must be a TextUnderlineStyle",
+                        );
+
+                    textinput.add_underline(&window_adapter, &item_rc, start, end, color, style);
+                } else {
+                    panic!(
+                        "internal error: member function called on element that doesn't have it: {}",
+                        elem.borrow().original_name()
+                    )
+                }
+
+                Value::Void
+            } else {
+                panic!("internal error: first argument to add-underline must be an element")
+            }
+        }
         BuiltinFunction::ItemMemberFunction(name) => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to item member function call")
@@ -839,6 +916,12 @@ fn call_builtin_function(
                         "cut" => textinput.cut(&window_adapter, &item_rc),
                         "copy" => textinput.copy(&window_adapter, &item_rc),
                         "paste" => textinput.paste(&window_adapter, &item_rc),
+                        "clear-underlines" => textinput.clear_underlines(&window_adapter, &item_rc),
+                        "undo" => textinput.undo(&window_adapter, &item_rc),
+                        "redo" => textinput.redo(&window_adapter, &item_rc),
+                        "clear-undo-history" => {
+                            textinput.clear_undo_history(&window_adapter, &item_rc)
+                        }
                         _ => panic!("internal: Unknown member function {name} called on TextInput"),
                     }
                 } else if let Some(s) =
@@ -891,6 +974,48 @@ fn call_builtin_function(
                 panic!("internal error: argument to set-selection-offsetsAll must be an element")
             }
         }
+        BuiltinFunction::FontMetrics => {
+            let family: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let size: f32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot get the window from a global component")
+                }
+            };
+            let window_adapter = component.window_adapter();
+            corelib::window::WindowInner::from_pub(window_adapter.window())
+                .font_metrics(family, corelib::lengths::LogicalLength::new(size as _))
+                .into()
+        }
+        BuiltinFunction::StartSystemMove => {
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot get the window from a global component")
+                }
+            };
+            let window_adapter = component.window_adapter();
+            corelib::window::WindowInner::from_pub(window_adapter.window()).start_system_move();
+            Value::Void
+        }
+        BuiltinFunction::StartSystemResize => {
+            let edge: corelib::items::WindowResizeEdge =
+                eval_expression(&arguments[1], local_context).try_into().expect(
+                    "internal error: argument to start-system-resize must be a WindowResizeEdge",
+                );
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot get the window from a global component")
+                }
+            };
+            let window_adapter = component.window_adapter();
+            corelib::window::WindowInner::from_pub(window_adapter.window())
+                .start_system_resize(edge);
+            Value::Void
+        }
         BuiltinFunction::StringIsFloat => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to StringIsFloat")
@@ -1092,6 +1217,17 @@ fn call_builtin_function(
                 panic!("Cannot get the window from a global component")
             }
         },
+        BuiltinFunction::AccentColor => match local_context.component_instance {
+            ComponentInstance::InstanceRef(component) => Value::Brush(Brush::SolidColor(
+                component
+                    .window_adapter()
+                    .internal(corelib::InternalToken)
+                    .map_or(Color::default(), |x| x.accent_color()),
+            )),
+            ComponentInstance::GlobalComponent(_) => {
+                panic!("Cannot get the window from a global component")
+            }
+        },
         BuiltinFunction::SupportsNativeMenuBar => match local_context.component_instance {
             ComponentInstance::InstanceRef(component) => component
                 .window_adapter()
@@ -1111,16 +1247,13 @@ fn call_builtin_function(
             else {
                 panic!("internal error: incorrect arguments to SetupNativeMenuBar")
             };
-            if let Some(w) = component.window_adapter().internal(i_slint_core::InternalToken) {
-                if w.supports_native_menu_bar() {
-                    w.setup_menubar(vtable::VBox::new(MenuWrapper {
-                        entries: entries.clone(),
-                        sub_menu: sub_menu.clone(),
-                        activated: activated.clone(),
-                        item_tree: component.self_weak().get().unwrap().clone(),
-                    }));
-                }
-            }
+            corelib::window::WindowInner::from_pub(component.window_adapter().window())
+                .setup_menubar(vtable::VBox::new(MenuWrapper {
+                    entries: entries.clone(),
+                    sub_menu: sub_menu.clone(),
+                    activated: activated.clone(),
+                    item_tree: component.self_weak().get().unwrap().clone(),
+                }));
             Value::Void
         }
         BuiltinFunction::MonthDayCount => {
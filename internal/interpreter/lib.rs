@@ -79,6 +79,11 @@
 );
 
 mod api;
+#[cfg(feature = "internal")]
+#[doc(hidden)]
+pub mod breakpoints;
+#[cfg(not(feature = "internal"))]
+mod breakpoints;
 mod dynamic_item_tree;
 mod dynamic_type;
 mod eval;
@@ -86,11 +91,18 @@
 mod global_component;
 #[cfg(feature = "highlight")]
 pub mod highlight;
+#[cfg(feature = "timeline")]
+mod timeline;
 mod value_model;
 
 #[doc(inline)]
 pub use api::*;
 
+#[cfg(feature = "timeline")]
+pub use timeline::{
+    Keyframe, Timeline, TimelineError, TimelinePlayer, TimelineRecorder, TimelineTrack,
+};
+
 #[cfg(feature = "internal")]
 #[doc(hidden)]
 pub use eval::default_value_for_type;
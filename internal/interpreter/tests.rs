@@ -44,3 +44,193 @@ fn reuse_window() {
         instance
     };
 }
+
+#[test]
+fn breakpoint_on_binding() {
+    i_slint_backend_testing::init_no_event_loop();
+    use crate::{Compiler, Value};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    let code = r#"
+        export component Test {
+            in property <int> a: 1;
+            in property <int> b: 2;
+            out property <int> sum;
+            sum: a + b;
+        }
+    "#;
+    let path = std::path::PathBuf::from("/tmp/breakpoint_on_binding.slint");
+    let offset = code.find("a + b").unwrap() as u32;
+    let hits = Rc::new(RefCell::new(Vec::new()));
+    let hits_ = hits.clone();
+    crate::breakpoints::set_breakpoints(vec![(path.clone(), offset)], move |hit| {
+        hits_.borrow_mut().push(hit)
+    });
+
+    let compiler = Compiler::default();
+    let result = spin_on::spin_on(compiler.build_from_source(code.into(), path.clone()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Test").unwrap().create().unwrap();
+    instance.set_property("a", Value::from(40)).unwrap();
+    assert_eq!(instance.get_property("sum").unwrap(), Value::from(42));
+    assert!(crate::breakpoints::is_paused());
+
+    // Once paused, the evaluations are not reported until resumed
+    instance.set_property("b", Value::from(3)).unwrap();
+    assert_eq!(instance.get_property("sum").unwrap(), Value::from(43));
+    assert_eq!(hits.borrow().len(), 1);
+    crate::breakpoints::resume();
+    assert!(!crate::breakpoints::is_paused());
+    instance.set_property("b", Value::from(4)).unwrap();
+    assert_eq!(instance.get_property("sum").unwrap(), Value::from(44));
+    crate::breakpoints::clear_breakpoints();
+
+    let hits = hits.borrow();
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].path, path);
+    assert_eq!(hits[0].offset, offset);
+    assert_eq!(hits[0].result, Value::from(42));
+    assert_eq!(
+        hits[0].values.iter().map(|(_, v)| v.clone()).collect::<Vec<_>>(),
+        vec![Value::from(40), Value::from(2)]
+    );
+    assert_eq!(hits[1].result, Value::from(44));
+}
+
+#[cfg(feature = "timeline")]
+#[test]
+fn timeline_player() {
+    i_slint_backend_testing::init_no_event_loop();
+    use crate::{Compiler, ComponentHandle, Timeline, TimelineError, TimelinePlayer, Value};
+    use i_slint_core::tests::slint_mock_elapsed_time;
+    use std::rc::Rc;
+    let code = r#"
+        export component Test {
+            in-out property <length> pos;
+            out property <float> r-opacity: r.opacity;
+            in-out property <int> moved-count;
+            callback moved();
+            changed pos => { moved-count += 1; moved(); }
+            r := Rectangle { }
+        }
+    "#;
+    let compiler = Compiler::default();
+    let result = spin_on::spin_on(compiler.build_from_source(code.into(), Default::default()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Test").unwrap().create().unwrap();
+
+    let timeline = Timeline::from_json(
+        r#"{ "tracks": [
+            { "property": "pos", "keyframes": [
+                { "time": 1000, "value": 100, "easing": "ease-in-out" },
+                { "time": 0, "value": 0 }
+            ] },
+            { "element": "r", "property": "opacity", "keyframes": [
+                { "time": 0, "value": 0 },
+                { "time": 1000, "value": 1 }
+            ] }
+        ] }"#,
+    )
+    .unwrap();
+    assert_eq!(timeline.duration(), 1000.);
+    let player = Rc::new(TimelinePlayer::new(&instance, &timeline).unwrap());
+    let pos = || instance.get_property("pos").unwrap();
+
+    player.play();
+    assert!(player.is_playing());
+    slint_mock_elapsed_time(500);
+    // ease-in-out is symmetric, so it's half way at half the time
+    let Value::Number(half_way) = pos() else { panic!("pos is not a number") };
+    assert!((half_way - 50.).abs() < 0.5, "{half_way}");
+    assert_eq!(instance.get_property("r-opacity").unwrap(), Value::Number(0.5));
+
+    player.pause();
+    slint_mock_elapsed_time(200);
+    assert_eq!(player.position(), 500.);
+    assert_eq!(pos(), Value::Number(half_way));
+
+    player.seek(0.).unwrap();
+    assert_eq!(pos(), Value::Number(0.));
+    player.play();
+    slint_mock_elapsed_time(1000);
+    slint_mock_elapsed_time(16);
+    assert!(!player.is_playing());
+    assert_eq!(player.position(), 1000.);
+    assert_eq!(pos(), Value::Number(100.));
+
+    // Pausing from a change handler of an animated property
+    let player_weak = Rc::downgrade(&player);
+    instance
+        .set_callback("moved", move |_| {
+            if let Some(player) = player_weak.upgrade() {
+                player.pause();
+            }
+            Value::Void
+        })
+        .unwrap();
+    instance.set_property("moved-count", Value::Number(0.)).unwrap();
+    player.play();
+    slint_mock_elapsed_time(250);
+    slint_mock_elapsed_time(16);
+    assert!(!player.is_playing());
+    assert_eq!(instance.get_property("moved-count").unwrap(), Value::Number(1.));
+
+    let unknown_easing = Timeline::from_json(
+        r#"{ "tracks": [ { "property": "pos", "keyframes": [
+            { "time": 100, "value": 1, "easing": "ease-sideways" }
+        ] } ] }"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        TimelinePlayer::new(&instance, &unknown_easing),
+        Err(TimelineError::UnknownEasing(name)) if name == "ease-sideways"
+    ));
+}
+
+#[cfg(feature = "timeline")]
+#[test]
+fn timeline_recorder() {
+    i_slint_backend_testing::init_no_event_loop();
+    use crate::{Compiler, Timeline, TimelinePlayer, TimelineRecorder, Value};
+    use i_slint_core::tests::slint_mock_elapsed_time;
+    let code = r#"
+        export component Test {
+            in-out property <length> pos;
+            in property <color> tint: #ff0000;
+            out property <int> count;
+            in property <image> icon;
+        }
+    "#;
+    let compiler = Compiler::default();
+    let result = spin_on::spin_on(compiler.build_from_source(code.into(), Default::default()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Test").unwrap().create().unwrap();
+
+    let recorder = TimelineRecorder::new(&instance);
+    slint_mock_elapsed_time(100);
+    instance.set_property("pos", Value::Number(40.)).unwrap();
+    instance.set_property("pos", Value::Number(50.)).unwrap();
+    slint_mock_elapsed_time(0);
+    slint_mock_elapsed_time(100);
+    instance
+        .set_property("tint", Value::Brush(i_slint_core::Color::from_rgb_u8(0, 0, 255).into()))
+        .unwrap();
+    slint_mock_elapsed_time(100);
+
+    let timeline = Timeline::from_json(&recorder.timeline().to_json()).unwrap();
+    drop(recorder);
+    assert_eq!(timeline.duration(), 300.);
+    // The output property and the image can't be played back, so they are not recorded
+    let tracks = timeline.tracks.iter().map(|t| t.property.as_str()).collect::<Vec<_>>();
+    assert_eq!(tracks, ["pos", "tint"]);
+    let times = timeline.tracks[0].keyframes.iter().map(|k| k.time).collect::<Vec<_>>();
+    assert_eq!(times, [0., 100., 100.]);
+    assert_eq!(timeline.tracks[1].keyframes.last().unwrap().value, "#0000ffff");
+
+    instance.set_property("pos", Value::Number(0.)).unwrap();
+    let player = TimelinePlayer::new(&instance, &timeline).unwrap();
+    player.seek(99.).unwrap();
+    assert_eq!(instance.get_property("pos").unwrap(), Value::Number(0.));
+    player.seek(100.).unwrap();
+    assert_eq!(instance.get_property("pos").unwrap(), Value::Number(50.));
+}
@@ -0,0 +1,482 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Timelines of keyframed property animations, loaded from JSON and played on a [`ComponentInstance`].
+
+A timeline is a list of tracks. Each track animates one property, either a public property of the
+component, or a property of an element identified by its id. The keyframes of a track are sorted
+by time (in milliseconds) and each may specify the easing used to reach it from the previous keyframe.
+
+```json
+{
+    "duration": 2000,
+    "looping": true,
+    "tracks": [
+        {
+            "element": "logo",
+            "property": "x",
+            "keyframes": [
+                { "time": 0, "value": 0 },
+                { "time": 1000, "value": 200, "easing": "ease-in-out" },
+                { "time": 2000, "value": 0, "easing": "cubic-bezier(0.3, 0, 0.7, 1)" }
+            ]
+        },
+        {
+            "property": "background-color",
+            "keyframes": [
+                { "time": 0, "value": "#ff0000" },
+                { "time": 2000, "value": "#0000ff" }
+            ]
+        }
+    ]
+}
+```
+
+Numbers are assigned to properties of numeric types, including lengths (in logical pixels) and
+durations (in milliseconds). Strings starting with `#` are colors, other strings and booleans are
+assigned as is. Numbers and colors are interpolated, other values change when their keyframe is reached.
+
+Since the timeline is loaded at run-time, a designer can iterate on the animation without
+recompiling the application.
+*/
+
+use crate::{ComponentHandle, ComponentInstance, SetPropertyError, Value};
+use i_slint_core::animations::{animation_tick, current_tick, EasingCurve, Instant};
+use i_slint_core::graphics::{Brush, Color};
+use i_slint_core::properties::{ChangeTracker, InterpolatedPropertyValue};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A timeline of keyframed property animations. See the [module documentation](self) for the JSON format.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Timeline {
+    /// The duration of the timeline in milliseconds. Defaults to the time of the last keyframe.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+    /// Whether the timeline restarts from the beginning when reaching its end.
+    #[serde(default)]
+    pub looping: bool,
+    /// The animated properties.
+    pub tracks: Vec<TimelineTrack>,
+}
+
+/// The keyframes for one property of a [`Timeline`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TimelineTrack {
+    /// The id of the element whose property is animated. When not set, the property is a
+    /// public property of the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub element: Option<String>,
+    /// The name of the animated property.
+    pub property: String,
+    /// The keyframes, sorted by time.
+    pub keyframes: Vec<Keyframe>,
+}
+
+/// The value of a property at a given time of a [`Timeline`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Keyframe {
+    /// The time of the keyframe in milliseconds since the start of the timeline.
+    pub time: f64,
+    /// The value of the property.
+    pub value: serde_json::Value,
+    /// The easing curve used to interpolate from the previous keyframe, using the same names as
+    /// in `.slint` files (for example `ease-in-out` or `cubic-bezier(0.3, 0, 0.7, 1)`).
+    /// Defaults to `linear`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub easing: Option<String>,
+}
+
+/// Error returned when loading a [`Timeline`].
+#[derive(Debug, derive_more::Error, derive_more::Display)]
+#[non_exhaustive]
+pub enum TimelineError {
+    /// The JSON could not be parsed.
+    #[display("invalid timeline: {_0}")]
+    Json(serde_json::Error),
+    /// A keyframe has an easing that is not known.
+    #[display("unknown easing curve `{_0}`")]
+    #[error(ignore)]
+    UnknownEasing(String),
+    /// A keyframe has a value that cannot be assigned to a property.
+    #[display("unsupported keyframe value `{_0}`")]
+    #[error(ignore)]
+    UnsupportedValue(serde_json::Value),
+}
+
+impl Timeline {
+    /// Parses a timeline from its JSON representation.
+    pub fn from_json(json: &str) -> Result<Self, TimelineError> {
+        let mut timeline: Self = serde_json::from_str(json).map_err(TimelineError::Json)?;
+        for track in &mut timeline.tracks {
+            track.keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        }
+        Ok(timeline)
+    }
+
+    /// Returns the JSON representation of the timeline.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// The duration of the timeline in milliseconds.
+    pub fn duration(&self) -> f64 {
+        self.duration.unwrap_or_else(|| {
+            self.tracks.iter().filter_map(|t| t.keyframes.last()).map(|k| k.time).fold(0., f64::max)
+        })
+    }
+}
+
+struct CompiledKeyframe {
+    time: f64,
+    value: Value,
+    easing: EasingCurve,
+}
+
+struct CompiledTrack {
+    element: Option<String>,
+    property: String,
+    keyframes: Vec<CompiledKeyframe>,
+}
+
+impl CompiledTrack {
+    fn value_at(&self, time: f64) -> Option<Value> {
+        let next = self.keyframes.iter().position(|k| k.time > time);
+        match next {
+            None => self.keyframes.last().map(|k| k.value.clone()),
+            Some(0) => self.keyframes.first().map(|k| k.value.clone()),
+            Some(next) => {
+                let (from, to) = (&self.keyframes[next - 1], &self.keyframes[next]);
+                let t = ((time - from.time) / (to.time - from.time)) as f32;
+                let t = i_slint_core::animations::easing_curve(&to.easing, t);
+                Some(interpolate(&from.value, &to.value, t))
+            }
+        }
+    }
+}
+
+fn interpolate(from: &Value, to: &Value, t: f32) -> Value {
+    match (from, to) {
+        (Value::Number(a), Value::Number(b)) => Value::Number(a + (b - a) * t as f64),
+        (Value::Brush(a), Value::Brush(b)) => Value::Brush(a.interpolate(b, t)),
+        _ if t < 1. => from.clone(),
+        _ => to.clone(),
+    }
+}
+
+fn parse_value(value: &serde_json::Value) -> Result<Value, TimelineError> {
+    Ok(match value {
+        serde_json::Value::Number(n) => {
+            Value::Number(n.as_f64().ok_or_else(|| TimelineError::UnsupportedValue(value.clone()))?)
+        }
+        serde_json::Value::Bool(b) => Value::Bool(*b),
+        serde_json::Value::String(s) if s.starts_with('#') => {
+            let argb = i_slint_compiler::literals::parse_color_literal(s)
+                .ok_or_else(|| TimelineError::UnsupportedValue(value.clone()))?;
+            Value::Brush(Brush::SolidColor(Color::from_argb_encoded(argb)))
+        }
+        serde_json::Value::String(s) => Value::String(s.into()),
+        _ => return Err(TimelineError::UnsupportedValue(value.clone())),
+    })
+}
+
+fn parse_easing(easing: Option<&str>) -> Result<EasingCurve, TimelineError> {
+    let Some(name) = easing else { return Ok(EasingCurve::Linear) };
+    let name = name.trim();
+    if let Some(args) = name.strip_prefix("cubic-bezier(").and_then(|a| a.strip_suffix(')')) {
+        let args = args.split(',').map(|a| a.trim().parse::<f32>()).collect::<Result<Vec<_>, _>>();
+        return match args.as_deref() {
+            Ok(&[a, b, c, d]) => Ok(EasingCurve::CubicBezier([a, b, c, d])),
+            _ => Err(TimelineError::UnknownEasing(name.into())),
+        };
+    }
+    i_slint_compiler::lookup::easing_curve_from_name(name)
+        .map(|curve| crate::eval::easing_curve(&curve))
+        .ok_or_else(|| TimelineError::UnknownEasing(name.into()))
+}
+
+struct PlayerInner {
+    instance: crate::Weak<ComponentInstance>,
+    tracks: Vec<CompiledTrack>,
+    duration: f64,
+    looping: bool,
+    /// The position in the timeline when it was last paused or seeked.
+    position: f64,
+    /// The animation tick at which the playback was last started, if playing.
+    started: Option<Instant>,
+}
+
+impl PlayerInner {
+    fn current_position(&self) -> f64 {
+        let elapsed = self.started.map_or(0, |s| current_tick().0.saturating_sub(s.0));
+        let position = self.position + elapsed as f64;
+        if self.looping && self.duration > 0. {
+            position % self.duration
+        } else {
+            position.min(self.duration)
+        }
+    }
+
+    /// Returns the values of the properties at `position`.
+    fn values_at(&self, position: f64) -> Vec<(Option<String>, String, Value)> {
+        self.tracks
+            .iter()
+            .filter_map(|track| {
+                Some((track.element.clone(), track.property.clone(), track.value_at(position)?))
+            })
+            .collect()
+    }
+}
+
+/// Sets the values returned by [`PlayerInner::values_at()`]. This must be called without
+/// borrowing the player, since the change handlers of the properties may call into it.
+fn apply(
+    instance: &crate::Weak<ComponentInstance>,
+    values: Vec<(Option<String>, String, Value)>,
+) -> Result<(), SetPropertyError> {
+    let Some(instance) = instance.upgrade() else { return Ok(()) };
+    for (element, property, value) in values {
+        match element {
+            Some(element) => instance.set_element_property(&element, &property, value)?,
+            None => instance.set_property(&property, value)?,
+        }
+    }
+    Ok(())
+}
+
+/// Plays a [`Timeline`] on a [`ComponentInstance`], setting the animated properties on every frame.
+///
+/// The player is driven by the same clock as the animations of the `.slint` files, so it runs at
+/// the frame rate of the window and is paused by the same means.
+///
+/// ```rust,no_run
+/// # use slint_interpreter::*;
+/// # fn f(instance: &ComponentInstance) -> Result<(), Box<dyn std::error::Error>> {
+/// let timeline = Timeline::from_json(&std::fs::read_to_string("intro.json")?)?;
+/// let player = TimelinePlayer::new(instance, &timeline)?;
+/// player.play();
+/// # Ok(()) }
+/// ```
+pub struct TimelinePlayer {
+    inner: Rc<RefCell<PlayerInner>>,
+    /// Applies the values on each animation tick while playing
+    tracker: ChangeTracker,
+}
+
+impl TimelinePlayer {
+    /// Creates a player for the timeline, driving the properties of `instance`.
+    /// The player starts paused at the beginning of the timeline.
+    pub fn new(instance: &ComponentInstance, timeline: &Timeline) -> Result<Self, TimelineError> {
+        let tracks = timeline
+            .tracks
+            .iter()
+            .map(|track| {
+                Ok(CompiledTrack {
+                    element: track.element.clone(),
+                    property: track.property.clone(),
+                    keyframes: track
+                        .keyframes
+                        .iter()
+                        .map(|k| {
+                            Ok(CompiledKeyframe {
+                                time: k.time,
+                                value: parse_value(&k.value)?,
+                                easing: parse_easing(k.easing.as_deref())?,
+                            })
+                        })
+                        .collect::<Result<_, TimelineError>>()?,
+                })
+            })
+            .collect::<Result<_, TimelineError>>()?;
+        Ok(Self {
+            inner: Rc::new(RefCell::new(PlayerInner {
+                instance: instance.as_weak(),
+                tracks,
+                duration: timeline.duration(),
+                looping: timeline.looping,
+                position: 0.,
+                started: None,
+            })),
+            tracker: ChangeTracker::default(),
+        })
+    }
+
+    /// Starts or resumes the playback from the current position.
+    pub fn play(&self) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            if inner.started.is_some() {
+                return;
+            }
+            if !inner.looping && inner.position >= inner.duration {
+                inner.position = 0.;
+            }
+            inner.started = Some(current_tick());
+        }
+        self.tracker.init(
+            Rc::downgrade(&self.inner),
+            |inner| {
+                // Depend on the animation tick while playing, which also requests the next frame
+                let playing = inner.upgrade().is_some_and(|i| i.borrow().started.is_some());
+                playing.then(animation_tick)
+            },
+            |inner, tick| {
+                let (Some(inner), Some(_)) = (inner.upgrade(), tick) else { return };
+                let (instance, values) = {
+                    let mut inner = inner.borrow_mut();
+                    let position = inner.current_position();
+                    if !inner.looping && position >= inner.duration {
+                        inner.position = inner.duration;
+                        inner.started = None;
+                    }
+                    (inner.instance.clone(), inner.values_at(position))
+                };
+                if let Err(err) = apply(&instance, values) {
+                    i_slint_core::debug_log!("TimelinePlayer: cannot set property: {err}");
+                }
+            },
+        );
+    }
+
+    /// Pauses the playback at the current position.
+    pub fn pause(&self) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            inner.position = inner.current_position();
+            inner.started = None;
+        }
+        self.tracker.clear();
+    }
+
+    /// Returns true if the timeline is currently playing.
+    pub fn is_playing(&self) -> bool {
+        self.inner.borrow().started.is_some()
+    }
+
+    /// Returns the current position in the timeline, in milliseconds.
+    pub fn position(&self) -> f64 {
+        self.inner.borrow().current_position()
+    }
+
+    /// Moves to the given position in milliseconds and applies the values of the properties at that time.
+    pub fn seek(&self, position: f64) -> Result<(), SetPropertyError> {
+        let (instance, values) = {
+            let mut inner = self.inner.borrow_mut();
+            inner.position = position.clamp(0., inner.duration);
+            if inner.started.is_some() {
+                inner.started = Some(current_tick());
+            }
+            (inner.instance.clone(), inner.values_at(inner.position))
+        };
+        apply(&instance, values)
+    }
+}
+
+/// Returns the JSON representation of a keyframe value, if it can be stored in a timeline.
+fn to_json(value: &Value) -> Option<serde_json::Value> {
+    Some(match value {
+        Value::Number(n) => serde_json::Number::from_f64(*n)?.into(),
+        Value::Bool(b) => (*b).into(),
+        // Strings starting with `#` would be read back as colors
+        Value::String(s) if !s.starts_with('#') => s.as_str().into(),
+        Value::Brush(Brush::SolidColor(c)) => {
+            format!("#{:02x}{:02x}{:02x}{:02x}", c.red(), c.green(), c.blue(), c.alpha()).into()
+        }
+        _ => return None,
+    })
+}
+
+struct RecorderInner {
+    /// The animation tick at which the recording started.
+    started: Instant,
+    tracks: Vec<TimelineTrack>,
+}
+
+impl RecorderInner {
+    fn elapsed(&self) -> f64 {
+        current_tick().0.saturating_sub(self.started.0) as f64
+    }
+}
+
+/// Records the changes of the public properties of a [`ComponentInstance`] into a [`Timeline`],
+/// which a [`TimelinePlayer`] can play back.
+///
+/// Only the properties that can be set from outside the component and whose values are numbers,
+/// booleans, strings, or plain colors are recorded. The recording lasts until the recorder is dropped.
+///
+/// ```rust,no_run
+/// # use slint_interpreter::*;
+/// # fn f(instance: &ComponentInstance) -> Result<(), Box<dyn std::error::Error>> {
+/// let recorder = TimelineRecorder::new(instance);
+/// // ... interact with the component ...
+/// std::fs::write("intro.json", recorder.timeline().to_json())?;
+/// # Ok(()) }
+/// ```
+pub struct TimelineRecorder {
+    inner: Rc<RefCell<RecorderInner>>,
+    /// One tracker per recorded property, adding a keyframe when it changes
+    _trackers: Vec<ChangeTracker>,
+}
+
+impl TimelineRecorder {
+    /// Starts recording the properties of `instance`, with their current values as the first keyframes.
+    pub fn new(instance: &ComponentInstance) -> Self {
+        let inner =
+            Rc::new(RefCell::new(RecorderInner { started: current_tick(), tracks: Vec::new() }));
+        let mut trackers = Vec::new();
+        for property in instance.settable_properties() {
+            let Some(value) = instance.get_property(&property).ok().as_ref().and_then(to_json)
+            else {
+                continue;
+            };
+            let index = {
+                let mut inner = inner.borrow_mut();
+                inner.tracks.push(TimelineTrack {
+                    element: None,
+                    property: property.clone(),
+                    keyframes: vec![Keyframe { time: 0., value, easing: None }],
+                });
+                inner.tracks.len() - 1
+            };
+            let tracker = ChangeTracker::default();
+            tracker.init(
+                (instance.as_weak(), Rc::downgrade(&inner), property),
+                |(instance, _, property)| {
+                    instance.upgrade().and_then(|i| i.get_property(property).ok())
+                },
+                move |(_, inner, _), value| {
+                    let (Some(inner), Some(value)) =
+                        (inner.upgrade(), value.as_ref().and_then(to_json))
+                    else {
+                        return;
+                    };
+                    let mut inner = inner.borrow_mut();
+                    let time = inner.elapsed();
+                    let keyframes = &mut inner.tracks[index].keyframes;
+                    let previous = keyframes.last().unwrap().clone();
+                    if previous.time == time {
+                        // Only keep the last value set within the same frame
+                        keyframes.pop();
+                    } else {
+                        // Hold the previous value until the change, instead of interpolating towards the new one
+                        keyframes.push(Keyframe { time, ..previous });
+                    }
+                    keyframes.push(Keyframe { time, value, easing: None });
+                },
+            );
+            trackers.push(tracker);
+        }
+        Self { inner, _trackers: trackers }
+    }
+
+    /// Returns the timeline recorded so far. Its duration is the time since the start of the recording.
+    pub fn timeline(&self) -> Timeline {
+        let inner = self.inner.borrow();
+        Timeline { duration: Some(inner.elapsed()), looping: false, tracks: inner.tracks.clone() }
+    }
+}
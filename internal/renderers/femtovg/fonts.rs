@@ -15,6 +15,7 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 
+use super::itemrenderer::Canvas;
 use super::{PhysicalLength, PhysicalPoint, PhysicalSize};
 
 pub const DEFAULT_FONT_SIZE: LogicalLength = LogicalLength::new(12.);
@@ -32,6 +33,8 @@ pub struct Font {
     fonts: SharedVector<femtovg::FontId>,
     pixel_size: PhysicalLength,
     text_context: TextContext,
+    /// The styles of the request that the primary font lacks, applied when drawing
+    synthesis: sharedfontdb::FontSynthesis,
 }
 
 impl Font {
@@ -85,6 +88,43 @@ pub fn text_size(
         euclid::size2(width, lines as f32 * font_metrics.height())
     }
 
+    /// Returns the byte ranges of the lines of the text, without trailing whitespace, broken like
+    /// in [`Self::text_size`].
+    pub fn line_ranges(
+        &self,
+        letter_spacing: PhysicalLength,
+        text: &str,
+        max_width: Option<PhysicalLength>,
+    ) -> Vec<core::ops::Range<usize>> {
+        let paint = self.init_paint(letter_spacing, femtovg::Paint::default());
+        let mut ranges = Vec::new();
+        let mut start = 0;
+        let mut push_line = |start: usize, line: &str| {
+            ranges.push(start..start + line.trim_end().len());
+        };
+        if let Some(max_width) = max_width {
+            while start < text.len() {
+                let max_line_index = text[start..].find('\n').map_or(text.len(), |i| i + 1 + start);
+                let index = self
+                    .text_context
+                    .break_text(max_width.get(), &text[start..max_line_index], &paint)
+                    .unwrap();
+                if index == 0 {
+                    break;
+                }
+                let index = start + index;
+                push_line(start, &text[start..index]);
+                start = index;
+            }
+        } else {
+            for line in text.split_inclusive('\n') {
+                push_line(start, line);
+                start += line.len();
+            }
+        }
+        ranges
+    }
+
     pub fn height(&self) -> PhysicalLength {
         let mut paint = femtovg::Paint::default();
         // These are the only two properties measure_font() needs
@@ -92,6 +132,69 @@ pub fn height(&self) -> PhysicalLength {
         paint.set_font_size(self.pixel_size.get());
         PhysicalLength::new(self.text_context.measure_font(&paint).unwrap().height())
     }
+
+    pub fn ascent(&self) -> PhysicalLength {
+        let mut paint = femtovg::Paint::default();
+        paint.set_font(&self.fonts);
+        paint.set_font_size(self.pixel_size.get());
+        PhysicalLength::new(self.text_context.measure_font(&paint).unwrap().ascender())
+    }
+
+    /// Fills `text` at the given position, thickening the strokes or slanting the glyphs when the
+    /// font lacks the requested weight or style.
+    pub fn fill_text(
+        &self,
+        canvas: &mut Canvas,
+        x: f32,
+        y: f32,
+        text: &str,
+        paint: &femtovg::Paint,
+    ) {
+        self.with_synthetic_oblique(canvas, x, y, |canvas, x, y| {
+            canvas.fill_text(x, y, text, paint).unwrap();
+            if self.synthesis.bold {
+                let mut bold_paint = paint.clone();
+                bold_paint.set_line_width(
+                    self.pixel_size.get() * sharedfontdb::FontSynthesis::BOLD_STRENGTH,
+                );
+                canvas.stroke_text(x, y, text, &bold_paint).unwrap();
+            }
+        })
+    }
+
+    /// Strokes the outline of `text` at the given position, slanting the glyphs when the font
+    /// lacks the requested style.
+    pub fn stroke_text(
+        &self,
+        canvas: &mut Canvas,
+        x: f32,
+        y: f32,
+        text: &str,
+        paint: &femtovg::Paint,
+    ) {
+        self.with_synthetic_oblique(canvas, x, y, |canvas, x, y| {
+            canvas.stroke_text(x, y, text, paint).unwrap();
+        })
+    }
+
+    fn with_synthetic_oblique(
+        &self,
+        canvas: &mut Canvas,
+        x: f32,
+        y: f32,
+        draw: impl FnOnce(&mut Canvas, f32, f32),
+    ) {
+        if !self.synthesis.oblique {
+            return draw(canvas, x, y);
+        }
+        // Slant around the baseline, as the text is positioned by its top
+        let ascent = self.ascent().get();
+        canvas.save();
+        canvas.translate(x, y + ascent);
+        canvas.skew_x(-sharedfontdb::FontSynthesis::OBLIQUE_SLANT.atan());
+        draw(canvas, 0., -ascent);
+        canvas.restore();
+    }
 }
 
 pub(crate) fn text_size(
@@ -107,6 +210,18 @@ pub(crate) fn text_size(
         / scale_factor
 }
 
+pub(crate) fn text_line_ranges(
+    font_request: &i_slint_core::graphics::FontRequest,
+    scale_factor: ScaleFactor,
+    text: &str,
+    max_width: Option<LogicalLength>,
+) -> Vec<core::ops::Range<usize>> {
+    let font =
+        FONT_CACHE.with(|cache| cache.borrow_mut().font(font_request.clone(), scale_factor, text));
+    let letter_spacing = font_request.letter_spacing.unwrap_or_default();
+    font.line_ranges(letter_spacing * scale_factor, text, max_width.map(|x| x * scale_factor))
+}
+
 pub(crate) fn font_metrics(
     font_request: i_slint_core::graphics::FontRequest,
 ) -> i_slint_core::items::FontMetrics {
@@ -128,11 +243,112 @@ pub(crate) fn font_metrics(
     }
 }
 
+/// Returns the outline of the glyphs of `text` as laid out by femtovg, in logical coordinates
+/// relative to the top-left corner of the text.
+pub(crate) fn text_outline(
+    font_request: &i_slint_core::graphics::FontRequest,
+    scale_factor: ScaleFactor,
+    text: &str,
+) -> Vec<i_slint_core::api::PathCommand> {
+    use i_slint_core::api::{LogicalPosition, PathCommand};
+
+    struct PathBuilder<'a> {
+        commands: &'a mut Vec<PathCommand>,
+        /// The origin of the glyph on the baseline, in physical pixels.
+        origin: (f32, f32),
+        /// Converts font design units to physical pixels.
+        scale: f32,
+        slant: f32,
+        scale_factor: f32,
+    }
+
+    impl PathBuilder<'_> {
+        fn point(&self, x: f32, y: f32) -> LogicalPosition {
+            LogicalPosition::new(
+                (self.origin.0 + (x + y * self.slant) * self.scale) / self.scale_factor,
+                (self.origin.1 - y * self.scale) / self.scale_factor,
+            )
+        }
+    }
+
+    impl ttf_parser::OutlineBuilder for PathBuilder<'_> {
+        fn move_to(&mut self, x: f32, y: f32) {
+            self.commands.push(PathCommand::MoveTo(self.point(x, y)));
+        }
+
+        fn line_to(&mut self, x: f32, y: f32) {
+            self.commands.push(PathCommand::LineTo(self.point(x, y)));
+        }
+
+        fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+            let command =
+                PathCommand::QuadraticTo { control: self.point(x1, y1), to: self.point(x, y) };
+            self.commands.push(command);
+        }
+
+        fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+            let command = PathCommand::CubicTo {
+                control1: self.point(x1, y1),
+                control2: self.point(x2, y2),
+                to: self.point(x, y),
+            };
+            self.commands.push(command);
+        }
+
+        fn close(&mut self) {
+            self.commands.push(PathCommand::Close);
+        }
+    }
+
+    let (font, face_ids) = FONT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let font = cache.font(font_request.clone(), scale_factor, text);
+        let face_ids = cache
+            .loaded_fonts
+            .values()
+            .map(|loaded_font| (loaded_font.femtovg_font_id, loaded_font.fontdb_face_id))
+            .collect::<HashMap<_, _>>();
+        (font, face_ids)
+    });
+    let letter_spacing = font_request.letter_spacing.unwrap_or_default() * scale_factor;
+    let paint = font.init_paint(letter_spacing, femtovg::Paint::default());
+    let line_height = font.height().get();
+    let slant =
+        if font.synthesis.oblique { sharedfontdb::FontSynthesis::OBLIQUE_SLANT } else { 0. };
+
+    let mut commands = Vec::new();
+    for (line_index, line) in text.split('\n').enumerate() {
+        let Ok(metrics) =
+            font.text_context.measure_text(0., line_index as f32 * line_height, line, &paint)
+        else {
+            continue;
+        };
+        for glyph in &metrics.glyphs {
+            let Some(face_id) = face_ids.get(&glyph.font_id) else { continue };
+            sharedfontdb::FONT_DB.with(|db| {
+                db.borrow().with_face_data(*face_id, |face_data, face_index| {
+                    let face = ttf_parser::Face::parse(face_data, face_index).unwrap();
+                    let mut builder = PathBuilder {
+                        commands: &mut commands,
+                        origin: (glyph.x - glyph.bearing_x, glyph.y + glyph.bearing_y),
+                        scale: font.pixel_size.get() / face.units_per_em() as f32,
+                        slant,
+                        scale_factor: scale_factor.get(),
+                    };
+                    face.outline_glyph(ttf_parser::GlyphId(glyph.codepoint as u16), &mut builder);
+                })
+            });
+        }
+    }
+    commands
+}
+
 #[derive(Clone)]
 struct LoadedFont {
     femtovg_font_id: femtovg::FontId,
     fontdb_face_id: fontdb::ID,
     design_font_metrics: i_slint_common::sharedfontdb::DesignFontMetrics,
+    synthesis: sharedfontdb::FontSynthesis,
 }
 
 struct SharedFontData(std::sync::Arc<dyn AsRef<[u8]>>);
@@ -164,20 +380,28 @@ pub struct FontCache {
     // for a given fontdb face id, this tells us what we've learned about the script
     // coverage of the font.
     loaded_font_coverage: HashMap<fontdb::ID, GlyphCoverage>,
+    // The faces added to the text context, with their metrics. Fonts can't be removed from a
+    // femtovg text context, so this is kept when the loaded fonts are cleared, to add each face
+    // only once.
+    loaded_faces: HashMap<fontdb::ID, (femtovg::FontId, sharedfontdb::DesignFontMetrics)>,
     pub(crate) text_context: TextContext,
     available_families: HashSet<SharedString>,
 }
 
+fn available_families() -> HashSet<SharedString> {
+    sharedfontdb::FONT_DB.with(|db| {
+        db.borrow()
+            .faces()
+            .filter_map(|face_info| {
+                face_info.families.first().map(|(family_name, _)| family_name.as_str().into())
+            })
+            .collect()
+    })
+}
+
 impl Default for FontCache {
     fn default() -> Self {
-        let available_families = sharedfontdb::FONT_DB.with(|db| {
-            db.borrow()
-                .faces()
-                .filter_map(|face_info| {
-                    face_info.families.first().map(|(family_name, _)| family_name.as_str().into())
-                })
-                .collect()
-        });
+        let available_families = available_families();
 
         let text_context = TextContext::default();
         text_context.resize_shaped_words_cache(NonZeroUsize::new(10_000_000).unwrap());
@@ -186,6 +410,7 @@ fn default() -> Self {
         Self {
             loaded_fonts: HashMap::new(),
             loaded_font_coverage: HashMap::new(),
+            loaded_faces: HashMap::new(),
             text_context,
             available_families,
         }
@@ -197,6 +422,24 @@ fn default() -> Self {
 }
 
 impl FontCache {
+    /// Forgets the fonts loaded so far, after faces were removed from the font database. The
+    /// text context keeps the removed fonts, as femtovg can't remove them, but they are not
+    /// referenced anymore and the glyphs and shaped words cached for them are not used again. The
+    /// faces that remain are not added to the text context again.
+    pub fn clear_loaded_fonts(&mut self) {
+        self.loaded_fonts.clear();
+        self.loaded_font_coverage.clear();
+        sharedfontdb::FONT_DB.with_borrow(|db| {
+            self.loaded_faces.retain(|fontdb_face_id, _| db.face(*fontdb_face_id).is_some())
+        });
+        self.update_available_families();
+    }
+
+    /// Picks up the families added to or removed from the font database.
+    pub fn update_available_families(&mut self) {
+        self.available_families = available_families();
+    }
+
     fn load_single_font(
         &mut self,
         family: Option<&SharedString>,
@@ -216,15 +459,35 @@ fn load_single_font(
 
         //let now = std::time::Instant::now();
 
-        let fontdb_face_id = sharedfontdb::FONT_DB.with_borrow(|db| {
-            db.query_with_family(query, family.map(|s| s.as_str()))
+        let (fontdb_face_id, synthesis) = sharedfontdb::FONT_DB.with_borrow(|db| {
+            let fontdb_face_id = db
+                .query_with_family(query, family.map(|s| s.as_str()))
                 .or_else(|| {
                     // If the requested family could not be found, fall back to *some* family that must exist
                     db.query_with_family(query, None)
                 })
-                .expect("there must be a sans-serif font face registered")
+                .expect("there must be a sans-serif font face registered");
+            (fontdb_face_id, db.synthesis(fontdb_face_id, &query))
         });
 
+        let (femtovg_font_id, design_font_metrics) = self
+            .loaded_faces
+            .entry(fontdb_face_id)
+            .or_insert_with(|| Self::add_face(&text_context, fontdb_face_id))
+            .clone();
+
+        //println!("Loaded {:#?} in {}ms.", request, now.elapsed().as_millis());
+        let new_font =
+            LoadedFont { femtovg_font_id, fontdb_face_id, design_font_metrics, synthesis };
+        self.loaded_fonts.insert(cache_key, new_font.clone());
+        new_font
+    }
+
+    /// Adds the face of the font database to the text context.
+    fn add_face(
+        text_context: &TextContext,
+        fontdb_face_id: fontdb::ID,
+    ) -> (femtovg::FontId, sharedfontdb::DesignFontMetrics) {
         // Safety: We map font files into memory that - while we never unmap them - may
         // theoretically get corrupted/truncated by another process and then we'll crash
         // and burn. In practice that should not happen though, font files are - at worst -
@@ -264,10 +527,7 @@ fn load_single_font(
             .add_shared_font_with_index(SharedFontData(shared_data), face_index)
             .unwrap();
 
-        //println!("Loaded {:#?} in {}ms.", request, now.elapsed().as_millis());
-        let new_font = LoadedFont { femtovg_font_id, fontdb_face_id, design_font_metrics };
-        self.loaded_fonts.insert(cache_key, new_font.clone());
-        new_font
+        (femtovg_font_id, design_font_metrics)
     }
 
     pub fn font(
@@ -311,12 +571,22 @@ pub fn font(
         //);
 
         let fallbacks = if !matches!(coverage_result, GlyphCoverageCheckResult::Complete) {
-            self.font_fallbacks_for_request(
+            // The families the application prefers for the missing scripts come before the
+            // ones the system picks.
+            let mut fallbacks = scripts_required
+                .keys()
+                .flat_map(|script| {
+                    i_slint_core::graphics::script_font_families(script.short_name())
+                })
+                .filter(|family_name| self.is_known_family(family_name))
+                .collect::<Vec<SharedString>>();
+            fallbacks.extend(self.font_fallbacks_for_request(
                 font_request.family.as_ref(),
                 pixel_size,
                 &primary_font,
                 reference_text,
-            )
+            ));
+            fallbacks
         } else {
             Vec::new()
         };
@@ -346,7 +616,12 @@ pub fn font(
             }))
             .collect::<SharedVector<_>>();
 
-        Font { fonts, text_context: self.text_context.clone(), pixel_size }
+        Font {
+            fonts,
+            text_context: self.text_context.clone(),
+            pixel_size,
+            synthesis: primary_font.synthesis,
+        }
     }
 
     #[cfg(target_os = "macos")]
@@ -576,6 +851,7 @@ fn check_and_update_script_coverage(
 /// Layout the given string in lines, and call the `layout_line` callback with the line to draw at position y.
 /// The signature of the `layout_line` function is: `(text, pos, start_index, line_metrics)`.
 /// start index is the starting byte of the text in the string.
+/// When `max_lines` is set, the text is truncated after that many lines, and the last line ends with `…`.
 /// Returns the coordinates of the cursor, if a cursor byte offset was provided.
 pub(crate) fn layout_text_lines(
     string: &str,
@@ -585,6 +861,7 @@ pub(crate) fn layout_text_lines(
     wrap: TextWrap,
     overflow: TextOverflow,
     single_line: bool,
+    max_lines: Option<usize>,
     cursor_byte_offset: Option<usize>,
     paint: &femtovg::Paint,
     mut layout_line: impl FnMut(&str, PhysicalPoint, usize, &femtovg::TextMetrics),
@@ -613,6 +890,10 @@ pub(crate) fn layout_text_lines(
                     if wrap { Some(max_width) } else { None },
                 )
                 .height_length();
+            let text_height = match max_lines {
+                Some(max_lines) => text_height.min(font_height * max_lines as f32),
+                None => text_height,
+            };
             if elide && text_height > max_height {
                 // The height of the text is used for vertical alignment below.
                 // If the full text doesn't fit into max_height and eliding is
@@ -676,8 +957,14 @@ pub(crate) fn layout_text_lines(
     };
     let mut y = baseline_y;
     let mut start = 0;
-    'lines: while start < string.len() && y + font_height <= max_height {
-        if wrap && (!elide || y + font_height * 2. <= max_height) {
+    let mut line_count = 0;
+    'lines: while start < string.len()
+        && y + font_height <= max_height
+        && max_lines.map_or(true, |max_lines| line_count < max_lines)
+    {
+        line_count += 1;
+        let is_last_line = max_lines == Some(line_count);
+        if wrap && !is_last_line && (!elide || y + font_height * 2. <= max_height) {
             let max_line_index = string[start..].find('\n').map_or(string.len(), |i| i + 1 + start);
             let index = text_context
                 .break_text(max_width.get(), &string[start..max_line_index], paint)
@@ -700,8 +987,9 @@ pub(crate) fn layout_text_lines(
             };
             let line = &string[start..index];
             let text_metrics = text_context.measure_text(0., 0., line, paint).unwrap();
-            let elide_last_line =
-                elide && index < string.len() && y + font_height * 2. > max_height;
+            let elide_last_line = index < string.len()
+                && ((elide && y + font_height * 2. > max_height) || is_last_line);
+            let elide = elide || is_last_line;
             if text_metrics.width() > max_width.get() || elide_last_line {
                 let w = max_width
                     - if elide {
@@ -10,7 +10,7 @@
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self};
 use i_slint_core::graphics::rendering_metrics_collector::RenderingMetrics;
-use i_slint_core::graphics::{IntRect, Point, Size};
+use i_slint_core::graphics::{ImagePatternBrush, IntRect, Point, SharedImageBuffer, Size};
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderBorderRectangle, RenderImage, RenderText,
 };
@@ -360,20 +360,33 @@ fn draw_text(
             text.wrap(),
             text.overflow(),
             false,
+            text.max_lines(),
             None,
             &paint,
             |to_draw, pos, _, _| {
                 match (stroke_style, &stroke_paint) {
                     (TextStrokeStyle::Outside, Some(stroke_paint)) => {
-                        canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), stroke_paint).unwrap();
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                        font.stroke_text(
+                            &mut canvas,
+                            pos.x,
+                            pos.y,
+                            to_draw.trim_end(),
+                            stroke_paint,
+                        );
+                        font.fill_text(&mut canvas, pos.x, pos.y, to_draw.trim_end(), &paint);
                     }
                     (TextStrokeStyle::Center, Some(stroke_paint)) => {
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
-                        canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), stroke_paint).unwrap();
+                        font.fill_text(&mut canvas, pos.x, pos.y, to_draw.trim_end(), &paint);
+                        font.stroke_text(
+                            &mut canvas,
+                            pos.x,
+                            pos.y,
+                            to_draw.trim_end(),
+                            stroke_paint,
+                        );
                     }
                     _ => {
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                        font.fill_text(&mut canvas, pos.x, pos.y, to_draw.trim_end(), &paint);
                     }
                 };
             },
@@ -414,8 +427,12 @@ fn draw_text_input(
             None => return,
         };
 
-        let (min_select, max_select) = if !visual_representation.preedit_range.is_empty() {
-            (visual_representation.preedit_range.start, visual_representation.preedit_range.end)
+        // The selection is not shown while composing, instead the composition is underlined and the clause
+        // that the input method is converting is highlighted.
+        let preedit_range = visual_representation.preedit_range.clone();
+        let (min_select, max_select) = if !preedit_range.is_empty() {
+            let clause = &visual_representation.preedit_clause_range;
+            (clause.start, clause.end)
         } else {
             (visual_representation.selection_range.start, visual_representation.selection_range.end)
         };
@@ -431,6 +448,9 @@ fn draw_text_input(
         let font_height = font.height();
         let text: SharedString = visual_representation.text.into();
 
+        let underline_thickness = (font_height.get() / 16.).max(1.);
+        let underline_y = font.ascent().get() + underline_thickness;
+
         let cursor_point = fonts::layout_text_lines(
             text.as_str(),
             &font,
@@ -439,38 +459,47 @@ fn draw_text_input(
             text_input.wrap(),
             items::TextOverflow::Clip,
             text_input.single_line(),
+            None,
             cursor_visible.then_some(cursor_pos),
             &paint,
             |to_draw: &str, pos: PhysicalPoint, start, metrics: &femtovg::TextMetrics| {
                 let range = start..(start + to_draw.len());
-                if min_select != max_select
-                    && (range.contains(&min_select)
-                        || range.contains(&max_select)
-                        || (min_select..max_select).contains(&start))
-                {
-                    let mut selection_start_x = PhysicalLength::default();
-                    let mut selection_end_x = PhysicalLength::default();
-                    let mut after_selection_x = PhysicalLength::default();
-                    // Determine the first and last (inclusive) glyph of the selection. The anchor
+                let intersects_line = |min: usize, max: usize| {
+                    min != max
+                        && (range.contains(&min)
+                            || range.contains(&max)
+                            || (min..max).contains(&start))
+                };
+                // Returns the x position of the start and end of the given range within this line,
+                // and the start of the glyph after it.
+                let range_x = |min: usize, max: usize| {
+                    let mut start_x = PhysicalLength::default();
+                    let mut end_x = PhysicalLength::default();
+                    let mut after_x = PhysicalLength::default();
+                    // Determine the first and last (inclusive) glyph of the range. The start
                     // will always be at the start of a grapheme boundary, so there's at ShapedGlyph
-                    // that has a matching byte index. For the selection end we have to look for the
+                    // that has a matching byte index. For the end we have to look for the
                     // visual end of glyph before the cursor, because due to for example ligatures
                     // (or generally glyph substitution) there may not be a dedicated glyph.
                     // FIXME: in the case of ligature, there is currently no way to know the exact
                     // position of the split. When we know it, we might need to draw in two
                     // steps with clip to draw each part of the ligature in a different color
                     for glyph in &metrics.glyphs {
-                        if glyph.byte_index == min_select.saturating_sub(start) {
-                            selection_start_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
+                        if glyph.byte_index == min.saturating_sub(start) {
+                            start_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
                         }
-                        if glyph.byte_index == max_select - start
-                            || glyph.byte_index >= to_draw.len()
-                        {
-                            after_selection_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
+                        if glyph.byte_index == max - start || glyph.byte_index >= to_draw.len() {
+                            after_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
                             break;
                         }
-                        selection_end_x = PhysicalLength::new(glyph.x + glyph.advance_x);
+                        end_x = PhysicalLength::new(glyph.x + glyph.advance_x);
                     }
+                    (start_x, end_x, after_x)
+                };
+
+                if intersects_line(min_select, max_select) {
+                    let (selection_start_x, selection_end_x, after_selection_x) =
+                        range_x(min_select, max_select);
 
                     let selection_rect = PhysicalRect::new(
                         pos + PhysicalPoint::from_lengths(
@@ -492,36 +521,80 @@ fn draw_text_input(
                     let mut selected_paint = paint.clone();
                     selected_paint
                         .set_color(to_femtovg_color(&text_input.selection_foreground_color()));
-                    canvas
-                        .fill_text(
-                            pos.x,
-                            pos.y,
-                            to_draw[..min_select.saturating_sub(start)].trim_end(),
-                            &paint,
-                        )
-                        .unwrap();
-                    canvas
-                        .fill_text(
-                            pos.x + selection_start_x.get(),
-                            pos.y,
-                            to_draw[min_select.saturating_sub(start)
-                                ..(max_select - start).min(to_draw.len())]
-                                .trim_end(),
-                            &selected_paint,
-                        )
-                        .unwrap();
-                    canvas
-                        .fill_text(
-                            pos.x + after_selection_x.get(),
-                            pos.y,
-                            to_draw[(max_select - start).min(to_draw.len())..].trim_end(),
-                            &paint,
-                        )
-                        .unwrap();
+                    font.fill_text(
+                        &mut canvas,
+                        pos.x,
+                        pos.y,
+                        to_draw[..min_select.saturating_sub(start)].trim_end(),
+                        &paint,
+                    );
+                    font.fill_text(
+                        &mut canvas,
+                        pos.x + selection_start_x.get(),
+                        pos.y,
+                        to_draw[min_select.saturating_sub(start)
+                            ..(max_select - start).min(to_draw.len())]
+                            .trim_end(),
+                        &selected_paint,
+                    );
+                    font.fill_text(
+                        &mut canvas,
+                        pos.x + after_selection_x.get(),
+                        pos.y,
+                        to_draw[(max_select - start).min(to_draw.len())..].trim_end(),
+                        &paint,
+                    );
                 } else {
                     // no selection on this line
-                    canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                    font.fill_text(&mut canvas, pos.x, pos.y, to_draw.trim_end(), &paint);
                 };
+
+                if intersects_line(preedit_range.start, preedit_range.end) {
+                    let (underline_start_x, underline_end_x, _) =
+                        range_x(preedit_range.start, preedit_range.end);
+                    let mut underline = femtovg::Path::new();
+                    underline.rect(
+                        pos.x + underline_start_x.get(),
+                        pos.y + underline_y,
+                        (underline_end_x - underline_start_x).get(),
+                        underline_thickness,
+                    );
+                    canvas.fill_path(&underline, &paint);
+                }
+
+                for (range, color, style) in &visual_representation.underlines {
+                    if !intersects_line(range.start, range.end) {
+                        continue;
+                    }
+                    let (underline_start_x, underline_end_x, _) = range_x(range.start, range.end);
+                    let start_x = pos.x + underline_start_x.get();
+                    let end_x = pos.x + underline_end_x.get();
+                    let y = pos.y + underline_y;
+                    let mut underline = femtovg::Path::new();
+                    underline.move_to(start_x, y);
+                    match style {
+                        items::TextUnderlineStyle::Solid => underline.line_to(end_x, y),
+                        items::TextUnderlineStyle::Wavy => {
+                            let half_wave = underline_thickness * 2.;
+                            let mut x = start_x;
+                            let mut up = true;
+                            while x < end_x {
+                                let next_x = (x + half_wave).min(end_x);
+                                let ctrl_y = if up {
+                                    y - underline_thickness * 1.5
+                                } else {
+                                    y + underline_thickness * 1.5
+                                };
+                                underline.quad_to((x + next_x) / 2., ctrl_y, next_x, y);
+                                x = next_x;
+                                up = !up;
+                            }
+                        }
+                    }
+                    let mut underline_paint = femtovg::Paint::color(to_femtovg_color(color));
+                    underline_paint.set_line_width(underline_thickness);
+                    canvas.stroke_path(&underline, &underline_paint);
+                }
             },
         );
 
@@ -965,7 +1038,7 @@ fn draw_string(&mut self, string: &str, color: Color) {
         let paint = font
             .init_paint(PhysicalLength::default(), femtovg::Paint::color(to_femtovg_color(&color)));
         let mut canvas = self.canvas.borrow_mut();
-        canvas.fill_text(0., 0., string, &paint).unwrap();
+        font.fill_text(&mut canvas, 0., 0., string, &paint);
     }
 
     fn draw_image_direct(&mut self, image: i_slint_core::graphics::Image) {
@@ -1090,6 +1163,7 @@ pub(super) fn new(
         window: &'a i_slint_core::api::Window,
         width: u32,
         height: u32,
+        render_target: femtovg::RenderTarget,
     ) -> Self {
         let scale_factor = ScaleFactor::new(window.scale_factor());
         Self {
@@ -1106,7 +1180,7 @@ pub(super) fn new(
                     PhysicalSize::new(width as f32, height as f32) / scale_factor,
                 ),
                 global_alpha: 1.,
-                current_render_target: femtovg::RenderTarget::Screen,
+                current_render_target: render_target,
             }],
             metrics: RenderingMetrics { layers_created: Some(0) },
         }
@@ -1547,10 +1621,143 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
                     stops,
                 )
             }
+            Brush::ConicGradient(gradient) => {
+                let path_bounds = path_bounding_box(&self.canvas, path);
+
+                let width = path_bounds.width().ceil() as usize;
+                let height = path_bounds.height().ceil() as usize;
+                if width == 0 || height == 0 {
+                    return None;
+                }
+
+                // femtovg doesn't support conic gradients, so render the gradient in an image instead
+                let pixels = conic_gradient_pixels(&gradient, width, height);
+                let image_id = self
+                    .canvas
+                    .borrow_mut()
+                    .create_image(
+                        imgref::Img::new(pixels.as_slice(), width, height),
+                        femtovg::ImageFlags::empty(),
+                    )
+                    .ok()?;
+                self.textures_to_delete_after_flush
+                    .borrow_mut()
+                    .push(Texture::adopt(&self.canvas, image_id));
+                femtovg::Paint::image(image_id, 0., 0., width as f32, height as f32, 0., 1.)
+            }
+            Brush::ImagePattern(pattern) => self.image_pattern_to_paint(&pattern)?,
             _ => return None,
         })
     }
 
+    fn image_pattern_to_paint(&self, pattern: &ImagePatternBrush) -> Option<femtovg::Paint> {
+        let image_inner: &ImageInner = pattern.image().into();
+        let image_size = pattern.image().size().cast::<f32>();
+        if image_size.is_empty() {
+            return None;
+        }
+        let (scale_x, scale_y) = pattern.scale();
+        let target_size_for_scalable_source = image_inner.is_svg().then(|| {
+            euclid::size2(
+                (image_size.width * scale_x.abs() * self.scale_factor.get()).ceil() as u32,
+                (image_size.height * scale_y.abs() * self.scale_factor.get()).ceil() as u32,
+            )
+        });
+
+        let (repeat_x, repeat_y) = pattern.repeat();
+        let tiling = |repeat| if repeat { ImageTiling::Repeat } else { ImageTiling::None };
+        let tiling = (tiling(repeat_x), tiling(repeat_y));
+        // femtovg extends the edges of the image beyond it, so a transparent border is added in
+        // the directions where the image isn't repeated.
+        let (border_x, border_y) = (usize::from(!repeat_x), usize::from(!repeat_y));
+
+        let (texture, image_pixel_size) = if repeat_x && repeat_y {
+            let texture = TextureCacheKey::new(
+                image_inner,
+                target_size_for_scalable_source,
+                ImageRendering::Smooth,
+                tiling,
+            )
+            .and_then(|cache_key| {
+                self.texture_cache.borrow_mut().lookup_image_in_cache_or_create(cache_key, || {
+                    Texture::new_from_image(
+                        image_inner,
+                        &self.canvas,
+                        target_size_for_scalable_source,
+                        ImageRendering::Smooth,
+                        tiling,
+                    )
+                })
+            })
+            .or_else(|| {
+                Texture::new_from_image(
+                    image_inner,
+                    &self.canvas,
+                    target_size_for_scalable_source,
+                    ImageRendering::Smooth,
+                    tiling,
+                )
+            })?;
+            let texture_size = texture.size()?.cast::<f32>();
+            let pixel_size =
+                (image_size.width / texture_size.width, image_size.height / texture_size.height);
+            (texture, pixel_size)
+        } else {
+            let buffer = image_inner.render_to_buffer(target_size_for_scalable_source)?;
+            let (width, height) = (buffer.width() as usize, buffer.height() as usize);
+            let padded_width = width + 2 * border_x;
+            let mut pixels = vec![rgb::RGBA8::default(); padded_width * (height + 2 * border_y)];
+            let padded_rows = pixels.chunks_mut(padded_width).skip(border_y);
+            let mut flags = super::images::base_image_flags(ImageRendering::Smooth, tiling);
+            match &buffer {
+                SharedImageBuffer::RGB8(buffer) => {
+                    for (row, source) in padded_rows.zip(buffer.as_slice().chunks(width)) {
+                        for (pixel, source) in row[border_x..].iter_mut().zip(source) {
+                            *pixel = rgb::RGBA8::new(source.r, source.g, source.b, 255);
+                        }
+                    }
+                }
+                SharedImageBuffer::RGBA8(buffer)
+                | SharedImageBuffer::RGBA8Premultiplied(buffer) => {
+                    for (row, source) in padded_rows.zip(buffer.as_slice().chunks(width)) {
+                        row[border_x..border_x + width].copy_from_slice(source);
+                    }
+                }
+            }
+            if matches!(buffer, SharedImageBuffer::RGBA8Premultiplied(_)) {
+                flags |= femtovg::ImageFlags::PREMULTIPLIED;
+            }
+            let image_id = self
+                .canvas
+                .borrow_mut()
+                .create_image(
+                    imgref::Img::new(pixels.as_slice(), padded_width, height + 2 * border_y),
+                    flags,
+                )
+                .ok()?;
+            let texture = Texture::adopt(&self.canvas, image_id);
+            (texture, (image_size.width / width as f32, image_size.height / height as f32))
+        };
+        let texture_size = texture.size()?.cast::<f32>();
+        self.textures_to_delete_after_flush.borrow_mut().push(texture.clone());
+
+        // The size of a pixel of the texture in the logical coordinates of the image
+        let (pixel_width, pixel_height) = image_pixel_size;
+        let origin = pattern.transform().transform_point(euclid::point2(
+            -(border_x as f32) * pixel_width,
+            -(border_y as f32) * pixel_height,
+        )) * self.scale_factor.get();
+        Some(femtovg::Paint::image(
+            texture.id,
+            origin.x,
+            origin.y,
+            texture_size.width * pixel_width * scale_x * self.scale_factor.get(),
+            texture_size.height * pixel_height * scale_y * self.scale_factor.get(),
+            pattern.rotation().to_radians(),
+            pattern.opacity(),
+        ))
+    }
+
     fn current_render_target(&self) -> femtovg::RenderTarget {
         self.state.last().unwrap().current_render_target
     }
@@ -1559,3 +1766,53 @@ fn current_render_target(&self) -> femtovg::RenderTarget {
 pub fn to_femtovg_color(col: &Color) -> femtovg::Color {
     femtovg::Color::rgba(col.red(), col.green(), col.blue(), col.alpha())
 }
+
+/// Returns the pixels of an image of the given size filled with the conic gradient, centered in it.
+fn conic_gradient_pixels(
+    gradient: &i_slint_core::graphics::ConicGradientBrush,
+    width: usize,
+    height: usize,
+) -> Vec<rgb::RGBA8> {
+    let (center_x, center_y) = (width as f32 / 2., height as f32 / 2.);
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            // Clockwise from the top, as y goes down
+            let angle = (x as f32 + 0.5 - center_x).atan2(center_y - y as f32 - 0.5).to_degrees();
+            let color = gradient.color_at_angle(angle);
+            rgb::RGBA8::new(color.red(), color.green(), color.blue(), color.alpha())
+        })
+        .collect()
+}
+
+#[test]
+fn conic_gradient_start_and_direction() {
+    use i_slint_core::graphics::{ConicGradientBrush, GradientStop};
+    let red = Color::from_rgb_u8(255, 0, 0);
+    let blue = Color::from_rgb_u8(0, 0, 255);
+    // Red on the right half, blue on the left half, when starting at the top
+    let gradient = ConicGradientBrush::new(
+        0.,
+        [
+            GradientStop { color: red, position: 0. },
+            GradientStop { color: red, position: 0.5 },
+            GradientStop { color: blue, position: 0.5 },
+            GradientStop { color: blue, position: 1. },
+        ],
+    );
+    let pixels = conic_gradient_pixels(&gradient, 20, 10);
+    let pixel = |x: usize, y: usize| pixels[y * 20 + x];
+    assert_eq!(pixel(15, 2), rgb::RGBA8::new(255, 0, 0, 255));
+    assert_eq!(pixel(15, 8), rgb::RGBA8::new(255, 0, 0, 255));
+    assert_eq!(pixel(4, 2), rgb::RGBA8::new(0, 0, 255, 255));
+    assert_eq!(pixel(4, 8), rgb::RGBA8::new(0, 0, 255, 255));
+
+    // Starting on the right, red is on the bottom half
+    let gradient = ConicGradientBrush::new(90., gradient.stops().cloned());
+    let pixels = conic_gradient_pixels(&gradient, 20, 10);
+    let pixel = |x: usize, y: usize| pixels[y * 20 + x];
+    assert_eq!(pixel(4, 8), rgb::RGBA8::new(255, 0, 0, 255));
+    assert_eq!(pixel(15, 8), rgb::RGBA8::new(255, 0, 0, 255));
+    assert_eq!(pixel(4, 1), rgb::RGBA8::new(0, 0, 255, 255));
+    assert_eq!(pixel(15, 1), rgb::RGBA8::new(0, 0, 255, 255));
+}
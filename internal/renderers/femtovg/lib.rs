@@ -127,6 +127,9 @@ pub struct FemtoVGRenderer {
     texture_cache: RefCell<images::TextureCache>,
     rendering_metrics_collector: RefCell<Option<Rc<RenderingMetricsCollector>>>,
     rendering_first_time: Cell<bool>,
+    render_scale: Cell<f32>,
+    /// The texture the scene is rendered to when the render scale isn't 1
+    render_scale_texture: RefCell<Option<Rc<images::Texture>>>,
     // Last field, so that it's dropped last and context exists and is current when destroying the FemtoVG canvas
     opengl_context: RefCell<Box<dyn OpenGLInterface>>,
     #[cfg(target_arch = "wasm32")]
@@ -249,6 +252,29 @@ fn internal_render_with_post_callback(
                     })?;
                 }
 
+                let render_scale = self.render_scale.get();
+                let scaled_texture = if render_scale != 1. {
+                    self.render_scale_texture(&canvas, width, height, render_scale)
+                } else {
+                    self.render_scale_texture.take();
+                    None
+                };
+                if let Some(texture) = &scaled_texture {
+                    let mut femtovg_canvas = canvas.borrow_mut();
+                    femtovg_canvas.save();
+                    femtovg_canvas.set_render_target(texture.as_render_target());
+                    femtovg_canvas.reset();
+                    let size = texture.size().unwrap_or_default();
+                    femtovg_canvas.clear_rect(
+                        0,
+                        0,
+                        size.width,
+                        size.height,
+                        femtovg::Color::rgba(0, 0, 0, 0),
+                    );
+                    femtovg_canvas.scale(render_scale, render_scale);
+                }
+
                 self.graphics_cache.clear_cache_if_scale_factor_changed(window);
 
                 let mut item_renderer = self::itemrenderer::GLItemRenderer::new(
@@ -258,6 +284,9 @@ fn internal_render_with_post_callback(
                     window,
                     width.get(),
                     height.get(),
+                    scaled_texture.as_ref().map_or(femtovg::RenderTarget::Screen, |texture| {
+                        texture.as_render_target()
+                    }),
                 );
 
                 // Draws the window background as gradient
@@ -289,6 +318,20 @@ fn internal_render_with_post_callback(
                     collector.measure_frame_rendered(&mut item_renderer);
                 }
 
+                if let Some(texture) = &scaled_texture {
+                    // Draw the scene scaled to the size of the window
+                    let mut femtovg_canvas = canvas.borrow_mut();
+                    femtovg_canvas.restore();
+                    femtovg_canvas.set_render_target(femtovg::RenderTarget::Screen);
+                    let (width, height) = (width.get() as f32, height.get() as f32);
+                    let mut path = femtovg::Path::new();
+                    path.rect(0., 0., width, height);
+                    femtovg_canvas.fill_path(
+                        &path,
+                        &femtovg::Paint::image(texture.id, 0., 0., width, height, 0., 1.),
+                    );
+                }
+
                 canvas.borrow_mut().flush();
 
                 // Delete any images and layer images (and their FBOs) before making the context not current anymore, to
@@ -307,6 +350,24 @@ fn internal_render_with_post_callback(
         Ok(())
     }
 
+    /// Returns the texture to render the scene into at `render_scale` times the size of the window, creating a new one
+    /// if the size changed.
+    fn render_scale_texture(
+        &self,
+        canvas: &CanvasRc,
+        width: NonZeroU32,
+        height: NonZeroU32,
+        render_scale: f32,
+    ) -> Option<Rc<images::Texture>> {
+        let width = ((width.get() as f32 * render_scale).ceil() as u32).max(1);
+        let height = ((height.get() as f32 * render_scale).ceil() as u32).max(1);
+        let mut texture = self.render_scale_texture.borrow_mut();
+        if texture.as_ref().and_then(|texture| texture.size()) != Some([width, height].into()) {
+            *texture = images::Texture::new_empty_on_gpu(canvas, width, height);
+        }
+        texture.clone()
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     fn with_graphics_api(
         &self,
@@ -357,6 +418,17 @@ fn text_size(
         crate::fonts::text_size(&font_request, scale_factor, text, max_width)
     }
 
+    fn text_line_ranges(
+        &self,
+        font_request: i_slint_core::graphics::FontRequest,
+        text: &str,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        _text_wrap: TextWrap,
+    ) -> Result<Vec<core::ops::Range<usize>>, PlatformError> {
+        Ok(crate::fonts::text_line_ranges(&font_request, scale_factor, text, max_width))
+    }
+
     fn font_metrics(
         &self,
         font_request: i_slint_core::graphics::FontRequest,
@@ -365,6 +437,19 @@ fn font_metrics(
         crate::fonts::font_metrics(font_request)
     }
 
+    fn text_outline(
+        &self,
+        text: &str,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Result<i_slint_core::api::TextOutline, PlatformError> {
+        Ok(i_slint_core::api::TextOutline::new(crate::fonts::text_outline(
+            &font_request,
+            scale_factor,
+            text,
+        )))
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
@@ -401,6 +486,7 @@ fn text_input_byte_offset_for_position(
             i_slint_core::items::TextOverflow::Clip,
             text_input.single_line(),
             None,
+            None,
             &paint,
             |line_text, line_pos, start, metrics| {
                 if (line_pos.y..(line_pos.y + font_height)).contains(&pos.y) {
@@ -420,13 +506,13 @@ fn text_input_byte_offset_for_position(
         visual_representation.map_byte_offset_from_byte_offset_in_visual_text(result)
     }
 
-    fn text_input_cursor_rect_for_byte_offset(
+    fn text_input_cursor_metrics_for_byte_offset(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
         byte_offset: usize,
         font_request: FontRequest,
         scale_factor: ScaleFactor,
-    ) -> LogicalRect {
+    ) -> i_slint_core::items::TextCursorMetrics {
         let text = text_input.text();
 
         let font_size = font_request.pixel_size.unwrap_or(fonts::DEFAULT_FONT_SIZE);
@@ -434,16 +520,24 @@ fn text_input_cursor_rect_for_byte_offset(
         let width = text_input.width() * scale_factor;
         let height = text_input.height() * scale_factor;
         if width.get() <= 0. || height.get() <= 0. {
-            return LogicalRect::new(
+            let rect = LogicalRect::new(
                 LogicalPoint::default(),
                 LogicalSize::from_lengths(LogicalLength::new(1.0), font_size),
             );
+            return i_slint_core::items::TextCursorMetrics {
+                rect,
+                baseline: font_size.get(),
+                line_index: 0,
+                line_rect: rect,
+            };
         }
 
         let font = crate::fonts::FONT_CACHE
             .with(|cache| cache.borrow_mut().font(font_request, scale_factor, &text_input.text()));
 
         let paint = font.init_paint(text_input.letter_spacing() * scale_factor, Default::default());
+        let line_height = font.height();
+        let mut lines = Vec::new();
         let cursor_point = fonts::layout_text_lines(
             text.as_str(),
             &font,
@@ -452,29 +546,87 @@ fn text_input_cursor_rect_for_byte_offset(
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
             text_input.single_line(),
+            None,
             Some(byte_offset),
             &paint,
-            |_, _, _, _| {},
-        );
-
-        LogicalRect::new(
-            cursor_point.unwrap_or_default() / scale_factor,
-            LogicalSize::from_lengths(LogicalLength::new(1.0), font_size),
+            |_, pos, _, metrics| {
+                lines.push(PhysicalRect::new(
+                    pos,
+                    PhysicalSize::from_lengths(PhysicalLength::new(metrics.width()), line_height),
+                ))
+            },
         )
+        .unwrap_or_default();
+
+        // The cursor after a trailing newline is on a line of its own that has no text
+        let (line_index, line_rect) = lines
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.origin.y == cursor_point.y)
+            .map(|(index, line)| (index, *line))
+            .unwrap_or_else(|| {
+                (
+                    lines.len(),
+                    PhysicalRect::new(
+                        cursor_point,
+                        PhysicalSize::from_lengths(Default::default(), line_height),
+                    ),
+                )
+            });
+
+        i_slint_core::items::TextCursorMetrics {
+            rect: LogicalRect::new(
+                cursor_point / scale_factor,
+                LogicalSize::from_lengths(LogicalLength::new(1.0), font_size),
+            ),
+            baseline: (cursor_point.y + font.ascent().get()) / scale_factor.get(),
+            line_index,
+            line_rect: line_rect / scale_factor,
+        }
     }
 
     fn register_font_from_memory(
         &self,
         data: &'static [u8],
     ) -> Result<(), Box<dyn std::error::Error>> {
-        sharedfontdb::register_font_from_memory(data)
+        sharedfontdb::register_font_from_memory(data)?;
+        fonts::FONT_CACHE.with(|cache| cache.borrow_mut().update_available_families());
+        Ok(())
     }
 
     fn register_font_from_path(
         &self,
         path: &std::path::Path,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        sharedfontdb::register_font_from_path(path)
+        sharedfontdb::register_font_from_path(path)?;
+        fonts::FONT_CACHE.with(|cache| cache.borrow_mut().update_available_families());
+        Ok(())
+    }
+
+    fn unregister_font(&self, family: &str) -> Result<(), Box<dyn std::error::Error>> {
+        sharedfontdb::unregister_font(family)?;
+        fonts::FONT_CACHE.with(|cache| cache.borrow_mut().clear_loaded_fonts());
+        Ok(())
+    }
+
+    fn replace_font_from_memory(
+        &self,
+        family: &str,
+        data: &'static [u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        sharedfontdb::replace_font_from_memory(family, data)?;
+        fonts::FONT_CACHE.with(|cache| cache.borrow_mut().clear_loaded_fonts());
+        Ok(())
+    }
+
+    fn replace_font_from_path(
+        &self,
+        family: &str,
+        path: &std::path::Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        sharedfontdb::replace_font_from_path(family, path)?;
+        fonts::FONT_CACHE.with(|cache| cache.borrow_mut().clear_loaded_fonts());
+        Ok(())
     }
 
     fn default_font_size(&self) -> LogicalLength {
@@ -510,6 +662,7 @@ fn set_window_adapter(&self, window_adapter: &Rc<dyn WindowAdapter>) {
         if self.opengl_context.borrow().ensure_current().is_ok() {
             self.graphics_cache.clear_all();
             self.texture_cache.borrow_mut().clear();
+            self.render_scale_texture.take();
         }
     }
 
@@ -520,6 +673,13 @@ fn resize(&self, size: i_slint_core::api::PhysicalSize) -> Result<(), PlatformEr
         Ok(())
     }
 
+    fn set_render_scale(&self, render_scale: f32) -> Result<(), PlatformError> {
+        if self.render_scale.replace(render_scale) != render_scale {
+            self.window_adapter()?.request_redraw();
+        }
+        Ok(())
+    }
+
     /// Returns an image buffer of what was rendered last by reading the previous front buffer (using glReadPixels).
     fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
         self.opengl_context.borrow().ensure_current()?;
@@ -579,6 +739,8 @@ fn new_without_context() -> Self {
             texture_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Cell::new(true),
+            render_scale: Cell::new(1.),
+            render_scale_texture: Default::default(),
             opengl_context: RefCell::new(opengl_context),
             #[cfg(target_arch = "wasm32")]
             canvas_id: Default::default(),
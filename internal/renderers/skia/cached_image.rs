@@ -132,6 +132,9 @@ pub(crate) fn as_skia_image(
         ImageInner::NineSlice(n) => {
             as_skia_image(n.image(), target_size_fn, ImageFit::Preserve, scale_factor, canvas)
         }
+        ImageInner::AnimatedImage(_) => {
+            as_skia_image(image.frame(0)?, target_size_fn, image_fit, scale_factor, canvas)
+        }
     }
 }
 
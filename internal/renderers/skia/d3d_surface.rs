@@ -122,6 +122,7 @@ fn render_and_present<T>(
         &mut self,
         callback: impl FnOnce(&mut skia_safe::Surface, &mut skia_safe::gpu::DirectContext, u8) -> T,
         pre_present_callback: &RefCell<Option<Box<dyn FnMut()>>>,
+        vsync: bool,
     ) -> Result<T, PlatformError> {
         let current_fence_value = self.fence_values[self.current_buffer_index];
 
@@ -147,7 +148,8 @@ fn render_and_present<T>(
             pre_present_callback();
         }
 
-        let present_result = unsafe { self.swap_chain.Present(1, DXGI_PRESENT(0)) };
+        let sync_interval = if vsync { 1 } else { 0 };
+        let present_result = unsafe { self.swap_chain.Present(sync_interval, DXGI_PRESENT(0)) };
         if present_result != S_OK && present_result != DXGI_STATUS_OCCLUDED {
             return Err(format!("Error presenting d3d swap chain: {:x}", present_result.0).into());
         }
@@ -403,7 +405,7 @@ fn resize_event(
 
     fn render(
         &self,
-        _window: &Window,
+        window: &Window,
         _size: PhysicalWindowSize,
         callback: &dyn Fn(
             &skia_safe::Canvas,
@@ -417,6 +419,7 @@ fn render(
                 callback(surface.canvas(), Some(gr_context), buffer_age);
             },
             pre_present_callback,
+            window.vsync_enabled(),
         )
     }
 
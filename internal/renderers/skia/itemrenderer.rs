@@ -79,8 +79,14 @@ fn brush_to_paint(
         width: PhysicalLength,
         height: PhysicalLength,
     ) -> Option<skia_safe::Paint> {
-        let (mut paint, shader) =
-            Self::brush_to_shader(self.default_paint().unwrap_or_default(), brush, width, height)?;
+        let (mut paint, shader) = Self::brush_to_shader(
+            self.default_paint().unwrap_or_default(),
+            brush,
+            width,
+            height,
+            self.scale_factor,
+            self.canvas,
+        )?;
         paint.set_shader(Some(shader));
 
         Some(paint)
@@ -91,6 +97,8 @@ fn brush_to_shader(
         brush: Brush,
         width: PhysicalLength,
         height: PhysicalLength,
+        scale_factor: ScaleFactor,
+        canvas: &skia_safe::Canvas,
     ) -> Option<(skia_safe::Paint, skia_safe::Shader)> {
         if brush.is_transparent() {
             return None;
@@ -137,6 +145,60 @@ fn brush_to_shader(
                         as &skia_safe::Matrix,
                 )
             }
+            Brush::ConicGradient(g) => {
+                let (colors, pos): (Vec<_>, Vec<_>) =
+                    g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                let center = skia_safe::Point::new(width.get() / 2., height.get() / 2.);
+
+                paint.set_dither(true);
+
+                // Skia's sweep gradient starts on the right, while a conic gradient starts at the top
+                skia_safe::gradient_shader::sweep(
+                    center,
+                    skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
+                    Some(&*pos),
+                    TileMode::Clamp,
+                    None,
+                    skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
+                    &skia_safe::Matrix::rotate_deg_pivot(g.from_angle() - 90., center),
+                )
+            }
+            Brush::ImagePattern(pattern) => {
+                let image_size = pattern.image().size().cast::<f32>();
+                if image_size.is_empty() {
+                    return None;
+                }
+                let skia_image = super::cached_image::as_skia_image(
+                    pattern.image().clone(),
+                    &|| LogicalSize::from_untyped(image_size),
+                    ImageFit::Preserve,
+                    scale_factor,
+                    canvas,
+                )?;
+
+                let (offset_x, offset_y) = pattern.offset();
+                let (scale_x, scale_y) = pattern.scale();
+                // Scalable images, such as SVG, are rendered at the physical size
+                let matrix = Matrix::scale((scale_factor.get(), scale_factor.get()))
+                    * Matrix::translate((offset_x, offset_y))
+                    * Matrix::rotate_deg(pattern.rotation())
+                    * Matrix::scale((
+                        scale_x * image_size.width / skia_image.width() as f32,
+                        scale_y * image_size.height / skia_image.height() as f32,
+                    ));
+                let tile_mode = |repeat| if repeat { TileMode::Repeat } else { TileMode::Decal };
+                let (repeat_x, repeat_y) = pattern.repeat();
+
+                paint.set_alpha_f(paint.alpha_f() * pattern.opacity());
+
+                skia_image.to_shader(
+                    (tile_mode(repeat_x), tile_mode(repeat_y)),
+                    skia_safe::SamplingOptions::from(
+                        skia_safe::sampling_options::FilterMode::Linear,
+                    ),
+                    &matrix,
+                )
+            }
             _ => None,
         }
         .map(|shader| (paint, shader))
@@ -159,6 +221,8 @@ fn colorize_image(
             colorize_brush,
             PhysicalLength::new(image.width() as f32),
             PhysicalLength::new(image.height() as f32),
+            self.scale_factor,
+            self.canvas,
         )
         .map(|(mut paint, colorize_shader)| {
             let mut surface = self.canvas.new_surface(&image_info, None)?;
@@ -547,7 +611,8 @@ fn draw_text(
                         vertical_alignment,
                         text.wrap(),
                         text.overflow(),
-                        None,
+                        text.max_lines(),
+                        &[],
                     ))
                 }
             }
@@ -565,7 +630,8 @@ fn draw_text(
             vertical_alignment,
             text.wrap(),
             text.overflow(),
-            None,
+            text.max_lines(),
+            &[],
         );
 
         match (stroke_style, stroke_layout) {
@@ -609,23 +675,37 @@ fn draw_text_input(
         let mut text_style = skia_safe::textlayout::TextStyle::new();
         text_style.set_foreground_paint(&paint);
 
-        let selection = if !visual_representation.preedit_range.is_empty() {
-            Some(super::textlayout::Selection {
-                range: visual_representation.preedit_range,
+        let mut selections = Vec::new();
+        if !visual_representation.preedit_range.is_empty() {
+            // Underline the composition, and highlight the clause that is being converted
+            let preedit = visual_representation.preedit_range.clone();
+            let clause = Some(visual_representation.preedit_clause_range.clone())
+                .filter(|clause| !clause.is_empty())
+                .unwrap_or(preedit.end..preedit.end);
+            let underlined = |range| super::textlayout::Selection {
+                range,
                 foreground: None,
                 background: None,
                 underline: true,
-            })
+            };
+            selections.push(underlined(preedit.start..clause.start));
+            if !clause.is_empty() {
+                selections.push(super::textlayout::Selection {
+                    range: clause.clone(),
+                    foreground: text_input.selection_foreground_color().into(),
+                    background: text_input.selection_background_color().into(),
+                    underline: true,
+                });
+            }
+            selections.push(underlined(clause.end..preedit.end));
         } else if !visual_representation.selection_range.is_empty() {
-            Some(super::textlayout::Selection {
-                range: visual_representation.selection_range,
+            selections.push(super::textlayout::Selection {
+                range: visual_representation.selection_range.clone(),
                 foreground: text_input.selection_foreground_color().into(),
                 background: text_input.selection_background_color().into(),
                 underline: false,
-            })
-        } else {
-            None
-        };
+            });
+        }
 
         let (layout, layout_top_left) = super::textlayout::create_layout(
             font_request,
@@ -638,16 +718,35 @@ fn draw_text_input(
             text_input.vertical_alignment(),
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
-            selection.as_ref(),
+            None,
+            &selections,
         );
 
         layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
 
+        for (range, color, style) in &visual_representation.underlines {
+            let (path, thickness) = super::textlayout::underline_path(
+                &visual_representation.text,
+                range.clone(),
+                &layout,
+                *style,
+            );
+            let mut underline_paint = skia_safe::Paint::default();
+            underline_paint.set_anti_alias(true);
+            underline_paint.set_color(to_skia_color(color));
+            underline_paint.set_style(skia_safe::PaintStyle::Stroke);
+            underline_paint.set_stroke_width(thickness);
+            self.canvas.save();
+            self.canvas.translate(to_skia_point(layout_top_left));
+            self.canvas.draw_path(&path, &underline_paint);
+            self.canvas.restore();
+        }
+
         if let Some(cursor_position) = visual_representation.cursor_position {
             let cursor_rect = super::textlayout::cursor_rect(
                 &visual_representation.text,
                 cursor_position,
-                layout,
+                &layout,
                 text_input.text_cursor_width() * self.scale_factor,
                 text_input.horizontal_alignment(),
             )
@@ -1037,3 +1136,55 @@ fn adjust_rect_and_border_for_inner_drawing(
     rect.origin += PhysicalSize::from_lengths(*border_width / 2., *border_width / 2.);
     rect.size -= PhysicalSize::from_lengths(*border_width, *border_width);
 }
+
+#[test]
+fn conic_gradient_start_and_direction() {
+    use i_slint_core::graphics::{ConicGradientBrush, GradientStop};
+    const SIZE: i32 = 20;
+    let gradient = ConicGradientBrush::new(
+        30.,
+        [
+            GradientStop { color: Color::from_rgb_u8(255, 0, 0), position: 0. },
+            GradientStop { color: Color::from_rgb_u8(0, 255, 0), position: 0.5 },
+            GradientStop { color: Color::from_rgb_u8(0, 0, 255), position: 1. },
+        ],
+    );
+
+    let mut surface = skia_safe::surfaces::raster_n32_premul((SIZE, SIZE)).unwrap();
+    let (mut paint, shader) = SkiaItemRenderer::brush_to_shader(
+        skia_safe::Paint::default(),
+        Brush::ConicGradient(gradient.clone()),
+        PhysicalLength::new(SIZE as f32),
+        PhysicalLength::new(SIZE as f32),
+        ScaleFactor::new(1.),
+        surface.canvas(),
+    )
+    .unwrap();
+    paint.set_shader(Some(shader));
+    surface.canvas().draw_paint(&paint);
+
+    let info = skia_safe::ImageInfo::new(
+        (SIZE, SIZE),
+        skia_safe::ColorType::RGBA8888,
+        skia_safe::AlphaType::Unpremul,
+        None,
+    );
+    let mut pixels = vec![0u8; (SIZE * SIZE * 4) as usize];
+    assert!(surface.read_pixels(&info, &mut pixels, SIZE as usize * 4, (0, 0)));
+
+    // Skia's sweep gradient must start at the same angle, and turn in the same direction, as the
+    // color_at_angle() that the other renderers use.
+    for (x, y) in [(15, 5), (15, 15), (5, 15), (5, 5), (10, 1), (18, 10), (10, 18), (1, 10)] {
+        let angle = (x as f32 + 0.5 - SIZE as f32 / 2.)
+            .atan2(SIZE as f32 / 2. - y as f32 - 0.5)
+            .to_degrees();
+        let expected = gradient.color_at_angle(angle);
+        let offset = (y * SIZE + x) as usize * 4;
+        let actual = &pixels[offset..offset + 3];
+        for (actual, expected) in
+            actual.iter().zip([expected.red(), expected.green(), expected.blue()])
+        {
+            assert!(actual.abs_diff(expected) <= 4, "pixel {x},{y}: {actual} != {expected}");
+        }
+    }
+}
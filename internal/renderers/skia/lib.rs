@@ -13,9 +13,12 @@
 };
 use i_slint_core::graphics::euclid::{self, Vector2D};
 use i_slint_core::graphics::rendering_metrics_collector::RenderingMetricsCollector;
-use i_slint_core::graphics::{BorderRadius, FontRequest, RequestedGraphicsAPI, SharedPixelBuffer};
+use i_slint_core::graphics::{
+    BorderRadius, FontRequest, RequestedGraphicsAPI, SharedPixelBuffer, VisionSimulation,
+};
 use i_slint_core::item_rendering::RepaintBufferType;
 use i_slint_core::item_rendering::{DirtyRegion, ItemCache, ItemRenderer, PartialRenderingState};
+use i_slint_core::item_tree::ItemWeak;
 use i_slint_core::lengths::{
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PhysicalPx, ScaleFactor,
 };
@@ -91,6 +94,30 @@ fn create_default_surface(
     }
 }
 
+/// Returns the filter to apply to the rendered content of the window to simulate the vision deficiency
+fn vision_simulation_filter(
+    simulation: VisionSimulation,
+    scale_factor: ScaleFactor,
+) -> Option<skia_safe::ImageFilter> {
+    if let Some([r, g, b]) = simulation.color_matrix() {
+        let matrix = skia_safe::ColorMatrix::new(
+            r[0], r[1], r[2], 0., 0., //
+            g[0], g[1], g[2], 0., 0., //
+            b[0], b[1], b[2], 0., 0., //
+            0., 0., 0., 1., 0.,
+        );
+        // The matrices model the response of the cones, so they apply to linear light
+        let color_filter = skia_safe::color_filters::matrix(&matrix, None)
+            .with_working_color_space(skia_safe::ColorSpace::new_srgb_linear())?;
+        skia_safe::image_filters::color_filter(color_filter, None, None)
+    } else if simulation == VisionSimulation::Blur {
+        let sigma = 3. * scale_factor.get();
+        skia_safe::image_filters::blur((sigma, sigma), skia_safe::TileMode::Clamp, None, None)
+    } else {
+        None
+    }
+}
+
 fn create_partial_renderer_state() -> (Option<PartialRenderingState>, bool) {
     let visualize_dirty_region = match std::env::var("SLINT_SKIA_PARTIAL_RENDERING").as_deref() {
         Ok("debug") => true,
@@ -120,6 +147,13 @@ pub struct SkiaRenderer {
     pre_present_callback: RefCell<Option<Box<dyn FnMut()>>>,
     partial_rendering_state: Option<PartialRenderingState>,
     visualize_dirty_region: bool,
+    /// The filter, and the item to whose area it's restricted
+    vision_simulation: RefCell<(VisionSimulation, Option<ItemWeak>)>,
+    render_scale: Cell<f32>,
+    gamma_correct_blending: Cell<bool>,
+    /// The surface the scene is rendered to when the render scale isn't 1 or when blending in linear light, kept
+    /// across frames for partial rendering
+    offscreen_surface: RefCell<Option<skia_safe::Surface>>,
 }
 
 impl Default for SkiaRenderer {
@@ -137,6 +171,10 @@ fn default() -> Self {
             pre_present_callback: Default::default(),
             partial_rendering_state,
             visualize_dirty_region,
+            vision_simulation: Default::default(),
+            render_scale: Cell::new(1.),
+            gamma_correct_blending: Default::default(),
+            offscreen_surface: Default::default(),
         }
     }
 }
@@ -166,6 +204,10 @@ pub fn default_software() -> Self {
             pre_present_callback: Default::default(),
             partial_rendering_state,
             visualize_dirty_region,
+            vision_simulation: Default::default(),
+            render_scale: Cell::new(1.),
+            gamma_correct_blending: Default::default(),
+            offscreen_surface: Default::default(),
         }
     }
 
@@ -193,6 +235,10 @@ pub fn default_opengl() -> Self {
             pre_present_callback: Default::default(),
             partial_rendering_state,
             visualize_dirty_region,
+            vision_simulation: Default::default(),
+            render_scale: Cell::new(1.),
+            gamma_correct_blending: Default::default(),
+            offscreen_surface: Default::default(),
         }
     }
 
@@ -220,6 +266,10 @@ pub fn default_metal() -> Self {
             pre_present_callback: Default::default(),
             partial_rendering_state,
             visualize_dirty_region,
+            vision_simulation: Default::default(),
+            render_scale: Cell::new(1.),
+            gamma_correct_blending: Default::default(),
+            offscreen_surface: Default::default(),
         }
     }
 
@@ -247,6 +297,10 @@ pub fn default_vulkan() -> Self {
             pre_present_callback: Default::default(),
             partial_rendering_state,
             visualize_dirty_region,
+            vision_simulation: Default::default(),
+            render_scale: Cell::new(1.),
+            gamma_correct_blending: Default::default(),
+            offscreen_surface: Default::default(),
         }
     }
 
@@ -274,6 +328,10 @@ pub fn default_direct3d() -> Self {
             pre_present_callback: Default::default(),
             partial_rendering_state,
             visualize_dirty_region,
+            vision_simulation: Default::default(),
+            render_scale: Cell::new(1.),
+            gamma_correct_blending: Default::default(),
+            offscreen_surface: Default::default(),
         }
     }
 
@@ -308,6 +366,10 @@ pub fn new_with_surface(surface: Box<dyn Surface + 'static>) -> Self {
             pre_present_callback: Default::default(),
             partial_rendering_state,
             visualize_dirty_region,
+            vision_simulation: Default::default(),
+            render_scale: Cell::new(1.),
+            gamma_correct_blending: Default::default(),
+            offscreen_surface: Default::default(),
         }
     }
 
@@ -323,6 +385,8 @@ fn clear_surface(&self) {
         let Some(surface) = self.surface.borrow_mut().take() else {
             return;
         };
+        // The offscreen surface belongs to the graphics context of the surface
+        self.offscreen_surface.take();
 
         // If we've rendered a frame before, then we need to invoke the RenderingTearDown notifier.
         if !self.rendering_first_time.get() {
@@ -407,6 +471,18 @@ fn internal_render_with_post_callback(
             window,
             surface_size,
             &|skia_canvas, gr_context, back_buffer_age| {
+                if self.needs_offscreen_rendering() {
+                    skia_canvas.rotate(rotation_angle_degrees, None);
+                    skia_canvas.translate(translation);
+                    return self.render_offscreen_to_canvas(
+                        skia_canvas,
+                        &mut self.offscreen_surface.borrow_mut(),
+                        gr_context,
+                        Some(surface.as_ref()),
+                        window,
+                        post_render_cb,
+                    );
+                }
                 self.render_to_canvas(
                     skia_canvas,
                     rotation_angle_degrees,
@@ -422,6 +498,112 @@ fn internal_render_with_post_callback(
         )
     }
 
+    fn needs_offscreen_rendering(&self) -> bool {
+        self.render_scale.get() != 1. || self.gamma_correct_blending.get()
+    }
+
+    /// Renders the scene into `offscreen_surface`, at the render scale times the size of the window, and draws it
+    /// scaled to the size of the window into `skia_canvas`. The offscreen surface is (re-)created if its size doesn't
+    /// match. With gamma-correct blending, the offscreen surface has a linear color space, so that the colors are
+    /// blended in linear light, and they are encoded back to sRGB when drawn into `skia_canvas`.
+    fn render_offscreen_to_canvas(
+        &self,
+        skia_canvas: &skia_safe::Canvas,
+        offscreen_surface: &mut Option<skia_safe::Surface>,
+        gr_context: Option<&mut skia_safe::gpu::DirectContext>,
+        surface: Option<&dyn Surface>,
+        window: &i_slint_core::api::Window,
+        post_render_cb: Option<&dyn Fn(&mut dyn ItemRenderer)>,
+    ) -> Option<DirtyRegion> {
+        let render_scale = self.render_scale.get();
+        let gamma_correct_blending = self.gamma_correct_blending.get();
+        let size = window.size();
+        let width = ((size.width as f32 * render_scale).ceil() as i32).max(1);
+        let height = ((size.height as f32 * render_scale).ceil() as i32).max(1);
+
+        let reused = offscreen_surface
+            .as_ref()
+            .is_some_and(|offscreen| offscreen.width() == width && offscreen.height() == height);
+        if !reused {
+            *offscreen_surface = if gamma_correct_blending {
+                // With an sRGB color type, the GPU decodes the colors to linear light for blending and encodes them
+                // back, with 8 bits per channel. Otherwise, the colors are stored linearly with 16 bits per channel,
+                // as 8 bits aren't enough for the dark colors.
+                [skia_safe::ColorType::SRGBA8888, skia_safe::ColorType::RGBAF16]
+                    .into_iter()
+                    .find_map(|color_type| {
+                        let image_info = skia_safe::ImageInfo::new(
+                            (width, height),
+                            color_type,
+                            skia_safe::AlphaType::Premul,
+                            skia_safe::ColorSpace::new_srgb_linear(),
+                        );
+                        skia_canvas.new_surface(&image_info, None)
+                    })
+            } else {
+                let image_info = skia_canvas
+                    .image_info()
+                    .with_dimensions((width, height))
+                    .with_alpha_type(skia_safe::AlphaType::Premul);
+                skia_canvas.new_surface(&image_info, None)
+            };
+        }
+        let Some(offscreen_surface) = offscreen_surface.as_mut() else {
+            // The backend can't create the surface, render at the resolution of the window instead
+            if gamma_correct_blending {
+                i_slint_core::debug_log!(
+                    "Skia renderer: the graphics backend can't render in linear light, blending in the sRGB color space instead"
+                );
+            }
+            return self.render_to_canvas(
+                skia_canvas,
+                0.,
+                (0., 0.),
+                gr_context,
+                0,
+                surface,
+                window,
+                post_render_cb,
+            );
+        };
+
+        let offscreen_canvas = offscreen_surface.canvas();
+        offscreen_canvas.save();
+        offscreen_canvas.scale((render_scale, render_scale));
+        self.render_to_canvas(
+            offscreen_canvas,
+            0.,
+            (0., 0.),
+            gr_context,
+            if reused { 1 } else { 0 },
+            surface,
+            window,
+            post_render_cb,
+        );
+        offscreen_canvas.restore();
+
+        let image = offscreen_surface.image_snapshot();
+        // Replace the previous content, which may be transparent
+        let mut paint = skia_safe::Paint::default();
+        paint.set_blend_mode(skia_safe::BlendMode::Src);
+        if gamma_correct_blending && skia_canvas.image_info().color_space().is_none() {
+            // Without a color space, Skia doesn't convert the image to the color space of the target
+            paint.set_color_filter(skia_safe::color_filters::linear_to_srgb_gamma());
+        }
+        skia_canvas.save();
+        skia_canvas.scale((1. / render_scale, 1. / render_scale));
+        skia_canvas.draw_image_with_sampling_options(
+            image,
+            skia_safe::Point::default(),
+            skia_safe::sampling_options::FilterMode::Linear,
+            Some(&paint),
+        );
+        skia_canvas.restore();
+
+        // The whole window was drawn
+        None
+    }
+
     fn render_to_canvas(
         &self,
         skia_canvas: &skia_safe::Canvas,
@@ -438,6 +620,17 @@ fn render_to_canvas(
 
         let window_inner = WindowInner::from_pub(window);
 
+        let scale_factor = ScaleFactor::new(window_inner.scale_factor());
+        let (simulation, simulation_item) = self.vision_simulation.borrow().clone();
+        let vision_simulation = vision_simulation_filter(simulation, scale_factor);
+        if vision_simulation.is_some() {
+            // The filter reads the neighbouring pixels (blur) and its area can move, so repaint
+            // everything rather than tracking that in the dirty region
+            if let Some(partial_rendering_state) = self.partial_rendering_state.as_ref() {
+                partial_rendering_state.force_screen_refresh();
+            }
+        }
+
         let dirty_region = window_inner
             .draw_contents(|components| {
                 self.render_components_to_canvas(
@@ -452,6 +645,23 @@ fn render_to_canvas(
             })
             .unwrap_or_default();
 
+        if let Some(filter) = &vision_simulation {
+            // Replace the rendered content with the filtered one, in the area of the item if set
+            skia_canvas.save();
+            if let Some(item) = simulation_item.as_ref().and_then(|item| item.upgrade()) {
+                let geometry = item.geometry();
+                let area = LogicalRect::new(item.map_to_window(geometry.origin), geometry.size);
+                skia_canvas.clip_rect(to_skia_rect(&(area * scale_factor)), None, None);
+            }
+            let mut paint = skia_safe::Paint::default();
+            paint.set_blend_mode(skia_safe::BlendMode::Src);
+            skia_canvas.save_layer(
+                &skia_safe::canvas::SaveLayerRec::default().backdrop(filter).paint(&paint),
+            );
+            skia_canvas.restore();
+            skia_canvas.restore();
+        }
+
         if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
             if let Some(surface) = surface {
                 surface.with_graphics_api(&mut |api| {
@@ -641,12 +851,43 @@ fn text_size(
             Default::default(),
             Default::default(),
             None,
+            &[],
         );
 
         PhysicalSize::new(layout.max_intrinsic_width().ceil(), layout.height().ceil())
             / scale_factor
     }
 
+    fn text_line_ranges(
+        &self,
+        font_request: i_slint_core::graphics::FontRequest,
+        text: &str,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        _text_wrap: TextWrap, //TODO: Add support for char-wrap
+    ) -> Result<Vec<core::ops::Range<usize>>, PlatformError> {
+        let (layout, _) = textlayout::create_layout(
+            font_request,
+            scale_factor,
+            text,
+            None,
+            max_width.map(|w| w * scale_factor),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            None,
+            &[],
+        );
+
+        Ok(layout
+            .get_line_metrics()
+            .iter()
+            .map(|metrics| metrics.start_index..metrics.end_excluding_whitespaces)
+            .collect())
+    }
+
     fn font_metrics(
         &self,
         font_request: i_slint_core::graphics::FontRequest,
@@ -655,6 +896,19 @@ fn font_metrics(
         textlayout::font_metrics(font_request, scale_factor)
     }
 
+    fn text_outline(
+        &self,
+        text: &str,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Result<i_slint_core::api::TextOutline, PlatformError> {
+        Ok(i_slint_core::api::TextOutline::new(textlayout::text_outline(
+            font_request,
+            scale_factor,
+            text,
+        )))
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: std::pin::Pin<&i_slint_core::items::TextInput>,
@@ -684,6 +938,7 @@ fn text_input_byte_offset_for_position(
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
             None,
+            &[],
         );
 
         let utf16_index =
@@ -703,13 +958,13 @@ fn text_input_byte_offset_for_position(
         visual_representation.map_byte_offset_from_byte_offset_in_visual_text(byte_offset)
     }
 
-    fn text_input_cursor_rect_for_byte_offset(
+    fn text_input_cursor_metrics_for_byte_offset(
         &self,
         text_input: std::pin::Pin<&i_slint_core::items::TextInput>,
         byte_offset: usize,
         font_request: FontRequest,
         scale_factor: ScaleFactor,
-    ) -> LogicalRect {
+    ) -> i_slint_core::items::TextCursorMetrics {
         let max_width = text_input.width() * scale_factor;
         let max_height = text_input.height() * scale_factor;
 
@@ -732,17 +987,27 @@ fn text_input_cursor_rect_for_byte_offset(
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
             None,
+            &[],
         );
 
         let physical_cursor_rect = textlayout::cursor_rect(
             string,
             byte_offset,
-            layout,
+            &layout,
             text_input.text_cursor_width() * scale_factor,
             text_input.horizontal_alignment(),
         );
 
-        physical_cursor_rect.translate(layout_top_left.to_vector()) / scale_factor
+        let (line_index, baseline, line_rect) =
+            textlayout::cursor_line(&layout, &physical_cursor_rect);
+
+        let offset = layout_top_left.to_vector();
+        i_slint_core::items::TextCursorMetrics {
+            rect: physical_cursor_rect.translate(offset) / scale_factor,
+            baseline: (baseline.get() + offset.y) / scale_factor.get(),
+            line_index,
+            line_rect: line_rect.translate(offset) / scale_factor,
+        }
     }
 
     fn register_font_from_memory(
@@ -759,6 +1024,26 @@ fn register_font_from_path(
         textlayout::register_font_from_path(path)
     }
 
+    fn unregister_font(&self, family: &str) -> Result<(), Box<dyn std::error::Error>> {
+        textlayout::unregister_font(family)
+    }
+
+    fn replace_font_from_memory(
+        &self,
+        family: &str,
+        data: &'static [u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        textlayout::replace_font_from_memory(family, data)
+    }
+
+    fn replace_font_from_path(
+        &self,
+        family: &str,
+        path: &std::path::Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        textlayout::replace_font_from_path(family, path)
+    }
+
     fn set_rendering_notifier(
         &self,
         callback: Box<dyn RenderingNotifier>,
@@ -783,6 +1068,42 @@ fn default_font_size(&self) -> LogicalLength {
         self::textlayout::DEFAULT_FONT_SIZE
     }
 
+    fn set_vision_simulation(
+        &self,
+        simulation: VisionSimulation,
+        item: Option<ItemWeak>,
+    ) -> Result<(), PlatformError> {
+        *self.vision_simulation.borrow_mut() = (simulation, item);
+        // The filter may have covered pixels outside of the dirty region, so everything needs to be repainted
+        if let Some(partial_rendering_state) = self.partial_rendering_state.as_ref() {
+            partial_rendering_state.force_screen_refresh();
+        }
+        self.window_adapter()?.request_redraw();
+        Ok(())
+    }
+
+    fn set_render_scale(&self, render_scale: f32) -> Result<(), PlatformError> {
+        if self.render_scale.replace(render_scale) != render_scale {
+            self.offscreen_surface.take();
+            if let Some(partial_rendering_state) = self.partial_rendering_state.as_ref() {
+                partial_rendering_state.force_screen_refresh();
+            }
+            self.window_adapter()?.request_redraw();
+        }
+        Ok(())
+    }
+
+    fn set_gamma_correct_blending(&self, enabled: bool) -> Result<(), PlatformError> {
+        if self.gamma_correct_blending.replace(enabled) != enabled {
+            self.offscreen_surface.take();
+            if let Some(partial_rendering_state) = self.partial_rendering_state.as_ref() {
+                partial_rendering_state.force_screen_refresh();
+            }
+            self.window_adapter()?.request_redraw();
+        }
+        Ok(())
+    }
+
     fn free_graphics_resources(
         &self,
         component: i_slint_core::item_tree::ItemTreeRef,
@@ -840,7 +1161,27 @@ fn take_snapshot(
         )
         .ok_or_else(|| format!("Error wrapping target buffer for rendering into with Skia"))?;
 
-        self.render_to_canvas(surface_borrow.canvas(), 0., (0.0, 0.0), None, 0, None, window, None);
+        if self.needs_offscreen_rendering() {
+            self.render_offscreen_to_canvas(
+                surface_borrow.canvas(),
+                &mut None,
+                None,
+                None,
+                window,
+                None,
+            );
+        } else {
+            self.render_to_canvas(
+                surface_borrow.canvas(),
+                0.,
+                (0.0, 0.0),
+                None,
+                0,
+                None,
+                window,
+                None,
+            );
+        }
 
         Ok(target_buffer)
     }
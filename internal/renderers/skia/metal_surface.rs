@@ -109,7 +109,7 @@ fn resize_event(
 
     fn render(
         &self,
-        _window: &Window,
+        window: &Window,
         _size: PhysicalWindowSize,
         callback: &dyn Fn(
             &skia_safe::Canvas,
@@ -121,6 +121,12 @@ fn render(
         autoreleasepool(|_| {
             // SAFETY: The pointer is a valid `CAMetalLayer`.
             let ca_layer: &CAMetalLayer = unsafe { self.layer.as_ptr().cast().as_ref() };
+            #[cfg(target_os = "macos")]
+            unsafe {
+                ca_layer.setDisplaySyncEnabled(window.vsync_enabled())
+            };
+            #[cfg(not(target_os = "macos"))]
+            let _ = window;
             let drawable = match unsafe { ca_layer.nextDrawable() } {
                 Some(drawable) => drawable,
                 None => {
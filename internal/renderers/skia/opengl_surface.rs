@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::num::NonZeroU32;
 use std::rc::Rc;
 
@@ -27,6 +27,8 @@ pub struct OpenGLSurface {
     gr_context: RefCell<skia_safe::gpu::DirectContext>,
     glutin_context: glutin::context::PossiblyCurrentContext,
     glutin_surface: glutin::surface::Surface<glutin::surface::WindowSurface>,
+    /// The vsync setting last applied to the surface.
+    vsync_enabled: Cell<bool>,
 }
 
 impl super::Surface for OpenGLSurface {
@@ -75,7 +77,7 @@ fn with_active_surface(&self, callback: &mut dyn FnMut()) -> Result<(), Platform
 
     fn render(
         &self,
-        _window: &Window,
+        window: &Window,
         size: PhysicalWindowSize,
         callback: &dyn Fn(
             &skia_safe::Canvas,
@@ -88,6 +90,12 @@ fn render(
 
         let current_context = &self.glutin_context;
 
+        let vsync = window.vsync_enabled();
+        if self.vsync_enabled.replace(vsync) != vsync {
+            // Ignore if the driver doesn't support changing the swap interval.
+            self.glutin_surface.set_swap_interval(current_context, swap_interval(vsync)).ok();
+        }
+
         let gr_context = &mut self.gr_context.borrow_mut();
 
         let mut surface = self.surface.borrow_mut();
@@ -243,6 +251,7 @@ pub fn new_with_config(
             gr_context: RefCell::new(gr_context),
             glutin_context: current_glutin_context,
             glutin_surface,
+            vsync_enabled: Cell::new(true),
         })
     }
 
@@ -403,12 +412,7 @@ fn init_glutin(
         }
 
         // Try to default to vsync and ignore if the driver doesn't support it.
-        surface
-            .set_swap_interval(
-                &context,
-                glutin::surface::SwapInterval::Wait(NonZeroU32::new(1).unwrap()),
-            )
-            .ok();
+        surface.set_swap_interval(&context, swap_interval(true)).ok();
 
         Ok((context, surface))
     }
@@ -467,3 +471,11 @@ fn drop(&mut self) {
         }
     }
 }
+
+fn swap_interval(vsync: bool) -> glutin::surface::SwapInterval {
+    if vsync {
+        glutin::surface::SwapInterval::Wait(NonZeroU32::new(1).unwrap())
+    } else {
+        glutin::surface::SwapInterval::DontWait
+    }
+}
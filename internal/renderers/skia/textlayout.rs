@@ -2,7 +2,6 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use std::cell::RefCell;
-use std::collections::HashMap;
 
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::FontRequest;
@@ -15,7 +14,7 @@
 
 pub const DEFAULT_FONT_SIZE: LogicalLength = LogicalLength::new(12.);
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone)]
 enum CustomFontSource {
     ByData(&'static [u8]),
     ByPath(std::path::PathBuf),
@@ -25,7 +24,8 @@ struct FontCache {
     font_collection: RefCell<skia_safe::textlayout::FontCollection>,
     font_mgr: skia_safe::FontMgr,
     type_face_font_provider: RefCell<skia_safe::textlayout::TypefaceFontProvider>,
-    custom_fonts: RefCell<HashMap<String, CustomFontSource>>,
+    // The family name, the source and the typeface of the registered fonts
+    custom_fonts: RefCell<Vec<(String, CustomFontSource, skia_safe::Typeface)>>,
 }
 
 thread_local! {
@@ -51,6 +51,8 @@ pub fn default_font(scale_factor: f32) -> Option<skia_safe::Font> {
     })
 }
 
+/// A range of the text that is drawn with a different style. Used for the selection and the
+/// composition of the input method. The ranges passed to [`create_layout`] must be sorted and must not overlap.
 pub struct Selection {
     pub range: std::ops::Range<usize>,
     pub background: Option<Color>,
@@ -81,7 +83,8 @@ pub fn create_layout(
     v_align: TextVerticalAlignment,
     wrap: items::TextWrap,
     overflow: items::TextOverflow,
-    selection: Option<&Selection>,
+    max_lines: Option<usize>,
+    selections: &[Selection],
 ) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
     let mut text_style = text_style.unwrap_or_default();
 
@@ -89,6 +92,15 @@ pub fn create_layout(
         text_style.set_font_families(&[family_name.as_str()]);
     }
 
+    // Skia takes each character from the first family of the list that has a glyph for it, so the
+    // families preferred for the scripts come after the family of the text.
+    let script_families = i_slint_core::graphics::all_script_font_families();
+    if !script_families.is_empty() {
+        let mut families = text_style.font_families().iter().map(String::from).collect::<Vec<_>>();
+        families.extend(script_families.iter().map(|family| family.to_string()));
+        text_style.set_font_families(&families);
+    }
+
     let pixel_size = font_request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE) * scale_factor;
 
     if let Some(letter_spacing) = font_request.letter_spacing {
@@ -96,16 +108,27 @@ pub fn create_layout(
     }
     text_style.set_font_size(pixel_size.get());
     text_style.set_font_style(font_style_for_request(&font_request));
+    for feature in &font_request.features {
+        text_style.add_font_feature(feature.tag_str(), feature.value as i32);
+    }
 
     let mut style = skia_safe::textlayout::ParagraphStyle::new();
-
-    if overflow == items::TextOverflow::Elide {
+    // Embolden or skew the glyphs when the matched typeface lacks the requested weight or slant
+    style.set_fake_bold(true);
+    style.set_fake_italic(true);
+
+    let mut line_limit = max_lines;
+    if overflow == items::TextOverflow::Elide && wrap != items::TextWrap::NoWrap {
+        let metrics = text_style.font_metrics();
+        let line_height = metrics.descent - metrics.ascent + metrics.leading;
+        let lines_in_height = (max_height.get() / line_height).floor() as usize;
+        line_limit = Some(line_limit.map_or(lines_in_height, |l| l.min(lines_in_height)));
+    }
+    if overflow == items::TextOverflow::Elide || max_lines.is_some() {
         style.set_ellipsis("…");
-        if wrap != items::TextWrap::NoWrap {
-            let metrics = text_style.font_metrics();
-            let line_height = metrics.descent - metrics.ascent + metrics.leading;
-            style.set_max_lines((max_height.get() / line_height).floor() as usize);
-        }
+    }
+    if let Some(line_limit) = line_limit {
+        style.set_max_lines(line_limit);
     }
 
     style.set_text_align(match h_align {
@@ -123,9 +146,9 @@ pub fn create_layout(
         )
     });
 
-    if let Some(selection) = selection {
-        let before_selection = &text[..selection.range.start];
-        builder.add_text(before_selection);
+    let mut text_pos = 0;
+    for selection in selections {
+        builder.add_text(&text[text_pos..selection.range.start]);
 
         let mut selection_style = text_style.clone();
 
@@ -149,15 +172,11 @@ pub fn create_layout(
         }
 
         builder.push_style(&selection_style);
-        let selected_text = &text[selection.range.clone()];
-        builder.add_text(selected_text);
+        builder.add_text(&text[selection.range.clone()]);
         builder.pop();
-
-        let after_selection = &text[selection.range.end..];
-        builder.add_text(after_selection);
-    } else {
-        builder.add_text(text);
+        text_pos = selection.range.end;
     }
+    builder.add_text(&text[text_pos..]);
 
     let mut paragraph = builder.build();
     paragraph.layout(max_width.map_or(f32::MAX, |physical_width| physical_width.get()));
@@ -189,6 +208,7 @@ pub fn font_metrics(
         Default::default(),
         Default::default(),
         None,
+        &[],
     );
 
     let fonts = layout.get_fonts();
@@ -207,53 +227,224 @@ pub fn font_metrics(
     }
 }
 
-fn register_font(source: CustomFontSource) -> Result<(), Box<dyn std::error::Error>> {
-    FONT_CACHE.with(|font_cache| {
-        if font_cache
-            .custom_fonts
-            .borrow()
-            .values()
-            .position(|registered_font| *registered_font == source)
-            .is_some()
-        {
-            return Ok(());
+/// Returns the outline of the glyphs of `text` as laid out by the paragraph builder, in logical
+/// coordinates relative to the top-left corner of the text.
+pub fn text_outline(
+    font_request: FontRequest,
+    scale_factor: ScaleFactor,
+    text: &str,
+) -> Vec<i_slint_core::api::PathCommand> {
+    use i_slint_core::api::{LogicalPosition, PathCommand};
+
+    let (mut layout, _) = create_layout(
+        font_request,
+        scale_factor,
+        text,
+        None,
+        None,
+        PhysicalLength::new(f32::MAX),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        None,
+        &[],
+    );
+
+    let to_logical = |offset: skia_safe::Point, point: skia_safe::Point| {
+        LogicalPosition::new(
+            (offset.x + point.x) / scale_factor.get(),
+            (offset.y + point.y) / scale_factor.get(),
+        )
+    };
+
+    let mut commands = Vec::new();
+    layout.visit(|_, info| {
+        let Some(info) = info else { return };
+        for (glyph, position) in info.glyphs().iter().zip(info.positions()) {
+            let Some(path) = info.font().get_path(*glyph) else { continue };
+            let offset = info.origin() + *position;
+            for (verb, points) in skia_safe::path::Iter::new(&path, false) {
+                let point = |index: usize| to_logical(offset, points[index]);
+                commands.push(match verb {
+                    skia_safe::path::Verb::Move => PathCommand::MoveTo(point(0)),
+                    skia_safe::path::Verb::Line => PathCommand::LineTo(point(1)),
+                    // Font outlines are made of quadratic or cubic curves, never of conics
+                    skia_safe::path::Verb::Quad | skia_safe::path::Verb::Conic => {
+                        PathCommand::QuadraticTo { control: point(1), to: point(2) }
+                    }
+                    skia_safe::path::Verb::Cubic => PathCommand::CubicTo {
+                        control1: point(1),
+                        control2: point(2),
+                        to: point(3),
+                    },
+                    skia_safe::path::Verb::Close => PathCommand::Close,
+                    skia_safe::path::Verb::Done => break,
+                });
+            }
         }
+    });
+    commands
+}
 
-        let data: std::borrow::Cow<[u8]> = match &source {
-            CustomFontSource::ByData(data) => std::borrow::Cow::Borrowed(data),
-            CustomFontSource::ByPath(path) => std::borrow::Cow::Owned(std::fs::read(path)?),
-        };
+fn load_type_face(
+    font_mgr: &skia_safe::FontMgr,
+    source: &CustomFontSource,
+) -> Result<skia_safe::Typeface, Box<dyn std::error::Error>> {
+    let data: std::borrow::Cow<[u8]> = match source {
+        CustomFontSource::ByData(data) => std::borrow::Cow::Borrowed(data),
+        CustomFontSource::ByPath(path) => std::borrow::Cow::Owned(std::fs::read(path)?),
+    };
 
-        let type_face =
-            font_cache.font_mgr.new_from_data(data.as_ref(), None).ok_or_else(|| {
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "error parsing TrueType font".to_string(),
-                )
-            })?;
+    font_mgr.new_from_data(data.as_ref(), None).ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::Other, "error parsing TrueType font".to_string())
+            .into()
+    })
+}
+
+/// Loads and registers the font of `source`, unless it's already registered.
+fn add_custom_font(
+    font_cache: &FontCache,
+    source: &CustomFontSource,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if font_cache
+        .custom_fonts
+        .borrow()
+        .iter()
+        .any(|(_, registered_font, _)| registered_font == source)
+    {
+        return Ok(());
+    }
 
-        drop(data);
+    let type_face = load_type_face(&font_cache.font_mgr, source)?;
 
-        let family_name = type_face.family_name();
+    let family_name = type_face.family_name();
+    let no_alias: Option<&str> = None;
+    font_cache.type_face_font_provider.borrow_mut().register_typeface(type_face.clone(), no_alias);
+    font_cache.custom_fonts.borrow_mut().push((family_name, source.clone(), type_face));
+    Ok(())
+}
+
+/// Removes the registered fonts of `family`, except the one of `keep`, and returns whether any
+/// font was removed.
+fn remove_custom_fonts(
+    font_cache: &FontCache,
+    family: &str,
+    keep: Option<&CustomFontSource>,
+) -> bool {
+    let mut custom_fonts = font_cache.custom_fonts.borrow_mut();
+    let count = custom_fonts.len();
+    custom_fonts.retain(|(family_name, source, _)| {
+        !family_name.eq_ignore_ascii_case(family) || Some(source) == keep
+    });
+    if custom_fonts.len() == count {
+        return false;
+    }
+
+    // Typefaces can't be removed from a TypefaceFontProvider, so register the remaining
+    // ones with a new provider. They are kept loaded, so that this can't fail half-way.
+    let mut type_face_font_provider = skia_safe::textlayout::TypefaceFontProvider::new();
+    for (_, _, type_face) in custom_fonts.iter() {
         let no_alias: Option<&str> = None;
-        font_cache.type_face_font_provider.borrow_mut().register_typeface(type_face, no_alias);
-        font_cache.custom_fonts.borrow_mut().insert(family_name, source);
+        type_face_font_provider.register_typeface(type_face.clone(), no_alias);
+    }
+
+    let mut font_collection = font_cache.font_collection.borrow_mut();
+    font_collection.set_asset_font_manager(Some(type_face_font_provider.clone().into()));
+    font_collection.clear_caches();
+    *font_cache.type_face_font_provider.borrow_mut() = type_face_font_provider;
+    true
+}
+
+pub fn unregister_font(family: &str) -> Result<(), Box<dyn std::error::Error>> {
+    FONT_CACHE.with(|font_cache| {
+        if !remove_custom_fonts(font_cache, family, None) {
+            return Err(format!("No font of the family {family} was registered").into());
+        }
+        Ok(())
+    })
+}
+
+/// Registers the font of `source`, and then removes the other fonts registered for `family`.
+/// If the font can't be loaded, the fonts of `family` are kept.
+fn replace_font(family: &str, source: CustomFontSource) -> Result<(), Box<dyn std::error::Error>> {
+    FONT_CACHE.with(|font_cache| {
+        add_custom_font(font_cache, &source)?;
+        remove_custom_fonts(font_cache, family, Some(&source));
         Ok(())
     })
 }
 
 pub fn register_font_from_memory(data: &'static [u8]) -> Result<(), Box<dyn std::error::Error>> {
-    register_font(CustomFontSource::ByData(data))
+    FONT_CACHE.with(|font_cache| add_custom_font(font_cache, &CustomFontSource::ByData(data)))
 }
 
 pub fn register_font_from_path(path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
-    register_font(CustomFontSource::ByPath(path.into()))
+    FONT_CACHE
+        .with(|font_cache| add_custom_font(font_cache, &CustomFontSource::ByPath(path.into())))
+}
+
+pub fn replace_font_from_memory(
+    family: &str,
+    data: &'static [u8],
+) -> Result<(), Box<dyn std::error::Error>> {
+    replace_font(family, CustomFontSource::ByData(data))
+}
+
+pub fn replace_font_from_path(
+    family: &str,
+    path: &std::path::Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    replace_font(family, CustomFontSource::ByPath(path.into()))
+}
+
+/// Returns the path of the line to draw below the given byte range of the text, as added with `add-underline()`.
+pub fn underline_path(
+    string: &str,
+    range: std::ops::Range<usize>,
+    layout: &skia_safe::textlayout::Paragraph,
+    style: items::TextUnderlineStyle,
+) -> (skia_safe::Path, f32) {
+    let utf16_start: usize = string[..range.start].chars().map(char::len_utf16).sum();
+    let utf16_len: usize = string[range].chars().map(char::len_utf16).sum();
+    let boxes = layout.get_rects_for_range(
+        utf16_start..utf16_start + utf16_len,
+        skia_safe::textlayout::RectHeightStyle::Tight,
+        skia_safe::textlayout::RectWidthStyle::Tight,
+    );
+
+    let mut path = skia_safe::Path::new();
+    let mut thickness: f32 = 1.;
+    for text_box in boxes {
+        let rect = text_box.rect;
+        thickness = thickness.max(rect.height() / 16.);
+        let y = rect.bottom() + thickness;
+        path.move_to((rect.left(), y));
+        match style {
+            items::TextUnderlineStyle::Solid => {
+                path.line_to((rect.right(), y));
+            }
+            items::TextUnderlineStyle::Wavy => {
+                let half_wave = thickness * 2.;
+                let mut x = rect.left();
+                let mut up = true;
+                while x < rect.right() {
+                    let next_x = (x + half_wave).min(rect.right());
+                    let ctrl_y = if up { y - thickness * 1.5 } else { y + thickness * 1.5 };
+                    path.quad_to(((x + next_x) / 2., ctrl_y), (next_x, y));
+                    x = next_x;
+                    up = !up;
+                }
+            }
+        }
+    }
+    (path, thickness)
 }
 
 pub fn cursor_rect(
     string: &str,
     cursor_pos: usize,
-    layout: skia_safe::textlayout::Paragraph,
+    layout: &skia_safe::textlayout::Paragraph,
     cursor_width: PhysicalLength,
     h_align: TextHorizontalAlignment,
 ) -> PhysicalRect {
@@ -307,22 +498,10 @@ pub fn cursor_rect(
         |rect: &skia_safe::Rect| rect.left
     };
 
-    let mut grapheme_cursor =
-        unicode_segmentation::GraphemeCursor::new(cursor_pos, string.len(), true);
     let adjacent_grapheme_byte_range = if cursor_pos == string.len() {
-        let prev_grapheme = match grapheme_cursor.prev_boundary(string, 0) {
-            Ok(byte_offset) => byte_offset.unwrap_or(0),
-            Err(_) => return Default::default(),
-        };
-
-        prev_grapheme..cursor_pos
+        i_slint_core::textlayout::prev_grapheme_boundary(string, cursor_pos)..cursor_pos
     } else {
-        let next_grapheme = match grapheme_cursor.next_boundary(string, 0) {
-            Ok(byte_offset) => byte_offset.unwrap_or_else(|| string.len()),
-            Err(_) => return Default::default(),
-        };
-
-        cursor_pos..next_grapheme
+        cursor_pos..i_slint_core::textlayout::next_grapheme_boundary(string, cursor_pos)
     };
 
     let adjacent_grapheme_utf16_start =
@@ -350,3 +529,31 @@ pub fn cursor_rect(
         })
         .unwrap_or_default()
 }
+
+/// Returns the index, the baseline, and the box of the line that contains the cursor rectangle
+/// returned by [`cursor_rect`].
+pub fn cursor_line(
+    layout: &skia_safe::textlayout::Paragraph,
+    cursor_rect: &PhysicalRect,
+) -> (usize, PhysicalLength, PhysicalRect) {
+    let cursor_y = cursor_rect.center().y;
+    let line_metrics = layout.line_metrics();
+    line_metrics
+        .iter()
+        .find(|metrics| cursor_y < (metrics.baseline + metrics.descent) as f32)
+        .or(line_metrics.last())
+        .map(|metrics| {
+            (
+                metrics.line_number,
+                PhysicalLength::new(metrics.baseline as f32),
+                PhysicalRect::new(
+                    PhysicalPoint::new(
+                        metrics.left as f32,
+                        (metrics.baseline - metrics.ascent) as f32,
+                    ),
+                    PhysicalSize::new(metrics.width as f32, metrics.height as f32),
+                ),
+            )
+        })
+        .unwrap_or_else(|| (0, PhysicalLength::new(cursor_rect.max_y()), *cursor_rect))
+}
@@ -487,6 +487,7 @@ pub enum LspToPreviewMessage {
     SetConfiguration { config: PreviewConfig },
     ShowPreview(PreviewComponent),
     HighlightFromEditor { url: Option<Url>, offset: u32 },
+    SetBreakpoints { breakpoints: Vec<(Url, u32)> },
 }
 
 impl lsp_types::notification::Notification for LspToPreviewMessage {
@@ -13,8 +13,16 @@
 #[cfg(feature = "preview-engine")]
 fn builtin_component_info(name: &str) -> ComponentInformation {
     let is_layout = matches!(name, "GridLayout" | "HorizontalLayout" | "VerticalLayout");
-    let is_interactive =
-        matches!(name, "Flickable" | "FocusScope" | "SwipeGestureHandler" | "TouchArea");
+    let is_interactive = matches!(
+        name,
+        "DropArea"
+            | "Flickable"
+            | "FocusScope"
+            | "PinchGestureHandler"
+            | "SwipeGestureHandler"
+            | "TouchArea"
+            | "WindowFrameArea"
+    );
 
     let default_properties = match name {
         "Text" | "TextInput" => vec![PropertyChange::new("text", format!("\"{name}\""))],
@@ -23,13 +31,15 @@ fn builtin_component_info(name: &str) -> ComponentInformation {
     };
 
     let component = {
-        if ["Flickable", "SwipeGestureHandler", "TouchArea"].contains(&name) {
+        if ["DropArea", "Flickable", "PinchGestureHandler", "SwipeGestureHandler", "TouchArea"]
+            .contains(&name)
+        {
             "Gestures"
         } else if ["FocusScope", "TextInput"].contains(&name) {
             "Keyboard Input"
         } else if name.ends_with("Layout") {
             "Basic Layouts"
-        } else if ["Dialog", "PopupWindow", "Window"].contains(&name) {
+        } else if ["Dialog", "PopupWindow", "Window", "WindowFrameArea"].contains(&name) {
             "Window"
         } else {
             "Basic Elements"
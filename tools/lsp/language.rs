@@ -46,12 +46,15 @@
 
 const POPULATE_COMMAND: &str = "slint/populate";
 pub const SHOW_PREVIEW_COMMAND: &str = "slint/showPreview";
+const TOGGLE_BREAKPOINT_COMMAND: &str = "slint/toggleBreakpoint";
 
 fn command_list() -> Vec<String> {
     vec![
         POPULATE_COMMAND.into(),
         #[cfg(any(feature = "preview-builtin", feature = "preview-external"))]
         SHOW_PREVIEW_COMMAND.into(),
+        #[cfg(any(feature = "preview-builtin", feature = "preview-external"))]
+        TOGGLE_BREAKPOINT_COMMAND.into(),
     ]
 }
 
@@ -68,6 +71,15 @@ fn create_show_preview_command(
     )
 }
 
+#[cfg(any(feature = "preview-builtin", feature = "preview-external"))]
+fn create_toggle_breakpoint_command(file: &lsp_types::Url, offset: u32) -> Command {
+    Command::new(
+        "Toggle Breakpoint".into(),
+        TOGGLE_BREAKPOINT_COMMAND.into(),
+        Some(vec![file.as_str().into(), offset.into()]),
+    )
+}
+
 fn create_populate_command(
     uri: lsp_types::Url,
     version: common::SourceFileVersion,
@@ -103,6 +115,11 @@ pub fn request_state(ctx: &std::rc::Rc<Context>) {
     if let Some(c) = ctx.to_show.borrow().clone() {
         ctx.server_notifier.send_message_to_preview(common::LspToPreviewMessage::ShowPreview(c))
     }
+    let breakpoints = ctx.breakpoints.borrow().clone();
+    if !breakpoints.is_empty() {
+        ctx.server_notifier
+            .send_message_to_preview(common::LspToPreviewMessage::SetBreakpoints { breakpoints });
+    }
 }
 
 async fn register_file_watcher(ctx: &Context) -> common::Result<()> {
@@ -147,6 +164,9 @@ pub struct Context {
     /// The last component for which the user clicked "show preview"
     #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
     pub to_show: RefCell<Option<common::PreviewComponent>>,
+    /// The bindings with a breakpoint, as url and offset of the binding expression
+    #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
+    pub breakpoints: RefCell<Vec<(lsp_types::Url, u32)>>,
     /// File currently open in the editor
     pub open_urls: RefCell<HashSet<lsp_types::Url>>,
 }
@@ -366,6 +386,11 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
             show_preview_command(&params.arguments, &ctx)?;
             return Ok(None::<serde_json::Value>);
         }
+        if params.command.as_str() == TOGGLE_BREAKPOINT_COMMAND {
+            #[cfg(any(feature = "preview-builtin", feature = "preview-external"))]
+            toggle_breakpoint_command(&params.arguments, &ctx)?;
+            return Ok(None::<serde_json::Value>);
+        }
         if params.command.as_str() == POPULATE_COMMAND {
             populate_command(&params.arguments, &ctx).await?;
             return Ok(None::<serde_json::Value>);
@@ -602,6 +627,50 @@ pub fn show_preview_command(
     Ok(())
 }
 
+#[cfg(any(feature = "preview-builtin", feature = "preview-external"))]
+pub fn toggle_breakpoint_command(
+    params: &[serde_json::Value],
+    ctx: &Rc<Context>,
+) -> Result<(), LspError> {
+    let url: Url = extract_param(params, 0, "url")?;
+    let offset: u32 = extract_param(params, 1, "offset")?;
+
+    // Normalize the URL the same way as for the preview
+    let url =
+        common::uri_to_file(&url).and_then(|u| Url::from_file_path(u).ok()).ok_or_else(|| {
+            LspError {
+                code: LspErrorCode::InvalidParameter,
+                message: "invalid document url".into(),
+            }
+        })?;
+
+    let breakpoints = {
+        let mut breakpoints = ctx.breakpoints.borrow_mut();
+        if let Some(index) = breakpoints.iter().position(|(u, o)| *u == url && *o == offset) {
+            breakpoints.remove(index);
+        } else {
+            breakpoints.push((url, offset));
+        }
+        breakpoints.clone()
+    };
+    ctx.server_notifier
+        .send_message_to_preview(common::LspToPreviewMessage::SetBreakpoints { breakpoints });
+
+    Ok(())
+}
+
+/// Returns the offset identifying a breakpoint on the binding or callback containing `node`
+#[cfg(any(feature = "preview-builtin", feature = "preview-external"))]
+fn breakpoint_offset(node: &SyntaxNode) -> Option<u32> {
+    std::iter::successors(Some(node.clone()), |n| n.parent()).find_map(|n| match n.kind() {
+        SyntaxKind::Binding => {
+            Some(n.child_node(SyntaxKind::BindingExpression)?.text_range().start().into())
+        }
+        SyntaxKind::CallbackConnection => Some(n.text_range().start().into()),
+        _ => None,
+    })
+}
+
 fn populate_command_range(node: &SyntaxNode) -> Option<lsp_types::Range> {
     let range = node.text_range();
 
@@ -958,6 +1027,10 @@ fn get_code_actions(
                 )))
             }
         }
+        if let Some(offset) = breakpoint_offset(&node) {
+            result
+                .push(CodeActionOrCommand::Command(create_toggle_breakpoint_command(&uri, offset)));
+        }
     }
 
     if token.kind() == SyntaxKind::StringLiteral && node.kind() == SyntaxKind::Expression {
@@ -208,6 +208,7 @@ pub(crate) fn completion_at(
                     ("image-url", "image-url(\"$1\")"),
                     ("linear-gradient", "linear-gradient($1)"),
                     ("radial-gradient", "radial-gradient(circle, $1)"),
+                    ("conic-gradient", "conic-gradient($1)"),
                 ]
                 .into_iter()
                 .map(|(label, insert)| {
@@ -158,6 +158,8 @@ fn accurate_diagnostics_in_dependencies() {
         init_param: Default::default(),
         #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
         to_show: Default::default(),
+        #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
+        breakpoints: Default::default(),
         open_urls: RefCell::new(HashSet::from_iter([foo_url.clone(), bar_url.clone()])),
     }));
 
@@ -366,6 +366,8 @@ fn main_loop(connection: Connection, init_param: InitializeParams, cli_args: Cli
         init_param,
         #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
         to_show: Default::default(),
+        #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
+        breakpoints: Default::default(),
         open_urls: Default::default(),
     });
 
@@ -114,6 +114,16 @@ struct PreviewState {
     preview_loading_delay_timer: Option<slint::Timer>,
 }
 thread_local! {static PREVIEW_STATE: std::cell::RefCell<PreviewState> = Default::default();}
+thread_local! {
+    /// The vision simulation chosen in the header, and the item showing the previewed component
+    /// to whose area it's restricted, so that the preview UI itself isn't filtered.
+    static VISION_SIMULATION: RefCell<(
+        i_slint_core::graphics::VisionSimulation,
+        Option<i_slint_core::item_tree::ItemWeak>,
+    )> = Default::default();
+    /// The recorder of the previewed component's properties, while recording a timeline
+    static TIMELINE_RECORDER: RefCell<Option<slint_interpreter::TimelineRecorder>> = Default::default();
+}
 
 pub fn poll_once<F: std::future::Future>(future: F) -> Option<F::Output> {
     struct DummyWaker();
@@ -936,6 +946,107 @@ fn change_style() {
     }
 }
 
+fn change_vision_simulation(index: i32) {
+    use i_slint_core::graphics::VisionSimulation;
+
+    let simulation = match index {
+        1 => VisionSimulation::Protanopia,
+        2 => VisionSimulation::Deuteranopia,
+        3 => VisionSimulation::Tritanopia,
+        4 => VisionSimulation::Blur,
+        _ => VisionSimulation::None,
+    };
+
+    VISION_SIMULATION.with(|current| current.borrow_mut().0 = simulation);
+    let result = PREVIEW_STATE.with(|preview_state| {
+        let preview_state = preview_state.borrow();
+        let Some(ui) = &preview_state.ui else {
+            return Ok(());
+        };
+        apply_vision_simulation(ui.window())
+    });
+    if let Err(err) = result {
+        set_status_text(&err.to_string());
+    }
+}
+
+fn apply_vision_simulation(window: &slint::Window) -> Result<(), PlatformError> {
+    use i_slint_core::renderer::RendererSealed;
+    let (simulation, area) = VISION_SIMULATION.with(|current| current.borrow().clone());
+    let window_inner = i_slint_core::window::WindowInner::from_pub(window);
+    window_inner.window_adapter().renderer().set_vision_simulation(simulation, area)
+}
+
+/// Starts recording the properties of the previewed component, or stops and exports the recording
+/// to a `.timeline.json` file next to the previewed `.slint` file.
+fn toggle_timeline_recording(recording: bool) {
+    if recording {
+        let Some(instance) = component_instance() else {
+            set_status_text("Cannot record a timeline: nothing is previewed");
+            return;
+        };
+        let recorder = slint_interpreter::TimelineRecorder::new(&instance);
+        TIMELINE_RECORDER.with(|current| *current.borrow_mut() = Some(recorder));
+        set_status_text("Recording the timeline of the previewed component");
+        return;
+    }
+
+    let Some(recorder) = TIMELINE_RECORDER.with(|current| current.borrow_mut().take()) else {
+        return;
+    };
+    let cache = CONTENT_CACHE.get_or_init(Default::default).lock().unwrap();
+    let Some(current) = cache.current_component() else {
+        return;
+    };
+    drop(cache);
+
+    let mut url = current.url;
+    let path = url.path();
+    let path = format!("{}.timeline.json", path.strip_suffix(".slint").unwrap_or(path));
+    url.set_path(&path);
+
+    let create = lsp_types::ResourceOp::Create(lsp_types::CreateFile {
+        uri: url.clone(),
+        options: Some(lsp_types::CreateFileOptions {
+            overwrite: Some(true),
+            ignore_if_exists: None,
+        }),
+        annotation_id: None,
+    });
+    let text_edit = lsp_types::TextEdit {
+        range: lsp_types::Range::default(),
+        new_text: recorder.timeline().to_json(),
+    };
+    let edit = lsp_types::WorkspaceEdit {
+        document_changes: Some(lsp_types::DocumentChanges::Operations(vec![
+            lsp_types::DocumentChangeOperation::Op(create),
+            lsp_types::DocumentChangeOperation::Edit(common::create_text_document_edit(
+                url,
+                None,
+                vec![text_edit],
+            )),
+        ])),
+        ..Default::default()
+    };
+    // Not going through `send_workspace_edit()`: writing the timeline doesn't change the
+    // previewed component, so there is no reload to wait for before the next edit.
+    send_message_to_lsp(PreviewToLspMessage::SendWorkspaceEdit {
+        label: Some("Export timeline".to_string()),
+        edit,
+    });
+    set_status_text(&format!("Exported the timeline to {path}"));
+}
+
+fn change_pseudo_localization(index: i32) {
+    use i_slint_core::translations::PseudoLocalization;
+
+    i_slint_core::translations::set_pseudo_localization(match index {
+        1 => PseudoLocalization::Accented,
+        2 => PseudoLocalization::RightToLeft,
+        _ => PseudoLocalization::None,
+    });
+}
+
 fn start_parsing() {
     set_status_text("Updating Preview...");
     PREVIEW_STATE.with(|preview_state| {
@@ -1408,8 +1519,26 @@ fn set_preview_factory(
     i_slint_core::window::WindowInner::from_pub(ui.window()).close_all_popups();
 
     let factory = slint::ComponentFactory::new(move |ctx: FactoryContext| {
+        // The factory context points to the dynamic node of the ComponentContainer, whose parent
+        // is the item covering the previewed component.
+        let area = ctx.parent_item_tree.upgrade().map(|item_tree| {
+            let parent_index = i_slint_core::item_tree::ItemTreeRc::borrow_pin(&item_tree)
+                .as_ref()
+                .get_item_tree()[ctx.parent_item_tree_index as usize]
+                .parent_index();
+            i_slint_core::item_tree::ItemRc::new(item_tree, parent_index).downgrade()
+        });
         let instance = compiled.create_embedded(ctx).unwrap();
 
+        let simulation = VISION_SIMULATION.with(|current| {
+            let mut current = current.borrow_mut();
+            current.1 = area;
+            current.0
+        });
+        if simulation != i_slint_core::graphics::VisionSimulation::None {
+            apply_vision_simulation(instance.window()).ok();
+        }
+
         callback(instance.clone_strong());
 
         Some(instance)
@@ -1452,6 +1581,54 @@ pub fn highlight(url: Option<Url>, offset: TextSize) {
     }
 }
 
+/// Sets the breakpoints in the interpreter and reloads the preview so that they apply.
+///
+/// When a binding with a breakpoint is evaluated, the preview pauses: the values the binding was
+/// evaluated with are shown over the preview, which doesn't take input until the user continues,
+/// and the editor shows the binding in its status.
+fn set_breakpoints(breakpoints: Vec<(Url, u32)>) {
+    let locations = breakpoints
+        .into_iter()
+        .filter_map(|(url, offset)| Some((Url::to_file_path(&url).ok()?, offset)))
+        .collect::<Vec<_>>();
+    let _ = run_in_ui_thread(move || async move {
+        slint_interpreter::breakpoints::set_breakpoints(locations, |hit| {
+            let values = hit
+                .values
+                .iter()
+                .map(|(name, value)| format!("\n{name} = {value:?}"))
+                .collect::<String>();
+            let text = format!("{} = {:?}{values}", hit.binding, hit.result);
+            send_status(&format!("Paused at the breakpoint on {}", hit.binding), Health::Warning);
+            // The handler runs while evaluating a binding, so don't change properties from here
+            let _ = i_slint_core::api::invoke_from_event_loop(move || {
+                PREVIEW_STATE.with(|preview_state| {
+                    if let Some(ui) = &preview_state.borrow().ui {
+                        ui.global::<ui::Api>().set_breakpoint_text(text.into());
+                    }
+                })
+            });
+        });
+        set_breakpoint_text("");
+        reload_preview();
+    });
+}
+
+/// Resumes after a breakpoint was hit.
+fn continue_from_breakpoint() {
+    slint_interpreter::breakpoints::resume();
+    set_breakpoint_text("");
+    send_status("", Health::Ok);
+}
+
+fn set_breakpoint_text(text: &str) {
+    PREVIEW_STATE.with(|preview_state| {
+        if let Some(ui) = &preview_state.borrow().ui {
+            ui.global::<ui::Api>().set_breakpoint_text(text.into());
+        }
+    })
+}
+
 pub fn get_component_info(component_type: &str) -> Option<ComponentInformation> {
     PREVIEW_STATE.with(|preview_state| {
         let preview_state = preview_state.borrow();
@@ -1847,6 +2024,9 @@ pub fn lsp_to_preview_message(message: crate::common::LspToPreviewMessage) {
         M::HighlightFromEditor { url, offset } => {
             highlight(url, offset.into());
         }
+        M::SetBreakpoints { breakpoints } => {
+            set_breakpoints(breakpoints);
+        }
     }
 }
 
@@ -59,6 +59,9 @@ pub fn create_ui(style: String, experimental: bool) -> Result<PreviewUi, Platfor
     api.on_add_new_component(super::add_new_component);
     api.on_rename_component(super::rename_component);
     api.on_style_changed(super::change_style);
+    api.on_vision_simulation_changed(super::change_vision_simulation);
+    api.on_timeline_recording_toggled(super::toggle_timeline_recording);
+    api.on_pseudo_localization_changed(super::change_pseudo_localization);
     api.on_show_component(super::show_component);
     api.on_show_document(|file, line, column| {
         use lsp_types::{Position, Range};
@@ -68,6 +71,7 @@ pub fn create_ui(style: String, experimental: bool) -> Result<PreviewUi, Platfor
     api.on_show_document_offset_range(super::show_document_offset_range);
     api.on_show_preview_for(super::show_preview_for);
     api.on_reload_preview(super::reload_preview);
+    api.on_continue_from_breakpoint(super::continue_from_breakpoint);
     api.on_unselect(super::element_selection::unselect_element);
     api.on_reselect(super::element_selection::reselect_element);
     api.on_select_at(super::element_selection::select_element_at);
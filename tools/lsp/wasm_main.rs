@@ -222,6 +222,7 @@ pub fn create(
             init_param,
             server_notifier,
             to_show: Default::default(),
+            breakpoints: Default::default(),
             open_urls: Default::default(),
         }),
         reentry_guard,
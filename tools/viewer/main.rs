@@ -37,6 +37,8 @@ fn from(value: T) -> Self {
 
 type Result<T> = std::result::Result<T, Error>;
 
+mod scene;
+
 #[derive(Clone, clap::Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
@@ -69,6 +71,10 @@ struct Cli {
     #[arg(long, action)]
     auto_reload: bool,
 
+    /// Show a scene saved with `Window::serialize_scene()`, passed as path, instead of a .slint file
+    #[arg(long, action)]
+    scene: bool,
+
     /// Load properties from a json file ('-' for stdin)
     #[arg(long, value_name = "json file", action)]
     load_data: Option<std::path::PathBuf>,
@@ -77,6 +83,10 @@ struct Cli {
     #[arg(long, value_name = "json file", action)]
     save_data: Option<std::path::PathBuf>,
 
+    /// Play the keyframed property animations of a timeline json file
+    #[arg(long, value_name = "json file", action)]
+    timeline: Option<std::path::PathBuf>,
+
     /// Specify callbacks handler.
     /// The first argument is the callback name, and the second argument is a string that is going
     /// to be passed to the shell to be executed. Occurrences of `$1` will be replaced by the first argument,
@@ -123,6 +133,7 @@ fn to_json(val: slint_interpreter::Value) -> Option<serde_json::Value> {
 }
 
 thread_local! {static CURRENT_INSTANCE: std::cell::RefCell<Option<ComponentInstance>> = Default::default();}
+thread_local! {static CURRENT_TIMELINE: std::cell::RefCell<Option<slint_interpreter::TimelinePlayer>> = Default::default();}
 static EXIT_CODE: std::sync::atomic::AtomicI32 = std::sync::atomic::AtomicI32::new(0);
 
 fn main() -> Result<()> {
@@ -148,7 +159,7 @@ fn main() -> Result<()> {
 
     let fswatcher = if args.auto_reload { Some(start_fswatch_thread(args.clone())?) } else { None };
     let compiler = init_compiler(&args, fswatcher);
-    let r = spin_on::spin_on(compiler.build_from_path(&args.path));
+    let r = spin_on::spin_on(build(&compiler, &args))?;
     r.print_diagnostics();
     if r.has_errors() {
         std::process::exit(-1);
@@ -171,6 +182,9 @@ fn main() -> Result<()> {
     if let Some(data_path) = args.load_data {
         load_data(&c, &component, &data_path)?;
     }
+    if let Some(timeline_path) = &args.timeline {
+        play_timeline(&component, timeline_path)?;
+    }
     install_callbacks(&component, &args.on);
 
     if args.auto_reload {
@@ -220,6 +234,9 @@ fn init_compiler(
         if let Some(data_path) = &args.load_data {
             watch_with_retry(data_path, &watcher);
         }
+        if let Some(timeline_path) = &args.timeline {
+            watch_with_retry(timeline_path, &watcher);
+        }
         compiler.set_file_loader(move |path| {
             watch_with_retry(&path.into(), &watcher);
             Box::pin(async { None })
@@ -235,6 +252,19 @@ fn init_compiler(
     compiler
 }
 
+async fn build(
+    compiler: &slint_interpreter::Compiler,
+    args: &Cli,
+) -> Result<slint_interpreter::CompilationResult> {
+    if args.scene {
+        let scene = std::fs::read_to_string(&args.path)?;
+        let source = scene::scene_to_slint(&scene)?;
+        Ok(compiler.build_from_source(source, args.path.clone()).await)
+    } else {
+        Ok(compiler.build_from_path(&args.path).await)
+    }
+}
+
 fn watch_with_retry(path: &PathBuf, watcher: &Arc<Mutex<notify::RecommendedWatcher>>) {
     notify::Watcher::watch(
         &mut *watcher.lock().unwrap(),
@@ -316,7 +346,14 @@ fn start_fswatch_thread(args: Cli) -> Result<Arc<Mutex<notify::RecommendedWatche
 
 async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
     let compiler = init_compiler(&args, Some(fswatcher));
-    let r = compiler.build_from_path(&args.path).await;
+    let r = match build(&compiler, &args).await {
+        Ok(r) => r,
+        Err(err) => {
+            eprintln!("{err:?}");
+            PENDING_EVENTS.fetch_sub(1, Ordering::SeqCst);
+            return;
+        }
+    };
     r.print_diagnostics();
     if let Some(c) = r.components().next() {
         CURRENT_INSTANCE.with(|current| {
@@ -335,6 +372,11 @@ async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
             if let Some(data_path) = args.load_data {
                 let _ = load_data(&c, current.as_ref().unwrap(), &data_path);
             }
+            if let Some(timeline_path) = &args.timeline {
+                if let Err(err) = play_timeline(current.as_ref().unwrap(), timeline_path) {
+                    eprintln!("Cannot play the timeline: {err:?}");
+                }
+            }
             eprintln!("Successful reload of {}", args.path.display());
         });
     } else if !r.has_errors() {
@@ -347,6 +389,16 @@ async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
     PENDING_EVENTS.fetch_sub(1, Ordering::SeqCst);
 }
 
+fn play_timeline(instance: &ComponentInstance, timeline_path: &std::path::Path) -> Result<()> {
+    let timeline =
+        slint_interpreter::Timeline::from_json(&std::fs::read_to_string(timeline_path)?)?;
+    let player = slint_interpreter::TimelinePlayer::new(instance, &timeline)?;
+    player.play();
+    // Replacing the previous player stops it
+    CURRENT_TIMELINE.with(|current| current.replace(Some(player)));
+    Ok(())
+}
+
 fn load_data(
     c: &ComponentDefinition,
     instance: &ComponentInstance,
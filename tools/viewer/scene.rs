@@ -0,0 +1,152 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Conversion of a scene saved with `Window::serialize_scene()` into a .slint file that shows an
+//! approximation of it.
+
+use serde_json::Value;
+use std::fmt::Write;
+
+/// The most recent version of the scene format that this viewer understands.
+const SUPPORTED_VERSION: u64 = 1;
+
+pub fn scene_to_slint(scene: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let scene: Value = serde_json::from_str(scene)?;
+    let version = scene["version"].as_u64().ok_or("The file is not a serialized Slint scene")?;
+    if version > SUPPORTED_VERSION {
+        return Err(format!("Unsupported scene version {version}, update slint-viewer").into());
+    }
+
+    let mut out = String::from("export component Scene inherits Window {\n");
+    writeln!(out, "    width: {}px;", number(&scene, "width"))?;
+    writeln!(out, "    height: {}px;", number(&scene, "height"))?;
+    for root in scene["items"].as_array().into_iter().flatten() {
+        // The root item is the window itself, so only its background and children are needed.
+        if let Some(background) = root["background"].as_str() {
+            writeln!(out, "    background: {background};")?;
+        }
+        for child in root["children"].as_array().into_iter().flatten() {
+            write_item(&mut out, child, (number(root, "x"), number(root, "y")), 1)?;
+        }
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
+fn number(value: &Value, field: &str) -> f64 {
+    value[field].as_f64().unwrap_or_default()
+}
+
+fn write_item(
+    out: &mut String,
+    item: &Value,
+    (parent_x, parent_y): (f64, f64),
+    depth: usize,
+) -> std::fmt::Result {
+    let indent = "    ".repeat(depth);
+    let (x, y) = (number(item, "x"), number(item, "y"));
+    let element = match item["type"].as_str() {
+        Some("Text" | "TextInput") => "Text",
+        Some("Image") if item["source"].is_string() => "Image",
+        _ => "Rectangle",
+    };
+    writeln!(out, "{indent}{element} {{")?;
+    writeln!(out, "{indent}    x: {}px;", x - parent_x)?;
+    writeln!(out, "{indent}    y: {}px;", y - parent_y)?;
+    writeln!(out, "{indent}    width: {}px;", number(item, "width"))?;
+    writeln!(out, "{indent}    height: {}px;", number(item, "height"))?;
+
+    let mut property = |name: &str, value: String| writeln!(out, "{indent}    {name}: {value};");
+    let color = |field: &str| item[field].as_str().map(str::to_string);
+    let length = |field: &str| format!("{}px", number(item, field));
+    match item["type"].as_str() {
+        Some("Rectangle") => {
+            if let Some(background) = color("background") {
+                property("background", background)?;
+            }
+        }
+        Some("BorderRectangle") => {
+            if let Some(background) = color("background") {
+                property("background", background)?;
+            }
+            if let Some(border_color) = color("border_color") {
+                property("border-color", border_color)?;
+            }
+            property("border-width", length("border_width"))?;
+            property("border-radius", length("border_radius"))?;
+        }
+        Some("Text" | "TextInput") => {
+            property("text", string_literal(item["text"].as_str().unwrap_or_default()))?;
+            if let Some(color) = color("color") {
+                property("color", color)?;
+            }
+            if number(item, "font_size") > 0. {
+                property("font-size", length("font_size"))?;
+            }
+        }
+        Some("Image") => match item["source"].as_str() {
+            Some(source) => {
+                property("source", format!("@image-url({})", string_literal(source)))?;
+                if let Some(colorize) = color("colorize") {
+                    property("colorize", colorize)?;
+                }
+            }
+            // The pixels of the image are not part of the scene, so show where it was.
+            None => {
+                property("background", "#8882".into())?;
+                property("border-color", "#888".into())?;
+                property("border-width", "1px".into())?;
+            }
+        },
+        Some("Clip") => property("clip", item["clip"].as_bool().unwrap_or_default().to_string())?,
+        Some("Flickable") => property("clip", "true".into())?,
+        Some("Opacity") => property("opacity", number(item, "opacity").to_string())?,
+        Some("BoxShadow") => {
+            if let Some(color) = color("color") {
+                property("drop-shadow-color", color)?;
+            }
+            property("drop-shadow-offset-x", length("offset_x"))?;
+            property("drop-shadow-offset-y", length("offset_y"))?;
+            property("drop-shadow-blur", length("blur"))?;
+            property("border-radius", length("border_radius"))?;
+        }
+        _ => {}
+    }
+
+    for child in item["children"].as_array().into_iter().flatten() {
+        write_item(out, child, (x, y), depth + 1)?;
+    }
+    writeln!(out, "{indent}}}")
+}
+
+fn string_literal(string: &str) -> String {
+    let mut literal = String::from("\"");
+    for ch in string.chars() {
+        match ch {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            ch => literal.push(ch),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+#[test]
+fn test_scene_to_slint() {
+    let scene = r##"{"version":1,"width":100,"height":50,"scale_factor":2,"items":[
+        {"type":"Window","x":0,"y":0,"width":100,"height":50,"background":"#ffffffff","children":[
+            {"type":"Rectangle","x":10,"y":10,"width":80,"height":30,"background":"#ff0000ff","children":[
+                {"type":"Text","x":15,"y":15,"width":20,"height":10,"text":"Say \"hi\"","color":"#000000ff","font_size":0,"children":[]}
+            ]}
+        ]}
+    ]}"##;
+    let slint = scene_to_slint(scene).unwrap();
+    assert!(slint.contains("    background: #ffffffff;\n"));
+    assert!(slint.contains("        background: #ff0000ff;\n"));
+    assert!(slint.contains("        Text {\n            x: 5px;\n"));
+    assert!(slint.contains(r#"text: "Say \"hi\"";"#));
+    assert!(!slint.contains("font-size"));
+    assert!(scene_to_slint(r#"{"version":2}"#).is_err());
+}